@@ -0,0 +1,155 @@
+//! Load test for the donation total caches under concurrent `make_donation` traffic.
+//!
+//! Each simulated donation does what the donation path does with the cache: read the
+//! donor's running total for the campaign, read the campaign total, then record the
+//! settled amount. The sharded `DonationCache` is compared with the single
+//! `Mutex<HashMap>` it replaced. Run with `cargo bench --bench donation_cache_load`.
+
+use backend::repository::donation_cache::DonationCache;
+use std::collections::HashMap;
+use std::sync::{Barrier, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const THREADS: usize = 16;
+const DONATIONS_PER_THREAD: usize = 20_000;
+const CAMPAIGNS: i32 = 256;
+const USERS: i32 = 4_096;
+
+trait TotalsCache: Sync {
+    fn make_donation(&self, user_id: i32, campaign_id: i32, amount: f64);
+}
+
+impl TotalsCache for DonationCache {
+    fn make_donation(&self, user_id: i32, campaign_id: i32, amount: f64) {
+        if self.user_campaign_total(user_id, campaign_id).is_none() {
+            self.set_user_campaign_total(user_id, campaign_id, 0.0);
+        }
+        if self.campaign_total(campaign_id).is_none() {
+            self.set_campaign_total(campaign_id, 0.0);
+        }
+        self.record_donation(user_id, campaign_id, amount);
+    }
+}
+
+// The cache as it was before it was sharded: every lookup takes the same lock.
+#[derive(Default)]
+struct MutexCache {
+    campaign_totals: Mutex<HashMap<i32, f64>>,
+    user_totals: Mutex<HashMap<(i32, i32), f64>>,
+}
+
+impl TotalsCache for MutexCache {
+    fn make_donation(&self, user_id: i32, campaign_id: i32, amount: f64) {
+        let user_total = self
+            .user_totals
+            .lock()
+            .unwrap()
+            .get(&(user_id, campaign_id))
+            .copied();
+        if user_total.is_none() {
+            self.user_totals
+                .lock()
+                .unwrap()
+                .insert((user_id, campaign_id), 0.0);
+        }
+        let campaign_total = self
+            .campaign_totals
+            .lock()
+            .unwrap()
+            .get(&campaign_id)
+            .copied();
+        if campaign_total.is_none() {
+            self.campaign_totals
+                .lock()
+                .unwrap()
+                .insert(campaign_id, 0.0);
+        }
+        if let Some(total) = self.campaign_totals.lock().unwrap().get_mut(&campaign_id) {
+            *total += amount;
+        }
+        if let Some(total) = self
+            .user_totals
+            .lock()
+            .unwrap()
+            .get_mut(&(user_id, campaign_id))
+        {
+            *total += amount;
+        }
+    }
+}
+
+// Cheap deterministic spread of donors and campaigns, so both caches see the same load.
+fn donation(thread: usize, i: usize) -> (i32, i32) {
+    let n = (thread * DONATIONS_PER_THREAD + i) as u64;
+    let mixed = n.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 16;
+    (
+        (mixed % USERS as u64) as i32,
+        (mixed % CAMPAIGNS as u64) as i32,
+    )
+}
+
+fn run(cache: &dyn TotalsCache) -> (Duration, Vec<Duration>) {
+    let barrier = Barrier::new(THREADS);
+    let started = Instant::now();
+    let mut latencies: Vec<Duration> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    let mut latencies = Vec::with_capacity(DONATIONS_PER_THREAD);
+                    barrier.wait();
+                    for i in 0..DONATIONS_PER_THREAD {
+                        let (user_id, campaign_id) = donation(t, i);
+                        let call = Instant::now();
+                        cache.make_donation(user_id, campaign_id, 10_000.0);
+                        latencies.push(call.elapsed());
+                    }
+                    latencies
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    let elapsed = started.elapsed();
+    latencies.sort_unstable();
+    (elapsed, latencies)
+}
+
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    sorted[((sorted.len() - 1) as f64 * p).round() as usize]
+}
+
+fn report(name: &str, cache: &dyn TotalsCache) -> Duration {
+    let (elapsed, latencies) = run(cache);
+    let p99 = percentile(&latencies, 0.99);
+    println!(
+        "{:<12} {:>10.0} donations/s  p50 {:>8?}  p99 {:>8?}  max {:>8?}",
+        name,
+        latencies.len() as f64 / elapsed.as_secs_f64(),
+        percentile(&latencies, 0.50),
+        p99,
+        latencies[latencies.len() - 1],
+    );
+    p99
+}
+
+fn main() {
+    // Lock contention only shows when the threads actually run in parallel.
+    println!(
+        "{} threads x {} donations over {} campaigns on {} cores",
+        THREADS,
+        DONATIONS_PER_THREAD,
+        CAMPAIGNS,
+        thread::available_parallelism().map_or(1, |cores| cores.get())
+    );
+    let mutex_p99 = report("mutex", &MutexCache::default());
+    let dashmap_p99 = report("dashmap", &DonationCache::new());
+    println!(
+        "p99 improvement: {:.1}x",
+        mutex_p99.as_secs_f64() / dashmap_p99.as_secs_f64().max(f64::EPSILON)
+    );
+}
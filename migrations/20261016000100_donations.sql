@@ -0,0 +1,31 @@
+CREATE TYPE donation_status AS ENUM ('settled', 'pending_review', 'rejected', 'expired', 'imported', 'refunded');
+CREATE TABLE donations (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    campaign_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    message TEXT,
+    private_note TEXT,
+    referral_code TEXT,
+    honoree_name TEXT,
+    honoree_email TEXT,
+    status donation_status NOT NULL DEFAULT 'settled',
+    receipt_number TEXT UNIQUE,
+    receipt_issued_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE receipt_numbers (
+    fiscal_year INT PRIMARY KEY,
+    last_number INT NOT NULL
+);
+CREATE TABLE donations_archive (
+    LIKE donations INCLUDING DEFAULTS,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (id)
+);
+CREATE VIEW all_donations AS
+    SELECT id, user_id, campaign_id, amount, referral_code, status, receipt_number, receipt_issued_at, created_at
+    FROM donations
+    UNION ALL
+    SELECT id, user_id, campaign_id, amount, referral_code, status, receipt_number, receipt_issued_at, created_at
+    FROM donations_archive;
@@ -0,0 +1,25 @@
+CREATE TABLE wallets (
+    user_id INT PRIMARY KEY,
+    balance FLOAT8 NOT NULL DEFAULT 0,
+    held_amount FLOAT8 NOT NULL DEFAULT 0
+);
+CREATE TYPE campaign_overflow_policy AS ENUM ('allow', 'cap', 'extend');
+CREATE TABLE campaigns (
+    id SERIAL PRIMARY KEY,
+    target_amount FLOAT8 NOT NULL,
+    collected_amount FLOAT8 NOT NULL DEFAULT 0,
+    held_amount FLOAT8 NOT NULL DEFAULT 0,
+    fundraiser_id INT,
+    title TEXT NOT NULL DEFAULT '',
+    status TEXT NOT NULL DEFAULT 'active',
+    overflow_policy campaign_overflow_policy NOT NULL DEFAULT 'allow',
+    spend_reports_required BOOLEAN NOT NULL DEFAULT FALSE,
+    latitude FLOAT8,
+    longitude FLOAT8,
+    description TEXT,
+    region TEXT,
+    category TEXT,
+    organization_id INT,
+    CHECK ((latitude IS NULL) = (longitude IS NULL))
+);
+CREATE INDEX campaigns_latitude ON campaigns (latitude) WHERE latitude IS NOT NULL;
@@ -0,0 +1,9 @@
+CREATE TYPE transaction_type AS ENUM ('top_up', 'donation', 'withdrawal', 'refund');
+CREATE TABLE transactions (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    campaign_id INT,
+    transaction_type transaction_type NOT NULL,
+    amount FLOAT8 NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
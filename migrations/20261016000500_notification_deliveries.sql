@@ -0,0 +1,16 @@
+CREATE TYPE notification_delivery_status AS ENUM ('pending', 'delivered', 'dead');
+CREATE TABLE notification_delivery_attempts (
+    id BIGSERIAL PRIMARY KEY,
+    outbox_id BIGINT NOT NULL REFERENCES outbox (id),
+    event_type TEXT NOT NULL,
+    subscriber TEXT NOT NULL,
+    status notification_delivery_status NOT NULL DEFAULT 'pending',
+    attempts INT NOT NULL DEFAULT 1,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (outbox_id, subscriber)
+);
+CREATE INDEX notification_delivery_attempts_due
+    ON notification_delivery_attempts (next_attempt_at) WHERE status = 'pending';
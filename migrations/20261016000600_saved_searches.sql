@@ -0,0 +1,17 @@
+CREATE TABLE saved_searches (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    keywords TEXT,
+    category TEXT,
+    region TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (keywords IS NOT NULL OR category IS NOT NULL OR region IS NOT NULL)
+);
+CREATE INDEX saved_searches_user ON saved_searches (user_id);
+CREATE TABLE saved_search_alerts (
+    saved_search_id INT NOT NULL REFERENCES saved_searches (id) ON DELETE CASCADE,
+    campaign_id INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (saved_search_id, campaign_id)
+);
@@ -0,0 +1,23 @@
+CREATE TYPE dispute_status AS ENUM ('open', 'refunded', 'dismissed');
+CREATE TABLE disputes (
+    id SERIAL PRIMARY KEY,
+    donation_id INT NOT NULL,
+    user_id INT NOT NULL,
+    campaign_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    reason TEXT NOT NULL,
+    status dispute_status NOT NULL DEFAULT 'open',
+    resolved_by INT,
+    resolution_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+CREATE UNIQUE INDEX disputes_one_open_per_donation ON disputes (donation_id) WHERE status = 'open';
+CREATE TABLE dispute_audit_log (
+    id SERIAL PRIMARY KEY,
+    dispute_id INT NOT NULL REFERENCES disputes (id),
+    actor_id INT NOT NULL,
+    action TEXT NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,9 @@
+CREATE TYPE campaign_role AS ENUM ('owner', 'editor', 'viewer');
+CREATE TABLE campaign_members (
+    campaign_id INT NOT NULL REFERENCES campaigns (id),
+    user_id INT NOT NULL,
+    role campaign_role NOT NULL,
+    added_by INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (campaign_id, user_id)
+);
@@ -0,0 +1,25 @@
+CREATE TYPE organization_role AS ENUM ('admin', 'treasurer', 'member');
+CREATE TABLE organizations (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    registration_number TEXT NOT NULL UNIQUE,
+    description TEXT,
+    website TEXT,
+    created_by INT NOT NULL,
+    verified_at TIMESTAMPTZ,
+    verified_by INT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE organization_members (
+    organization_id INT NOT NULL REFERENCES organizations (id),
+    user_id INT NOT NULL,
+    role organization_role NOT NULL,
+    added_by INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+CREATE TABLE organization_wallets (
+    organization_id INT PRIMARY KEY REFERENCES organizations (id),
+    balance FLOAT8 NOT NULL DEFAULT 0,
+    held_amount FLOAT8 NOT NULL DEFAULT 0
+);
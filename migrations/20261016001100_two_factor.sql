@@ -0,0 +1,14 @@
+CREATE TABLE admin_two_factor (
+    admin_id INT PRIMARY KEY,
+    secret TEXT NOT NULL,
+    enabled_at TIMESTAMPTZ,
+    last_verified_at TIMESTAMPTZ,
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE admin_recovery_codes (
+    id SERIAL PRIMARY KEY,
+    admin_id INT NOT NULL REFERENCES admin_two_factor (admin_id),
+    code_hash TEXT NOT NULL,
+    used_at TIMESTAMPTZ
+);
@@ -0,0 +1,11 @@
+CREATE TYPE security_event_type AS ENUM ('login_succeeded', 'login_failed');
+CREATE TABLE security_events (
+    id BIGSERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    event_type security_event_type NOT NULL,
+    ip_address TEXT,
+    user_agent TEXT,
+    country TEXT,
+    suspicious BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,20 @@
+CREATE TYPE discrepancy_kind AS ENUM ('missing_in_ledger', 'missing_in_settlement', 'duplicated', 'amount_mismatch');
+CREATE TABLE reconciliation_runs (
+    id SERIAL PRIMARY KEY,
+    provider TEXT NOT NULL,
+    period_from TIMESTAMPTZ NOT NULL,
+    period_to TIMESTAMPTZ NOT NULL,
+    settlement_rows INT NOT NULL,
+    matched INT NOT NULL,
+    discrepancy_count INT NOT NULL,
+    created_by INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE reconciliation_discrepancies (
+    id SERIAL PRIMARY KEY,
+    run_id INT NOT NULL REFERENCES reconciliation_runs (id),
+    kind discrepancy_kind NOT NULL,
+    order_id INT NOT NULL,
+    ledger_amount FLOAT8,
+    settlement_amount FLOAT8
+);
@@ -0,0 +1,7 @@
+CREATE TABLE profiles (
+    user_id INT PRIMARY KEY,
+    display_name TEXT NOT NULL,
+    avatar_url TEXT,
+    bio TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,8 @@
+CREATE TABLE campaign_images (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL REFERENCES campaigns (id),
+    url TEXT NOT NULL,
+    position INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX campaign_images_campaign_position ON campaign_images (campaign_id, position);
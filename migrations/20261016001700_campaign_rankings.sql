@@ -0,0 +1,5 @@
+CREATE TABLE campaign_rankings (
+    campaign_id INT PRIMARY KEY REFERENCES campaigns (id),
+    score FLOAT8 NOT NULL,
+    computed_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,17 @@
+CREATE TYPE donation_intent_status AS ENUM ('pending', 'confirmed', 'expired', 'failed');
+CREATE TABLE donation_intents (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    campaign_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    message TEXT,
+    private_note TEXT,
+    tier_id INT,
+    referral_code TEXT,
+    token_hash TEXT NOT NULL,
+    status donation_intent_status NOT NULL DEFAULT 'pending',
+    donation_id INT,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX donation_intents_pending_expiry ON donation_intents (expires_at) WHERE status = 'pending';
@@ -0,0 +1,15 @@
+CREATE TABLE donation_tiers (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    min_amount FLOAT8 NOT NULL,
+    quantity INT,
+    remaining INT CHECK (remaining >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE reward_claims (
+    donation_id INT PRIMARY KEY,
+    tier_id INT NOT NULL REFERENCES donation_tiers (id),
+    claimed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
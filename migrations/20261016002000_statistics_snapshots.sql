@@ -0,0 +1,11 @@
+CREATE TYPE statistic_metric AS ENUM (
+    'donation_amount_total', 'donation_count', 'donor_count',
+    'active_campaigns', 'completed_campaigns', 'wallet_balance_total'
+);
+CREATE TABLE statistics_snapshots (
+    snapshot_date DATE NOT NULL,
+    metric statistic_metric NOT NULL,
+    value FLOAT8 NOT NULL,
+    captured_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (snapshot_date, metric)
+);
@@ -0,0 +1,10 @@
+CREATE TABLE short_links (
+    id SERIAL PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    campaign_id INT NOT NULL,
+    channel TEXT,
+    click_count BIGINT NOT NULL DEFAULT 0,
+    created_by INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX short_links_campaign ON short_links (campaign_id);
@@ -0,0 +1,8 @@
+CREATE TYPE share_platform AS ENUM ('whatsapp', 'facebook', 'x', 'telegram', 'instagram', 'copy_link', 'other');
+CREATE TABLE campaign_shares (
+    campaign_id INT NOT NULL REFERENCES campaigns (id),
+    platform share_platform NOT NULL,
+    day DATE NOT NULL,
+    share_count BIGINT NOT NULL,
+    PRIMARY KEY (campaign_id, platform, day)
+);
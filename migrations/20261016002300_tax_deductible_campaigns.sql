@@ -0,0 +1,5 @@
+CREATE TABLE tax_deductible_campaigns (
+    campaign_id INT PRIMARY KEY REFERENCES campaigns (id),
+    marked_by INT NOT NULL,
+    marked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,8 @@
+CREATE TABLE donation_export_runs (
+    id SERIAL PRIMARY KEY,
+    since TIMESTAMPTZ,
+    until TIMESTAMPTZ NOT NULL,
+    object_key TEXT NOT NULL,
+    row_count BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,5 @@
+CREATE TABLE notification_preferences (
+    user_id INT PRIMARY KEY,
+    low_balance_threshold FLOAT8,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,11 @@
+CREATE TYPE campaign_review_item AS ENUM ('identity_verified', 'documents_valid', 'description_accurate');
+CREATE TYPE campaign_review_result AS ENUM ('pending', 'passed', 'failed');
+CREATE TABLE campaign_reviews (
+    campaign_id INT NOT NULL,
+    item campaign_review_item NOT NULL,
+    result campaign_review_result NOT NULL,
+    comment TEXT,
+    reviewer_id INT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (campaign_id, item)
+);
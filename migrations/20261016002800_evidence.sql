@@ -0,0 +1,16 @@
+CREATE TYPE evidence_processing_status AS ENUM ('queued', 'completed', 'failed');
+CREATE TABLE campaign_evidence (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL,
+    uploaded_by INT NOT NULL,
+    file_name TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    status evidence_processing_status NOT NULL DEFAULT 'queued',
+    content_type TEXT,
+    page_count INT,
+    exif_stripped BOOLEAN NOT NULL DEFAULT FALSE,
+    object_key TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    processed_at TIMESTAMPTZ
+);
@@ -0,0 +1,30 @@
+CREATE TYPE kyc_status AS ENUM ('draft', 'pending', 'approved', 'rejected');
+CREATE TYPE kyc_document_type AS ENUM ('id_card', 'selfie', 'registration_certificate', 'tax_id');
+CREATE TABLE kyc_submissions (
+    id SERIAL PRIMARY KEY,
+    user_id INT,
+    organization_id INT,
+    submitted_by INT NOT NULL,
+    status kyc_status NOT NULL DEFAULT 'draft',
+    review_note TEXT,
+    reviewed_by INT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMPTZ,
+    reviewed_at TIMESTAMPTZ,
+    CHECK ((user_id IS NULL) <> (organization_id IS NULL))
+);
+CREATE UNIQUE INDEX kyc_submissions_one_open_per_user
+    ON kyc_submissions (user_id) WHERE status IN ('draft', 'pending');
+CREATE UNIQUE INDEX kyc_submissions_one_open_per_organization
+    ON kyc_submissions (organization_id) WHERE status IN ('draft', 'pending');
+CREATE TABLE kyc_documents (
+    id SERIAL PRIMARY KEY,
+    submission_id INT NOT NULL REFERENCES kyc_submissions (id),
+    document_type kyc_document_type NOT NULL,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    object_key TEXT NOT NULL,
+    uploaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (submission_id, document_type)
+);
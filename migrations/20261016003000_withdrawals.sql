@@ -0,0 +1,26 @@
+CREATE TYPE withdrawal_status AS ENUM ('pending', 'approved', 'rejected');
+CREATE TABLE withdrawals (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    bank_name TEXT NOT NULL,
+    account_number TEXT NOT NULL,
+    account_holder TEXT NOT NULL,
+    status withdrawal_status NOT NULL DEFAULT 'pending',
+    admin_note TEXT,
+    required_approvals INT NOT NULL DEFAULT 1,
+    approval_count INT NOT NULL DEFAULT 0,
+    organization_id INT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reviewed_at TIMESTAMPTZ
+);
+CREATE TABLE withdrawal_audit_log (
+    id SERIAL PRIMARY KEY,
+    withdrawal_id INT NOT NULL REFERENCES withdrawals (id),
+    actor_id INT,
+    action TEXT NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX withdrawal_audit_log_one_approval_per_admin
+    ON withdrawal_audit_log (withdrawal_id, actor_id) WHERE action = 'approved';
@@ -0,0 +1,17 @@
+CREATE TABLE campaign_budget_items (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL,
+    label TEXT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    position INT NOT NULL
+);
+CREATE TABLE campaign_spend_reports (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL,
+    budget_item_id INT NOT NULL REFERENCES campaign_budget_items (id),
+    withdrawal_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    description TEXT NOT NULL,
+    created_by INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
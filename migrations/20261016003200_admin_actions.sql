@@ -0,0 +1,17 @@
+CREATE TYPE admin_action_kind AS ENUM ('delete_campaign', 'adjust_balance', 'refund_all_donations');
+CREATE TYPE admin_action_status AS ENUM ('pending', 'approved', 'declined', 'failed');
+CREATE TABLE pending_admin_actions (
+    id SERIAL PRIMARY KEY,
+    kind admin_action_kind NOT NULL,
+    target_id INT NOT NULL,
+    amount FLOAT8,
+    reason TEXT,
+    status admin_action_status NOT NULL DEFAULT 'pending',
+    requested_by INT NOT NULL,
+    decided_by INT,
+    decision_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    decided_at TIMESTAMPTZ
+);
+CREATE UNIQUE INDEX pending_admin_actions_one_pending_per_target
+    ON pending_admin_actions (kind, target_id) WHERE status = 'pending';
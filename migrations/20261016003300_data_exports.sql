@@ -0,0 +1,10 @@
+CREATE TYPE data_export_status AS ENUM ('pending', 'ready', 'failed');
+CREATE TABLE data_exports (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    status data_export_status NOT NULL DEFAULT 'pending',
+    payload JSONB,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
@@ -0,0 +1,29 @@
+CREATE TYPE risk_rule_kind AS ENUM ('daily_amount_cap', 'distinct_campaigns_per_hour');
+CREATE TYPE risk_action AS ENUM ('block', 'flag');
+CREATE TYPE risk_activity AS ENUM ('donation', 'top_up');
+CREATE TABLE risk_rules (
+    id SERIAL PRIMARY KEY,
+    kind risk_rule_kind NOT NULL,
+    threshold FLOAT8 NOT NULL,
+    action risk_action NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE risk_blacklist (
+    id SERIAL PRIMARY KEY,
+    user_id INT,
+    ip_address TEXT,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (user_id IS NOT NULL OR ip_address IS NOT NULL)
+);
+CREATE TABLE risk_flags (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    rule_id INT REFERENCES risk_rules (id) ON DELETE SET NULL,
+    activity risk_activity NOT NULL,
+    amount FLOAT8 NOT NULL,
+    action risk_action NOT NULL,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,11 @@
+CREATE TABLE balance_adjustments (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    amount FLOAT8 NOT NULL,
+    reason TEXT NOT NULL,
+    requested_by INT NOT NULL,
+    approved_by INT,
+    admin_action_id INT REFERENCES pending_admin_actions (id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX balance_adjustments_user_id ON balance_adjustments (user_id, created_at);
@@ -0,0 +1,9 @@
+CREATE TABLE campaign_audit_log (
+    id SERIAL PRIMARY KEY,
+    campaign_id INT NOT NULL REFERENCES campaigns (id),
+    actor_id INT NOT NULL,
+    action TEXT NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX campaign_audit_log_campaign_id ON campaign_audit_log (campaign_id);
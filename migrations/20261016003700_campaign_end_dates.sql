@@ -0,0 +1 @@
+ALTER TABLE campaigns ADD COLUMN ends_at TIMESTAMPTZ;
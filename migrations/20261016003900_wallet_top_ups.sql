@@ -0,0 +1,7 @@
+-- Top-ups confirmed by a payment provider carry the provider's payment reference, so
+-- a redelivered notification cannot credit the wallet twice.
+ALTER TABLE transactions
+    ADD COLUMN provider TEXT,
+    ADD COLUMN reference TEXT;
+CREATE UNIQUE INDEX transactions_provider_reference_key
+    ON transactions (provider, reference) WHERE reference IS NOT NULL;
@@ -0,0 +1,8 @@
+-- Failed events back off between attempts and are dead-lettered once out of them,
+-- instead of being retried on every dispatcher run forever.
+CREATE TYPE outbox_status AS ENUM ('pending', 'dispatched', 'dead');
+ALTER TABLE outbox
+    ADD COLUMN status outbox_status NOT NULL DEFAULT 'pending',
+    ADD COLUMN next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+UPDATE outbox SET status = 'dispatched' WHERE dispatched_at IS NOT NULL;
+CREATE INDEX outbox_due ON outbox (next_attempt_at) WHERE status = 'pending';
@@ -0,0 +1,4 @@
+ALTER TABLE notification_preferences
+    ADD COLUMN daily_digest BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN last_digest_at TIMESTAMPTZ;
+CREATE INDEX outbox_user_notifications ON outbox (((payload ->> 'user_id')::INT), created_at);
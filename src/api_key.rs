@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+
+use rocket::State;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+
+use crate::auth::AdminUser;
+use crate::errors::AppError;
+use crate::model::api_key::ApiKeyScope;
+use crate::service::api_key_service::ApiKeyService;
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// The scope an `ApiKey` guard demands, e.g. `ApiKey<StatsRead>`.
+pub trait RequiredScope: Send + Sync + 'static {
+    const SCOPE: ApiKeyScope;
+}
+
+pub struct StatsRead;
+
+impl RequiredScope for StatsRead {
+    const SCOPE: ApiKeyScope = ApiKeyScope::StatsRead;
+}
+
+pub struct NotificationsWrite;
+
+impl RequiredScope for NotificationsWrite {
+    const SCOPE: ApiKeyScope = ApiKeyScope::NotificationsWrite;
+}
+
+pub struct ExportsRead;
+
+impl RequiredScope for ExportsRead {
+    const SCOPE: ApiKeyScope = ApiKeyScope::ExportsRead;
+}
+
+/// Machine caller authenticated by the `X-Api-Key` header and holding scope `S`.
+pub struct ApiKey<S: RequiredScope> {
+    pub id: i32,
+    pub name: String,
+    _scope: PhantomData<S>,
+}
+
+#[rocket::async_trait]
+impl<'r, S: RequiredScope> FromRequest<'r> for ApiKey<S> {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, AppError> {
+        let Some(key) = req.headers().get_one(API_KEY_HEADER) else {
+            return Outcome::Error((Status::Unauthorized, AppError::Unauthorized));
+        };
+        let Some(api_key_service) = req.guard::<&State<ApiKeyService>>().await.succeeded() else {
+            return Outcome::Error((
+                Status::InternalServerError,
+                AppError::InternalServerError("ApiKeyService is not managed".to_string()),
+            ));
+        };
+
+        match api_key_service.authenticate(key, S::SCOPE).await {
+            Ok(record) => Outcome::Success(ApiKey {
+                id: record.id,
+                name: record.name,
+                _scope: PhantomData,
+            }),
+            Err(e) => {
+                let status = match e {
+                    AppError::Unauthorized => Status::Unauthorized,
+                    AppError::Forbidden(_) => Status::Forbidden,
+                    _ => Status::InternalServerError,
+                };
+                Outcome::Error((status, e))
+            }
+        }
+    }
+}
+
+/// Either a signed-in admin or a machine caller holding scope `S`, for admin reads
+/// that dashboards and cron jobs also pull. A request with `X-Api-Key` is checked
+/// as an API key; anything else must carry an admin token.
+pub enum AdminOrApiKey<S: RequiredScope> {
+    Admin(AdminUser),
+    ApiKey(ApiKey<S>),
+}
+
+#[rocket::async_trait]
+impl<'r, S: RequiredScope> FromRequest<'r> for AdminOrApiKey<S> {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, AppError> {
+        if req.headers().contains(API_KEY_HEADER) {
+            req.guard::<ApiKey<S>>().await.map(AdminOrApiKey::ApiKey)
+        } else {
+            req.guard::<AdminUser>().await.map(AdminOrApiKey::Admin)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::TokenVerifier;
+    use crate::auth::tests::{TEST_SECRET, bearer};
+    use crate::model::api_key::ApiKeyRecord;
+    use crate::repository::api_key_repo::MockApiKeyRepository;
+    use crate::service::api_key_service::hash_api_key;
+    use chrono::Utc;
+    use rocket::http::Header;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+    use std::sync::Arc;
+
+    #[get("/stats")]
+    fn stats_route(caller: AdminOrApiKey<StatsRead>) -> String {
+        match caller {
+            AdminOrApiKey::Admin(admin) => format!("admin {}", admin.id),
+            AdminOrApiKey::ApiKey(key) => format!("key {}", key.name),
+        }
+    }
+
+    fn client() -> Client {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+        mock_api_key_repo
+            .expect_find_active_by_hash()
+            .returning(|key_hash| {
+                let scopes = if key_hash == hash_api_key("ak_stats") {
+                    vec![ApiKeyScope::StatsRead]
+                } else if key_hash == hash_api_key("ak_exports") {
+                    vec![ApiKeyScope::ExportsRead]
+                } else {
+                    return Ok(None);
+                };
+                Ok(Some(ApiKeyRecord {
+                    id: 1,
+                    name: "dashboard".to_string(),
+                    key_prefix: "stats".to_string(),
+                    key_hash: key_hash.to_string(),
+                    scopes,
+                    created_by: 1,
+                    created_at: Utc::now(),
+                    last_used_at: Some(Utc::now()),
+                    revoked_at: None,
+                }))
+            });
+        let rocket = rocket::build()
+            .mount("/", routes![stats_route])
+            .manage(TokenVerifier::new(TEST_SECRET))
+            .manage(ApiKeyService::new(Arc::new(mock_api_key_repo)));
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_admin_or_api_key_accepts_either_credential() {
+        let client = client();
+        let response = client.get("/stats").header(bearer(4, true)).dispatch();
+        assert_eq!(response.into_string().unwrap(), "admin 4");
+        let response = client
+            .get("/stats")
+            .header(Header::new(API_KEY_HEADER, "ak_stats"))
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "key dashboard");
+    }
+
+    #[test]
+    fn test_admin_or_api_key_rejects_other_scopes_and_non_admins() {
+        let client = client();
+        let response = client
+            .get("/stats")
+            .header(Header::new(API_KEY_HEADER, "ak_exports"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+        let response = client
+            .get("/stats")
+            .header(Header::new(API_KEY_HEADER, "ak_unknown"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        let response = client.get("/stats").header(bearer(4, false)).dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+}
@@ -0,0 +1,263 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use rocket::State;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::serde::Deserialize;
+use rocket::serde::json;
+use sha2::Sha256;
+
+use crate::errors::AppError;
+use crate::fairing::error_reporting::ReportedUserId;
+
+const ADMIN_ROLE: &str = "admin";
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Header {
+    alg: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    role: Option<String>,
+    exp: i64,
+}
+
+/// Checks the HS256 access tokens issued by the auth service. Users, passwords
+/// and sessions live there; this service only trusts the signed user id and role.
+pub struct TokenVerifier {
+    secret: Vec<u8>,
+}
+
+impl TokenVerifier {
+    pub fn new(secret: &str) -> Self {
+        TokenVerifier {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    fn verify(&self, token: &str) -> Result<Claims, AppError> {
+        let mut parts = token.split('.');
+        let (Some(header), Some(payload), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(AppError::Unauthorized);
+        };
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| AppError::Unauthorized)?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(header.as_bytes());
+        mac.update(b".");
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| AppError::Unauthorized)?;
+
+        let header: Header = decode_segment(header)?;
+        if header.alg != "HS256" {
+            return Err(AppError::Unauthorized);
+        }
+        let claims: Claims = decode_segment(payload)?;
+        if claims.exp <= chrono::Utc::now().timestamp() {
+            return Err(AppError::Unauthorized);
+        }
+        Ok(claims)
+    }
+}
+
+fn decode_segment<T: rocket::serde::DeserializeOwned>(segment: &str) -> Result<T, AppError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| AppError::Unauthorized)?;
+    json::from_slice(&bytes).map_err(|_| AppError::Unauthorized)
+}
+
+async fn authenticate(req: &Request<'_>) -> Result<(i32, Option<String>), (Status, AppError)> {
+    let Some(token) = req
+        .headers()
+        .get_one("Authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return Err((Status::Unauthorized, AppError::Unauthorized));
+    };
+    let Some(verifier) = req.guard::<&State<TokenVerifier>>().await.succeeded() else {
+        return Err((
+            Status::InternalServerError,
+            AppError::InternalServerError("TokenVerifier is not managed".to_string()),
+        ));
+    };
+    let claims = verifier
+        .verify(token)
+        .map_err(|e| (Status::Unauthorized, e))?;
+    let user_id = claims
+        .sub
+        .parse::<i32>()
+        .map_err(|_| (Status::Unauthorized, AppError::Unauthorized))?;
+    // Read by error reporting, request logging and metrics once the response is out.
+    req.local_cache(|| ReportedUserId(Some(user_id)));
+    Ok((user_id, claims.role))
+}
+
+/// Any signed-in user, from a `Authorization: Bearer` access token.
+pub struct AuthUser {
+    pub id: i32,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AuthUser {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, AppError> {
+        match authenticate(req).await {
+            Ok((id, _)) => Outcome::Success(AuthUser { id }),
+            Err(e) => Outcome::Error(e),
+        }
+    }
+}
+
+/// A signed-in user whose token carries the `admin` role.
+pub struct AdminUser {
+    pub id: i32,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AdminUser {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, AppError> {
+        match authenticate(req).await {
+            Ok((id, Some(role))) if role == ADMIN_ROLE => Outcome::Success(AdminUser { id }),
+            Ok(_) => Outcome::Error((
+                Status::Forbidden,
+                AppError::Forbidden("Admin access required".to_string()),
+            )),
+            Err(e) => Outcome::Error(e),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+
+    pub(crate) const TEST_SECRET: &str = "test-secret";
+
+    pub(crate) fn sign(secret: &str, payload: &str) -> String {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(payload);
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{}.{}", header, payload).as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+        format!("{}.{}.{}", header, payload, signature)
+    }
+
+    /// A valid bearer header for `user_id`, with the admin role when `admin` is set.
+    pub(crate) fn bearer(user_id: i32, admin: bool) -> rocket::http::Header<'static> {
+        let exp = chrono::Utc::now().timestamp() + 3600;
+        let payload = if admin {
+            format!(r#"{{"sub":"{}","role":"admin","exp":{}}}"#, user_id, exp)
+        } else {
+            format!(r#"{{"sub":"{}","exp":{}}}"#, user_id, exp)
+        };
+        rocket::http::Header::new(
+            "Authorization",
+            format!("Bearer {}", sign(TEST_SECRET, &payload)),
+        )
+    }
+
+    #[get("/me")]
+    fn me_route(auth_user: AuthUser) -> String {
+        auth_user.id.to_string()
+    }
+
+    #[get("/admin")]
+    fn admin_route(admin: AdminUser) -> String {
+        admin.id.to_string()
+    }
+
+    // Runs after `AuthUser`, so it sees whatever the auth guard recorded.
+    struct Reported(Option<i32>);
+
+    #[rocket::async_trait]
+    impl<'r> FromRequest<'r> for Reported {
+        type Error = ();
+
+        async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+            Outcome::Success(Reported(req.local_cache(|| ReportedUserId(None)).0))
+        }
+    }
+
+    #[get("/me/reported")]
+    fn reported_route(_auth_user: AuthUser, reported: Reported) -> String {
+        format!("{:?}", reported.0)
+    }
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![me_route, admin_route, reported_route])
+            .manage(TokenVerifier::new(TEST_SECRET));
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_accepts_signed_tokens() {
+        let client = client();
+        let response = client.get("/me").header(bearer(7, false)).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "7");
+        let response = client.get("/admin").header(bearer(1, true)).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    #[test]
+    fn test_rejects_missing_forged_and_expired_tokens() {
+        let client = client();
+        assert_eq!(client.get("/me").dispatch().status(), Status::Unauthorized);
+
+        let exp = chrono::Utc::now().timestamp() + 3600;
+        let forged = sign("other-secret", &format!(r#"{{"sub":"7","exp":{}}}"#, exp));
+        let response = client
+            .get("/me")
+            .header(rocket::http::Header::new(
+                "Authorization",
+                format!("Bearer {}", forged),
+            ))
+            .dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        let expired = sign(TEST_SECRET, r#"{"sub":"7","exp":1}"#);
+        let response = client
+            .get("/me")
+            .header(rocket::http::Header::new(
+                "Authorization",
+                format!("Bearer {}", expired),
+            ))
+            .dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    #[test]
+    fn test_records_the_user_for_error_reporting() {
+        let client = client();
+        let response = client
+            .get("/me/reported")
+            .header(bearer(7, false))
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "Some(7)");
+    }
+
+    #[test]
+    fn test_admin_routes_need_the_admin_role() {
+        let client = client();
+        let response = client.get("/admin").header(bearer(7, false)).dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+}
@@ -0,0 +1,745 @@
+use rocket::Config;
+use rocket::figment::Figment;
+use rocket::figment::providers::Env;
+use rocket::figment::value::{Uncased, UncasedStr};
+use rocket::serde::Deserialize;
+use thiserror::Error;
+
+use crate::fairing::compression::CompressionConfig;
+use crate::fairing::cors::CorsConfig;
+use crate::fairing::error_reporting::ErrorReportingConfig;
+use crate::fairing::security_headers::SecurityHeadersConfig;
+use crate::logging::LoggingConfig;
+use crate::service::ops_alerter::AlertWebhookFormat;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("could not read configuration: {0}")]
+    Extract(Box<rocket::figment::Error>),
+
+    #[error("invalid configuration:\n  - {}", .0.join("\n  - "))]
+    Invalid(Vec<String>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct DatabaseConfig {
+    pub url: Option<String>,
+    pub max_connections: u32,
+    /// Queries taking at least this long are logged and counted as slow.
+    pub slow_query_threshold_ms: u64,
+    /// Re-runs the slowest statements under `EXPLAIN ANALYZE` for the diagnostics
+    /// endpoint. Debug builds only: every capture executes the query a second time.
+    pub explain_slow_queries: bool,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        DatabaseConfig {
+            url: None,
+            max_connections: 10,
+            slow_query_threshold_ms: 250,
+            explain_slow_queries: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct AuthConfig {
+    /// HS256 key shared with the auth service that issues access tokens.
+    pub jwt_secret: Option<String>,
+    /// The auth service's batch account endpoint. Bulk user imports are not mounted
+    /// without it.
+    pub accounts_url: Option<String>,
+    /// Bearer token this service presents to `accounts_url`.
+    pub service_token: Option<String>,
+    /// 64 hex characters: the AES-256 key admin TOTP secrets are encrypted with.
+    pub two_factor_key: Option<String>,
+}
+
+impl AuthConfig {
+    /// None if the key is missing or not 32 bytes of hex.
+    pub fn two_factor_key_bytes(&self) -> Option<[u8; 32]> {
+        let key = hex::decode(self.two_factor_key.as_deref()?).ok()?;
+        key.try_into().ok()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct PaymentProviderConfig {
+    pub name: String,
+    pub api_base_url: String,
+    /// Signs top-up notifications and authenticates settlement report downloads.
+    pub secret_key: Option<String>,
+    pub enabled: bool,
+    /// Path under `api_base_url` serving the daily settlement report CSV.
+    pub settlement_report_path: String,
+}
+
+impl Default for PaymentProviderConfig {
+    fn default() -> Self {
+        PaymentProviderConfig {
+            name: String::new(),
+            api_base_url: String::new(),
+            secret_key: None,
+            enabled: true,
+            settlement_report_path: "/settlements".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct LimitsConfig {
+    pub review_threshold: f64,
+    pub dispute_window_days: i64,
+    pub max_page_size: i64,
+    /// Wallet balance adjustments larger than this need a second admin to confirm them.
+    pub balance_adjustment_cap: f64,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        LimitsConfig {
+            review_threshold: 10_000_000.0,
+            dispute_window_days: 30,
+            max_page_size: 200,
+            balance_adjustment_cap: 1_000_000.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct FeatureToggles {
+    pub disputes: bool,
+    pub donation_import: bool,
+    pub public_feeds: bool,
+}
+
+impl Default for FeatureToggles {
+    fn default() -> Self {
+        FeatureToggles {
+            disputes: true,
+            donation_import: true,
+            public_feeds: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct SharingConfig {
+    /// Web frontend origin that campaign share URLs point at.
+    pub public_base_url: String,
+    /// Public origin of this API, which serves the `/c/<slug>` short links.
+    pub short_link_base_url: String,
+}
+
+impl Default for SharingConfig {
+    fn default() -> Self {
+        SharingConfig {
+            public_base_url: "http://localhost:3000".to_string(),
+            short_link_base_url: "http://localhost:8000".to_string(),
+        }
+    }
+}
+
+impl SharingConfig {
+    fn problems(&self, release: bool) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (key, url) in [
+            ("public_base_url", &self.public_base_url),
+            ("short_link_base_url", &self.short_link_base_url),
+        ] {
+            let scheme_ok = url.starts_with("https://") || (!release && url.starts_with("http://"));
+            if !scheme_ok || url.ends_with('/') {
+                problems.push(format!(
+                    "sharing.{} must be an {} origin without a trailing slash",
+                    key,
+                    if release { "https" } else { "http(s)" }
+                ));
+            }
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct ReceiptConfig {
+    /// Leading part of every receipt number, e.g. `KWT` in `KWT/2025/000042`.
+    pub prefix: String,
+    /// Month (1-12) the fiscal year starts in; a fiscal year is named after the
+    /// calendar year it starts in.
+    pub fiscal_year_start_month: u32,
+}
+
+impl Default for ReceiptConfig {
+    fn default() -> Self {
+        ReceiptConfig {
+            prefix: "KWT".to_string(),
+            fiscal_year_start_month: 1,
+        }
+    }
+}
+
+impl ReceiptConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.prefix.is_empty()
+            || !self
+                .prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            problems.push("receipts.prefix must be letters, digits or '-'".to_string());
+        }
+        if !(1..=12).contains(&self.fiscal_year_start_month) {
+            problems.push("receipts.fiscal_year_start_month must be between 1 and 12".to_string());
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct PayoutPolicyConfig {
+    /// Payouts below this amount are approved as soon as they are requested.
+    /// `0` sends every payout to review.
+    pub auto_approve_below: f64,
+    /// Payouts of at least this amount need two different admins to approve them;
+    /// anything in between needs one.
+    pub dual_approval_from: f64,
+}
+
+impl Default for PayoutPolicyConfig {
+    fn default() -> Self {
+        PayoutPolicyConfig {
+            auto_approve_below: 0.0,
+            dual_approval_from: 50_000_000.0,
+        }
+    }
+}
+
+impl PayoutPolicyConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.auto_approve_below < 0.0 {
+            problems.push("payouts.auto_approve_below must not be negative".to_string());
+        }
+        if self.dual_approval_from < self.auto_approve_below {
+            problems.push(
+                "payouts.dual_approval_from must not be below payouts.auto_approve_below"
+                    .to_string(),
+            );
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct ContentThrottleConfig {
+    /// Public messages (donation messages) one user may post per rolling hour.
+    pub max_messages_per_hour: usize,
+    /// A user posting the same text again within this many minutes is refused.
+    pub duplicate_window_minutes: u64,
+}
+
+impl Default for ContentThrottleConfig {
+    fn default() -> Self {
+        ContentThrottleConfig {
+            max_messages_per_hour: 20,
+            duplicate_window_minutes: 60,
+        }
+    }
+}
+
+impl ContentThrottleConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.max_messages_per_hour == 0 {
+            problems.push("content_throttle.max_messages_per_hour must be at least 1".to_string());
+        }
+        if self.duplicate_window_minutes == 0 {
+            problems
+                .push("content_throttle.duplicate_window_minutes must be at least 1".to_string());
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct WidgetConfig {
+    /// Widget requests one client address may make per rolling minute.
+    pub requests_per_minute: usize,
+    /// How long widget data is served from memory and may be cached downstream.
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for WidgetConfig {
+    fn default() -> Self {
+        WidgetConfig {
+            requests_per_minute: 120,
+            cache_ttl_secs: 300,
+        }
+    }
+}
+
+impl WidgetConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.requests_per_minute == 0 {
+            problems.push("widgets.requests_per_minute must be at least 1".to_string());
+        }
+        if self.cache_ttl_secs == 0 {
+            problems.push("widgets.cache_ttl_secs must be at least 1".to_string());
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct NotificationRetryConfig {
+    /// Attempts, the original delivery included, before a failing subscriber
+    /// delivery is dead-lettered.
+    pub max_attempts: i32,
+    /// Delay before the first retry; doubled after every further failure.
+    pub base_delay_secs: u64,
+    pub max_delay_secs: u64,
+}
+
+impl Default for NotificationRetryConfig {
+    fn default() -> Self {
+        NotificationRetryConfig {
+            max_attempts: 8,
+            base_delay_secs: 30,
+            max_delay_secs: 6 * 60 * 60,
+        }
+    }
+}
+
+impl NotificationRetryConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.max_attempts < 2 {
+            problems.push("notification_retries.max_attempts must be at least 2".to_string());
+        }
+        if self.base_delay_secs == 0 {
+            problems.push("notification_retries.base_delay_secs must be at least 1".to_string());
+        }
+        if self.max_delay_secs < self.base_delay_secs {
+            problems.push(
+                "notification_retries.max_delay_secs must not be below notification_retries.base_delay_secs"
+                    .to_string(),
+            );
+        }
+        problems
+    }
+}
+
+/// Bucket for KYC documents, campaign evidence and donation exports. Without a
+/// `base_url` the routes that upload files are not mounted.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct StorageConfig {
+    pub base_url: Option<String>,
+    pub token: Option<String>,
+}
+
+impl StorageConfig {
+    fn problems(&self, release: bool) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(url) = self.base_url.as_deref() {
+            let scheme_ok = url.starts_with("https://") || (!release && url.starts_with("http://"));
+            if !scheme_ok || url.ends_with('/') {
+                problems.push(format!(
+                    "storage.base_url must be an {} URL without a trailing slash",
+                    if release { "https" } else { "http(s)" }
+                ));
+            }
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct AlertsConfig {
+    /// Slack or Discord incoming webhook; ops alerts are off without one.
+    pub webhook_url: Option<String>,
+    pub format: AlertWebhookFormat,
+    /// Payout requests at or above this amount raise an alert.
+    pub payout_threshold: f64,
+    pub batch_interval_secs: u64,
+    pub max_alerts_per_batch: usize,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        AlertsConfig {
+            webhook_url: None,
+            format: AlertWebhookFormat::Slack,
+            payout_threshold: 50_000_000.0,
+            batch_interval_secs: 60,
+            max_alerts_per_batch: 20,
+        }
+    }
+}
+
+impl AlertsConfig {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(url) = self.webhook_url.as_deref()
+            && !url.starts_with("https://")
+        {
+            problems.push("alerts.webhook_url must use https".to_string());
+        }
+        if self.batch_interval_secs == 0 {
+            problems.push("alerts.batch_interval_secs must be at least 1".to_string());
+        }
+        if self.max_alerts_per_batch == 0 {
+            problems.push("alerts.max_alerts_per_batch must be at least 1".to_string());
+        }
+        problems
+    }
+}
+
+/// Application settings, read once at startup and managed as state.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct AppConfig {
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub cors: CorsConfig,
+    pub payment_providers: Vec<PaymentProviderConfig>,
+    // Rocket already owns the top-level `limits` table for request body sizes.
+    #[serde(rename = "app_limits")]
+    pub limits: LimitsConfig,
+    pub features: FeatureToggles,
+    pub compression: CompressionConfig,
+    pub error_reporting: ErrorReportingConfig,
+    pub security_headers: SecurityHeadersConfig,
+    pub sharing: SharingConfig,
+    pub receipts: ReceiptConfig,
+    pub payouts: PayoutPolicyConfig,
+    pub content_throttle: ContentThrottleConfig,
+    pub widgets: WidgetConfig,
+    pub notification_retries: NotificationRetryConfig,
+    pub storage: StorageConfig,
+    pub alerts: AlertsConfig,
+    pub logging: LoggingConfig,
+    #[serde(skip)]
+    pub release: bool,
+}
+
+impl AppConfig {
+    // Reads Rocket.toml / ROCKET_* as usual, plus the conventional DATABASE_URL,
+    // JWT_SECRET and LOG_FORMAT.
+    pub fn from_figment(figment: &Figment) -> Result<Self, ConfigError> {
+        let mut config: AppConfig = figment
+            .clone()
+            .merge(
+                Env::raw()
+                    .only(&["database_url", "jwt_secret", "log_format"])
+                    .map(env_key),
+            )
+            .extract()
+            .map_err(|e| ConfigError::Extract(Box::new(e)))?;
+        config.release = figment.profile() == Config::RELEASE_PROFILE;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        match self.database.url.as_deref() {
+            Some(url) if !url.starts_with("postgres://") && !url.starts_with("postgresql://") => {
+                problems.push("database.url must be a postgres:// URL".to_string())
+            }
+            None if self.release => {
+                problems.push("database.url (or DATABASE_URL) is required in release".to_string())
+            }
+            _ => {}
+        }
+        if self.database.max_connections == 0 {
+            problems.push("database.max_connections must be at least 1".to_string());
+        }
+        if self.database.slow_query_threshold_ms == 0 {
+            problems.push("database.slow_query_threshold_ms must be at least 1".to_string());
+        }
+        if self.release && self.database.explain_slow_queries {
+            problems.push("database.explain_slow_queries must be off in release".to_string());
+        }
+        match self.auth.jwt_secret.as_deref() {
+            Some(secret) if secret.len() < 32 => {
+                problems.push("auth.jwt_secret must be at least 32 bytes".to_string())
+            }
+            None if self.release => {
+                problems.push("auth.jwt_secret (or JWT_SECRET) is required in release".to_string())
+            }
+            _ => {}
+        }
+        if let Some(url) = self.auth.accounts_url.as_deref() {
+            let scheme_ok =
+                url.starts_with("https://") || (!self.release && url.starts_with("http://"));
+            if !scheme_ok {
+                problems.push("auth.accounts_url must use https".to_string());
+            }
+            if self
+                .auth
+                .service_token
+                .as_deref()
+                .is_none_or(|token| token.trim().is_empty())
+            {
+                problems.push("auth.service_token is required with auth.accounts_url".to_string());
+            }
+        }
+        match self.auth.two_factor_key.as_deref() {
+            Some(_) if self.auth.two_factor_key_bytes().is_none() => {
+                problems.push("auth.two_factor_key must be 64 hex characters".to_string())
+            }
+            None if self.release => {
+                problems.push("auth.two_factor_key is required in release".to_string())
+            }
+            _ => {}
+        }
+
+        problems.extend(self.cors.problems(self.release));
+        problems.extend(self.sharing.problems(self.release));
+        problems.extend(self.receipts.problems());
+        problems.extend(self.payouts.problems());
+        problems.extend(self.content_throttle.problems());
+        problems.extend(self.widgets.problems());
+        problems.extend(self.notification_retries.problems());
+        problems.extend(self.storage.problems(self.release));
+        problems.extend(self.alerts.problems());
+        problems.extend(self.logging.problems());
+
+        for (index, provider) in self.payment_providers.iter().enumerate() {
+            if provider.name.trim().is_empty() {
+                problems.push(format!("payment_providers[{}].name is required", index));
+            }
+            if !provider.api_base_url.starts_with("https://") {
+                problems.push(format!(
+                    "payment_providers[{}].api_base_url must use https",
+                    index
+                ));
+            }
+            if provider.enabled
+                && provider
+                    .secret_key
+                    .as_deref()
+                    .is_none_or(|key| key.trim().is_empty())
+            {
+                problems.push(format!(
+                    "payment_providers[{}].secret_key is required when the provider is enabled",
+                    index
+                ));
+            }
+            if !provider.settlement_report_path.starts_with('/') {
+                problems.push(format!(
+                    "payment_providers[{}].settlement_report_path must start with '/'",
+                    index
+                ));
+            }
+        }
+
+        if self.limits.review_threshold <= 0.0 {
+            problems.push("app_limits.review_threshold must be positive".to_string());
+        }
+        if self.limits.dispute_window_days <= 0 {
+            problems.push("app_limits.dispute_window_days must be positive".to_string());
+        }
+        if self.limits.max_page_size <= 0 {
+            problems.push("app_limits.max_page_size must be positive".to_string());
+        }
+        if self.limits.balance_adjustment_cap < 0.0 {
+            problems.push("app_limits.balance_adjustment_cap must not be negative".to_string());
+        }
+
+        if !(0.0..=1.0).contains(&self.error_reporting.sample_rate) {
+            problems.push("error_reporting.sample_rate must be between 0 and 1".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+// Env keys keep the variable's own case, so compare case-insensitively.
+fn env_key(key: &UncasedStr) -> Uncased<'_> {
+    if key == "log_format" {
+        "logging.format".into()
+    } else if key == "jwt_secret" {
+        "auth.jwt_secret".into()
+    } else {
+        "database.url".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::LogFormat;
+    use rocket::figment::providers::{Format, Toml};
+
+    fn figment(toml: &str) -> Figment {
+        Figment::from(Config::default()).merge(Toml::string(toml))
+    }
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let config = AppConfig::from_figment(&figment("")).unwrap();
+
+        assert_eq!(config.database.max_connections, 10);
+        assert_eq!(config.limits.dispute_window_days, 30);
+        assert!(config.features.disputes);
+        assert_eq!(config.compression.min_size, 1024);
+    }
+
+    #[test]
+    fn test_reads_typed_sections() {
+        let config = AppConfig::from_figment(&figment(
+            r#"
+            [database]
+            url = "postgres://localhost/donations"
+
+            [cors]
+            allowed_origins = ["https://app.example.com"]
+
+            [[payment_providers]]
+            name = "midtrans"
+            api_base_url = "https://api.midtrans.com"
+            secret_key = "sk_test"
+
+            [app_limits]
+            review_threshold = 5000000.0
+
+            [features]
+            donation_import = false
+
+            [payouts]
+            auto_approve_below = 500000.0
+
+            [alerts]
+            webhook_url = "https://discord.com/api/webhooks/1/abc"
+            format = "discord"
+
+            [logging]
+            format = "json"
+            "#,
+        ))
+        .unwrap();
+
+        assert_eq!(
+            config.database.url.as_deref(),
+            Some("postgres://localhost/donations")
+        );
+        assert_eq!(config.cors.allowed_origins, vec!["https://app.example.com"]);
+        assert_eq!(config.payment_providers[0].name, "midtrans");
+        assert_eq!(config.limits.review_threshold, 5_000_000.0);
+        assert!(!config.features.donation_import);
+        assert!(config.features.public_feeds);
+        assert_eq!(config.payouts.auto_approve_below, 500_000.0);
+        assert_eq!(config.payouts.dual_approval_from, 50_000_000.0);
+        assert_eq!(config.alerts.format, AlertWebhookFormat::Discord);
+        assert!(config.storage.base_url.is_none());
+        assert_eq!(config.logging.format, LogFormat::Json);
+        assert_eq!(config.logging.level, "info");
+    }
+
+    #[test]
+    fn test_maps_conventional_env_vars() {
+        assert_eq!(env_key(UncasedStr::new("LOG_FORMAT")), "logging.format");
+        assert_eq!(env_key(UncasedStr::new("JWT_SECRET")), "auth.jwt_secret");
+        assert_eq!(env_key(UncasedStr::new("DATABASE_URL")), "database.url");
+    }
+
+    #[test]
+    fn test_reports_every_invalid_field() {
+        let error = AppConfig::from_figment(&figment(
+            r#"
+            [database]
+            slow_query_threshold_ms = 0
+
+            [auth]
+            jwt_secret = "short"
+            accounts_url = "auth.example.com/accounts"
+            two_factor_key = "not-hex"
+
+            [cors]
+            allowed_origins = ["*", "https://app.example.com/"]
+
+            [[payment_providers]]
+            name = "midtrans"
+            api_base_url = "http://api.midtrans.com"
+
+            [app_limits]
+            dispute_window_days = 0
+
+            [sharing]
+            public_base_url = "https://donasi.example.com/"
+
+            [receipts]
+            prefix = "KWT/"
+            fiscal_year_start_month = 13
+
+            [payouts]
+            auto_approve_below = 1000000.0
+            dual_approval_from = 500000.0
+
+            [content_throttle]
+            max_messages_per_hour = 0
+
+            [widgets]
+            cache_ttl_secs = 0
+
+            [notification_retries]
+            base_delay_secs = 600
+            max_delay_secs = 60
+
+            [storage]
+            base_url = "s3.example.com"
+
+            [alerts]
+            webhook_url = "http://hooks.slack.com/services/x"
+
+            [logging]
+            level = "info,sqlx=loud"
+            "#,
+        ))
+        .unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("database.slow_query_threshold_ms must be at least 1"));
+        assert!(message.contains("auth.jwt_secret must be at least 32 bytes"));
+        assert!(message.contains("auth.accounts_url must use https"));
+        assert!(message.contains("auth.service_token is required with auth.accounts_url"));
+        assert!(message.contains("auth.two_factor_key must be 64 hex characters"));
+        assert!(message.contains("'*' must be a scheme and host"));
+        assert!(message.contains("'https://app.example.com/' must be a scheme and host"));
+        assert!(message.contains("payment_providers[0].api_base_url must use https"));
+        assert!(message.contains("payment_providers[0].secret_key is required"));
+        assert!(message.contains("app_limits.dispute_window_days must be positive"));
+        assert!(message.contains("sharing.public_base_url must be an http(s) origin"));
+        assert!(message.contains("receipts.prefix must be letters, digits or '-'"));
+        assert!(message.contains("receipts.fiscal_year_start_month must be between 1 and 12"));
+        assert!(message.contains("payouts.dual_approval_from must not be below"));
+        assert!(message.contains("content_throttle.max_messages_per_hour must be at least 1"));
+        assert!(message.contains("widgets.cache_ttl_secs must be at least 1"));
+        assert!(message.contains("notification_retries.max_delay_secs must not be below"));
+        assert!(message.contains("storage.base_url must be an http(s) URL"));
+        assert!(message.contains("alerts.webhook_url must use https"));
+        assert!(message.contains("logging.level is not a valid filter"));
+    }
+}
@@ -0,0 +1,114 @@
+use rocket::{State, delete, get, post, routes, Responder};
+use rocket::serde::json::Json;
+use crate::service::admin_action_service::AdminActionService;
+use crate::model::admin_action::{AdminActionStatus, BalanceAdjustmentRequest, DeclineAdminActionRequest, PendingAdminAction};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AdminUser;
+
+
+// 202 while the action waits for a second admin, 204 when it ran immediately.
+#[derive(Responder)]
+enum Gated {
+    #[response(status = 202)]
+    Queued(Json<PendingAdminAction>),
+    #[response(status = 204)]
+    Done(()),
+}
+
+impl From<Option<PendingAdminAction>> for Gated {
+    fn from(action: Option<PendingAdminAction>) -> Self {
+        match action {
+            Some(action) => Gated::Queued(Json(action)),
+            None => Gated::Done(()),
+        }
+    }
+}
+
+
+#[delete("/admin/campaigns/<campaign_id>")]
+async fn delete_campaign_route(
+    admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    campaign_id: i32,
+) -> Result<Gated, AppError> {
+    let action = action_service.delete_campaign(campaign_id, admin.id).await?;
+    Ok(action.into())
+}
+
+
+#[post("/admin/wallets/<user_id>/adjustments", format = "json", data = "<adjustment_req>")]
+async fn adjust_balance_route(
+    admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    user_id: i32,
+    adjustment_req: Json<BalanceAdjustmentRequest>,
+) -> Result<Gated, AppError> {
+    validate(&*adjustment_req)?;
+    let req = adjustment_req.into_inner();
+    let action = action_service
+        .adjust_balance(user_id, req.amount, req.reason, admin.id)
+        .await?;
+    Ok(action.into())
+}
+
+
+// Always queued; the refunds run when a second admin approves.
+#[post("/admin/campaigns/<campaign_id>/refund-donations")]
+async fn refund_campaign_donations_route(
+    admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    campaign_id: i32,
+) -> Result<Gated, AppError> {
+    let action = action_service.refund_all_donations(campaign_id, admin.id).await?;
+    Ok(Gated::Queued(Json(action)))
+}
+
+
+#[get("/admin/actions?<status>")]
+async fn get_admin_actions_route(
+    _admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    status: Option<AdminActionStatus>,
+) -> Result<Json<Vec<PendingAdminAction>>, AppError> {
+    let status = status.unwrap_or(AdminActionStatus::Pending);
+    let actions = action_service.get_actions(status).await?;
+    Ok(Json(actions))
+}
+
+
+#[post("/admin/actions/<action_id>/approve")]
+async fn approve_admin_action_route(
+    admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    action_id: i32,
+) -> Result<Json<PendingAdminAction>, AppError> {
+    let action = action_service.approve(action_id, admin.id).await?;
+    Ok(Json(action))
+}
+
+
+#[post("/admin/actions/<action_id>/decline", format = "json", data = "<decline_req>")]
+async fn decline_admin_action_route(
+    admin: AdminUser,
+    action_service: &State<AdminActionService>,
+    action_id: i32,
+    decline_req: Json<DeclineAdminActionRequest>,
+) -> Result<Json<PendingAdminAction>, AppError> {
+    let action = action_service
+        .decline(action_id, admin.id, decline_req.into_inner().note)
+        .await?;
+    Ok(Json(action))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        delete_campaign_route,
+        adjust_balance_route,
+        refund_campaign_donations_route,
+        get_admin_actions_route,
+        approve_admin_action_route,
+        decline_admin_action_route
+    ]
+}
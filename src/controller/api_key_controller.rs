@@ -0,0 +1,45 @@
+use rocket::{State, post, delete, get, routes};
+use rocket::serde::json::Json;
+use crate::service::api_key_service::ApiKeyService;
+use crate::model::api_key::{ApiKeyRecord, CreateApiKeyRequest, MintedApiKey};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AdminUser;
+
+
+#[post("/admin/api-keys", format = "json", data = "<key_req>")]
+async fn mint_api_key_route(
+    admin: AdminUser,
+    api_key_service: &State<ApiKeyService>,
+    key_req: Json<CreateApiKeyRequest>,
+) -> Result<Json<MintedApiKey>, AppError> {
+    validate(&*key_req)?;
+    let minted = api_key_service.mint(admin.id, key_req.into_inner()).await?;
+    Ok(Json(minted))
+}
+
+
+#[get("/admin/api-keys")]
+async fn list_api_keys_route(
+    _admin: AdminUser,
+    api_key_service: &State<ApiKeyService>,
+) -> Result<Json<Vec<ApiKeyRecord>>, AppError> {
+    let keys = api_key_service.list().await?;
+    Ok(Json(keys))
+}
+
+
+#[delete("/admin/api-keys/<key_id>")]
+async fn revoke_api_key_route(
+    _admin: AdminUser,
+    api_key_service: &State<ApiKeyService>,
+    key_id: i32,
+) -> Result<Json<ApiKeyRecord>, AppError> {
+    let key = api_key_service.revoke(key_id).await?;
+    Ok(Json(key))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![mint_api_key_route, list_api_keys_route, revoke_api_key_route]
+}
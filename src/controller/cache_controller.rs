@@ -0,0 +1,46 @@
+use rocket::{State, post, get, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::cache_service::CacheService;
+use crate::service::metrics_service::MetricsService;
+use crate::model::cache::{CacheScope, CacheStats};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+#[get("/admin/cache/stats")]
+fn get_cache_stats_route(
+    _admin: AdminUser,
+    cache_service: &State<CacheService>,
+) -> Json<CacheStats> {
+    Json(cache_service.stats())
+}
+
+
+#[post("/admin/cache/flush?<scope>")]
+fn flush_cache_route(
+    _admin: AdminUser,
+    cache_service: &State<CacheService>,
+    scope: Option<CacheScope>,
+) -> Json<CacheStats> {
+    Json(cache_service.flush(scope))
+}
+
+
+#[get("/metrics")]
+async fn metrics_route(metrics_service: &State<Arc<MetricsService>>) -> Result<String, AppError> {
+    metrics_service.render_prometheus().await
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_cache_stats_route,
+        flush_cache_route
+    ]
+}
+
+// Mounted at the root rather than under /api so Prometheus can scrape it.
+pub fn metrics_routes() -> Vec<rocket::Route> {
+    routes![metrics_route]
+}
@@ -0,0 +1,78 @@
+use rocket::{State, get, post, put, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_budget_service::CampaignBudgetService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::campaign_budget::{CampaignBudget, DeclareBudgetRequest, NewSpendReportRequest, RequireSpendReportsRequest, SpendReport};
+use crate::model::campaign_member::CampaignAction;
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::{AdminUser, AuthUser};
+
+
+// Public transparency tab: the declared breakdown and what has been spent against it.
+#[get("/campaigns/<campaign_id>/budget")]
+async fn get_budget_route(
+    budget_service: &State<CampaignBudgetService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignBudget>, AppError> {
+    let budget = budget_service.get_budget(campaign_id).await?;
+    Ok(Json(budget))
+}
+
+
+#[put("/campaigns/<campaign_id>/budget", format = "json", data = "<budget_req>")]
+async fn declare_budget_route(
+    auth_user: AuthUser,
+    budget_service: &State<CampaignBudgetService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    budget_req: Json<DeclareBudgetRequest>,
+) -> Result<Json<CampaignBudget>, AppError> {
+    validate(&*budget_req)?;
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::UpdateCampaign)
+        .await?;
+    let budget = budget_service
+        .declare_budget(campaign_id, budget_req.into_inner())
+        .await?;
+    Ok(Json(budget))
+}
+
+
+#[post("/campaigns/<campaign_id>/budget/reports", format = "json", data = "<report_req>")]
+async fn add_spend_report_route(
+    auth_user: AuthUser,
+    budget_service: &State<CampaignBudgetService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    report_req: Json<NewSpendReportRequest>,
+) -> Result<Json<SpendReport>, AppError> {
+    validate(&*report_req)?;
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::UpdateCampaign)
+        .await?;
+    let report = budget_service
+        .add_spend_report(campaign_id, auth_user.id, report_req.into_inner())
+        .await?;
+    Ok(Json(report))
+}
+
+
+#[put("/admin/campaigns/<campaign_id>/budget/reports-required", format = "json", data = "<required_req>")]
+async fn set_reports_required_route(
+    _admin: AdminUser,
+    budget_service: &State<CampaignBudgetService>,
+    campaign_id: i32,
+    required_req: Json<RequireSpendReportsRequest>,
+) -> Result<Json<CampaignBudget>, AppError> {
+    let budget = budget_service
+        .set_reports_required(campaign_id, required_req.required)
+        .await?;
+    Ok(Json(budget))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_budget_route, declare_budget_route, add_spend_report_route, set_reports_required_route]
+}
@@ -0,0 +1,55 @@
+use rocket::{State, get, routes, Responder};
+use rocket::http::{ContentType, Header};
+use rocket::serde::json::Json;
+use crate::service::campaign_feed_service::CampaignFeedService;
+use crate::model::campaign_feed::{CampaignFeedItem, CampaignFeedPage};
+use crate::errors::AppError;
+
+
+#[derive(Responder)]
+struct Cached<T> {
+    inner: T,
+    cache_control: Header<'static>,
+}
+
+fn cached<T>(feed_service: &CampaignFeedService, inner: T) -> Cached<T> {
+    let max_age = feed_service.cache_ttl().as_secs();
+    Cached {
+        inner,
+        cache_control: Header::new("Cache-Control", format!("public, max-age={}", max_age)),
+    }
+}
+
+
+#[get("/sitemap.xml")]
+async fn sitemap_route(
+    feed_service: &State<CampaignFeedService>,
+) -> Result<Cached<(ContentType, String)>, AppError> {
+    let sitemap = feed_service.get_sitemap().await?;
+    Ok(cached(feed_service, (ContentType::XML, sitemap)))
+}
+
+
+#[get("/feeds/campaigns.json?<page>&<region>")]
+async fn campaign_feed_route(
+    feed_service: &State<CampaignFeedService>,
+    page: Option<i64>,
+    region: Option<&str>,
+) -> Result<Cached<Json<CampaignFeedPage>>, AppError> {
+    let feed_page = feed_service.get_feed_page(page, region).await?;
+    Ok(cached(feed_service, Json(feed_page)))
+}
+
+
+#[get("/campaigns/almost-funded")]
+async fn almost_funded_route(
+    feed_service: &State<CampaignFeedService>,
+) -> Result<Cached<Json<Vec<CampaignFeedItem>>>, AppError> {
+    let items = feed_service.get_almost_funded().await?;
+    Ok(cached(feed_service, Json(items)))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![sitemap_route, campaign_feed_route, almost_funded_route]
+}
@@ -0,0 +1,64 @@
+use rocket::{State, get, post, put, delete, routes};
+use rocket::serde::json::Json;
+use crate::service::campaign_image_service::CampaignImageService;
+use crate::model::campaign_image::{AddCampaignImageRequest, CampaignImage, ReorderCampaignImagesRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/campaigns/<campaign_id>/images")]
+async fn get_images_route(
+    image_service: &State<CampaignImageService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<CampaignImage>>, AppError> {
+    let images = image_service.get_images(campaign_id).await?;
+    Ok(Json(images))
+}
+
+
+#[post("/campaigns/<campaign_id>/images", format = "json", data = "<image_req>")]
+async fn add_image_route(
+    auth_user: AuthUser,
+    image_service: &State<CampaignImageService>,
+    campaign_id: i32,
+    image_req: Json<AddCampaignImageRequest>,
+) -> Result<Json<CampaignImage>, AppError> {
+    validate(&*image_req)?;
+    let image = image_service
+        .add_image(campaign_id, auth_user.id, image_req.into_inner())
+        .await?;
+    Ok(Json(image))
+}
+
+
+#[put("/campaigns/<campaign_id>/images/order", format = "json", data = "<order_req>")]
+async fn reorder_images_route(
+    auth_user: AuthUser,
+    image_service: &State<CampaignImageService>,
+    campaign_id: i32,
+    order_req: Json<ReorderCampaignImagesRequest>,
+) -> Result<Json<Vec<CampaignImage>>, AppError> {
+    validate(&*order_req)?;
+    let images = image_service
+        .reorder_images(campaign_id, auth_user.id, order_req.into_inner())
+        .await?;
+    Ok(Json(images))
+}
+
+
+#[delete("/campaigns/<campaign_id>/images/<image_id>")]
+async fn remove_image_route(
+    auth_user: AuthUser,
+    image_service: &State<CampaignImageService>,
+    campaign_id: i32,
+    image_id: i32,
+) -> Result<(), AppError> {
+    image_service.remove_image(campaign_id, auth_user.id, image_id).await?;
+    Ok(())
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_images_route, add_image_route, reorder_images_route, remove_image_route]
+}
@@ -0,0 +1,57 @@
+use rocket::{State, get, put, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_location_service::CampaignLocationService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::campaign_location::{CampaignLocation, NearbyCampaign, UpdateCampaignLocationRequest};
+use crate::model::campaign_member::CampaignAction;
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+// `radius_km` defaults to 25 and is capped at 500.
+#[get("/campaigns/nearby?<lat>&<lng>&<radius_km>")]
+async fn nearby_campaigns_route(
+    location_service: &State<CampaignLocationService>,
+    lat: f64,
+    lng: f64,
+    radius_km: Option<f64>,
+) -> Result<Json<Vec<NearbyCampaign>>, AppError> {
+    let campaigns = location_service.find_nearby(lat, lng, radius_km).await?;
+    Ok(Json(campaigns))
+}
+
+
+#[get("/campaigns/<campaign_id>/location")]
+async fn get_location_route(
+    location_service: &State<CampaignLocationService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignLocation>, AppError> {
+    let location = location_service.get_location(campaign_id).await?;
+    Ok(Json(location))
+}
+
+
+#[put("/campaigns/<campaign_id>/location", format = "json", data = "<location_req>")]
+async fn update_location_route(
+    auth_user: AuthUser,
+    location_service: &State<CampaignLocationService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    location_req: Json<UpdateCampaignLocationRequest>,
+) -> Result<Json<CampaignLocation>, AppError> {
+    validate(&*location_req)?;
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::UpdateCampaign)
+        .await?;
+    let location = location_service
+        .update_location(campaign_id, location_req.into_inner())
+        .await?;
+    Ok(Json(location))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![nearby_campaigns_route, get_location_route, update_location_route]
+}
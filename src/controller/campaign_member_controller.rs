@@ -0,0 +1,51 @@
+use rocket::{State, put, delete, get, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::campaign_member::{AddCampaignMemberRequest, CampaignMember};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/campaigns/<campaign_id>/members")]
+async fn get_members_route(
+    auth_user: AuthUser,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+) -> Result<Json<Vec<CampaignMember>>, AppError> {
+    let members = member_service.get_members(campaign_id, auth_user.id).await?;
+    Ok(Json(members))
+}
+
+
+#[put("/campaigns/<campaign_id>/members", format = "json", data = "<member_req>")]
+async fn add_member_route(
+    auth_user: AuthUser,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    member_req: Json<AddCampaignMemberRequest>,
+) -> Result<Json<CampaignMember>, AppError> {
+    validate(&*member_req)?;
+    let member = member_service
+        .add_member(campaign_id, auth_user.id, member_req.into_inner())
+        .await?;
+    Ok(Json(member))
+}
+
+
+#[delete("/campaigns/<campaign_id>/members/<user_id>")]
+async fn remove_member_route(
+    auth_user: AuthUser,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    user_id: i32,
+) -> Result<(), AppError> {
+    member_service.remove_member(campaign_id, auth_user.id, user_id).await?;
+    Ok(())
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_members_route, add_member_route, remove_member_route]
+}
@@ -0,0 +1,36 @@
+use rocket::{State, get, put, routes};
+use rocket::serde::json::Json;
+use crate::service::campaign_overflow_service::CampaignOverflowService;
+use crate::model::campaign_overflow::{CampaignOverflowSettings, UpdateOverflowPolicyRequest};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+#[get("/admin/campaigns/<campaign_id>/overflow-policy")]
+async fn get_overflow_policy_route(
+    _admin: AdminUser,
+    overflow_service: &State<CampaignOverflowService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignOverflowSettings>, AppError> {
+    let settings = overflow_service.get_settings(campaign_id).await?;
+    Ok(Json(settings))
+}
+
+
+#[put("/admin/campaigns/<campaign_id>/overflow-policy", format = "json", data = "<policy_req>")]
+async fn update_overflow_policy_route(
+    _admin: AdminUser,
+    overflow_service: &State<CampaignOverflowService>,
+    campaign_id: i32,
+    policy_req: Json<UpdateOverflowPolicyRequest>,
+) -> Result<Json<CampaignOverflowSettings>, AppError> {
+    let settings = overflow_service
+        .update_policy(campaign_id, policy_req.into_inner())
+        .await?;
+    Ok(Json(settings))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_overflow_policy_route, update_overflow_policy_route]
+}
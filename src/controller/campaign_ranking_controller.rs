@@ -0,0 +1,49 @@
+use rocket::{State, get, routes, Responder};
+use rocket::http::Header;
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_ranking_service::CampaignRankingService;
+use crate::model::campaign_ranking::{RecommendedCampaign, TrendingCampaign};
+use crate::errors::AppError;
+use crate::auth::AuthUser;
+
+
+#[derive(Responder)]
+struct Cached<T> {
+    inner: T,
+    cache_control: Header<'static>,
+}
+
+
+#[get("/campaigns/trending")]
+async fn trending_campaigns_route(
+    ranking_service: &State<Arc<CampaignRankingService>>,
+) -> Result<Cached<Json<Vec<TrendingCampaign>>>, AppError> {
+    let campaigns = ranking_service.get_trending().await?;
+    let max_age = ranking_service.cache_ttl().as_secs();
+    Ok(Cached {
+        inner: Json(campaigns),
+        cache_control: Header::new("Cache-Control", format!("public, max-age={}", max_age)),
+    })
+}
+
+
+// Per user, so only the browser may cache it.
+#[get("/me/recommended")]
+async fn recommended_campaigns_route(
+    auth_user: AuthUser,
+    ranking_service: &State<Arc<CampaignRankingService>>,
+) -> Result<Cached<Json<Vec<RecommendedCampaign>>>, AppError> {
+    let campaigns = ranking_service.get_recommended(auth_user.id).await?;
+    let max_age = ranking_service.cache_ttl().as_secs();
+    Ok(Cached {
+        inner: Json(campaigns),
+        cache_control: Header::new("Cache-Control", format!("private, max-age={}", max_age)),
+    })
+}
+
+
+// The service is managed as an Arc so `spawn` can run the scheduled refresh.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![trending_campaigns_route, recommended_campaigns_route]
+}
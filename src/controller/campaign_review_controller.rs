@@ -0,0 +1,111 @@
+use rocket::{State, get, post, put, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_review_service::CampaignReviewService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::campaign_review::{BulkReviewRequest, BulkReviewResult, CampaignReview, CampaignReviewItem, SimilarCampaign, UpdateChecklistItemRequest};
+use crate::model::campaign_member::CampaignAction;
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::{AdminUser, AuthUser};
+
+
+// Lets the campaign team see which checks failed and the reviewer's comments.
+#[get("/campaigns/<campaign_id>/review")]
+async fn get_campaign_review_route(
+    auth_user: AuthUser,
+    review_service: &State<CampaignReviewService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+) -> Result<Json<CampaignReview>, AppError> {
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::View)
+        .await?;
+    let review = review_service.get_review(campaign_id).await?;
+    Ok(Json(review))
+}
+
+
+#[get("/admin/campaigns/<campaign_id>/review")]
+async fn admin_get_campaign_review_route(
+    _admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignReview>, AppError> {
+    let review = review_service.get_review(campaign_id).await?;
+    Ok(Json(review))
+}
+
+
+#[put("/admin/campaigns/<campaign_id>/review/items", format = "json", data = "<item_req>")]
+async fn record_review_item_route(
+    admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    campaign_id: i32,
+    item_req: Json<UpdateChecklistItemRequest>,
+) -> Result<Json<CampaignReviewItem>, AppError> {
+    validate(&*item_req)?;
+    let item = review_service
+        .record_item(campaign_id, admin.id, item_req.into_inner())
+        .await?;
+    Ok(Json(item))
+}
+
+
+#[post("/admin/campaigns/<campaign_id>/approve")]
+async fn approve_campaign_route(
+    _admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignReview>, AppError> {
+    let review = review_service.approve(campaign_id).await?;
+    Ok(Json(review))
+}
+
+
+#[post("/admin/campaigns/<campaign_id>/reject")]
+async fn reject_campaign_route(
+    _admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    campaign_id: i32,
+) -> Result<Json<CampaignReview>, AppError> {
+    let review = review_service.reject(campaign_id).await?;
+    Ok(Json(review))
+}
+
+
+// Returns 200 with a result per campaign even when some of them failed.
+#[post("/admin/campaigns/bulk", format = "json", data = "<bulk_req>")]
+async fn bulk_review_campaigns_route(
+    _admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    bulk_req: Json<BulkReviewRequest>,
+) -> Result<Json<Vec<BulkReviewResult>>, AppError> {
+    validate(&*bulk_req)?;
+    let results = review_service.bulk_review(bulk_req.into_inner()).await?;
+    Ok(Json(results))
+}
+
+
+#[get("/admin/campaigns/<campaign_id>/similar")]
+async fn get_similar_campaigns_route(
+    _admin: AdminUser,
+    review_service: &State<CampaignReviewService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<SimilarCampaign>>, AppError> {
+    let similar = review_service.get_similar_campaigns(campaign_id).await?;
+    Ok(Json(similar))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_campaign_review_route,
+        admin_get_campaign_review_route,
+        record_review_item_route,
+        approve_campaign_route,
+        reject_campaign_route,
+        bulk_review_campaigns_route,
+        get_similar_campaigns_route
+    ]
+}
@@ -0,0 +1,61 @@
+use rocket::{State, get, post, routes, Responder};
+use rocket::http::Header;
+use rocket::serde::json::Json;
+use crate::service::campaign_share_service::{CampaignShareService, OPEN_GRAPH_MAX_AGE};
+use crate::model::campaign_share::{CampaignOpenGraph, RecordShareRequest, ShareCount};
+use crate::errors::AppError;
+use crate::locale::Locale;
+
+
+#[derive(Responder)]
+struct Cached<T> {
+    inner: T,
+    cache_control: Header<'static>,
+}
+
+
+// Fetched by link-preview crawlers, so no auth and a short public cache.
+#[get("/campaigns/<campaign_id>/og")]
+async fn open_graph_route(
+    share_service: &State<CampaignShareService>,
+    campaign_id: i32,
+    locale: Option<Locale>,
+) -> Result<Cached<Json<CampaignOpenGraph>>, AppError> {
+    let open_graph = share_service.get_open_graph(campaign_id, locale).await?;
+    Ok(Cached {
+        inner: Json(open_graph),
+        cache_control: Header::new(
+            "Cache-Control",
+            format!("public, max-age={}", OPEN_GRAPH_MAX_AGE.as_secs()),
+        ),
+    })
+}
+
+
+// Called by the share buttons; anonymous visitors share too, so this is unauthenticated.
+#[post("/campaigns/<campaign_id>/share", format = "json", data = "<share_req>")]
+async fn record_share_route(
+    share_service: &State<CampaignShareService>,
+    campaign_id: i32,
+    share_req: Json<RecordShareRequest>,
+) -> Result<Json<Vec<ShareCount>>, AppError> {
+    let counts = share_service
+        .record_share(campaign_id, share_req.platform)
+        .await?;
+    Ok(Json(counts))
+}
+
+
+#[get("/campaigns/<campaign_id>/shares")]
+async fn share_counts_route(
+    share_service: &State<CampaignShareService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<ShareCount>>, AppError> {
+    let counts = share_service.get_share_counts(campaign_id).await?;
+    Ok(Json(counts))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![open_graph_route, record_share_route, share_counts_route]
+}
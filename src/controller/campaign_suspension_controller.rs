@@ -0,0 +1,57 @@
+use rocket::{State, get, post, routes};
+use rocket::serde::json::Json;
+use crate::service::campaign_suspension_service::CampaignSuspensionService;
+use crate::model::campaign_suspension::{CampaignAuditEntry, ResolveSuspensionRequest, SuspendCampaignRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AdminUser;
+
+
+#[post("/admin/campaigns/<campaign_id>/suspension", format = "json", data = "<suspend_req>")]
+async fn suspend_campaign_route(
+    admin: AdminUser,
+    suspension_service: &State<CampaignSuspensionService>,
+    campaign_id: i32,
+    suspend_req: Json<SuspendCampaignRequest>,
+) -> Result<Json<CampaignAuditEntry>, AppError> {
+    validate(&*suspend_req)?;
+    let entry = suspension_service
+        .suspend(campaign_id, admin.id, suspend_req.into_inner())
+        .await?;
+    Ok(Json(entry))
+}
+
+
+// Reinstates the campaign or rejects it for good.
+#[post("/admin/campaigns/<campaign_id>/suspension/resolve", format = "json", data = "<resolve_req>")]
+async fn resolve_suspension_route(
+    admin: AdminUser,
+    suspension_service: &State<CampaignSuspensionService>,
+    campaign_id: i32,
+    resolve_req: Json<ResolveSuspensionRequest>,
+) -> Result<Json<CampaignAuditEntry>, AppError> {
+    let entry = suspension_service
+        .resolve(campaign_id, admin.id, resolve_req.into_inner())
+        .await?;
+    Ok(Json(entry))
+}
+
+
+#[get("/admin/campaigns/<campaign_id>/audit")]
+async fn get_campaign_audit_route(
+    _admin: AdminUser,
+    suspension_service: &State<CampaignSuspensionService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<CampaignAuditEntry>>, AppError> {
+    let entries = suspension_service.get_audit_log(campaign_id).await?;
+    Ok(Json(entries))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        suspend_campaign_route,
+        resolve_suspension_route,
+        get_campaign_audit_route
+    ]
+}
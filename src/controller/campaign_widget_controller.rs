@@ -0,0 +1,59 @@
+use std::net::IpAddr;
+use rocket::{State, get, routes, Responder};
+use rocket::http::Header;
+use rocket::response::content::RawHtml;
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::campaign_widget_service::{CampaignWidgetService, render_html};
+use crate::model::campaign_widget::{CampaignWidget, WidgetFormat};
+use crate::errors::AppError;
+
+
+#[derive(Responder)]
+enum WidgetBody {
+    Json(Json<CampaignWidget>),
+    Html(RawHtml<String>),
+}
+
+
+// Only this route is open to every origin and may be framed anywhere; the CORS
+// fairing leaves `/api/widgets/` to the route, and the security headers fairing
+// keeps route-provided policies. Browsers ignore X-Frame-Options when the CSP
+// sets frame-ancestors.
+#[derive(Responder)]
+struct Embeddable {
+    inner: WidgetBody,
+    cache_control: Header<'static>,
+    allow_origin: Header<'static>,
+    content_security_policy: Header<'static>,
+}
+
+
+#[get("/widgets/campaigns/<campaign_id>?<format>")]
+async fn campaign_widget_route(
+    widget_service: &State<Arc<CampaignWidgetService>>,
+    client_ip: Option<IpAddr>,
+    campaign_id: i32,
+    format: Option<WidgetFormat>,
+) -> Result<Embeddable, AppError> {
+    let widget = widget_service.get_widget(campaign_id, client_ip).await?;
+    let inner = match format.unwrap_or(WidgetFormat::Json) {
+        WidgetFormat::Json => WidgetBody::Json(Json(widget)),
+        WidgetFormat::Html => WidgetBody::Html(RawHtml(render_html(&widget))),
+    };
+    let max_age = widget_service.cache_ttl().as_secs();
+    Ok(Embeddable {
+        inner,
+        cache_control: Header::new("Cache-Control", format!("public, max-age={}", max_age)),
+        allow_origin: Header::new("Access-Control-Allow-Origin", "*"),
+        content_security_policy: Header::new(
+            "Content-Security-Policy",
+            "default-src 'none'; style-src 'unsafe-inline'; frame-ancestors *",
+        ),
+    })
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![campaign_widget_route]
+}
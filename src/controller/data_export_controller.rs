@@ -0,0 +1,46 @@
+use rocket::{State, post, get, routes};
+use rocket::serde::json::Json;
+use crate::service::data_export_service::DataExportService;
+use crate::model::data_export::{DataExport, UserDataExport};
+use crate::errors::AppError;
+use crate::auth::AuthUser;
+
+
+#[get("/me/export")]
+async fn export_my_data_route(
+    auth_user: AuthUser,
+    export_service: &State<DataExportService>,
+) -> Result<Json<UserDataExport>, AppError> {
+    let export = export_service.assemble_export(auth_user.id).await?;
+    Ok(Json(export))
+}
+
+
+#[post("/me/exports")]
+async fn request_export_route(
+    auth_user: AuthUser,
+    export_service: &State<DataExportService>,
+) -> Result<Json<DataExport>, AppError> {
+    let export = export_service.request_export(auth_user.id).await?;
+    Ok(Json(export))
+}
+
+
+#[get("/me/exports/<export_id>")]
+async fn get_export_route(
+    auth_user: AuthUser,
+    export_service: &State<DataExportService>,
+    export_id: i32,
+) -> Result<Json<DataExport>, AppError> {
+    let export = export_service.get_export(export_id, auth_user.id).await?;
+    Ok(Json(export))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        export_my_data_route,
+        request_export_route,
+        get_export_route
+    ]
+}
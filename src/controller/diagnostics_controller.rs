@@ -0,0 +1,32 @@
+use rocket::{State, get, delete, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::repository::query_monitor::QueryMonitor;
+use crate::model::query_diagnostics::QueryDiagnostics;
+use crate::auth::AdminUser;
+
+
+// Plans are only filled in when `database.explain_slow_queries` is on.
+#[get("/admin/diagnostics/slow-queries")]
+fn get_slow_queries_route(
+    _admin: AdminUser,
+    query_monitor: &State<Arc<QueryMonitor>>,
+) -> Json<QueryDiagnostics> {
+    Json(query_monitor.diagnostics())
+}
+
+
+#[delete("/admin/diagnostics/slow-queries")]
+fn clear_slow_queries_route(
+    _admin: AdminUser,
+    query_monitor: &State<Arc<QueryMonitor>>,
+) -> Json<QueryDiagnostics> {
+    query_monitor.clear();
+    Json(query_monitor.diagnostics())
+}
+
+
+// The monitor is managed as an Arc because the repositories and MetricsService share it.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_slow_queries_route, clear_slow_queries_route]
+}
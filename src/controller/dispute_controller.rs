@@ -0,0 +1,87 @@
+use rocket::{State, post, get, routes};
+use rocket::serde::json::Json;
+use crate::service::dispute_service::DisputeService;
+use crate::service::commands::dispute_commands::{OpenDisputeCommand, ResolveDisputeCommand};
+use crate::model::dispute::{Dispute, DisputeAuditEntry, NewDisputeRequest, ResolveDisputeRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::{AdminUser, AuthUser};
+
+
+#[post("/disputes", format = "json", data = "<dispute_req>")]
+async fn open_dispute_route(
+    auth_user: AuthUser,
+    dispute_service: &State<DisputeService>,
+    dispute_req: Json<NewDisputeRequest>,
+) -> Result<Json<Dispute>, AppError> {
+    validate(&*dispute_req)?;
+    let dispute_req = dispute_req.into_inner();
+    let cmd = OpenDisputeCommand {
+        donation_id: dispute_req.donation_id,
+        user_id: auth_user.id,
+        reason: dispute_req.reason,
+    };
+    let dispute = dispute_service.open_dispute(cmd).await?;
+    Ok(Json(dispute))
+}
+
+
+#[get("/disputes/me")]
+async fn get_my_disputes_route(
+    auth_user: AuthUser,
+    dispute_service: &State<DisputeService>,
+) -> Result<Json<Vec<Dispute>>, AppError> {
+    let disputes = dispute_service.get_disputes_by_user(auth_user.id).await?;
+    Ok(Json(disputes))
+}
+
+
+#[get("/admin/disputes")]
+async fn get_open_disputes_route(
+    _admin: AdminUser,
+    dispute_service: &State<DisputeService>,
+) -> Result<Json<Vec<Dispute>>, AppError> {
+    let disputes = dispute_service.get_open_disputes().await?;
+    Ok(Json(disputes))
+}
+
+
+#[get("/admin/disputes/<dispute_id>/audit")]
+async fn get_dispute_audit_route(
+    _admin: AdminUser,
+    dispute_service: &State<DisputeService>,
+    dispute_id: i32,
+) -> Result<Json<Vec<DisputeAuditEntry>>, AppError> {
+    let entries = dispute_service.get_audit_log(dispute_id).await?;
+    Ok(Json(entries))
+}
+
+
+#[post("/admin/disputes/<dispute_id>/resolve", format = "json", data = "<resolve_req>")]
+async fn resolve_dispute_route(
+    admin: AdminUser,
+    dispute_service: &State<DisputeService>,
+    dispute_id: i32,
+    resolve_req: Json<ResolveDisputeRequest>,
+) -> Result<Json<Dispute>, AppError> {
+    let resolve_req = resolve_req.into_inner();
+    let cmd = ResolveDisputeCommand {
+        dispute_id,
+        admin_id: admin.id,
+        resolution: resolve_req.resolution,
+        note: resolve_req.note,
+    };
+    let dispute = dispute_service.resolve_dispute(cmd).await?;
+    Ok(Json(dispute))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        open_dispute_route,
+        get_my_disputes_route,
+        get_open_disputes_route,
+        get_dispute_audit_route,
+        resolve_dispute_route,
+    ]
+}
@@ -0,0 +1,50 @@
+use rocket::{State, get, post, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::donation_archive_service::DonationArchiveService;
+use crate::model::donation_archive::{ArchivedDonation, DonationArchiveReport, DonationRestoreReport};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// Runs the scheduled archival immediately.
+#[post("/admin/donations/archive")]
+async fn archive_donations_route(
+    _admin: AdminUser,
+    archive_service: &State<Arc<DonationArchiveService>>,
+) -> Result<Json<DonationArchiveReport>, AppError> {
+    let report = archive_service.archive_old_donations().await?;
+    Ok(Json(report))
+}
+
+
+#[get("/admin/campaigns/<campaign_id>/archived-donations")]
+async fn archived_donations_route(
+    _admin: AdminUser,
+    archive_service: &State<Arc<DonationArchiveService>>,
+    campaign_id: i32,
+) -> Result<Json<Vec<ArchivedDonation>>, AppError> {
+    let donations = archive_service.get_archived_donations(campaign_id).await?;
+    Ok(Json(donations))
+}
+
+
+#[post("/admin/campaigns/<campaign_id>/archived-donations/restore")]
+async fn restore_archived_donations_route(
+    _admin: AdminUser,
+    archive_service: &State<Arc<DonationArchiveService>>,
+    campaign_id: i32,
+) -> Result<Json<DonationRestoreReport>, AppError> {
+    let report = archive_service.restore_campaign(campaign_id).await?;
+    Ok(Json(report))
+}
+
+
+// The service is managed as an Arc so `spawn` can run the scheduled archival.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        archive_donations_route,
+        archived_donations_route,
+        restore_archived_donations_route
+    ]
+}
@@ -1,32 +1,77 @@
 use rocket::{State, post, delete, get, routes};
+use rocket::data::{Data, ToByteUnit};
+use rocket::http::ContentType;
 use rocket::serde::json::Json;
+use std::sync::Arc;
+use std::net::IpAddr;
 use crate::service::donation_service::DonationService;
-use crate::model::donation::{NewDonationRequest, Donation};
+use crate::service::donation_import_service::DonationImportService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::campaign::CampaignDetail;
+use crate::model::donation::{NewDonationRequest, Donation, DonationReceipt, DonationPrivateNote, DonationSummary, CampaignDonationStats, CampaignDonorStatistics, PublicDonation, ReferralTotal};
+use crate::model::campaign_member::CampaignAction;
+use crate::model::donation_import::{DonationImportFormat, DonationImportReport};
+use crate::model::donation_basket::{DonationBasketReceipt, NewDonationBasketRequest};
 use crate::errors::AppError;
-use crate::auth::AuthUser; 
+use crate::validation::validate;
+use crate::locale::Locale;
+use crate::money::Localized;
+use crate::payload::LimitedJson;
+use crate::auth::{AdminUser, AuthUser};
 
 
 #[post("/donations", format = "json", data = "<donation_req>")]
 async fn make_donation_route(
     auth_user: AuthUser, 
-    donation_service: &State<DonationService>,
-    donation_req: Json<NewDonationRequest>,
+    client_ip: Option<IpAddr>,
+    donation_service: &State<Arc<DonationService>>,
+    donation_req: LimitedJson<NewDonationRequest>,
 ) -> Result<Json<Donation>, AppError> {
+    validate(&*donation_req)?;
     let cmd = crate::service::commands::donation_commands::MakeDonationCommand {
         donor_id: auth_user.id,
         campaign_id: donation_req.campaign_id,
         amount: donation_req.amount,
         message: donation_req.message.clone(),
+        private_note: donation_req.private_note.clone(),
+        tier_id: donation_req.tier_id,
+        referral_code: donation_req.referral_code.clone(),
+        honoree_name: donation_req.honoree_name.clone(),
+        honoree_email: donation_req.honoree_email.clone(),
+        anonymous: donation_req.anonymous,
+        ip_address: client_ip.map(|ip| ip.to_string()),
     };
     let donation = donation_service.make_donation(cmd).await?;
     Ok(Json(donation))
 }
 
 
+// Donates to several campaigns from one wallet debit; the response is the combined receipt.
+#[post("/donations/basket", format = "json", data = "<basket_req>")]
+async fn make_basket_donation_route(
+    auth_user: AuthUser,
+    client_ip: Option<IpAddr>,
+    donation_service: &State<Arc<DonationService>>,
+    basket_req: LimitedJson<NewDonationBasketRequest>,
+    locale: Option<Locale>,
+) -> Result<Json<Localized<DonationBasketReceipt>>, AppError> {
+    validate(&*basket_req)?;
+    let basket_req = basket_req.into_inner();
+    let cmd = crate::service::commands::donation_commands::MakeBasketDonationCommand {
+        donor_id: auth_user.id,
+        items: basket_req.items,
+        message: basket_req.message,
+        ip_address: client_ip.map(|ip| ip.to_string()),
+    };
+    let receipt = donation_service.make_basket_donation(cmd).await?;
+    Ok(Json(Localized::new(receipt, locale)))
+}
+
+
 #[delete("/donations/<donation_id>/message")]
 async fn delete_donation_message_route(
     auth_user: AuthUser,
-    donation_service: &State<DonationService>,
+    donation_service: &State<Arc<DonationService>>,
     donation_id: i32,
 ) -> Result<(), AppError> { 
     let cmd = crate::service::commands::donation_commands::DeleteDonationMessageCommand {
@@ -40,30 +85,240 @@ async fn delete_donation_message_route(
 
 #[get("/campaigns/<campaign_id>/donations")]
 async fn get_campaign_donations_route(
-    donation_service: &State<DonationService>,
+    donation_service: &State<Arc<DonationService>>,
     campaign_id: i32,
-    
-) -> Result<Json<Vec<Donation>>, AppError> {
-    let donations = donation_service.get_donations_by_campaign(campaign_id).await?;
-    Ok(Json(donations))
+    locale: Option<Locale>,
+) -> Result<Json<Vec<Localized<PublicDonation>>>, AppError> {
+    let donations = donation_service.get_public_donations_by_campaign(campaign_id).await?;
+    Ok(Json(Localized::all(donations, locale)))
+}
+
+
+#[get("/campaigns/<campaign_id>/donations/stats")]
+async fn get_campaign_donation_stats_route(
+    donation_service: &State<Arc<DonationService>>,
+    campaign_id: i32,
+) -> Result<Json<CampaignDonationStats>, AppError> {
+    let stats = donation_service.get_campaign_donation_stats(campaign_id).await?;
+    Ok(Json(stats))
+}
+
+
+#[get("/campaigns/<campaign_id>/donations/private-notes")]
+async fn get_campaign_private_notes_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+) -> Result<Json<Vec<DonationPrivateNote>>, AppError> {
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::ReadPrivateNotes)
+        .await?;
+    let notes = donation_service.get_private_notes(campaign_id).await?;
+    Ok(Json(notes))
+}
+
+
+// Aggregates only, so any campaign member may read them.
+#[get("/campaigns/<campaign_id>/donations/statistics")]
+async fn get_campaign_donor_statistics_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+) -> Result<Json<CampaignDonorStatistics>, AppError> {
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::View)
+        .await?;
+    let stats = donation_service.get_campaign_donor_statistics(campaign_id).await?;
+    Ok(Json(stats))
+}
+
+
+#[get("/campaigns/<campaign_id>/donations/referrals")]
+async fn get_campaign_referrals_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+) -> Result<Json<Vec<ReferralTotal>>, AppError> {
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::View)
+        .await?;
+    let totals = donation_service.get_referral_totals(Some(campaign_id)).await?;
+    Ok(Json(totals))
+}
+
+
+#[get("/campaigns/<campaign_id>/full")]
+async fn get_campaign_detail_route(
+    donation_service: &State<Arc<DonationService>>,
+    campaign_id: i32,
+) -> Result<Json<CampaignDetail>, AppError> {
+    let detail = donation_service.get_campaign_detail(campaign_id).await?;
+    Ok(Json(detail))
 }
 
 
 #[get("/donations/me")]
 async fn get_my_donations_route(
     auth_user: AuthUser,
-    donation_service: &State<DonationService>,
-) -> Result<Json<Vec<Donation>>, AppError> {
+    donation_service: &State<Arc<DonationService>>,
+    locale: Option<Locale>,
+) -> Result<Json<Vec<Localized<Donation>>>, AppError> {
     let donations = donation_service.get_donations_by_user(auth_user.id).await?;
+    Ok(Json(Localized::all(donations, locale)))
+}
+
+
+#[get("/donations/me/summary")]
+async fn get_my_donation_summary_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+) -> Result<Json<DonationSummary>, AppError> {
+    let summary = donation_service.get_donation_summary(auth_user.id).await?;
+    Ok(Json(summary))
+}
+
+
+#[get("/donations/<donation_id>")]
+async fn get_donation_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+    donation_id: i32,
+    locale: Option<Locale>,
+) -> Result<Json<Localized<Donation>>, AppError> {
+    let donation = donation_service.get_donation(donation_id, auth_user.id).await?;
+    Ok(Json(Localized::new(donation, locale)))
+}
+
+
+#[get("/donations/<donation_id>/receipt")]
+async fn get_donation_receipt_route(
+    auth_user: AuthUser,
+    donation_service: &State<Arc<DonationService>>,
+    donation_id: i32,
+    locale: Option<Locale>,
+) -> Result<Json<Localized<DonationReceipt>>, AppError> {
+    let receipt = donation_service.get_receipt(donation_id, auth_user.id).await?;
+    Ok(Json(Localized::new(receipt, locale)))
+}
+
+
+#[get("/admin/donations/reviews")]
+async fn get_pending_reviews_route(
+    _admin: AdminUser,
+    donation_service: &State<Arc<DonationService>>,
+) -> Result<Json<Vec<Donation>>, AppError> {
+    let donations = donation_service.get_pending_reviews().await?;
     Ok(Json(donations))
 }
 
 
+#[get("/admin/campaigns/<campaign_id>/statistics")]
+async fn admin_campaign_donor_statistics_route(
+    _admin: AdminUser,
+    donation_service: &State<Arc<DonationService>>,
+    campaign_id: i32,
+) -> Result<Json<CampaignDonorStatistics>, AppError> {
+    let stats = donation_service.get_campaign_donor_statistics(campaign_id).await?;
+    Ok(Json(stats))
+}
+
+
+// Without `campaign_id` the totals span every campaign.
+#[get("/admin/donations/referrals?<campaign_id>")]
+async fn admin_referrals_route(
+    _admin: AdminUser,
+    donation_service: &State<Arc<DonationService>>,
+    campaign_id: Option<i32>,
+) -> Result<Json<Vec<ReferralTotal>>, AppError> {
+    let totals = donation_service.get_referral_totals(campaign_id).await?;
+    Ok(Json(totals))
+}
+
+
+#[post("/admin/donations/<donation_id>/approve")]
+async fn approve_donation_route(
+    admin: AdminUser,
+    donation_service: &State<Arc<DonationService>>,
+    donation_id: i32,
+) -> Result<Json<Donation>, AppError> {
+    let cmd = crate::service::commands::donation_commands::ReviewDonationCommand {
+        donation_id,
+        admin_id: admin.id,
+    };
+    let donation = donation_service.approve_donation(cmd).await?;
+    Ok(Json(donation))
+}
+
+
+#[post("/admin/donations/<donation_id>/reject")]
+async fn reject_donation_route(
+    admin: AdminUser,
+    donation_service: &State<Arc<DonationService>>,
+    donation_id: i32,
+) -> Result<Json<Donation>, AppError> {
+    let cmd = crate::service::commands::donation_commands::ReviewDonationCommand {
+        donation_id,
+        admin_id: admin.id,
+    };
+    let donation = donation_service.reject_donation(cmd).await?;
+    Ok(Json(donation))
+}
+
+
+// Accepts `text/csv` with a header row; any other content type is read as NDJSON.
+#[post("/admin/donations/import", data = "<body>")]
+async fn import_donations_route(
+    _admin: AdminUser,
+    import_service: &State<DonationImportService>,
+    content_type: Option<&ContentType>,
+    body: Data<'_>,
+) -> Result<Json<DonationImportReport>, AppError> {
+    let format = match content_type {
+        Some(ct) if ct.media_type() == ContentType::CSV.media_type() => DonationImportFormat::Csv,
+        _ => DonationImportFormat::Ndjson,
+    };
+    let body = body
+        .open(64.mebibytes())
+        .into_string()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Could not read import body: {}", e)))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Import body exceeds the 64 MiB limit".to_string(),
+        ));
+    }
+    let report = import_service.import(format, &body).await?;
+    Ok(Json(report))
+}
+
+
 pub fn routes() -> Vec<rocket::Route> {
     routes![
         make_donation_route,
+        make_basket_donation_route,
         delete_donation_message_route,
         get_campaign_donations_route,
-        get_my_donations_route
+        get_campaign_donation_stats_route,
+        get_campaign_detail_route,
+        get_campaign_private_notes_route,
+        get_campaign_donor_statistics_route,
+        get_campaign_referrals_route,
+        get_my_donations_route,
+        get_my_donation_summary_route,
+        get_donation_route,
+        get_donation_receipt_route,
+        get_pending_reviews_route,
+        admin_campaign_donor_statistics_route,
+        admin_referrals_route,
+        approve_donation_route,
+        reject_donation_route
     ]
 }
+
+// Mounted only while `features.donation_import` is on.
+pub fn import_routes() -> Vec<rocket::Route> {
+    routes![import_donations_route]
+}
@@ -0,0 +1,48 @@
+use rocket::{State, get, post, routes};
+use rocket::http::ContentType;
+use rocket::response::stream::ByteStream;
+use rocket::futures::Stream;
+use rocket::serde::json::Json;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use crate::service::donation_export_service::DonationExportService;
+use crate::model::donation_export::DonationExportRun;
+use crate::api_key::{ApiKey, ExportsRead};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// `since` is an RFC 3339 timestamp, e.g. the `until` of the previous delta.
+#[get("/admin/export/donations?<since>")]
+async fn export_donations_route(
+    _key: ApiKey<ExportsRead>,
+    export_service: &State<Arc<DonationExportService>>,
+    since: Option<&str>,
+) -> Result<(ContentType, ByteStream<impl Stream<Item = Vec<u8>>>), AppError> {
+    let since = since
+        .map(|since| {
+            DateTime::parse_from_rfc3339(since)
+                .map(|since| since.with_timezone(&Utc))
+                .map_err(|_| AppError::ValidationError("since must be an RFC 3339 timestamp".to_string()))
+        })
+        .transpose()?;
+    let content_type = ContentType::new("application", "x-ndjson");
+    Ok((content_type, ByteStream(export_service.ndjson_stream(since))))
+}
+
+
+// Runs the scheduled delta upload immediately.
+#[post("/admin/export/donations/delta")]
+async fn export_donation_delta_route(
+    _admin: AdminUser,
+    export_service: &State<Arc<DonationExportService>>,
+) -> Result<Json<DonationExportRun>, AppError> {
+    let run = export_service.export_delta().await?;
+    Ok(Json(run))
+}
+
+
+// The service is managed as an Arc so `spawn` can run the scheduled delta upload.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![export_donations_route, export_donation_delta_route]
+}
@@ -0,0 +1,59 @@
+use rocket::{State, post, routes};
+use rocket::serde::json::Json;
+use std::net::IpAddr;
+use std::sync::Arc;
+use crate::service::donation_intent_service::DonationIntentService;
+use crate::service::commands::donation_commands::{ConfirmDonationIntentCommand, CreateDonationIntentCommand};
+use crate::model::donation::{Donation, NewDonationRequest};
+use crate::model::donation_intent::{ConfirmDonationIntentRequest, CreatedDonationIntent};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::payload::LimitedJson;
+use crate::auth::AuthUser;
+
+
+#[post("/donations/intents", format = "json", data = "<donation_req>")]
+async fn create_donation_intent_route(
+    auth_user: AuthUser,
+    intent_service: &State<Arc<DonationIntentService>>,
+    client_ip: Option<IpAddr>,
+    donation_req: LimitedJson<NewDonationRequest>,
+) -> Result<Json<CreatedDonationIntent>, AppError> {
+    validate(&*donation_req)?;
+    let cmd = CreateDonationIntentCommand {
+        donor_id: auth_user.id,
+        campaign_id: donation_req.campaign_id,
+        amount: donation_req.amount,
+        message: donation_req.message.clone(),
+        private_note: donation_req.private_note.clone(),
+        tier_id: donation_req.tier_id,
+        referral_code: donation_req.referral_code.clone(),
+        ip_address: client_ip.map(|ip| ip.to_string()),
+    };
+    let intent = intent_service.create_intent(cmd).await?;
+    Ok(Json(intent))
+}
+
+
+#[post("/donations/intents/<intent_id>/confirm", format = "json", data = "<confirm_req>")]
+async fn confirm_donation_intent_route(
+    auth_user: AuthUser,
+    intent_service: &State<Arc<DonationIntentService>>,
+    intent_id: i32,
+    confirm_req: LimitedJson<ConfirmDonationIntentRequest>,
+) -> Result<Json<Donation>, AppError> {
+    validate(&*confirm_req)?;
+    let cmd = ConfirmDonationIntentCommand {
+        intent_id,
+        user_id: auth_user.id,
+        confirmation_token: confirm_req.confirmation_token.clone(),
+    };
+    let donation = intent_service.confirm_intent(cmd).await?;
+    Ok(Json(donation))
+}
+
+
+// The service is managed as an Arc so `spawn` can run the expiry job.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![create_donation_intent_route, confirm_donation_intent_route]
+}
@@ -0,0 +1,48 @@
+use rocket::{State, get, post, routes};
+use rocket::serde::json::Json;
+use crate::service::donation_tier_service::DonationTierService;
+use crate::model::donation_tier::{DonationTier, NewDonationTierRequest, RewardClaim};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/campaigns/<campaign_id>/tiers")]
+async fn get_tiers_route(
+    tier_service: &State<DonationTierService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<DonationTier>>, AppError> {
+    let tiers = tier_service.get_tiers(campaign_id).await?;
+    Ok(Json(tiers))
+}
+
+
+#[post("/campaigns/<campaign_id>/tiers", format = "json", data = "<tier_req>")]
+async fn create_tier_route(
+    auth_user: AuthUser,
+    tier_service: &State<DonationTierService>,
+    campaign_id: i32,
+    tier_req: Json<NewDonationTierRequest>,
+) -> Result<Json<DonationTier>, AppError> {
+    validate(&*tier_req)?;
+    let tier = tier_service
+        .create_tier(campaign_id, auth_user.id, tier_req.into_inner())
+        .await?;
+    Ok(Json(tier))
+}
+
+
+#[get("/campaigns/<campaign_id>/tiers/claims")]
+async fn get_claims_route(
+    auth_user: AuthUser,
+    tier_service: &State<DonationTierService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<RewardClaim>>, AppError> {
+    let claims = tier_service.get_claims(campaign_id, auth_user.id).await?;
+    Ok(Json(claims))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_tiers_route, create_tier_route, get_claims_route]
+}
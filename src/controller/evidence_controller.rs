@@ -0,0 +1,58 @@
+use rocket::{State, get, post, routes};
+use rocket::data::{Data, Limits, ToByteUnit};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::evidence_service::EvidenceService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::model::evidence::{EvidenceFile, EvidenceProcessingReport};
+use crate::model::campaign_member::CampaignAction;
+use crate::errors::AppError;
+use crate::auth::{AdminUser, AuthUser};
+
+
+// The raw file is the body; it is capped by the `file` data limit.
+#[post("/campaigns/<campaign_id>/evidence?<file_name>", data = "<body>")]
+async fn upload_evidence_route(
+    auth_user: AuthUser,
+    evidence_service: &State<EvidenceService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    limits: &Limits,
+    campaign_id: i32,
+    file_name: &str,
+    body: Data<'_>,
+) -> Result<Json<EvidenceFile>, AppError> {
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::UploadEvidence)
+        .await?;
+    let body = body
+        .open(limits.get("file").unwrap_or(5.mebibytes()))
+        .into_bytes()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Could not read evidence file: {}", e)))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Evidence file exceeds the upload limit".to_string(),
+        ));
+    }
+    let file = evidence_service
+        .upload(campaign_id, auth_user.id, file_name, body.into_inner())
+        .await?;
+    Ok(Json(file))
+}
+
+
+// Admins check that every file has been processed before starting a review.
+#[get("/campaigns/<campaign_id>/evidence/status")]
+async fn get_evidence_status_route(
+    _admin: AdminUser,
+    evidence_service: &State<EvidenceService>,
+    campaign_id: i32,
+) -> Result<Json<EvidenceProcessingReport>, AppError> {
+    let report = evidence_service.get_processing_report(campaign_id).await?;
+    Ok(Json(report))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![upload_evidence_route, get_evidence_status_route]
+}
@@ -0,0 +1,21 @@
+use rocket::{State, get, routes};
+use rocket::serde::json::Json;
+use crate::service::fundraiser_service::FundraiserService;
+use crate::model::fundraiser::FundraiserSummary;
+use crate::errors::AppError;
+use crate::auth::AuthUser;
+
+
+#[get("/me/fundraiser/summary")]
+async fn get_fundraiser_summary_route(
+    auth_user: AuthUser,
+    fundraiser_service: &State<FundraiserService>,
+) -> Result<Json<FundraiserSummary>, AppError> {
+    let summary = fundraiser_service.get_summary(auth_user.id).await?;
+    Ok(Json(summary))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_fundraiser_summary_route]
+}
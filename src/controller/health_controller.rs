@@ -0,0 +1,22 @@
+use rocket::{State, get, routes};
+use rocket::http::Status;
+use rocket::serde::json::{json, Json, Value};
+use std::sync::Arc;
+use crate::service::cache_warmer::CacheWarmer;
+
+
+#[get("/readyz")]
+fn readyz_route(cache_warmer: &State<Arc<CacheWarmer>>) -> (Status, Json<Value>) {
+    let cache_warm = cache_warmer.is_ready();
+    let status = if cache_warm {
+        Status::Ok
+    } else {
+        Status::ServiceUnavailable
+    };
+    (status, Json(json!({ "ready": cache_warm, "cache_warm": cache_warm })))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![readyz_route]
+}
@@ -0,0 +1,185 @@
+use rocket::{State, get, post, routes};
+use rocket::data::{Data, Limits, ToByteUnit};
+use rocket::serde::json::Json;
+use crate::service::kyc_service::KycService;
+use crate::service::organization_service::OrganizationService;
+use crate::model::kyc::{KycDocument, KycDocumentType, KycStatus, KycSubject, KycSubmission, KycVerification, ReviewKycRequest};
+use crate::model::organization::OrganizationAction;
+use crate::errors::AppError;
+use crate::auth::{AdminUser, AuthUser};
+
+
+async fn read_document(limits: &Limits, body: Data<'_>) -> Result<Vec<u8>, AppError> {
+    let body = body
+        .open(limits.get("file").unwrap_or(5.mebibytes()))
+        .into_bytes()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Could not read document: {}", e)))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Document exceeds the upload limit".to_string(),
+        ));
+    }
+    Ok(body.into_inner())
+}
+
+
+#[get("/me/kyc")]
+async fn get_my_verification_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+) -> Result<Json<KycVerification>, AppError> {
+    let verification = kyc_service.get_verification(KycSubject::User(auth_user.id)).await?;
+    Ok(Json(verification))
+}
+
+
+// The raw file is the body; it is capped by the `file` data limit.
+#[post("/me/kyc/documents?<document_type>&<file_name>", data = "<body>")]
+async fn upload_my_document_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+    limits: &Limits,
+    document_type: KycDocumentType,
+    file_name: &str,
+    body: Data<'_>,
+) -> Result<Json<KycDocument>, AppError> {
+    let body = read_document(limits, body).await?;
+    let document = kyc_service
+        .upload_document(KycSubject::User(auth_user.id), auth_user.id, document_type, file_name, body)
+        .await?;
+    Ok(Json(document))
+}
+
+
+#[post("/me/kyc/submit")]
+async fn submit_my_verification_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+) -> Result<Json<KycSubmission>, AppError> {
+    let submission = kyc_service.submit(KycSubject::User(auth_user.id)).await?;
+    Ok(Json(submission))
+}
+
+
+#[get("/organizations/<organization_id>/kyc")]
+async fn get_organization_verification_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<KycVerification>, AppError> {
+    organization_service
+        .authorize(organization_id, auth_user.id, OrganizationAction::View)
+        .await?;
+    let verification = kyc_service
+        .get_verification(KycSubject::Organization(organization_id))
+        .await?;
+    Ok(Json(verification))
+}
+
+
+#[post("/organizations/<organization_id>/kyc/documents?<document_type>&<file_name>", data = "<body>")]
+#[allow(clippy::too_many_arguments)]
+async fn upload_organization_document_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+    organization_service: &State<OrganizationService>,
+    limits: &Limits,
+    organization_id: i32,
+    document_type: KycDocumentType,
+    file_name: &str,
+    body: Data<'_>,
+) -> Result<Json<KycDocument>, AppError> {
+    organization_service
+        .authorize(organization_id, auth_user.id, OrganizationAction::ManageVerification)
+        .await?;
+    let body = read_document(limits, body).await?;
+    let subject = KycSubject::Organization(organization_id);
+    let document = kyc_service
+        .upload_document(subject, auth_user.id, document_type, file_name, body)
+        .await?;
+    Ok(Json(document))
+}
+
+
+#[post("/organizations/<organization_id>/kyc/submit")]
+async fn submit_organization_verification_route(
+    auth_user: AuthUser,
+    kyc_service: &State<KycService>,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<KycSubmission>, AppError> {
+    organization_service
+        .authorize(organization_id, auth_user.id, OrganizationAction::ManageVerification)
+        .await?;
+    let submission = kyc_service.submit(KycSubject::Organization(organization_id)).await?;
+    Ok(Json(submission))
+}
+
+
+// The review queue, oldest submission first.
+#[get("/admin/kyc?<status>")]
+async fn get_kyc_queue_route(
+    _admin: AdminUser,
+    kyc_service: &State<KycService>,
+    status: Option<KycStatus>,
+) -> Result<Json<Vec<KycSubmission>>, AppError> {
+    let submissions = kyc_service.get_queue(status.unwrap_or(KycStatus::Pending)).await?;
+    Ok(Json(submissions))
+}
+
+
+#[get("/admin/kyc/<submission_id>")]
+async fn get_kyc_submission_route(
+    _admin: AdminUser,
+    kyc_service: &State<KycService>,
+    submission_id: i32,
+) -> Result<Json<KycVerification>, AppError> {
+    let verification = kyc_service.get_submission(submission_id).await?;
+    Ok(Json(verification))
+}
+
+
+#[post("/admin/kyc/<submission_id>/approve", format = "json", data = "<review_req>")]
+async fn approve_kyc_route(
+    admin: AdminUser,
+    kyc_service: &State<KycService>,
+    submission_id: i32,
+    review_req: Json<ReviewKycRequest>,
+) -> Result<Json<KycSubmission>, AppError> {
+    let submission = kyc_service
+        .approve(submission_id, admin.id, review_req.into_inner().note)
+        .await?;
+    Ok(Json(submission))
+}
+
+
+#[post("/admin/kyc/<submission_id>/reject", format = "json", data = "<review_req>")]
+async fn reject_kyc_route(
+    admin: AdminUser,
+    kyc_service: &State<KycService>,
+    submission_id: i32,
+    review_req: Json<ReviewKycRequest>,
+) -> Result<Json<KycSubmission>, AppError> {
+    let submission = kyc_service
+        .reject(submission_id, admin.id, review_req.into_inner().note)
+        .await?;
+    Ok(Json(submission))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_my_verification_route,
+        upload_my_document_route,
+        submit_my_verification_route,
+        get_organization_verification_route,
+        upload_organization_document_route,
+        submit_organization_verification_route,
+        get_kyc_queue_route,
+        get_kyc_submission_route,
+        approve_kyc_route,
+        reject_kyc_route
+    ]
+}
@@ -1 +1,43 @@
-pub mod donation_controller;
\ No newline at end of file
+pub mod admin_action_controller;
+pub mod api_key_controller;
+pub mod cache_controller;
+pub mod campaign_budget_controller;
+pub mod campaign_feed_controller;
+pub mod campaign_image_controller;
+pub mod campaign_location_controller;
+pub mod campaign_member_controller;
+pub mod campaign_overflow_controller;
+pub mod campaign_ranking_controller;
+pub mod campaign_review_controller;
+pub mod campaign_share_controller;
+pub mod campaign_suspension_controller;
+pub mod campaign_widget_controller;
+pub mod data_export_controller;
+pub mod diagnostics_controller;
+pub mod dispute_controller;
+pub mod donation_archive_controller;
+pub mod donation_controller;
+pub mod donation_export_controller;
+pub mod donation_intent_controller;
+pub mod donation_tier_controller;
+pub mod evidence_controller;
+pub mod fundraiser_controller;
+pub mod health_controller;
+pub mod kyc_controller;
+pub mod notification_delivery_controller;
+pub mod notification_preference_controller;
+pub mod organization_controller;
+pub mod profile_controller;
+pub mod reconciliation_controller;
+pub mod risk_controller;
+pub mod saved_search_controller;
+pub mod security_event_controller;
+pub mod short_link_controller;
+pub mod statistic_controller;
+pub mod statistics_snapshot_controller;
+pub mod tax_summary_controller;
+pub mod top_up_controller;
+pub mod transaction_controller;
+pub mod two_factor_controller;
+pub mod user_import_controller;
+pub mod withdrawal_controller;
@@ -0,0 +1,37 @@
+use rocket::{State, get, post, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::notification_delivery_service::NotificationDeliveryService;
+use crate::model::notification_delivery::{NotificationDelivery, NotificationDeliveryStatus};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// Dead letters by default: deliveries that ran out of retries.
+#[get("/admin/notification-deliveries?<status>")]
+async fn get_deliveries_route(
+    _admin: AdminUser,
+    delivery_service: &State<Arc<NotificationDeliveryService>>,
+    status: Option<NotificationDeliveryStatus>,
+) -> Result<Json<Vec<NotificationDelivery>>, AppError> {
+    let deliveries = delivery_service
+        .get_deliveries(status.unwrap_or(NotificationDeliveryStatus::Dead))
+        .await?;
+    Ok(Json(deliveries))
+}
+
+
+#[post("/admin/notification-deliveries/<delivery_id>/retry")]
+async fn retry_delivery_route(
+    _admin: AdminUser,
+    delivery_service: &State<Arc<NotificationDeliveryService>>,
+    delivery_id: i64,
+) -> Result<Json<NotificationDelivery>, AppError> {
+    let delivery = delivery_service.retry_dead_letter(delivery_id).await?;
+    Ok(Json(delivery))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_deliveries_route, retry_delivery_route]
+}
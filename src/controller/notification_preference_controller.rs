@@ -0,0 +1,38 @@
+use rocket::{State, get, put, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::notification_preference_service::NotificationPreferenceService;
+use crate::model::notification_preference::{NotificationPreferences, UpdateNotificationPreferencesRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/me/notification-preferences")]
+async fn get_my_notification_preferences_route(
+    auth_user: AuthUser,
+    preference_service: &State<Arc<NotificationPreferenceService>>,
+) -> Result<Json<NotificationPreferences>, AppError> {
+    let preferences = preference_service.get_preferences(auth_user.id).await?;
+    Ok(Json(preferences))
+}
+
+
+#[put("/me/notification-preferences", format = "json", data = "<preferences_req>")]
+async fn update_my_notification_preferences_route(
+    auth_user: AuthUser,
+    preference_service: &State<Arc<NotificationPreferenceService>>,
+    preferences_req: Json<UpdateNotificationPreferencesRequest>,
+) -> Result<Json<NotificationPreferences>, AppError> {
+    validate(&*preferences_req)?;
+    let preferences = preference_service
+        .update_preferences(auth_user.id, preferences_req.into_inner())
+        .await?;
+    Ok(Json(preferences))
+}
+
+
+// The service is managed as an Arc because it is also subscribed to the event bus.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_my_notification_preferences_route, update_my_notification_preferences_route]
+}
@@ -0,0 +1,180 @@
+use rocket::{State, post, put, delete, get, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::organization_service::OrganizationService;
+use crate::service::campaign_member_service::CampaignMemberService;
+use crate::service::withdrawal_service::WithdrawalService;
+use crate::service::commands::withdrawal_commands::RequestWithdrawalCommand;
+use crate::model::organization::{AddOrganizationMemberRequest, AssignCampaignOrganizationRequest, NewOrganizationRequest, Organization, OrganizationAction, OrganizationMember, OrganizationWallet};
+use crate::model::campaign_member::CampaignAction;
+use crate::model::withdrawal::{NewWithdrawalRequest, Withdrawal};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::{AdminUser, AuthUser};
+
+
+// The creator becomes the organization's first admin.
+#[post("/organizations", format = "json", data = "<organization_req>")]
+async fn create_organization_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    organization_req: Json<NewOrganizationRequest>,
+) -> Result<Json<Organization>, AppError> {
+    validate(&*organization_req)?;
+    let organization = organization_service
+        .create_organization(auth_user.id, organization_req.into_inner())
+        .await?;
+    Ok(Json(organization))
+}
+
+
+#[get("/organizations/<organization_id>")]
+async fn get_organization_route(
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<Organization>, AppError> {
+    let organization = organization_service.get_organization(organization_id).await?;
+    Ok(Json(organization))
+}
+
+
+#[post("/admin/organizations/<organization_id>/verify")]
+async fn verify_organization_route(
+    admin: AdminUser,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<Organization>, AppError> {
+    let organization = organization_service
+        .verify_organization(organization_id, admin.id)
+        .await?;
+    Ok(Json(organization))
+}
+
+
+#[get("/organizations/<organization_id>/members")]
+async fn get_members_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<Vec<OrganizationMember>>, AppError> {
+    let members = organization_service.get_members(organization_id, auth_user.id).await?;
+    Ok(Json(members))
+}
+
+
+#[put("/organizations/<organization_id>/members", format = "json", data = "<member_req>")]
+async fn add_member_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+    member_req: Json<AddOrganizationMemberRequest>,
+) -> Result<Json<OrganizationMember>, AppError> {
+    validate(&*member_req)?;
+    let member = organization_service
+        .add_member(organization_id, auth_user.id, member_req.into_inner())
+        .await?;
+    Ok(Json(member))
+}
+
+
+#[delete("/organizations/<organization_id>/members/<user_id>")]
+async fn remove_member_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+    user_id: i32,
+) -> Result<(), AppError> {
+    organization_service.remove_member(organization_id, auth_user.id, user_id).await?;
+    Ok(())
+}
+
+
+#[get("/organizations/<organization_id>/wallet")]
+async fn get_wallet_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    organization_id: i32,
+) -> Result<Json<OrganizationWallet>, AppError> {
+    let wallet = organization_service.get_wallet(organization_id, auth_user.id).await?;
+    Ok(Json(wallet))
+}
+
+
+// Paid out of the organization's wallet; goes through the same approval policy as
+// personal withdrawals.
+#[post("/organizations/<organization_id>/withdrawals", format = "json", data = "<withdrawal_req>")]
+async fn request_withdrawal_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    withdrawal_service: &State<WithdrawalService>,
+    organization_id: i32,
+    withdrawal_req: Json<NewWithdrawalRequest>,
+) -> Result<Json<Withdrawal>, AppError> {
+    validate(&*withdrawal_req)?;
+    organization_service
+        .authorize(organization_id, auth_user.id, OrganizationAction::RequestPayout)
+        .await?;
+    let req = withdrawal_req.into_inner();
+    let cmd = RequestWithdrawalCommand {
+        user_id: auth_user.id,
+        organization_id: Some(organization_id),
+        amount: req.amount,
+        bank_name: req.bank_name,
+        account_number: req.account_number,
+        account_holder: req.account_holder,
+    };
+    let withdrawal = withdrawal_service.request_withdrawal(cmd).await?;
+    Ok(Json(withdrawal))
+}
+
+
+#[get("/organizations/<organization_id>/withdrawals")]
+async fn get_withdrawals_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    withdrawal_service: &State<WithdrawalService>,
+    organization_id: i32,
+) -> Result<Json<Vec<Withdrawal>>, AppError> {
+    organization_service
+        .authorize(organization_id, auth_user.id, OrganizationAction::ViewWallet)
+        .await?;
+    let withdrawals = withdrawal_service.get_withdrawals_by_organization(organization_id).await?;
+    Ok(Json(withdrawals))
+}
+
+
+// Moves the campaign under the organization; needs ownership of the campaign and an
+// admin role in the organization.
+#[put("/campaigns/<campaign_id>/organization", format = "json", data = "<assign_req>")]
+async fn assign_campaign_route(
+    auth_user: AuthUser,
+    organization_service: &State<OrganizationService>,
+    member_service: &State<Arc<CampaignMemberService>>,
+    campaign_id: i32,
+    assign_req: Json<AssignCampaignOrganizationRequest>,
+) -> Result<(), AppError> {
+    validate(&*assign_req)?;
+    member_service
+        .authorize(campaign_id, auth_user.id, CampaignAction::ManageMembers)
+        .await?;
+    organization_service
+        .assign_campaign(campaign_id, assign_req.organization_id, auth_user.id)
+        .await?;
+    Ok(())
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        create_organization_route,
+        get_organization_route,
+        verify_organization_route,
+        get_members_route,
+        add_member_route,
+        remove_member_route,
+        get_wallet_route,
+        request_withdrawal_route,
+        get_withdrawals_route,
+        assign_campaign_route
+    ]
+}
@@ -0,0 +1,36 @@
+use rocket::{State, get, put, routes};
+use rocket::serde::json::Json;
+use crate::service::profile_service::ProfileService;
+use crate::model::profile::{Profile, UpdateProfileRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/me/profile")]
+async fn get_my_profile_route(
+    auth_user: AuthUser,
+    profile_service: &State<ProfileService>,
+) -> Result<Json<Profile>, AppError> {
+    let profile = profile_service.get_profile(auth_user.id).await?;
+    Ok(Json(profile))
+}
+
+
+#[put("/me/profile", format = "json", data = "<profile_req>")]
+async fn update_my_profile_route(
+    auth_user: AuthUser,
+    profile_service: &State<ProfileService>,
+    profile_req: Json<UpdateProfileRequest>,
+) -> Result<Json<Profile>, AppError> {
+    validate(&*profile_req)?;
+    let profile = profile_service
+        .update_profile(auth_user.id, profile_req.into_inner())
+        .await?;
+    Ok(Json(profile))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_my_profile_route, update_my_profile_route]
+}
@@ -0,0 +1,65 @@
+use rocket::{State, get, post, routes};
+use rocket::data::{Data, ToByteUnit};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::reconciliation_service::ReconciliationService;
+use crate::model::reconciliation::{ReconciliationReport, ReconciliationRun};
+use crate::controller::transaction_controller::parse_date;
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// The body is the provider's settlement CSV; `from` and `to` (YYYY-MM-DD, `to`
+// inclusive) bound the top-ups it is matched against.
+#[post("/admin/reconciliation?<provider>&<from>&<to>", data = "<body>")]
+async fn reconcile_route(
+    admin: AdminUser,
+    reconciliation_service: &State<Arc<ReconciliationService>>,
+    provider: &str,
+    from: &str,
+    to: &str,
+    body: Data<'_>,
+) -> Result<Json<ReconciliationReport>, AppError> {
+    let from = parse_date(Some(from), "from", false)?.unwrap();
+    let to = parse_date(Some(to), "to", true)?.unwrap();
+    let body = body
+        .open(16.mebibytes())
+        .into_string()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Could not read settlement report: {}", e)))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Settlement report exceeds the 16 MiB limit".to_string(),
+        ));
+    }
+    let report = reconciliation_service
+        .reconcile(admin.id, provider, from, to, &body)
+        .await?;
+    Ok(Json(report))
+}
+
+
+#[get("/admin/reconciliation")]
+async fn list_runs_route(
+    _admin: AdminUser,
+    reconciliation_service: &State<Arc<ReconciliationService>>,
+) -> Result<Json<Vec<ReconciliationRun>>, AppError> {
+    let runs = reconciliation_service.list_runs().await?;
+    Ok(Json(runs))
+}
+
+
+#[get("/admin/reconciliation/<run_id>")]
+async fn get_report_route(
+    _admin: AdminUser,
+    reconciliation_service: &State<Arc<ReconciliationService>>,
+    run_id: i32,
+) -> Result<Json<ReconciliationReport>, AppError> {
+    let report = reconciliation_service.get_report(run_id).await?;
+    Ok(Json(report))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![reconcile_route, list_runs_route, get_report_route]
+}
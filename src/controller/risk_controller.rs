@@ -0,0 +1,110 @@
+use rocket::{State, post, put, delete, get, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::risk_service::RiskService;
+use crate::model::risk::{
+    BlacklistEntry, FlaggedActivity, NewBlacklistEntryRequest, NewRiskRuleRequest, RiskRule,
+    UpdateRiskRuleRequest,
+};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+#[get("/admin/risk/rules")]
+async fn get_rules_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+) -> Result<Json<Vec<RiskRule>>, AppError> {
+    let rules = risk_service.get_rules().await?;
+    Ok(Json(rules))
+}
+
+
+#[post("/admin/risk/rules", format = "json", data = "<rule_req>")]
+async fn create_rule_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+    rule_req: Json<NewRiskRuleRequest>,
+) -> Result<Json<RiskRule>, AppError> {
+    let rule = risk_service.create_rule(rule_req.into_inner()).await?;
+    Ok(Json(rule))
+}
+
+
+#[put("/admin/risk/rules/<rule_id>", format = "json", data = "<rule_req>")]
+async fn update_rule_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+    rule_id: i32,
+    rule_req: Json<UpdateRiskRuleRequest>,
+) -> Result<Json<RiskRule>, AppError> {
+    let rule = risk_service.update_rule(rule_id, rule_req.into_inner()).await?;
+    Ok(Json(rule))
+}
+
+
+#[delete("/admin/risk/rules/<rule_id>")]
+async fn delete_rule_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+    rule_id: i32,
+) -> Result<(), AppError> {
+    risk_service.delete_rule(rule_id).await?;
+    Ok(())
+}
+
+
+#[get("/admin/risk/blacklist")]
+async fn get_blacklist_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+) -> Result<Json<Vec<BlacklistEntry>>, AppError> {
+    let entries = risk_service.get_blacklist().await?;
+    Ok(Json(entries))
+}
+
+
+#[post("/admin/risk/blacklist", format = "json", data = "<entry_req>")]
+async fn add_blacklist_entry_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+    entry_req: Json<NewBlacklistEntryRequest>,
+) -> Result<Json<BlacklistEntry>, AppError> {
+    let entry = risk_service.add_blacklist_entry(entry_req.into_inner()).await?;
+    Ok(Json(entry))
+}
+
+
+#[delete("/admin/risk/blacklist/<entry_id>")]
+async fn remove_blacklist_entry_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+    entry_id: i32,
+) -> Result<(), AppError> {
+    risk_service.remove_blacklist_entry(entry_id).await?;
+    Ok(())
+}
+
+
+#[get("/admin/risk/flags")]
+async fn get_flagged_activity_route(
+    _admin: AdminUser,
+    risk_service: &State<Arc<RiskService>>,
+) -> Result<Json<Vec<FlaggedActivity>>, AppError> {
+    let flags = risk_service.get_flagged_activity().await?;
+    Ok(Json(flags))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_rules_route,
+        create_rule_route,
+        update_rule_route,
+        delete_rule_route,
+        get_blacklist_route,
+        add_blacklist_entry_route,
+        remove_blacklist_entry_route,
+        get_flagged_activity_route
+    ]
+}
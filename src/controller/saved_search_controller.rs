@@ -0,0 +1,68 @@
+use rocket::{State, delete, get, post, put, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::saved_search_service::SavedSearchService;
+use crate::model::saved_search::{SavedSearch, SavedSearchRequest};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+#[get("/me/saved-searches")]
+async fn get_saved_searches_route(
+    auth_user: AuthUser,
+    saved_search_service: &State<Arc<SavedSearchService>>,
+) -> Result<Json<Vec<SavedSearch>>, AppError> {
+    let searches = saved_search_service.get_saved_searches(auth_user.id).await?;
+    Ok(Json(searches))
+}
+
+
+#[post("/me/saved-searches", format = "json", data = "<search_req>")]
+async fn create_saved_search_route(
+    auth_user: AuthUser,
+    saved_search_service: &State<Arc<SavedSearchService>>,
+    search_req: Json<SavedSearchRequest>,
+) -> Result<Json<SavedSearch>, AppError> {
+    validate(&*search_req)?;
+    let search = saved_search_service
+        .create_saved_search(auth_user.id, search_req.into_inner())
+        .await?;
+    Ok(Json(search))
+}
+
+
+#[put("/me/saved-searches/<search_id>", format = "json", data = "<search_req>")]
+async fn update_saved_search_route(
+    auth_user: AuthUser,
+    saved_search_service: &State<Arc<SavedSearchService>>,
+    search_id: i32,
+    search_req: Json<SavedSearchRequest>,
+) -> Result<Json<SavedSearch>, AppError> {
+    validate(&*search_req)?;
+    let search = saved_search_service
+        .update_saved_search(search_id, auth_user.id, search_req.into_inner())
+        .await?;
+    Ok(Json(search))
+}
+
+
+#[delete("/me/saved-searches/<search_id>")]
+async fn delete_saved_search_route(
+    auth_user: AuthUser,
+    saved_search_service: &State<Arc<SavedSearchService>>,
+    search_id: i32,
+) -> Result<(), AppError> {
+    saved_search_service.delete_saved_search(search_id, auth_user.id).await?;
+    Ok(())
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_saved_searches_route,
+        create_saved_search_route,
+        update_saved_search_route,
+        delete_saved_search_route
+    ]
+}
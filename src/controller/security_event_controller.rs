@@ -0,0 +1,21 @@
+use rocket::{State, get, routes};
+use rocket::serde::json::Json;
+use crate::service::security_event_service::SecurityEventService;
+use crate::model::security_event::SecurityEvent;
+use crate::errors::AppError;
+use crate::auth::AuthUser;
+
+
+#[get("/me/security/events")]
+async fn get_my_security_events_route(
+    auth_user: AuthUser,
+    security_event_service: &State<SecurityEventService>,
+) -> Result<Json<Vec<SecurityEvent>>, AppError> {
+    let events = security_event_service.get_events(auth_user.id).await?;
+    Ok(Json(events))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![get_my_security_events_route]
+}
@@ -0,0 +1,67 @@
+use rocket::{State, get, post, routes};
+use rocket::http::ContentType;
+use rocket::response::Redirect;
+use rocket::serde::json::Json;
+use crate::service::short_link_service::ShortLinkService;
+use crate::model::short_link::{NewShortLinkRequest, ShortLinkResponse};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AuthUser;
+
+
+// `link` selects one of the campaign's short links, so poster scans are counted per channel.
+#[get("/campaigns/<campaign_id>/qr.png?<link>")]
+async fn campaign_qr_route(
+    short_link_service: &State<ShortLinkService>,
+    campaign_id: i32,
+    link: Option<&str>,
+) -> Result<(ContentType, Vec<u8>), AppError> {
+    let png = short_link_service.campaign_qr(campaign_id, link).await?;
+    Ok((ContentType::PNG, png))
+}
+
+
+#[post("/campaigns/<campaign_id>/short-links", format = "json", data = "<link_req>")]
+async fn create_short_link_route(
+    auth_user: AuthUser,
+    short_link_service: &State<ShortLinkService>,
+    campaign_id: i32,
+    link_req: Json<NewShortLinkRequest>,
+) -> Result<Json<ShortLinkResponse>, AppError> {
+    validate(&*link_req)?;
+    let link = short_link_service
+        .create_link(campaign_id, auth_user.id, link_req.into_inner())
+        .await?;
+    Ok(Json(link))
+}
+
+
+#[get("/campaigns/<campaign_id>/short-links")]
+async fn list_short_links_route(
+    auth_user: AuthUser,
+    short_link_service: &State<ShortLinkService>,
+    campaign_id: i32,
+) -> Result<Json<Vec<ShortLinkResponse>>, AppError> {
+    let links = short_link_service.list_links(campaign_id, auth_user.id).await?;
+    Ok(Json(links))
+}
+
+
+#[get("/c/<slug>")]
+async fn follow_short_link_route(
+    short_link_service: &State<ShortLinkService>,
+    slug: &str,
+) -> Result<Redirect, AppError> {
+    let target = short_link_service.follow(slug).await?;
+    Ok(Redirect::found(target))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![campaign_qr_route, create_short_link_route, list_short_links_route]
+}
+
+// Mounted at the root: short links are `<sharing.short_link_base_url>/c/<slug>`.
+pub fn redirect_routes() -> Vec<rocket::Route> {
+    routes![follow_short_link_route]
+}
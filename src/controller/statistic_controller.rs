@@ -0,0 +1,35 @@
+use rocket::{State, get, routes, Responder};
+use rocket::http::Header;
+use rocket::serde::json::Json;
+use crate::service::statistic_service::StatisticService;
+use crate::model::statistic::PublicStats;
+use crate::money::Localized;
+use crate::locale::Locale;
+use crate::errors::AppError;
+
+
+#[derive(Responder)]
+struct Cached<T> {
+    inner: T,
+    cache_control: Header<'static>,
+}
+
+
+// Public and unauthenticated; `amount_formatted` holds `total_raised` when a locale is requested.
+#[get("/stats/public")]
+async fn public_stats_route(
+    statistic_service: &State<StatisticService>,
+    locale: Option<Locale>,
+) -> Result<Cached<Json<Localized<PublicStats>>>, AppError> {
+    let stats = statistic_service.get_public_stats().await?;
+    let max_age = statistic_service.cache_ttl().as_secs();
+    Ok(Cached {
+        inner: Json(Localized::new(stats, locale)),
+        cache_control: Header::new("Cache-Control", format!("public, max-age={}", max_age)),
+    })
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![public_stats_route]
+}
@@ -0,0 +1,41 @@
+use rocket::{State, get, post, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::statistics_snapshot_service::StatisticsSnapshotService;
+use crate::model::statistics_snapshot::{StatisticHistory, StatisticMetric, StatisticRange};
+use crate::api_key::{AdminOrApiKey, StatsRead};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// `range` defaults to the last 90 days. Dashboards can read it with a `stats:read`
+// API key instead of an admin token.
+#[get("/admin/statistics/history?<metric>&<range>")]
+async fn statistics_history_route(
+    _caller: AdminOrApiKey<StatsRead>,
+    snapshot_service: &State<Arc<StatisticsSnapshotService>>,
+    metric: StatisticMetric,
+    range: Option<StatisticRange>,
+) -> Result<Json<StatisticHistory>, AppError> {
+    let history = snapshot_service
+        .get_history(metric, range.unwrap_or(StatisticRange::Days90))
+        .await?;
+    Ok(Json(history))
+}
+
+
+// Captures today's snapshot now, e.g. right after deploying or when the nightly run failed.
+#[post("/admin/statistics/snapshots")]
+async fn capture_snapshot_route(
+    _admin: AdminUser,
+    snapshot_service: &State<Arc<StatisticsSnapshotService>>,
+) -> Result<(), AppError> {
+    snapshot_service.capture().await?;
+    Ok(())
+}
+
+
+// The service is managed as an Arc so `spawn` can run the nightly snapshot.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![statistics_history_route, capture_snapshot_route]
+}
@@ -0,0 +1,85 @@
+use rocket::{State, delete, get, put, routes, Responder};
+use rocket::http::{ContentType, Header};
+use rocket::serde::json::Json;
+use crate::service::tax_summary_service::TaxSummaryService;
+use crate::model::tax_summary::{TaxDeductibleCampaign, TaxSummary};
+use crate::errors::AppError;
+use crate::locale::Locale;
+use crate::auth::{AdminUser, AuthUser};
+
+
+#[derive(Responder)]
+struct Attachment {
+    inner: (ContentType, Vec<u8>),
+    disposition: Header<'static>,
+}
+
+
+#[get("/me/tax-summary?<year>")]
+async fn get_tax_summary_route(
+    auth_user: AuthUser,
+    tax_service: &State<TaxSummaryService>,
+    year: i32,
+) -> Result<Json<TaxSummary>, AppError> {
+    let summary = tax_service.get_summary(auth_user.id, year).await?;
+    Ok(Json(summary))
+}
+
+
+#[get("/me/tax-summary/pdf?<year>")]
+async fn get_tax_summary_pdf_route(
+    auth_user: AuthUser,
+    tax_service: &State<TaxSummaryService>,
+    year: i32,
+    locale: Option<Locale>,
+) -> Result<Attachment, AppError> {
+    let pdf = tax_service.get_summary_pdf(auth_user.id, year, locale).await?;
+    Ok(Attachment {
+        inner: (ContentType::PDF, pdf),
+        disposition: Header::new(
+            "Content-Disposition",
+            format!("attachment; filename=\"tax-summary-{}.pdf\"", year),
+        ),
+    })
+}
+
+
+#[get("/admin/tax-deductible-campaigns")]
+async fn list_deductible_campaigns_route(
+    _admin: AdminUser,
+    tax_service: &State<TaxSummaryService>,
+) -> Result<Json<Vec<TaxDeductibleCampaign>>, AppError> {
+    let campaigns = tax_service.list_deductible_campaigns().await?;
+    Ok(Json(campaigns))
+}
+
+
+#[put("/admin/campaigns/<campaign_id>/tax-deductible")]
+async fn mark_deductible_route(
+    admin: AdminUser,
+    tax_service: &State<TaxSummaryService>,
+    campaign_id: i32,
+) -> Result<(), AppError> {
+    tax_service.mark_deductible(campaign_id, admin.id).await
+}
+
+
+#[delete("/admin/campaigns/<campaign_id>/tax-deductible")]
+async fn unmark_deductible_route(
+    _admin: AdminUser,
+    tax_service: &State<TaxSummaryService>,
+    campaign_id: i32,
+) -> Result<(), AppError> {
+    tax_service.unmark_deductible(campaign_id).await
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        get_tax_summary_route,
+        get_tax_summary_pdf_route,
+        list_deductible_campaigns_route,
+        mark_deductible_route,
+        unmark_deductible_route
+    ]
+}
@@ -0,0 +1,51 @@
+use rocket::{State, post, routes};
+use rocket::data::{Data, ToByteUnit};
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::serde::json::Json;
+use crate::service::top_up_service::{TopUpService, TOP_UP_SIGNATURE_HEADER};
+use crate::model::transaction::Transaction;
+use crate::errors::AppError;
+
+
+struct Signature(Option<String>);
+
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Signature {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(Signature(req.headers().get_one(TOP_UP_SIGNATURE_HEADER).map(str::to_string)))
+    }
+}
+
+
+// Called by the payment provider, not a user: the body must be signed with the
+// provider's secret key. Redelivering the same notification is harmless.
+#[post("/payments/<provider>/top-ups", data = "<body>")]
+async fn top_up_notification_route(
+    top_up_service: &State<TopUpService>,
+    provider: &str,
+    signature: Signature,
+    body: Data<'_>,
+) -> Result<Json<Transaction>, AppError> {
+    let body = body
+        .open(64.kibibytes())
+        .into_string()
+        .await
+        .map_err(|_| AppError::ValidationError("Could not read top-up notification".to_string()))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Top-up notification is too large".to_string(),
+        ));
+    }
+    let transaction = top_up_service
+        .record_top_up(provider, signature.0.as_deref(), &body)
+        .await?;
+    Ok(Json(transaction))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![top_up_notification_route]
+}
@@ -0,0 +1,79 @@
+use rocket::{State, get, routes, FromForm};
+use rocket::http::ContentType;
+use rocket::serde::json::Json;
+use chrono::{DateTime, NaiveDate, Utc};
+use crate::service::transaction_service::TransactionService;
+use crate::model::transaction::{TransactionFilter, TransactionPage, TransactionType};
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+#[derive(FromForm)]
+struct TransactionQuery {
+    #[field(name = "type")]
+    transaction_type: Option<TransactionType>,
+    user_id: Option<i32>,
+    campaign_id: Option<i32>,
+    min_amount: Option<f64>,
+    max_amount: Option<f64>,
+    from: Option<String>,
+    to: Option<String>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+// Dates are `YYYY-MM-DD`; `to` is inclusive of the whole day.
+pub(crate) fn parse_date(value: Option<&str>, field: &str, end_of_day: bool) -> Result<Option<DateTime<Utc>>, AppError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        AppError::ValidationError(format!("{} must be a date in YYYY-MM-DD format", field))
+    })?;
+    let date = if end_of_day { date.succ_opt().unwrap_or(date) } else { date };
+    Ok(Some(date.and_hms_opt(0, 0, 0).unwrap().and_utc()))
+}
+
+impl TransactionQuery {
+    fn into_filter(self) -> Result<TransactionFilter, AppError> {
+        Ok(TransactionFilter {
+            transaction_type: self.transaction_type,
+            user_id: self.user_id,
+            campaign_id: self.campaign_id,
+            min_amount: self.min_amount,
+            max_amount: self.max_amount,
+            from: parse_date(self.from.as_deref(), "from", false)?,
+            to: parse_date(self.to.as_deref(), "to", true)?,
+        })
+    }
+}
+
+
+#[get("/admin/transactions?<query..>")]
+async fn list_transactions_route(
+    _admin: AdminUser,
+    transaction_service: &State<TransactionService>,
+    query: TransactionQuery,
+) -> Result<Json<TransactionPage>, AppError> {
+    let (page, per_page) = (query.page, query.per_page);
+    let transactions = transaction_service
+        .list(query.into_filter()?, page, per_page)
+        .await?;
+    Ok(Json(transactions))
+}
+
+
+#[get("/admin/transactions/export?<query..>")]
+async fn export_transactions_route(
+    _admin: AdminUser,
+    transaction_service: &State<TransactionService>,
+    query: TransactionQuery,
+) -> Result<(ContentType, String), AppError> {
+    let csv = transaction_service.export_csv(query.into_filter()?).await?;
+    Ok((ContentType::CSV, csv))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![list_transactions_route, export_transactions_route]
+}
@@ -0,0 +1,50 @@
+use rocket::{State, post, routes};
+use rocket::serde::json::Json;
+use std::sync::Arc;
+use crate::service::two_factor_service::TwoFactorService;
+use crate::model::two_factor::{RecoveryCodes, StepUpStatus, TwoFactorCodeRequest, TwoFactorEnrollment};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::auth::AdminUser;
+
+
+#[post("/admin/2fa/enroll")]
+async fn enroll_route(
+    admin: AdminUser,
+    two_factor_service: &State<Arc<TwoFactorService>>,
+) -> Result<Json<TwoFactorEnrollment>, AppError> {
+    let enrollment = two_factor_service.enroll(admin.id).await?;
+    Ok(Json(enrollment))
+}
+
+
+#[post("/admin/2fa/verify", format = "json", data = "<code_req>")]
+async fn verify_enrollment_route(
+    admin: AdminUser,
+    two_factor_service: &State<Arc<TwoFactorService>>,
+    code_req: Json<TwoFactorCodeRequest>,
+) -> Result<Json<RecoveryCodes>, AppError> {
+    validate(&*code_req)?;
+    let recovery_codes = two_factor_service
+        .verify_enrollment(admin.id, &code_req.code)
+        .await?;
+    Ok(Json(recovery_codes))
+}
+
+
+#[post("/admin/2fa/step-up", format = "json", data = "<code_req>")]
+async fn step_up_route(
+    admin: AdminUser,
+    two_factor_service: &State<Arc<TwoFactorService>>,
+    code_req: Json<TwoFactorCodeRequest>,
+) -> Result<Json<StepUpStatus>, AppError> {
+    validate(&*code_req)?;
+    let status = two_factor_service.step_up(admin.id, &code_req.code).await?;
+    Ok(Json(status))
+}
+
+
+// TwoFactorService is managed as an Arc so WithdrawalService can share it.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![enroll_route, verify_enrollment_route, step_up_route]
+}
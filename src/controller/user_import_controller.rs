@@ -0,0 +1,34 @@
+use rocket::{State, post, routes};
+use rocket::data::{Data, ToByteUnit};
+use rocket::serde::json::Json;
+use crate::service::user_import_service::UserImportService;
+use crate::model::user_import::UserImportReport;
+use crate::errors::AppError;
+use crate::auth::AdminUser;
+
+
+// Expects `text/csv` with an `email,name,role` header row.
+#[post("/admin/users/import", format = "text/csv", data = "<body>")]
+async fn import_users_route(
+    _admin: AdminUser,
+    import_service: &State<UserImportService>,
+    body: Data<'_>,
+) -> Result<Json<UserImportReport>, AppError> {
+    let body = body
+        .open(8.mebibytes())
+        .into_string()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Could not read import body: {}", e)))?;
+    if !body.is_complete() {
+        return Err(AppError::ValidationError(
+            "Import body exceeds the 8 MiB limit".to_string(),
+        ));
+    }
+    let report = import_service.import(&body).await?;
+    Ok(Json(report))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![import_users_route]
+}
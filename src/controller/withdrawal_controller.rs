@@ -0,0 +1,123 @@
+use rocket::{State, post, get, routes};
+use rocket::serde::json::Json;
+use crate::service::withdrawal_service::WithdrawalService;
+use crate::service::commands::withdrawal_commands::{RequestWithdrawalCommand, ReviewWithdrawalCommand};
+use crate::model::withdrawal::{NewWithdrawalRequest, ReviewWithdrawalRequest, Withdrawal, WithdrawalAuditEntry, WithdrawalStatus};
+use crate::errors::AppError;
+use crate::validation::validate;
+use crate::locale::Locale;
+use crate::money::Localized;
+use crate::auth::{AdminUser, AuthUser};
+
+
+#[post("/wallet/withdrawals", format = "json", data = "<withdrawal_req>")]
+async fn request_withdrawal_route(
+    auth_user: AuthUser,
+    withdrawal_service: &State<WithdrawalService>,
+    withdrawal_req: Json<NewWithdrawalRequest>,
+) -> Result<Json<Withdrawal>, AppError> {
+    validate(&*withdrawal_req)?;
+    let req = withdrawal_req.into_inner();
+    let cmd = RequestWithdrawalCommand {
+        user_id: auth_user.id,
+        organization_id: None,
+        amount: req.amount,
+        bank_name: req.bank_name,
+        account_number: req.account_number,
+        account_holder: req.account_holder,
+    };
+    let withdrawal = withdrawal_service.request_withdrawal(cmd).await?;
+    Ok(Json(withdrawal))
+}
+
+
+#[get("/wallet/withdrawals")]
+async fn get_my_withdrawals_route(
+    auth_user: AuthUser,
+    withdrawal_service: &State<WithdrawalService>,
+    locale: Option<Locale>,
+) -> Result<Json<Vec<Localized<Withdrawal>>>, AppError> {
+    let withdrawals = withdrawal_service.get_withdrawals_by_user(auth_user.id).await?;
+    Ok(Json(Localized::all(withdrawals, locale)))
+}
+
+
+#[get("/admin/withdrawals?<status>")]
+async fn get_withdrawals_by_status_route(
+    _admin: AdminUser,
+    withdrawal_service: &State<WithdrawalService>,
+    status: Option<WithdrawalStatus>,
+) -> Result<Json<Vec<Withdrawal>>, AppError> {
+    let status = status.unwrap_or(WithdrawalStatus::Pending);
+    let withdrawals = withdrawal_service.get_withdrawals_by_status(status).await?;
+    Ok(Json(withdrawals))
+}
+
+
+// Withdrawals still waiting for an approval from the calling admin.
+#[get("/admin/withdrawals/pending-approvals")]
+async fn get_pending_approvals_route(
+    admin: AdminUser,
+    withdrawal_service: &State<WithdrawalService>,
+) -> Result<Json<Vec<Withdrawal>>, AppError> {
+    let withdrawals = withdrawal_service.get_pending_approvals(admin.id).await?;
+    Ok(Json(withdrawals))
+}
+
+
+#[get("/admin/withdrawals/<withdrawal_id>/audit")]
+async fn get_withdrawal_audit_route(
+    _admin: AdminUser,
+    withdrawal_service: &State<WithdrawalService>,
+    withdrawal_id: i32,
+) -> Result<Json<Vec<WithdrawalAuditEntry>>, AppError> {
+    let entries = withdrawal_service.get_audit_log(withdrawal_id).await?;
+    Ok(Json(entries))
+}
+
+
+#[post("/admin/withdrawals/<withdrawal_id>/approve", format = "json", data = "<review_req>")]
+async fn approve_withdrawal_route(
+    admin: AdminUser,
+    withdrawal_service: &State<WithdrawalService>,
+    withdrawal_id: i32,
+    review_req: Json<ReviewWithdrawalRequest>,
+) -> Result<Json<Withdrawal>, AppError> {
+    let cmd = ReviewWithdrawalCommand {
+        withdrawal_id,
+        admin_id: admin.id,
+        note: review_req.into_inner().note,
+    };
+    let withdrawal = withdrawal_service.approve_withdrawal(cmd).await?;
+    Ok(Json(withdrawal))
+}
+
+
+#[post("/admin/withdrawals/<withdrawal_id>/reject", format = "json", data = "<review_req>")]
+async fn reject_withdrawal_route(
+    admin: AdminUser,
+    withdrawal_service: &State<WithdrawalService>,
+    withdrawal_id: i32,
+    review_req: Json<ReviewWithdrawalRequest>,
+) -> Result<Json<Withdrawal>, AppError> {
+    let cmd = ReviewWithdrawalCommand {
+        withdrawal_id,
+        admin_id: admin.id,
+        note: review_req.into_inner().note,
+    };
+    let withdrawal = withdrawal_service.reject_withdrawal(cmd).await?;
+    Ok(Json(withdrawal))
+}
+
+
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        request_withdrawal_route,
+        get_my_withdrawals_route,
+        get_withdrawals_by_status_route,
+        get_pending_approvals_route,
+        get_withdrawal_audit_route,
+        approve_withdrawal_route,
+        reject_withdrawal_route
+    ]
+}
@@ -0,0 +1,160 @@
+use rocket::{response::Responder, http::{Header, Status}, Response, Request};
+use rocket::serde::json::{json, Json};
+use thiserror::Error;
+use crate::fairing::error_reporting::ReportedError;
+use crate::fairing::request_logging::ReportedErrorCode;
+use crate::locale::Locale;
+use crate::validation::FieldError;
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error), // Example
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+
+    #[error("Request has {} invalid field(s)", .0.len())]
+    UnprocessableEntity(Vec<FieldError>),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Authentication required")]
+    Unauthorized,
+
+    #[error("Too many requests: {message}")]
+    TooManyRequests { message: String, retry_after_secs: u64 },
+
+    #[error("Internal server error: {0}")]
+    InternalServerError(String),
+
+}
+
+impl AppError {
+    /// Stable name of the error kind, for logs.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::DatabaseError(_) => "database_error",
+            AppError::NotFound(_) => "not_found",
+            AppError::ValidationError(_) => "validation_error",
+            AppError::UnprocessableEntity(_) => "unprocessable_entity",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::Unauthorized => "unauthorized",
+            AppError::TooManyRequests { .. } => "too_many_requests",
+            AppError::InternalServerError(_) => "internal_server_error",
+        }
+    }
+}
+
+
+#[rocket::async_trait]
+impl<'r> Responder<'r, 'static> for AppError {
+    fn respond_to(self, req: &'r Request<'_>) -> rocket::response::Result<'static> {
+        let locale = Locale::from_request_parts(req).unwrap_or(Locale::En);
+        req.local_cache(|| ReportedErrorCode(Some(self.code())));
+
+        let mut retry_after = None;
+        let (status, body) = match self {
+            AppError::UnprocessableEntity(fields) => {
+                let fields: Vec<FieldError> = fields
+                    .into_iter()
+                    .map(|field| FieldError {
+                        message: locale.translate(&field.message),
+                        ..field
+                    })
+                    .collect();
+                (
+                    Status::UnprocessableEntity,
+                    json!({ "error": locale.translate("Validation failed"), "fields": fields }),
+                )
+            }
+            AppError::TooManyRequests { message, retry_after_secs } => {
+                retry_after = Some(retry_after_secs);
+                (
+                    Status::TooManyRequests,
+                    json!({ "error": locale.translate(&message), "retry_after": retry_after_secs }),
+                )
+            }
+            // Database and internal failures keep their details in the logs and the error
+            // report, not the response.
+            AppError::DatabaseError(_) | AppError::InternalServerError(_) => {
+                tracing::error!(error = %self, error_code = self.code(), "Request failed");
+                req.local_cache(|| ReportedError(Some(self.to_string())));
+                (
+                    Status::InternalServerError,
+                    json!({ "error": locale.translate("Internal server error") }),
+                )
+            }
+            AppError::NotFound(msg) => (Status::NotFound, json!({ "error": locale.translate(&msg) })),
+            AppError::ValidationError(msg) => (Status::BadRequest, json!({ "error": locale.translate(&msg) })),
+            AppError::Forbidden(msg) => (Status::Forbidden, json!({ "error": locale.translate(&msg) })),
+            AppError::Unauthorized => (
+                Status::Unauthorized,
+                json!({ "error": locale.translate("Authentication required") }),
+            ),
+        };
+
+        let mut response = Response::build_from(Json(body).respond_to(req)?);
+        response.status(status);
+        if let Some(retry_after_secs) = retry_after {
+            response.header(Header::new("Retry-After", retry_after_secs.to_string()));
+        }
+        response.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+
+    #[get("/limited")]
+    fn limited_route() -> Result<(), AppError> {
+        Err(AppError::TooManyRequests {
+            message: "Too many requests".to_string(),
+            retry_after_secs: 30,
+        })
+    }
+
+    #[get("/broken")]
+    fn broken_route() -> Result<(), AppError> {
+        Err(AppError::InternalServerError("connection pool exhausted".to_string()))
+    }
+
+    #[get("/missing")]
+    fn missing_route() -> Result<(), AppError> {
+        Err(AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    fn client() -> Client {
+        let rocket = rocket::build().mount("/", routes![limited_route, broken_route, missing_route]);
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_too_many_requests_sets_retry_after() {
+        let client = client();
+        let response = client.get("/limited").dispatch();
+        assert_eq!(response.status(), Status::TooManyRequests);
+        assert_eq!(response.headers().get_one("Retry-After"), Some("30"));
+    }
+
+    #[test]
+    fn test_internal_errors_hide_details() {
+        let client = client();
+        let response = client.get("/broken").dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        let body = response.into_string().unwrap();
+        assert!(body.contains("Internal server error"));
+        assert!(!body.contains("pool"));
+
+        let response = client.get("/missing").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert!(response.into_string().unwrap().contains("Campaign not found"));
+    }
+}
@@ -0,0 +1,330 @@
+use std::io::{Cursor, Write};
+
+use flate2::Compression as GzipLevel;
+use flate2::write::GzEncoder;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::{ContentType, Header};
+use rocket::serde::Deserialize;
+use rocket::{Request, Response};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct CompressionConfig {
+    pub min_size: usize,
+    pub content_types: Vec<String>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        CompressionConfig {
+            min_size: 1024,
+            content_types: vec![
+                "application/json".to_string(),
+                "application/xml".to_string(),
+                "text/*".to_string(),
+            ],
+        }
+    }
+}
+
+impl CompressionConfig {
+    fn allows(&self, content_type: &ContentType) -> bool {
+        // Event streams stay open; buffering one to compress it would hold back every event.
+        if content_type.top() == "text" && content_type.sub() == "event-stream" {
+            return false;
+        }
+        self.content_types
+            .iter()
+            .any(|allowed| match allowed.split_once('/') {
+                Some((top, "*")) => content_type.top().as_str().eq_ignore_ascii_case(top),
+                Some((top, sub)) => {
+                    content_type.top().as_str().eq_ignore_ascii_case(top)
+                        && content_type.sub().as_str().eq_ignore_ascii_case(sub)
+                }
+                None => false,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Brotli,
+    Gzip,
+}
+
+impl Encoding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Encoding::Brotli => "br",
+            Encoding::Gzip => "gzip",
+        }
+    }
+
+    // Picks the best supported encoding from an Accept-Encoding header, preferring br on ties.
+    fn negotiate(accept_encoding: &str) -> Option<Encoding> {
+        let mut best: Option<(Encoding, f32)> = None;
+        for item in accept_encoding.split(',') {
+            let mut parts = item.split(';');
+            let name = parts.next().unwrap_or("").trim();
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let candidates: &[Encoding] = match name.to_ascii_lowercase().as_str() {
+                "br" => &[Encoding::Brotli],
+                "gzip" | "x-gzip" => &[Encoding::Gzip],
+                "*" => &[Encoding::Brotli, Encoding::Gzip],
+                _ => &[],
+            };
+            for &encoding in candidates {
+                if quality <= 0.0 {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some((current, q)) => {
+                        quality > q
+                            || (quality == q
+                                && encoding == Encoding::Brotli
+                                && current != Encoding::Brotli)
+                    }
+                };
+                if better {
+                    best = Some((encoding, quality));
+                }
+            }
+        }
+        best.map(|(encoding, _)| encoding)
+    }
+
+    fn compress(self, body: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Encoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), GzipLevel::default());
+                encoder.write_all(body)?;
+                encoder.finish()
+            }
+            Encoding::Brotli => {
+                let mut output = Vec::new();
+                {
+                    let mut writer = brotli::CompressorWriter::new(&mut output, 4096, 5, 22);
+                    writer.write_all(body)?;
+                }
+                Ok(output)
+            }
+        }
+    }
+}
+
+pub struct Compression {
+    config: CompressionConfig,
+}
+
+impl Compression {
+    pub fn new(config: CompressionConfig) -> Self {
+        Compression { config }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for Compression {
+    fn info(&self) -> Info {
+        Info {
+            name: "Response compression",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if res.headers().contains("Content-Encoding") {
+            return;
+        }
+        match res.content_type() {
+            Some(content_type) if self.config.allows(&content_type) => {}
+            _ => return,
+        }
+        // Caches must key on Accept-Encoding even when this response goes out uncompressed.
+        res.adjoin_header(Header::new("Vary", "Accept-Encoding"));
+        let Some(encoding) = req
+            .headers()
+            .get("Accept-Encoding")
+            .find_map(Encoding::negotiate)
+        else {
+            return;
+        };
+
+        let body = match res.body_mut().to_bytes().await {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read response body for compression");
+                return;
+            }
+        };
+
+        if body.len() < self.config.min_size {
+            res.set_sized_body(body.len(), Cursor::new(body));
+            return;
+        }
+
+        match encoding.compress(&body) {
+            Ok(compressed) => {
+                res.set_header(Header::new("Content-Encoding", encoding.as_str()));
+                res.set_sized_body(compressed.len(), Cursor::new(compressed));
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to compress response body");
+                res.set_sized_body(body.len(), Cursor::new(body));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+    use std::io::Read;
+
+    fn donation_list_json() -> String {
+        let donations: Vec<String> = (1..=200)
+            .map(|id| {
+                format!(
+                    r#"{{"id":{},"user_id":{},"campaign_id":10,"amount":50000.0,"message":"Semangat!","status":"settled","created_at":"2025-04-11T10:00:00Z"}}"#,
+                    id,
+                    id % 17
+                )
+            })
+            .collect();
+        format!("[{}]", donations.join(","))
+    }
+
+    #[get("/campaigns/10/donations")]
+    fn donation_list() -> (ContentType, String) {
+        (ContentType::JSON, donation_list_json())
+    }
+
+    #[get("/small")]
+    fn small() -> (ContentType, &'static str) {
+        (ContentType::JSON, "[]")
+    }
+
+    #[get("/image")]
+    fn image() -> (ContentType, Vec<u8>) {
+        (ContentType::PNG, vec![0u8; 4096])
+    }
+
+    #[get("/events")]
+    fn events() -> (ContentType, String) {
+        (ContentType::EventStream, "data: {}\n\n".repeat(512))
+    }
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![donation_list, small, image, events])
+            .attach(Compression::new(CompressionConfig::default()));
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_negotiate_prefers_highest_quality() {
+        assert_eq!(Encoding::negotiate("gzip, br"), Some(Encoding::Brotli));
+        assert_eq!(
+            Encoding::negotiate("gzip;q=1.0, br;q=0.5"),
+            Some(Encoding::Gzip)
+        );
+        assert_eq!(Encoding::negotiate("br;q=0, gzip"), Some(Encoding::Gzip));
+        assert_eq!(Encoding::negotiate("*"), Some(Encoding::Brotli));
+        assert_eq!(Encoding::negotiate("identity"), None);
+    }
+
+    #[test]
+    fn test_donation_list_payload_shrinks_with_gzip() {
+        let client = client();
+        let original = donation_list_json();
+        let response = client
+            .get("/campaigns/10/donations")
+            .header(Header::new("Accept-Encoding", "gzip"))
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("Content-Encoding"), Some("gzip"));
+        let compressed = response.into_bytes().unwrap();
+        assert!(compressed.len() * 5 < original.len());
+
+        let mut decoded = String::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_donation_list_payload_shrinks_with_brotli() {
+        let client = client();
+        let original = donation_list_json();
+        let response = client
+            .get("/campaigns/10/donations")
+            .header(Header::new("Accept-Encoding", "gzip, deflate, br"))
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("Content-Encoding"), Some("br"));
+        let compressed = response.into_bytes().unwrap();
+        assert!(compressed.len() * 5 < original.len());
+
+        let mut decoded = String::new();
+        brotli::Decompressor::new(&compressed[..], 4096)
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_skips_without_accept_encoding() {
+        let client = client();
+        let response = client.get("/campaigns/10/donations").dispatch();
+
+        assert!(response.headers().get_one("Content-Encoding").is_none());
+        assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+        assert_eq!(response.into_string().unwrap(), donation_list_json());
+    }
+
+    #[test]
+    fn test_skips_small_bodies() {
+        let client = client();
+        let response = client
+            .get("/small")
+            .header(Header::new("Accept-Encoding", "gzip"))
+            .dispatch();
+
+        assert!(response.headers().get_one("Content-Encoding").is_none());
+        assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+        assert_eq!(response.into_string().unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_skips_content_types_outside_allowlist() {
+        let client = client();
+        let response = client
+            .get("/image")
+            .header(Header::new("Accept-Encoding", "gzip"))
+            .dispatch();
+
+        assert!(response.headers().get_one("Content-Encoding").is_none());
+        assert!(response.headers().get_one("Vary").is_none());
+        assert_eq!(response.into_bytes().unwrap().len(), 4096);
+    }
+
+    #[test]
+    fn test_skips_event_streams() {
+        let client = client();
+        let response = client
+            .get("/events")
+            .header(Header::new("Accept-Encoding", "gzip"))
+            .dispatch();
+
+        assert!(response.headers().get_one("Content-Encoding").is_none());
+        assert!(response.into_string().unwrap().starts_with("data: {}"));
+    }
+}
@@ -0,0 +1,308 @@
+use std::io::Cursor;
+use std::str::FromStr;
+
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::{Header, Method, Status};
+use rocket::serde::Deserialize;
+use rocket::{Request, Response};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub allow_credentials: bool,
+    pub max_age_secs: u32,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        CorsConfig {
+            allowed_origins: Vec::new(),
+            allowed_methods: ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"]
+                .map(String::from)
+                .to_vec(),
+            allowed_headers: [
+                "Accept",
+                "Accept-Language",
+                "Authorization",
+                "Content-Type",
+                "X-Request-Id",
+            ]
+            .map(String::from)
+            .to_vec(),
+            allow_credentials: true,
+            max_age_secs: 3600,
+        }
+    }
+}
+
+impl CorsConfig {
+    pub fn problems(&self, release: bool) -> Vec<String> {
+        let mut problems = Vec::new();
+        if release && self.allowed_origins.is_empty() {
+            problems
+                .push("cors.allowed_origins must list every allowed origin in release".to_string());
+        }
+        for origin in &self.allowed_origins {
+            if !is_valid_origin(origin) {
+                problems.push(format!(
+                    "cors.allowed_origins: '{}' must be a scheme and host such as https://example.com",
+                    origin
+                ));
+            }
+        }
+        for method in &self.allowed_methods {
+            if Method::from_str(method).is_err() {
+                problems.push(format!(
+                    "cors.allowed_methods: '{}' is not an HTTP method",
+                    method
+                ));
+            }
+        }
+        problems
+    }
+}
+
+fn is_valid_origin(origin: &str) -> bool {
+    let host = origin
+        .strip_prefix("https://")
+        .or_else(|| origin.strip_prefix("http://"));
+    match host {
+        Some(host) => !host.is_empty() && !host.contains('/'),
+        None => false,
+    }
+}
+
+fn is_localhost(origin: &str) -> bool {
+    let Some(host) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if port.parse::<u16>().is_ok() => host,
+        _ => host,
+    };
+    host == "localhost" || host == "127.0.0.1"
+}
+
+pub struct Cors {
+    config: CorsConfig,
+    allow_localhost: bool,
+    public_paths: Vec<String>,
+}
+
+impl Cors {
+    pub fn new(config: CorsConfig) -> Self {
+        Cors {
+            config,
+            allow_localhost: false,
+            public_paths: Vec::new(),
+        }
+    }
+
+    // Routes under `prefix` set their own CORS headers (e.g. `*` for embeds) and are
+    // left alone.
+    pub fn with_public_path(mut self, prefix: impl Into<String>) -> Self {
+        self.public_paths.push(prefix.into());
+        self
+    }
+
+    // Development builds accept any localhost origin on top of the configured list.
+    pub fn with_localhost(mut self, allow_localhost: bool) -> Self {
+        self.allow_localhost = allow_localhost;
+        self
+    }
+
+    fn allows(&self, origin: &str) -> bool {
+        self.config
+            .allowed_origins
+            .iter()
+            .any(|allowed| allowed == origin)
+            || (self.allow_localhost && is_localhost(origin))
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for Cors {
+    fn info(&self) -> Info {
+        Info {
+            name: "CORS",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let path = req.uri().path();
+        if self
+            .public_paths
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+        {
+            return;
+        }
+        res.adjoin_header(Header::new("Vary", "Origin"));
+        let Some(origin) = req.headers().get_one("Origin") else {
+            return;
+        };
+        if !self.allows(origin) {
+            return;
+        }
+
+        res.set_header(Header::new(
+            "Access-Control-Allow-Origin",
+            origin.to_string(),
+        ));
+        if self.config.allow_credentials {
+            res.set_header(Header::new("Access-Control-Allow-Credentials", "true"));
+        }
+
+        let preflight = req.method() == Method::Options
+            && req.headers().contains("Access-Control-Request-Method");
+        if preflight {
+            res.set_status(Status::NoContent);
+            res.set_sized_body(0, Cursor::new(Vec::new()));
+            res.set_header(Header::new(
+                "Access-Control-Allow-Methods",
+                self.config.allowed_methods.join(", "),
+            ));
+            res.set_header(Header::new(
+                "Access-Control-Allow-Headers",
+                self.config.allowed_headers.join(", "),
+            ));
+            res.set_header(Header::new(
+                "Access-Control-Max-Age",
+                self.config.max_age_secs.to_string(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::blocking::{Client, LocalResponse};
+    use rocket::{get, routes};
+
+    #[get("/campaigns")]
+    fn campaigns() -> &'static str {
+        "[]"
+    }
+
+    #[derive(rocket::Responder)]
+    struct Embeddable {
+        inner: &'static str,
+        allow_origin: Header<'static>,
+    }
+
+    #[get("/widgets/1")]
+    fn widget() -> Embeddable {
+        Embeddable {
+            inner: "{}",
+            allow_origin: Header::new("Access-Control-Allow-Origin", "*"),
+        }
+    }
+
+    fn client(allow_localhost: bool) -> Client {
+        let config = CorsConfig {
+            allowed_origins: vec!["https://donasi.example.com".to_string()],
+            ..CorsConfig::default()
+        };
+        let rocket = rocket::build()
+            .mount("/", routes![campaigns, widget])
+            .attach(
+                Cors::new(config)
+                    .with_localhost(allow_localhost)
+                    .with_public_path("/widgets/"),
+            );
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    fn allowed_origin<'a>(response: &'a LocalResponse<'_>) -> Option<&'a str> {
+        response.headers().get_one("Access-Control-Allow-Origin")
+    }
+
+    #[test]
+    fn test_preflight_from_configured_origin() {
+        let client = client(false);
+        let response = client
+            .options("/campaigns")
+            .header(Header::new("Origin", "https://donasi.example.com"))
+            .header(Header::new("Access-Control-Request-Method", "PATCH"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::NoContent);
+        assert_eq!(
+            allowed_origin(&response),
+            Some("https://donasi.example.com")
+        );
+        assert!(
+            response
+                .headers()
+                .get_one("Access-Control-Allow-Methods")
+                .unwrap()
+                .contains("PATCH")
+        );
+    }
+
+    #[test]
+    fn test_unlisted_origin_gets_no_cors_headers() {
+        let client = client(false);
+        let response = client
+            .get("/campaigns")
+            .header(Header::new("Origin", "https://evil.example.com"))
+            .dispatch();
+        assert_eq!(allowed_origin(&response), None);
+
+        let response = client
+            .get("/campaigns")
+            .header(Header::new("Origin", "http://localhost:5173"))
+            .dispatch();
+        assert_eq!(allowed_origin(&response), None);
+    }
+
+    #[test]
+    fn test_localhost_allowed_in_development() {
+        let client = client(true);
+        let response = client
+            .get("/campaigns")
+            .header(Header::new("Origin", "http://localhost:5173"))
+            .dispatch();
+
+        assert_eq!(allowed_origin(&response), Some("http://localhost:5173"));
+        assert_eq!(
+            response
+                .headers()
+                .get_one("Access-Control-Allow-Credentials"),
+            Some("true")
+        );
+    }
+
+    #[test]
+    fn test_public_paths_keep_their_own_headers() {
+        let client = client(false);
+        let response = client
+            .get("/widgets/1")
+            .header(Header::new("Origin", "https://donasi.example.com"))
+            .dispatch();
+
+        assert_eq!(allowed_origin(&response), Some("*"));
+        assert_eq!(
+            response
+                .headers()
+                .get_one("Access-Control-Allow-Credentials"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_release_requires_explicit_origins() {
+        let problems = CorsConfig::default().problems(true);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("must list every allowed origin"));
+        assert!(CorsConfig::default().problems(false).is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+use std::sync::Arc;
+
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::Header;
+use rocket::serde::Deserialize;
+use rocket::{Data, Request, Response};
+use sentry::protocol::{Breadcrumb, Event, Map, Value};
+use sentry::{ClientInitGuard, ClientOptions, Hub, Level};
+use uuid::Uuid;
+
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
+const SENSITIVE_KEYS: &[&str] = &["email", "amount", "balance", "account_number"];
+
+// Reporting stays off unless a DSN is configured.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct ErrorReportingConfig {
+    pub dsn: Option<String>,
+    pub environment: Option<String>,
+    pub sample_rate: f32,
+}
+
+impl Default for ErrorReportingConfig {
+    fn default() -> Self {
+        ErrorReportingConfig {
+            dsn: None,
+            environment: None,
+            sample_rate: 1.0,
+        }
+    }
+}
+
+impl ErrorReportingConfig {
+    fn client_options(&self) -> ClientOptions {
+        ClientOptions {
+            release: sentry::release_name!(),
+            environment: self.environment.clone().map(Into::into),
+            sample_rate: self.sample_rate,
+            before_send: Some(Arc::new(|event| Some(scrub_event(event)))),
+            before_breadcrumb: Some(Arc::new(|breadcrumb| Some(scrub_breadcrumb(breadcrumb)))),
+            ..Default::default()
+        }
+    }
+}
+
+/// Per-request id, taken from an incoming `X-Request-Id` or generated.
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+/// Set by the authentication guard so 5xx reports can name the user.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportedUserId(pub Option<i32>);
+
+/// Set by the error responder with the underlying error before it is replaced by
+/// the generic "Internal server error" body.
+#[derive(Debug, Clone)]
+pub struct ReportedError(pub Option<String>);
+
+pub struct ErrorReporting {
+    hub: Arc<Hub>,
+    _guard: Option<ClientInitGuard>,
+}
+
+impl ErrorReporting {
+    // Installs the global client (which also captures panics) when a DSN is set.
+    pub fn init(config: ErrorReportingConfig) -> Self {
+        let guard = config.dsn.as_deref().map(|dsn| {
+            let mut options = config.client_options();
+            options.dsn = dsn.parse().ok();
+            if options.dsn.is_none() {
+                tracing::warn!("Invalid error reporting DSN; reporting disabled");
+            }
+            sentry::init(options)
+        });
+        ErrorReporting {
+            hub: Hub::main(),
+            _guard: guard,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_hub(hub: Arc<Hub>) -> Self {
+        ErrorReporting { hub, _guard: None }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for ErrorReporting {
+    fn info(&self) -> Info {
+        Info {
+            name: "Error reporting",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
+        let request_id = req
+            .headers()
+            .get_one(REQUEST_ID_HEADER)
+            .map(str::to_string)
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        req.local_cache(|| RequestId(request_id));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let request_id = req.local_cache(|| RequestId(Uuid::new_v4().to_string()));
+        res.set_header(Header::new(REQUEST_ID_HEADER, request_id.0.clone()));
+
+        if res.status().code < 500 {
+            return;
+        }
+
+        let route = req
+            .route()
+            .map(|route| route.uri.to_string())
+            .unwrap_or_else(|| req.uri().path().to_string());
+        let user_id = req.local_cache(|| ReportedUserId(None)).0;
+        let error = req.local_cache(|| ReportedError(None)).0.clone();
+        let message = match error {
+            Some(error) => format!(
+                "{} {} returned {}: {}",
+                req.method(),
+                route,
+                res.status(),
+                error
+            ),
+            None => format!("{} {} returned {}", req.method(), route, res.status()),
+        };
+
+        self.hub.with_scope(
+            |scope| {
+                scope.set_tag("route", &route);
+                scope.set_tag("method", req.method());
+                scope.set_tag("request_id", &request_id.0);
+                scope.set_tag("status", res.status().code);
+                if let Some(user_id) = user_id {
+                    scope.set_user(Some(sentry::User {
+                        id: Some(user_id.to_string()),
+                        ..Default::default()
+                    }));
+                }
+            },
+            || {
+                self.hub
+                    .capture_message(&scrub_text(&message), Level::Error)
+            },
+        );
+    }
+}
+
+// Emails and formatted amounts must not leave the process in reports or breadcrumbs.
+fn scrub_text(text: &str) -> String {
+    let mut scrubbed = Vec::new();
+    let mut after_currency = false;
+    for word in text.split(' ') {
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        let replacement = if is_email(core) {
+            Some("[email]")
+        } else if after_currency && core.starts_with(|c: char| c.is_ascii_digit()) {
+            Some("[amount]")
+        } else {
+            None
+        };
+        after_currency = core == "Rp" || core == "IDR";
+        scrubbed.push(match replacement {
+            Some(replacement) if !core.is_empty() => word.replacen(core, replacement, 1),
+            _ => word.to_string(),
+        });
+    }
+    scrubbed.join(" ")
+}
+
+fn is_email(word: &str) -> bool {
+    match word.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && domain.contains('.'),
+        None => false,
+    }
+}
+
+fn scrub_map(map: &mut Map<String, Value>) {
+    for (key, value) in map.iter_mut() {
+        let key = key.to_ascii_lowercase();
+        if SENSITIVE_KEYS
+            .iter()
+            .any(|sensitive| key.contains(sensitive))
+        {
+            *value = Value::String("[redacted]".to_string());
+        } else if let Value::String(text) = value {
+            *text = scrub_text(text);
+        }
+    }
+}
+
+fn scrub_breadcrumb(mut breadcrumb: Breadcrumb) -> Breadcrumb {
+    breadcrumb.message = breadcrumb.message.as_deref().map(scrub_text);
+    scrub_map(&mut breadcrumb.data);
+    breadcrumb
+}
+
+fn scrub_event(mut event: Event<'static>) -> Event<'static> {
+    event.message = event.message.as_deref().map(scrub_text);
+    if let Some(message) = event.logentry.as_mut() {
+        message.message = scrub_text(&message.message);
+    }
+    for exception in event.exception.values.iter_mut() {
+        exception.value = exception.value.as_deref().map(scrub_text);
+    }
+    for breadcrumb in event.breadcrumbs.values.iter_mut() {
+        *breadcrumb = scrub_breadcrumb(breadcrumb.clone());
+    }
+    scrub_map(&mut event.extra);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::request::{FromRequest, Outcome};
+    use rocket::response::{self, Responder};
+    use rocket::{get, routes};
+    use sentry::Scope;
+    use sentry::test::TestTransport;
+
+    // Stand-ins for the auth guard and the AppError responder.
+    struct SignedInUser;
+
+    #[rocket::async_trait]
+    impl<'r> FromRequest<'r> for SignedInUser {
+        type Error = ();
+
+        async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+            req.local_cache(|| ReportedUserId(Some(42)));
+            Outcome::Success(SignedInUser)
+        }
+    }
+
+    struct DatabaseDown;
+
+    impl<'r> Responder<'r, 'static> for DatabaseDown {
+        fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+            req.local_cache(|| {
+                ReportedError(Some("pool timed out for donor@example.com".to_string()))
+            });
+            Err(Status::InternalServerError)
+        }
+    }
+
+    #[get("/fail")]
+    fn fail(_user: SignedInUser) -> DatabaseDown {
+        DatabaseDown
+    }
+
+    #[get("/ok")]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    fn client() -> (Client, Arc<TestTransport>) {
+        let transport = TestTransport::new();
+        let mut options = ErrorReportingConfig::default().client_options();
+        options.dsn = Some("https://public@example.com/1".parse().unwrap());
+        options.transport = Some(Arc::new(transport.clone()));
+        let hub = Arc::new(Hub::new(
+            Some(Arc::new(options.into())),
+            Arc::new(Scope::default()),
+        ));
+
+        let rocket = rocket::build()
+            .mount("/", routes![fail, ok])
+            .attach(ErrorReporting::with_hub(hub));
+        (
+            Client::tracked(rocket).expect("valid rocket instance"),
+            transport,
+        )
+    }
+
+    #[test]
+    fn test_scrub_text_hides_emails_and_amounts() {
+        assert_eq!(
+            scrub_text("Donation of Rp 50.000 by donor@example.com failed"),
+            "Donation of Rp [amount] by [email] failed"
+        );
+        assert_eq!(scrub_text("IDR 50,000.00"), "IDR [amount]");
+        assert_eq!(scrub_text("campaign 10 not found"), "campaign 10 not found");
+    }
+
+    #[test]
+    fn test_5xx_is_reported_with_request_context() {
+        let (client, transport) = client();
+        let response = client
+            .get("/fail")
+            .header(Header::new(REQUEST_ID_HEADER, "req-123"))
+            .dispatch();
+
+        assert_eq!(
+            response.headers().get_one(REQUEST_ID_HEADER),
+            Some("req-123")
+        );
+        let events = transport.fetch_and_clear_events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.tags.get("route").map(String::as_str), Some("/fail"));
+        assert_eq!(
+            event.tags.get("request_id").map(String::as_str),
+            Some("req-123")
+        );
+        assert_eq!(
+            event.user.as_ref().and_then(|user| user.id.as_deref()),
+            Some("42")
+        );
+        let message = event.message.as_deref().unwrap_or_default();
+        assert!(message.contains("[email]"));
+        assert!(!message.contains("donor@example.com"));
+    }
+
+    #[test]
+    fn test_successful_responses_are_not_reported() {
+        let (client, transport) = client();
+        let response = client.get("/ok").dispatch();
+
+        assert!(response.headers().get_one(REQUEST_ID_HEADER).is_some());
+        assert!(transport.fetch_and_clear_events().is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod compression;
+pub mod cors;
+pub mod error_reporting;
+pub mod request_logging;
+pub mod request_metrics;
+pub mod security_headers;
+pub mod slo;
@@ -0,0 +1,177 @@
+use std::time::Instant;
+
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+use uuid::Uuid;
+
+use super::error_reporting::{ReportedUserId, RequestId};
+use super::request_metrics::RequestTimer;
+
+/// Set by the error responder with the kind of error, e.g. `not_found`.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportedErrorCode(pub Option<&'static str>);
+
+/// Logs one line per request. Attach after `ErrorReporting`, which assigns the
+/// request id this reuses.
+pub struct RequestLogging;
+
+#[rocket::async_trait]
+impl Fairing for RequestLogging {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request logging",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
+        req.local_cache(|| RequestTimer(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let latency_ms = req
+            .local_cache(|| RequestTimer(Instant::now()))
+            .0
+            .elapsed()
+            .as_micros() as f64
+            / 1000.0;
+        let request_id = req
+            .local_cache(|| RequestId(Uuid::new_v4().to_string()))
+            .0
+            .as_str();
+        let user_id = req.local_cache(|| ReportedUserId(None)).0;
+        let error_code = req.local_cache(|| ReportedErrorCode(None)).0;
+        let route = req
+            .route()
+            .map(|route| route.uri.origin.path().to_string())
+            .unwrap_or_else(|| req.uri().path().to_string());
+        let method = req.method().as_str();
+        let status = res.status().code;
+
+        // Levels must be constants, hence one arm per level.
+        macro_rules! log_request {
+            ($level:ident) => {
+                tracing::$level!(
+                    request_id,
+                    user_id,
+                    method,
+                    route = route.as_str(),
+                    status,
+                    latency_ms,
+                    error_code,
+                    "{} {} {}",
+                    method,
+                    route,
+                    status
+                )
+            };
+        }
+        match status {
+            500.. => log_request!(error),
+            400..=499 => log_request!(warn),
+            _ => log_request!(info),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fairing::error_reporting::ErrorReporting;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+    use rocket::request::{FromRequest, Outcome};
+    use rocket::serde::json::{self, Value};
+    use rocket::{get, routes};
+    use sentry::Hub;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // Stand-in for the auth guard.
+    struct SignedInUser;
+
+    #[rocket::async_trait]
+    impl<'r> FromRequest<'r> for SignedInUser {
+        type Error = ();
+
+        async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+            req.local_cache(|| ReportedUserId(Some(42)));
+            Outcome::Success(SignedInUser)
+        }
+    }
+
+    #[get("/campaigns/<_id>")]
+    fn get_campaign_route(_user: SignedInUser, _id: i32) -> (Status, &'static str) {
+        (Status::NotFound, "{}")
+    }
+
+    // Stand-in for the AppError responder.
+    impl<'r> rocket::response::Responder<'r, 'static> for ReportedErrorCode {
+        fn respond_to(self, req: &'r Request<'_>) -> rocket::response::Result<'static> {
+            req.local_cache(|| self);
+            Err(Status::NotFound)
+        }
+    }
+
+    #[get("/saved-searches/<_id>")]
+    fn get_saved_search_route(_id: i32) -> ReportedErrorCode {
+        ReportedErrorCode(Some("not_found"))
+    }
+
+    #[test]
+    fn test_logs_one_json_line_per_request() {
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .flatten_event(true)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let rocket = rocket::build()
+                .mount("/api", routes![get_campaign_route, get_saved_search_route])
+                .attach(ErrorReporting::with_hub(Arc::new(Hub::new(
+                    None,
+                    Default::default(),
+                ))))
+                .attach(RequestLogging);
+            let client = Client::tracked(rocket).expect("valid rocket instance");
+            client
+                .get("/api/campaigns/7")
+                .header(Header::new("X-Request-Id", "req-123"))
+                .dispatch();
+            client.get("/api/saved-searches/3").dispatch();
+        });
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<Value> = output
+            .lines()
+            .filter_map(|line| json::from_str::<Value>(line).ok())
+            .filter(|line| line.get("route").is_some())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "WARN");
+        assert_eq!(lines[0]["request_id"], "req-123");
+        assert_eq!(lines[0]["user_id"], 42);
+        assert_eq!(lines[0]["route"], "/api/campaigns/<_id>");
+        assert_eq!(lines[0]["status"], 404);
+        assert!(lines[0]["latency_ms"].is_number());
+        assert!(lines[0].get("error_code").is_none());
+        assert!(lines[1].get("user_id").is_none());
+        assert_eq!(lines[1]["error_code"], "not_found");
+    }
+}
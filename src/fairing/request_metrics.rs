@@ -0,0 +1,283 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use dashmap::DashMap;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+
+use super::error_reporting::ReportedUserId;
+use super::slo::{self, SloObjective};
+
+/// Upper bounds of the latency histogram buckets, in seconds.
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+// Requests that matched no route share one label so stray paths can't grow the series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct SeriesKey {
+    route: String,
+    // The route path without its mount point, which is what SLO objectives name.
+    pub(crate) path: String,
+    pub(crate) method: &'static str,
+    pub(crate) status_class: &'static str,
+    auth: &'static str,
+}
+
+pub(crate) struct Series {
+    pub(crate) buckets: Vec<AtomicU64>,
+    pub(crate) count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl Series {
+    fn new() -> Self {
+        Series {
+            buckets: LATENCY_BUCKETS.iter().map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum_micros: AtomicU64::new(0),
+        }
+    }
+}
+
+/// When the request arrived. Shared by every fairing that measures latency, so
+/// logs and metrics report the same duration.
+pub(crate) struct RequestTimer(pub(crate) Instant);
+
+fn status_class(code: u16) -> &'static str {
+    match code {
+        100..=199 => "1xx",
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        _ => "5xx",
+    }
+}
+
+/// Request count and latency for every route, labelled by route template, method,
+/// status class and whether the caller was signed in. SLO counters are derived from
+/// the same histogram rather than timed separately.
+#[derive(Default)]
+pub struct RequestMetrics {
+    series: DashMap<SeriesKey, Series>,
+    objectives: Vec<SloObjective>,
+}
+
+impl RequestMetrics {
+    pub fn new() -> Self {
+        RequestMetrics::default()
+    }
+
+    /// Panics if an objective's latency threshold isn't one of `LATENCY_BUCKETS`.
+    pub fn with_objectives(mut self, objectives: &[SloObjective]) -> Self {
+        for objective in objectives {
+            assert!(
+                slo::latency_bucket(objective).is_some(),
+                "SLO {} needs a latency threshold that is a histogram bucket bound",
+                objective.name
+            );
+        }
+        self.objectives = objectives.to_vec();
+        self
+    }
+
+    pub fn objectives(&self) -> &[SloObjective] {
+        &self.objectives
+    }
+
+    fn record(&self, key: SeriesKey, elapsed_secs: f64) {
+        let series = self.series.entry(key).or_insert_with(Series::new);
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(&series.buckets) {
+            if elapsed_secs <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        series.count.fetch_add(1, Ordering::Relaxed);
+        series
+            .sum_micros
+            .fetch_add((elapsed_secs * 1_000_000.0) as u64, Ordering::Relaxed);
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        let mut series: Vec<(SeriesKey, Vec<u64>, u64, u64)> = self
+            .series
+            .iter()
+            .map(|entry| {
+                let value = entry.value();
+                (
+                    entry.key().clone(),
+                    value
+                        .buckets
+                        .iter()
+                        .map(|bucket| bucket.load(Ordering::Relaxed))
+                        .collect(),
+                    value.count.load(Ordering::Relaxed),
+                    value.sum_micros.load(Ordering::Relaxed),
+                )
+            })
+            .collect();
+        series.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut out = String::from(
+            "# HELP http_requests_total Requests handled, by route template\n# TYPE http_requests_total counter\n",
+        );
+        for (key, _, count, _) in &series {
+            out.push_str(&format!(
+                "http_requests_total{{{}}} {}\n",
+                labels(key),
+                count
+            ));
+        }
+        out.push_str(
+            "# HELP http_request_duration_seconds Time from request to response, by route template\n# TYPE http_request_duration_seconds histogram\n",
+        );
+        for (key, buckets, count, sum_micros) in &series {
+            let labels = labels(key);
+            for (bound, value) in LATENCY_BUCKETS.iter().zip(buckets) {
+                out.push_str(&format!(
+                    "http_request_duration_seconds_bucket{{{},le=\"{}\"}} {}\n",
+                    labels, bound, value
+                ));
+            }
+            out.push_str(&format!(
+                "http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}\n",
+                labels, count
+            ));
+            out.push_str(&format!(
+                "http_request_duration_seconds_sum{{{}}} {}\n",
+                labels,
+                *sum_micros as f64 / 1_000_000.0
+            ));
+            out.push_str(&format!(
+                "http_request_duration_seconds_count{{{}}} {}\n",
+                labels, count
+            ));
+        }
+        out.push_str(&slo::to_prometheus(&self.objectives, &self.series));
+        out
+    }
+}
+
+fn labels(key: &SeriesKey) -> String {
+    format!(
+        "route=\"{}\",method=\"{}\",status=\"{}\",auth=\"{}\"",
+        key.route, key.method, key.status_class, key.auth
+    )
+}
+
+#[rocket::async_trait]
+impl Fairing for RequestMetrics {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request metrics",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
+        req.local_cache(|| RequestTimer(Instant::now()));
+    }
+
+    // The auth guard records the signed-in user while the handler runs, so by now it
+    // tells authenticated requests from anonymous ones.
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let started = req.local_cache(|| RequestTimer(Instant::now())).0;
+        let (route, path) = req
+            .route()
+            .map(|route| {
+                (
+                    route.uri.origin.path().to_string(),
+                    route.uri.unmounted_origin.path().to_string(),
+                )
+            })
+            .unwrap_or_else(|| (UNMATCHED_ROUTE.to_string(), UNMATCHED_ROUTE.to_string()));
+        let signed_in = req.local_cache(|| ReportedUserId(None)).0.is_some();
+        self.record(
+            SeriesKey {
+                route,
+                path,
+                method: req.method().as_str(),
+                status_class: status_class(res.status().code),
+                auth: if signed_in {
+                    "authenticated"
+                } else {
+                    "anonymous"
+                },
+            },
+            started.elapsed().as_secs_f64(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::request::{FromRequest, Outcome};
+    use rocket::{get, routes};
+    use std::sync::Arc;
+
+    // Stand-in for the auth guard.
+    struct SignedInUser;
+
+    #[rocket::async_trait]
+    impl<'r> FromRequest<'r> for SignedInUser {
+        type Error = ();
+
+        async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+            req.local_cache(|| ReportedUserId(Some(42)));
+            Outcome::Success(SignedInUser)
+        }
+    }
+
+    #[get("/campaigns/<_id>?<_format>")]
+    fn get_campaign_route(_id: i32, _format: Option<&str>) -> &'static str {
+        "{}"
+    }
+
+    #[get("/me/saved-searches")]
+    fn get_saved_searches_route(_user: SignedInUser) -> Status {
+        Status::ServiceUnavailable
+    }
+
+    fn client() -> (Client, Arc<RequestMetrics>) {
+        let metrics = Arc::new(RequestMetrics::new());
+        let rocket = rocket::build()
+            .mount(
+                "/api",
+                routes![get_campaign_route, get_saved_searches_route],
+            )
+            .attach(metrics.clone());
+        (
+            Client::tracked(rocket).expect("valid rocket instance"),
+            metrics,
+        )
+    }
+
+    #[test]
+    fn test_labels_by_route_template_status_and_auth() {
+        let (client, metrics) = client();
+        client.get("/api/campaigns/1").dispatch();
+        client.get("/api/campaigns/2?format=html").dispatch();
+        client.get("/api/me/saved-searches").dispatch();
+        client.get("/api/campaigns/abc/extra").dispatch();
+
+        let output = metrics.to_prometheus();
+        assert!(output.contains(
+            "http_requests_total{route=\"/api/campaigns/<_id>\",method=\"GET\",status=\"2xx\",auth=\"anonymous\"} 2\n"
+        ));
+        assert!(output.contains(
+            "http_requests_total{route=\"/api/me/saved-searches\",method=\"GET\",status=\"5xx\",auth=\"authenticated\"} 1\n"
+        ));
+        assert!(output.contains(
+            "http_requests_total{route=\"unmatched\",method=\"GET\",status=\"4xx\",auth=\"anonymous\"} 1\n"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_bucket{route=\"/api/campaigns/<_id>\",method=\"GET\",status=\"2xx\",auth=\"anonymous\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(!output.contains("abc"));
+    }
+}
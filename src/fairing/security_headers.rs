@@ -0,0 +1,157 @@
+use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::http::Header;
+use rocket::serde::Deserialize;
+use rocket::shield::Shield;
+use rocket::{Build, Request, Response, Rocket};
+
+// An empty value leaves the corresponding header out.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct SecurityHeadersConfig {
+    pub content_type_options: String,
+    pub frame_options: String,
+    pub referrer_policy: String,
+    pub strict_transport_security: String,
+    pub content_security_policy: String,
+}
+
+impl Default for SecurityHeadersConfig {
+    fn default() -> Self {
+        SecurityHeadersConfig {
+            content_type_options: "nosniff".to_string(),
+            frame_options: "DENY".to_string(),
+            referrer_policy: "strict-origin-when-cross-origin".to_string(),
+            strict_transport_security: "max-age=31536000; includeSubDomains".to_string(),
+            content_security_policy: "default-src 'none'; frame-ancestors 'none'".to_string(),
+        }
+    }
+}
+
+pub struct SecurityHeaders {
+    config: SecurityHeadersConfig,
+}
+
+impl SecurityHeaders {
+    pub fn new(config: SecurityHeadersConfig) -> Self {
+        SecurityHeaders { config }
+    }
+
+    fn headers(&self) -> [(&'static str, &str); 5] {
+        [
+            ("X-Content-Type-Options", &self.config.content_type_options),
+            ("X-Frame-Options", &self.config.frame_options),
+            ("Referrer-Policy", &self.config.referrer_policy),
+            (
+                "Strict-Transport-Security",
+                &self.config.strict_transport_security,
+            ),
+            (
+                "Content-Security-Policy",
+                &self.config.content_security_policy,
+            ),
+        ]
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for SecurityHeaders {
+    fn info(&self) -> Info {
+        Info {
+            name: "Security headers",
+            kind: Kind::Ignite | Kind::Response,
+        }
+    }
+
+    // Rocket's default Shield would otherwise set X-Frame-Options and nosniff first,
+    // which this fairing treats as route-provided; an empty Shield replaces it.
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        Ok(rocket.attach(Shield::new()))
+    }
+
+    async fn on_response<'r>(&self, _req: &'r Request<'_>, res: &mut Response<'r>) {
+        for (name, value) in self.headers() {
+            // Routes that set their own policy keep it.
+            if value.is_empty() || res.headers().contains(name) {
+                continue;
+            }
+            res.set_header(Header::new(name, value.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+
+    #[get("/campaigns")]
+    fn campaigns() -> &'static str {
+        "[]"
+    }
+
+    #[derive(rocket::Responder)]
+    struct Embeddable {
+        inner: &'static str,
+        frame_options: Header<'static>,
+    }
+
+    #[get("/embed")]
+    fn embed() -> Embeddable {
+        Embeddable {
+            inner: "<div></div>",
+            frame_options: Header::new("X-Frame-Options", "SAMEORIGIN"),
+        }
+    }
+
+    fn client(config: SecurityHeadersConfig) -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![campaigns, embed])
+            .attach(SecurityHeaders::new(config));
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_default_headers_on_every_response() {
+        let client = client(SecurityHeadersConfig::default());
+
+        for path in ["/campaigns", "/missing"] {
+            let response = client.get(path).dispatch();
+            let headers = response.headers();
+            assert_eq!(headers.get_one("X-Content-Type-Options"), Some("nosniff"));
+            assert_eq!(headers.get_one("X-Frame-Options"), Some("DENY"));
+            assert_eq!(
+                headers.get_one("Referrer-Policy"),
+                Some("strict-origin-when-cross-origin")
+            );
+            assert!(
+                headers
+                    .get_one("Strict-Transport-Security")
+                    .unwrap()
+                    .starts_with("max-age=")
+            );
+            assert_eq!(
+                headers.get_one("Content-Security-Policy"),
+                Some("default-src 'none'; frame-ancestors 'none'")
+            );
+        }
+    }
+
+    #[test]
+    fn test_route_header_wins_and_empty_values_are_skipped() {
+        let client = client(SecurityHeadersConfig {
+            strict_transport_security: String::new(),
+            ..SecurityHeadersConfig::default()
+        });
+        let response = client.get("/embed").dispatch();
+
+        assert_eq!(
+            response
+                .headers()
+                .get("X-Frame-Options")
+                .collect::<Vec<_>>(),
+            vec!["SAMEORIGIN"]
+        );
+        assert!(!response.headers().contains("Strict-Transport-Security"));
+    }
+}
@@ -0,0 +1,277 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use rocket::http::{ContentType, Method};
+use rocket::{State, get, routes};
+
+use super::request_metrics::{LATENCY_BUCKETS, RequestMetrics, Series, SeriesKey};
+
+/// A service-level objective covering one or more routes, identified by method and
+/// route path as declared, without the mount point (e.g. `/donations`).
+#[derive(Debug, Clone)]
+pub struct SloObjective {
+    pub name: &'static str,
+    pub routes: &'static [(Method, &'static str)],
+    pub latency_threshold: Duration,
+    pub latency_target: f64,
+    pub max_error_rate: f64,
+}
+
+impl SloObjective {
+    // 99% of requests under 250ms and fewer than 0.1% server errors.
+    pub const fn standard(name: &'static str, routes: &'static [(Method, &'static str)]) -> Self {
+        SloObjective {
+            name,
+            routes,
+            latency_threshold: Duration::from_millis(250),
+            latency_target: 0.99,
+            max_error_rate: 0.001,
+        }
+    }
+}
+
+pub const DEFAULT_OBJECTIVES: &[SloObjective] = &[
+    SloObjective::standard(
+        "donation_create",
+        &[
+            (Method::Post, "/donations"),
+            (Method::Post, "/donations/basket"),
+        ],
+    ),
+    SloObjective::standard(
+        "wallet",
+        &[
+            (Method::Post, "/wallet/withdrawals"),
+            (Method::Get, "/wallet/withdrawals"),
+        ],
+    ),
+];
+
+/// Index into `LATENCY_BUCKETS` of the objective's latency threshold, if it is one.
+pub(crate) fn latency_bucket(objective: &SloObjective) -> Option<usize> {
+    let threshold = objective.latency_threshold.as_secs_f64();
+    LATENCY_BUCKETS
+        .iter()
+        .position(|bound| (bound - threshold).abs() < 1e-9)
+}
+
+// Sums the request histogram series each objective covers.
+pub(crate) fn to_prometheus(
+    objectives: &[SloObjective],
+    series: &DashMap<SeriesKey, Series>,
+) -> String {
+    let counters: Vec<[u64; 3]> = objectives
+        .iter()
+        .map(|objective| {
+            let bucket = latency_bucket(objective).expect("checked by with_objectives");
+            let mut counters = [0; 3];
+            for entry in series.iter() {
+                let key = entry.key();
+                let covered = objective
+                    .routes
+                    .iter()
+                    .any(|(method, path)| method.as_str() == key.method && *path == key.path);
+                if !covered {
+                    continue;
+                }
+                let count = entry.count.load(Ordering::Relaxed);
+                counters[0] += count;
+                if key.status_class == "5xx" {
+                    counters[1] += count;
+                }
+                counters[2] += entry.buckets[bucket].load(Ordering::Relaxed);
+            }
+            counters
+        })
+        .collect();
+
+    let mut out = String::new();
+    for (index, (metric, help)) in [
+        (
+            "slo_requests_total",
+            "Requests covered by a service-level objective",
+        ),
+        (
+            "slo_errors_total",
+            "Covered requests that returned a 5xx status",
+        ),
+        (
+            "slo_requests_within_latency_total",
+            "Covered requests that finished within the objective's latency threshold",
+        ),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        out.push_str(&format!(
+            "# HELP {} {}\n# TYPE {} counter\n",
+            metric, help, metric
+        ));
+        for (objective, counters) in objectives.iter().zip(&counters) {
+            out.push_str(&format!(
+                "{}{{objective=\"{}\"}} {}\n",
+                metric, objective.name, counters[index]
+            ));
+        }
+    }
+    out
+}
+
+/// Prometheus alerting rules for every objective, ready to drop into a rule file.
+pub fn alert_rules_yaml(objectives: &[SloObjective]) -> String {
+    let mut out = String::from("groups:\n  - name: slo\n    rules:\n");
+    for objective in objectives {
+        let name = objective.name;
+        let error_rate = format!(
+            "sum(rate(slo_errors_total{{objective=\"{name}\"}}[5m])) / sum(rate(slo_requests_total{{objective=\"{name}\"}}[5m]))"
+        );
+        let slow_rate = format!(
+            "1 - sum(rate(slo_requests_within_latency_total{{objective=\"{name}\"}}[5m])) / sum(rate(slo_requests_total{{objective=\"{name}\"}}[5m]))"
+        );
+        for (alert, expr, threshold, summary) in [
+            (
+                "ErrorRate",
+                error_rate,
+                objective.max_error_rate,
+                format!(
+                    "{name} error rate above {}%",
+                    objective.max_error_rate * 100.0
+                ),
+            ),
+            (
+                "Latency",
+                slow_rate,
+                // Rounded so 1 - 0.99 renders as 0.01 rather than 0.010000000000000009.
+                ((1.0 - objective.latency_target) * 1e6).round() / 1e6,
+                format!(
+                    "{name}: fewer than {}% of requests under {}ms",
+                    objective.latency_target * 100.0,
+                    objective.latency_threshold.as_millis()
+                ),
+            ),
+        ] {
+            out.push_str(&format!(
+                    "      - alert: Slo{alert}_{name}\n        expr: {expr} > {threshold}\n        for: 5m\n        labels:\n          severity: page\n          objective: {name}\n        annotations:\n          summary: \"{summary}\"\n"
+                ));
+        }
+    }
+    out
+}
+
+#[get("/metrics/slo/alert-rules.yml")]
+fn slo_alert_rules_route(request_metrics: &State<Arc<RequestMetrics>>) -> (ContentType, String) {
+    (
+        ContentType::new("application", "yaml"),
+        alert_rules_yaml(request_metrics.objectives()),
+    )
+}
+
+// Needs the `Arc<RequestMetrics>` that is attached as a fairing to be managed.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![slo_alert_rules_route]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::post;
+
+    #[post("/donations")]
+    fn make_donation_route() -> &'static str {
+        "{}"
+    }
+
+    #[post("/donations/basket")]
+    fn make_basket_donation_route() -> &'static str {
+        "{}"
+    }
+
+    // Shares its handler name with the personal wallet route, which must not count.
+    #[post("/organizations/<_id>/withdrawals")]
+    fn request_withdrawal_route(_id: i32) -> Status {
+        Status::ServiceUnavailable
+    }
+
+    #[get("/wallet/withdrawals")]
+    fn get_my_withdrawals_route() -> Status {
+        Status::ServiceUnavailable
+    }
+
+    #[get("/campaigns")]
+    fn list_campaigns_route() -> &'static str {
+        "[]"
+    }
+
+    fn client() -> (Client, Arc<RequestMetrics>) {
+        let metrics = Arc::new(RequestMetrics::new().with_objectives(DEFAULT_OBJECTIVES));
+        let rocket = rocket::build()
+            .mount(
+                "/api",
+                routes![
+                    make_donation_route,
+                    make_basket_donation_route,
+                    request_withdrawal_route,
+                    get_my_withdrawals_route,
+                    list_campaigns_route
+                ],
+            )
+            .mount("/", super::routes())
+            .manage(metrics.clone())
+            .attach(metrics.clone());
+        (
+            Client::tracked(rocket).expect("valid rocket instance"),
+            metrics,
+        )
+    }
+
+    #[test]
+    fn test_counts_only_covered_routes() {
+        let (client, metrics) = client();
+        client.post("/api/donations").dispatch();
+        client.post("/api/donations/basket").dispatch();
+        client.get("/api/wallet/withdrawals").dispatch();
+        client.post("/api/organizations/3/withdrawals").dispatch();
+        client.get("/api/campaigns").dispatch();
+
+        let metrics = metrics.to_prometheus();
+        assert!(metrics.contains("slo_requests_total{objective=\"donation_create\"} 2\n"));
+        assert!(
+            metrics
+                .contains("slo_requests_within_latency_total{objective=\"donation_create\"} 2\n")
+        );
+        assert!(metrics.contains("slo_errors_total{objective=\"donation_create\"} 0\n"));
+        assert!(metrics.contains("slo_requests_total{objective=\"wallet\"} 1\n"));
+        assert!(metrics.contains("slo_errors_total{objective=\"wallet\"} 1\n"));
+        assert!(!metrics.contains("list_campaigns"));
+    }
+
+    #[test]
+    #[should_panic(expected = "histogram bucket bound")]
+    fn test_rejects_thresholds_between_buckets() {
+        let mut objective = DEFAULT_OBJECTIVES[0].clone();
+        objective.latency_threshold = Duration::from_millis(300);
+        RequestMetrics::new().with_objectives(&[objective]);
+    }
+
+    #[test]
+    fn test_alert_rules_cover_each_objective() {
+        let (client, _) = client();
+        let rules = client
+            .get("/metrics/slo/alert-rules.yml")
+            .dispatch()
+            .into_string()
+            .unwrap();
+
+        assert!(rules.contains("- alert: SloErrorRate_donation_create\n"));
+        assert!(rules.contains("- alert: SloLatency_wallet\n"));
+        assert!(rules.contains(
+            "sum(rate(slo_errors_total{objective=\"wallet\"}[5m])) / sum(rate(slo_requests_total{objective=\"wallet\"}[5m])) > 0.001"
+        ));
+        assert!(rules.contains("[5m])) > 0.01\n"));
+        assert!(rules.contains("fewer than 99% of requests under 250ms"));
+    }
+}
@@ -1 +1,23 @@
+pub mod api_key;
+pub mod auth;
+pub mod config;
+pub mod controller;
+pub mod errors;
+pub mod fairing;
+pub mod locale;
+pub mod logging;
+pub mod model;
+pub mod money;
+pub mod payload;
+pub mod pdf;
+pub mod rate_limit;
+pub mod repository;
+pub mod sanitize;
+pub mod service;
+pub mod validation;
 
+#[cfg(test)]
+mod test_support;
+
+/// Schema migrations from `migrations/`, applied at startup.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
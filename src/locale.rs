@@ -0,0 +1,761 @@
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+
+// gettext-style catalog: the English message is the key, so call sites keep their
+// literal strings and untranslated messages fall back to English. Messages that carry
+// a detail are written "Key: detail"; only the key is looked up.
+const ID_CATALOG: &[(&str, &str)] = &[
+    ("Authentication required", "Autentikasi diperlukan"),
+    ("Internal server error", "Terjadi kesalahan pada server"),
+    ("Validation failed", "Validasi gagal"),
+    (
+        "A campaign can have at most 10 images",
+        "Kampanye dapat memiliki paling banyak 10 gambar",
+    ),
+    (
+        "A different admin must confirm this action",
+        "Tindakan ini harus dikonfirmasi oleh admin lain",
+    ),
+    (
+        "A reason is required to reject a verification",
+        "Alasan wajib diisi untuk menolak verifikasi",
+    ),
+    (
+        "A reason is required to reject campaigns",
+        "Alasan wajib diisi untuk menolak kampanye",
+    ),
+    (
+        "API key does not have the required scope",
+        "Kunci API tidak memiliki cakupan yang diperlukan",
+    ),
+    ("API key not found", "Kunci API tidak ditemukan"),
+    (
+        "Adjustment amount must not be zero",
+        "Jumlah penyesuaian tidak boleh nol",
+    ),
+    (
+        "Adjustment would leave the wallet below its held funds",
+        "Penyesuaian akan membuat saldo dompet di bawah dana yang ditahan",
+    ),
+    ("Admin access required", "Akses admin diperlukan"),
+    (
+        "Admin action has already been decided",
+        "Tindakan admin sudah diputuskan",
+    ),
+    ("Admin action not found", "Tindakan admin tidak ditemukan"),
+    (
+        "An organization needs at least one admin",
+        "Organisasi harus memiliki setidaknya satu admin",
+    ),
+    (
+        "An organization with this registration number already exists",
+        "Organisasi dengan nomor registrasi ini sudah ada",
+    ),
+    (
+        "Bank account details are required",
+        "Detail rekening bank wajib diisi",
+    ),
+    (
+        "Blacklist entry needs a user id or an IP address",
+        "Entri daftar hitam memerlukan id pengguna atau alamat IP",
+    ),
+    (
+        "Blacklist entry not found",
+        "Entri daftar hitam tidak ditemukan",
+    ),
+    (
+        "Budget cannot be changed after spending has been reported",
+        "Anggaran tidak dapat diubah setelah pengeluaran dilaporkan",
+    ),
+    ("Budget item not found", "Item anggaran tidak ditemukan"),
+    (
+        "Budget items must add up to the campaign target",
+        "Item anggaran harus berjumlah sama dengan target kampanye",
+    ),
+    (
+        "Campaign has already reached its target",
+        "Kampanye sudah mencapai targetnya",
+    ),
+    (
+        "Campaign image not found",
+        "Gambar kampanye tidak ditemukan",
+    ),
+    (
+        "Campaign is not accepting donations",
+        "Kampanye tidak sedang menerima donasi",
+    ),
+    (
+        "Campaign is not awaiting review",
+        "Kampanye tidak sedang menunggu peninjauan",
+    ),
+    (
+        "Campaign is not marked tax-deductible",
+        "Kampanye tidak ditandai dapat mengurangi pajak",
+    ),
+    (
+        "Campaign is not suspended",
+        "Kampanye tidak sedang ditangguhkan",
+    ),
+    (
+        "Campaign is suspended while under investigation and cannot receive donations",
+        "Kampanye sedang ditangguhkan karena dalam investigasi dan tidak dapat menerima donasi",
+    ),
+    (
+        "Campaign member not found",
+        "Anggota kampanye tidak ditemukan",
+    ),
+    ("Campaign not found", "Kampanye tidak ditemukan"),
+    (
+        "Could not allocate a unique short link",
+        "Tidak dapat membuat tautan pendek yang unik",
+    ),
+    ("Could not read document", "Dokumen tidak dapat dibaca"),
+    ("Could not read import body", "Isi impor tidak dapat dibaca"),
+    (
+        "Could not read top-up notification",
+        "Notifikasi isi ulang tidak dapat dibaca",
+    ),
+    (
+        "Daily amount cap exceeded",
+        "Batas jumlah harian terlampaui",
+    ),
+    ("Data export not found", "Ekspor data tidak ditemukan"),
+    (
+        "Dead-lettered delivery not found",
+        "Pengiriman yang gagal permanen tidak ditemukan",
+    ),
+    (
+        "Dispute has already been resolved",
+        "Sengketa sudah diselesaikan",
+    ),
+    ("Dispute not found", "Sengketa tidak ditemukan"),
+    (
+        "Distinct campaign limit exceeded",
+        "Batas jumlah kampanye berbeda terlampaui",
+    ),
+    (
+        "Document exceeds the upload limit",
+        "Dokumen melebihi batas unggahan",
+    ),
+    (
+        "Documents must be PDF, PNG or JPEG files",
+        "Dokumen harus berupa file PDF, PNG, atau JPEG",
+    ),
+    (
+        "Donation already has an open dispute",
+        "Donasi ini sudah memiliki sengketa yang masih terbuka",
+    ),
+    (
+        "Donation amount is below the reward tier minimum",
+        "Jumlah donasi di bawah minimum tier hadiah",
+    ),
+    (
+        "Donation amount must be positive",
+        "Jumlah donasi harus lebih dari nol",
+    ),
+    (
+        "Donation intent has expired",
+        "Intent donasi sudah kedaluwarsa",
+    ),
+    (
+        "Donation intent is no longer pending",
+        "Intent donasi sudah tidak menunggu konfirmasi",
+    ),
+    ("Donation intent not found", "Intent donasi tidak ditemukan"),
+    (
+        "Donation is not awaiting review",
+        "Donasi tidak sedang menunggu peninjauan",
+    ),
+    ("Donation not found", "Donasi tidak ditemukan"),
+    (
+        "Donations above the review threshold cannot use intents",
+        "Donasi di atas ambang peninjauan tidak dapat menggunakan intent",
+    ),
+    (
+        "Donations that need review cannot be made as a basket",
+        "Donasi yang memerlukan peninjauan tidak dapat dilakukan sebagai keranjang",
+    ),
+    (
+        "Donations that need review cannot use intents",
+        "Donasi yang memerlukan peninjauan tidak dapat menggunakan intent",
+    ),
+    (
+        "Every checklist item must pass before approval",
+        "Semua item daftar periksa harus lolos sebelum persetujuan",
+    ),
+    (
+        "Evidence file exceeds the upload limit",
+        "File bukti melebihi batas unggahan",
+    ),
+    (
+        "Images can only be changed while the campaign is a draft or pending review",
+        "Gambar hanya dapat diubah selama kampanye berstatus draf atau menunggu peninjauan",
+    ),
+    (
+        "Import body exceeds the 64 MiB limit",
+        "Isi impor melebihi batas 64 MiB",
+    ),
+    (
+        "Import body exceeds the 8 MiB limit",
+        "Isi impor melebihi batas 8 MiB",
+    ),
+    (
+        "Insufficient wallet balance",
+        "Saldo dompet tidak mencukupi",
+    ),
+    ("Invalid CSV header", "Header CSV tidak valid"),
+    ("Invalid confirmation token", "Token konfirmasi tidak valid"),
+    ("Invalid two-factor code", "Kode dua faktor tidak valid"),
+    (
+        "Latitude and longitude must be set together",
+        "Latitude dan longitude harus diisi bersamaan",
+    ),
+    (
+        "Mark the failing checklist items before rejecting",
+        "Tandai item daftar periksa yang gagal sebelum menolak",
+    ),
+    ("Missing required document", "Dokumen wajib belum diunggah"),
+    (
+        "Only active campaigns can be suspended",
+        "Hanya kampanye aktif yang dapat ditangguhkan",
+    ),
+    (
+        "Only settled donations can be disputed",
+        "Hanya donasi yang sudah diselesaikan yang dapat disengketakan",
+    ),
+    (
+        "Only verified organizations can own campaigns",
+        "Hanya organisasi terverifikasi yang dapat memiliki kampanye",
+    ),
+    (
+        "Only verified organizations can request payouts",
+        "Hanya organisasi terverifikasi yang dapat mengajukan pencairan dana",
+    ),
+    (
+        "Organization is already verified",
+        "Organisasi sudah terverifikasi",
+    ),
+    (
+        "Organization member not found",
+        "Anggota organisasi tidak ditemukan",
+    ),
+    ("Organization not found", "Organisasi tidak ditemukan"),
+    (
+        "Payment provider not found",
+        "Penyedia pembayaran tidak ditemukan",
+    ),
+    ("Profile not found", "Profil tidak ditemukan"),
+    ("Receipt not found", "Kuitansi tidak ditemukan"),
+    (
+        "Recent two-factor verification required",
+        "Verifikasi dua faktor terbaru diperlukan",
+    ),
+    (
+        "Reconciliation report not found",
+        "Laporan rekonsiliasi tidak ditemukan",
+    ),
+    (
+        "Reserved funds are no longer held",
+        "Dana yang dicadangkan sudah tidak ditahan",
+    ),
+    ("Reward tier is sold out", "Tier hadiah sudah habis"),
+    ("Reward tier not found", "Tier hadiah tidak ditemukan"),
+    (
+        "Reward tiers are not available for donations that need review",
+        "Tier hadiah tidak tersedia untuk donasi yang perlu ditinjau",
+    ),
+    ("Risk rule not found", "Aturan risiko tidak ditemukan"),
+    (
+        "Rule threshold must be positive",
+        "Ambang batas aturan harus lebih dari nol",
+    ),
+    (
+        "Saved search not found",
+        "Pencarian tersimpan tidak ditemukan",
+    ),
+    (
+        "Set at least one of keywords, category or region",
+        "Isi setidaknya salah satu dari kata kunci, kategori atau wilayah",
+    ),
+    (
+        "Settlement report exceeds the 16 MiB limit",
+        "Laporan settlement melebihi batas 16 MiB",
+    ),
+    ("Short link not found", "Tautan pendek tidak ditemukan"),
+    (
+        "Spend reports must reference one of your approved payouts",
+        "Laporan pengeluaran harus merujuk ke salah satu pencairan Anda yang disetujui",
+    ),
+    (
+        "Submit a spend report for your previous payout before requesting another",
+        "Kirim laporan pengeluaran untuk pencairan sebelumnya sebelum meminta pencairan lain",
+    ),
+    (
+        "The dispute window for this donation has closed",
+        "Batas waktu pengajuan sengketa untuk donasi ini sudah berakhir",
+    ),
+    (
+        "This action is already waiting for a second admin",
+        "Tindakan ini sudah menunggu konfirmasi admin kedua",
+    ),
+    (
+        "Too many two-factor attempts",
+        "Terlalu banyak percobaan verifikasi dua langkah",
+    ),
+    (
+        "Too many widget requests",
+        "Terlalu banyak permintaan widget",
+    ),
+    (
+        "Top-up notification is not valid JSON",
+        "Notifikasi isi ulang bukan JSON yang valid",
+    ),
+    (
+        "Top-up notification is too large",
+        "Notifikasi isi ulang terlalu besar",
+    ),
+    (
+        "Two-factor authentication is already enabled",
+        "Autentikasi dua faktor sudah diaktifkan",
+    ),
+    (
+        "Two-factor authentication is not enabled",
+        "Autentikasi dua faktor belum diaktifkan",
+    ),
+    (
+        "Two-factor enrollment not found",
+        "Pendaftaran dua faktor tidak ditemukan",
+    ),
+    (
+        "Unsupported evidence file type",
+        "Jenis file bukti tidak didukung",
+    ),
+    (
+        "Upload your verification documents first",
+        "Unggah dokumen verifikasi Anda terlebih dahulu",
+    ),
+    ("User not found", "Pengguna tidak ditemukan"),
+    (
+        "User or IP address is blacklisted",
+        "Pengguna atau alamat IP masuk daftar hitam",
+    ),
+    (
+        "Verification documents can't be changed while under review",
+        "Dokumen verifikasi tidak dapat diubah selama sedang ditinjau",
+    ),
+    (
+        "Verification is already under review",
+        "Verifikasi sedang ditinjau",
+    ),
+    (
+        "Verification submission is not awaiting review",
+        "Pengajuan verifikasi tidak sedang menunggu peninjauan",
+    ),
+    (
+        "Verification submission not found",
+        "Pengajuan verifikasi tidak ditemukan",
+    ),
+    (
+        "Verify your identity before requesting a payout",
+        "Verifikasi identitas Anda sebelum mengajukan pencairan dana",
+    ),
+    ("Wallet not found", "Dompet tidak ditemukan"),
+    (
+        "Withdrawal amount must be positive",
+        "Jumlah penarikan harus lebih dari nol",
+    ),
+    (
+        "Withdrawal has already been reviewed",
+        "Penarikan dana sudah ditinjau",
+    ),
+    ("Withdrawal not found", "Penarikan dana tidak ditemukan"),
+    (
+        "You already posted this message recently",
+        "Anda sudah mengirim pesan ini baru-baru ini",
+    ),
+    (
+        "You are not a member of this campaign",
+        "Anda bukan anggota kampanye ini",
+    ),
+    (
+        "You are not a member of this organization",
+        "Anda bukan anggota organisasi ini",
+    ),
+    (
+        "You are posting messages too quickly",
+        "Anda mengirim pesan terlalu cepat",
+    ),
+    (
+        "You can only confirm your own donation intents",
+        "Anda hanya dapat mengonfirmasi intent donasi milik Anda sendiri",
+    ),
+    (
+        "You can't review your own verification",
+        "Anda tidak dapat meninjau verifikasi Anda sendiri",
+    ),
+    (
+        "You cannot access this data export",
+        "Anda tidak dapat mengakses ekspor data ini",
+    ),
+    (
+        "You cannot delete this donation message",
+        "Anda tidak dapat menghapus pesan donasi ini",
+    ),
+    (
+        "You cannot dispute this donation",
+        "Anda tidak dapat menyengketakan donasi ini",
+    ),
+    (
+        "You have already approved this withdrawal",
+        "Anda sudah menyetujui penarikan dana ini",
+    ),
+    (
+        "Your campaign role does not allow this action",
+        "Peran Anda di kampanye ini tidak mengizinkan tindakan tersebut",
+    ),
+    (
+        "Your organization role does not allow this action",
+        "Peran Anda di organisasi tidak mengizinkan tindakan ini",
+    ),
+    ("account_holder is required", "account_holder wajib diisi"),
+    ("account_number is required", "account_number wajib diisi"),
+    ("amount must be positive", "amount harus lebih dari nol"),
+    (
+        "at least one scope is required",
+        "minimal satu scope wajib diisi",
+    ),
+    (
+        "avatar_url must be a valid URL",
+        "avatar_url harus berupa URL yang valid",
+    ),
+    ("bank_name is required", "bank_name wajib diisi"),
+    (
+        "bio must be at most 500 characters",
+        "bio maksimal 500 karakter",
+    ),
+    (
+        "budget_item_id must be a valid budget item id",
+        "budget_item_id harus berupa id item anggaran yang valid",
+    ),
+    (
+        "campaign_id must be a valid campaign id",
+        "campaign_id harus berupa id kampanye yang valid",
+    ),
+    (
+        "campaign_ids must list 1 to 100 campaigns",
+        "campaign_ids harus berisi 1 sampai 100 kampanye",
+    ),
+    (
+        "category must be at most 100 characters",
+        "Kategori maksimal 100 karakter",
+    ),
+    (
+        "channel must be between 1 and 50 characters",
+        "channel harus terdiri dari 1 hingga 50 karakter",
+    ),
+    (
+        "code must be between 6 and 32 characters",
+        "code harus terdiri dari 6 sampai 32 karakter",
+    ),
+    (
+        "comment must be at most 1000 characters",
+        "comment maksimal 1000 karakter",
+    ),
+    (
+        "confirmation_token is required",
+        "confirmation_token wajib diisi",
+    ),
+    (
+        "description must be at most 1000 characters",
+        "description maksimal 1000 karakter",
+    ),
+    (
+        "description must be between 1 and 1000 characters",
+        "description harus terdiri dari 1 sampai 1000 karakter",
+    ),
+    (
+        "display_name must be between 1 and 50 characters",
+        "display_name harus antara 1 dan 50 karakter",
+    ),
+    (
+        "display_name must not be blank",
+        "display_name tidak boleh kosong",
+    ),
+    (
+        "donation_id must be a valid donation id",
+        "donation_id harus berupa id donasi yang valid",
+    ),
+    (
+        "email must be a valid email address",
+        "email harus berupa alamat email yang valid",
+    ),
+    (
+        "from date cannot be after to date",
+        "tanggal from tidak boleh setelah tanggal to",
+    ),
+    ("from must be before to", "from harus sebelum to"),
+    (
+        "honoree_email must be a valid email address",
+        "honoree_email harus berupa alamat email yang valid",
+    ),
+    (
+        "honoree_name must be between 1 and 100 characters",
+        "honoree_name harus terdiri dari 1 sampai 100 karakter",
+    ),
+    (
+        "image_ids must list every image of the campaign exactly once",
+        "image_ids harus mencantumkan setiap gambar kampanye tepat satu kali",
+    ),
+    (
+        "image_ids must not be empty",
+        "image_ids tidak boleh kosong",
+    ),
+    (
+        "items must contain between 1 and 20 campaigns",
+        "items harus berisi 1 sampai 20 kampanye",
+    ),
+    (
+        "items must contain between 1 and 50 budget lines",
+        "items harus berisi 1 sampai 50 baris anggaran",
+    ),
+    (
+        "keywords must be at most 200 characters",
+        "Kata kunci maksimal 200 karakter",
+    ),
+    (
+        "label must be between 1 and 100 characters",
+        "label harus terdiri dari 1 sampai 100 karakter",
+    ),
+    (
+        "lat must be between -90 and 90 and lng between -180 and 180",
+        "lat harus antara -90 dan 90 dan lng antara -180 dan 180",
+    ),
+    (
+        "latitude must be between -90 and 90",
+        "latitude harus antara -90 dan 90",
+    ),
+    (
+        "longitude must be between -180 and 180",
+        "longitude harus antara -180 dan 180",
+    ),
+    (
+        "low_balance_threshold must not be negative",
+        "low_balance_threshold tidak boleh negatif",
+    ),
+    (
+        "message must be at most 500 characters",
+        "message maksimal 500 karakter",
+    ),
+    (
+        "min_amount cannot exceed max_amount",
+        "min_amount tidak boleh melebihi max_amount",
+    ),
+    (
+        "min_amount must be positive",
+        "min_amount harus bernilai positif",
+    ),
+    ("name is required", "name wajib diisi"),
+    (
+        "name must be between 1 and 100 characters",
+        "name harus terdiri dari 1 sampai 100 karakter",
+    ),
+    (
+        "organization_id must be a valid organization id",
+        "organization_id harus berupa id organisasi yang valid",
+    ),
+    (
+        "private_note must be at most 500 characters",
+        "private_note maksimal 500 karakter",
+    ),
+    ("provider is required", "provider wajib diisi"),
+    ("quantity must be at least 1", "quantity minimal 1"),
+    (
+        "radius_km must be greater than 0 and at most 500",
+        "radius_km harus lebih dari 0 dan paling besar 500",
+    ),
+    ("reason is required", "reason wajib diisi"),
+    (
+        "reason must be 1 to 1000 characters",
+        "reason harus terdiri dari 1 sampai 1000 karakter",
+    ),
+    (
+        "ref must be at most 64 characters",
+        "ref maksimal 64 karakter",
+    ),
+    (
+        "region must be at most 100 characters",
+        "Wilayah maksimal 100 karakter",
+    ),
+    (
+        "region must be between 1 and 100 characters",
+        "region harus terdiri dari 1 sampai 100 karakter",
+    ),
+    (
+        "registration_number is required",
+        "registration_number wajib diisi",
+    ),
+    (
+        "since must be an RFC 3339 timestamp",
+        "since harus berupa stempel waktu RFC 3339",
+    ),
+    (
+        "tier_id must be a valid tier id",
+        "tier_id harus berupa id tier yang valid",
+    ),
+    (
+        "title must be between 1 and 100 characters",
+        "title harus terdiri dari 1 hingga 100 karakter",
+    ),
+    ("url must be a valid URL", "url harus berupa URL yang valid"),
+    (
+        "user_id must be a valid user id",
+        "user_id harus berupa id pengguna yang valid",
+    ),
+    (
+        "website must be a valid URL",
+        "website harus berupa URL yang valid",
+    ),
+    (
+        "withdrawal_id must be a valid withdrawal id",
+        "withdrawal_id harus berupa id penarikan yang valid",
+    ),
+    (
+        "year must be between 2000 and the current year",
+        "year harus antara 2000 dan tahun berjalan",
+    ),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Id,
+}
+
+impl Locale {
+    // Accepts bare language tags and region variants ("id", "id-ID", "en_US").
+    pub fn parse(tag: &str) -> Option<Locale> {
+        let language = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Locale::En),
+            "id" | "in" => Some(Locale::Id),
+            _ => None,
+        }
+    }
+
+    pub fn translate(&self, message: &str) -> String {
+        let catalog = match self {
+            Locale::En => return message.to_string(),
+            Locale::Id => ID_CATALOG,
+        };
+        let lookup = |key: &str| {
+            catalog
+                .iter()
+                .find(|(entry, _)| *entry == key)
+                .map(|(_, translated)| *translated)
+        };
+        if let Some(translated) = lookup(message) {
+            return translated.to_string();
+        }
+        match message.split_once(": ") {
+            Some((key, detail)) => lookup(key).map_or_else(
+                || message.to_string(),
+                |translated| format!("{}: {}", translated, detail),
+            ),
+            None => message.to_string(),
+        }
+    }
+
+    /// Resolves `?locale=` first, then `Accept-Language`.
+    pub fn from_request_parts(req: &Request<'_>) -> Option<Locale> {
+        req.query_value::<&str>("locale")
+            .and_then(|value| value.ok())
+            .and_then(Locale::parse)
+            .or_else(|| {
+                req.headers()
+                    .get_one("Accept-Language")
+                    .and_then(Locale::from_accept_language)
+            })
+    }
+
+    // Picks the first supported language in preference order, ignoring q-values of 0.
+    pub fn from_accept_language(header: &str) -> Option<Locale> {
+        let mut candidates: Vec<(f32, Locale)> = header
+            .split(',')
+            .filter_map(|part| {
+                let mut pieces = part.split(';');
+                let locale = Locale::parse(pieces.next()?)?;
+                let quality = pieces
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (quality > 0.0).then_some((quality, locale))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+        candidates.first().map(|(_, locale)| *locale)
+    }
+}
+
+// Forwards when neither `?locale=` nor `Accept-Language` names a supported locale, so
+// routes take `Option<Locale>` and keep their default output.
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Locale {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+        match Locale::from_request_parts(req) {
+            Some(locale) => Outcome::Success(locale),
+            None => Outcome::Forward(Status::NotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_language_tags() {
+        assert_eq!(Locale::parse("id-ID"), Some(Locale::Id));
+        assert_eq!(Locale::parse("en_US"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr"), None);
+    }
+
+    #[test]
+    fn test_accept_language_respects_quality() {
+        assert_eq!(
+            Locale::from_accept_language("fr;q=1.0, en;q=0.5, id-ID;q=0.8"),
+            Some(Locale::Id)
+        );
+        assert_eq!(Locale::from_accept_language("id;q=0, en"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("de, fr"), None);
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_english() {
+        assert_eq!(
+            Locale::Id.translate("Donation not found"),
+            "Donasi tidak ditemukan"
+        );
+        assert_eq!(
+            Locale::En.translate("Donation not found"),
+            "Donation not found"
+        );
+        assert_eq!(
+            Locale::Id.translate("Not a catalog message"),
+            "Not a catalog message"
+        );
+    }
+
+    #[test]
+    fn test_translate_keeps_the_detail_after_the_key() {
+        assert_eq!(
+            Locale::Id.translate("Missing required document: npwp"),
+            "Dokumen wajib belum diunggah: npwp"
+        );
+        assert_eq!(
+            Locale::Id.translate("Not a catalog message: detail"),
+            "Not a catalog message: detail"
+        );
+    }
+}
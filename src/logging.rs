@@ -0,0 +1,61 @@
+use rocket::serde::Deserialize;
+use std::io::IsTerminal;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(crate = "rocket::serde", rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines for a terminal.
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log shippers.
+    Json,
+}
+
+// `format` can also be set with LOG_FORMAT.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct LoggingConfig {
+    pub format: LogFormat,
+    /// An `EnvFilter` directive such as `info` or `info,sqlx=warn`.
+    pub level: String,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        LoggingConfig {
+            format: LogFormat::Pretty,
+            level: "info".to_string(),
+        }
+    }
+}
+
+impl LoggingConfig {
+    pub(crate) fn problems(&self) -> Vec<String> {
+        match EnvFilter::try_new(&self.level) {
+            Ok(_) => Vec::new(),
+            Err(e) => vec![format!("logging.level is not a valid filter: {}", e)],
+        }
+    }
+}
+
+/// Installs the global subscriber and bridges `log` records into it. Call this
+/// before the Rocket instance ignites: Rocket then finds a logger already set, so its
+/// own launch and error lines come through here in the configured format.
+pub fn init(config: &LoggingConfig) {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(&config.level))
+        .with_target(true)
+        .with_ansi(std::io::stdout().is_terminal());
+    let result = match config.format {
+        LogFormat::Pretty => builder.try_init(),
+        LogFormat::Json => builder
+            .json()
+            .flatten_event(true)
+            .with_current_span(false)
+            .try_init(),
+    };
+    if let Err(e) = result {
+        eprintln!("Could not install the log subscriber: {}", e);
+    }
+}
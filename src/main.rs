@@ -1,6 +1,127 @@
 #[macro_use]
 extern crate rocket;
 
+use backend::auth::TokenVerifier;
+use backend::config::AppConfig;
+use backend::fairing::compression::Compression;
+use backend::fairing::cors::Cors;
+use backend::fairing::error_reporting::ErrorReporting;
+use backend::fairing::request_logging::RequestLogging;
+use backend::fairing::request_metrics::RequestMetrics;
+use backend::fairing::security_headers::SecurityHeaders;
+use backend::fairing::slo::DEFAULT_OBJECTIVES;
+use backend::repository::admin_action_repo::PgAdminActionRepository;
+use backend::repository::api_key_repo::PgApiKeyRepository;
+use backend::repository::campaign_budget_repo::PgCampaignBudgetRepository;
+use backend::repository::campaign_deletion_repo::PgCampaignDeletionRepository;
+use backend::repository::campaign_feed_repo::PgCampaignFeedRepository;
+use backend::repository::campaign_image_repo::PgCampaignImageRepository;
+use backend::repository::campaign_location_repo::PgCampaignLocationRepository;
+use backend::repository::campaign_member_repo::PgCampaignMemberRepository;
+use backend::repository::campaign_overflow_repo::PgCampaignOverflowRepository;
+use backend::repository::campaign_ranking_repo::PgCampaignRankingRepository;
+use backend::repository::campaign_repo::PgCampaignRepository;
+use backend::repository::campaign_review_repo::PgCampaignReviewRepository;
+use backend::repository::campaign_share_repo::PgCampaignShareRepository;
+use backend::repository::campaign_suspension_repo::PgCampaignSuspensionRepository;
+use backend::repository::data_export_repo::PgDataExportRepository;
+use backend::repository::dispute_repo::PgDisputeRepository;
+use backend::repository::donation_archive_repo::PgDonationArchiveRepository;
+use backend::repository::donation_export_repo::PgDonationExportRepository;
+use backend::repository::donation_intent_repo::PgDonationIntentRepository;
+use backend::repository::donation_repo::PgDonationRepository;
+use backend::repository::donation_tier_repo::PgDonationTierRepository;
+use backend::repository::evidence_repo::PgEvidenceRepository;
+use backend::repository::fundraiser_repo::PgFundraiserRepository;
+use backend::repository::honoree_repo::PgHonoreeRepository;
+use backend::repository::kyc_repo::PgKycRepository;
+use backend::repository::metrics_repo::PgMetricsRepository;
+use backend::repository::notification_delivery_repo::PgNotificationDeliveryRepository;
+use backend::repository::notification_preference_repo::PgNotificationPreferenceRepository;
+use backend::repository::organization_repo::PgOrganizationRepository;
+use backend::repository::outbox_repo::PgOutboxRepository;
+use backend::repository::profile_repo::PgProfileRepository;
+use backend::repository::query_monitor::QueryMonitor;
+use backend::repository::reconciliation_repo::PgReconciliationRepository;
+use backend::repository::risk_repo::PgRiskRepository;
+use backend::repository::saved_search_repo::PgSavedSearchRepository;
+use backend::repository::security_event_repo::PgSecurityEventRepository;
+use backend::repository::seed_repo::PgSeedRepository;
+use backend::repository::short_link_repo::PgShortLinkRepository;
+use backend::repository::statistic_repo::PgStatisticRepository;
+use backend::repository::statistics_snapshot_repo::PgStatisticsSnapshotRepository;
+use backend::repository::tax_summary_repo::PgTaxSummaryRepository;
+use backend::repository::transaction_repo::PgTransactionRepository;
+use backend::repository::two_factor_repo::PgTwoFactorRepository;
+use backend::repository::user_import_repo::PgUserImportRepository;
+use backend::repository::wallet_repo::PgWalletRepository;
+use backend::repository::withdrawal_repo::PgWithdrawalRepository;
+use backend::service::admin_action_service::AdminActionService;
+use backend::service::api_key_service::ApiKeyService;
+use backend::service::cache_service::CacheService;
+use backend::service::cache_warmer::{CacheWarmer, CacheWarmupConfig};
+use backend::service::campaign_budget_service::CampaignBudgetService;
+use backend::service::campaign_feed_service::{
+    CampaignFeedConfig, CampaignFeedService, FEED_CACHE_TTL,
+};
+use backend::service::campaign_image_service::CampaignImageService;
+use backend::service::campaign_location_service::CampaignLocationService;
+use backend::service::campaign_member_service::CampaignMemberService;
+use backend::service::campaign_overflow_service::CampaignOverflowService;
+use backend::service::campaign_ranking_service::{CampaignRankingConfig, CampaignRankingService};
+use backend::service::campaign_review_service::CampaignReviewService;
+use backend::service::campaign_share_service::CampaignShareService;
+use backend::service::campaign_suspension_service::CampaignSuspensionService;
+use backend::service::campaign_widget_service::CampaignWidgetService;
+use backend::service::content_throttle::ContentThrottle;
+use backend::service::data_export_service::DataExportService;
+use backend::service::dispute_service::DisputeService;
+use backend::service::donation_archive_service::{DonationArchiveConfig, DonationArchiveService};
+use backend::service::donation_export_service::{
+    DonationExportConfig, DonationExportService, HttpObjectStore, ObjectStore,
+};
+use backend::service::donation_import_service::DonationImportService;
+use backend::service::donation_intent_service::{DEFAULT_EXPIRY_INTERVAL, DonationIntentService};
+use backend::service::donation_service::{DEFAULT_REVIEW_EXPIRY_INTERVAL, DonationService};
+use backend::service::donation_tier_service::DonationTierService;
+use backend::service::event_bus::EventBus;
+use backend::service::evidence_service::EvidenceService;
+use backend::service::fundraiser_service::FundraiserService;
+use backend::service::honoree_service::HonoreeService;
+use backend::service::kyc_service::KycService;
+use backend::service::metrics_service::MetricsService;
+use backend::service::notification_delivery_service::{
+    DEFAULT_RETRY_INTERVAL, NotificationDeliveryService,
+};
+use backend::service::notification_preference_service::{
+    DEFAULT_DIGEST_INTERVAL, NotificationPreferenceService,
+};
+use backend::service::ops_alerter::{OpsAlertConfig, OpsAlerter, WebhookAlertSink};
+use backend::service::organization_service::OrganizationService;
+use backend::service::outbox_dispatcher::{DEFAULT_DISPATCH_INTERVAL, OutboxDispatcher};
+use backend::service::profile_service::ProfileService;
+use backend::service::reconciliation_service::{HttpSettlementReportSource, ReconciliationService};
+use backend::service::risk_service::RiskService;
+use backend::service::saved_search_service::SavedSearchService;
+use backend::service::security_event_service::SecurityEventService;
+use backend::service::seed_service::{SeedConfig, SeedService};
+use backend::service::short_link_service::ShortLinkService;
+use backend::service::statistic_service::StatisticService;
+use backend::service::statistics_snapshot_service::StatisticsSnapshotService;
+use backend::service::tax_summary_service::TaxSummaryService;
+use backend::service::top_up_service::TopUpService;
+use backend::service::transaction_service::TransactionService;
+use backend::service::two_factor_service::TwoFactorService;
+use backend::service::user_import_service::{HttpAccountDirectory, UserImportService};
+use backend::service::withdrawal_service::WithdrawalService;
+use backend::{controller, fairing, logging, payload};
+use rocket::fairing::AdHoc;
+use rocket::{Build, Rocket};
+use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
+use std::sync::Arc;
+use std::time::Duration;
+
 #[get("/")]
 fn index() -> &'static str {
     "Hello, everynyan!"
@@ -16,9 +137,540 @@ fn not_found(req: &rocket::Request<'_>) -> String {
     format!("Sorry, '{}' is not a valid route..", req.uri())
 }
 
-#[launch]
-fn rocket() -> _ {
-    rocket::build()
+// Connects, applies pending migrations, then wires the API onto the pool. Without a
+// database URL (allowed outside release) the app starts without the API.
+fn database(config: AppConfig, request_metrics: Arc<RequestMetrics>) -> AdHoc {
+    AdHoc::try_on_ignite("Database", move |rocket| async move {
+        let Some(url) = config.database.url.as_deref() else {
+            return Ok(rocket);
+        };
+        let pool = match PgPoolOptions::new()
+            .max_connections(config.database.max_connections)
+            .connect(url)
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                tracing::error!("Could not connect to the database: {}", e);
+                return Err(rocket);
+            }
+        };
+        if let Err(e) = backend::MIGRATOR.run(&pool).await {
+            tracing::error!("Could not apply database migrations: {}", e);
+            return Err(rocket);
+        }
+        // Secrets enrolled before encryption at rest are sealed before any request can
+        // read them.
+        let two_factor_service = Arc::new(TwoFactorService::new(
+            Arc::new(PgTwoFactorRepository::new(pool.clone())),
+            &two_factor_key(&config),
+        ));
+        match two_factor_service.encrypt_stored_secrets().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!(count, "Encrypted stored two-factor secrets"),
+            Err(e) => {
+                tracing::error!("Could not encrypt stored two-factor secrets: {}", e);
+                return Err(rocket);
+            }
+        }
+        Ok(api(
+            rocket.manage(pool.clone()),
+            pool,
+            &config,
+            request_metrics,
+            two_factor_service,
+        ))
+    })
+}
+
+// Release configs must set `auth.two_factor_key`. Without one, development runs use a
+// random key, so two-factor enrollments don't survive a restart.
+fn two_factor_key(config: &AppConfig) -> [u8; 32] {
+    config.auth.two_factor_key_bytes().unwrap_or_else(|| {
+        tracing::warn!("auth.two_factor_key is not set; using a temporary key");
+        let mut key = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut key);
+        key
+    })
+}
+
+// Builds every repository and service on the shared pool, starts the background
+// jobs and mounts the controllers under `/api`. Services that other services or
+// jobs also hold are managed as `Arc`s.
+fn api(
+    rocket: Rocket<Build>,
+    pool: PgPool,
+    config: &AppConfig,
+    request_metrics: Arc<RequestMetrics>,
+    two_factor_service: Arc<TwoFactorService>,
+) -> Rocket<Build> {
+    let mut query_monitor = QueryMonitor::new(Duration::from_millis(
+        config.database.slow_query_threshold_ms,
+    ));
+    if config.database.explain_slow_queries {
+        query_monitor = query_monitor.with_plan_capture(pool.clone());
+    }
+    let query_monitor = Arc::new(query_monitor);
+
+    let donation_repo =
+        Arc::new(PgDonationRepository::new(pool.clone()).with_receipts(config.receipts.clone()));
+    let donation_cache = donation_repo.cache();
+    // `/readyz` answers 503 until the busiest campaigns' totals are cached.
+    let cache_warmer = Arc::new(CacheWarmer::new(
+        donation_repo.clone(),
+        donation_cache.clone(),
+        CacheWarmupConfig::default(),
+    ));
+    cache_warmer.clone().spawn();
+    let campaign_repo = Arc::new(PgCampaignRepository::new(pool.clone()));
+    let wallet_repo = Arc::new(PgWalletRepository::new(pool.clone()));
+    let withdrawal_repo = Arc::new(PgWithdrawalRepository::new(pool.clone()));
+    let profile_repo = Arc::new(PgProfileRepository::new(pool.clone()));
+    let feed_repo = Arc::new(PgCampaignFeedRepository::new(pool.clone()));
+    let budget_repo = Arc::new(PgCampaignBudgetRepository::new(pool.clone()));
+    let organization_repo = Arc::new(PgOrganizationRepository::new(pool.clone()));
+    let kyc_repo = Arc::new(PgKycRepository::new(pool.clone()));
+    let transaction_repo = Arc::new(
+        PgTransactionRepository::new(pool.clone()).with_query_monitor(query_monitor.clone()),
+    );
+    let object_store = config.storage.base_url.clone().map(|base_url| {
+        Arc::new(HttpObjectStore::new(base_url, config.storage.token.clone()))
+            as Arc<dyn ObjectStore>
+    });
+
+    let content_throttle = Arc::new(ContentThrottle::new(&config.content_throttle));
+    let risk_service = Arc::new(RiskService::new(Arc::new(PgRiskRepository::new(
+        pool.clone(),
+    ))));
+    let member_service = Arc::new(CampaignMemberService::new(Arc::new(
+        PgCampaignMemberRepository::new(pool.clone()),
+    )));
+    let donation_service = Arc::new(
+        DonationService::new(donation_repo.clone(), campaign_repo.clone())
+            .with_risk_service(risk_service.clone())
+            .with_cache_invalidator(donation_repo.clone())
+            .with_review_threshold(config.limits.review_threshold)
+            .with_content_throttle(content_throttle.clone())
+            .with_profile_repo(profile_repo.clone()),
+    );
+    donation_service
+        .clone()
+        .spawn(DEFAULT_REVIEW_EXPIRY_INTERVAL);
+    let donation_intent_service = Arc::new(
+        DonationIntentService::new(
+            Arc::new(PgDonationIntentRepository::new(pool.clone())),
+            donation_repo.clone(),
+            campaign_repo.clone(),
+        )
+        .with_risk_service(risk_service.clone())
+        .with_content_throttle(content_throttle.clone())
+        .with_donor_lock(donation_service.donor_lock())
+        .with_review_threshold(config.limits.review_threshold),
+    );
+    let widget_service = Arc::new(CampaignWidgetService::new(
+        feed_repo.clone(),
+        config.sharing.public_base_url.clone(),
+        &config.widgets,
+    ));
+    let delivery_service = Arc::new(NotificationDeliveryService::new(
+        Arc::new(PgNotificationDeliveryRepository::new(pool.clone())),
+        &config.notification_retries,
+    ));
+    let saved_search_service = Arc::new(SavedSearchService::new(Arc::new(
+        PgSavedSearchRepository::new(pool.clone()),
+    )));
+    let preference_service = Arc::new(NotificationPreferenceService::new(
+        Arc::new(PgNotificationPreferenceRepository::new(pool.clone())),
+        wallet_repo.clone(),
+    ));
+    let honoree_service = Arc::new(HonoreeService::new(Arc::new(PgHonoreeRepository::new(
+        pool.clone(),
+    ))));
+    let reconciliation_service = Arc::new(
+        ReconciliationService::new(
+            transaction_repo.clone(),
+            Arc::new(PgReconciliationRepository::new(pool.clone())),
+        )
+        .with_settlement_reports(
+            Arc::new(HttpSettlementReportSource::new()),
+            config.payment_providers.clone(),
+        ),
+    );
+    let ranking_service = Arc::new(CampaignRankingService::new(
+        Arc::new(PgCampaignRankingRepository::new(pool.clone())),
+        CampaignRankingConfig::default(),
+    ));
+    let archive_service = Arc::new(DonationArchiveService::new(
+        Arc::new(PgDonationArchiveRepository::new(pool.clone())),
+        DonationArchiveConfig::default(),
+    ));
+    let mut export_service = DonationExportService::new(
+        Arc::new(PgDonationExportRepository::new(pool.clone())),
+        DonationExportConfig::default(),
+    );
+    if let Some(object_store) = &object_store {
+        export_service = export_service.with_object_store(object_store.clone());
+    }
+    let export_service = Arc::new(export_service);
+    let snapshot_service = Arc::new(StatisticsSnapshotService::new(Arc::new(
+        PgStatisticsSnapshotRepository::new(pool.clone()),
+    )));
+
+    let event_bus = EventBus::new().with_deliveries(delivery_service.clone());
+    let metrics_service = Arc::new(
+        MetricsService::new(
+            Arc::new(PgMetricsRepository::new(pool.clone())),
+            donation_cache.clone(),
+        )
+        .with_query_monitor(query_monitor.clone())
+        .with_content_throttle(content_throttle.clone())
+        .with_campaign_widgets(widget_service.clone())
+        .with_subscriber_stats(event_bus.stats())
+        .with_request_metrics(request_metrics),
+    );
+    let event_bus = metrics_service.subscribe_to(event_bus);
+    let event_bus = saved_search_service.subscribe_to(event_bus);
+    let event_bus = preference_service.subscribe_to(event_bus);
+    let mut event_bus = honoree_service.subscribe_to(event_bus);
+    if let Some(webhook_url) = config.alerts.webhook_url.clone() {
+        let ops_alerter = Arc::new(OpsAlerter::new(
+            Arc::new(WebhookAlertSink::new(
+                webhook_url.clone(),
+                config.alerts.format,
+            )),
+            OpsAlertConfig {
+                webhook_url,
+                format: config.alerts.format,
+                payout_threshold: config.alerts.payout_threshold,
+                batch_interval: Duration::from_secs(config.alerts.batch_interval_secs),
+                max_alerts_per_batch: config.alerts.max_alerts_per_batch,
+            },
+        ));
+        event_bus = ops_alerter.subscribe_to(event_bus);
+        ops_alerter.spawn();
+    }
+    let event_bus = Arc::new(event_bus);
+
+    Arc::new(OutboxDispatcher::new(
+        Arc::new(PgOutboxRepository::new(pool.clone())),
+        event_bus.clone(),
+    ))
+    .spawn(DEFAULT_DISPATCH_INTERVAL);
+    donation_intent_service
+        .clone()
+        .spawn(DEFAULT_EXPIRY_INTERVAL);
+    delivery_service
+        .clone()
+        .spawn(event_bus, DEFAULT_RETRY_INTERVAL);
+    preference_service.clone().spawn(DEFAULT_DIGEST_INTERVAL);
+    content_throttle.spawn();
+    widget_service.clone().spawn();
+    ranking_service.clone().spawn();
+    archive_service.clone().spawn();
+    // Exports upload to the object store; without one they can only fail.
+    if object_store.is_some() {
+        export_service.clone().spawn();
+    }
+    snapshot_service.clone().spawn();
+    if !config.payment_providers.is_empty() {
+        reconciliation_service.clone().spawn();
+    }
+
+    let withdrawal_service = WithdrawalService::new(withdrawal_repo.clone())
+        .with_spend_report_check(budget_repo.clone())
+        .with_payout_policy(config.payouts.clone())
+        .with_organizations(organization_repo.clone())
+        .with_kyc_check(kyc_repo.clone())
+        .with_two_factor(two_factor_service.clone());
+    let admin_action_service = AdminActionService::new(
+        Arc::new(PgAdminActionRepository::new(pool.clone())),
+        Arc::new(PgCampaignDeletionRepository::new(pool.clone())),
+        wallet_repo.clone(),
+        donation_service.clone(),
+    )
+    .with_adjustment_cap(config.limits.balance_adjustment_cap)
+    .with_two_factor(two_factor_service.clone());
+
+    let rocket = rocket
+        .manage(query_monitor)
+        .manage(cache_warmer)
+        .manage(metrics_service)
+        .manage(donation_service)
+        .manage(donation_intent_service)
+        .manage(risk_service.clone())
+        .manage(member_service.clone())
+        .manage(two_factor_service)
+        .manage(saved_search_service)
+        .manage(preference_service)
+        .manage(delivery_service)
+        .manage(reconciliation_service)
+        .manage(ranking_service)
+        .manage(widget_service)
+        .manage(archive_service)
+        .manage(export_service)
+        .manage(snapshot_service)
+        .manage(withdrawal_service)
+        .manage(admin_action_service)
+        .manage(CacheService::new(donation_cache))
+        .manage(ApiKeyService::new(Arc::new(PgApiKeyRepository::new(
+            pool.clone(),
+        ))))
+        .manage(CampaignBudgetService::new(budget_repo))
+        .manage(CampaignImageService::new(
+            Arc::new(PgCampaignImageRepository::new(pool.clone())),
+            member_service.clone(),
+        ))
+        .manage(CampaignLocationService::new(Arc::new(
+            PgCampaignLocationRepository::new(pool.clone()),
+        )))
+        .manage(CampaignOverflowService::new(Arc::new(
+            PgCampaignOverflowRepository::new(pool.clone()),
+        )))
+        .manage(CampaignReviewService::new(Arc::new(
+            PgCampaignReviewRepository::new(pool.clone()),
+        )))
+        .manage(CampaignShareService::new(
+            Arc::new(PgCampaignShareRepository::new(pool.clone())),
+            config.sharing.clone(),
+        ))
+        .manage(CampaignSuspensionService::new(Arc::new(
+            PgCampaignSuspensionRepository::new(pool.clone()),
+        )))
+        .manage(DataExportService::new(
+            donation_repo.clone(),
+            withdrawal_repo.clone(),
+            Arc::new(PgDataExportRepository::new(pool.clone())),
+        ))
+        .manage(DonationTierService::new(
+            Arc::new(PgDonationTierRepository::new(pool.clone())),
+            member_service.clone(),
+        ))
+        .manage(FundraiserService::new(
+            Arc::new(PgFundraiserRepository::new(pool.clone())),
+            withdrawal_repo,
+        ))
+        .manage(OrganizationService::new(organization_repo))
+        .manage(ProfileService::new(profile_repo))
+        .manage(SecurityEventService::new(Arc::new(
+            PgSecurityEventRepository::new(pool.clone()),
+        )))
+        .manage(ShortLinkService::new(
+            Arc::new(PgShortLinkRepository::new(pool.clone())),
+            campaign_repo,
+            member_service,
+            config.sharing.clone(),
+        ))
+        .manage(StatisticService::new(Arc::new(PgStatisticRepository::new(
+            pool.clone(),
+        ))))
+        .manage(TaxSummaryService::new(Arc::new(
+            PgTaxSummaryRepository::new(pool.clone()),
+        )))
+        .manage(TransactionService::new(transaction_repo))
+        .manage(
+            TopUpService::new(wallet_repo.clone(), config.payment_providers.clone())
+                .with_risk_service(risk_service),
+        )
+        .mount("/", controller::cache_controller::metrics_routes())
+        .mount("/", controller::health_controller::routes())
+        .mount("/", controller::short_link_controller::redirect_routes())
+        .mount("/api", controller::admin_action_controller::routes())
+        .mount("/api", controller::api_key_controller::routes())
+        .mount("/api", controller::cache_controller::routes())
+        .mount("/api", controller::campaign_budget_controller::routes())
+        .mount("/api", controller::campaign_image_controller::routes())
+        .mount("/api", controller::campaign_location_controller::routes())
+        .mount("/api", controller::campaign_member_controller::routes())
+        .mount("/api", controller::campaign_overflow_controller::routes())
+        .mount("/api", controller::campaign_ranking_controller::routes())
+        .mount("/api", controller::campaign_review_controller::routes())
+        .mount("/api", controller::campaign_share_controller::routes())
+        .mount("/api", controller::campaign_suspension_controller::routes())
+        .mount("/api", controller::campaign_widget_controller::routes())
+        .mount("/api", controller::data_export_controller::routes())
+        .mount("/api", controller::diagnostics_controller::routes())
+        .mount("/api", controller::donation_archive_controller::routes())
+        .mount("/api", controller::donation_controller::routes())
+        .mount("/api", controller::donation_export_controller::routes())
+        .mount("/api", controller::donation_intent_controller::routes())
+        .mount("/api", controller::donation_tier_controller::routes())
+        .mount("/api", controller::fundraiser_controller::routes())
+        .mount(
+            "/api",
+            controller::notification_delivery_controller::routes(),
+        )
+        .mount(
+            "/api",
+            controller::notification_preference_controller::routes(),
+        )
+        .mount("/api", controller::organization_controller::routes())
+        .mount("/api", controller::profile_controller::routes())
+        .mount("/api", controller::reconciliation_controller::routes())
+        .mount("/api", controller::risk_controller::routes())
+        .mount("/api", controller::saved_search_controller::routes())
+        .mount("/api", controller::security_event_controller::routes())
+        .mount("/api", controller::short_link_controller::routes())
+        .mount("/api", controller::statistic_controller::routes())
+        .mount("/api", controller::statistics_snapshot_controller::routes())
+        .mount("/api", controller::tax_summary_controller::routes())
+        .mount("/api", controller::top_up_controller::routes())
+        .mount("/api", controller::transaction_controller::routes())
+        .mount("/api", controller::two_factor_controller::routes())
+        .mount("/api", controller::withdrawal_controller::routes());
+
+    // Uploads need somewhere to put the files.
+    let rocket = match object_store {
+        Some(object_store) => rocket
+            .manage(KycService::new(kyc_repo, object_store.clone()))
+            .manage(EvidenceService::new(
+                Arc::new(PgEvidenceRepository::new(pool.clone())),
+                object_store,
+            ))
+            .mount("/api", controller::kyc_controller::routes())
+            .mount("/api", controller::evidence_controller::routes()),
+        None => rocket,
+    };
+    // Accounts are created by the auth service.
+    let rocket = match (&config.auth.accounts_url, &config.auth.service_token) {
+        (Some(accounts_url), Some(service_token)) => rocket
+            .manage(UserImportService::new(
+                Arc::new(HttpAccountDirectory::new(
+                    accounts_url.clone(),
+                    service_token.clone(),
+                )),
+                Arc::new(PgUserImportRepository::new(pool.clone())),
+            ))
+            .mount("/api", controller::user_import_controller::routes()),
+        _ => rocket,
+    };
+    let rocket = if config.features.disputes {
+        rocket
+            .manage(
+                DisputeService::new(
+                    Arc::new(PgDisputeRepository::new(pool)),
+                    donation_repo.clone(),
+                )
+                .with_cache_invalidator(donation_repo.clone())
+                .with_dispute_window_days(config.limits.dispute_window_days),
+            )
+            .mount("/api", controller::dispute_controller::routes())
+    } else {
+        rocket
+    };
+    let rocket = if config.features.donation_import {
+        rocket
+            .manage(
+                DonationImportService::new(donation_repo.clone())
+                    .with_cache_invalidator(donation_repo),
+            )
+            .mount("/api", controller::donation_controller::import_routes())
+    } else {
+        rocket
+    };
+    if config.features.public_feeds {
+        rocket
+            .manage(CampaignFeedService::new(
+                feed_repo,
+                CampaignFeedConfig {
+                    public_base_url: config.sharing.public_base_url.clone(),
+                    cache_ttl: FEED_CACHE_TTL,
+                },
+            ))
+            .mount("/api", controller::campaign_feed_controller::routes())
+    } else {
+        rocket
+    }
+}
+
+// `cargo run -- seed` fills the configured database with demo data and exits.
+async fn seed(config: &AppConfig) -> Result<(), String> {
+    let url = config
+        .database
+        .url
+        .as_deref()
+        .ok_or("Seeding needs database.url (or DATABASE_URL)")?;
+    let pool = PgPoolOptions::new()
+        .max_connections(config.database.max_connections)
+        .connect(url)
+        .await
+        .map_err(|e| format!("Could not connect to the database: {}", e))?;
+    backend::MIGRATOR
+        .run(&pool)
+        .await
+        .map_err(|e| format!("Could not apply database migrations: {}", e))?;
+    let seed_service = SeedService::new(
+        Arc::new(PgDonationRepository::new(pool.clone())),
+        Arc::new(PgProfileRepository::new(pool.clone())),
+        Arc::new(PgSeedRepository::new(pool)),
+    );
+    let report = seed_service
+        .seed(&SeedConfig::default())
+        .await
+        .map_err(|e| format!("Seeding failed: {}", e))?;
+    tracing::info!(
+        profiles = report.profiles,
+        wallets = report.wallets,
+        campaigns = report.campaigns,
+        donations = report.donations,
+        notifications = report.notifications,
+        "Seeded demo data"
+    );
+    Ok(())
+}
+
+fn rocket(figment: rocket::figment::Figment, config: AppConfig) -> Rocket<Build> {
+    let rocket = rocket::custom(figment);
+    let rocket = match config.auth.jwt_secret.as_deref() {
+        Some(secret) => rocket.manage(TokenVerifier::new(secret)),
+        None => rocket,
+    };
+    let request_metrics = Arc::new(RequestMetrics::new().with_objectives(DEFAULT_OBJECTIVES));
+
+    rocket
         .mount("/", routes![index, name])
+        .mount("/", fairing::slo::routes())
+        .manage(request_metrics.clone())
         .register("/", catchers![not_found])
+        .register("/", payload::catchers())
+        .attach(database(config.clone(), request_metrics.clone()))
+        .attach(ErrorReporting::init(config.error_reporting.clone()))
+        .attach(RequestLogging)
+        .attach(
+            Cors::new(config.cors.clone())
+                .with_localhost(!config.release)
+                .with_public_path("/api/widgets/"),
+        )
+        .attach(SecurityHeaders::new(config.security_headers.clone()))
+        .attach(request_metrics)
+        .attach(Compression::new(config.compression.clone()))
+        .manage(config)
+}
+
+#[rocket::main]
+async fn main() {
+    let figment = rocket::Config::figment().join(("limits", payload::default_limits()));
+    let config = match AppConfig::from_figment(&figment) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    // Before `rocket::custom`, which otherwise installs Rocket's own logger.
+    logging::init(&config.logging);
+
+    let command = std::env::args().nth(1);
+    let result = match command.as_deref() {
+        Some("seed") => seed(&config).await,
+        Some(command) => Err(format!("Unknown command '{}'; expected 'seed'", command)),
+        None => rocket(figment, config)
+            .launch()
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
 }
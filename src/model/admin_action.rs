@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// Destructive admin operations that only run once a second admin confirms them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "admin_action_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AdminActionKind {
+    /// `target_id` is the campaign; only used when it already has donations.
+    DeleteCampaign,
+    /// `target_id` is the wallet owner and `amount` the signed adjustment.
+    AdjustBalance,
+    /// `target_id` is the campaign whose settled donations are all refunded.
+    RefundAllDonations,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "admin_action_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AdminActionStatus {
+    Pending,
+    Approved,
+    Declined,
+    /// Approved, but running the action failed; `decision_note` has the error.
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct PendingAdminAction {
+    pub id: i32,
+    pub kind: AdminActionKind,
+    pub target_id: i32,
+    pub amount: Option<f64>,
+    pub reason: Option<String>,
+    pub status: AdminActionStatus,
+    pub requested_by: i32,
+    pub decided_by: Option<i32>,
+    pub decision_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BalanceAdjustmentRequest {
+    /// Negative to deduct from the wallet.
+    pub amount: f64,
+    #[validate(length(min = 1, max = 500, message = "reason is required"))]
+    pub reason: String,
+}
+
+/// Who made a balance adjustment and why, recorded with every adjustment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceAdjustmentAudit {
+    pub reason: String,
+    pub requested_by: i32,
+    /// Set when the adjustment was above the cap and went through the queue.
+    pub approved_by: Option<i32>,
+    pub admin_action_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeclineAdminActionRequest {
+    pub note: Option<String>,
+}
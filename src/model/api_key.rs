@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "api_key_scope")]
+pub enum ApiKeyScope {
+    #[serde(rename = "stats:read")]
+    #[sqlx(rename = "stats:read")]
+    StatsRead,
+    #[serde(rename = "notifications:write")]
+    #[sqlx(rename = "notifications:write")]
+    NotificationsWrite,
+    #[serde(rename = "exports:read")]
+    #[sqlx(rename = "exports:read")]
+    ExportsRead,
+}
+
+/// A stored machine-to-machine key; only the SHA-256 of the secret is kept.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ApiKeyRecord {
+    pub id: i32,
+    pub name: String,
+    pub key_prefix: String,
+    #[serde(skip_serializing)]
+    pub key_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_by: i32,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyRecord {
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    #[validate(length(min = 1, message = "at least one scope is required"))]
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+/// Returned once at creation; the plaintext key cannot be recovered afterwards.
+#[derive(Debug, Serialize)]
+pub struct MintedApiKey {
+    pub key: String,
+    #[serde(flatten)]
+    pub record: ApiKeyRecord,
+}
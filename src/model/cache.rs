@@ -0,0 +1,71 @@
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, FromFormField)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheScope {
+    #[field(value = "campaign_totals")]
+    CampaignTotals,
+    #[field(value = "user_totals")]
+    UserTotals,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CacheMapStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub memory_estimate_bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CacheStats {
+    pub campaign_totals: CacheMapStats,
+    pub user_totals: CacheMapStats,
+}
+
+impl CacheStats {
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        for (metric, help, kind) in [
+            (
+                "donation_cache_entries",
+                "Number of cached donation totals",
+                "gauge",
+            ),
+            (
+                "donation_cache_hits_total",
+                "Donation cache lookups served from memory",
+                "counter",
+            ),
+            (
+                "donation_cache_misses_total",
+                "Donation cache lookups that fell through to the database",
+                "counter",
+            ),
+            (
+                "donation_cache_memory_bytes",
+                "Estimated memory held by the donation cache",
+                "gauge",
+            ),
+        ] {
+            out.push_str(&format!(
+                "# HELP {} {}\n# TYPE {} {}\n",
+                metric, help, metric, kind
+            ));
+            for (scope, stats) in [
+                ("campaign_totals", &self.campaign_totals),
+                ("user_totals", &self.user_totals),
+            ] {
+                let value = match metric {
+                    "donation_cache_entries" => stats.entries as u64,
+                    "donation_cache_hits_total" => stats.hits,
+                    "donation_cache_misses_total" => stats.misses,
+                    _ => stats.memory_estimate_bytes as u64,
+                };
+                out.push_str(&format!("{}{{scope=\"{}\"}} {}\n", metric, scope, value));
+            }
+        }
+        out
+    }
+}
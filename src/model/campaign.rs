@@ -0,0 +1,74 @@
+use crate::model::donation::CampaignDonationStats;
+use crate::model::profile::Profile;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, FromRow)]
+pub struct Campaign {
+    pub id: i32,
+    pub fundraiser_id: Option<i32>,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    /// `draft`, `pending_review`, `active`, `suspended`, `rejected`, `completed` or
+    /// `deleted`.
+    pub status: String,
+    /// None for campaigns that run until their target is met.
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+impl Campaign {
+    /// How much of the target has been collected; can go past 100.
+    pub fn progress_percent(&self) -> f64 {
+        if self.target_amount <= 0.0 {
+            return 0.0;
+        }
+        self.collected_amount / self.target_amount * 100.0
+    }
+
+    /// Whole days left, counting a partial day as one; zero once the campaign ended.
+    pub fn days_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.ends_at.map(|ends_at| {
+            let seconds = (ends_at - now).num_seconds().max(0);
+            (seconds + 86_399) / 86_400
+        })
+    }
+}
+
+/// Everything a campaign page shows, in one response.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignDetail {
+    pub campaign: Campaign,
+    /// The fundraiser's profile card; None until they set one up.
+    pub owner: Option<Profile>,
+    #[serde(flatten)]
+    pub stats: CampaignDonationStats,
+    pub progress_percent: f64,
+    pub days_remaining: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_progress_and_days_remaining() {
+        let now = Utc::now();
+        let campaign = Campaign {
+            target_amount: 1000.0,
+            collected_amount: 250.0,
+            ends_at: Some(now + Duration::hours(30)),
+            ..Default::default()
+        };
+
+        assert_eq!(campaign.progress_percent(), 25.0);
+        assert_eq!(campaign.days_remaining(now), Some(2));
+        assert_eq!(campaign.days_remaining(now + Duration::days(3)), Some(0));
+        assert_eq!(Campaign::default().days_remaining(now), None);
+        assert_eq!(Campaign::default().progress_percent(), 0.0);
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// A budget line with what has been reported as spent against it so far.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct BudgetLine {
+    pub id: i32,
+    pub label: String,
+    pub amount: f64,
+    pub spent: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct SpendReport {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub budget_item_id: i32,
+    /// The payout the money came from.
+    pub withdrawal_id: i32,
+    pub amount: f64,
+    pub description: String,
+    pub created_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The public transparency view of a campaign's budget.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignBudget {
+    pub campaign_id: i32,
+    pub target_amount: f64,
+    pub spend_reports_required: bool,
+    pub total_spent: f64,
+    pub lines: Vec<BudgetLine>,
+    /// Newest first.
+    pub reports: Vec<SpendReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct NewBudgetItem {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "label must be between 1 and 100 characters"
+    ))]
+    pub label: String,
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DeclareBudgetRequest {
+    #[validate(
+        length(
+            min = 1,
+            max = 50,
+            message = "items must contain between 1 and 50 budget lines"
+        ),
+        nested
+    )]
+    pub items: Vec<NewBudgetItem>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewSpendReportRequest {
+    #[validate(range(min = 1, message = "budget_item_id must be a valid budget item id"))]
+    pub budget_item_id: i32,
+    #[validate(range(min = 1, message = "withdrawal_id must be a valid withdrawal id"))]
+    pub withdrawal_id: i32,
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "description must be between 1 and 1000 characters"
+    ))]
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequireSpendReportsRequest {
+    pub required: bool,
+}
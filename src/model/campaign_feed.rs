@@ -0,0 +1,21 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignFeedItem {
+    pub id: i32,
+    pub title: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    pub region: Option<String>,
+    #[sqlx(skip)]
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignFeedPage {
+    pub items: Vec<CampaignFeedItem>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
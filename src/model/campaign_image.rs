@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignImage {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub url: String,
+    /// Zero-based; the first image is the cover.
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AddCampaignImageRequest {
+    #[validate(url(message = "url must be a valid URL"))]
+    pub url: String,
+}
+
+/// Every image of the campaign, in the new order.
+#[derive(Debug, Deserialize, Validate)]
+pub struct ReorderCampaignImagesRequest {
+    #[validate(length(min = 1, message = "image_ids must not be empty"))]
+    pub image_ids: Vec<i32>,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignLocation {
+    pub campaign_id: i32,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct NearbyCampaign {
+    pub id: i32,
+    pub title: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    pub region: Option<String>,
+    /// Great-circle distance from the searched point.
+    pub distance_km: f64,
+}
+
+/// Replaces the campaign's location; send nulls to clear it. Latitude and longitude
+/// are set or cleared together.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCampaignLocationRequest {
+    #[validate(range(min = -90.0, max = 90.0, message = "latitude must be between -90 and 90"))]
+    pub latitude: Option<f64>,
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "longitude must be between -180 and 180"
+    ))]
+    pub longitude: Option<f64>,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "region must be between 1 and 100 characters"
+    ))]
+    pub region: Option<String>,
+}
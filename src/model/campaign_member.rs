@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "campaign_role", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CampaignRole {
+    Owner,
+    Editor,
+    Viewer,
+}
+
+/// Actions on a campaign that are gated by membership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CampaignAction {
+    View,
+    UpdateCampaign,
+    UploadEvidence,
+    PostUpdate,
+    ManageMembers,
+    ReadPrivateNotes,
+}
+
+impl CampaignRole {
+    pub fn allows(&self, action: CampaignAction) -> bool {
+        match self {
+            CampaignRole::Owner => true,
+            CampaignRole::Editor => !matches!(
+                action,
+                CampaignAction::ManageMembers | CampaignAction::ReadPrivateNotes
+            ),
+            CampaignRole::Viewer => action == CampaignAction::View,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignMember {
+    pub campaign_id: i32,
+    pub user_id: i32,
+    pub role: CampaignRole,
+    pub added_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AddCampaignMemberRequest {
+    #[validate(range(min = 1, message = "user_id must be a valid user id"))]
+    pub user_id: i32,
+    pub role: CampaignRole,
+}
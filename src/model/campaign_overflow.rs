@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// What happens to a donation that would take a campaign past its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "campaign_overflow_policy", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Accept the whole donation; `collected_amount` may exceed the target.
+    Allow,
+    /// Accept only what is still needed and return the excess to the donor's wallet.
+    Cap,
+    /// Accept the whole donation and raise the target to match; the campaign stays open.
+    Extend,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverflowOutcome {
+    /// Amount credited to the campaign; the rest of the donation goes back to the wallet.
+    pub accepted_amount: f64,
+    pub target_amount: f64,
+    pub completes_campaign: bool,
+}
+
+impl OverflowPolicy {
+    pub fn apply(self, target_amount: f64, collected_amount: f64, amount: f64) -> OverflowOutcome {
+        let overflows = collected_amount + amount > target_amount;
+        match self {
+            OverflowPolicy::Cap => {
+                let accepted_amount = amount.min((target_amount - collected_amount).max(0.0));
+                OverflowOutcome {
+                    accepted_amount,
+                    target_amount,
+                    completes_campaign: collected_amount + accepted_amount >= target_amount,
+                }
+            }
+            OverflowPolicy::Extend if overflows => OverflowOutcome {
+                accepted_amount: amount,
+                target_amount: collected_amount + amount,
+                completes_campaign: false,
+            },
+            OverflowPolicy::Allow | OverflowPolicy::Extend => OverflowOutcome {
+                accepted_amount: amount,
+                target_amount,
+                completes_campaign: collected_amount + amount >= target_amount,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignOverflowSettings {
+    pub campaign_id: i32,
+    pub overflow_policy: OverflowPolicy,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateOverflowPolicyRequest {
+    pub overflow_policy: OverflowPolicy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policies_split_an_overflowing_donation() {
+        let allow = OverflowPolicy::Allow.apply(1000.0, 900.0, 300.0);
+        assert_eq!(allow.accepted_amount, 300.0);
+        assert_eq!(allow.target_amount, 1000.0);
+        assert!(allow.completes_campaign);
+
+        let cap = OverflowPolicy::Cap.apply(1000.0, 900.0, 300.0);
+        assert_eq!(cap.accepted_amount, 100.0);
+        assert!(cap.completes_campaign);
+
+        let extend = OverflowPolicy::Extend.apply(1000.0, 900.0, 300.0);
+        assert_eq!(extend.accepted_amount, 300.0);
+        assert_eq!(extend.target_amount, 1200.0);
+        assert!(!extend.completes_campaign);
+    }
+
+    #[test]
+    fn test_donations_within_target_are_unaffected() {
+        for policy in [
+            OverflowPolicy::Allow,
+            OverflowPolicy::Cap,
+            OverflowPolicy::Extend,
+        ] {
+            let outcome = policy.apply(1000.0, 900.0, 100.0);
+            assert_eq!(outcome.accepted_amount, 100.0);
+            assert_eq!(outcome.target_amount, 1000.0);
+            assert!(outcome.completes_campaign);
+        }
+    }
+}
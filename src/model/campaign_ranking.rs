@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct TrendingCampaign {
+    pub campaign_id: i32,
+    pub title: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    /// Recent donation volume with older donations decayed by their age.
+    pub score: f64,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// An active campaign in a category the user has donated to before.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct RecommendedCampaign {
+    pub campaign_id: i32,
+    pub title: String,
+    pub category: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+}
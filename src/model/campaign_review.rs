@@ -0,0 +1,177 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// What an admin checks before a campaign goes live. Every item is required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "campaign_review_item", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ChecklistItem {
+    IdentityVerified,
+    DocumentsValid,
+    DescriptionAccurate,
+}
+
+impl ChecklistItem {
+    pub const ALL: [ChecklistItem; 3] = [
+        ChecklistItem::IdentityVerified,
+        ChecklistItem::DocumentsValid,
+        ChecklistItem::DescriptionAccurate,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "campaign_review_result", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CheckResult {
+    Pending,
+    Passed,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignReviewItem {
+    pub item: ChecklistItem,
+    pub result: CheckResult,
+    /// Reviewer's note; fundraisers see it too, so it should say what to fix.
+    pub comment: Option<String>,
+    pub reviewer_id: Option<i32>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl CampaignReviewItem {
+    fn pending(item: ChecklistItem) -> Self {
+        CampaignReviewItem {
+            item,
+            result: CheckResult::Pending,
+            comment: None,
+            reviewer_id: None,
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignReview {
+    pub campaign_id: i32,
+    /// One entry per checklist item, in checklist order; unchecked items are pending.
+    pub items: Vec<CampaignReviewItem>,
+    pub can_approve: bool,
+}
+
+impl CampaignReview {
+    pub fn from_recorded(campaign_id: i32, recorded: Vec<CampaignReviewItem>) -> Self {
+        let items: Vec<CampaignReviewItem> = ChecklistItem::ALL
+            .iter()
+            .map(|&item| {
+                recorded
+                    .iter()
+                    .find(|recorded| recorded.item == item)
+                    .cloned()
+                    .unwrap_or_else(|| CampaignReviewItem::pending(item))
+            })
+            .collect();
+        let can_approve = items.iter().all(|item| item.result == CheckResult::Passed);
+        CampaignReview {
+            campaign_id,
+            items,
+            can_approve,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateChecklistItemRequest {
+    pub item: ChecklistItem,
+    pub result: CheckResult,
+    #[validate(length(max = 1000, message = "comment must be at most 1000 characters"))]
+    pub comment: Option<String>,
+}
+
+/// Another campaign whose title or description closely resembles the one under
+/// review. Scores are pg_trgm similarities from 0 to 1.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct SimilarCampaign {
+    pub id: i32,
+    pub title: String,
+    pub status: String,
+    pub fundraiser_id: Option<i32>,
+    pub title_similarity: f64,
+    /// None when either campaign has no description.
+    pub description_similarity: Option<f64>,
+    /// The higher of the two scores; results are ordered by it.
+    pub similarity: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkReviewAction {
+    Approve,
+    Reject,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BulkReviewRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "campaign_ids must list 1 to 100 campaigns"
+    ))]
+    pub campaign_ids: Vec<i32>,
+    pub action: BulkReviewAction,
+    /// Required when rejecting; sent to each fundraiser.
+    #[validate(length(min = 1, max = 1000, message = "reason must be 1 to 1000 characters"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BulkReviewResult {
+    pub campaign_id: i32,
+    /// None when the action went through.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(item: ChecklistItem, result: CheckResult) -> CampaignReviewItem {
+        CampaignReviewItem {
+            item,
+            result,
+            comment: None,
+            reviewer_id: Some(1),
+            updated_at: Some(Utc::now()),
+        }
+    }
+
+    #[test]
+    fn test_unchecked_items_block_approval() {
+        let review = CampaignReview::from_recorded(
+            10,
+            vec![
+                recorded(ChecklistItem::DescriptionAccurate, CheckResult::Passed),
+                recorded(ChecklistItem::IdentityVerified, CheckResult::Passed),
+            ],
+        );
+
+        let items: Vec<ChecklistItem> = review.items.iter().map(|item| item.item).collect();
+        assert_eq!(items, ChecklistItem::ALL.to_vec());
+        assert_eq!(review.items[1].result, CheckResult::Pending);
+        assert!(!review.can_approve);
+    }
+
+    #[test]
+    fn test_all_passed_items_allow_approval() {
+        let review = CampaignReview::from_recorded(
+            10,
+            ChecklistItem::ALL
+                .iter()
+                .map(|&item| recorded(item, CheckResult::Passed))
+                .collect(),
+        );
+
+        assert!(review.can_approve);
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "share_platform", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SharePlatform {
+    Whatsapp,
+    Facebook,
+    X,
+    Telegram,
+    Instagram,
+    CopyLink,
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordShareRequest {
+    pub platform: SharePlatform,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ShareCount {
+    pub platform: SharePlatform,
+    pub share_count: i64,
+}
+
+/// What a link preview needs from the campaign; the image is the gallery cover.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct CampaignPreview {
+    pub campaign_id: i32,
+    pub title: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetaTag {
+    /// `og:*` tags go in `property`, `twitter:*` tags in `name`.
+    pub attribute: &'static str,
+    pub key: &'static str,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignOpenGraph {
+    pub campaign_id: i32,
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub image_url: Option<String>,
+    pub collected_amount: f64,
+    pub target_amount: f64,
+    pub progress_percent: f64,
+    pub tags: Vec<MetaTag>,
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// What an admin decides once a suspended campaign has been investigated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuspensionDecision {
+    /// Back to `active`; donations are accepted again.
+    Reinstate,
+    /// Closed for good as `rejected`.
+    Reject,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignAuditEntry {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub actor_id: i32,
+    pub action: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SuspendCampaignRequest {
+    #[validate(length(min = 1, max = 1000, message = "reason is required"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveSuspensionRequest {
+    pub decision: SuspensionDecision,
+    pub note: Option<String>,
+}
@@ -0,0 +1,26 @@
+use rocket::FromFormField;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// The few fields a partner's progress bar needs. Kept small on purpose: this is
+/// served publicly to any site that embeds it.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignWidget {
+    pub id: i32,
+    pub title: String,
+    pub target_amount: f64,
+    pub collected_amount: f64,
+    pub completed: bool,
+    /// Whole percent raised; above 100 when a campaign accepts overflow donations.
+    #[sqlx(skip)]
+    pub percent_funded: u32,
+    #[sqlx(skip)]
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromFormField)]
+pub enum WidgetFormat {
+    Json,
+    /// A self-contained snippet for use in an iframe.
+    Html,
+}
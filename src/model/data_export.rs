@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use rocket::serde::json::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::model::donation::Donation;
+use crate::model::withdrawal::Withdrawal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "data_export_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DataExport {
+    pub id: i32,
+    pub user_id: i32,
+    pub status: DataExportStatus,
+    pub payload: Option<Value>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UserDataExport {
+    pub user_id: i32,
+    pub generated_at: DateTime<Utc>,
+    pub donations: Vec<Donation>,
+    pub withdrawals: Vec<Withdrawal>,
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "dispute_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeStatus {
+    Open,
+    Refunded,
+    Dismissed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Dispute {
+    pub id: i32,
+    pub donation_id: i32,
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub amount: f64,
+    pub reason: String,
+    pub status: DisputeStatus,
+    pub resolved_by: Option<i32>,
+    pub resolution_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DisputeAuditEntry {
+    pub id: i32,
+    pub dispute_id: i32,
+    pub actor_id: i32,
+    pub action: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewDisputeRequest {
+    #[validate(range(min = 1, message = "donation_id must be a valid donation id"))]
+    pub donation_id: i32,
+    #[validate(length(min = 1, max = 1000, message = "reason is required"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeResolution {
+    Refund,
+    Dismiss,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveDisputeRequest {
+    pub resolution: DisputeResolution,
+    pub note: Option<String>,
+}
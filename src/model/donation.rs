@@ -1,26 +1,213 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use validator::Validate;
 
-#[derive(Debug, Serialize, FromRow)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "donation_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DonationStatus {
+    Settled,
+    PendingReview,
+    Rejected,
+    Expired,
+    Imported,
+    Refunded,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
 pub struct Donation {
     pub id: i32,
     pub user_id: i32,
     pub campaign_id: i32,
     pub amount: f64,
     pub message: Option<String>,
+    /// Note for the fundraiser only; never serialized, read it through `DonationPrivateNote`.
+    #[serde(skip_serializing)]
+    pub private_note: Option<String>,
+    pub status: DonationStatus,
+    /// Assigned when the donation settles; see `DonationReceipt`.
+    pub receipt_number: Option<String>,
+    /// Part of the requested amount credited straight back to the wallet because the
+    /// campaign caps donations at its target. Only set on the response that created it.
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_excess: Option<f64>,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Donation receipt for the donor's records. Numbers run gap-free within a fiscal year.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationReceipt {
+    pub receipt_number: String,
+    pub donation_id: i32,
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub campaign_title: String,
+    pub amount: f64,
+    pub status: DonationStatus,
+    pub issued_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
 pub struct NewDonationRequest {
+   #[validate(range(min = 1, message = "campaign_id must be a valid campaign id"))]
    pub campaign_id: i32,
+   #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
    pub amount: f64,
+   #[validate(length(max = 500, message = "message must be at most 500 characters"))]
    pub message: Option<String>,
+   #[validate(length(max = 500, message = "private_note must be at most 500 characters"))]
+   pub private_note: Option<String>,
+   #[validate(range(min = 1, message = "tier_id must be a valid tier id"))]
+   pub tier_id: Option<i32>,
+   /// Short-link slug or referral code the donor arrived through.
+   #[serde(rename = "ref")]
+   #[validate(length(max = 64, message = "ref must be at most 64 characters"))]
+   pub referral_code: Option<String>,
+   /// Person the donation is made in honor of; shown on the public donation entry.
+   #[validate(length(min = 1, max = 100, message = "honoree_name must be between 1 and 100 characters"))]
+   pub honoree_name: Option<String>,
+   /// Where the honoree's greeting is sent. Never shown publicly.
+   #[validate(email(message = "honoree_email must be a valid email address"))]
+   pub honoree_email: Option<String>,
+   /// Hides the donor's name, avatar and id on public donation lists.
+   #[serde(default)]
+   pub anonymous: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateDonationMessageRequest {
+    #[validate(length(max = 500, message = "message must be at most 500 characters"))]
     pub message: Option<String>,
 }
 
+/// Donation as shown on public campaign pages, with the donor's profile card.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct PublicDonation {
+    pub id: i32,
+    /// None, like the name and avatar, when the donor gave anonymously.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i32>,
+    pub anonymous: bool,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub amount: f64,
+    pub message: Option<String>,
+    /// Honoree's name, for an "in honor of" line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_honor_of: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Honoree details of a gift donation, read when the greeting is sent.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DonationGift {
+    pub donation_id: i32,
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub message: Option<String>,
+    pub honoree_name: Option<String>,
+    pub honoree_email: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationPrivateNote {
+    pub donation_id: i32,
+    pub user_id: i32,
+    pub amount: f64,
+    pub private_note: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignDonationTotal {
+    pub campaign_id: i32,
+    pub total_amount: f64,
+    pub donation_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct UserCampaignTotal {
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub total_amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct MonthlyDonationTotal {
+    pub month: String,
+    pub total_amount: f64,
+    pub donation_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationSummary {
+    pub lifetime_total: f64,
+    pub campaigns_supported: usize,
+    pub per_campaign: Vec<CampaignDonationTotal>,
+    pub per_month: Vec<MonthlyDonationTotal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignDonationStats {
+    pub campaign_id: i32,
+    pub total_amount: f64,
+    pub donor_count: i64,
+    pub recent_donations: Vec<Donation>,
+}
+
+/// Repeat donors gave to this campaign more than once; everyone else counts as new.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignDonorCounts {
+    pub unique_donors: i64,
+    pub repeat_donors: i64,
+    pub median_donation: Option<f64>,
+}
+
+/// Donations whose amount falls in `bucket` of the bounds passed to the query
+/// (0 is below the first bound).
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DonationSizeCount {
+    pub bucket: i32,
+    pub donation_count: i64,
+    pub total_amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationSizeBucket {
+    pub min_amount: f64,
+    /// Exclusive; `None` for the open-ended top bucket.
+    pub max_amount: Option<f64>,
+    pub donation_count: i64,
+    pub total_amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignDonorStatistics {
+    pub campaign_id: i32,
+    pub unique_donors: i64,
+    pub new_donors: i64,
+    pub repeat_donors: i64,
+    pub median_donation: Option<f64>,
+    pub size_histogram: Vec<DonationSizeBucket>,
+}
+
+/// Settled donations attributed to one `ref` code.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ReferralTotal {
+    /// `None` groups donations made without a code.
+    pub referral_code: Option<String>,
+    /// Channel of the short link the code belongs to, if it is one.
+    pub channel: Option<String>,
+    pub donation_count: i64,
+    pub donor_count: i64,
+    pub total_amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CampaignRefundReport {
+    pub campaign_id: i32,
+    pub refunded_count: usize,
+    pub refunded_amount: f64,
+}
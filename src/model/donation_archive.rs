@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+use crate::model::donation::Donation;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ArchivedDonation {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub donation: Donation,
+    pub archived_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationArchiveReport {
+    /// Donations created before this instant were eligible.
+    pub cutoff: DateTime<Utc>,
+    pub archived_count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationRestoreReport {
+    pub campaign_id: i32,
+    pub restored_count: u64,
+}
@@ -0,0 +1,36 @@
+use crate::model::donation::DonationReceipt;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct BasketItem {
+    #[validate(range(min = 1, message = "campaign_id must be a valid campaign id"))]
+    pub campaign_id: i32,
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewDonationBasketRequest {
+    #[validate(
+        length(min = 1, max = 20, message = "items must contain between 1 and 20 campaigns"),
+        nested
+    )]
+    pub items: Vec<BasketItem>,
+    #[validate(length(max = 500, message = "message must be at most 500 characters"))]
+    pub message: Option<String>,
+}
+
+/// One receipt covering every donation of a basket. Each line keeps its own receipt
+/// number, since the gap-free sequence is per donation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationBasketReceipt {
+    pub user_id: i32,
+    pub total_amount: f64,
+    /// What capped campaigns returned to the wallet; not part of `total_amount`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_excess: Option<f64>,
+    pub issued_at: DateTime<Utc>,
+    pub lines: Vec<DonationReceipt>,
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+use crate::model::donation::DonationStatus;
+
+/// One NDJSON line of the warehouse export. Messages and private notes are left out.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationExportRow {
+    pub id: i32,
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub amount: f64,
+    pub status: DonationStatus,
+    pub referral_code: Option<String>,
+    pub receipt_number: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A delta pushed to object storage; the next delta starts at `until`.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationExportRun {
+    pub id: i32,
+    /// `None` for the first, full export.
+    pub since: Option<DateTime<Utc>>,
+    pub until: DateTime<Utc>,
+    pub object_key: String,
+    pub row_count: i64,
+    pub created_at: DateTime<Utc>,
+}
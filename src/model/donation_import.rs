@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DonationImportFormat {
+    Ndjson,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+pub struct ImportedDonationRow {
+    #[validate(range(min = 1, message = "user_id must be a valid user id"))]
+    pub user_id: i32,
+    #[validate(range(min = 1, message = "campaign_id must be a valid campaign id"))]
+    pub campaign_id: i32,
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+    #[validate(length(max = 500, message = "message must be at most 500 characters"))]
+    pub message: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportRowError {
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DonationImportReport {
+    pub total_rows: usize,
+    pub imported: usize,
+    pub failed: usize,
+    pub errors: Vec<ImportRowError>,
+}
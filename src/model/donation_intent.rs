@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "donation_intent_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DonationIntentStatus {
+    Pending,
+    Confirmed,
+    Expired,
+    Failed,
+}
+
+/// Funds reserved on the donor's wallet for a donation they have not confirmed yet.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationIntent {
+    pub id: i32,
+    pub user_id: i32,
+    pub campaign_id: i32,
+    pub amount: f64,
+    pub message: Option<String>,
+    #[serde(skip_serializing)]
+    pub private_note: Option<String>,
+    pub tier_id: Option<i32>,
+    pub referral_code: Option<String>,
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+    pub status: DonationIntentStatus,
+    pub donation_id: Option<i32>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Returned once on creation; only the token's hash is stored.
+#[derive(Debug, Serialize)]
+pub struct CreatedDonationIntent {
+    pub confirmation_token: String,
+    #[serde(flatten)]
+    pub intent: DonationIntent,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ConfirmDonationIntentRequest {
+    #[validate(length(min = 1, max = 64, message = "confirmation_token is required"))]
+    pub confirmation_token: String,
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// A reward donors can pick when giving at least `min_amount`.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct DonationTier {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub min_amount: f64,
+    /// `None` for unlimited tiers.
+    pub quantity: Option<i32>,
+    pub remaining: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewDonationTierRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "title must be between 1 and 100 characters"
+    ))]
+    pub title: String,
+    #[validate(length(max = 1000, message = "description must be at most 1000 characters"))]
+    pub description: Option<String>,
+    #[validate(range(exclusive_min = 0.0, message = "min_amount must be positive"))]
+    pub min_amount: f64,
+    #[validate(range(min = 1, message = "quantity must be at least 1"))]
+    pub quantity: Option<i32>,
+}
+
+/// A donation that claimed a tier, for the fundraiser to fulfill.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct RewardClaim {
+    pub donation_id: i32,
+    pub tier_id: i32,
+    pub tier_title: String,
+    pub user_id: i32,
+    pub amount: f64,
+    pub claimed_at: DateTime<Utc>,
+}
@@ -0,0 +1,280 @@
+use crate::model::notification_preference::NotificationCount;
+use crate::model::risk::RiskActivity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DomainEventKind {
+    DonationCreated,
+    DonationRefunded,
+    CampaignApproved,
+    CampaignCompleted,
+    PayoutApproved,
+    DisputeOpened,
+    DisputeResolved,
+    PayoutRequested,
+    RiskBlocked,
+    CampaignFlagged,
+    SuspiciousLogin,
+    WalletBalanceLow,
+    HonoreeGreeting,
+    KycSubmitted,
+    KycApproved,
+    KycRejected,
+    SavedSearchMatched,
+    UserInvited,
+    CampaignSuspended,
+    CampaignSuspensionResolved,
+    CampaignRejected,
+    NotificationDigest,
+}
+
+impl DomainEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainEventKind::DonationCreated => "donation_created",
+            DomainEventKind::DonationRefunded => "donation_refunded",
+            DomainEventKind::CampaignApproved => "campaign_approved",
+            DomainEventKind::CampaignCompleted => "campaign_completed",
+            DomainEventKind::PayoutApproved => "payout_approved",
+            DomainEventKind::DisputeOpened => "dispute_opened",
+            DomainEventKind::DisputeResolved => "dispute_resolved",
+            DomainEventKind::PayoutRequested => "payout_requested",
+            DomainEventKind::RiskBlocked => "risk_blocked",
+            DomainEventKind::CampaignFlagged => "campaign_flagged",
+            DomainEventKind::SuspiciousLogin => "suspicious_login",
+            DomainEventKind::WalletBalanceLow => "wallet_balance_low",
+            DomainEventKind::HonoreeGreeting => "honoree_greeting",
+            DomainEventKind::KycSubmitted => "kyc_submitted",
+            DomainEventKind::KycApproved => "kyc_approved",
+            DomainEventKind::KycRejected => "kyc_rejected",
+            DomainEventKind::SavedSearchMatched => "saved_search_matched",
+            DomainEventKind::UserInvited => "user_invited",
+            DomainEventKind::CampaignSuspended => "campaign_suspended",
+            DomainEventKind::CampaignSuspensionResolved => "campaign_suspension_resolved",
+            DomainEventKind::CampaignRejected => "campaign_rejected",
+            DomainEventKind::NotificationDigest => "notification_digest",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    DonationCreated {
+        donation_id: i32,
+        user_id: i32,
+        campaign_id: i32,
+        amount: f64,
+    },
+    DonationRefunded {
+        donation_id: i32,
+        user_id: i32,
+        campaign_id: i32,
+        amount: f64,
+    },
+    CampaignApproved {
+        campaign_id: i32,
+    },
+    CampaignCompleted {
+        campaign_id: i32,
+    },
+    PayoutApproved {
+        withdrawal_id: i32,
+        user_id: i32,
+        amount: f64,
+    },
+    DisputeOpened {
+        dispute_id: i32,
+        donation_id: i32,
+        user_id: i32,
+        campaign_id: i32,
+        amount: f64,
+    },
+    DisputeResolved {
+        dispute_id: i32,
+        donation_id: i32,
+        user_id: i32,
+        campaign_id: i32,
+        refunded: bool,
+    },
+    PayoutRequested {
+        withdrawal_id: i32,
+        user_id: i32,
+        amount: f64,
+    },
+    RiskBlocked {
+        user_id: i32,
+        activity: RiskActivity,
+        amount: f64,
+        reason: String,
+    },
+    CampaignFlagged {
+        campaign_id: i32,
+        reason: String,
+    },
+    SuspiciousLogin {
+        user_id: i32,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+        country: Option<String>,
+    },
+    WalletBalanceLow {
+        user_id: i32,
+        available_balance: f64,
+        threshold: f64,
+    },
+    /// Greeting for the person a gift donation was made in honor of. Delivery goes to
+    /// the account registered under `honoree_email` if there is one, else to the address.
+    HonoreeGreeting {
+        donation_id: i32,
+        donor_id: i32,
+        campaign_id: i32,
+        honoree_name: Option<String>,
+        honoree_email: String,
+        message: Option<String>,
+    },
+    /// `user_id` is whoever submitted; `organization_id` is set when the submission
+    /// verifies an organization.
+    KycSubmitted {
+        submission_id: i32,
+        user_id: i32,
+        organization_id: Option<i32>,
+    },
+    KycApproved {
+        submission_id: i32,
+        user_id: i32,
+        organization_id: Option<i32>,
+    },
+    KycRejected {
+        submission_id: i32,
+        user_id: i32,
+        organization_id: Option<i32>,
+        reason: String,
+    },
+    /// A newly approved campaign matches one of the user's saved searches.
+    SavedSearchMatched {
+        saved_search_id: i32,
+        user_id: i32,
+        campaign_id: i32,
+    },
+    /// An admin created the account on the user's behalf; the invitation tells them
+    /// how to sign in.
+    UserInvited {
+        user_id: i32,
+        email: String,
+        name: String,
+    },
+    /// Tells the fundraiser their campaign stopped taking donations, and why.
+    CampaignSuspended {
+        campaign_id: i32,
+        fundraiser_id: Option<i32>,
+        reason: String,
+    },
+    CampaignSuspensionResolved {
+        campaign_id: i32,
+        fundraiser_id: Option<i32>,
+        reinstated: bool,
+    },
+    /// Rejected at review. `reason` is set when the admin gave one for a bulk
+    /// rejection; otherwise the failed checklist items say why.
+    CampaignRejected {
+        campaign_id: i32,
+        reason: Option<String>,
+    },
+    /// Daily summary for users who chose digest mode: how many of each notification
+    /// they received since `since`.
+    NotificationDigest {
+        user_id: i32,
+        since: DateTime<Utc>,
+        counts: Vec<NotificationCount>,
+    },
+}
+
+impl DomainEvent {
+    pub fn kind(&self) -> DomainEventKind {
+        match self {
+            DomainEvent::DonationCreated { .. } => DomainEventKind::DonationCreated,
+            DomainEvent::DonationRefunded { .. } => DomainEventKind::DonationRefunded,
+            DomainEvent::CampaignApproved { .. } => DomainEventKind::CampaignApproved,
+            DomainEvent::CampaignCompleted { .. } => DomainEventKind::CampaignCompleted,
+            DomainEvent::PayoutApproved { .. } => DomainEventKind::PayoutApproved,
+            DomainEvent::DisputeOpened { .. } => DomainEventKind::DisputeOpened,
+            DomainEvent::DisputeResolved { .. } => DomainEventKind::DisputeResolved,
+            DomainEvent::PayoutRequested { .. } => DomainEventKind::PayoutRequested,
+            DomainEvent::RiskBlocked { .. } => DomainEventKind::RiskBlocked,
+            DomainEvent::CampaignFlagged { .. } => DomainEventKind::CampaignFlagged,
+            DomainEvent::SuspiciousLogin { .. } => DomainEventKind::SuspiciousLogin,
+            DomainEvent::WalletBalanceLow { .. } => DomainEventKind::WalletBalanceLow,
+            DomainEvent::HonoreeGreeting { .. } => DomainEventKind::HonoreeGreeting,
+            DomainEvent::KycSubmitted { .. } => DomainEventKind::KycSubmitted,
+            DomainEvent::KycApproved { .. } => DomainEventKind::KycApproved,
+            DomainEvent::KycRejected { .. } => DomainEventKind::KycRejected,
+            DomainEvent::SavedSearchMatched { .. } => DomainEventKind::SavedSearchMatched,
+            DomainEvent::UserInvited { .. } => DomainEventKind::UserInvited,
+            DomainEvent::CampaignSuspended { .. } => DomainEventKind::CampaignSuspended,
+            DomainEvent::CampaignSuspensionResolved { .. } => {
+                DomainEventKind::CampaignSuspensionResolved
+            }
+            DomainEvent::CampaignRejected { .. } => DomainEventKind::CampaignRejected,
+            DomainEvent::NotificationDigest { .. } => DomainEventKind::NotificationDigest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum SubscriberOutcome {
+    Delivered,
+    Failed {
+        error: String,
+    },
+    /// Cancelled after the bus's per-subscriber timeout.
+    TimedOut,
+}
+
+impl SubscriberOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubscriberOutcome::Delivered => "delivered",
+            SubscriberOutcome::Failed { .. } => "failed",
+            SubscriberOutcome::TimedOut => "timed_out",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubscriberResult {
+    pub subscriber: &'static str,
+    #[serde(flatten)]
+    pub outcome: SubscriberOutcome,
+    pub elapsed_ms: u64,
+}
+
+/// What happened to each subscriber of one published event, critical ones first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PublishReport {
+    pub results: Vec<SubscriberResult>,
+}
+
+impl fmt::Display for SubscriberResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            SubscriberOutcome::Delivered => write!(f, "{} delivered", self.subscriber),
+            SubscriberOutcome::Failed { error } => {
+                write!(f, "{} failed: {}", self.subscriber, error)
+            }
+            SubscriberOutcome::TimedOut => write!(f, "{} timed out", self.subscriber),
+        }
+    }
+}
+
+impl PublishReport {
+    pub fn failures(&self) -> impl Iterator<Item = &SubscriberResult> {
+        self.results
+            .iter()
+            .filter(|result| result.outcome != SubscriberOutcome::Delivered)
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "evidence_processing_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceProcessingStatus {
+    Queued,
+    Completed,
+    Failed,
+}
+
+/// An uploaded evidence file. Only the processed copy, with EXIF removed, is stored.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct EvidenceFile {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub uploaded_by: i32,
+    pub file_name: String,
+    pub size_bytes: i64,
+    pub status: EvidenceProcessingStatus,
+    pub content_type: Option<String>,
+    /// Set for PDFs.
+    pub page_count: Option<i32>,
+    pub exif_stripped: bool,
+    pub object_key: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvidenceMetadata {
+    pub content_type: &'static str,
+    pub page_count: Option<i32>,
+    pub exif_stripped: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EvidenceProcessingReport {
+    pub campaign_id: i32,
+    pub queued: usize,
+    pub failed: usize,
+    /// No file is still waiting to be processed.
+    pub processing_complete: bool,
+    pub files: Vec<EvidenceFile>,
+}
+
+impl EvidenceProcessingReport {
+    pub fn new(campaign_id: i32, files: Vec<EvidenceFile>) -> Self {
+        let count = |status| files.iter().filter(|file| file.status == status).count();
+        let queued = count(EvidenceProcessingStatus::Queued);
+        let failed = count(EvidenceProcessingStatus::Failed);
+        EvidenceProcessingReport {
+            campaign_id,
+            queued,
+            failed,
+            processing_complete: queued == 0,
+            files,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use sqlx::FromRow;
+use crate::model::donation::Donation;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct FundraiserCampaignOverview {
+    pub total_raised: f64,
+    pub active_campaigns: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FundraiserSummary {
+    pub total_raised: f64,
+    pub active_campaigns: i64,
+    pub pending_payout_count: usize,
+    pub pending_payout_amount: f64,
+    pub recent_donations: Vec<Donation>,
+}
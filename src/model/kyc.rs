@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "kyc_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum KycStatus {
+    /// Documents are still being uploaded.
+    Draft,
+    /// Waiting in the admin review queue.
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "kyc_document_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum KycDocumentType {
+    IdCard,
+    Selfie,
+    RegistrationCertificate,
+    TaxId,
+}
+
+impl KycDocumentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KycDocumentType::IdCard => "id_card",
+            KycDocumentType::Selfie => "selfie",
+            KycDocumentType::RegistrationCertificate => "registration_certificate",
+            KycDocumentType::TaxId => "tax_id",
+        }
+    }
+}
+
+/// Whose identity a submission verifies. Approving an organization's submission also
+/// marks the organization verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KycSubject {
+    User(i32),
+    Organization(i32),
+}
+
+impl KycSubject {
+    /// Documents that must be uploaded before the submission can go to review.
+    pub fn required_documents(&self) -> &'static [KycDocumentType] {
+        match self {
+            KycSubject::User(_) => &[KycDocumentType::IdCard, KycDocumentType::Selfie],
+            KycSubject::Organization(_) => &[KycDocumentType::RegistrationCertificate],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct KycSubmission {
+    pub id: i32,
+    /// Exactly one of `user_id` and `organization_id` is set.
+    pub user_id: Option<i32>,
+    pub organization_id: Option<i32>,
+    pub submitted_by: i32,
+    pub status: KycStatus,
+    pub review_note: Option<String>,
+    pub reviewed_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl KycSubmission {
+    pub fn subject(&self) -> KycSubject {
+        match self.organization_id {
+            Some(organization_id) => KycSubject::Organization(organization_id),
+            None => KycSubject::User(self.user_id.unwrap_or_default()),
+        }
+    }
+}
+
+/// An uploaded identity document. Only the processed copy, with EXIF removed, is stored.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct KycDocument {
+    pub id: i32,
+    pub submission_id: i32,
+    pub document_type: KycDocumentType,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub object_key: String,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+/// Verification status of a user or organization, with its latest submission.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KycVerification {
+    /// A submission has been approved at some point; payouts are allowed.
+    pub verified: bool,
+    pub submission: Option<KycSubmission>,
+    pub documents: Vec<KycDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewKycRequest {
+    pub note: Option<String>,
+}
@@ -0,0 +1,50 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct CampaignStatusAmount {
+    pub campaign_status: String,
+    pub total_amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BusinessMetrics {
+    pub donations_created_total: u64,
+    pub donation_amount_by_campaign_status: Vec<CampaignStatusAmount>,
+    pub wallet_balance_total: f64,
+}
+
+impl BusinessMetrics {
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# HELP donations_created_total Donations created since the process started\n\
+             # TYPE donations_created_total counter\n",
+        );
+        out.push_str(&format!(
+            "donations_created_total {}\n",
+            self.donations_created_total
+        ));
+
+        out.push_str(
+            "# HELP donation_amount_sum Settled donation amount by campaign status\n\
+             # TYPE donation_amount_sum gauge\n",
+        );
+        for row in &self.donation_amount_by_campaign_status {
+            out.push_str(&format!(
+                "donation_amount_sum{{campaign_status=\"{}\"}} {}\n",
+                row.campaign_status, row.total_amount
+            ));
+        }
+
+        out.push_str(
+            "# HELP wallet_balance_total Sum of all wallet balances\n\
+             # TYPE wallet_balance_total gauge\n",
+        );
+        out.push_str(&format!(
+            "wallet_balance_total {}\n",
+            self.wallet_balance_total
+        ));
+        out
+    }
+}
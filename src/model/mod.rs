@@ -1 +1,47 @@
-pub mod donation;
\ No newline at end of file
+pub mod admin_action;
+pub mod api_key;
+pub mod cache;
+pub mod campaign;
+pub mod campaign_budget;
+pub mod campaign_feed;
+pub mod campaign_image;
+pub mod campaign_location;
+pub mod campaign_member;
+pub mod campaign_overflow;
+pub mod campaign_ranking;
+pub mod campaign_review;
+pub mod campaign_share;
+pub mod campaign_suspension;
+pub mod campaign_widget;
+pub mod data_export;
+pub mod dispute;
+pub mod donation;
+pub mod donation_archive;
+pub mod donation_basket;
+pub mod donation_export;
+pub mod donation_import;
+pub mod donation_intent;
+pub mod donation_tier;
+pub mod event;
+pub mod evidence;
+pub mod fundraiser;
+pub mod kyc;
+pub mod metrics;
+pub mod notification_delivery;
+pub mod notification_preference;
+pub mod organization;
+pub mod outbox;
+pub mod profile;
+pub mod query_diagnostics;
+pub mod reconciliation;
+pub mod risk;
+pub mod saved_search;
+pub mod security_event;
+pub mod short_link;
+pub mod statistic;
+pub mod statistics_snapshot;
+pub mod tax_summary;
+pub mod transaction;
+pub mod two_factor;
+pub mod user_import;
+pub mod withdrawal;
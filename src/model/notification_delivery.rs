@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use rocket::FromFormField;
+use rocket::serde::json::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "notification_delivery_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationDeliveryStatus {
+    /// Waiting for `next_attempt_at`.
+    Pending,
+    Delivered,
+    /// Out of attempts; only an admin retry sends it again.
+    Dead,
+}
+
+/// One subscriber's delivery of one outbox event. Rows only exist for deliveries
+/// that failed at least once; first-time successes are not recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct NotificationDelivery {
+    pub id: i64,
+    pub outbox_id: i64,
+    pub event_type: String,
+    pub subscriber: String,
+    pub status: NotificationDeliveryStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// The event as stored in the outbox.
+    pub payload: Value,
+}
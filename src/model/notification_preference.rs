@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct NotificationPreferences {
+    pub user_id: i32,
+    /// Notify when a donation takes the available wallet balance to or below this
+    /// amount; `0` only alerts on an empty wallet and `None` opts out.
+    pub low_balance_threshold: Option<f64>,
+    /// Roll user notifications into one summary a day.
+    pub daily_digest: bool,
+    /// `None` until the user first saves their preferences.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl NotificationPreferences {
+    pub fn defaults(user_id: i32) -> Self {
+        NotificationPreferences {
+            user_id,
+            low_balance_threshold: None,
+            daily_digest: false,
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateNotificationPreferencesRequest {
+    #[validate(range(min = 0.0, message = "low_balance_threshold must not be negative"))]
+    pub low_balance_threshold: Option<f64>,
+    #[serde(default)]
+    pub daily_digest: bool,
+}
+
+/// One line of a daily digest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct NotificationCount {
+    pub event_type: String,
+    pub count: i64,
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "organization_role", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationRole {
+    Admin,
+    Treasurer,
+    Member,
+}
+
+/// Actions on an organization that are gated by membership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrganizationAction {
+    View,
+    ManageMembers,
+    ManageCampaigns,
+    ManageVerification,
+    ViewWallet,
+    RequestPayout,
+}
+
+impl OrganizationRole {
+    pub fn allows(&self, action: OrganizationAction) -> bool {
+        match self {
+            OrganizationRole::Admin => true,
+            OrganizationRole::Treasurer => matches!(
+                action,
+                OrganizationAction::View
+                    | OrganizationAction::ViewWallet
+                    | OrganizationAction::RequestPayout
+            ),
+            OrganizationRole::Member => action == OrganizationAction::View,
+        }
+    }
+}
+
+/// Public NGO profile. `verified_at` is set once an admin has checked the
+/// registration; only verified organizations can own campaigns or request payouts.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Organization {
+    pub id: i32,
+    pub name: String,
+    pub registration_number: String,
+    pub description: Option<String>,
+    pub website: Option<String>,
+    pub created_by: i32,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub verified_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct OrganizationMember {
+    pub organization_id: i32,
+    pub user_id: i32,
+    pub role: OrganizationRole,
+    pub added_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The wallet shared by the organization's members. Funds held for a pending payout
+/// stay in `balance` but are not available.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct OrganizationWallet {
+    pub organization_id: i32,
+    pub balance: f64,
+    pub held_amount: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewOrganizationRequest {
+    #[validate(length(min = 1, max = 200, message = "name is required"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 100, message = "registration_number is required"))]
+    pub registration_number: String,
+    #[validate(length(max = 1000, message = "description must be at most 1000 characters"))]
+    pub description: Option<String>,
+    #[validate(url(message = "website must be a valid URL"))]
+    pub website: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AddOrganizationMemberRequest {
+    #[validate(range(min = 1, message = "user_id must be a valid user id"))]
+    pub user_id: i32,
+    pub role: OrganizationRole,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AssignCampaignOrganizationRequest {
+    #[validate(range(min = 1, message = "organization_id must be a valid organization id"))]
+    pub organization_id: i32,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use rocket::serde::json::Value;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "outbox_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    /// Waiting for `next_attempt_at`, or claimed by a dispatcher until then.
+    Pending,
+    Dispatched,
+    /// Out of attempts; left for an admin to inspect.
+    Dead,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct OutboxEvent {
+    pub id: i64,
+    pub event_type: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub status: OutboxStatus,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Profile {
+    pub user_id: i32,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateProfileRequest {
+    #[validate(length(min = 1, max = 50, message = "display_name must be between 1 and 50 characters"))]
+    pub display_name: String,
+    #[validate(url(message = "avatar_url must be a valid URL"))]
+    pub avatar_url: Option<String>,
+    #[validate(length(max = 500, message = "bio must be at most 500 characters"))]
+    pub bio: Option<String>,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// A statement that crossed the slow-query threshold, aggregated over every run.
+/// `sql` has its string literals redacted; bound parameter values are never kept.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlowQuery {
+    pub sql: String,
+    pub count: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub last_seen: DateTime<Utc>,
+    /// `EXPLAIN ANALYZE` output of the slowest run, when plan capture is enabled.
+    pub plan: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryDiagnostics {
+    pub threshold_ms: u64,
+    pub plan_capture_enabled: bool,
+    pub slow_queries_total: u64,
+    /// Slowest first.
+    pub slow_queries: Vec<SlowQuery>,
+}
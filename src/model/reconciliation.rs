@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// One line of a provider settlement report; `order_id` is our top-up transaction id.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SettlementRow {
+    pub order_id: i32,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "discrepancy_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DiscrepancyKind {
+    /// Settled by the provider but no top-up in our ledger.
+    MissingInLedger,
+    /// Top-up in our ledger that the provider did not settle.
+    MissingInSettlement,
+    /// The provider settled the same order more than once.
+    Duplicated,
+    AmountMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Discrepancy {
+    pub kind: DiscrepancyKind,
+    pub order_id: i32,
+    pub ledger_amount: Option<f64>,
+    pub settlement_amount: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ReconciliationRun {
+    pub id: i32,
+    pub provider: String,
+    pub period_from: DateTime<Utc>,
+    pub period_to: DateTime<Utc>,
+    pub settlement_rows: i32,
+    pub matched: i32,
+    pub discrepancy_count: i32,
+    pub created_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReconciliationReport {
+    #[serde(flatten)]
+    pub run: ReconciliationRun,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewReconciliationRun {
+    pub provider: String,
+    pub period_from: DateTime<Utc>,
+    pub period_to: DateTime<Utc>,
+    pub settlement_rows: i32,
+    pub matched: i32,
+    pub created_by: i32,
+    pub discrepancies: Vec<Discrepancy>,
+}
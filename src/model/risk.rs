@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "risk_rule_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RiskRuleKind {
+    DailyAmountCap,
+    DistinctCampaignsPerHour,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "risk_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RiskAction {
+    Block,
+    Flag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "risk_activity", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RiskActivity {
+    Donation,
+    TopUp,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct RiskRule {
+    pub id: i32,
+    pub kind: RiskRuleKind,
+    pub threshold: f64,
+    pub action: RiskAction,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct BlacklistEntry {
+    pub id: i32,
+    pub user_id: Option<i32>,
+    pub ip_address: Option<String>,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct FlaggedActivity {
+    pub id: i32,
+    pub user_id: i32,
+    pub rule_id: Option<i32>,
+    pub activity: RiskActivity,
+    pub amount: f64,
+    pub action: RiskAction,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewRiskRuleRequest {
+    pub kind: RiskRuleKind,
+    pub threshold: f64,
+    pub action: RiskAction,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRiskRuleRequest {
+    pub threshold: f64,
+    pub action: RiskAction,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewBlacklistEntryRequest {
+    pub user_id: Option<i32>,
+    pub ip_address: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskDecision {
+    Allow,
+    Flag(String),
+    Block(String),
+}
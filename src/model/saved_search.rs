@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// A donor's standing search. Every criterion that is set must match; matching is
+/// case-insensitive and keywords must all appear in the campaign title.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct SavedSearch {
+    pub id: i32,
+    pub user_id: i32,
+    pub keywords: Option<String>,
+    pub category: Option<String>,
+    pub region: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Create or replace a saved search. At least one criterion is required.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SavedSearchRequest {
+    #[validate(length(max = 200, message = "keywords must be at most 200 characters"))]
+    pub keywords: Option<String>,
+    #[validate(length(max = 100, message = "category must be at most 100 characters"))]
+    pub category: Option<String>,
+    #[validate(length(max = 100, message = "region must be at most 100 characters"))]
+    pub region: Option<String>,
+}
+
+/// A saved search that matched a newly approved campaign.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct SavedSearchMatch {
+    pub saved_search_id: i32,
+    pub user_id: i32,
+}
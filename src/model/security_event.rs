@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "security_event_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityEventType {
+    LoginSucceeded,
+    LoginFailed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct SecurityEvent {
+    pub id: i64,
+    pub user_id: i32,
+    pub event_type: SecurityEventType,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub country: Option<String>,
+    /// Set on successful logins from a device or country not seen for this user before.
+    pub suspicious: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LoginHistory {
+    pub has_previous_logins: bool,
+    pub known_device: bool,
+    pub known_country: bool,
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// A campaign share link for one distribution channel (poster, Instagram bio, ...).
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ShortLink {
+    pub id: i32,
+    pub slug: String,
+    pub campaign_id: i32,
+    pub channel: Option<String>,
+    pub click_count: i64,
+    pub created_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShortLinkResponse {
+    #[serde(flatten)]
+    pub link: ShortLink,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewShortLinkRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "channel must be between 1 and 50 characters"
+    ))]
+    pub channel: Option<String>,
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// Platform-wide totals shown on the public homepage.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct PublicStats {
+    /// Settled and imported donations, archived ones included.
+    pub total_raised: f64,
+    /// Campaigns whose collected amount has reached their target.
+    pub campaigns_funded: i64,
+    pub donor_count: i64,
+    pub computed_at: DateTime<Utc>,
+}
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Platform KPIs captured by the nightly snapshot job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "statistic_metric", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum StatisticMetric {
+    /// Settled and imported donations, all time.
+    #[field(value = "donation_amount_total")]
+    DonationAmountTotal,
+    #[field(value = "donation_count")]
+    DonationCount,
+    #[field(value = "donor_count")]
+    DonorCount,
+    #[field(value = "active_campaigns")]
+    ActiveCampaigns,
+    #[field(value = "completed_campaigns")]
+    CompletedCampaigns,
+    #[field(value = "wallet_balance_total")]
+    WalletBalanceTotal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, FromFormField)]
+pub enum StatisticRange {
+    #[field(value = "30d")]
+    #[serde(rename = "30d")]
+    Days30,
+    #[field(value = "90d")]
+    #[serde(rename = "90d")]
+    Days90,
+    #[field(value = "1y")]
+    #[serde(rename = "1y")]
+    Year,
+    #[field(value = "all")]
+    #[serde(rename = "all")]
+    All,
+}
+
+impl StatisticRange {
+    /// First snapshot date included in the range, or `None` for all history.
+    pub fn since(self, now: DateTime<Utc>) -> Option<NaiveDate> {
+        let days = match self {
+            StatisticRange::Days30 => 30,
+            StatisticRange::Days90 => 90,
+            StatisticRange::Year => 365,
+            StatisticRange::All => return None,
+        };
+        Some((now - Duration::days(days)).date_naive())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct StatisticPoint {
+    pub snapshot_date: NaiveDate,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatisticHistory {
+    pub metric: StatisticMetric,
+    pub range: StatisticRange,
+    pub points: Vec<StatisticPoint>,
+}
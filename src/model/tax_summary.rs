@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// A campaign an admin has confirmed donors can deduct from taxable income.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct TaxDeductibleCampaign {
+    pub campaign_id: i32,
+    pub campaign_title: String,
+    pub marked_by: i32,
+    pub marked_at: DateTime<Utc>,
+}
+
+/// A settled donation with a receipt, to a campaign that is tax-deductible.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct EligibleDonation {
+    pub campaign_id: i32,
+    pub campaign_title: String,
+    pub donation_id: i32,
+    pub receipt_number: String,
+    pub amount: f64,
+    pub issued_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaxReceiptLine {
+    pub donation_id: i32,
+    pub receipt_number: String,
+    pub amount: f64,
+    pub issued_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaxSummaryCampaign {
+    pub campaign_id: i32,
+    pub campaign_title: String,
+    pub total_amount: f64,
+    pub receipts: Vec<TaxReceiptLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaxSummary {
+    pub user_id: i32,
+    pub year: i32,
+    pub total_amount: f64,
+    pub campaigns: Vec<TaxSummaryCampaign>,
+}
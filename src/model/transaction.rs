@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "transaction_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionType {
+    #[field(value = "top_up")]
+    TopUp,
+    #[field(value = "donation")]
+    Donation,
+    #[field(value = "withdrawal")]
+    Withdrawal,
+    #[field(value = "refund")]
+    Refund,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Transaction {
+    pub id: i32,
+    pub user_id: i32,
+    pub campaign_id: Option<i32>,
+    pub transaction_type: TransactionType,
+    pub amount: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A payment provider's notice that a wallet top-up was paid. `reference` is the
+/// provider's own payment id; the recorded transaction's id is the order id its
+/// settlement report lists.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct TopUpNotification {
+    pub user_id: i32,
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+    #[validate(length(min = 1, max = 128, message = "reference must be 1-128 characters"))]
+    pub reference: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionFilter {
+    pub transaction_type: Option<TransactionType>,
+    pub user_id: Option<i32>,
+    pub campaign_id: Option<i32>,
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TransactionPage {
+    pub items: Vec<Transaction>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct AdminTwoFactor {
+    pub admin_id: i32,
+    pub secret: String,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub last_verified_at: Option<DateTime<Utc>>,
+    /// TOTP time step of the last accepted code, so a code cannot be replayed.
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TwoFactorEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecoveryCodes {
+    pub recovery_codes: Vec<String>,
+}
+
+/// Either a 6-digit TOTP code or an unused recovery code.
+#[derive(Debug, Deserialize, Validate)]
+pub struct TwoFactorCodeRequest {
+    #[validate(length(
+        min = 6,
+        max = 32,
+        message = "code must be between 6 and 32 characters"
+    ))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StepUpStatus {
+    pub verified_until: DateTime<Utc>,
+}
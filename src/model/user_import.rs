@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    Donor,
+    Fundraiser,
+    Admin,
+}
+
+/// One line of the users CSV: `email,name,role`. Also the body the auth service
+/// receives for each account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct ImportedUserRow {
+    #[validate(email(message = "email must be a valid email address"))]
+    pub email: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    pub role: UserRole,
+}
+
+/// The auth service's answer for one submitted row.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ImportedAccount {
+    pub user_id: i32,
+    pub email: String,
+    /// False when the auth service already had an account with the email.
+    pub created: bool,
+}
+
+/// An account the import created, to be invited.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvitedUser {
+    pub user_id: i32,
+    pub email: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserImportStatus {
+    Created,
+    /// An account with the email already exists, or an earlier line had it.
+    Skipped,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UserImportRowResult {
+    pub line: usize,
+    /// None when the line couldn't be parsed.
+    pub email: Option<String>,
+    pub status: UserImportStatus,
+    pub user_id: Option<i32>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UserImportReport {
+    pub total_rows: usize,
+    pub created: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub rows: Vec<UserImportRowResult>,
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "withdrawal_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WithdrawalStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct Withdrawal {
+    pub id: i32,
+    pub user_id: i32,
+    pub amount: f64,
+    pub bank_name: String,
+    pub account_number: String,
+    pub account_holder: String,
+    pub status: WithdrawalStatus,
+    pub admin_note: Option<String>,
+    /// Admin approvals the payout policy asked for when it was requested; `0` means
+    /// it was approved automatically.
+    pub required_approvals: i32,
+    pub approval_count: i32,
+    /// Set when the payout comes out of an organization's wallet; `user_id` is then
+    /// the member who requested it.
+    pub organization_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// One approval or rejection of a withdrawal. `actor_id` is `None` for payouts the
+/// policy approved on its own.
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct WithdrawalAuditEntry {
+    pub id: i32,
+    pub withdrawal_id: i32,
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewWithdrawalRequest {
+    #[validate(range(exclusive_min = 0.0, message = "amount must be positive"))]
+    pub amount: f64,
+    #[validate(length(min = 1, max = 100, message = "bank_name is required"))]
+    pub bank_name: String,
+    #[validate(length(min = 1, max = 34, message = "account_number is required"))]
+    pub account_number: String,
+    #[validate(length(min = 1, max = 100, message = "account_holder is required"))]
+    pub account_holder: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewWithdrawalRequest {
+    pub note: Option<String>,
+}
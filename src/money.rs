@@ -0,0 +1,142 @@
+use crate::locale::Locale;
+use crate::model::donation::{CampaignDonationTotal, Donation, DonationReceipt, PublicDonation};
+use crate::model::donation_basket::DonationBasketReceipt;
+use crate::model::statistic::PublicStats;
+use crate::model::withdrawal::Withdrawal;
+use serde::Serialize;
+
+/// Formats a rupiah amount for display, e.g. "Rp 50.000" (id) or "IDR 50,000" (en).
+/// Cents are only shown when the amount has a fractional part.
+pub fn format_amount(amount: f64, locale: Locale) -> String {
+    let (prefix, thousands, decimal) = match locale {
+        Locale::Id => ("Rp ", '.', ','),
+        Locale::En => ("IDR ", ',', '.'),
+    };
+
+    let cents = (amount.abs() * 100.0).round() as u64;
+    let whole = (cents / 100).to_string();
+    let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index).is_multiple_of(3) {
+            grouped.push(thousands);
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if amount < 0.0 && cents > 0 { "-" } else { "" };
+    match cents % 100 {
+        0 => format!("{}{}{}", sign, prefix, grouped),
+        fraction => format!("{}{}{}{}{:02}", sign, prefix, grouped, decimal, fraction),
+    }
+}
+
+pub trait Monetary {
+    fn amount(&self) -> f64;
+}
+
+impl Monetary for Donation {
+    fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+impl Monetary for DonationReceipt {
+    fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+impl Monetary for DonationBasketReceipt {
+    fn amount(&self) -> f64 {
+        self.total_amount
+    }
+}
+
+impl Monetary for PublicDonation {
+    fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+impl Monetary for Withdrawal {
+    fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+impl Monetary for CampaignDonationTotal {
+    fn amount(&self) -> f64 {
+        self.total_amount
+    }
+}
+
+impl Monetary for PublicStats {
+    fn amount(&self) -> f64 {
+        self.total_raised
+    }
+}
+
+/// Serializes `T` unchanged, plus `amount_formatted` when the client asked for a locale.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Localized<T: Serialize> {
+    #[serde(flatten)]
+    pub inner: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_formatted: Option<String>,
+}
+
+impl<T: Serialize + Monetary> Localized<T> {
+    pub fn new(inner: T, locale: Option<Locale>) -> Self {
+        let amount_formatted = locale.map(|locale| format_amount(inner.amount(), locale));
+        Localized {
+            inner,
+            amount_formatted,
+        }
+    }
+
+    pub fn all(items: Vec<T>, locale: Option<Locale>) -> Vec<Self> {
+        items
+            .into_iter()
+            .map(|item| Localized::new(item, locale))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::DonationStatus;
+    use chrono::Utc;
+
+    #[test]
+    fn test_format_amount_per_locale() {
+        assert_eq!(format_amount(50_000.0, Locale::Id), "Rp 50.000");
+        assert_eq!(format_amount(1_234_567.5, Locale::Id), "Rp 1.234.567,50");
+        assert_eq!(format_amount(50_000.0, Locale::En), "IDR 50,000");
+        assert_eq!(format_amount(999.0, Locale::En), "IDR 999");
+        assert_eq!(format_amount(-1500.0, Locale::Id), "-Rp 1.500");
+    }
+
+    #[test]
+    fn test_localized_only_adds_field_when_locale_requested() {
+        let donation = Donation {
+            id: 1,
+            user_id: 1,
+            campaign_id: 10,
+            amount: 50_000.0,
+            message: None,
+            private_note: None,
+            status: DonationStatus::Settled,
+            receipt_number: None,
+            refunded_excess: None,
+            created_at: Utc::now(),
+        };
+
+        let plain = serde_json::to_value(Localized::new(donation.clone(), None)).unwrap();
+        let localized = serde_json::to_value(Localized::new(donation, Some(Locale::Id))).unwrap();
+
+        assert!(plain.get("amount_formatted").is_none());
+        assert_eq!(plain["amount"], 50_000.0);
+        assert_eq!(localized["amount_formatted"], "Rp 50.000");
+    }
+}
@@ -0,0 +1,260 @@
+use rocket::data::{self, ByteUnit, Data, FromData, Limits, ToByteUnit};
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::serde::DeserializeOwned;
+use rocket::serde::json::{Json, Value, json, serde_json};
+use rocket::{Catcher, Request, catch, catchers};
+use thiserror::Error;
+
+pub const MAX_JSON_DEPTH: usize = 32;
+
+// Joined under any `limits` table from Rocket.toml, so entries there win. JSON limits
+// are looked up as `json/<first segment of the route path>`, ignoring the mount
+// point, falling back to `json`.
+pub fn default_limits() -> Limits {
+    Limits::default()
+        .limit("json", 256.kibibytes())
+        .limit("json/donations", 64.kibibytes())
+        .limit("json/notifications", 64.kibibytes())
+        .limit("file", 5.mebibytes())
+        .limit("data-form", 5.mebibytes())
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum PayloadError {
+    #[error("Request body exceeds the {0} limit")]
+    TooLarge(ByteUnit),
+
+    #[error("Request body is nested more than {0} levels deep")]
+    TooDeep(usize),
+
+    #[error("Request body is not valid JSON: {0}")]
+    Malformed(String),
+}
+
+impl PayloadError {
+    fn status(&self) -> Status {
+        match self {
+            PayloadError::TooLarge(_) => Status::PayloadTooLarge,
+            PayloadError::TooDeep(_) | PayloadError::Malformed(_) => Status::UnprocessableEntity,
+        }
+    }
+}
+
+// Left in the request cache so the catchers can explain the rejection.
+struct PayloadRejection(Option<PayloadError>);
+
+/// JSON body guard with a per-route size limit and a nesting depth cap.
+#[derive(Debug)]
+pub struct LimitedJson<T>(pub T);
+
+impl<T> LimitedJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for LimitedJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn json_limit(req: &Request<'_>) -> ByteUnit {
+    let limits = req.limits();
+    let segment = req
+        .route()
+        .and_then(|route| route.uri.unmounted_origin.path().segments().next());
+    match segment {
+        Some(segment) => limits.find(["json", segment]),
+        None => limits.get("json"),
+    }
+    .unwrap_or(Limits::JSON)
+}
+
+// Counts open brackets outside of strings; cheaper than letting serde recurse first.
+fn exceeds_depth(body: &str, max_depth: usize) -> bool {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for byte in body.bytes() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > max_depth {
+                    return true;
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    false
+}
+
+async fn read_json<T: DeserializeOwned>(
+    req: &Request<'_>,
+    data: Data<'_>,
+) -> Result<T, PayloadError> {
+    let limit = json_limit(req);
+    let body = data
+        .open(limit)
+        .into_string()
+        .await
+        .map_err(|e| PayloadError::Malformed(e.to_string()))?;
+    if !body.is_complete() {
+        return Err(PayloadError::TooLarge(limit));
+    }
+    if exceeds_depth(&body, MAX_JSON_DEPTH) {
+        return Err(PayloadError::TooDeep(MAX_JSON_DEPTH));
+    }
+    serde_json::from_str(&body).map_err(|e| PayloadError::Malformed(e.to_string()))
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for LimitedJson<T> {
+    type Error = PayloadError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        match read_json(req, data).await {
+            Ok(value) => Outcome::Success(LimitedJson(value)),
+            Err(e) => {
+                req.local_cache(|| PayloadRejection(Some(e.clone())));
+                Outcome::Error((e.status(), e))
+            }
+        }
+    }
+}
+
+fn rejection_body(req: &Request<'_>, fallback: &str) -> Json<Value> {
+    match &req.local_cache(|| PayloadRejection(None)).0 {
+        Some(PayloadError::TooLarge(limit)) => json!({
+            "error": PayloadError::TooLarge(*limit).to_string(),
+            "limit_bytes": limit.as_u64(),
+        }),
+        Some(PayloadError::TooDeep(max_depth)) => json!({
+            "error": PayloadError::TooDeep(*max_depth).to_string(),
+            "max_depth": max_depth,
+        }),
+        Some(e) => json!({ "error": e.to_string() }),
+        None => json!({ "error": fallback }),
+    }
+    .into()
+}
+
+#[catch(413)]
+fn payload_too_large(req: &Request<'_>) -> Json<Value> {
+    rejection_body(req, "Request body too large")
+}
+
+#[catch(422)]
+fn unprocessable_payload(req: &Request<'_>) -> Json<Value> {
+    rejection_body(req, "Request body could not be processed")
+}
+
+pub fn catchers() -> Vec<Catcher> {
+    catchers![payload_too_large, unprocessable_payload]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::figment::Figment;
+    use rocket::http::ContentType;
+    use rocket::local::blocking::Client;
+    use rocket::serde::Deserialize;
+    use rocket::{Config, post, routes};
+
+    #[derive(Deserialize)]
+    #[serde(crate = "rocket::serde")]
+    struct NewDonation {
+        amount: f64,
+    }
+
+    #[post("/donations", data = "<body>")]
+    fn donate(body: LimitedJson<NewDonation>) -> String {
+        body.amount.to_string()
+    }
+
+    #[post("/campaigns", data = "<body>")]
+    fn create_campaign(body: LimitedJson<Value>) -> String {
+        body.to_string().len().to_string()
+    }
+
+    fn client() -> Client {
+        let figment = Figment::from(Config::debug_default()).merge((
+            "limits",
+            default_limits().limit("json/donations", 1.kibibytes()),
+        ));
+        let rocket = rocket::custom(figment)
+            .mount("/api", routes![donate, create_campaign])
+            .register("/", catchers());
+        Client::tracked(rocket).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn test_route_limit_rejects_with_413() {
+        let client = client();
+        let message = "a".repeat(2048);
+        let body = format!(r#"{{"amount": 50000, "message": "{}"}}"#, message);
+
+        let response = client
+            .post("/api/donations")
+            .header(ContentType::JSON)
+            .body(&body)
+            .dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+        let json: Value = response.into_json().unwrap();
+        assert_eq!(json["limit_bytes"], 1024);
+
+        // Other routes fall back to the general json limit.
+        let response = client
+            .post("/api/campaigns")
+            .header(ContentType::JSON)
+            .body(&body)
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    #[test]
+    fn test_deeply_nested_json_rejected_with_422() {
+        let client = client();
+        let body = format!("{}{}", "[".repeat(40), "]".repeat(40));
+
+        let response = client
+            .post("/api/campaigns")
+            .header(ContentType::JSON)
+            .body(&body)
+            .dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        let json: Value = response.into_json().unwrap();
+        assert_eq!(json["max_depth"], MAX_JSON_DEPTH);
+    }
+
+    #[test]
+    fn test_brackets_inside_strings_do_not_count() {
+        let body = format!(r#"{{"message": "{}"}}"#, "[{\\\"".repeat(50));
+        assert!(!exceeds_depth(&body, 2));
+        assert!(exceeds_depth("[[[]]]", 2));
+
+        let client = client();
+        let response = client
+            .post("/api/donations")
+            .header(ContentType::JSON)
+            .body(r#"{"amount": 25000}"#)
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "25000");
+    }
+}
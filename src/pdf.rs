@@ -0,0 +1,151 @@
+// Just enough PDF to hand donors a printable plain-text document: A4 pages, the
+// standard Helvetica fonts, one line of text per entry. Characters outside Latin-1
+// are replaced with '?' because the base fonts only cover WinAnsi.
+
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 50;
+const TITLE_SIZE: u32 = 14;
+const BODY_SIZE: u32 = 10;
+const LEADING: u32 = 14;
+const LINES_PER_PAGE: usize = 52;
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            '\u{a0}'..='\u{ff}' => escaped.push_str(&format!("\\{:03o}", c as u32)),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+fn page_content(title: Option<&str>, lines: &[String]) -> String {
+    let mut top = PAGE_HEIGHT - MARGIN;
+    let mut content = String::new();
+    if let Some(title) = title {
+        content.push_str(&format!(
+            "BT /F2 {} Tf {} {} Td ({}) Tj ET\n",
+            TITLE_SIZE,
+            MARGIN,
+            top,
+            escape_text(title)
+        ));
+        top -= 2 * LEADING;
+    }
+    content.push_str(&format!(
+        "BT /F1 {} Tf {} TL {} {} Td\n",
+        BODY_SIZE, LEADING, MARGIN, top
+    ));
+    for line in lines {
+        content.push_str(&format!("({}) Tj T*\n", escape_text(line)));
+    }
+    content.push_str("ET\n");
+    content
+}
+
+/// Renders `lines` under a bold `title`, breaking onto new pages as needed.
+pub fn render_text_pdf(title: &str, lines: &[String]) -> Vec<u8> {
+    // The title takes two lines' worth of space on the first page.
+    let first_page = lines.len().min(LINES_PER_PAGE - 2);
+    let mut pages = vec![page_content(Some(title), &lines[..first_page])];
+    pages.extend(
+        lines[first_page..]
+            .chunks(LINES_PER_PAGE)
+            .map(|chunk| page_content(None, chunk)),
+    );
+
+    // Objects 1-4 are the catalog, page tree and fonts; each page adds a page object
+    // and its content stream.
+    let page_ids: Vec<usize> = (0..pages.len()).map(|index| 5 + index * 2).collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    for (content, page_id) in pages.iter().zip(&page_ids) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            page_id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+    }
+    let xref_offset = pdf.len();
+    pdf.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+    pdf.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_is_escaped_for_pdf_strings() {
+        assert_eq!(
+            escape_text("Sumur (desa) \\ 50%"),
+            "Sumur \\(desa\\) \\\\ 50%"
+        );
+        assert_eq!(escape_text("Café 🙏"), "Caf\\351 ?");
+    }
+
+    #[test]
+    fn test_long_documents_span_pages_with_valid_xref() {
+        let lines: Vec<String> = (1..=80).map(|n| format!("Line {}", n)).collect();
+        let pdf = String::from_utf8(render_text_pdf("Summary", &lines)).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.contains("(Line 80) Tj"));
+
+        let startxref: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[startxref..].starts_with("xref\n0 9\n"));
+    }
+}
@@ -0,0 +1,115 @@
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Sliding-window limiter: at most `limit` hits per key within any `window`.
+/// Generic over the key so the same primitive can count per user in the service
+/// layer or per client address at the HTTP layer.
+pub struct RateLimiter<K: Eq + Hash + Clone> {
+    limit: usize,
+    window: Duration,
+    hits: DashMap<K, VecDeque<Instant>>,
+}
+
+impl<K: Eq + Hash + Clone> RateLimiter<K> {
+    pub fn new(limit: usize, window: Duration) -> Self {
+        RateLimiter {
+            limit,
+            window,
+            hits: DashMap::new(),
+        }
+    }
+
+    /// Records a hit for `key`, or returns how long until the oldest hit leaves the
+    /// window if the key is already at its limit. A refused hit is not recorded.
+    pub fn try_acquire(&self, key: K) -> Result<(), Duration> {
+        self.try_acquire_at(key, Instant::now())
+    }
+
+    fn try_acquire_at(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut hits = self.hits.entry(key).or_default();
+        while hits
+            .front()
+            .is_some_and(|&hit| now.duration_since(hit) >= self.window)
+        {
+            hits.pop_front();
+        }
+        if hits.len() >= self.limit {
+            let oldest = hits[0];
+            return Err(self.window - now.duration_since(oldest));
+        }
+        hits.push_back(now);
+        Ok(())
+    }
+
+    /// Takes back the most recent hit for `key`, for callers that only count
+    /// attempts that turn out to fail.
+    pub fn release(&self, key: &K) {
+        if let Some(mut hits) = self.hits.get_mut(key) {
+            hits.pop_back();
+        }
+    }
+
+    /// Forgets keys whose hits have all left the window, so idle keys don't
+    /// accumulate.
+    pub fn prune(&self) {
+        let now = Instant::now();
+        self.hits.retain(|_, hits| {
+            hits.back()
+                .is_some_and(|&hit| now.duration_since(hit) < self.window)
+        });
+    }
+
+    pub fn tracked_keys(&self) -> usize {
+        self.hits.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refuses_over_limit_until_window_slides() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at(1, start).is_ok());
+        assert!(
+            limiter
+                .try_acquire_at(1, start + Duration::from_secs(10))
+                .is_ok()
+        );
+        let retry_after = limiter
+            .try_acquire_at(1, start + Duration::from_secs(20))
+            .unwrap_err();
+        assert_eq!(retry_after, Duration::from_secs(40));
+        assert!(
+            limiter
+                .try_acquire_at(2, start + Duration::from_secs(20))
+                .is_ok()
+        );
+
+        assert!(
+            limiter
+                .try_acquire_at(1, start + Duration::from_secs(60))
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .try_acquire_at(1, start + Duration::from_secs(61))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_released_hits_do_not_count() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+
+        assert!(limiter.try_acquire(1).is_ok());
+        limiter.release(&1);
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(limiter.try_acquire(1).is_err());
+    }
+}
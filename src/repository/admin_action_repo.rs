@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::admin_action::{AdminActionKind, AdminActionStatus, PendingAdminAction};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait AdminActionRepository: Send + Sync {
+    /// Returns None if the same action on the same target is already waiting.
+    async fn create(&self, kind: AdminActionKind, target_id: i32, amount: Option<f64>, reason: Option<String>, requested_by: i32) -> Result<Option<PendingAdminAction>, AppError>;
+    async fn find_by_id(&self, action_id: i32) -> Result<Option<PendingAdminAction>, AppError>;
+    async fn find_by_status(&self, status: AdminActionStatus) -> Result<Vec<PendingAdminAction>, AppError>;
+    /// Moves a pending action to `status`; returns None if it was already decided.
+    async fn decide(&self, action_id: i32, admin_id: i32, status: AdminActionStatus, note: Option<String>) -> Result<Option<PendingAdminAction>, AppError>;
+    async fn mark_failed(&self, action_id: i32, error: String) -> Result<(), AppError>;
+}
+
+pub struct PgAdminActionRepository {
+    pool: PgPool,
+}
+
+impl PgAdminActionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgAdminActionRepository { pool }
+    }
+}
+
+#[async_trait]
+impl AdminActionRepository for PgAdminActionRepository {
+    async fn create(&self, kind: AdminActionKind, target_id: i32, amount: Option<f64>, reason: Option<String>, requested_by: i32) -> Result<Option<PendingAdminAction>, AppError> {
+        let action = sqlx::query_as::<_, PendingAdminAction>(
+            "INSERT INTO pending_admin_actions (kind, target_id, amount, reason, requested_by) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (kind, target_id) WHERE status = 'pending' DO NOTHING RETURNING *",
+        )
+        .bind(kind)
+        .bind(target_id)
+        .bind(amount)
+        .bind(reason)
+        .bind(requested_by)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(action)
+    }
+
+    async fn find_by_id(&self, action_id: i32) -> Result<Option<PendingAdminAction>, AppError> {
+        let action = sqlx::query_as::<_, PendingAdminAction>("SELECT * FROM pending_admin_actions WHERE id = $1")
+            .bind(action_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(action)
+    }
+
+    async fn find_by_status(&self, status: AdminActionStatus) -> Result<Vec<PendingAdminAction>, AppError> {
+        let actions = sqlx::query_as::<_, PendingAdminAction>(
+            "SELECT * FROM pending_admin_actions WHERE status = $1 ORDER BY created_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(actions)
+    }
+
+    // The requester can't decide their own action, except to decline (withdraw) it.
+    async fn decide(&self, action_id: i32, admin_id: i32, status: AdminActionStatus, note: Option<String>) -> Result<Option<PendingAdminAction>, AppError> {
+        let action = sqlx::query_as::<_, PendingAdminAction>(
+            "UPDATE pending_admin_actions \
+             SET status = $3, decided_by = $2, decision_note = $4, decided_at = NOW() \
+             WHERE id = $1 AND status = 'pending' AND ($3 = 'declined' OR requested_by <> $2) \
+             RETURNING *",
+        )
+        .bind(action_id)
+        .bind(admin_id)
+        .bind(status)
+        .bind(note)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(action)
+    }
+
+    async fn mark_failed(&self, action_id: i32, error: String) -> Result<(), AppError> {
+        sqlx::query("UPDATE pending_admin_actions SET status = 'failed', decision_note = $2 WHERE id = $1")
+            .bind(action_id)
+            .bind(error)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_action_needs_a_second_admin() {
+        let db = test_db().await;
+        let repo = PgAdminActionRepository::new(db.pool.clone());
+
+        let action = repo
+            .create(AdminActionKind::AdjustBalance, 7, Some(5_000_000.0), Some("Bank correction".to_string()), 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(repo.create(AdminActionKind::AdjustBalance, 7, Some(1.0), None, 2).await.unwrap().is_none());
+
+        assert!(repo.decide(action.id, 1, AdminActionStatus::Approved, None).await.unwrap().is_none());
+        let approved = repo.decide(action.id, 2, AdminActionStatus::Approved, None).await.unwrap().unwrap();
+        assert_eq!(approved.status, AdminActionStatus::Approved);
+        assert_eq!(approved.decided_by, Some(2));
+        assert!(repo.decide(action.id, 3, AdminActionStatus::Declined, None).await.unwrap().is_none());
+        assert!(repo.find_by_status(AdminActionStatus::Pending).await.unwrap().is_empty());
+
+        // Once decided, the same target can be queued again, and a requester may withdraw it.
+        let again = repo.create(AdminActionKind::AdjustBalance, 7, Some(2_000_000.0), None, 1).await.unwrap().unwrap();
+        let declined = repo.decide(again.id, 1, AdminActionStatus::Declined, None).await.unwrap().unwrap();
+        assert_eq!(declined.status, AdminActionStatus::Declined);
+    }
+}
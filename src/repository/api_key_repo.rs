@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::api_key::{ApiKeyRecord, ApiKeyScope};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, name: &str, key_prefix: &str, key_hash: &str, scopes: &[ApiKeyScope], created_by: i32) -> Result<ApiKeyRecord, AppError>;
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKeyRecord>, AppError>;
+    async fn find_all(&self) -> Result<Vec<ApiKeyRecord>, AppError>;
+    async fn touch_last_used(&self, id: i32) -> Result<(), AppError>;
+    async fn revoke(&self, id: i32) -> Result<Option<ApiKeyRecord>, AppError>;
+}
+
+pub struct PgApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PgApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgApiKeyRepository { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PgApiKeyRepository {
+    async fn create(&self, name: &str, key_prefix: &str, key_hash: &str, scopes: &[ApiKeyScope], created_by: i32) -> Result<ApiKeyRecord, AppError> {
+        let record = sqlx::query_as::<_, ApiKeyRecord>(
+            "INSERT INTO api_keys (name, key_prefix, key_hash, scopes, created_by) VALUES ($1, $2, $3, $4, $5) RETURNING *",
+        )
+        .bind(name)
+        .bind(key_prefix)
+        .bind(key_hash)
+        .bind(scopes)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKeyRecord>, AppError> {
+        let record = sqlx::query_as::<_, ApiKeyRecord>(
+            "SELECT * FROM api_keys WHERE key_hash = $1 AND revoked_at IS NULL",
+        )
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    async fn find_all(&self) -> Result<Vec<ApiKeyRecord>, AppError> {
+        let records = sqlx::query_as::<_, ApiKeyRecord>("SELECT * FROM api_keys ORDER BY created_at DESC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(records)
+    }
+
+    async fn touch_last_used(&self, id: i32) -> Result<(), AppError> {
+        sqlx::query("UPDATE api_keys SET last_used_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    // Revoking twice keeps the original revocation time.
+    async fn revoke(&self, id: i32) -> Result<Option<ApiKeyRecord>, AppError> {
+        let record = sqlx::query_as::<_, ApiKeyRecord>(
+            "UPDATE api_keys SET revoked_at = COALESCE(revoked_at, NOW()) WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_revoked_keys_are_not_found() {
+        let db = test_db().await;
+        let repo = PgApiKeyRepository::new(db.pool.clone());
+
+        let record = repo
+            .create("dashboard", "1a2b3c4d", "hash-1", &[ApiKeyScope::StatsRead, ApiKeyScope::NotificationsWrite], 1)
+            .await
+            .unwrap();
+        assert_eq!(record.scopes, vec![ApiKeyScope::StatsRead, ApiKeyScope::NotificationsWrite]);
+        assert_eq!(repo.find_active_by_hash("hash-1").await.unwrap().map(|r| r.id), Some(record.id));
+
+        repo.touch_last_used(record.id).await.unwrap();
+        let revoked = repo.revoke(record.id).await.unwrap().unwrap();
+        assert!(revoked.last_used_at.is_some());
+        assert!(revoked.revoked_at.is_some());
+        assert_eq!(repo.find_active_by_hash("hash-1").await.unwrap(), None);
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_budget::{BudgetLine, CampaignBudget, NewBudgetItem, NewSpendReportRequest, SpendReport};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Budget lines are compared to the target in rupiah; anything under a cent is rounding.
+const BUDGET_TOLERANCE: f64 = 0.005;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignBudgetRepository: Send + Sync {
+    async fn find_budget(&self, campaign_id: i32) -> Result<Option<CampaignBudget>, AppError>;
+    async fn declare(&self, campaign_id: i32, items: Vec<NewBudgetItem>) -> Result<(), AppError>;
+    async fn add_report(&self, campaign_id: i32, user_id: i32, report: &NewSpendReportRequest) -> Result<SpendReport, AppError>;
+    async fn set_reports_required(&self, campaign_id: i32, required: bool) -> Result<bool, AppError>;
+    /// Whether the user has an approved payout without a spend report while one of
+    /// their campaigns requires reports.
+    async fn has_unreported_payout(&self, user_id: i32) -> Result<bool, AppError>;
+}
+
+pub struct PgCampaignBudgetRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignBudgetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignBudgetRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignBudgetRepository for PgCampaignBudgetRepository {
+    async fn find_budget(&self, campaign_id: i32) -> Result<Option<CampaignBudget>, AppError> {
+        let campaign: Option<(f64, bool)> = sqlx::query_as("SELECT target_amount, spend_reports_required FROM campaigns WHERE id = $1")
+            .bind(campaign_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((target_amount, spend_reports_required)) = campaign else {
+            return Ok(None);
+        };
+
+        let lines = sqlx::query_as::<_, BudgetLine>(
+            "SELECT b.id, b.label, b.amount, COALESCE(SUM(r.amount), 0)::FLOAT8 AS spent \
+             FROM campaign_budget_items b LEFT JOIN campaign_spend_reports r ON r.budget_item_id = b.id \
+             WHERE b.campaign_id = $1 GROUP BY b.id ORDER BY b.position",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let reports = sqlx::query_as::<_, SpendReport>(
+            "SELECT * FROM campaign_spend_reports WHERE campaign_id = $1 ORDER BY created_at DESC, id DESC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(CampaignBudget {
+            campaign_id,
+            target_amount,
+            spend_reports_required,
+            total_spent: lines.iter().map(|line| line.spent).sum(),
+            lines,
+            reports,
+        }))
+    }
+
+    // Replaces the whole breakdown. Once spending has been reported against the lines,
+    // changing them would rewrite what donors were shown, so it is refused.
+    async fn declare(&self, campaign_id: i32, items: Vec<NewBudgetItem>) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        let target_amount: Option<f64> = sqlx::query_scalar("SELECT target_amount FROM campaigns WHERE id = $1 FOR UPDATE")
+            .bind(campaign_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(target_amount) = target_amount else {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        };
+        let total: f64 = items.iter().map(|item| item.amount).sum();
+        if (total - target_amount).abs() > BUDGET_TOLERANCE {
+            return Err(AppError::ValidationError("Budget items must add up to the campaign target".to_string()));
+        }
+        let reported: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM campaign_spend_reports WHERE campaign_id = $1)")
+            .bind(campaign_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if reported {
+            return Err(AppError::ValidationError("Budget cannot be changed after spending has been reported".to_string()));
+        }
+
+        sqlx::query("DELETE FROM campaign_budget_items WHERE campaign_id = $1")
+            .bind(campaign_id)
+            .execute(&mut *tx)
+            .await?;
+        let labels: Vec<String> = items.iter().map(|item| item.label.clone()).collect();
+        let amounts: Vec<f64> = items.iter().map(|item| item.amount).collect();
+        sqlx::query(
+            "INSERT INTO campaign_budget_items (campaign_id, label, amount, position) \
+             SELECT $1, label, amount, position::INT FROM UNNEST($2::TEXT[], $3::FLOAT8[]) WITH ORDINALITY AS t(label, amount, position)",
+        )
+        .bind(campaign_id)
+        .bind(&labels)
+        .bind(&amounts)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn add_report(&self, campaign_id: i32, user_id: i32, report: &NewSpendReportRequest) -> Result<SpendReport, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let item_exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM campaign_budget_items WHERE id = $1 AND campaign_id = $2)")
+            .bind(report.budget_item_id)
+            .bind(campaign_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if !item_exists {
+            return Err(AppError::NotFound("Budget item not found".to_string()));
+        }
+        let payout_approved: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM withdrawals WHERE id = $1 AND user_id = $2 AND status = 'approved')",
+        )
+        .bind(report.withdrawal_id)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if !payout_approved {
+            return Err(AppError::ValidationError("Spend reports must reference one of your approved payouts".to_string()));
+        }
+
+        let created = sqlx::query_as::<_, SpendReport>(
+            "INSERT INTO campaign_spend_reports (campaign_id, budget_item_id, withdrawal_id, amount, description, created_by) \
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        )
+        .bind(campaign_id)
+        .bind(report.budget_item_id)
+        .bind(report.withdrawal_id)
+        .bind(report.amount)
+        .bind(&report.description)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn set_reports_required(&self, campaign_id: i32, required: bool) -> Result<bool, AppError> {
+        let updated = sqlx::query("UPDATE campaigns SET spend_reports_required = $2 WHERE id = $1")
+            .bind(campaign_id)
+            .bind(required)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        Ok(updated > 0)
+    }
+
+    async fn has_unreported_payout(&self, user_id: i32) -> Result<bool, AppError> {
+        let unreported = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM campaigns WHERE fundraiser_id = $1 AND spend_reports_required) \
+             AND EXISTS ( \
+                 SELECT 1 FROM withdrawals w WHERE w.user_id = $1 AND w.status = 'approved' \
+                 AND NOT EXISTS (SELECT 1 FROM campaign_spend_reports r WHERE r.withdrawal_id = w.id) \
+             )",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(unreported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    fn item(label: &str, amount: f64) -> NewBudgetItem {
+        NewBudgetItem { label: label.to_string(), amount }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_budget_declaration_and_spend_reports() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, target_amount, fundraiser_id) VALUES (10, 1000, 7);
+             INSERT INTO withdrawals (id, user_id, amount, bank_name, account_number, account_holder, status) VALUES
+                 (1, 7, 400, 'BCA', '123', 'Budi', 'approved'),
+                 (2, 8, 400, 'BCA', '456', 'Sari', 'approved');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignBudgetRepository::new(db.pool.clone());
+
+        assert!(repo.declare(10, vec![item("Materials", 600.0)]).await.is_err());
+        repo.declare(10, vec![item("Materials", 600.0), item("Labour", 400.0)]).await.unwrap();
+        let budget = repo.find_budget(10).await.unwrap().unwrap();
+        let labels: Vec<&str> = budget.lines.iter().map(|line| line.label.as_str()).collect();
+        assert_eq!(labels, vec!["Materials", "Labour"]);
+
+        assert!(!repo.has_unreported_payout(7).await.unwrap());
+        assert!(repo.set_reports_required(10, true).await.unwrap());
+        assert!(repo.has_unreported_payout(7).await.unwrap());
+
+        let report = |withdrawal_id: i32| NewSpendReportRequest {
+            budget_item_id: budget.lines[0].id,
+            withdrawal_id,
+            amount: 250.0,
+            description: "Cement and bricks".to_string(),
+        };
+        assert!(repo.add_report(10, 7, &report(2)).await.is_err());
+        repo.add_report(10, 7, &report(1)).await.unwrap();
+        assert!(!repo.has_unreported_payout(7).await.unwrap());
+
+        let budget = repo.find_budget(10).await.unwrap().unwrap();
+        assert_eq!(budget.lines[0].spent, 250.0);
+        assert_eq!(budget.total_spent, 250.0);
+        assert_eq!(budget.reports.len(), 1);
+        assert!(repo.declare(10, vec![item("Everything", 1000.0)]).await.is_err());
+    }
+}
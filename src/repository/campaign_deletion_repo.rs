@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignDeletionRepository: Send + Sync {
+    /// Donations the campaign has received, archived ones included; None if there is
+    /// no such campaign.
+    async fn count_donations(&self, campaign_id: i32) -> Result<Option<i64>, AppError>;
+    async fn delete(&self, campaign_id: i32) -> Result<bool, AppError>;
+}
+
+pub struct PgCampaignDeletionRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignDeletionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignDeletionRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignDeletionRepository for PgCampaignDeletionRepository {
+    async fn count_donations(&self, campaign_id: i32) -> Result<Option<i64>, AppError> {
+        let count = sqlx::query_scalar(
+            "SELECT (SELECT COUNT(*) FROM all_donations d WHERE d.campaign_id = c.id) \
+             FROM campaigns c WHERE c.id = $1",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    // A soft delete: donations, receipts and payouts keep pointing at the campaign row.
+    async fn delete(&self, campaign_id: i32) -> Result<bool, AppError> {
+        let result = sqlx::query("UPDATE campaigns SET status = 'deleted' WHERE id = $1 AND status <> 'deleted'")
+            .bind(campaign_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() == 1)
+    }
+}
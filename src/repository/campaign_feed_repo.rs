@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_feed::CampaignFeedItem;
+use crate::model::campaign_widget::CampaignWidget;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Read-only view of public campaigns for the sitemap, feed and embeddable widgets.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignFeedRepository: Send + Sync {
+    /// `region` matches case-insensitively; None means every region.
+    async fn count_active(&self, region: Option<String>) -> Result<i64, AppError>;
+    async fn find_active(&self, region: Option<String>, limit: i64, offset: i64) -> Result<Vec<CampaignFeedItem>, AppError>;
+    async fn find_active_ids(&self, limit: i64) -> Result<Vec<i32>, AppError>;
+    async fn find_almost_funded(&self, min_ratio: f64, limit: i64) -> Result<Vec<CampaignFeedItem>, AppError>;
+    /// Active or completed campaigns only; anything else is not public.
+    async fn find_widget(&self, campaign_id: i32) -> Result<Option<CampaignWidget>, AppError>;
+}
+
+pub struct PgCampaignFeedRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignFeedRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignFeedRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignFeedRepository for PgCampaignFeedRepository {
+    async fn count_active(&self, region: Option<String>) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM campaigns WHERE status = 'active' AND ($1::TEXT IS NULL OR LOWER(region) = LOWER($1))",
+        )
+        .bind(region)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    async fn find_active(&self, region: Option<String>, limit: i64, offset: i64) -> Result<Vec<CampaignFeedItem>, AppError> {
+        let items = sqlx::query_as::<_, CampaignFeedItem>(
+            "SELECT id, title, target_amount, collected_amount, region FROM campaigns \
+             WHERE status = 'active' AND ($3::TEXT IS NULL OR LOWER(region) = LOWER($3)) \
+             ORDER BY id ASC LIMIT $1 OFFSET $2",
+        )
+        .bind(limit)
+        .bind(offset)
+        .bind(region)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(items)
+    }
+
+    async fn find_active_ids(&self, limit: i64) -> Result<Vec<i32>, AppError> {
+        let ids = sqlx::query_scalar::<_, i32>(
+            "SELECT id FROM campaigns WHERE status = 'active' ORDER BY id ASC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(ids)
+    }
+
+    // Closest to their target first; fully funded campaigns are no longer "almost" funded.
+    async fn find_almost_funded(&self, min_ratio: f64, limit: i64) -> Result<Vec<CampaignFeedItem>, AppError> {
+        let items = sqlx::query_as::<_, CampaignFeedItem>(
+            "SELECT id, title, target_amount, collected_amount, region FROM campaigns \
+             WHERE status = 'active' AND target_amount > 0 \
+               AND collected_amount >= target_amount * $1 AND collected_amount < target_amount \
+             ORDER BY collected_amount / target_amount DESC, id ASC LIMIT $2",
+        )
+        .bind(min_ratio)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(items)
+    }
+
+    async fn find_widget(&self, campaign_id: i32) -> Result<Option<CampaignWidget>, AppError> {
+        let widget = sqlx::query_as::<_, CampaignWidget>(
+            "SELECT id, title, target_amount, collected_amount, status = 'completed' AS completed \
+             FROM campaigns WHERE id = $1 AND status IN ('active', 'completed')",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(widget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_almost_funded_skips_inactive_and_completed() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, collected_amount, status) VALUES \
+                 (10, 'Almost', 1000, 850, 'active'), \
+                 (11, 'Closer', 1000, 950, 'active'), \
+                 (12, 'Halfway', 1000, 500, 'active'), \
+                 (13, 'Suspended', 1000, 900, 'suspended'), \
+                 (14, 'Draft', 1000, 900, 'draft'), \
+                 (15, 'Done', 1000, 1000, 'active');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignFeedRepository::new(db.pool.clone());
+
+        let items = repo.find_almost_funded(0.8, 10).await.unwrap();
+        let ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![11, 10]);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_active_filters_by_region() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, region, status) VALUES \
+                 (10, 'Flood relief', 1000, 'Jawa Barat', 'active'), \
+                 (11, 'School roof', 1000, 'Bali', 'active'), \
+                 (12, 'Library', 1000, 'Jawa Barat', 'suspended'), \
+                 (13, 'Clinic', 1000, NULL, 'active');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignFeedRepository::new(db.pool.clone());
+
+        let region = Some("jawa barat".to_string());
+        assert_eq!(repo.count_active(region.clone()).await.unwrap(), 1);
+        let items = repo.find_active(region, 10, 0).await.unwrap();
+        assert_eq!(items[0].id, 10);
+        assert_eq!(items[0].region.as_deref(), Some("Jawa Barat"));
+        assert_eq!(repo.count_active(None).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_widget_only_for_public_campaigns() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, collected_amount, status) VALUES \
+                 (10, 'Flood relief', 1000, 400, 'active'), \
+                 (11, 'School roof', 1000, 1000, 'completed'), \
+                 (12, 'Draft', 1000, 0, 'draft'), \
+                 (13, 'Removed', 1000, 0, 'deleted');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignFeedRepository::new(db.pool.clone());
+
+        let active = repo.find_widget(10).await.unwrap().unwrap();
+        assert_eq!(active.collected_amount, 400.0);
+        assert!(!active.completed);
+        assert!(repo.find_widget(11).await.unwrap().unwrap().completed);
+        assert!(repo.find_widget(12).await.unwrap().is_none());
+        assert!(repo.find_widget(13).await.unwrap().is_none());
+    }
+}
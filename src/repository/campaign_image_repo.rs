@@ -0,0 +1,181 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use crate::model::campaign_image::CampaignImage;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+pub const MAX_CAMPAIGN_IMAGES: i64 = 10;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignImageRepository: Send + Sync {
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<CampaignImage>, AppError>;
+    async fn add(&self, campaign_id: i32, url: &str) -> Result<CampaignImage, AppError>;
+    async fn remove(&self, campaign_id: i32, image_id: i32) -> Result<bool, AppError>;
+    async fn reorder(&self, campaign_id: i32, image_ids: Vec<i32>) -> Result<Vec<CampaignImage>, AppError>;
+}
+
+pub struct PgCampaignImageRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignImageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignImageRepository { pool }
+    }
+}
+
+// Locks the campaign row so gallery edits serialize with each other and with the
+// campaign leaving draft/pending review.
+async fn lock_editable_campaign(conn: &mut PgConnection, campaign_id: i32) -> Result<(), AppError> {
+    let status: Option<String> =
+        sqlx::query_scalar("SELECT status::TEXT FROM campaigns WHERE id = $1 FOR UPDATE")
+            .bind(campaign_id)
+            .fetch_optional(conn)
+            .await?;
+    match status.as_deref() {
+        None => Err(AppError::NotFound("Campaign not found".to_string())),
+        Some("draft") | Some("pending") => Ok(()),
+        Some(_) => Err(AppError::ValidationError(
+            "Images can only be changed while the campaign is a draft or pending review".to_string(),
+        )),
+    }
+}
+
+async fn images_of(conn: &mut PgConnection, campaign_id: i32) -> Result<Vec<CampaignImage>, AppError> {
+    let images = sqlx::query_as::<_, CampaignImage>(
+        "SELECT * FROM campaign_images WHERE campaign_id = $1 ORDER BY position ASC, id ASC",
+    )
+    .bind(campaign_id)
+    .fetch_all(conn)
+    .await?;
+    Ok(images)
+}
+
+#[async_trait]
+impl CampaignImageRepository for PgCampaignImageRepository {
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<CampaignImage>, AppError> {
+        let mut conn = self.pool.acquire().await?;
+        images_of(&mut conn, campaign_id).await
+    }
+
+    async fn add(&self, campaign_id: i32, url: &str) -> Result<CampaignImage, AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_editable_campaign(&mut tx, campaign_id).await?;
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM campaign_images WHERE campaign_id = $1")
+            .bind(campaign_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if count >= MAX_CAMPAIGN_IMAGES {
+            return Err(AppError::ValidationError(format!(
+                "A campaign can have at most {} images",
+                MAX_CAMPAIGN_IMAGES
+            )));
+        }
+
+        let image = sqlx::query_as::<_, CampaignImage>(
+            "INSERT INTO campaign_images (campaign_id, url, position) VALUES ($1, $2, $3) RETURNING *",
+        )
+        .bind(campaign_id)
+        .bind(url)
+        .bind(count as i32)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(image)
+    }
+
+    // Later images move up so positions stay contiguous.
+    async fn remove(&self, campaign_id: i32, image_id: i32) -> Result<bool, AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_editable_campaign(&mut tx, campaign_id).await?;
+
+        let Some(position) = sqlx::query_scalar::<_, i32>(
+            "DELETE FROM campaign_images WHERE id = $1 AND campaign_id = $2 RETURNING position",
+        )
+        .bind(image_id)
+        .bind(campaign_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(false);
+        };
+        sqlx::query("UPDATE campaign_images SET position = position - 1 WHERE campaign_id = $1 AND position > $2")
+            .bind(campaign_id)
+            .bind(position)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn reorder(&self, campaign_id: i32, image_ids: Vec<i32>) -> Result<Vec<CampaignImage>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_editable_campaign(&mut tx, campaign_id).await?;
+
+        let mut current: Vec<i32> = images_of(&mut tx, campaign_id)
+            .await?
+            .into_iter()
+            .map(|image| image.id)
+            .collect();
+        let mut requested = image_ids.clone();
+        current.sort_unstable();
+        requested.sort_unstable();
+        if current != requested {
+            return Err(AppError::ValidationError(
+                "image_ids must list every image of the campaign exactly once".to_string(),
+            ));
+        }
+
+        sqlx::query(
+            "UPDATE campaign_images ci SET position = ordered.ord - 1 \
+             FROM UNNEST($2::INT[]) WITH ORDINALITY AS ordered (id, ord) \
+             WHERE ci.id = ordered.id AND ci.campaign_id = $1",
+        )
+        .bind(campaign_id)
+        .bind(&image_ids)
+        .execute(&mut *tx)
+        .await?;
+        let images = images_of(&mut tx, campaign_id).await?;
+        tx.commit().await?;
+        Ok(images)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_gallery_edits_keep_positions_contiguous() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, target_amount, status) VALUES (10, 1000, 'draft'), (11, 1000, 'active');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignImageRepository::new(db.pool.clone());
+
+        let a = repo.add(10, "https://cdn.example.org/a.png").await.unwrap();
+        let b = repo.add(10, "https://cdn.example.org/b.png").await.unwrap();
+        let c = repo.add(10, "https://cdn.example.org/c.png").await.unwrap();
+        assert!(repo.remove(10, a.id).await.unwrap());
+        assert!(!repo.remove(10, a.id).await.unwrap());
+
+        let reordered = repo.reorder(10, vec![c.id, b.id]).await.unwrap();
+        let order: Vec<(i32, i32)> = reordered.iter().map(|image| (image.id, image.position)).collect();
+        assert_eq!(order, vec![(c.id, 0), (b.id, 1)]);
+        assert!(repo.reorder(10, vec![c.id]).await.is_err());
+
+        match repo.add(11, "https://cdn.example.org/d.png").await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("draft or pending")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+}
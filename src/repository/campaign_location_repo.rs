@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_location::{CampaignLocation, NearbyCampaign};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+// Length of one degree of latitude, for the bounding-box prefilter.
+const KM_PER_DEGREE_LATITUDE: f64 = 111.045;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignLocationRepository: Send + Sync {
+    async fn find(&self, campaign_id: i32) -> Result<Option<CampaignLocation>, AppError>;
+    async fn set(&self, campaign_id: i32, latitude: Option<f64>, longitude: Option<f64>, region: Option<String>) -> Result<Option<CampaignLocation>, AppError>;
+    /// Active campaigns within `radius_km` of the point, nearest first.
+    async fn find_nearby(&self, latitude: f64, longitude: f64, radius_km: f64, limit: i64) -> Result<Vec<NearbyCampaign>, AppError>;
+}
+
+pub struct PgCampaignLocationRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignLocationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignLocationRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignLocationRepository for PgCampaignLocationRepository {
+    async fn find(&self, campaign_id: i32) -> Result<Option<CampaignLocation>, AppError> {
+        let location = sqlx::query_as::<_, CampaignLocation>(
+            "SELECT id AS campaign_id, latitude, longitude, region FROM campaigns WHERE id = $1",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(location)
+    }
+
+    async fn set(&self, campaign_id: i32, latitude: Option<f64>, longitude: Option<f64>, region: Option<String>) -> Result<Option<CampaignLocation>, AppError> {
+        let location = sqlx::query_as::<_, CampaignLocation>(
+            "UPDATE campaigns SET latitude = $2, longitude = $3, region = $4 WHERE id = $1 \
+             RETURNING id AS campaign_id, latitude, longitude, region",
+        )
+        .bind(campaign_id)
+        .bind(latitude)
+        .bind(longitude)
+        .bind(region)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(location)
+    }
+
+    // Haversine distance in plain SQL, so PostGIS isn't needed. The latitude band
+    // lets the partial index on `latitude` skip campaigns that can't be in range.
+    async fn find_nearby(&self, latitude: f64, longitude: f64, radius_km: f64, limit: i64) -> Result<Vec<NearbyCampaign>, AppError> {
+        let campaigns = sqlx::query_as::<_, NearbyCampaign>(
+            "SELECT * FROM ( \
+                 SELECT id, title, target_amount, collected_amount, region, \
+                        2 * $5 * ASIN(LEAST(1, SQRT( \
+                            POWER(SIN(RADIANS(latitude - $1) / 2), 2) \
+                            + COS(RADIANS($1)) * COS(RADIANS(latitude)) * POWER(SIN(RADIANS(longitude - $2) / 2), 2) \
+                        ))) AS distance_km \
+                 FROM campaigns \
+                 WHERE status = 'active' AND latitude BETWEEN $1 - $6 AND $1 + $6 \
+             ) c \
+             WHERE distance_km <= $3 ORDER BY distance_km ASC, id ASC LIMIT $4",
+        )
+        .bind(latitude)
+        .bind(longitude)
+        .bind(radius_km)
+        .bind(limit)
+        .bind(EARTH_RADIUS_KM)
+        .bind(radius_km / KM_PER_DEGREE_LATITUDE)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(campaigns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_nearby_orders_by_distance_within_radius() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status) VALUES \
+                 (10, 'Bogor', 1000, 'active'), \
+                 (11, 'Depok', 1000, 'active'), \
+                 (12, 'Bandung', 1000, 'active'), \
+                 (13, 'Depok draft', 1000, 'draft'), \
+                 (14, 'Nowhere', 1000, 'active');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignLocationRepository::new(db.pool.clone());
+        repo.set(10, Some(-6.5971), Some(106.8060), Some("Jawa Barat".to_string())).await.unwrap();
+        repo.set(11, Some(-6.4025), Some(106.7942), Some("Jawa Barat".to_string())).await.unwrap();
+        repo.set(12, Some(-6.9175), Some(107.6191), Some("Jawa Barat".to_string())).await.unwrap();
+        repo.set(13, Some(-6.4025), Some(106.7942), None).await.unwrap();
+        assert!(repo.set(99, None, None, None).await.unwrap().is_none());
+
+        // Central Jakarta: Depok is about 23 km away, Bogor about 42 km, Bandung about 120 km.
+        let nearby = repo.find_nearby(-6.2000, 106.8167, 50.0, 10).await.unwrap();
+        let ids: Vec<i32> = nearby.iter().map(|campaign| campaign.id).collect();
+        assert_eq!(ids, vec![11, 10]);
+        assert!((nearby[0].distance_km - 22.6).abs() < 1.0);
+    }
+}
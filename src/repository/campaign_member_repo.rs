@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_member::{CampaignMember, CampaignRole};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignMemberRepository: Send + Sync {
+    async fn find_role(&self, campaign_id: i32, user_id: i32) -> Result<Option<CampaignRole>, AppError>;
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<CampaignMember>, AppError>;
+    async fn upsert(&self, campaign_id: i32, user_id: i32, role: CampaignRole, added_by: i32) -> Result<CampaignMember, AppError>;
+    async fn remove(&self, campaign_id: i32, user_id: i32) -> Result<u64, AppError>;
+}
+
+pub struct PgCampaignMemberRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignMemberRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignMemberRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignMemberRepository for PgCampaignMemberRepository {
+    // The campaign's creator (`campaigns.fundraiser_id`) is always an owner, even
+    // for campaigns created before `campaign_members` existed. On an organization's
+    // campaign its admins are owners and its other members editors. The most
+    // privileged role wins; `campaign_role` sorts owner first.
+    async fn find_role(&self, campaign_id: i32, user_id: i32) -> Result<Option<CampaignRole>, AppError> {
+        let role = sqlx::query_scalar::<_, CampaignRole>(
+            "SELECT 'owner'::campaign_role FROM campaigns WHERE id = $1 AND fundraiser_id = $2 \
+             UNION ALL \
+             SELECT role FROM campaign_members WHERE campaign_id = $1 AND user_id = $2 \
+             UNION ALL \
+             SELECT CASE m.role WHEN 'admin' THEN 'owner' ELSE 'editor' END::campaign_role \
+             FROM campaigns c JOIN organization_members m ON m.organization_id = c.organization_id \
+             WHERE c.id = $1 AND m.user_id = $2 \
+             ORDER BY 1 LIMIT 1",
+        )
+        .bind(campaign_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(role)
+    }
+
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<CampaignMember>, AppError> {
+        let members = sqlx::query_as::<_, CampaignMember>(
+            "SELECT * FROM campaign_members WHERE campaign_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(members)
+    }
+
+    async fn upsert(&self, campaign_id: i32, user_id: i32, role: CampaignRole, added_by: i32) -> Result<CampaignMember, AppError> {
+        let member = sqlx::query_as::<_, CampaignMember>(
+            "INSERT INTO campaign_members (campaign_id, user_id, role, added_by) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (campaign_id, user_id) DO UPDATE SET role = EXCLUDED.role \
+             RETURNING *",
+        )
+        .bind(campaign_id)
+        .bind(user_id)
+        .bind(role)
+        .bind(added_by)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(member)
+    }
+
+    async fn remove(&self, campaign_id: i32, user_id: i32) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM campaign_members WHERE campaign_id = $1 AND user_id = $2")
+            .bind(campaign_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_role_includes_campaign_creator() {
+        let db = test_db().await;
+        sqlx::raw_sql("INSERT INTO campaigns (id, target_amount, fundraiser_id) VALUES (10, 1000, 1);")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgCampaignMemberRepository::new(db.pool.clone());
+
+        repo.upsert(10, 2, CampaignRole::Viewer, 1).await.unwrap();
+        repo.upsert(10, 2, CampaignRole::Editor, 1).await.unwrap();
+
+        assert_eq!(repo.find_role(10, 1).await.unwrap(), Some(CampaignRole::Owner));
+        assert_eq!(repo.find_role(10, 2).await.unwrap(), Some(CampaignRole::Editor));
+        assert_eq!(repo.find_role(10, 3).await.unwrap(), None);
+        assert_eq!(repo.remove(10, 2).await.unwrap(), 1);
+        assert_eq!(repo.find_role(10, 2).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_role_resolves_organization_membership() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO organizations (id, name, registration_number, created_by) VALUES (5, 'Yayasan', 'AHU-1', 2);
+             INSERT INTO organization_members (organization_id, user_id, role, added_by)
+             VALUES (5, 2, 'admin', 2), (5, 3, 'treasurer', 2), (5, 4, 'member', 2);
+             INSERT INTO campaigns (id, target_amount, fundraiser_id, organization_id) VALUES (10, 1000, 1, 5);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignMemberRepository::new(db.pool.clone());
+
+        repo.upsert(10, 4, CampaignRole::Viewer, 1).await.unwrap();
+
+        assert_eq!(repo.find_role(10, 2).await.unwrap(), Some(CampaignRole::Owner));
+        assert_eq!(repo.find_role(10, 3).await.unwrap(), Some(CampaignRole::Editor));
+        assert_eq!(repo.find_role(10, 4).await.unwrap(), Some(CampaignRole::Editor));
+        assert_eq!(repo.find_role(10, 1).await.unwrap(), Some(CampaignRole::Owner));
+    }
+}
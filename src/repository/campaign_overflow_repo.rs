@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_overflow::{CampaignOverflowSettings, OverflowPolicy};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignOverflowRepository: Send + Sync {
+    async fn find(&self, campaign_id: i32) -> Result<Option<CampaignOverflowSettings>, AppError>;
+    async fn set_policy(&self, campaign_id: i32, policy: OverflowPolicy) -> Result<Option<CampaignOverflowSettings>, AppError>;
+}
+
+pub struct PgCampaignOverflowRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignOverflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignOverflowRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignOverflowRepository for PgCampaignOverflowRepository {
+    async fn find(&self, campaign_id: i32) -> Result<Option<CampaignOverflowSettings>, AppError> {
+        let settings = sqlx::query_as::<_, CampaignOverflowSettings>(
+            "SELECT id AS campaign_id, overflow_policy, target_amount, collected_amount FROM campaigns WHERE id = $1",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(settings)
+    }
+
+    // Takes effect for the next donation; `insert_settled` reads the policy under the
+    // same row lock it uses to update `collected_amount`.
+    async fn set_policy(&self, campaign_id: i32, policy: OverflowPolicy) -> Result<Option<CampaignOverflowSettings>, AppError> {
+        let settings = sqlx::query_as::<_, CampaignOverflowSettings>(
+            "UPDATE campaigns SET overflow_policy = $2 WHERE id = $1 \
+             RETURNING id AS campaign_id, overflow_policy, target_amount, collected_amount",
+        )
+        .bind(campaign_id)
+        .bind(policy)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_policy_defaults_to_allow_and_can_be_changed() {
+        let db = test_db().await;
+        sqlx::raw_sql("INSERT INTO campaigns (id, target_amount) VALUES (10, 100);")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgCampaignOverflowRepository::new(db.pool.clone());
+
+        assert_eq!(repo.find(10).await.unwrap().unwrap().overflow_policy, OverflowPolicy::Allow);
+        let updated = repo.set_policy(10, OverflowPolicy::Cap).await.unwrap().unwrap();
+        assert_eq!(updated.overflow_policy, OverflowPolicy::Cap);
+        assert!(repo.set_policy(11, OverflowPolicy::Cap).await.unwrap().is_none());
+    }
+}
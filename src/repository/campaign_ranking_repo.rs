@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_ranking::{RecommendedCampaign, TrendingCampaign};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignRankingRepository: Send + Sync {
+    async fn recompute(&self, half_life_hours: f64, window_days: i32, share_weight: f64) -> Result<u64, AppError>;
+    async fn find_trending(&self, limit: i64) -> Result<Vec<TrendingCampaign>, AppError>;
+    async fn find_recommended(&self, user_id: i32, limit: i64) -> Result<Vec<RecommendedCampaign>, AppError>;
+}
+
+pub struct PgCampaignRankingRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignRankingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignRankingRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignRankingRepository for PgCampaignRankingRepository {
+    // Each donation counts `amount * 0.5^(age / half_life)`, so a donation loses half
+    // its weight every half-life; each share counts like a donation of `share_weight`,
+    // aged from the start of the day it was counted in. The table is rebuilt in one
+    // transaction so readers never see a partial ranking.
+    async fn recompute(&self, half_life_hours: f64, window_days: i32, share_weight: f64) -> Result<u64, AppError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM campaign_rankings").execute(&mut *tx).await?;
+        let ranked = sqlx::query(
+            "INSERT INTO campaign_rankings (campaign_id, score, computed_at) \
+             SELECT s.campaign_id, SUM(s.score)::FLOAT8, NOW() \
+             FROM ( \
+                 SELECT d.campaign_id, \
+                        d.amount * POWER(0.5, EXTRACT(EPOCH FROM NOW() - d.created_at) / 3600.0 / $1) AS score \
+                 FROM donations d \
+                 WHERE d.status IN ('settled', 'imported') \
+                   AND d.created_at >= NOW() - make_interval(days => $2) \
+                 UNION ALL \
+                 SELECT sh.campaign_id, \
+                        $3 * sh.share_count * POWER(0.5, EXTRACT(EPOCH FROM NOW() - sh.day::TIMESTAMP AT TIME ZONE 'UTC') / 3600.0 / $1) \
+                 FROM campaign_shares sh \
+                 WHERE sh.day >= (NOW() - make_interval(days => $2))::DATE \
+             ) s JOIN campaigns c ON c.id = s.campaign_id \
+             WHERE c.status = 'active' \
+             GROUP BY s.campaign_id",
+        )
+        .bind(half_life_hours)
+        .bind(window_days)
+        .bind(share_weight)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        tx.commit().await?;
+        Ok(ranked)
+    }
+
+    async fn find_trending(&self, limit: i64) -> Result<Vec<TrendingCampaign>, AppError> {
+        let campaigns = sqlx::query_as::<_, TrendingCampaign>(
+            "SELECT r.campaign_id, c.title, c.target_amount, c.collected_amount, r.score, r.computed_at \
+             FROM campaign_rankings r JOIN campaigns c ON c.id = r.campaign_id \
+             WHERE c.status = 'active' ORDER BY r.score DESC, r.campaign_id ASC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(campaigns)
+    }
+
+    // Categories the user gave to most often come first, then trending campaigns
+    // within a category. Campaigns the user already gave to are left out.
+    async fn find_recommended(&self, user_id: i32, limit: i64) -> Result<Vec<RecommendedCampaign>, AppError> {
+        let campaigns = sqlx::query_as::<_, RecommendedCampaign>(
+            "WITH donated AS ( \
+                 SELECT c.category, COUNT(*) AS donations \
+                 FROM donations d JOIN campaigns c ON c.id = d.campaign_id \
+                 WHERE d.user_id = $1 AND d.status IN ('settled', 'imported') AND c.category IS NOT NULL \
+                 GROUP BY c.category \
+             ) \
+             SELECT c.id AS campaign_id, c.title, c.category, c.target_amount, c.collected_amount \
+             FROM campaigns c JOIN donated ON donated.category = c.category \
+             LEFT JOIN campaign_rankings r ON r.campaign_id = c.id \
+             WHERE c.status = 'active' \
+               AND NOT EXISTS (SELECT 1 FROM donations d WHERE d.user_id = $1 AND d.campaign_id = c.id) \
+             ORDER BY donated.donations DESC, COALESCE(r.score, 0) DESC, c.id ASC LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(campaigns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_recent_donations_outrank_older_larger_ones() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status) VALUES \
+                 (10, 'Old big', 1000, 'active'), (11, 'New small', 1000, 'active'), (12, 'Suspended', 1000, 'suspended');
+             INSERT INTO donations (user_id, campaign_id, amount, created_at) VALUES \
+                 (1, 10, 400, NOW() - INTERVAL '6 days'), \
+                 (1, 11, 100, NOW() - INTERVAL '1 hour'), \
+                 (1, 12, 900, NOW()), \
+                 (1, 10, 5000, NOW() - INTERVAL '30 days');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignRankingRepository::new(db.pool.clone());
+
+        assert_eq!(repo.recompute(48.0, 14, 10.0).await.unwrap(), 2);
+        let trending = repo.find_trending(10).await.unwrap();
+        let ids: Vec<i32> = trending.iter().map(|campaign| campaign.campaign_id).collect();
+        assert_eq!(ids, vec![11, 10]);
+        // 400 after three half-lives.
+        assert!((trending[1].score - 50.0).abs() < 1.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_shares_count_towards_trending_score() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status) VALUES \
+                 (10, 'Donated', 1000, 'active'), (11, 'Shared', 1000, 'active');
+             INSERT INTO donations (user_id, campaign_id, amount, created_at) VALUES (1, 10, 100, NOW());
+             INSERT INTO campaign_shares (campaign_id, platform, day, share_count) VALUES \
+                 (11, 'whatsapp', (NOW() AT TIME ZONE 'UTC')::DATE, 30), \
+                 (11, 'x', (NOW() AT TIME ZONE 'UTC')::DATE - 60, 1000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignRankingRepository::new(db.pool.clone());
+
+        repo.recompute(48.0, 14, 10.0).await.unwrap();
+        let trending = repo.find_trending(10).await.unwrap();
+        let ids: Vec<i32> = trending.iter().map(|campaign| campaign.campaign_id).collect();
+        // 30 shares today outweigh the single donation; shares outside the window are ignored.
+        assert_eq!(ids, vec![11, 10]);
+        assert!(trending[0].score > 200.0 && trending[0].score <= 300.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_recommendations_follow_donated_categories() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status, category) VALUES \
+                 (10, 'Given to', 1000, 'active', 'health'), (11, 'Health', 1000, 'active', 'health'), \
+                 (12, 'Education', 1000, 'active', 'education'), (13, 'Closed', 1000, 'completed', 'health'), \
+                 (14, 'Other school', 1000, 'active', 'education'), (15, 'Disaster', 1000, 'active', 'disaster');
+             INSERT INTO donations (user_id, campaign_id, amount) VALUES (1, 10, 100), (1, 10, 50), (1, 12, 20), (2, 15, 10);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignRankingRepository::new(db.pool.clone());
+
+        let recommended = repo.find_recommended(1, 10).await.unwrap();
+        let ids: Vec<i32> = recommended.iter().map(|campaign| campaign.campaign_id).collect();
+        assert_eq!(ids, vec![11, 14]);
+        assert!(repo.find_recommended(3, 10).await.unwrap().is_empty());
+    }
+}
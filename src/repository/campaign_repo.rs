@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign::Campaign;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignRepository: Send + Sync {
+    async fn find_by_id(&self, campaign_id: i32) -> Result<Option<Campaign>, AppError>;
+}
+
+pub struct PgCampaignRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignRepository for PgCampaignRepository {
+    async fn find_by_id(&self, campaign_id: i32) -> Result<Option<Campaign>, AppError> {
+        let campaign = sqlx::query_as::<_, Campaign>(
+            "SELECT id, fundraiser_id, title, description, category, target_amount, collected_amount, status, ends_at \
+             FROM campaigns WHERE id = $1 AND status <> 'deleted'",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(campaign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_by_id_skips_deleted_campaigns() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status) VALUES \
+                 (10, 'Clean water', 1000, 'active'), (11, 'Gone', 1000, 'deleted');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignRepository::new(db.pool.clone());
+
+        let campaign = repo.find_by_id(10).await.unwrap().unwrap();
+        assert_eq!(campaign.title, "Clean water");
+        assert_eq!(campaign.status, "active");
+        assert!(repo.find_by_id(11).await.unwrap().is_none());
+        assert!(repo.find_by_id(12).await.unwrap().is_none());
+    }
+}
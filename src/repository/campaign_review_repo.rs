@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, PgConnection, PgPool};
+use crate::model::campaign_review::{BulkReviewAction, BulkReviewResult, CampaignReviewItem, CheckResult, ChecklistItem, SimilarCampaign};
+use crate::model::event::DomainEvent;
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignReviewRepository: Send + Sync {
+    async fn find_items(&self, campaign_id: i32) -> Result<Option<Vec<CampaignReviewItem>>, AppError>;
+    async fn record_item(&self, campaign_id: i32, reviewer_id: i32, item: ChecklistItem, result: CheckResult, comment: Option<String>) -> Result<Option<CampaignReviewItem>, AppError>;
+    async fn approve(&self, campaign_id: i32) -> Result<(), AppError>;
+    async fn reject(&self, campaign_id: i32) -> Result<(), AppError>;
+    /// Applies `action` to each campaign on its own, so one failure doesn't undo the
+    /// rest. Everything commits together, which releases the notifications as one batch.
+    async fn bulk_review(&self, campaign_ids: Vec<i32>, action: BulkReviewAction, reason: Option<String>) -> Result<Vec<BulkReviewResult>, AppError>;
+    /// Other campaigns scoring at least `min_similarity`, most similar first.
+    /// `None` when the campaign doesn't exist.
+    async fn find_similar(&self, campaign_id: i32, min_similarity: f64, limit: i64) -> Result<Option<Vec<SimilarCampaign>>, AppError>;
+}
+
+pub struct PgCampaignReviewRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignReviewRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignReviewRepository { pool }
+    }
+}
+
+// Locks the campaign so a checklist change can't slip in between the check and the
+// status change, and returns how many items are recorded with `result`.
+async fn lock_pending_campaign(conn: &mut PgConnection, campaign_id: i32, result: CheckResult) -> Result<i64, AppError> {
+    let status: Option<String> = sqlx::query_scalar("SELECT status FROM campaigns WHERE id = $1 FOR UPDATE")
+        .bind(campaign_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    match status.as_deref() {
+        None => return Err(AppError::NotFound("Campaign not found".to_string())),
+        Some("pending") => {}
+        Some(_) => return Err(AppError::ValidationError("Campaign is not awaiting review".to_string())),
+    }
+    let count = sqlx::query_scalar("SELECT COUNT(*) FROM campaign_reviews WHERE campaign_id = $1 AND result = $2")
+        .bind(campaign_id)
+        .bind(result)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(count)
+}
+
+async fn approve_pending(conn: &mut PgConnection, campaign_id: i32) -> Result<(), AppError> {
+    let passed = lock_pending_campaign(conn, campaign_id, CheckResult::Passed).await?;
+    if passed < ChecklistItem::ALL.len() as i64 {
+        return Err(AppError::ValidationError("Every checklist item must pass before approval".to_string()));
+    }
+    sqlx::query("UPDATE campaigns SET status = 'active' WHERE id = $1")
+        .bind(campaign_id)
+        .execute(&mut *conn)
+        .await?;
+    enqueue_event(conn, &DomainEvent::CampaignApproved { campaign_id }).await
+}
+
+async fn reject_pending(conn: &mut PgConnection, campaign_id: i32, reason: Option<String>) -> Result<(), AppError> {
+    let failed = lock_pending_campaign(conn, campaign_id, CheckResult::Failed).await?;
+    if failed == 0 {
+        return Err(AppError::ValidationError("Mark the failing checklist items before rejecting".to_string()));
+    }
+    sqlx::query("UPDATE campaigns SET status = 'rejected' WHERE id = $1")
+        .bind(campaign_id)
+        .execute(&mut *conn)
+        .await?;
+    enqueue_event(conn, &DomainEvent::CampaignRejected { campaign_id, reason }).await
+}
+
+#[async_trait]
+impl CampaignReviewRepository for PgCampaignReviewRepository {
+    // `None` when the campaign doesn't exist; an empty list when nothing is checked yet.
+    async fn find_items(&self, campaign_id: i32) -> Result<Option<Vec<CampaignReviewItem>>, AppError> {
+        let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM campaigns WHERE id = $1)")
+            .bind(campaign_id)
+            .fetch_one(&self.pool)
+            .await?;
+        if !exists {
+            return Ok(None);
+        }
+        let items = sqlx::query_as::<_, CampaignReviewItem>(
+            "SELECT item, result, comment, reviewer_id, updated_at FROM campaign_reviews WHERE campaign_id = $1",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(Some(items))
+    }
+
+    async fn record_item(&self, campaign_id: i32, reviewer_id: i32, item: ChecklistItem, result: CheckResult, comment: Option<String>) -> Result<Option<CampaignReviewItem>, AppError> {
+        let recorded = sqlx::query_as::<_, CampaignReviewItem>(
+            "INSERT INTO campaign_reviews (campaign_id, item, result, comment, reviewer_id) \
+             SELECT id, $2, $3, $4, $5 FROM campaigns WHERE id = $1 \
+             ON CONFLICT (campaign_id, item) DO UPDATE SET result = EXCLUDED.result, comment = EXCLUDED.comment, \
+             reviewer_id = EXCLUDED.reviewer_id, updated_at = NOW() \
+             RETURNING item, result, comment, reviewer_id, updated_at",
+        )
+        .bind(campaign_id)
+        .bind(item)
+        .bind(result)
+        .bind(comment)
+        .bind(reviewer_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(recorded)
+    }
+
+    async fn approve(&self, campaign_id: i32) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        approve_pending(&mut tx, campaign_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn reject(&self, campaign_id: i32) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        reject_pending(&mut tx, campaign_id, None).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // Each campaign runs in a savepoint: a failed one rolls back to it and the loop
+    // moves on.
+    async fn bulk_review(&self, campaign_ids: Vec<i32>, action: BulkReviewAction, reason: Option<String>) -> Result<Vec<BulkReviewResult>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let mut results = Vec::with_capacity(campaign_ids.len());
+        for campaign_id in campaign_ids {
+            let mut savepoint = (&mut tx).begin().await?;
+            let outcome = match action {
+                BulkReviewAction::Approve => approve_pending(&mut savepoint, campaign_id).await,
+                BulkReviewAction::Reject => reject_pending(&mut savepoint, campaign_id, reason.clone()).await,
+            };
+            let error = match outcome {
+                Ok(()) => {
+                    savepoint.commit().await?;
+                    None
+                }
+                Err(e @ AppError::DatabaseError(_)) => return Err(e),
+                Err(e) => {
+                    savepoint.rollback().await?;
+                    Some(e.to_string())
+                }
+            };
+            results.push(BulkReviewResult { campaign_id, error });
+        }
+        tx.commit().await?;
+        Ok(results)
+    }
+
+    // Needs the pg_trgm extension. similarity() is NULL when a description is missing,
+    // and GREATEST skips NULLs.
+    async fn find_similar(&self, campaign_id: i32, min_similarity: f64, limit: i64) -> Result<Option<Vec<SimilarCampaign>>, AppError> {
+        let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM campaigns WHERE id = $1)")
+            .bind(campaign_id)
+            .fetch_one(&self.pool)
+            .await?;
+        if !exists {
+            return Ok(None);
+        }
+        let similar = sqlx::query_as::<_, SimilarCampaign>(
+            "SELECT id, title, status, fundraiser_id, title_similarity, description_similarity, \
+                    GREATEST(title_similarity, description_similarity) AS similarity \
+             FROM ( \
+                 SELECT c.id, c.title, c.status, c.fundraiser_id, \
+                        similarity(c.title, t.title)::FLOAT8 AS title_similarity, \
+                        similarity(c.description, t.description)::FLOAT8 AS description_similarity \
+                 FROM campaigns t JOIN campaigns c ON c.id <> t.id WHERE t.id = $1 \
+             ) scored \
+             WHERE GREATEST(title_similarity, description_similarity) >= $2 \
+             ORDER BY similarity DESC, id ASC LIMIT $3",
+        )
+        .bind(campaign_id)
+        .bind(min_similarity)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(Some(similar))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_approval_requires_every_item_to_pass() {
+        let db = test_db().await;
+        sqlx::raw_sql("INSERT INTO campaigns (id, target_amount, status) VALUES (10, 100, 'pending');")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgCampaignReviewRepository::new(db.pool.clone());
+
+        assert!(repo.record_item(11, 1, ChecklistItem::DocumentsValid, CheckResult::Passed, None).await.unwrap().is_none());
+        repo.record_item(10, 1, ChecklistItem::IdentityVerified, CheckResult::Passed, None).await.unwrap();
+        repo.record_item(10, 1, ChecklistItem::DocumentsValid, CheckResult::Failed, Some("KTP scan is blurry".to_string()))
+            .await
+            .unwrap();
+        repo.record_item(10, 1, ChecklistItem::DescriptionAccurate, CheckResult::Passed, None).await.unwrap();
+        assert!(repo.approve(10).await.is_err());
+
+        let fixed = repo.record_item(10, 2, ChecklistItem::DocumentsValid, CheckResult::Passed, None).await.unwrap().unwrap();
+        assert_eq!(fixed.reviewer_id, Some(2));
+        assert_eq!(repo.find_items(10).await.unwrap().unwrap().len(), 3);
+        repo.approve(10).await.unwrap();
+
+        let status: String = sqlx::query_scalar("SELECT status FROM campaigns WHERE id = 10")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(status, "active");
+        let event_type: String = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(event_type, "campaign_approved");
+        assert!(repo.reject(10).await.is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_bulk_review_reports_each_campaign() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, target_amount, status) VALUES (10, 100, 'pending'), (11, 100, 'pending'), (12, 100, 'active');
+             INSERT INTO campaign_reviews (campaign_id, item, result, reviewer_id) VALUES (10, 'documents_valid', 'failed', 1), (12, 'documents_valid', 'failed', 1);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignReviewRepository::new(db.pool.clone());
+
+        let results = repo
+            .bulk_review(vec![10, 11, 12, 99], BulkReviewAction::Reject, Some("Duplicate of an existing campaign".to_string()))
+            .await
+            .unwrap();
+        let failed: Vec<(i32, bool)> = results.iter().map(|result| (result.campaign_id, result.error.is_some())).collect();
+        assert_eq!(failed, vec![(10, false), (11, true), (12, true), (99, true)]);
+
+        let statuses: Vec<String> = sqlx::query_scalar("SELECT status FROM campaigns ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(statuses, vec!["rejected", "pending", "active"]);
+        let payloads: Vec<sqlx::types::JsonValue> = sqlx::query_scalar("SELECT payload FROM outbox")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0]["reason"], "Duplicate of an existing campaign");
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_similar_scores_title_and_description() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, description, target_amount, status) VALUES \
+                 (10, 'Bantu Adik Rina Operasi Jantung', 'Rina butuh operasi jantung segera di RSCM', 1000, 'pending'), \
+                 (11, 'Bantu Adik Rina Operasi Jantung!', NULL, 1000, 'active'), \
+                 (12, 'Pengobatan untuk Budi', 'Rina butuh operasi jantung segera di RSCM Jakarta', 1000, 'active'), \
+                 (13, 'Renovasi Masjid Al-Ikhlas', 'Atap masjid bocor', 1000, 'active');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignReviewRepository::new(db.pool.clone());
+
+        let similar = repo.find_similar(10, 0.3, 10).await.unwrap().unwrap();
+        let ids: Vec<i32> = similar.iter().map(|campaign| campaign.id).collect();
+        assert_eq!(ids, vec![11, 12]);
+        assert!(similar[0].similarity > 0.9);
+        assert_eq!(similar[0].description_similarity, None);
+        assert!(similar[1].description_similarity.unwrap() > similar[1].title_similarity);
+        assert!(repo.find_similar(99, 0.3, 10).await.unwrap().is_none());
+    }
+}
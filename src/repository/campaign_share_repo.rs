@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign_share::{CampaignPreview, SharePlatform, ShareCount};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignShareRepository: Send + Sync {
+    async fn find_preview(&self, campaign_id: i32) -> Result<Option<CampaignPreview>, AppError>;
+    /// Returns false if the campaign does not exist.
+    async fn record_share(&self, campaign_id: i32, platform: SharePlatform) -> Result<bool, AppError>;
+    async fn share_counts(&self, campaign_id: i32) -> Result<Vec<ShareCount>, AppError>;
+}
+
+pub struct PgCampaignShareRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignShareRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignShareRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignShareRepository for PgCampaignShareRepository {
+    async fn find_preview(&self, campaign_id: i32) -> Result<Option<CampaignPreview>, AppError> {
+        let preview = sqlx::query_as::<_, CampaignPreview>(
+            "SELECT c.id AS campaign_id, c.title, c.target_amount, c.collected_amount, \
+             (SELECT url FROM campaign_images WHERE campaign_id = c.id ORDER BY position LIMIT 1) AS image_url \
+             FROM campaigns c WHERE c.id = $1",
+        )
+        .bind(campaign_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(preview)
+    }
+
+    // Counted per UTC day so the trending ranking can decay shares like donations.
+    async fn record_share(&self, campaign_id: i32, platform: SharePlatform) -> Result<bool, AppError> {
+        let recorded = sqlx::query(
+            "INSERT INTO campaign_shares (campaign_id, platform, day, share_count) \
+             SELECT id, $2, (NOW() AT TIME ZONE 'UTC')::DATE, 1 FROM campaigns WHERE id = $1 \
+             ON CONFLICT (campaign_id, platform, day) \
+             DO UPDATE SET share_count = campaign_shares.share_count + 1",
+        )
+        .bind(campaign_id)
+        .bind(platform)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(recorded > 0)
+    }
+
+    async fn share_counts(&self, campaign_id: i32) -> Result<Vec<ShareCount>, AppError> {
+        let counts = sqlx::query_as::<_, ShareCount>(
+            "SELECT platform, SUM(share_count)::BIGINT AS share_count FROM campaign_shares \
+             WHERE campaign_id = $1 GROUP BY platform ORDER BY share_count DESC, platform",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_preview_uses_cover_image_and_shares_are_counted() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, collected_amount) VALUES (10, 'Sumur desa', 1000, 250);
+             INSERT INTO campaign_images (campaign_id, url, position) VALUES
+                 (10, 'https://cdn.example.org/b.png', 1), (10, 'https://cdn.example.org/a.png', 0);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignShareRepository::new(db.pool.clone());
+
+        let preview = repo.find_preview(10).await.unwrap().unwrap();
+        assert_eq!(preview.title, "Sumur desa");
+        assert_eq!(preview.image_url.as_deref(), Some("https://cdn.example.org/a.png"));
+        assert!(repo.find_preview(99).await.unwrap().is_none());
+
+        assert!(repo.record_share(10, SharePlatform::Whatsapp).await.unwrap());
+        assert!(repo.record_share(10, SharePlatform::Whatsapp).await.unwrap());
+        assert!(repo.record_share(10, SharePlatform::X).await.unwrap());
+        assert!(!repo.record_share(99, SharePlatform::X).await.unwrap());
+
+        let counts = repo.share_counts(10).await.unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                ShareCount { platform: SharePlatform::Whatsapp, share_count: 2 },
+                ShareCount { platform: SharePlatform::X, share_count: 1 },
+            ]
+        );
+    }
+}
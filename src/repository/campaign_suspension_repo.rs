@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use crate::model::campaign_suspension::{CampaignAuditEntry, SuspensionDecision};
+use crate::model::event::DomainEvent;
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Donations check `campaigns.status` when they credit the campaign, so a suspended
+// campaign stops taking them as soon as the suspension commits.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CampaignSuspensionRepository: Send + Sync {
+    async fn suspend(&self, campaign_id: i32, admin_id: i32, reason: String) -> Result<CampaignAuditEntry, AppError>;
+    async fn resolve(&self, campaign_id: i32, admin_id: i32, decision: SuspensionDecision, note: Option<String>) -> Result<CampaignAuditEntry, AppError>;
+    /// `None` when the campaign doesn't exist.
+    async fn find_audit_log(&self, campaign_id: i32) -> Result<Option<Vec<CampaignAuditEntry>>, AppError>;
+}
+
+pub struct PgCampaignSuspensionRepository {
+    pool: PgPool,
+}
+
+impl PgCampaignSuspensionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCampaignSuspensionRepository { pool }
+    }
+}
+
+// Moves the campaign from `from` to `to` under a row lock and returns its fundraiser.
+async fn transition(conn: &mut PgConnection, campaign_id: i32, from: &str, to: &str, not_allowed: &str) -> Result<Option<i32>, AppError> {
+    let campaign: Option<(String, Option<i32>)> = sqlx::query_as("SELECT status, fundraiser_id FROM campaigns WHERE id = $1 FOR UPDATE")
+        .bind(campaign_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    let fundraiser_id = match campaign {
+        None => return Err(AppError::NotFound("Campaign not found".to_string())),
+        Some((status, fundraiser_id)) if status == from => fundraiser_id,
+        Some(_) => return Err(AppError::ValidationError(not_allowed.to_string())),
+    };
+    sqlx::query("UPDATE campaigns SET status = $2 WHERE id = $1")
+        .bind(campaign_id)
+        .bind(to)
+        .execute(&mut *conn)
+        .await?;
+    Ok(fundraiser_id)
+}
+
+async fn record_audit(conn: &mut PgConnection, campaign_id: i32, actor_id: i32, action: &str, note: Option<&str>) -> Result<CampaignAuditEntry, AppError> {
+    let entry = sqlx::query_as::<_, CampaignAuditEntry>(
+        "INSERT INTO campaign_audit_log (campaign_id, actor_id, action, note) VALUES ($1, $2, $3, $4) RETURNING *",
+    )
+    .bind(campaign_id)
+    .bind(actor_id)
+    .bind(action)
+    .bind(note)
+    .fetch_one(conn)
+    .await?;
+    Ok(entry)
+}
+
+#[async_trait]
+impl CampaignSuspensionRepository for PgCampaignSuspensionRepository {
+    async fn suspend(&self, campaign_id: i32, admin_id: i32, reason: String) -> Result<CampaignAuditEntry, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let fundraiser_id = transition(&mut tx, campaign_id, "active", "suspended", "Only active campaigns can be suspended").await?;
+        let entry = record_audit(&mut tx, campaign_id, admin_id, "suspended", Some(&reason)).await?;
+        enqueue_event(&mut tx, &DomainEvent::CampaignSuspended { campaign_id, fundraiser_id, reason }).await?;
+        tx.commit().await?;
+        Ok(entry)
+    }
+
+    async fn resolve(&self, campaign_id: i32, admin_id: i32, decision: SuspensionDecision, note: Option<String>) -> Result<CampaignAuditEntry, AppError> {
+        let (status, action) = match decision {
+            SuspensionDecision::Reinstate => ("active", "reinstated"),
+            SuspensionDecision::Reject => ("rejected", "rejected"),
+        };
+        let mut tx = self.pool.begin().await?;
+        let fundraiser_id = transition(&mut tx, campaign_id, "suspended", status, "Campaign is not suspended").await?;
+        let entry = record_audit(&mut tx, campaign_id, admin_id, action, note.as_deref()).await?;
+        enqueue_event(
+            &mut tx,
+            &DomainEvent::CampaignSuspensionResolved {
+                campaign_id,
+                fundraiser_id,
+                reinstated: decision == SuspensionDecision::Reinstate,
+            },
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(entry)
+    }
+
+    async fn find_audit_log(&self, campaign_id: i32) -> Result<Option<Vec<CampaignAuditEntry>>, AppError> {
+        let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM campaigns WHERE id = $1)")
+            .bind(campaign_id)
+            .fetch_one(&self.pool)
+            .await?;
+        if !exists {
+            return Ok(None);
+        }
+        let entries = sqlx::query_as::<_, CampaignAuditEntry>(
+            "SELECT * FROM campaign_audit_log WHERE campaign_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(Some(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_suspension_is_audited_and_notified() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, status, fundraiser_id) VALUES \
+                 (10, 'Clean water', 1000, 'active', 3), (11, 'Draft', 1000, 'pending', 3);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgCampaignSuspensionRepository::new(db.pool.clone());
+
+        repo.suspend(10, 1, "Reported as fraudulent".to_string()).await.unwrap();
+        assert!(matches!(repo.suspend(10, 1, "Again".to_string()).await, Err(AppError::ValidationError(_))));
+        assert!(matches!(repo.suspend(11, 1, "Not live".to_string()).await, Err(AppError::ValidationError(_))));
+        assert!(matches!(repo.resolve(11, 1, SuspensionDecision::Reinstate, None).await, Err(AppError::ValidationError(_))));
+        let status: String = sqlx::query_scalar("SELECT status FROM campaigns WHERE id = 10")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(status, "suspended");
+
+        repo.resolve(10, 2, SuspensionDecision::Reinstate, Some("Documents check out".to_string())).await.unwrap();
+        let audit = repo.find_audit_log(10).await.unwrap().unwrap();
+        let actions: Vec<(&str, i32)> = audit.iter().map(|entry| (entry.action.as_str(), entry.actor_id)).collect();
+        assert_eq!(actions, vec![("suspended", 1), ("reinstated", 2)]);
+        assert!(repo.find_audit_log(99).await.unwrap().is_none());
+
+        let events: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(events, vec!["campaign_suspended", "campaign_suspension_resolved"]);
+    }
+}
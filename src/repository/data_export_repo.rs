@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use rocket::serde::json::Value;
+use sqlx::PgPool;
+use crate::model::data_export::DataExport;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DataExportRepository: Send + Sync {
+    async fn create(&self, user_id: i32) -> Result<DataExport, AppError>;
+    async fn find_by_id(&self, export_id: i32) -> Result<Option<DataExport>, AppError>;
+    async fn mark_ready(&self, export_id: i32, payload: Value) -> Result<(), AppError>;
+    async fn mark_failed(&self, export_id: i32, error: String) -> Result<(), AppError>;
+}
+
+pub struct PgDataExportRepository {
+    pool: PgPool,
+}
+
+impl PgDataExportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDataExportRepository { pool }
+    }
+}
+
+#[async_trait]
+impl DataExportRepository for PgDataExportRepository {
+    async fn create(&self, user_id: i32) -> Result<DataExport, AppError> {
+        let export = sqlx::query_as::<_, DataExport>(
+            "INSERT INTO data_exports (user_id, status) VALUES ($1, 'pending') RETURNING *",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(export)
+    }
+
+    async fn find_by_id(&self, export_id: i32) -> Result<Option<DataExport>, AppError> {
+        let export = sqlx::query_as::<_, DataExport>("SELECT * FROM data_exports WHERE id = $1")
+            .bind(export_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(export)
+    }
+
+    async fn mark_ready(&self, export_id: i32, payload: Value) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE data_exports SET status = 'ready', payload = $2, completed_at = NOW() WHERE id = $1",
+        )
+        .bind(export_id)
+        .bind(payload)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, export_id: i32, error: String) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE data_exports SET status = 'failed', error = $2, completed_at = NOW() WHERE id = $1",
+        )
+        .bind(export_id)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use crate::model::dispute::{Dispute, DisputeAuditEntry, DisputeResolution, DisputeStatus};
+use crate::model::donation::Donation;
+use crate::model::event::DomainEvent;
+use crate::errors::AppError;
+use crate::repository::retry::{with_retry, RetryPolicy};
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+// While a dispute is open its amount sits in `campaigns.held_amount`, which is
+// excluded from what the fundraiser can withdraw.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DisputeRepository: Send + Sync {
+    async fn open(&self, donation: &Donation, reason: String) -> Result<Dispute, AppError>;
+    async fn find_by_id(&self, dispute_id: i32) -> Result<Option<Dispute>, AppError>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Dispute>, AppError>;
+    async fn find_by_status(&self, status: DisputeStatus) -> Result<Vec<Dispute>, AppError>;
+    async fn find_audit_log(&self, dispute_id: i32) -> Result<Vec<DisputeAuditEntry>, AppError>;
+    async fn resolve(&self, dispute_id: i32, admin_id: i32, resolution: DisputeResolution, note: Option<String>) -> Result<Option<Dispute>, AppError>;
+}
+
+pub struct PgDisputeRepository {
+    pool: PgPool,
+}
+
+impl PgDisputeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDisputeRepository { pool }
+    }
+}
+
+async fn record_audit(
+    conn: &mut PgConnection,
+    dispute_id: i32,
+    actor_id: i32,
+    action: &str,
+    note: Option<&str>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO dispute_audit_log (dispute_id, actor_id, action, note) VALUES ($1, $2, $3, $4)",
+    )
+    .bind(dispute_id)
+    .bind(actor_id)
+    .bind(action)
+    .bind(note)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+fn is_unique_violation(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .and_then(|db_error| db_error.code())
+        .is_some_and(|code| code == "23505")
+}
+
+#[async_trait]
+impl DisputeRepository for PgDisputeRepository {
+    async fn open(&self, donation: &Donation, reason: String) -> Result<Dispute, AppError> {
+        let pool = &self.pool;
+        let reason = &reason;
+        with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+
+            // The partial unique index on open disputes rejects a second open dispute
+            // for the same donation, including one racing this insert.
+            let dispute = sqlx::query_as::<_, Dispute>(
+                "INSERT INTO disputes (donation_id, user_id, campaign_id, amount, reason, status) \
+                 VALUES ($1, $2, $3, $4, $5, 'open') RETURNING *",
+            )
+            .bind(donation.id)
+            .bind(donation.user_id)
+            .bind(donation.campaign_id)
+            .bind(donation.amount)
+            .bind(reason)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                if is_unique_violation(&e) {
+                    AppError::ValidationError("Donation already has an open dispute".to_string())
+                } else {
+                    AppError::DatabaseError(e)
+                }
+            })?;
+
+            sqlx::query("UPDATE campaigns SET held_amount = held_amount + $2 WHERE id = $1")
+                .bind(dispute.campaign_id)
+                .bind(dispute.amount)
+                .execute(&mut *tx)
+                .await?;
+
+            record_audit(&mut tx, dispute.id, dispute.user_id, "opened", Some(reason)).await?;
+            enqueue_event(
+                &mut tx,
+                &DomainEvent::DisputeOpened {
+                    dispute_id: dispute.id,
+                    donation_id: dispute.donation_id,
+                    user_id: dispute.user_id,
+                    campaign_id: dispute.campaign_id,
+                    amount: dispute.amount,
+                },
+            )
+            .await?;
+
+            tx.commit().await?;
+            Ok(dispute)
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, dispute_id: i32) -> Result<Option<Dispute>, AppError> {
+        let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+            .bind(dispute_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(dispute)
+    }
+
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Dispute>, AppError> {
+        let disputes = sqlx::query_as::<_, Dispute>(
+            "SELECT * FROM disputes WHERE user_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(disputes)
+    }
+
+    async fn find_by_status(&self, status: DisputeStatus) -> Result<Vec<Dispute>, AppError> {
+        let disputes = sqlx::query_as::<_, Dispute>(
+            "SELECT * FROM disputes WHERE status = $1 ORDER BY created_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(disputes)
+    }
+
+    async fn find_audit_log(&self, dispute_id: i32) -> Result<Vec<DisputeAuditEntry>, AppError> {
+        let entries = sqlx::query_as::<_, DisputeAuditEntry>(
+            "SELECT * FROM dispute_audit_log WHERE dispute_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(dispute_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(entries)
+    }
+
+    // Only open disputes can be resolved; returns None if it was already resolved.
+    // A refund only moves money if the donation is still settled, so a donation that
+    // was already refunded campaign-wide is not paid back twice.
+    async fn resolve(&self, dispute_id: i32, admin_id: i32, resolution: DisputeResolution, note: Option<String>) -> Result<Option<Dispute>, AppError> {
+        let pool = &self.pool;
+        let note = &note;
+        with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+
+            let status = match resolution {
+                DisputeResolution::Refund => DisputeStatus::Refunded,
+                DisputeResolution::Dismiss => DisputeStatus::Dismissed,
+            };
+            let Some(dispute) = sqlx::query_as::<_, Dispute>(
+                "UPDATE disputes SET status = $2, resolved_by = $3, resolution_note = $4, resolved_at = NOW() \
+                 WHERE id = $1 AND status = 'open' RETURNING *",
+            )
+            .bind(dispute_id)
+            .bind(status)
+            .bind(admin_id)
+            .bind(note)
+            .fetch_optional(&mut *tx)
+            .await?
+            else {
+                return Ok(None);
+            };
+
+            sqlx::query(
+                "UPDATE campaigns SET held_amount = GREATEST(held_amount - $2, 0) WHERE id = $1",
+            )
+            .bind(dispute.campaign_id)
+            .bind(dispute.amount)
+            .execute(&mut *tx)
+            .await?;
+
+            let mut refunded = false;
+            if resolution == DisputeResolution::Refund {
+                refunded = sqlx::query(
+                    "UPDATE donations SET status = 'refunded' WHERE id = $1 AND status = 'settled'",
+                )
+                .bind(dispute.donation_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected()
+                    == 1;
+
+                if refunded {
+                    sqlx::query("UPDATE wallets SET balance = balance + $2 WHERE user_id = $1")
+                        .bind(dispute.user_id)
+                        .bind(dispute.amount)
+                        .execute(&mut *tx)
+                        .await?;
+                    sqlx::query(
+                        "UPDATE campaigns SET collected_amount = GREATEST(collected_amount - $2, 0) WHERE id = $1",
+                    )
+                    .bind(dispute.campaign_id)
+                    .bind(dispute.amount)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+            }
+
+            let action = match resolution {
+                DisputeResolution::Refund => "refunded",
+                DisputeResolution::Dismiss => "dismissed",
+            };
+            record_audit(&mut tx, dispute.id, admin_id, action, note.as_deref()).await?;
+            enqueue_event(
+                &mut tx,
+                &DomainEvent::DisputeResolved {
+                    dispute_id: dispute.id,
+                    donation_id: dispute.donation_id,
+                    user_id: dispute.user_id,
+                    campaign_id: dispute.campaign_id,
+                    refunded,
+                },
+            )
+            .await?;
+
+            tx.commit().await?;
+            Ok(Some(dispute))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::DonationStatus;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_dispute_holds_then_refunds_donation() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 0);
+             INSERT INTO campaigns (id, target_amount, collected_amount) VALUES (10, 1000, 300);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let donation = sqlx::query_as::<_, Donation>(
+            "INSERT INTO donations (user_id, campaign_id, amount, status) \
+             VALUES (1, 10, 300, 'settled') RETURNING *",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDisputeRepository::new(db.pool.clone());
+
+        let dispute = repo.open(&donation, "Not authorised".to_string()).await.unwrap();
+        let held: f64 = sqlx::query_scalar("SELECT held_amount FROM campaigns WHERE id = 10")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(held, 300.0);
+        match repo.open(&donation, "Again".to_string()).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("already has an open dispute")),
+            _ => panic!("Expected ValidationError"),
+        }
+
+        let resolved = repo
+            .resolve(dispute.id, 99, DisputeResolution::Refund, Some("Card stolen".to_string()))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.status, DisputeStatus::Refunded);
+        assert!(repo
+            .resolve(dispute.id, 99, DisputeResolution::Dismiss, None)
+            .await
+            .unwrap()
+            .is_none());
+
+        let (held, collected): (f64, f64) =
+            sqlx::query_as("SELECT held_amount, collected_amount FROM campaigns WHERE id = 10")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        let balance: f64 = sqlx::query_scalar("SELECT balance FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        let status: DonationStatus = sqlx::query_scalar("SELECT status FROM donations WHERE id = $1")
+            .bind(donation.id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!((held, collected, balance), (0.0, 0.0, 300.0));
+        assert_eq!(status, DonationStatus::Refunded);
+
+        let audit = repo.find_audit_log(dispute.id).await.unwrap();
+        let actions: Vec<&str> = audit.iter().map(|entry| entry.action.as_str()).collect();
+        assert_eq!(actions, vec!["opened", "refunded"]);
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::model::donation_archive::ArchivedDonation;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Every column of `donations`; `donations_archive` has the same ones plus `archived_at`.
+const DONATION_COLUMNS: &str = "id, user_id, campaign_id, amount, message, private_note, referral_code, \
+                                honoree_name, honoree_email, status, receipt_number, receipt_issued_at, created_at, \
+                                is_anonymous";
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DonationArchiveRepository: Send + Sync {
+    async fn archive_batch(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<u64, AppError>;
+    async fn restore_campaign(&self, campaign_id: i32) -> Result<u64, AppError>;
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<ArchivedDonation>, AppError>;
+}
+
+pub struct PgDonationArchiveRepository {
+    pool: PgPool,
+}
+
+impl PgDonationArchiveRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDonationArchiveRepository { pool }
+    }
+}
+
+#[async_trait]
+impl DonationArchiveRepository for PgDonationArchiveRepository {
+    // Completed campaigns no longer take donations, so their old rows are settled history.
+    // Delete and insert are one statement, so a row is never in both tables or in neither.
+    async fn archive_batch(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<u64, AppError> {
+        let archived = sqlx::query(&format!(
+            "WITH moved AS ( \
+                 DELETE FROM donations WHERE id IN ( \
+                     SELECT d.id FROM donations d JOIN campaigns c ON c.id = d.campaign_id \
+                     WHERE c.status = 'completed' AND d.created_at < $1 AND d.status <> 'pending_review' \
+                     ORDER BY d.id LIMIT $2 FOR UPDATE OF d SKIP LOCKED \
+                 ) RETURNING {columns} \
+             ) \
+             INSERT INTO donations_archive ({columns}) SELECT {columns} FROM moved",
+            columns = DONATION_COLUMNS
+        ))
+        .bind(cutoff)
+        .bind(limit)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(archived)
+    }
+
+    async fn restore_campaign(&self, campaign_id: i32) -> Result<u64, AppError> {
+        let restored = sqlx::query(&format!(
+            "WITH moved AS ( \
+                 DELETE FROM donations_archive WHERE campaign_id = $1 RETURNING {columns} \
+             ) \
+             INSERT INTO donations ({columns}) SELECT {columns} FROM moved",
+            columns = DONATION_COLUMNS
+        ))
+        .bind(campaign_id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(restored)
+    }
+
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<ArchivedDonation>, AppError> {
+        let donations = sqlx::query_as::<_, ArchivedDonation>(
+            "SELECT * FROM donations_archive WHERE campaign_id = $1 ORDER BY created_at",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::donation_repo::{DonationRepository, PgDonationRepository};
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_archive_keeps_totals_and_restores() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, target_amount, status) VALUES (10, 100, 'completed'), (11, 100, 'active');
+             INSERT INTO donations (user_id, campaign_id, amount, created_at) VALUES
+                 (1, 10, 40, NOW() - INTERVAL '6 years'),
+                 (1, 10, 60, NOW() - INTERVAL '6 years'),
+                 (1, 10, 5, NOW()),
+                 (1, 11, 70, NOW() - INTERVAL '6 years');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationArchiveRepository::new(db.pool.clone());
+        let donation_repo = PgDonationRepository::new(db.pool.clone());
+        let cutoff = Utc::now() - chrono::Duration::days(5 * 365);
+
+        assert_eq!(repo.archive_batch(cutoff, 1).await.unwrap(), 1);
+        assert_eq!(repo.archive_batch(cutoff, 10).await.unwrap(), 1);
+        assert_eq!(repo.archive_batch(cutoff, 10).await.unwrap(), 0);
+
+        let live: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM donations")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(live, 2);
+        assert_eq!(repo.find_by_campaign(10).await.unwrap().len(), 2);
+        assert_eq!(donation_repo.campaign_total(10).await.unwrap(), 105.0);
+
+        assert_eq!(repo.restore_campaign(10).await.unwrap(), 2);
+        assert!(repo.find_by_campaign(10).await.unwrap().is_empty());
+        let live: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM donations")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(live, 4);
+    }
+}
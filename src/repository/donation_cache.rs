@@ -0,0 +1,217 @@
+use crate::model::cache::{CacheMapStats, CacheScope, CacheStats};
+use dashmap::DashMap;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait CacheInvalidator: Send + Sync {
+    fn invalidate_campaign(&self, campaign_id: i32);
+    fn invalidate_user_campaign(&self, user_id: i32, campaign_id: i32);
+    fn flush_all(&self);
+}
+
+// Running totals of settled donations, keyed by campaign and by (user, campaign).
+// The maps are sharded so donations to different campaigns don't contend on one lock.
+#[derive(Default)]
+pub struct DonationCache {
+    campaign_totals: DashMap<i32, f64>,
+    user_totals: DashMap<(i32, i32), f64>,
+    campaign_hits: AtomicU64,
+    campaign_misses: AtomicU64,
+    user_hits: AtomicU64,
+    user_misses: AtomicU64,
+}
+
+fn count_lookup(result: Option<f64>, hits: &AtomicU64, misses: &AtomicU64) -> Option<f64> {
+    match result {
+        Some(_) => hits.fetch_add(1, Ordering::Relaxed),
+        None => misses.fetch_add(1, Ordering::Relaxed),
+    };
+    result
+}
+
+// Rough per-entry footprint: key + value + one control byte of hashbrown overhead.
+fn estimate_bytes<K>(entries: usize) -> usize {
+    entries * (size_of::<K>() + size_of::<f64>() + 1)
+}
+
+impl DonationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn campaign_total(&self, campaign_id: i32) -> Option<f64> {
+        let total = self.campaign_totals.get(&campaign_id).map(|total| *total);
+        count_lookup(total, &self.campaign_hits, &self.campaign_misses)
+    }
+
+    pub fn user_campaign_total(&self, user_id: i32, campaign_id: i32) -> Option<f64> {
+        let total = self
+            .user_totals
+            .get(&(user_id, campaign_id))
+            .map(|total| *total);
+        count_lookup(total, &self.user_hits, &self.user_misses)
+    }
+
+    pub fn set_campaign_total(&self, campaign_id: i32, total: f64) {
+        self.campaign_totals.insert(campaign_id, total);
+    }
+
+    pub fn set_user_campaign_total(&self, user_id: i32, campaign_id: i32, total: f64) {
+        self.user_totals.insert((user_id, campaign_id), total);
+    }
+
+    // Only bumps totals that are already cached; a missing entry is loaded from the DB on next read.
+    pub fn record_donation(&self, user_id: i32, campaign_id: i32, amount: f64) {
+        if let Some(mut total) = self.campaign_totals.get_mut(&campaign_id) {
+            *total += amount;
+        }
+        if let Some(mut total) = self.user_totals.get_mut(&(user_id, campaign_id)) {
+            *total += amount;
+        }
+    }
+}
+
+impl DonationCache {
+    pub fn stats(&self) -> CacheStats {
+        let campaign_entries = self.campaign_totals.len();
+        let user_entries = self.user_totals.len();
+        CacheStats {
+            campaign_totals: CacheMapStats {
+                entries: campaign_entries,
+                hits: self.campaign_hits.load(Ordering::Relaxed),
+                misses: self.campaign_misses.load(Ordering::Relaxed),
+                memory_estimate_bytes: estimate_bytes::<i32>(campaign_entries),
+            },
+            user_totals: CacheMapStats {
+                entries: user_entries,
+                hits: self.user_hits.load(Ordering::Relaxed),
+                misses: self.user_misses.load(Ordering::Relaxed),
+                memory_estimate_bytes: estimate_bytes::<(i32, i32)>(user_entries),
+            },
+        }
+    }
+
+    pub fn flush_scope(&self, scope: CacheScope) {
+        match scope {
+            CacheScope::CampaignTotals => self.campaign_totals.clear(),
+            CacheScope::UserTotals => self.user_totals.clear(),
+        }
+    }
+}
+
+impl CacheInvalidator for DonationCache {
+    fn invalidate_campaign(&self, campaign_id: i32) {
+        self.campaign_totals.remove(&campaign_id);
+    }
+
+    fn invalidate_user_campaign(&self, user_id: i32, campaign_id: i32) {
+        self.user_totals.remove(&(user_id, campaign_id));
+    }
+
+    fn flush_all(&self) {
+        self.campaign_totals.clear();
+        self.user_totals.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_donation_only_bumps_cached_totals() {
+        let cache = DonationCache::new();
+        cache.set_campaign_total(10, 100.0);
+
+        cache.record_donation(1, 10, 50.0);
+        cache.record_donation(1, 11, 50.0);
+
+        assert_eq!(cache.campaign_total(10), Some(150.0));
+        assert_eq!(cache.campaign_total(11), None);
+        assert_eq!(cache.user_campaign_total(1, 10), None);
+    }
+
+    #[test]
+    fn test_concurrent_record_donation_keeps_every_update() {
+        let cache = std::sync::Arc::new(DonationCache::new());
+        for campaign_id in 0..4 {
+            cache.set_campaign_total(campaign_id, 0.0);
+        }
+
+        let handles: Vec<_> = (0..8)
+            .map(|user_id| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    for i in 0..1000 {
+                        cache.record_donation(user_id, i % 4, 1.0);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for campaign_id in 0..4 {
+            assert_eq!(cache.campaign_total(campaign_id), Some(2000.0));
+        }
+    }
+
+    #[test]
+    fn test_invalidation_scopes() {
+        let cache = DonationCache::new();
+        cache.set_campaign_total(10, 100.0);
+        cache.set_campaign_total(11, 200.0);
+        cache.set_user_campaign_total(1, 10, 40.0);
+        cache.set_user_campaign_total(2, 10, 60.0);
+
+        cache.invalidate_campaign(10);
+        cache.invalidate_user_campaign(1, 10);
+
+        assert_eq!(cache.campaign_total(10), None);
+        assert_eq!(cache.campaign_total(11), Some(200.0));
+        assert_eq!(cache.user_campaign_total(1, 10), None);
+        assert_eq!(cache.user_campaign_total(2, 10), Some(60.0));
+
+        cache.flush_all();
+        assert_eq!(cache.campaign_total(11), None);
+        assert_eq!(cache.user_campaign_total(2, 10), None);
+    }
+
+    #[test]
+    fn test_stats_count_hits_and_misses() {
+        let cache = DonationCache::new();
+        cache.set_campaign_total(10, 100.0);
+        cache.set_user_campaign_total(1, 10, 40.0);
+
+        cache.campaign_total(10);
+        cache.campaign_total(10);
+        cache.campaign_total(11);
+        cache.user_campaign_total(2, 10);
+
+        let stats = cache.stats();
+        assert_eq!(stats.campaign_totals.entries, 1);
+        assert_eq!(stats.campaign_totals.hits, 2);
+        assert_eq!(stats.campaign_totals.misses, 1);
+        assert_eq!(stats.user_totals.entries, 1);
+        assert_eq!(stats.user_totals.hits, 0);
+        assert_eq!(stats.user_totals.misses, 1);
+        assert!(stats.user_totals.memory_estimate_bytes > 0);
+    }
+
+    #[test]
+    fn test_flush_scope_only_clears_selected_map() {
+        let cache = DonationCache::new();
+        cache.set_campaign_total(10, 100.0);
+        cache.set_user_campaign_total(1, 10, 40.0);
+
+        cache.flush_scope(CacheScope::UserTotals);
+
+        assert_eq!(cache.stats().campaign_totals.entries, 1);
+        assert_eq!(cache.stats().user_totals.entries, 0);
+    }
+}
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::model::donation_export::{DonationExportRow, DonationExportRun};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DonationExportRepository: Send + Sync {
+    /// Donations created in `[since, until)` with an id above `after_id`, in id order.
+    async fn find_page(&self, since: Option<DateTime<Utc>>, until: DateTime<Utc>, after_id: i32, limit: i64) -> Result<Vec<DonationExportRow>, AppError>;
+    async fn find_last_run(&self) -> Result<Option<DonationExportRun>, AppError>;
+    async fn record_run(&self, since: Option<DateTime<Utc>>, until: DateTime<Utc>, object_key: &str, row_count: i64) -> Result<DonationExportRun, AppError>;
+}
+
+pub struct PgDonationExportRepository {
+    pool: PgPool,
+}
+
+impl PgDonationExportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDonationExportRepository { pool }
+    }
+}
+
+#[async_trait]
+impl DonationExportRepository for PgDonationExportRepository {
+    // Reads `all_donations` so archived donations are part of a full export.
+    async fn find_page(&self, since: Option<DateTime<Utc>>, until: DateTime<Utc>, after_id: i32, limit: i64) -> Result<Vec<DonationExportRow>, AppError> {
+        let rows = sqlx::query_as::<_, DonationExportRow>(
+            "SELECT id, user_id, campaign_id, amount, status, referral_code, receipt_number, created_at \
+             FROM all_donations \
+             WHERE ($1::TIMESTAMPTZ IS NULL OR created_at >= $1) AND created_at < $2 AND id > $3 \
+             ORDER BY id LIMIT $4",
+        )
+        .bind(since)
+        .bind(until)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    async fn find_last_run(&self) -> Result<Option<DonationExportRun>, AppError> {
+        let run = sqlx::query_as::<_, DonationExportRun>(
+            "SELECT * FROM donation_export_runs ORDER BY until DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(run)
+    }
+
+    async fn record_run(&self, since: Option<DateTime<Utc>>, until: DateTime<Utc>, object_key: &str, row_count: i64) -> Result<DonationExportRun, AppError> {
+        let run = sqlx::query_as::<_, DonationExportRun>(
+            "INSERT INTO donation_export_runs (since, until, object_key, row_count) \
+             VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(since)
+        .bind(until)
+        .bind(object_key)
+        .bind(row_count)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_pages_cover_window_and_archive() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO donations (id, user_id, campaign_id, amount, created_at) VALUES
+                 (1, 1, 10, 10, '2024-01-01T00:00:00Z'),
+                 (3, 1, 10, 30, '2024-02-01T00:00:00Z'),
+                 (4, 1, 10, 40, '2024-03-01T00:00:00Z');
+             INSERT INTO donations_archive (id, user_id, campaign_id, amount, created_at) VALUES
+                 (2, 1, 10, 20, '2024-01-15T00:00:00Z');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationExportRepository::new(db.pool.clone());
+        let until = "2024-03-01T00:00:00Z".parse().unwrap();
+
+        let first = repo.find_page(None, until, 0, 2).await.unwrap();
+        let ids: Vec<i32> = first.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        let second = repo.find_page(None, until, 2, 2).await.unwrap();
+        let ids: Vec<i32> = second.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![3]);
+
+        let since = "2024-01-10T00:00:00Z".parse().unwrap();
+        let delta = repo.find_page(Some(since), until, 0, 10).await.unwrap();
+        assert_eq!(delta.len(), 2);
+
+        assert!(repo.find_last_run().await.unwrap().is_none());
+        repo.record_run(None, until, "donations/full.ndjson", 3).await.unwrap();
+        assert_eq!(repo.find_last_run().await.unwrap().unwrap().until, until);
+    }
+}
@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use crate::model::donation::NewDonationRequest;
+use crate::model::donation_intent::DonationIntent;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DonationIntentRepository: Send + Sync {
+    /// Holds the amount on the donor's wallet and inserts the intent in one transaction.
+    /// Returns None, holding nothing, if the available balance is too low.
+    async fn create(&self, user_id: i32, new_donation: &NewDonationRequest, token_hash: &str, expires_at: DateTime<Utc>) -> Result<Option<DonationIntent>, AppError>;
+    async fn find_by_id(&self, intent_id: i32) -> Result<Option<DonationIntent>, AppError>;
+    /// Marks a pending intent failed and releases its hold in the same transaction.
+    async fn fail(&self, intent_id: i32, user_id: i32, token_hash: &str) -> Result<(), AppError>;
+    /// Expires up to `limit` overdue pending intents and releases their holds in the
+    /// same statement.
+    async fn expire_due(&self, limit: i64) -> Result<Vec<DonationIntent>, AppError>;
+}
+
+pub struct PgDonationIntentRepository {
+    pool: PgPool,
+}
+
+impl PgDonationIntentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDonationIntentRepository { pool }
+    }
+}
+
+// Moves a pending, unexpired intent to confirmed so a second confirm or the expiry job
+// cannot also act on its hold. Runs in the caller's settling transaction. Returns None if
+// any condition fails.
+pub(crate) async fn claim(conn: &mut PgConnection, intent_id: i32, user_id: i32, token_hash: &str) -> Result<Option<DonationIntent>, AppError> {
+    let intent = sqlx::query_as::<_, DonationIntent>(
+        "UPDATE donation_intents SET status = 'confirmed' \
+         WHERE id = $1 AND user_id = $2 AND token_hash = $3 AND status = 'pending' AND expires_at > NOW() \
+         RETURNING *",
+    )
+    .bind(intent_id)
+    .bind(user_id)
+    .bind(token_hash)
+    .fetch_optional(conn)
+    .await?;
+    Ok(intent)
+}
+
+#[async_trait]
+impl DonationIntentRepository for PgDonationIntentRepository {
+    async fn create(&self, user_id: i32, new_donation: &NewDonationRequest, token_hash: &str, expires_at: DateTime<Utc>) -> Result<Option<DonationIntent>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let held = sqlx::query(
+            "UPDATE wallets SET held_amount = held_amount + $2 \
+             WHERE user_id = $1 AND balance - held_amount >= $2",
+        )
+        .bind(user_id)
+        .bind(new_donation.amount)
+        .execute(&mut *tx)
+        .await?;
+        if held.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let intent = sqlx::query_as::<_, DonationIntent>(
+            "INSERT INTO donation_intents (user_id, campaign_id, amount, message, private_note, tier_id, referral_code, token_hash, expires_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *",
+        )
+        .bind(user_id)
+        .bind(new_donation.campaign_id)
+        .bind(new_donation.amount)
+        .bind(&new_donation.message)
+        .bind(&new_donation.private_note)
+        .bind(new_donation.tier_id)
+        .bind(&new_donation.referral_code)
+        .bind(token_hash)
+        .bind(expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(Some(intent))
+    }
+
+    async fn find_by_id(&self, intent_id: i32) -> Result<Option<DonationIntent>, AppError> {
+        let intent = sqlx::query_as::<_, DonationIntent>("SELECT * FROM donation_intents WHERE id = $1")
+            .bind(intent_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(intent)
+    }
+
+    async fn fail(&self, intent_id: i32, user_id: i32, token_hash: &str) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        let failed = sqlx::query_as::<_, DonationIntent>(
+            "UPDATE donation_intents SET status = 'failed' \
+             WHERE id = $1 AND user_id = $2 AND token_hash = $3 AND status = 'pending' \
+             RETURNING *",
+        )
+        .bind(intent_id)
+        .bind(user_id)
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some(intent) = failed {
+            sqlx::query("UPDATE wallets SET held_amount = held_amount - $2 WHERE user_id = $1")
+                .bind(intent.user_id)
+                .bind(intent.amount)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn expire_due(&self, limit: i64) -> Result<Vec<DonationIntent>, AppError> {
+        let expired = sqlx::query_as::<_, DonationIntent>(
+            "WITH expired AS ( \
+                 UPDATE donation_intents SET status = 'expired' WHERE id IN ( \
+                     SELECT id FROM donation_intents WHERE status = 'pending' AND expires_at <= NOW() \
+                     ORDER BY expires_at LIMIT $1 FOR UPDATE SKIP LOCKED \
+                 ) RETURNING * \
+             ), released AS ( \
+                 UPDATE wallets w SET held_amount = w.held_amount - e.amount \
+                 FROM (SELECT user_id, SUM(amount) AS amount FROM expired GROUP BY user_id) e \
+                 WHERE w.user_id = e.user_id \
+             ) \
+             SELECT * FROM expired",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(expired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+    use chrono::Duration;
+
+    fn request(amount: f64) -> NewDonationRequest {
+        NewDonationRequest {
+            campaign_id: 10,
+            amount,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_intent_is_claimed_once_and_expired_ones_are_not_claimable() {
+        let db = test_db().await;
+        let repo = PgDonationIntentRepository::new(db.pool.clone());
+
+        sqlx::raw_sql("INSERT INTO wallets (user_id, balance) VALUES (1, 1000);")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let live = repo.create(1, &request(100.0), "live", Utc::now() + Duration::minutes(15)).await.unwrap().unwrap();
+        let stale = repo.create(1, &request(200.0), "stale", Utc::now() - Duration::minutes(1)).await.unwrap().unwrap();
+        // Only 700 is left available.
+        assert!(repo.create(1, &request(800.0), "broke", Utc::now() + Duration::minutes(15)).await.unwrap().is_none());
+
+        let mut conn = db.pool.acquire().await.unwrap();
+        assert!(claim(&mut conn, live.id, 2, "live").await.unwrap().is_none());
+        assert!(claim(&mut conn, live.id, 1, "wrong").await.unwrap().is_none());
+        assert!(claim(&mut conn, live.id, 1, "live").await.unwrap().is_some());
+        assert!(claim(&mut conn, live.id, 1, "live").await.unwrap().is_none());
+        assert!(claim(&mut conn, stale.id, 1, "stale").await.unwrap().is_none());
+
+        let expired = repo.expire_due(10).await.unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, stale.id);
+        assert!(repo.expire_due(10).await.unwrap().is_empty());
+        // The expired intent's 200 is released; the claimed one's 100 stays held.
+        let held: f64 = sqlx::query_scalar("SELECT held_amount FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(held, 100.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_fail_releases_the_hold_once() {
+        let db = test_db().await;
+        sqlx::raw_sql("INSERT INTO wallets (user_id, balance) VALUES (1, 1000);")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgDonationIntentRepository::new(db.pool.clone());
+        let intent = repo.create(1, &request(100.0), "token", Utc::now() + Duration::minutes(15)).await.unwrap().unwrap();
+
+        repo.fail(intent.id, 1, "wrong").await.unwrap();
+        repo.fail(intent.id, 1, "token").await.unwrap();
+        repo.fail(intent.id, 1, "token").await.unwrap();
+
+        let held: f64 = sqlx::query_scalar("SELECT held_amount FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(held, 0.0);
+        let status = repo.find_by_id(intent.id).await.unwrap().unwrap().status;
+        assert_eq!(status, crate::model::donation_intent::DonationIntentStatus::Failed);
+    }
+}
@@ -1,47 +1,1613 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
-use crate::model::donation::{Donation, NewDonationRequest};
+use sqlx::{FromRow, PgConnection, PgPool};
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use crate::model::donation::{
+    CampaignDonationTotal, CampaignDonorCounts, Donation, DonationPrivateNote, DonationReceipt,
+    DonationSizeCount, DonationStatus, MonthlyDonationTotal, NewDonationRequest, PublicDonation, ReferralTotal,
+    UserCampaignTotal,
+};
+use crate::model::donation_import::ImportedDonationRow;
+use crate::model::donation_basket::{BasketItem, DonationBasketReceipt};
+use crate::model::campaign_overflow::OverflowPolicy;
+use crate::config::ReceiptConfig;
 use crate::errors::AppError;
+use crate::repository::donation_cache::{CacheInvalidator, DonationCache};
+use crate::repository::retry::{with_retry, RetryPolicy};
+use crate::model::event::DomainEvent;
+use crate::repository::donation_intent_repo;
+use crate::service::event_bus::enqueue_event;
 
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
 #[async_trait]
 pub trait DonationRepository: Send + Sync {
     async fn create(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Donation, AppError>;
+    /// Claims a pending donation intent and settles the funds it holds, all in one
+    /// transaction. None if the intent can't be claimed.
+    async fn settle_intent(&self, intent_id: i32, user_id: i32, token_hash: &str) -> Result<Option<Donation>, AppError>;
+    async fn create_basket(&self, user_id: i32, items: Vec<BasketItem>, message: Option<String>) -> Result<(Vec<Donation>, DonationBasketReceipt), AppError>;
     async fn find_by_id(&self, donation_id: i32) -> Result<Option<Donation>, AppError>;
+    async fn find_receipt(&self, donation_id: i32) -> Result<Option<DonationReceipt>, AppError>;
     async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<Donation>, AppError>;
+    async fn find_public_by_campaign(&self, campaign_id: i32) -> Result<Vec<PublicDonation>, AppError>;
     async fn find_by_user(&self, user_id: i32) -> Result<Vec<Donation>, AppError>;
     async fn update_message(&self, donation_id: i32, user_id: i32, message: Option<String>) -> Result<u64, AppError>;
+    /// Holds the amount on the donor's wallet and inserts the donation awaiting review,
+    /// in one transaction. None if the wallet can't cover the amount.
+    async fn create_pending_review(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Option<Donation>, AppError>;
+    async fn find_by_status(&self, status: DonationStatus) -> Result<Vec<Donation>, AppError>;
+    async fn find_pending_review_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Donation>, AppError>;
+    async fn approve_pending(&self, donation_id: i32) -> Result<Option<Donation>, AppError>;
+    async fn close_pending(&self, donation_id: i32, status: DonationStatus) -> Result<Option<Donation>, AppError>;
+    async fn sum_by_campaign_for_user(&self, user_id: i32) -> Result<Vec<CampaignDonationTotal>, AppError>;
+    async fn sum_by_month_for_user(&self, user_id: i32, since: DateTime<Utc>) -> Result<Vec<MonthlyDonationTotal>, AppError>;
+    async fn campaign_total(&self, campaign_id: i32) -> Result<f64, AppError>;
+    async fn user_campaign_total(&self, user_id: i32, campaign_id: i32) -> Result<f64, AppError>;
+    async fn top_campaign_totals(&self, limit: i64) -> Result<Vec<CampaignDonationTotal>, AppError>;
+    async fn user_totals_for_campaigns(&self, campaign_ids: Vec<i32>) -> Result<Vec<UserCampaignTotal>, AppError>;
+    async fn import_batch(&self, rows: Vec<ImportedDonationRow>) -> Result<u64, AppError>;
+    async fn recent_with_donor_count(&self, campaign_id: i32, limit: i64) -> Result<(i64, Vec<Donation>), AppError>;
+    /// Refunds up to `limit` settled or imported donations. With `skip_locked`, rows
+    /// another transaction holds are passed over; without it the batch waits for them.
+    async fn refund_batch(&self, campaign_id: i32, limit: i64, skip_locked: bool) -> Result<Vec<Donation>, AppError>;
+    async fn find_private_notes(&self, campaign_id: i32) -> Result<Vec<DonationPrivateNote>, AppError>;
+    async fn donor_counts(&self, campaign_id: i32) -> Result<CampaignDonorCounts, AppError>;
+    async fn size_histogram(&self, campaign_id: i32, bounds: Vec<f64>) -> Result<Vec<DonationSizeCount>, AppError>;
+    async fn referral_totals(&self, campaign_id: Option<i32>) -> Result<Vec<ReferralTotal>, AppError>;
+}
+
+#[derive(FromRow)]
+struct RecentDonationRow {
+    #[sqlx(flatten)]
+    donation: Donation,
+    donor_count: i64,
+}
+
+fn format_receipt_number(prefix: &str, fiscal_year: i32, sequence: i32) -> String {
+    format!("{}/{}/{:06}", prefix, fiscal_year, sequence)
+}
+
+// Takes the next number of the current fiscal year inside the settling transaction.
+// The counter row stays locked until commit, so concurrent settlements get consecutive
+// numbers and a rollback hands its number back: the sequence has no gaps. Callers run
+// this last so the per-year lock is held as briefly as possible.
+async fn assign_receipt_number(conn: &mut PgConnection, receipts: &ReceiptConfig, donation_id: i32) -> Result<String, AppError> {
+    let (fiscal_year, sequence): (i32, i32) = sqlx::query_as(
+        "INSERT INTO receipt_numbers (fiscal_year, last_number) \
+         VALUES (EXTRACT(YEAR FROM (NOW() AT TIME ZONE 'UTC') - make_interval(months => $1))::INT, 1) \
+         ON CONFLICT (fiscal_year) DO UPDATE SET last_number = receipt_numbers.last_number + 1 \
+         RETURNING fiscal_year, last_number",
+    )
+    .bind(receipts.fiscal_year_start_month as i32 - 1)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    let receipt_number = format_receipt_number(&receipts.prefix, fiscal_year, sequence);
+    sqlx::query("UPDATE donations SET receipt_number = $2, receipt_issued_at = NOW() WHERE id = $1")
+        .bind(donation_id)
+        .bind(&receipt_number)
+        .execute(&mut *conn)
+        .await?;
+    Ok(receipt_number)
+}
+
+fn not_accepting_donations(status: &str) -> AppError {
+    match status {
+        "suspended" => AppError::ValidationError(
+            "Campaign is suspended while under investigation and cannot receive donations"
+                .to_string(),
+        ),
+        _ => AppError::ValidationError(
+            "Campaign is not accepting donations".to_string(),
+        ),
+    }
+}
+
+// Adds `amount` to the campaign once the donor's wallet has been debited for it: applies
+// the overflow policy under the campaign row lock, completes the campaign when the target
+// is met and returns any capped excess to the wallet. Returns the amount accepted.
+async fn credit_campaign(conn: &mut PgConnection, user_id: i32, campaign_id: i32, amount: f64) -> Result<f64, AppError> {
+    let campaign: Option<(String, f64, f64, OverflowPolicy)> = sqlx::query_as(
+        "SELECT status::TEXT, target_amount, collected_amount, overflow_policy FROM campaigns WHERE id = $1 FOR UPDATE",
+    )
+    .bind(campaign_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let (target_amount, collected_amount, overflow_policy) = match campaign {
+        None => return Err(AppError::NotFound("Campaign not found".to_string())),
+        Some((status, target_amount, collected_amount, overflow_policy)) if status == "active" => {
+            (target_amount, collected_amount, overflow_policy)
+        }
+        Some((status, ..)) => return Err(not_accepting_donations(&status)),
+    };
+
+    let outcome = overflow_policy.apply(target_amount, collected_amount, amount);
+    if outcome.accepted_amount <= 0.0 {
+        return Err(AppError::ValidationError("Campaign has already reached its target".to_string()));
+    }
+    sqlx::query(
+        "UPDATE campaigns SET collected_amount = collected_amount + $2, target_amount = $3, \
+         status = CASE WHEN $4 THEN 'completed' ELSE status END \
+         WHERE id = $1",
+    )
+    .bind(campaign_id)
+    .bind(outcome.accepted_amount)
+    .bind(outcome.target_amount)
+    .bind(outcome.completes_campaign)
+    .execute(&mut *conn)
+    .await?;
+    if outcome.completes_campaign {
+        enqueue_event(conn, &DomainEvent::CampaignCompleted { campaign_id }).await?;
+    }
+
+    // A capped campaign hands the excess straight back; the wallet row is already locked.
+    let refunded_excess = amount - outcome.accepted_amount;
+    if refunded_excess > 0.0 {
+        sqlx::query("UPDATE wallets SET balance = balance + $2 WHERE user_id = $1")
+            .bind(user_id)
+            .bind(refunded_excess)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(outcome.accepted_amount)
+}
+
+// Spends funds a review or a donation intent already put on hold.
+async fn debit_hold(conn: &mut PgConnection, user_id: i32, amount: f64) -> Result<(), AppError> {
+    let debited = sqlx::query(
+        "UPDATE wallets SET balance = balance - $2, held_amount = held_amount - $2 \
+         WHERE user_id = $1 AND held_amount >= $2",
+    )
+    .bind(user_id)
+    .bind(amount)
+    .execute(conn)
+    .await?
+    .rows_affected();
+    if debited == 0 {
+        return Err(AppError::ValidationError("Reserved funds are no longer held".to_string()));
+    }
+    Ok(())
+}
+
+// Subscribers hear about a settled donation only once it has committed.
+// Writes the ledger row for a settled donation and enqueues `DonationCreated`, so the
+// transaction history and the event stream both see it in the same transaction.
+async fn record_settled_donation(conn: &mut PgConnection, donation: &Donation) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO transactions (user_id, campaign_id, transaction_type, amount) \
+         VALUES ($1, $2, 'donation', $3)",
+    )
+    .bind(donation.user_id)
+    .bind(donation.campaign_id)
+    .bind(donation.amount)
+    .execute(&mut *conn)
+    .await?;
+    let event = DomainEvent::DonationCreated {
+        donation_id: donation.id,
+        user_id: donation.user_id,
+        campaign_id: donation.campaign_id,
+        amount: donation.amount,
+    };
+    enqueue_event(conn, &event).await
+}
+
+// Credits one new donation to its campaign, inserts the settled row, claims the reward
+// tier and records it in the ledger and outbox. Receipt numbers are left to the caller
+// so they are taken last.
+async fn settle_into_campaign(conn: &mut PgConnection, user_id: i32, new_donation: &NewDonationRequest) -> Result<Donation, AppError> {
+    let accepted_amount = credit_campaign(conn, user_id, new_donation.campaign_id, new_donation.amount).await?;
+    let refunded_excess = new_donation.amount - accepted_amount;
+
+    let mut donation = sqlx::query_as::<_, Donation>(
+        "INSERT INTO donations (user_id, campaign_id, amount, message, private_note, referral_code, honoree_name, honoree_email, is_anonymous, status) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'settled') RETURNING *",
+    )
+    .bind(user_id)
+    .bind(new_donation.campaign_id)
+    .bind(accepted_amount)
+    .bind(&new_donation.message)
+    .bind(&new_donation.private_note)
+    .bind(&new_donation.referral_code)
+    .bind(&new_donation.honoree_name)
+    .bind(&new_donation.honoree_email)
+    .bind(new_donation.anonymous)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if let Some(tier_id) = new_donation.tier_id {
+        // Limited tiers decrement in the same transaction, so stock can't be oversold.
+        let claimed = sqlx::query(
+            "UPDATE donation_tiers SET remaining = remaining - 1 \
+             WHERE id = $1 AND campaign_id = $2 AND min_amount <= $3 \
+             AND (remaining IS NULL OR remaining > 0)",
+        )
+        .bind(tier_id)
+        .bind(new_donation.campaign_id)
+        .bind(accepted_amount)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+        if claimed == 0 {
+            let min_amount: Option<f64> = sqlx::query_scalar(
+                "SELECT min_amount FROM donation_tiers WHERE id = $1 AND campaign_id = $2",
+            )
+            .bind(tier_id)
+            .bind(new_donation.campaign_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+            return Err(match min_amount {
+                None => AppError::NotFound("Reward tier not found".to_string()),
+                Some(min_amount) if accepted_amount < min_amount => AppError::ValidationError(
+                    "Donation amount is below the reward tier minimum".to_string(),
+                ),
+                Some(_) => AppError::ValidationError("Reward tier is sold out".to_string()),
+            });
+        }
+
+        sqlx::query("INSERT INTO reward_claims (donation_id, tier_id) VALUES ($1, $2)")
+            .bind(donation.id)
+            .bind(tier_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    record_settled_donation(conn, &donation).await?;
+    donation.refunded_excess = (refunded_excess > 0.0).then_some(refunded_excess);
+    Ok(donation)
 }
 
 pub struct PgDonationRepository {
     pool: PgPool,
+    cache: Arc<DonationCache>,
+    receipts: ReceiptConfig,
 }
 
 impl PgDonationRepository {
     pub fn new(pool: PgPool) -> Self {
-        PgDonationRepository { pool }
+        PgDonationRepository {
+            pool,
+            cache: Arc::new(DonationCache::new()),
+            receipts: ReceiptConfig::default(),
+        }
+    }
+
+    pub fn with_receipts(mut self, receipts: ReceiptConfig) -> Self {
+        self.receipts = receipts;
+        self
+    }
+
+    pub fn cache(&self) -> Arc<DonationCache> {
+        self.cache.clone()
+    }
+
+    // Wallet debit, campaign total and the target-met completion all commit together.
+    // The campaign row is locked before its overflow policy is applied, so concurrent
+    // donations crossing the target serialize there and exactly one of them flips the status.
+    async fn insert_settled(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Donation, AppError> {
+        let pool = &self.pool;
+        let receipts = &self.receipts;
+        let donation = with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+
+            let debited = sqlx::query(
+                "UPDATE wallets SET balance = balance - $2 \
+                 WHERE user_id = $1 AND balance - held_amount >= $2",
+            )
+            .bind(user_id)
+            .bind(new_donation.amount)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            if debited == 0 {
+                return Err(AppError::ValidationError("Insufficient wallet balance".to_string()));
+            }
+
+            let mut donation = settle_into_campaign(&mut tx, user_id, new_donation).await?;
+            donation.receipt_number = Some(assign_receipt_number(&mut tx, receipts, donation.id).await?);
+
+            tx.commit().await?;
+            Ok(donation)
+        })
+        .await?;
+
+        self.cache
+            .record_donation(user_id, donation.campaign_id, donation.amount);
+        Ok(donation)
+    }
+}
+
+impl CacheInvalidator for PgDonationRepository {
+    fn invalidate_campaign(&self, campaign_id: i32) {
+        self.cache.invalidate_campaign(campaign_id);
+    }
+
+    fn invalidate_user_campaign(&self, user_id: i32, campaign_id: i32) {
+        self.cache.invalidate_user_campaign(user_id, campaign_id);
+    }
+
+    fn flush_all(&self) {
+        self.cache.flush_all();
     }
 }
 
 #[async_trait]
 impl DonationRepository for PgDonationRepository {
     async fn create(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Donation, AppError> {
-        unimplemented!()
+        self.insert_settled(user_id, new_donation).await
+    }
+
+    // Claiming happens inside the settling transaction, so a failed settlement leaves the
+    // intent pending with its hold in place rather than confirmed without a donation.
+    async fn settle_intent(&self, intent_id: i32, user_id: i32, token_hash: &str) -> Result<Option<Donation>, AppError> {
+        let pool = &self.pool;
+        let receipts = &self.receipts;
+        let donation = with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+            let Some(intent) = donation_intent_repo::claim(&mut tx, intent_id, user_id, token_hash).await? else {
+                return Ok(None);
+            };
+            debit_hold(&mut tx, intent.user_id, intent.amount).await?;
+
+            let new_donation = NewDonationRequest {
+                campaign_id: intent.campaign_id,
+                amount: intent.amount,
+                message: intent.message,
+                private_note: intent.private_note,
+                tier_id: intent.tier_id,
+                referral_code: intent.referral_code,
+                honoree_name: None,
+                honoree_email: None,
+                anonymous: false,
+            };
+            let mut donation = settle_into_campaign(&mut tx, intent.user_id, &new_donation).await?;
+            donation.receipt_number = Some(assign_receipt_number(&mut tx, receipts, donation.id).await?);
+            sqlx::query("UPDATE donation_intents SET donation_id = $2 WHERE id = $1")
+                .bind(intent.id)
+                .bind(donation.id)
+                .execute(&mut *tx)
+                .await?;
+
+            tx.commit().await?;
+            Ok(Some(donation))
+        })
+        .await?;
+
+        if let Some(donation) = &donation {
+            self.cache
+                .record_donation(donation.user_id, donation.campaign_id, donation.amount);
+        }
+        Ok(donation)
+    }
+
+    // The wallet is debited for the whole basket up front and every campaign is credited
+    // in the same transaction, so one rejected campaign rolls back all of the donations.
+    // Campaign rows are locked in id order so two baskets sharing campaigns can't deadlock.
+    async fn create_basket(&self, user_id: i32, mut items: Vec<BasketItem>, message: Option<String>) -> Result<(Vec<Donation>, DonationBasketReceipt), AppError> {
+        items.sort_by_key(|item| item.campaign_id);
+        let total_amount: f64 = items.iter().map(|item| item.amount).sum();
+        let pool = &self.pool;
+        let receipts = &self.receipts;
+        let items = &items;
+        let message = &message;
+        let (donations, receipt) = with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+
+            let debited = sqlx::query(
+                "UPDATE wallets SET balance = balance - $2 \
+                 WHERE user_id = $1 AND balance - held_amount >= $2",
+            )
+            .bind(user_id)
+            .bind(total_amount)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            if debited == 0 {
+                return Err(AppError::ValidationError("Insufficient wallet balance".to_string()));
+            }
+
+            let mut donations = Vec::with_capacity(items.len());
+            for item in items {
+                let new_donation = NewDonationRequest {
+                    campaign_id: item.campaign_id,
+                    amount: item.amount,
+                    message: message.clone(),
+                    private_note: None,
+                    tier_id: None,
+                    referral_code: None,
+                    honoree_name: None,
+                    honoree_email: None,
+                    anonymous: false,
+                };
+                donations.push(settle_into_campaign(&mut tx, user_id, &new_donation).await?);
+            }
+            for donation in donations.iter_mut() {
+                donation.receipt_number = Some(assign_receipt_number(&mut tx, receipts, donation.id).await?);
+            }
+
+            let donation_ids: Vec<i32> = donations.iter().map(|donation| donation.id).collect();
+            let lines = sqlx::query_as::<_, DonationReceipt>(
+                "SELECT d.receipt_number, d.id AS donation_id, d.user_id, d.campaign_id, \
+                 c.title AS campaign_title, d.amount, d.status, d.receipt_issued_at AS issued_at \
+                 FROM donations d JOIN campaigns c ON c.id = d.campaign_id \
+                 WHERE d.id = ANY($1) ORDER BY d.id",
+            )
+            .bind(&donation_ids)
+            .fetch_all(&mut *tx)
+            .await?;
+            tx.commit().await?;
+
+            let refunded_excess: f64 = donations.iter().filter_map(|donation| donation.refunded_excess).sum();
+            let receipt = DonationBasketReceipt {
+                user_id,
+                total_amount: donations.iter().map(|donation| donation.amount).sum(),
+                refunded_excess: (refunded_excess > 0.0).then_some(refunded_excess),
+                issued_at: lines.first().map(|line| line.issued_at).unwrap_or_else(Utc::now),
+                lines,
+            };
+            Ok((donations, receipt))
+        })
+        .await?;
+
+        for donation in &donations {
+            self.cache
+                .record_donation(user_id, donation.campaign_id, donation.amount);
+        }
+        Ok((donations, receipt))
     }
 
     async fn find_by_id(&self, donation_id: i32) -> Result<Option<Donation>, AppError> {
-        unimplemented!()
+        let donation = sqlx::query_as::<_, Donation>("SELECT * FROM donations WHERE id = $1")
+            .bind(donation_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(donation)
     }
 
-     async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<Donation>, AppError> {
-        unimplemented!()
+    async fn find_receipt(&self, donation_id: i32) -> Result<Option<DonationReceipt>, AppError> {
+        let receipt = sqlx::query_as::<_, DonationReceipt>(
+            "SELECT d.receipt_number, d.id AS donation_id, d.user_id, d.campaign_id, \
+             c.title AS campaign_title, d.amount, d.status, d.receipt_issued_at AS issued_at \
+             FROM donations d JOIN campaigns c ON c.id = d.campaign_id \
+             WHERE d.id = $1 AND d.receipt_number IS NOT NULL",
+        )
+        .bind(donation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(receipt)
     }
 
-     async fn find_by_user(&self, user_id: i32) -> Result<Vec<Donation>, AppError> {
-        unimplemented!()
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<Donation>, AppError> {
+        let donations = sqlx::query_as::<_, Donation>(
+            "SELECT * FROM donations WHERE campaign_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+
+    // Donors without a profile still show up, just without a name or avatar. Anonymous
+    // donations never join the profile, so nothing identifying leaves the query.
+    async fn find_public_by_campaign(&self, campaign_id: i32) -> Result<Vec<PublicDonation>, AppError> {
+        let donations = sqlx::query_as::<_, PublicDonation>(
+            "SELECT d.id, CASE WHEN d.is_anonymous THEN NULL ELSE d.user_id END AS user_id, d.is_anonymous AS anonymous, \
+                    p.display_name, p.avatar_url, d.amount, d.message, d.honoree_name AS in_honor_of, d.created_at \
+             FROM donations d LEFT JOIN profiles p ON p.user_id = d.user_id AND NOT d.is_anonymous \
+             WHERE d.campaign_id = $1 AND d.status IN ('settled', 'imported') \
+             ORDER BY d.created_at DESC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+
+    // Includes archived donations: this is the donor's full history, e.g. for a data export.
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Donation>, AppError> {
+        let donations = sqlx::query_as::<_, Donation>(
+            "SELECT id, user_id, campaign_id, amount, message, private_note, status, receipt_number, created_at \
+             FROM donations WHERE user_id = $1 \
+             UNION ALL \
+             SELECT id, user_id, campaign_id, amount, message, private_note, status, receipt_number, created_at \
+             FROM donations_archive WHERE user_id = $1 \
+             ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
     }
 
     async fn update_message(&self, donation_id: i32, user_id: i32, message: Option<String>) -> Result<u64, AppError> {
-         unimplemented!()
+        let result = sqlx::query("UPDATE donations SET message = $3 WHERE id = $1 AND user_id = $2")
+            .bind(donation_id)
+            .bind(user_id)
+            .bind(message)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    // Approval re-checks the campaign, but a campaign that can't take donations now
+    // shouldn't tie up the donor's funds for the whole review.
+    async fn create_pending_review(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Option<Donation>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let status: Option<String> = sqlx::query_scalar("SELECT status::TEXT FROM campaigns WHERE id = $1 FOR SHARE")
+            .bind(new_donation.campaign_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        match status.as_deref() {
+            None => return Err(AppError::NotFound("Campaign not found".to_string())),
+            Some("active") => {}
+            Some(status) => return Err(not_accepting_donations(status)),
+        }
+
+        let held = sqlx::query(
+            "UPDATE wallets SET held_amount = held_amount + $2 \
+             WHERE user_id = $1 AND balance - held_amount >= $2",
+        )
+        .bind(user_id)
+        .bind(new_donation.amount)
+        .execute(&mut *tx)
+        .await?;
+        if held.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let donation = sqlx::query_as::<_, Donation>(
+            "INSERT INTO donations (user_id, campaign_id, amount, message, private_note, referral_code, honoree_name, honoree_email, is_anonymous, status) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'pending_review') RETURNING *",
+        )
+        .bind(user_id)
+        .bind(new_donation.campaign_id)
+        .bind(new_donation.amount)
+        .bind(&new_donation.message)
+        .bind(&new_donation.private_note)
+        .bind(&new_donation.referral_code)
+        .bind(&new_donation.honoree_name)
+        .bind(&new_donation.honoree_email)
+        .bind(new_donation.anonymous)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(Some(donation))
+    }
+
+    async fn find_by_status(&self, status: DonationStatus) -> Result<Vec<Donation>, AppError> {
+        let donations = sqlx::query_as::<_, Donation>(
+            "SELECT * FROM donations WHERE status = $1 ORDER BY created_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+
+    async fn find_pending_review_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Donation>, AppError> {
+        let donations = sqlx::query_as::<_, Donation>(
+            "SELECT * FROM donations WHERE status = 'pending_review' AND created_at < $1",
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+
+    // Settles a donation held for review the same way a direct donation settles: the
+    // held funds are debited and credited to the campaign (status check, overflow policy
+    // and completion included), all with the status change and receipt number. None if
+    // it isn't awaiting review.
+    async fn approve_pending(&self, donation_id: i32) -> Result<Option<Donation>, AppError> {
+        let pool = &self.pool;
+        let receipts = &self.receipts;
+        let donation = with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+            let pending = sqlx::query_as::<_, Donation>(
+                "SELECT * FROM donations WHERE id = $1 AND status = 'pending_review' FOR UPDATE",
+            )
+            .bind(donation_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+            let Some(pending) = pending else {
+                return Ok(None);
+            };
+
+            debit_hold(&mut tx, pending.user_id, pending.amount).await?;
+
+            let accepted_amount = credit_campaign(&mut tx, pending.user_id, pending.campaign_id, pending.amount).await?;
+            let mut donation = sqlx::query_as::<_, Donation>(
+                "UPDATE donations SET status = 'settled', amount = $2 WHERE id = $1 RETURNING *",
+            )
+            .bind(donation_id)
+            .bind(accepted_amount)
+            .fetch_one(&mut *tx)
+            .await?;
+            record_settled_donation(&mut tx, &donation).await?;
+            let refunded_excess = pending.amount - accepted_amount;
+            donation.refunded_excess = (refunded_excess > 0.0).then_some(refunded_excess);
+            donation.receipt_number = Some(assign_receipt_number(&mut tx, receipts, donation.id).await?);
+
+            tx.commit().await?;
+            Ok(Some(donation))
+        })
+        .await?;
+
+        if let Some(donation) = &donation {
+            self.cache
+                .record_donation(donation.user_id, donation.campaign_id, donation.amount);
+        }
+        Ok(donation)
+    }
+
+    // Rejects or expires a donation held for review and hands its reserved funds back in
+    // the same transaction. None if it isn't awaiting review.
+    async fn close_pending(&self, donation_id: i32, status: DonationStatus) -> Result<Option<Donation>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let donation = sqlx::query_as::<_, Donation>(
+            "UPDATE donations SET status = $2 WHERE id = $1 AND status = 'pending_review' RETURNING *",
+        )
+        .bind(donation_id)
+        .bind(status)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(donation) = donation else {
+            return Ok(None);
+        };
+
+        sqlx::query("UPDATE wallets SET held_amount = held_amount - $2 WHERE user_id = $1")
+            .bind(donation.user_id)
+            .bind(donation.amount)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(donation))
+    }
+
+    // Totals read `all_donations`, which includes archived rows, so archival never changes them.
+    async fn sum_by_campaign_for_user(&self, user_id: i32) -> Result<Vec<CampaignDonationTotal>, AppError> {
+        let totals = sqlx::query_as::<_, CampaignDonationTotal>(
+            "SELECT campaign_id, SUM(amount)::FLOAT8 AS total_amount, COUNT(*) AS donation_count \
+             FROM all_donations WHERE user_id = $1 AND status IN ('settled', 'imported') \
+             GROUP BY campaign_id ORDER BY total_amount DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(totals)
+    }
+
+    async fn sum_by_month_for_user(&self, user_id: i32, since: DateTime<Utc>) -> Result<Vec<MonthlyDonationTotal>, AppError> {
+        let totals = sqlx::query_as::<_, MonthlyDonationTotal>(
+            "SELECT to_char(date_trunc('month', created_at), 'YYYY-MM') AS month, \
+                    SUM(amount)::FLOAT8 AS total_amount, COUNT(*) AS donation_count \
+             FROM all_donations WHERE user_id = $1 AND status IN ('settled', 'imported') AND created_at >= $2 \
+             GROUP BY 1 ORDER BY 1",
+        )
+        .bind(user_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(totals)
+    }
+
+    async fn campaign_total(&self, campaign_id: i32) -> Result<f64, AppError> {
+        if let Some(total) = self.cache.campaign_total(campaign_id) {
+            return Ok(total);
+        }
+
+        let total: f64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(amount), 0)::FLOAT8 FROM all_donations \
+             WHERE campaign_id = $1 AND status IN ('settled', 'imported')",
+        )
+        .bind(campaign_id)
+        .fetch_one(&self.pool)
+        .await?;
+        self.cache.set_campaign_total(campaign_id, total);
+        Ok(total)
+    }
+
+    async fn user_campaign_total(&self, user_id: i32, campaign_id: i32) -> Result<f64, AppError> {
+        if let Some(total) = self.cache.user_campaign_total(user_id, campaign_id) {
+            return Ok(total);
+        }
+
+        let total: f64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(amount), 0)::FLOAT8 FROM all_donations \
+             WHERE user_id = $1 AND campaign_id = $2 AND status IN ('settled', 'imported')",
+        )
+        .bind(user_id)
+        .bind(campaign_id)
+        .fetch_one(&self.pool)
+        .await?;
+        self.cache.set_user_campaign_total(user_id, campaign_id, total);
+        Ok(total)
+    }
+
+    async fn top_campaign_totals(&self, limit: i64) -> Result<Vec<CampaignDonationTotal>, AppError> {
+        let totals = sqlx::query_as::<_, CampaignDonationTotal>(
+            "SELECT campaign_id, SUM(amount)::FLOAT8 AS total_amount, COUNT(*) AS donation_count \
+             FROM all_donations WHERE status IN ('settled', 'imported') \
+             GROUP BY campaign_id ORDER BY donation_count DESC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(totals)
+    }
+
+    async fn user_totals_for_campaigns(&self, campaign_ids: Vec<i32>) -> Result<Vec<UserCampaignTotal>, AppError> {
+        let totals = sqlx::query_as::<_, UserCampaignTotal>(
+            "SELECT user_id, campaign_id, SUM(amount)::FLOAT8 AS total_amount \
+             FROM all_donations WHERE status IN ('settled', 'imported') AND campaign_id = ANY($1) \
+             GROUP BY user_id, campaign_id",
+        )
+        .bind(campaign_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(totals)
+    }
+
+    // All rows of a batch land in one transaction; no wallet is debited for imported donations.
+    async fn import_batch(&self, rows: Vec<ImportedDonationRow>) -> Result<u64, AppError> {
+        let pool = &self.pool;
+        let rows = &rows;
+        with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+            let mut inserted = 0;
+            for row in rows {
+                inserted += sqlx::query(
+                    "INSERT INTO donations (user_id, campaign_id, amount, message, status, created_at) \
+                     VALUES ($1, $2, $3, $4, 'imported', $5)",
+                )
+                .bind(row.user_id)
+                .bind(row.campaign_id)
+                .bind(row.amount)
+                .bind(&row.message)
+                .bind(row.created_at)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            }
+            tx.commit().await?;
+            Ok(inserted)
+        })
+        .await
+    }
+
+    // Donor count rides along on every recent row so the detail view costs one round trip.
+    async fn recent_with_donor_count(&self, campaign_id: i32, limit: i64) -> Result<(i64, Vec<Donation>), AppError> {
+        let rows = sqlx::query_as::<_, RecentDonationRow>(
+            "SELECT d.*, \
+             (SELECT COUNT(DISTINCT user_id) FROM all_donations \
+              WHERE campaign_id = $1 AND status IN ('settled', 'imported')) AS donor_count \
+             FROM donations d WHERE d.campaign_id = $1 AND d.status IN ('settled', 'imported') \
+             ORDER BY d.created_at DESC LIMIT $2",
+        )
+        .bind(campaign_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let donor_count = rows.first().map_or(0, |row| row.donor_count);
+        Ok((donor_count, rows.into_iter().map(|row| row.donation).collect()))
+    }
+
+    // Marks one batch refunded, credits each donor's wallet, records the refund in the
+    // ledger and takes the amount back off the campaign, all in one transaction. Imported
+    // donations are refunded too: the campaign holds their money all the same, and their
+    // donors may not have a wallet yet.
+    async fn refund_batch(&self, campaign_id: i32, limit: i64, skip_locked: bool) -> Result<Vec<Donation>, AppError> {
+        let pool = &self.pool;
+        let lock = if skip_locked { "FOR UPDATE SKIP LOCKED" } else { "FOR UPDATE" };
+        let sql = format!(
+            "WITH batch AS ( \
+                 SELECT id FROM donations WHERE campaign_id = $1 AND status IN ('settled', 'imported') \
+                 ORDER BY id LIMIT $2 {} \
+             ) \
+             UPDATE donations d SET status = 'refunded' FROM batch \
+             WHERE d.id = batch.id RETURNING d.*",
+            lock
+        );
+        let sql = &sql;
+        let refunded = with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+
+            let refunded = sqlx::query_as::<_, Donation>(sql)
+                .bind(campaign_id)
+                .bind(limit)
+                .fetch_all(&mut *tx)
+                .await?;
+
+            // Donors are notified through the outbox, so a crash after commit loses nothing.
+            for donation in &refunded {
+                sqlx::query(
+                    "INSERT INTO wallets (user_id, balance) VALUES ($1, $2) \
+                     ON CONFLICT (user_id) DO UPDATE SET balance = wallets.balance + EXCLUDED.balance",
+                )
+                .bind(donation.user_id)
+                .bind(donation.amount)
+                .execute(&mut *tx)
+                .await?;
+                sqlx::query(
+                    "INSERT INTO transactions (user_id, campaign_id, transaction_type, amount) \
+                     VALUES ($1, $2, 'refund', $3)",
+                )
+                .bind(donation.user_id)
+                .bind(donation.campaign_id)
+                .bind(donation.amount)
+                .execute(&mut *tx)
+                .await?;
+                enqueue_event(
+                    &mut tx,
+                    &DomainEvent::DonationRefunded {
+                        donation_id: donation.id,
+                        user_id: donation.user_id,
+                        campaign_id: donation.campaign_id,
+                        amount: donation.amount,
+                    },
+                )
+                .await?;
+            }
+
+            let batch_total: f64 = refunded.iter().map(|donation| donation.amount).sum();
+            sqlx::query(
+                "UPDATE campaigns SET collected_amount = GREATEST(collected_amount - $2, 0) WHERE id = $1",
+            )
+            .bind(campaign_id)
+            .bind(batch_total)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            Ok(refunded)
+        })
+        .await?;
+
+        self.cache.invalidate_campaign(campaign_id);
+        for donation in &refunded {
+            self.cache.invalidate_user_campaign(donation.user_id, campaign_id);
+        }
+        Ok(refunded)
+    }
+
+    async fn find_private_notes(&self, campaign_id: i32) -> Result<Vec<DonationPrivateNote>, AppError> {
+        let notes = sqlx::query_as::<_, DonationPrivateNote>(
+            "SELECT id AS donation_id, user_id, amount, private_note, created_at FROM donations \
+             WHERE campaign_id = $1 AND private_note IS NOT NULL AND status IN ('settled', 'imported') \
+             ORDER BY created_at DESC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(notes)
+    }
+
+    async fn donor_counts(&self, campaign_id: i32) -> Result<CampaignDonorCounts, AppError> {
+        let counts = sqlx::query_as::<_, CampaignDonorCounts>(
+            "SELECT COUNT(DISTINCT user_id) AS unique_donors, \
+             (SELECT COUNT(*) FROM ( \
+                  SELECT user_id FROM all_donations \
+                  WHERE campaign_id = $1 AND status IN ('settled', 'imported') \
+                  GROUP BY user_id HAVING COUNT(*) > 1 \
+             ) repeat) AS repeat_donors, \
+             PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY amount) AS median_donation \
+             FROM all_donations WHERE campaign_id = $1 AND status IN ('settled', 'imported')",
+        )
+        .bind(campaign_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(counts)
+    }
+
+    // Empty buckets are left out; `bounds` must be ascending.
+    async fn size_histogram(&self, campaign_id: i32, bounds: Vec<f64>) -> Result<Vec<DonationSizeCount>, AppError> {
+        let buckets = sqlx::query_as::<_, DonationSizeCount>(
+            "SELECT WIDTH_BUCKET(amount, $2::FLOAT8[]) AS bucket, COUNT(*) AS donation_count, \
+             SUM(amount)::FLOAT8 AS total_amount \
+             FROM all_donations WHERE campaign_id = $1 AND status IN ('settled', 'imported') \
+             GROUP BY bucket ORDER BY bucket",
+        )
+        .bind(campaign_id)
+        .bind(bounds)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(buckets)
+    }
+
+    // Codes matching a short-link slug pick up that link's channel; unknown codes are still
+    // reported as given. Donations without a code are grouped under a NULL referral_code.
+    async fn referral_totals(&self, campaign_id: Option<i32>) -> Result<Vec<ReferralTotal>, AppError> {
+        let totals = sqlx::query_as::<_, ReferralTotal>(
+            "SELECT d.referral_code, MIN(s.channel) AS channel, COUNT(*) AS donation_count, \
+             COUNT(DISTINCT d.user_id) AS donor_count, SUM(d.amount)::FLOAT8 AS total_amount \
+             FROM all_donations d LEFT JOIN short_links s ON s.slug = d.referral_code \
+             WHERE ($1::INT IS NULL OR d.campaign_id = $1) AND d.status IN ('settled', 'imported') \
+             GROUP BY d.referral_code ORDER BY total_amount DESC, d.referral_code NULLS LAST",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(totals)
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    fn imported_row(user_id: i32, campaign_id: i32, amount: f64) -> ImportedDonationRow {
+        ImportedDonationRow {
+            user_id,
+            campaign_id,
+            amount,
+            message: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_import_batch_counts_towards_totals() {
+        let db = test_db().await;
+        let repo = PgDonationRepository::new(db.pool.clone());
+
+        let inserted = repo
+            .import_batch(vec![imported_row(1, 10, 50.0), imported_row(2, 10, 25.0)])
+            .await
+            .unwrap();
+
+        assert_eq!(inserted, 2);
+        assert_eq!(repo.campaign_total(10).await.unwrap(), 75.0);
+        assert_eq!(repo.user_campaign_total(1, 10).await.unwrap(), 50.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_concurrent_target_crossing_donations_complete_campaign_once() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000), (2, 1000);
+             INSERT INTO campaigns (id, target_amount, collected_amount) VALUES (10, 100, 50);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = Arc::new(PgDonationRepository::new(db.pool.clone()));
+
+        let donate = |user_id: i32| {
+            let repo = repo.clone();
+            tokio::spawn(async move {
+                let req = NewDonationRequest {
+                    campaign_id: 10,
+                    amount: 60.0,
+                    message: None,
+                    private_note: None,
+                    tier_id: None,
+                    referral_code: None,
+                    honoree_name: None,
+                    honoree_email: None,
+                    anonymous: false,
+                };
+                repo.create(user_id, &req).await
+            })
+        };
+        let (first, second) = tokio::join!(donate(1), donate(2));
+        let results = [first.unwrap(), second.unwrap()];
+
+        // The first donation completes the campaign; the second finds it no longer active.
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        let (collected, status): (f64, String) =
+            sqlx::query_as("SELECT collected_amount, status FROM campaigns WHERE id = 10")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(collected, 110.0);
+        assert_eq!(status, "completed");
+        let completions: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM outbox WHERE event_type = 'campaign_completed' AND payload->>'campaign_id' = '10'",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(completions, 1);
+        let created: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM outbox WHERE event_type = 'donation_created'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(created, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_overflow_policies_cap_or_extend_the_target() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000);
+             INSERT INTO campaigns (id, target_amount, collected_amount, overflow_policy) VALUES
+                 (10, 100, 50, 'cap'),
+                 (11, 100, 50, 'extend');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        let req = |campaign_id: i32| NewDonationRequest {
+            campaign_id,
+            amount: 80.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+
+        let capped = repo.create(1, &req(10)).await.unwrap();
+        assert_eq!(capped.amount, 50.0);
+        assert_eq!(capped.refunded_excess, Some(30.0));
+        let extended = repo.create(1, &req(11)).await.unwrap();
+        assert_eq!(extended.amount, 80.0);
+        assert_eq!(extended.refunded_excess, None);
+
+        let campaigns: Vec<(f64, f64, String)> =
+            sqlx::query_as("SELECT target_amount, collected_amount, status FROM campaigns ORDER BY id")
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(campaigns[0], (100.0, 100.0, "completed".to_string()));
+        assert_eq!(campaigns[1], (130.0, 130.0, "active".to_string()));
+        let balance: f64 = sqlx::query_scalar("SELECT balance FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(balance, 870.0);
+        // The ledger records what each campaign accepted, not the requested amount.
+        let ledger: Vec<(Option<i32>, f64)> = sqlx::query_as(
+            "SELECT campaign_id, amount FROM transactions WHERE transaction_type = 'donation' ORDER BY id",
+        )
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(ledger, vec![(Some(10), 50.0), (Some(11), 80.0)]);
+        assert!(repo.create(1, &req(10)).await.is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_basket_is_all_or_nothing() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000);
+             INSERT INTO campaigns (id, target_amount, title, status) VALUES
+                 (10, 500, 'Clean water', 'active'),
+                 (11, 500, 'School books', 'active'),
+                 (12, 500, 'Under review', 'suspended');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        let item = |campaign_id: i32, amount: f64| BasketItem { campaign_id, amount };
+
+        let rejected = repo
+            .create_basket(1, vec![item(10, 100.0), item(12, 100.0)], None)
+            .await;
+        assert!(rejected.is_err());
+        let (donations,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM donations")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(donations, 0);
+
+        let (donations, receipt) = repo
+            .create_basket(1, vec![item(11, 250.0), item(10, 100.0)], Some("For both".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(donations.len(), 2);
+        assert_eq!(receipt.total_amount, 350.0);
+        let titles: Vec<&str> = receipt.lines.iter().map(|line| line.campaign_title.as_str()).collect();
+        assert_eq!(titles, vec!["Clean water", "School books"]);
+        assert!(receipt.lines.iter().all(|line| line.issued_at == receipt.issued_at));
+
+        let balance: f64 = sqlx::query_scalar("SELECT balance FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(balance, 650.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_donation_to_suspended_campaign_is_rejected() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000);
+             INSERT INTO campaigns (id, target_amount, status) VALUES (10, 100, 'suspended');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        let req = NewDonationRequest {
+            campaign_id: 10,
+            amount: 60.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+
+        match repo.create(1, &req).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("suspended")),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+        let balance: f64 = sqlx::query_scalar("SELECT balance FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(balance, 1000.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_by_user_includes_archived_donations() {
+        let db = test_db().await;
+        let repo = PgDonationRepository::new(db.pool.clone());
+        repo.import_batch(vec![
+            imported_row(1, 10, 10.0),
+            imported_row(1, 11, 20.0),
+            imported_row(2, 10, 30.0),
+        ])
+        .await
+        .unwrap();
+        let archived: i32 = sqlx::query_scalar(
+            "WITH moved AS (DELETE FROM donations WHERE user_id = 1 AND campaign_id = 11 RETURNING *) \
+             INSERT INTO donations_archive (id, user_id, campaign_id, amount, status, created_at) \
+             SELECT id, user_id, campaign_id, amount, status, created_at FROM moved RETURNING id",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+
+        let mut amounts: Vec<f64> = repo.find_by_user(1).await.unwrap().iter().map(|d| d.amount).collect();
+        amounts.sort_by(f64::total_cmp);
+        assert_eq!(amounts, vec![10.0, 20.0]);
+        assert!(repo.find_by_user(3).await.unwrap().is_empty());
+
+        let campaign = repo.find_by_campaign(10).await.unwrap();
+        assert_eq!(campaign.len(), 2);
+        let donation = repo.find_by_id(campaign[0].id).await.unwrap().unwrap();
+        assert_eq!(donation, campaign[0]);
+        assert!(repo.find_by_id(archived).await.unwrap().is_none());
+
+        assert_eq!(repo.update_message(donation.id, 99, Some("hi".to_string())).await.unwrap(), 0);
+        assert_eq!(repo.update_message(donation.id, donation.user_id, Some("hi".to_string())).await.unwrap(), 1);
+        assert_eq!(repo.find_by_id(donation.id).await.unwrap().unwrap().message.as_deref(), Some("hi"));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_recent_with_donor_count() {
+        let db = test_db().await;
+        let repo = PgDonationRepository::new(db.pool.clone());
+        repo.import_batch(vec![
+            imported_row(1, 10, 10.0),
+            imported_row(1, 10, 20.0),
+            imported_row(2, 10, 30.0),
+            imported_row(3, 11, 40.0),
+        ])
+        .await
+        .unwrap();
+
+        let (donor_count, recent) = repo.recent_with_donor_count(10, 2).await.unwrap();
+
+        assert_eq!(donor_count, 2);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(repo.recent_with_donor_count(99, 2).await.unwrap(), (0, vec![]));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_refund_batch_credits_wallets_and_records_refunds() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000), (2, 1000);
+             INSERT INTO campaigns (id, target_amount) VALUES (10, 10000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        for (user_id, amount) in [(1, 100.0), (2, 200.0), (1, 300.0)] {
+            let req = NewDonationRequest {
+                campaign_id: 10,
+                amount,
+                message: None,
+                private_note: None,
+                tier_id: None,
+                referral_code: None,
+                honoree_name: None,
+                honoree_email: None,
+                anonymous: false,
+            };
+            repo.create(user_id, &req).await.unwrap();
+        }
+        // Carried over from the old system by a donor with no wallet here yet.
+        sqlx::raw_sql(
+            "INSERT INTO donations (user_id, campaign_id, amount, status) VALUES (3, 10, 50, 'imported');
+             UPDATE campaigns SET collected_amount = collected_amount + 50 WHERE id = 10;",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        assert_eq!(repo.refund_batch(10, 2, true).await.unwrap().len(), 2);
+        assert_eq!(repo.refund_batch(10, 2, true).await.unwrap().len(), 2);
+        assert!(repo.refund_batch(10, 2, false).await.unwrap().is_empty());
+
+        let balances: Vec<f64> = sqlx::query_scalar("SELECT balance FROM wallets ORDER BY user_id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(balances, vec![1000.0, 1000.0, 50.0]);
+        assert_eq!(repo.campaign_total(10).await.unwrap(), 0.0);
+        let collected: f64 = sqlx::query_scalar("SELECT collected_amount FROM campaigns WHERE id = 10")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(collected, 0.0);
+        let refunds: f64 = sqlx::query_scalar("SELECT SUM(amount) FROM transactions WHERE transaction_type = 'refund'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(refunds, 650.0);
+        let refunded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM outbox WHERE event_type = 'donation_refunded'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(refunded, 4);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_concurrent_settlements_get_gap_free_receipt_numbers() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) SELECT id, 100 FROM generate_series(1, 6) id;
+             INSERT INTO wallets (user_id, balance) VALUES (7, 0);
+             INSERT INTO campaigns (id, title, target_amount) VALUES (10, 'Sumur desa', 10000);
+             INSERT INTO donations (id, user_id, campaign_id, amount, status) VALUES (100, 1, 10, 50, 'pending_review');
+             UPDATE wallets SET held_amount = 50 WHERE user_id = 1;",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = Arc::new(PgDonationRepository::new(db.pool.clone()).with_receipts(ReceiptConfig {
+            prefix: "KWT".to_string(),
+            fiscal_year_start_month: 1,
+        }));
+
+        let donate = |user_id: i32| {
+            let repo = repo.clone();
+            tokio::spawn(async move {
+                let req = NewDonationRequest {
+                    campaign_id: 10,
+                    amount: 10.0,
+                    message: None,
+                    private_note: None,
+                    tier_id: None,
+                    referral_code: None,
+                    honoree_name: None,
+                    honoree_email: None,
+                    anonymous: false,
+                };
+                repo.create(user_id, &req).await
+            })
+        };
+        let handles: Vec<_> = (1..=7).map(donate).collect();
+        let mut numbers = Vec::new();
+        for handle in handles {
+            // User 7 has no balance; the rolled-back attempt must not use up a number.
+            if let Ok(donation) = handle.await.unwrap() {
+                numbers.push(donation.receipt_number.unwrap());
+            }
+        }
+        numbers.sort();
+
+        let year = Utc::now().format("%Y").to_string();
+        let expected: Vec<String> = (1..=6).map(|n| format!("KWT/{}/{:06}", year, n)).collect();
+        assert_eq!(numbers, expected);
+
+        let reviewed = repo.approve_pending(100).await.unwrap().unwrap();
+        assert_eq!(reviewed.receipt_number, Some(format!("KWT/{}/000007", year)));
+
+        let receipt = repo.find_receipt(100).await.unwrap().unwrap();
+        assert_eq!(receipt.receipt_number, format!("KWT/{}/000007", year));
+        assert_eq!(receipt.campaign_title, "Sumur desa");
+        assert_eq!(receipt.amount, 50.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_settle_intent_claims_and_settles_together() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance, held_amount) VALUES (1, 1000, 300);
+             INSERT INTO campaigns (id, target_amount) VALUES (10, 10000);
+             INSERT INTO campaigns (id, target_amount, status) VALUES (11, 10000, 'completed');
+             INSERT INTO donation_intents (id, user_id, campaign_id, amount, token_hash, expires_at) VALUES
+                 (1, 1, 10, 100, 'live', NOW() + INTERVAL '15 minutes'),
+                 (2, 1, 11, 200, 'closed', NOW() + INTERVAL '15 minutes');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+
+        assert!(repo.settle_intent(1, 1, "wrong").await.unwrap().is_none());
+        let donation = repo.settle_intent(1, 1, "live").await.unwrap().unwrap();
+        assert_eq!(donation.amount, 100.0);
+        assert!(donation.receipt_number.is_some());
+        assert!(repo.settle_intent(1, 1, "live").await.unwrap().is_none());
+        // A campaign that stopped accepting donations rolls the claim back too.
+        assert!(repo.settle_intent(2, 1, "closed").await.is_err());
+
+        let intents: Vec<(String, Option<i32>)> =
+            sqlx::query_as("SELECT status::TEXT, donation_id FROM donation_intents ORDER BY id")
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(intents, vec![("confirmed".to_string(), Some(donation.id)), ("pending".to_string(), None)]);
+        let wallet: (f64, f64) = sqlx::query_as("SELECT balance, held_amount FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(wallet, (900.0, 200.0));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_review_settles_or_releases_the_hold_with_the_status() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance, held_amount) VALUES (1, 1000, 700);
+             INSERT INTO campaigns (id, target_amount, overflow_policy) VALUES (10, 400, 'cap');
+             INSERT INTO donations (id, user_id, campaign_id, amount, status) VALUES
+                 (100, 1, 10, 500, 'pending_review'),
+                 (101, 1, 10, 200, 'pending_review');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+
+        // Approval credits the campaign like a direct donation, capped at its target.
+        let approved = repo.approve_pending(100).await.unwrap().unwrap();
+        assert_eq!(approved.status, DonationStatus::Settled);
+        assert_eq!(approved.amount, 400.0);
+        assert_eq!(approved.refunded_excess, Some(100.0));
+        assert!(approved.receipt_number.is_some());
+        let (collected, status): (f64, String) =
+            sqlx::query_as("SELECT collected_amount, status::TEXT FROM campaigns WHERE id = 10")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!((collected, status.as_str()), (400.0, "completed"));
+        let created: String = sqlx::query_scalar("SELECT payload->>'amount' FROM outbox WHERE event_type = 'donation_created'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(created, "400.0");
+        let rejected = repo.close_pending(101, DonationStatus::Rejected).await.unwrap().unwrap();
+        assert_eq!(rejected.status, DonationStatus::Rejected);
+        assert!(repo.approve_pending(101).await.unwrap().is_none());
+
+        let wallet: (f64, f64) = sqlx::query_as("SELECT balance, held_amount FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(wallet, (600.0, 0.0));
+        let ledger: Vec<(i32, f64)> = sqlx::query_as(
+            "SELECT user_id, amount FROM transactions WHERE transaction_type = 'donation'",
+        )
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(ledger, vec![(1, 400.0)]);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_create_pending_review_holds_funds_with_the_insert() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000);
+             INSERT INTO campaigns (id, target_amount) VALUES (10, 5000);
+             INSERT INTO campaigns (id, target_amount, status) VALUES (11, 5000, 'suspended');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        let req = |campaign_id, amount| NewDonationRequest {
+            campaign_id,
+            amount,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+
+        let pending = repo.create_pending_review(1, &req(10, 600.0)).await.unwrap().unwrap();
+        assert_eq!(pending.status, DonationStatus::PendingReview);
+        // Only 400 is left unheld.
+        assert!(repo.create_pending_review(1, &req(10, 500.0)).await.unwrap().is_none());
+        match repo.create_pending_review(1, &req(11, 100.0)).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("suspended")),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        let held: f64 = sqlx::query_scalar("SELECT held_amount FROM wallets WHERE user_id = 1")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(held, 600.0);
+        let donations: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM donations")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(donations, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_private_note_is_stored_but_never_serialized() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000);
+             INSERT INTO campaigns (id, target_amount) VALUES (10, 10000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        let req = NewDonationRequest {
+            campaign_id: 10,
+            amount: 100.0,
+            message: Some("Semangat!".to_string()),
+            private_note: Some("For Budi's surgery".to_string()),
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+        let donation = repo.create(1, &req).await.unwrap();
+
+        let body = rocket::serde::json::to_string(&donation).unwrap();
+        assert!(body.contains("Semangat!"));
+        assert!(!body.contains("private_note"));
+
+        let notes = repo.find_private_notes(10).await.unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].donation_id, donation.id);
+        assert_eq!(notes[0].private_note, "For Budi's surgery");
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_donor_counts_and_size_histogram() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO donations (user_id, campaign_id, amount, status) VALUES
+                 (1, 10, 10, 'settled'), (1, 10, 30, 'settled'), (2, 10, 200, 'imported'),
+                 (3, 10, 999, 'rejected'), (4, 11, 50, 'settled');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+
+        let counts = repo.donor_counts(10).await.unwrap();
+        assert_eq!(counts.unique_donors, 2);
+        assert_eq!(counts.repeat_donors, 1);
+        assert_eq!(counts.median_donation, Some(30.0));
+
+        let buckets = repo.size_histogram(10, vec![20.0, 100.0]).await.unwrap();
+        let buckets: Vec<(i32, i64, f64)> = buckets
+            .into_iter()
+            .map(|b| (b.bucket, b.donation_count, b.total_amount))
+            .collect();
+        assert_eq!(buckets, vec![(0, 1, 10.0), (1, 1, 30.0), (2, 1, 200.0)]);
+
+        let empty = repo.donor_counts(99).await.unwrap();
+        assert_eq!(empty.unique_donors, 0);
+        assert_eq!(empty.median_donation, None);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_referral_totals_attribute_short_link_channels() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO short_links (slug, campaign_id, channel, created_by) VALUES ('poster1', 10, 'poster', 1);
+             INSERT INTO donations (user_id, campaign_id, amount, referral_code, status) VALUES
+                 (1, 10, 100, 'poster1', 'settled'), (2, 10, 50, 'poster1', 'settled'),
+                 (3, 10, 70, 'budi', 'settled'), (4, 10, 10, NULL, 'imported'),
+                 (5, 10, 999, 'poster1', 'pending_review'), (6, 11, 500, 'poster1', 'settled');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+
+        let totals = repo.referral_totals(Some(10)).await.unwrap();
+        let summary: Vec<(Option<&str>, Option<&str>, i64, f64)> = totals
+            .iter()
+            .map(|t| (t.referral_code.as_deref(), t.channel.as_deref(), t.donation_count, t.total_amount))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("poster1"), Some("poster"), 2, 150.0),
+                (Some("budi"), None, 1, 70.0),
+                (None, None, 1, 10.0),
+            ]
+        );
+
+        let platform = repo.referral_totals(None).await.unwrap();
+        assert_eq!(platform[0].referral_code.as_deref(), Some("poster1"));
+        assert_eq!(platform[0].total_amount, 650.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_public_by_campaign_joins_profiles() {
+        let db = test_db().await;
+        sqlx::query("INSERT INTO profiles (user_id, display_name, avatar_url) VALUES (1, 'Budi', 'https://cdn.example.org/budi.png')")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgDonationRepository::new(db.pool.clone());
+        repo.import_batch(vec![
+            imported_row(1, 10, 10.0),
+            imported_row(2, 10, 20.0),
+            imported_row(1, 11, 30.0),
+        ])
+        .await
+        .unwrap();
+        sqlx::query("UPDATE donations SET honoree_name = 'Sari', honoree_email = 'sari@example.com' WHERE user_id = 2")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let mut donations = repo.find_public_by_campaign(10).await.unwrap();
+        donations.sort_by_key(|donation| donation.user_id);
+        assert_eq!(donations.len(), 2);
+        assert_eq!(donations[0].display_name.as_deref(), Some("Budi"));
+        assert_eq!(donations[0].avatar_url.as_deref(), Some("https://cdn.example.org/budi.png"));
+        assert_eq!(donations[1].display_name, None);
+        assert_eq!(donations[1].in_honor_of.as_deref(), Some("Sari"));
+
+        sqlx::query("UPDATE donations SET is_anonymous = TRUE WHERE user_id = 1")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let anonymous = repo.find_public_by_campaign(10).await.unwrap().into_iter().find(|donation| donation.anonymous).unwrap();
+        assert_eq!(anonymous.user_id, None);
+        assert_eq!(anonymous.display_name, None);
+        assert_eq!(anonymous.avatar_url, None);
+    }
+}
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::donation_tier::{DonationTier, NewDonationTierRequest, RewardClaim};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DonationTierRepository: Send + Sync {
+    async fn create(&self, campaign_id: i32, new_tier: &NewDonationTierRequest) -> Result<DonationTier, AppError>;
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<DonationTier>, AppError>;
+    async fn find_claims(&self, campaign_id: i32) -> Result<Vec<RewardClaim>, AppError>;
+}
+
+pub struct PgDonationTierRepository {
+    pool: PgPool,
+}
+
+impl PgDonationTierRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgDonationTierRepository { pool }
+    }
+}
+
+// Tiers are claimed inside the donation transaction; see PgDonationRepository.
+#[async_trait]
+impl DonationTierRepository for PgDonationTierRepository {
+    async fn create(&self, campaign_id: i32, new_tier: &NewDonationTierRequest) -> Result<DonationTier, AppError> {
+        let tier = sqlx::query_as::<_, DonationTier>(
+            "INSERT INTO donation_tiers (campaign_id, title, description, min_amount, quantity, remaining) \
+             VALUES ($1, $2, $3, $4, $5, $5) RETURNING *",
+        )
+        .bind(campaign_id)
+        .bind(&new_tier.title)
+        .bind(&new_tier.description)
+        .bind(new_tier.min_amount)
+        .bind(new_tier.quantity)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(tier)
+    }
+
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<DonationTier>, AppError> {
+        let tiers = sqlx::query_as::<_, DonationTier>(
+            "SELECT * FROM donation_tiers WHERE campaign_id = $1 ORDER BY min_amount, id",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(tiers)
+    }
+
+    async fn find_claims(&self, campaign_id: i32) -> Result<Vec<RewardClaim>, AppError> {
+        let claims = sqlx::query_as::<_, RewardClaim>(
+            "SELECT c.donation_id, c.tier_id, t.title AS tier_title, d.user_id, d.amount, c.claimed_at \
+             FROM reward_claims c \
+             JOIN donation_tiers t ON t.id = c.tier_id \
+             JOIN donations d ON d.id = c.donation_id \
+             WHERE t.campaign_id = $1 AND d.status = 'settled' \
+             ORDER BY c.claimed_at, c.donation_id",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::NewDonationRequest;
+    use crate::repository::donation_repo::{DonationRepository, PgDonationRepository};
+    use crate::test_support::test_db;
+    use crate::errors::AppError;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_limited_tier_is_claimed_until_sold_out() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 1000), (2, 1000);
+             INSERT INTO campaigns (id, target_amount) VALUES (10, 100000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let tier_repo = PgDonationTierRepository::new(db.pool.clone());
+        let donation_repo = PgDonationRepository::new(db.pool.clone());
+        let tier = tier_repo
+            .create(10, &NewDonationTierRequest {
+                title: "Kaos".to_string(),
+                description: None,
+                min_amount: 100.0,
+                quantity: Some(1),
+            })
+            .await
+            .unwrap();
+        assert_eq!(tier.remaining, Some(1));
+
+        let donate = |amount: f64| NewDonationRequest {
+            campaign_id: 10,
+            amount,
+            message: None,
+            private_note: None,
+            tier_id: Some(tier.id),
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+        match donation_repo.create(1, &donate(50.0)).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("minimum")),
+            _ => panic!("Expected ValidationError"),
+        }
+        let donation = donation_repo.create(1, &donate(150.0)).await.unwrap();
+        match donation_repo.create(2, &donate(150.0)).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("sold out")),
+            _ => panic!("Expected ValidationError"),
+        }
+
+        // Failed claims roll back the whole donation, wallet debit included.
+        let balance: f64 = sqlx::query_scalar("SELECT balance FROM wallets WHERE user_id = 2")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(balance, 1000.0);
+
+        let claims = tier_repo.find_claims(10).await.unwrap();
+        assert_eq!(claims.len(), 1);
+        assert_eq!(claims[0].donation_id, donation.id);
+        assert_eq!(claims[0].tier_title, "Kaos");
+        assert_eq!(tier_repo.find_by_campaign(10).await.unwrap()[0].remaining, Some(0));
+    }
+}
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::evidence::{EvidenceFile, EvidenceMetadata};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait EvidenceRepository: Send + Sync {
+    async fn create(&self, campaign_id: i32, uploaded_by: i32, file_name: &str, size_bytes: i64) -> Result<EvidenceFile, AppError>;
+    async fn mark_completed(&self, evidence_id: i32, metadata: EvidenceMetadata, object_key: &str) -> Result<(), AppError>;
+    async fn mark_failed(&self, evidence_id: i32, error: String) -> Result<(), AppError>;
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<EvidenceFile>, AppError>;
+}
+
+pub struct PgEvidenceRepository {
+    pool: PgPool,
+}
+
+impl PgEvidenceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgEvidenceRepository { pool }
+    }
+}
+
+#[async_trait]
+impl EvidenceRepository for PgEvidenceRepository {
+    async fn create(&self, campaign_id: i32, uploaded_by: i32, file_name: &str, size_bytes: i64) -> Result<EvidenceFile, AppError> {
+        let file = sqlx::query_as::<_, EvidenceFile>(
+            "INSERT INTO campaign_evidence (campaign_id, uploaded_by, file_name, size_bytes) VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(campaign_id)
+        .bind(uploaded_by)
+        .bind(file_name)
+        .bind(size_bytes)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(file)
+    }
+
+    async fn mark_completed(&self, evidence_id: i32, metadata: EvidenceMetadata, object_key: &str) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE campaign_evidence SET status = 'completed', content_type = $2, page_count = $3, \
+             exif_stripped = $4, object_key = $5, processed_at = NOW() WHERE id = $1",
+        )
+        .bind(evidence_id)
+        .bind(metadata.content_type)
+        .bind(metadata.page_count)
+        .bind(metadata.exif_stripped)
+        .bind(object_key)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, evidence_id: i32, error: String) -> Result<(), AppError> {
+        sqlx::query("UPDATE campaign_evidence SET status = 'failed', error = $2, processed_at = NOW() WHERE id = $1")
+            .bind(evidence_id)
+            .bind(error)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<EvidenceFile>, AppError> {
+        let files = sqlx::query_as::<_, EvidenceFile>("SELECT * FROM campaign_evidence WHERE campaign_id = $1 ORDER BY id")
+            .bind(campaign_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::evidence::EvidenceProcessingStatus;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_processing_status_is_recorded() {
+        let db = test_db().await;
+        let repo = PgEvidenceRepository::new(db.pool.clone());
+
+        let pdf = repo.create(10, 1, "permit.pdf", 2048).await.unwrap();
+        let scan = repo.create(10, 1, "scan.bmp", 512).await.unwrap();
+        assert_eq!(pdf.status, EvidenceProcessingStatus::Queued);
+
+        let metadata = EvidenceMetadata {
+            content_type: "application/pdf",
+            page_count: Some(3),
+            exif_stripped: false,
+        };
+        repo.mark_completed(pdf.id, metadata, "evidence/10/1").await.unwrap();
+        repo.mark_failed(scan.id, "Unsupported evidence file type".to_string()).await.unwrap();
+
+        let files = repo.find_by_campaign(10).await.unwrap();
+        assert_eq!(files[0].status, EvidenceProcessingStatus::Completed);
+        assert_eq!(files[0].page_count, Some(3));
+        assert_eq!(files[1].status, EvidenceProcessingStatus::Failed);
+        assert!(files[1].processed_at.is_some());
+    }
+}
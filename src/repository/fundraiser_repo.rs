@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::donation::Donation;
+use crate::model::fundraiser::FundraiserCampaignOverview;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait FundraiserRepository: Send + Sync {
+    async fn campaign_overview(&self, fundraiser_id: i32) -> Result<FundraiserCampaignOverview, AppError>;
+    async fn recent_donations(&self, fundraiser_id: i32, limit: i64) -> Result<Vec<Donation>, AppError>;
+}
+
+pub struct PgFundraiserRepository {
+    pool: PgPool,
+}
+
+impl PgFundraiserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgFundraiserRepository { pool }
+    }
+}
+
+#[async_trait]
+impl FundraiserRepository for PgFundraiserRepository {
+    async fn campaign_overview(&self, fundraiser_id: i32) -> Result<FundraiserCampaignOverview, AppError> {
+        let overview = sqlx::query_as::<_, FundraiserCampaignOverview>(
+            "SELECT COALESCE(SUM(collected_amount), 0)::FLOAT8 AS total_raised, \
+             COUNT(*) FILTER (WHERE status = 'active') AS active_campaigns \
+             FROM campaigns WHERE fundraiser_id = $1",
+        )
+        .bind(fundraiser_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(overview)
+    }
+
+    async fn recent_donations(&self, fundraiser_id: i32, limit: i64) -> Result<Vec<Donation>, AppError> {
+        let donations = sqlx::query_as::<_, Donation>(
+            "SELECT d.* FROM donations d JOIN campaigns c ON c.id = d.campaign_id \
+             WHERE c.fundraiser_id = $1 AND d.status IN ('settled', 'imported') \
+             ORDER BY d.created_at DESC LIMIT $2",
+        )
+        .bind(fundraiser_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+}
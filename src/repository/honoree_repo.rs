@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::donation::DonationGift;
+use crate::model::event::DomainEvent;
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait HonoreeRepository: Send + Sync {
+    /// The donation's honoree, if it was a gift with an address to greet.
+    async fn find_gift(&self, donation_id: i32) -> Result<Option<DonationGift>, AppError>;
+    async fn enqueue_greeting(&self, gift: &DonationGift) -> Result<(), AppError>;
+}
+
+pub struct PgHonoreeRepository {
+    pool: PgPool,
+}
+
+impl PgHonoreeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgHonoreeRepository { pool }
+    }
+}
+
+#[async_trait]
+impl HonoreeRepository for PgHonoreeRepository {
+    async fn find_gift(&self, donation_id: i32) -> Result<Option<DonationGift>, AppError> {
+        let gift = sqlx::query_as::<_, DonationGift>(
+            "SELECT id AS donation_id, user_id, campaign_id, message, honoree_name, honoree_email \
+             FROM donations WHERE id = $1 AND honoree_email IS NOT NULL",
+        )
+        .bind(donation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(gift)
+    }
+
+    // Goes through the outbox so the greeting is retried if delivery fails.
+    async fn enqueue_greeting(&self, gift: &DonationGift) -> Result<(), AppError> {
+        let mut conn = self.pool.acquire().await?;
+        enqueue_event(
+            &mut conn,
+            &DomainEvent::HonoreeGreeting {
+                donation_id: gift.donation_id,
+                donor_id: gift.user_id,
+                campaign_id: gift.campaign_id,
+                honoree_name: gift.honoree_name.clone(),
+                honoree_email: gift.honoree_email.clone(),
+                message: gift.message.clone(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_gift_and_enqueue_greeting() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO donations (id, user_id, campaign_id, amount, message, honoree_name, honoree_email) VALUES
+                 (1, 7, 10, 50, 'Happy birthday!', 'Sari', 'sari@example.com'),
+                 (2, 7, 10, 50, NULL, 'Sari', NULL);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgHonoreeRepository::new(db.pool.clone());
+
+        assert!(repo.find_gift(2).await.unwrap().is_none());
+        let gift = repo.find_gift(1).await.unwrap().unwrap();
+        assert_eq!(gift.honoree_email, "sari@example.com");
+
+        repo.enqueue_greeting(&gift).await.unwrap();
+        let event_type: String = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(event_type, "honoree_greeting");
+    }
+}
@@ -0,0 +1,288 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::event::DomainEvent;
+use crate::model::kyc::{KycDocument, KycDocumentType, KycStatus, KycSubject, KycSubmission};
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+fn subject_columns(subject: KycSubject) -> (Option<i32>, Option<i32>) {
+    match subject {
+        KycSubject::User(user_id) => (Some(user_id), None),
+        KycSubject::Organization(organization_id) => (None, Some(organization_id)),
+    }
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait KycRepository: Send + Sync {
+    async fn find_latest(&self, subject: KycSubject) -> Result<Option<KycSubmission>, AppError>;
+    async fn find_by_id(&self, submission_id: i32) -> Result<Option<KycSubmission>, AppError>;
+    async fn find_by_status(&self, status: KycStatus) -> Result<Vec<KycSubmission>, AppError>;
+    async fn is_approved(&self, subject: KycSubject) -> Result<bool, AppError>;
+    /// Returns the subject's draft, starting one if there is none. Returns None if a
+    /// submission is already waiting for review.
+    async fn open_draft(&self, subject: KycSubject, submitted_by: i32) -> Result<Option<KycSubmission>, AppError>;
+    /// Replaces any document of the same type on the submission.
+    async fn upsert_document(&self, submission_id: i32, document_type: KycDocumentType, file_name: &str, content_type: &str, size_bytes: i64, object_key: &str) -> Result<KycDocument, AppError>;
+    async fn find_documents(&self, submission_id: i32) -> Result<Vec<KycDocument>, AppError>;
+    /// Moves a draft into the review queue. Returns None if it isn't a draft.
+    async fn submit(&self, submission_id: i32) -> Result<Option<KycSubmission>, AppError>;
+    /// Approves or rejects a pending submission. Returns None if it isn't pending or
+    /// `admin_id` submitted it.
+    async fn review(&self, submission_id: i32, admin_id: i32, decision: KycStatus, note: Option<String>) -> Result<Option<KycSubmission>, AppError>;
+}
+
+pub struct PgKycRepository {
+    pool: PgPool,
+}
+
+impl PgKycRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgKycRepository { pool }
+    }
+}
+
+#[async_trait]
+impl KycRepository for PgKycRepository {
+    async fn find_latest(&self, subject: KycSubject) -> Result<Option<KycSubmission>, AppError> {
+        let (user_id, organization_id) = subject_columns(subject);
+        let submission = sqlx::query_as::<_, KycSubmission>(
+            "SELECT * FROM kyc_submissions \
+             WHERE user_id IS NOT DISTINCT FROM $1 AND organization_id IS NOT DISTINCT FROM $2 \
+             ORDER BY id DESC LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(submission)
+    }
+
+    async fn find_by_id(&self, submission_id: i32) -> Result<Option<KycSubmission>, AppError> {
+        let submission = sqlx::query_as::<_, KycSubmission>("SELECT * FROM kyc_submissions WHERE id = $1")
+            .bind(submission_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(submission)
+    }
+
+    async fn find_by_status(&self, status: KycStatus) -> Result<Vec<KycSubmission>, AppError> {
+        let submissions = sqlx::query_as::<_, KycSubmission>(
+            "SELECT * FROM kyc_submissions WHERE status = $1 ORDER BY submitted_at ASC NULLS LAST, id ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(submissions)
+    }
+
+    async fn is_approved(&self, subject: KycSubject) -> Result<bool, AppError> {
+        let (user_id, organization_id) = subject_columns(subject);
+        let approved = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (SELECT 1 FROM kyc_submissions \
+             WHERE user_id IS NOT DISTINCT FROM $1 AND organization_id IS NOT DISTINCT FROM $2 \
+             AND status = 'approved')",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(approved)
+    }
+
+    // The partial unique indexes allow one draft or pending submission per subject, so a
+    // racing upload lands on the same draft instead of starting a second one.
+    async fn open_draft(&self, subject: KycSubject, submitted_by: i32) -> Result<Option<KycSubmission>, AppError> {
+        let (user_id, organization_id) = subject_columns(subject);
+        sqlx::query(
+            "INSERT INTO kyc_submissions (user_id, organization_id, submitted_by) VALUES ($1, $2, $3) \
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .bind(submitted_by)
+        .execute(&self.pool)
+        .await?;
+
+        let draft = sqlx::query_as::<_, KycSubmission>(
+            "SELECT * FROM kyc_submissions \
+             WHERE user_id IS NOT DISTINCT FROM $1 AND organization_id IS NOT DISTINCT FROM $2 \
+             AND status = 'draft'",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(draft)
+    }
+
+    async fn upsert_document(&self, submission_id: i32, document_type: KycDocumentType, file_name: &str, content_type: &str, size_bytes: i64, object_key: &str) -> Result<KycDocument, AppError> {
+        let document = sqlx::query_as::<_, KycDocument>(
+            "INSERT INTO kyc_documents (submission_id, document_type, file_name, content_type, size_bytes, object_key) \
+             VALUES ($1, $2, $3, $4, $5, $6) \
+             ON CONFLICT (submission_id, document_type) DO UPDATE SET \
+             file_name = EXCLUDED.file_name, content_type = EXCLUDED.content_type, \
+             size_bytes = EXCLUDED.size_bytes, object_key = EXCLUDED.object_key, uploaded_at = NOW() \
+             RETURNING *",
+        )
+        .bind(submission_id)
+        .bind(document_type)
+        .bind(file_name)
+        .bind(content_type)
+        .bind(size_bytes)
+        .bind(object_key)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(document)
+    }
+
+    async fn find_documents(&self, submission_id: i32) -> Result<Vec<KycDocument>, AppError> {
+        let documents = sqlx::query_as::<_, KycDocument>(
+            "SELECT * FROM kyc_documents WHERE submission_id = $1 ORDER BY document_type ASC",
+        )
+        .bind(submission_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(documents)
+    }
+
+    // The notification goes through the outbox on the same transaction as the status change.
+    async fn submit(&self, submission_id: i32) -> Result<Option<KycSubmission>, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let submission = sqlx::query_as::<_, KycSubmission>(
+            "UPDATE kyc_submissions SET status = 'pending', submitted_at = NOW() \
+             WHERE id = $1 AND status = 'draft' RETURNING *",
+        )
+        .bind(submission_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(submission) = submission else {
+            return Ok(None);
+        };
+
+        let event = DomainEvent::KycSubmitted {
+            submission_id: submission.id,
+            user_id: submission.submitted_by,
+            organization_id: submission.organization_id,
+        };
+        enqueue_event(&mut tx, &event).await?;
+
+        tx.commit().await?;
+        Ok(Some(submission))
+    }
+
+    async fn review(&self, submission_id: i32, admin_id: i32, decision: KycStatus, note: Option<String>) -> Result<Option<KycSubmission>, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let submission = sqlx::query_as::<_, KycSubmission>(
+            "UPDATE kyc_submissions \
+             SET status = $3, reviewed_by = $2, review_note = $4, reviewed_at = NOW() \
+             WHERE id = $1 AND status = 'pending' AND submitted_by <> $2 \
+             RETURNING *",
+        )
+        .bind(submission_id)
+        .bind(admin_id)
+        .bind(decision)
+        .bind(&note)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(submission) = submission else {
+            return Ok(None);
+        };
+
+        let event = if decision == KycStatus::Approved {
+            if let Some(organization_id) = submission.organization_id {
+                sqlx::query(
+                    "UPDATE organizations SET verified_at = NOW(), verified_by = $2 \
+                     WHERE id = $1 AND verified_at IS NULL",
+                )
+                .bind(organization_id)
+                .bind(admin_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            DomainEvent::KycApproved {
+                submission_id: submission.id,
+                user_id: submission.submitted_by,
+                organization_id: submission.organization_id,
+            }
+        } else {
+            DomainEvent::KycRejected {
+                submission_id: submission.id,
+                user_id: submission.submitted_by,
+                organization_id: submission.organization_id,
+                reason: note.unwrap_or_default(),
+            }
+        };
+        enqueue_event(&mut tx, &event).await?;
+
+        tx.commit().await?;
+        Ok(Some(submission))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_submission_moves_through_review_and_notifies() {
+        let db = test_db().await;
+        let repo = PgKycRepository::new(db.pool.clone());
+        let subject = KycSubject::User(7);
+
+        let draft = repo.open_draft(subject, 7).await.unwrap().unwrap();
+        assert_eq!(repo.open_draft(subject, 7).await.unwrap().unwrap().id, draft.id);
+        repo.upsert_document(draft.id, KycDocumentType::IdCard, "ktp.jpg", "image/jpeg", 100, "kyc/1/id_card").await.unwrap();
+        let replaced = repo.upsert_document(draft.id, KycDocumentType::IdCard, "ktp-2.jpg", "image/jpeg", 120, "kyc/1/id_card").await.unwrap();
+        assert_eq!(replaced.file_name, "ktp-2.jpg");
+        assert_eq!(repo.find_documents(draft.id).await.unwrap().len(), 1);
+
+        let pending = repo.submit(draft.id).await.unwrap().unwrap();
+        assert_eq!(pending.status, KycStatus::Pending);
+        assert!(repo.submit(draft.id).await.unwrap().is_none());
+        assert!(repo.open_draft(subject, 7).await.unwrap().is_none());
+        assert_eq!(repo.find_by_status(KycStatus::Pending).await.unwrap().len(), 1);
+
+        assert!(repo.review(draft.id, 7, KycStatus::Approved, None).await.unwrap().is_none());
+        let approved = repo.review(draft.id, 99, KycStatus::Approved, None).await.unwrap().unwrap();
+        assert_eq!(approved.reviewed_by, Some(99));
+        assert!(repo.is_approved(subject).await.unwrap());
+        assert!(!repo.is_approved(KycSubject::User(8)).await.unwrap());
+
+        let events: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(events, vec!["kyc_submitted", "kyc_approved"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_approving_organization_submission_verifies_it() {
+        let db = test_db().await;
+        sqlx::raw_sql("INSERT INTO organizations (id, name, registration_number, created_by) VALUES (5, 'Yayasan', 'AHU-1', 2);")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgKycRepository::new(db.pool.clone());
+
+        let draft = repo.open_draft(KycSubject::Organization(5), 2).await.unwrap().unwrap();
+        repo.submit(draft.id).await.unwrap().unwrap();
+        repo.review(draft.id, 99, KycStatus::Approved, None).await.unwrap().unwrap();
+
+        let verified_by: Option<i32> = sqlx::query_scalar("SELECT verified_by FROM organizations WHERE id = 5")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(verified_by, Some(99));
+        assert_eq!(repo.find_latest(KycSubject::Organization(5)).await.unwrap().unwrap().id, draft.id);
+        assert!(repo.find_latest(KycSubject::User(2)).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::metrics::CampaignStatusAmount;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait MetricsRepository: Send + Sync {
+    async fn donation_amount_by_campaign_status(&self) -> Result<Vec<CampaignStatusAmount>, AppError>;
+    async fn wallet_balance_total(&self) -> Result<f64, AppError>;
+}
+
+pub struct PgMetricsRepository {
+    pool: PgPool,
+}
+
+impl PgMetricsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgMetricsRepository { pool }
+    }
+}
+
+#[async_trait]
+impl MetricsRepository for PgMetricsRepository {
+    async fn donation_amount_by_campaign_status(&self) -> Result<Vec<CampaignStatusAmount>, AppError> {
+        let rows = sqlx::query_as::<_, CampaignStatusAmount>(
+            "SELECT c.status::TEXT AS campaign_status, COALESCE(SUM(d.amount), 0)::FLOAT8 AS total_amount \
+             FROM campaigns c JOIN donations d ON d.campaign_id = c.id \
+             WHERE d.status IN ('settled', 'imported') \
+             GROUP BY c.status ORDER BY c.status",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    async fn wallet_balance_total(&self) -> Result<f64, AppError> {
+        let total = sqlx::query_scalar::<_, f64>("SELECT COALESCE(SUM(balance), 0)::FLOAT8 FROM wallets")
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_business_metric_queries() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 100), (2, 250.5);
+             INSERT INTO campaigns (id, target_amount, status) VALUES (10, 1000, 'active'), (11, 1000, 'completed');
+             INSERT INTO donations (user_id, campaign_id, amount, status) VALUES
+                (1, 10, 50, 'settled'),
+                (1, 10, 70, 'rejected'),
+                (2, 11, 30, 'settled');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgMetricsRepository::new(db.pool.clone());
+
+        let by_status = repo.donation_amount_by_campaign_status().await.unwrap();
+        assert_eq!(
+            by_status,
+            vec![
+                CampaignStatusAmount { campaign_status: "active".to_string(), total_amount: 50.0 },
+                CampaignStatusAmount { campaign_status: "completed".to_string(), total_amount: 30.0 },
+            ]
+        );
+        assert_eq!(repo.wallet_balance_total().await.unwrap(), 350.5);
+    }
+}
@@ -1 +1,48 @@
-pub mod donation_repo;
\ No newline at end of file
+pub mod admin_action_repo;
+pub mod api_key_repo;
+pub mod campaign_budget_repo;
+pub mod campaign_deletion_repo;
+pub mod campaign_feed_repo;
+pub mod campaign_image_repo;
+pub mod campaign_location_repo;
+pub mod campaign_member_repo;
+pub mod campaign_overflow_repo;
+pub mod campaign_ranking_repo;
+pub mod campaign_repo;
+pub mod campaign_review_repo;
+pub mod campaign_share_repo;
+pub mod campaign_suspension_repo;
+pub mod data_export_repo;
+pub mod dispute_repo;
+pub mod donation_archive_repo;
+pub mod donation_cache;
+pub mod donation_export_repo;
+pub mod donation_intent_repo;
+pub mod donation_repo;
+pub mod donation_tier_repo;
+pub mod evidence_repo;
+pub mod fundraiser_repo;
+pub mod honoree_repo;
+pub mod kyc_repo;
+pub mod metrics_repo;
+pub mod notification_delivery_repo;
+pub mod notification_preference_repo;
+pub mod organization_repo;
+pub mod outbox_repo;
+pub mod profile_repo;
+pub mod query_monitor;
+pub mod reconciliation_repo;
+pub mod retry;
+pub mod risk_repo;
+pub mod saved_search_repo;
+pub mod security_event_repo;
+pub mod seed_repo;
+pub mod short_link_repo;
+pub mod statistic_repo;
+pub mod statistics_snapshot_repo;
+pub mod tax_summary_repo;
+pub mod transaction_repo;
+pub mod two_factor_repo;
+pub mod user_import_repo;
+pub mod wallet_repo;
+pub mod withdrawal_repo;
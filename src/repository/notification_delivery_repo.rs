@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::model::notification_delivery::{NotificationDelivery, NotificationDeliveryStatus};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+const SELECT_DELIVERY: &str =
+    "SELECT d.*, o.payload FROM notification_delivery_attempts d JOIN outbox o ON o.id = d.outbox_id";
+
+// Per-subscriber retry state for outbox events whose subscriber failed.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait NotificationDeliveryRepository: Send + Sync {
+    /// Records the first failed attempt. If the outbox hands the same event over again,
+    /// the existing row and its schedule are kept.
+    async fn record_failure(&self, outbox_id: i64, event_type: &str, subscriber: &str, error: &str, retry_at: DateTime<Utc>) -> Result<(), AppError>;
+    /// Pending deliveries whose retry time has come, oldest first.
+    async fn find_due(&self, limit: i64) -> Result<Vec<NotificationDelivery>, AppError>;
+    async fn find_by_status(&self, status: NotificationDeliveryStatus, limit: i64) -> Result<Vec<NotificationDelivery>, AppError>;
+    async fn mark_delivered(&self, delivery_id: i64) -> Result<(), AppError>;
+    async fn reschedule(&self, delivery_id: i64, error: &str, retry_at: DateTime<Utc>) -> Result<(), AppError>;
+    async fn mark_dead(&self, delivery_id: i64, error: &str) -> Result<(), AppError>;
+    /// Puts a dead delivery back in the queue with a fresh attempt count.
+    /// None if it doesn't exist or isn't dead.
+    async fn requeue(&self, delivery_id: i64) -> Result<Option<NotificationDelivery>, AppError>;
+}
+
+pub struct PgNotificationDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PgNotificationDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgNotificationDeliveryRepository { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationDeliveryRepository for PgNotificationDeliveryRepository {
+    async fn record_failure(&self, outbox_id: i64, event_type: &str, subscriber: &str, error: &str, retry_at: DateTime<Utc>) -> Result<(), AppError> {
+        sqlx::query(
+            "INSERT INTO notification_delivery_attempts (outbox_id, event_type, subscriber, last_error, next_attempt_at) \
+             VALUES ($1, $2, $3, $4, $5) ON CONFLICT (outbox_id, subscriber) DO NOTHING",
+        )
+        .bind(outbox_id)
+        .bind(event_type)
+        .bind(subscriber)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_due(&self, limit: i64) -> Result<Vec<NotificationDelivery>, AppError> {
+        let deliveries = sqlx::query_as::<_, NotificationDelivery>(&format!(
+            "{} WHERE d.status = 'pending' AND d.next_attempt_at <= NOW() ORDER BY d.next_attempt_at ASC, d.id ASC LIMIT $1",
+            SELECT_DELIVERY
+        ))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(deliveries)
+    }
+
+    async fn find_by_status(&self, status: NotificationDeliveryStatus, limit: i64) -> Result<Vec<NotificationDelivery>, AppError> {
+        let deliveries = sqlx::query_as::<_, NotificationDelivery>(&format!(
+            "{} WHERE d.status = $1 ORDER BY d.updated_at DESC, d.id DESC LIMIT $2",
+            SELECT_DELIVERY
+        ))
+        .bind(status)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(deliveries)
+    }
+
+    async fn mark_delivered(&self, delivery_id: i64) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE notification_delivery_attempts SET status = 'delivered', attempts = attempts + 1, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(delivery_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn reschedule(&self, delivery_id: i64, error: &str, retry_at: DateTime<Utc>) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE notification_delivery_attempts \
+             SET attempts = attempts + 1, last_error = $2, next_attempt_at = $3, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(delivery_id)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_dead(&self, delivery_id: i64, error: &str) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE notification_delivery_attempts \
+             SET status = 'dead', attempts = attempts + 1, last_error = $2, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(delivery_id)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn requeue(&self, delivery_id: i64) -> Result<Option<NotificationDelivery>, AppError> {
+        let requeued = sqlx::query(
+            "UPDATE notification_delivery_attempts \
+             SET status = 'pending', attempts = 0, next_attempt_at = NOW(), updated_at = NOW() \
+             WHERE id = $1 AND status = 'dead'",
+        )
+        .bind(delivery_id)
+        .execute(&self.pool)
+        .await?;
+        if requeued.rows_affected() == 0 {
+            return Ok(None);
+        }
+        let delivery = sqlx::query_as::<_, NotificationDelivery>(&format!("{} WHERE d.id = $1", SELECT_DELIVERY))
+            .bind(delivery_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(delivery)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+    use chrono::Duration;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_failures_are_scheduled_dead_lettered_and_requeued() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO outbox (id, event_type, payload) VALUES \
+                 (1, 'campaign_completed', '{\"type\": \"campaign_completed\", \"campaign_id\": 10}');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgNotificationDeliveryRepository::new(db.pool.clone());
+
+        let past = Utc::now() - Duration::seconds(5);
+        repo.record_failure(1, "campaign_completed", "ops_alerter", "timeout", past).await.unwrap();
+        repo.record_failure(1, "campaign_completed", "metrics", "down", Utc::now() + Duration::hours(1)).await.unwrap();
+        // A second hand-over of the same event keeps the original schedule.
+        repo.record_failure(1, "campaign_completed", "ops_alerter", "again", Utc::now() + Duration::hours(1)).await.unwrap();
+
+        let due = repo.find_due(10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].subscriber, "ops_alerter");
+        assert_eq!(due[0].last_error.as_deref(), Some("timeout"));
+        assert_eq!(due[0].payload["campaign_id"], 10);
+
+        repo.mark_dead(due[0].id, "timeout").await.unwrap();
+        assert!(repo.find_due(10).await.unwrap().is_empty());
+        let dead = repo.find_by_status(NotificationDeliveryStatus::Dead, 10).await.unwrap();
+        assert_eq!(dead[0].attempts, 2);
+
+        let requeued = repo.requeue(dead[0].id).await.unwrap().unwrap();
+        assert_eq!(requeued.status, NotificationDeliveryStatus::Pending);
+        assert_eq!(requeued.attempts, 0);
+        assert!(repo.requeue(dead[0].id).await.unwrap().is_none());
+        assert_eq!(repo.find_due(10).await.unwrap().len(), 1);
+    }
+}
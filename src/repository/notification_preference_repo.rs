@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::model::event::DomainEvent;
+use crate::model::notification_preference::{NotificationCount, NotificationPreferences};
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    async fn find(&self, user_id: i32) -> Result<Option<NotificationPreferences>, AppError>;
+    async fn upsert(&self, user_id: i32, low_balance_threshold: Option<f64>, daily_digest: bool) -> Result<NotificationPreferences, AppError>;
+    async fn enqueue_low_balance_alert(&self, user_id: i32, available_balance: f64, threshold: f64) -> Result<(), AppError>;
+    /// Digest users whose last digest went out at or before `since`.
+    async fn find_due_digests(&self, since: DateTime<Utc>) -> Result<Vec<i32>, AppError>;
+    /// Counts the user's notifications of `event_types` since their last digest (or
+    /// `since` for a first one), enqueues the digest if there were any and records it
+    /// as sent. None if the user is no longer due, e.g. another run got there first.
+    async fn send_digest(&self, user_id: i32, event_types: Vec<String>, since: DateTime<Utc>) -> Result<Option<Vec<NotificationCount>>, AppError>;
+}
+
+pub struct PgNotificationPreferenceRepository {
+    pool: PgPool,
+}
+
+impl PgNotificationPreferenceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgNotificationPreferenceRepository { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for PgNotificationPreferenceRepository {
+    async fn find(&self, user_id: i32) -> Result<Option<NotificationPreferences>, AppError> {
+        let preferences = sqlx::query_as::<_, NotificationPreferences>("SELECT * FROM notification_preferences WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(preferences)
+    }
+
+    async fn upsert(&self, user_id: i32, low_balance_threshold: Option<f64>, daily_digest: bool) -> Result<NotificationPreferences, AppError> {
+        let preferences = sqlx::query_as::<_, NotificationPreferences>(
+            "INSERT INTO notification_preferences (user_id, low_balance_threshold, daily_digest) VALUES ($1, $2, $3) \
+             ON CONFLICT (user_id) DO UPDATE SET low_balance_threshold = EXCLUDED.low_balance_threshold, \
+                 daily_digest = EXCLUDED.daily_digest, updated_at = NOW() \
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(low_balance_threshold)
+        .bind(daily_digest)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(preferences)
+    }
+
+    // Goes through the outbox so the notification is retried if delivery fails.
+    async fn enqueue_low_balance_alert(&self, user_id: i32, available_balance: f64, threshold: f64) -> Result<(), AppError> {
+        let mut conn = self.pool.acquire().await?;
+        enqueue_event(
+            &mut conn,
+            &DomainEvent::WalletBalanceLow {
+                user_id,
+                available_balance,
+                threshold,
+            },
+        )
+        .await
+    }
+
+    async fn find_due_digests(&self, since: DateTime<Utc>) -> Result<Vec<i32>, AppError> {
+        let user_ids = sqlx::query_scalar(
+            "SELECT user_id FROM notification_preferences \
+             WHERE daily_digest AND (last_digest_at IS NULL OR last_digest_at <= $1) ORDER BY user_id",
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(user_ids)
+    }
+
+    async fn send_digest(&self, user_id: i32, event_types: Vec<String>, since: DateTime<Utc>) -> Result<Option<Vec<NotificationCount>>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        // The row lock keeps two runs from sending the same digest twice.
+        let last_digest_at: Option<Option<DateTime<Utc>>> = sqlx::query_scalar(
+            "SELECT last_digest_at FROM notification_preferences \
+             WHERE user_id = $1 AND daily_digest AND (last_digest_at IS NULL OR last_digest_at <= $2) \
+             FOR UPDATE",
+        )
+        .bind(user_id)
+        .bind(since)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(last_digest_at) = last_digest_at else {
+            return Ok(None);
+        };
+        let since = last_digest_at.unwrap_or(since);
+
+        let counts = sqlx::query_as::<_, NotificationCount>(
+            "SELECT event_type, COUNT(*) AS count FROM outbox \
+             WHERE (payload ->> 'user_id')::INT = $1 AND event_type = ANY($2) AND created_at > $3 \
+             GROUP BY event_type ORDER BY event_type",
+        )
+        .bind(user_id)
+        .bind(&event_types)
+        .bind(since)
+        .fetch_all(&mut *tx)
+        .await?;
+        if !counts.is_empty() {
+            enqueue_event(
+                &mut tx,
+                &DomainEvent::NotificationDigest {
+                    user_id,
+                    since,
+                    counts: counts.clone(),
+                },
+            )
+            .await?;
+        }
+        sqlx::query("UPDATE notification_preferences SET last_digest_at = NOW() WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(Some(counts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_upsert_and_enqueue_alert() {
+        let db = test_db().await;
+        let repo = PgNotificationPreferenceRepository::new(db.pool.clone());
+
+        assert!(repo.find(1).await.unwrap().is_none());
+        repo.upsert(1, Some(50_000.0), false).await.unwrap();
+        let preferences = repo.upsert(1, Some(10_000.0), false).await.unwrap();
+        assert_eq!(preferences.low_balance_threshold, Some(10_000.0));
+        assert_eq!(repo.find(1).await.unwrap(), Some(preferences));
+
+        repo.enqueue_low_balance_alert(1, 5_000.0, 10_000.0).await.unwrap();
+        let event_type: String = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(event_type, "wallet_balance_low");
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_send_digest_counts_notifications_once() {
+        let db = test_db().await;
+        let repo = PgNotificationPreferenceRepository::new(db.pool.clone());
+        repo.upsert(1, None, true).await.unwrap();
+        repo.upsert(2, None, false).await.unwrap();
+        repo.enqueue_low_balance_alert(1, 5_000.0, 10_000.0).await.unwrap();
+        repo.enqueue_low_balance_alert(1, 1_000.0, 10_000.0).await.unwrap();
+        repo.enqueue_low_balance_alert(2, 1_000.0, 10_000.0).await.unwrap();
+
+        let since = Utc::now() - chrono::Duration::hours(24);
+        assert_eq!(repo.find_due_digests(since).await.unwrap(), vec![1]);
+        let types = vec!["wallet_balance_low".to_string()];
+        let counts = repo.send_digest(1, types.clone(), since).await.unwrap().unwrap();
+        assert_eq!(counts, vec![NotificationCount { event_type: "wallet_balance_low".to_string(), count: 2 }]);
+
+        // Sent, so the user is not due again for another day.
+        assert!(repo.find_due_digests(since).await.unwrap().is_empty());
+        assert!(repo.send_digest(1, types.clone(), since).await.unwrap().is_none());
+        assert!(repo.send_digest(2, types, since).await.unwrap().is_none());
+        let digests: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM outbox WHERE event_type = 'notification_digest'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(digests, 1);
+    }
+}
@@ -0,0 +1,228 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use crate::model::organization::{NewOrganizationRequest, Organization, OrganizationMember, OrganizationRole, OrganizationWallet};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+// The shared wallet follows `wallets`: held funds stay in `balance` but are excluded
+// from the available amount while a payout is under review.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// Creates the organization with an empty wallet and `created_by` as its admin.
+    /// Returns None if the registration number is already taken.
+    async fn create(&self, created_by: i32, new_organization: &NewOrganizationRequest) -> Result<Option<Organization>, AppError>;
+    async fn find_by_id(&self, organization_id: i32) -> Result<Option<Organization>, AppError>;
+    /// Returns None if the organization is already verified.
+    async fn verify(&self, organization_id: i32, admin_id: i32) -> Result<Option<Organization>, AppError>;
+    async fn find_role(&self, organization_id: i32, user_id: i32) -> Result<Option<OrganizationRole>, AppError>;
+    async fn find_members(&self, organization_id: i32) -> Result<Vec<OrganizationMember>, AppError>;
+    /// Returns None instead of demoting the organization's last admin.
+    async fn upsert_member(&self, organization_id: i32, user_id: i32, role: OrganizationRole, added_by: i32) -> Result<Option<OrganizationMember>, AppError>;
+    /// Leaves the organization's last admin in place.
+    async fn remove_member(&self, organization_id: i32, user_id: i32) -> Result<u64, AppError>;
+    async fn assign_campaign(&self, campaign_id: i32, organization_id: i32) -> Result<bool, AppError>;
+    async fn find_wallet(&self, organization_id: i32) -> Result<Option<OrganizationWallet>, AppError>;
+}
+
+pub struct PgOrganizationRepository {
+    pool: PgPool,
+}
+
+impl PgOrganizationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgOrganizationRepository { pool }
+    }
+}
+
+// Member changes lock the organization row first, so two admins demoting or removing
+// each other at the same time can't leave it without an admin.
+async fn lock_organization(conn: &mut PgConnection, organization_id: i32) -> Result<(), AppError> {
+    sqlx::query("SELECT id FROM organizations WHERE id = $1 FOR UPDATE")
+        .bind(organization_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+#[async_trait]
+impl OrganizationRepository for PgOrganizationRepository {
+    async fn create(&self, created_by: i32, new_organization: &NewOrganizationRequest) -> Result<Option<Organization>, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let organization = sqlx::query_as::<_, Organization>(
+            "INSERT INTO organizations (name, registration_number, description, website, created_by) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (registration_number) DO NOTHING RETURNING *",
+        )
+        .bind(&new_organization.name)
+        .bind(&new_organization.registration_number)
+        .bind(&new_organization.description)
+        .bind(&new_organization.website)
+        .bind(created_by)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(organization) = organization else {
+            return Ok(None);
+        };
+
+        sqlx::query("INSERT INTO organization_wallets (organization_id) VALUES ($1)")
+            .bind(organization.id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO organization_members (organization_id, user_id, role, added_by) \
+             VALUES ($1, $2, 'admin', $2)",
+        )
+        .bind(organization.id)
+        .bind(created_by)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(organization))
+    }
+
+    async fn find_by_id(&self, organization_id: i32) -> Result<Option<Organization>, AppError> {
+        let organization = sqlx::query_as::<_, Organization>("SELECT * FROM organizations WHERE id = $1")
+            .bind(organization_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(organization)
+    }
+
+    async fn verify(&self, organization_id: i32, admin_id: i32) -> Result<Option<Organization>, AppError> {
+        let organization = sqlx::query_as::<_, Organization>(
+            "UPDATE organizations SET verified_at = NOW(), verified_by = $2 \
+             WHERE id = $1 AND verified_at IS NULL RETURNING *",
+        )
+        .bind(organization_id)
+        .bind(admin_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(organization)
+    }
+
+    async fn find_role(&self, organization_id: i32, user_id: i32) -> Result<Option<OrganizationRole>, AppError> {
+        let role = sqlx::query_scalar::<_, OrganizationRole>(
+            "SELECT role FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(role)
+    }
+
+    async fn find_members(&self, organization_id: i32) -> Result<Vec<OrganizationMember>, AppError> {
+        let members = sqlx::query_as::<_, OrganizationMember>(
+            "SELECT * FROM organization_members WHERE organization_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(members)
+    }
+
+    async fn upsert_member(&self, organization_id: i32, user_id: i32, role: OrganizationRole, added_by: i32) -> Result<Option<OrganizationMember>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_organization(&mut tx, organization_id).await?;
+
+        let member = sqlx::query_as::<_, OrganizationMember>(
+            "INSERT INTO organization_members (organization_id, user_id, role, added_by) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (organization_id, user_id) DO UPDATE SET role = EXCLUDED.role \
+             WHERE EXCLUDED.role = 'admin' OR EXISTS ( \
+                 SELECT 1 FROM organization_members other \
+                 WHERE other.organization_id = $1 AND other.user_id <> $2 AND other.role = 'admin' \
+             ) \
+             RETURNING *",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(role)
+        .bind(added_by)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(member)
+    }
+
+    async fn remove_member(&self, organization_id: i32, user_id: i32) -> Result<u64, AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_organization(&mut tx, organization_id).await?;
+
+        let result = sqlx::query(
+            "DELETE FROM organization_members \
+             WHERE organization_id = $1 AND user_id = $2 AND (role <> 'admin' OR EXISTS ( \
+                 SELECT 1 FROM organization_members other \
+                 WHERE other.organization_id = $1 AND other.user_id <> $2 AND other.role = 'admin' \
+             ))",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn assign_campaign(&self, campaign_id: i32, organization_id: i32) -> Result<bool, AppError> {
+        let result = sqlx::query("UPDATE campaigns SET organization_id = $2 WHERE id = $1")
+            .bind(campaign_id)
+            .bind(organization_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_wallet(&self, organization_id: i32) -> Result<Option<OrganizationWallet>, AppError> {
+        let wallet = sqlx::query_as::<_, OrganizationWallet>(
+            "SELECT * FROM organization_wallets WHERE organization_id = $1",
+        )
+        .bind(organization_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(wallet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    fn request(registration_number: &str) -> NewOrganizationRequest {
+        NewOrganizationRequest {
+            name: "Yayasan Peduli".to_string(),
+            registration_number: registration_number.to_string(),
+            description: None,
+            website: None,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_last_admin_cannot_be_removed_or_demoted() {
+        let db = test_db().await;
+        let repo = PgOrganizationRepository::new(db.pool.clone());
+
+        let organization = repo.create(1, &request("AHU-0001")).await.unwrap().unwrap();
+        assert!(repo.create(2, &request("AHU-0001")).await.unwrap().is_none());
+        assert_eq!(repo.find_role(organization.id, 1).await.unwrap(), Some(OrganizationRole::Admin));
+        assert_eq!(repo.find_wallet(organization.id).await.unwrap().unwrap().balance, 0.0);
+
+        assert!(repo.upsert_member(organization.id, 1, OrganizationRole::Member, 1).await.unwrap().is_none());
+        assert_eq!(repo.remove_member(organization.id, 1).await.unwrap(), 0);
+
+        repo.upsert_member(organization.id, 2, OrganizationRole::Admin, 1).await.unwrap().unwrap();
+        let demoted = repo.upsert_member(organization.id, 1, OrganizationRole::Treasurer, 2).await.unwrap().unwrap();
+        assert_eq!(demoted.role, OrganizationRole::Treasurer);
+        assert_eq!(repo.remove_member(organization.id, 2).await.unwrap(), 0);
+        assert_eq!(repo.remove_member(organization.id, 1).await.unwrap(), 1);
+        assert_eq!(repo.find_members(organization.id).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rocket::serde::json::Value;
+use sqlx::{PgConnection, PgPool};
+use crate::model::outbox::OutboxEvent;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Called with the caller's open transaction so the event commits or rolls back
+// together with the state change that produced it.
+pub async fn enqueue(conn: &mut PgConnection, event_type: &str, payload: &Value) -> Result<(), AppError> {
+    sqlx::query("INSERT INTO outbox (event_type, payload) VALUES ($1, $2)")
+        .bind(event_type)
+        .bind(payload)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claims up to `limit` due pending events by pushing their `next_attempt_at` out by
+    /// `lease`. Rows another dispatcher is claiming are skipped, and an event whose
+    /// dispatcher dies is picked up again once the lease runs out.
+    async fn claim_due(&self, limit: i64, lease: Duration) -> Result<Vec<OutboxEvent>, AppError>;
+    async fn mark_dispatched(&self, event_id: i64) -> Result<(), AppError>;
+    /// Counts the failed attempt. The event is retried at `retry_at`, or dead-lettered
+    /// once it has used `max_attempts`.
+    async fn mark_failed(&self, event_id: i64, error: String, retry_at: DateTime<Utc>, max_attempts: i32) -> Result<(), AppError>;
+}
+
+pub struct PgOutboxRepository {
+    pool: PgPool,
+}
+
+impl PgOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgOutboxRepository { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PgOutboxRepository {
+    async fn claim_due(&self, limit: i64, lease: Duration) -> Result<Vec<OutboxEvent>, AppError> {
+        let mut events = sqlx::query_as::<_, OutboxEvent>(
+            "UPDATE outbox SET next_attempt_at = NOW() + $2 WHERE id IN ( \
+                 SELECT id FROM outbox WHERE status = 'pending' AND next_attempt_at <= NOW() \
+                 ORDER BY id LIMIT $1 FOR UPDATE SKIP LOCKED \
+             ) RETURNING *",
+        )
+        .bind(limit)
+        .bind(lease)
+        .fetch_all(&self.pool)
+        .await?;
+        events.sort_by_key(|event| event.id);
+        Ok(events)
+    }
+
+    async fn mark_dispatched(&self, event_id: i64) -> Result<(), AppError> {
+        sqlx::query("UPDATE outbox SET status = 'dispatched', dispatched_at = NOW(), attempts = attempts + 1 WHERE id = $1")
+            .bind(event_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, event_id: i64, error: String, retry_at: DateTime<Utc>, max_attempts: i32) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE outbox SET attempts = attempts + 1, last_error = $2, next_attempt_at = $3, \
+             status = CASE WHEN attempts + 1 >= $4 THEN 'dead'::outbox_status ELSE 'pending'::outbox_status END \
+             WHERE id = $1",
+        )
+        .bind(event_id)
+        .bind(error)
+        .bind(retry_at)
+        .bind(max_attempts)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::outbox::OutboxStatus;
+    use crate::test_support::test_db;
+    use rocket::serde::json::json;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_claimed_events_are_skipped_until_retried_or_dead_lettered() {
+        let db = test_db().await;
+        let repo = PgOutboxRepository::new(db.pool.clone());
+        let mut conn = db.pool.acquire().await.unwrap();
+        for campaign_id in [1, 2] {
+            enqueue(&mut conn, "campaign_approved", &json!({ "campaign_id": campaign_id })).await.unwrap();
+        }
+
+        let claimed = repo.claim_due(10, Duration::minutes(10)).await.unwrap();
+        assert_eq!(claimed.iter().map(|event| event.id).collect::<Vec<_>>(), vec![1, 2]);
+        // Still leased to the first claim.
+        assert!(repo.claim_due(10, Duration::minutes(10)).await.unwrap().is_empty());
+
+        repo.mark_dispatched(1).await.unwrap();
+        repo.mark_failed(2, "SMTP unavailable".to_string(), Utc::now() - Duration::seconds(1), 2).await.unwrap();
+        let retried = repo.claim_due(10, Duration::minutes(10)).await.unwrap();
+        assert_eq!(retried.len(), 1);
+        assert_eq!((retried[0].id, retried[0].attempts), (2, 1));
+
+        repo.mark_failed(2, "SMTP unavailable".to_string(), Utc::now() - Duration::seconds(1), 2).await.unwrap();
+        assert!(repo.claim_due(10, Duration::minutes(10)).await.unwrap().is_empty());
+        let statuses: Vec<(i64, OutboxStatus)> = sqlx::query_as("SELECT id, status FROM outbox ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(statuses, vec![(1, OutboxStatus::Dispatched), (2, OutboxStatus::Dead)]);
+    }
+}
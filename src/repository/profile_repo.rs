@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::profile::{Profile, UpdateProfileRequest};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ProfileRepository: Send + Sync {
+    async fn find(&self, user_id: i32) -> Result<Option<Profile>, AppError>;
+    async fn upsert(&self, user_id: i32, profile: &UpdateProfileRequest) -> Result<Profile, AppError>;
+}
+
+pub struct PgProfileRepository {
+    pool: PgPool,
+}
+
+impl PgProfileRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgProfileRepository { pool }
+    }
+}
+
+#[async_trait]
+impl ProfileRepository for PgProfileRepository {
+    async fn find(&self, user_id: i32) -> Result<Option<Profile>, AppError> {
+        let profile = sqlx::query_as::<_, Profile>("SELECT * FROM profiles WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(profile)
+    }
+
+    async fn upsert(&self, user_id: i32, profile: &UpdateProfileRequest) -> Result<Profile, AppError> {
+        let profile = sqlx::query_as::<_, Profile>(
+            "INSERT INTO profiles (user_id, display_name, avatar_url, bio) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (user_id) DO UPDATE SET display_name = EXCLUDED.display_name, \
+             avatar_url = EXCLUDED.avatar_url, bio = EXCLUDED.bio, updated_at = NOW() \
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(&profile.display_name)
+        .bind(&profile.avatar_url)
+        .bind(&profile.bio)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_upsert_creates_then_replaces_profile() {
+        let db = test_db().await;
+        let repo = PgProfileRepository::new(db.pool.clone());
+        assert!(repo.find(1).await.unwrap().is_none());
+
+        let req = UpdateProfileRequest {
+            display_name: "Budi".to_string(),
+            avatar_url: Some("https://cdn.example.org/budi.png".to_string()),
+            bio: Some("Suka berbagi".to_string()),
+        };
+        repo.upsert(1, &req).await.unwrap();
+        let req = UpdateProfileRequest {
+            display_name: "Budi S.".to_string(),
+            avatar_url: None,
+            bio: None,
+        };
+        let updated = repo.upsert(1, &req).await.unwrap();
+
+        assert_eq!(repo.find(1).await.unwrap(), Some(updated.clone()));
+        assert_eq!(updated.display_name, "Budi S.");
+        assert_eq!(updated.avatar_url, None);
+    }
+}
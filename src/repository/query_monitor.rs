@@ -0,0 +1,316 @@
+use crate::errors::AppError;
+use crate::model::query_diagnostics::{QueryDiagnostics, SlowQuery};
+use chrono::Utc;
+use dashmap::DashMap;
+use sqlx::postgres::PgArguments;
+use sqlx::{Execute, PgPool, Postgres, QueryBuilder};
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(250);
+
+// Distinct statements kept for the diagnostics endpoint; the fastest is dropped first.
+const MAX_TRACKED_STATEMENTS: usize = 50;
+
+/// Replaces every quoted string literal with `'?'`, so values inlined into SQL or
+/// echoed back in a query plan don't end up in logs.
+fn redact_literals(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            out.push(c);
+            continue;
+        }
+        out.push_str("'?'");
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                // `''` is an escaped quote inside the literal.
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+    out
+}
+
+fn redact_sql(sql: &str) -> String {
+    redact_literals(sql)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn count_placeholders(sql: &str) -> usize {
+    let mut highest = 0;
+    for (index, _) in sql.match_indices('$') {
+        let digits: String = sql[index + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        highest = highest.max(digits.parse().unwrap_or(0));
+    }
+    highest
+}
+
+/// Splits a built query into its SQL and bound arguments so it can be run through
+/// `QueryMonitor::observe`.
+pub fn into_parts(
+    query: &mut QueryBuilder<'_, Postgres>,
+) -> Result<(String, PgArguments), AppError> {
+    let args = query
+        .build()
+        .take_arguments()
+        .map_err(sqlx::Error::Encode)?
+        .unwrap_or_default();
+    Ok((query.sql().to_string(), args))
+}
+
+// The parameters are bound again, inside a transaction that is always rolled back,
+// so plans of writes can be captured without applying them twice.
+async fn explain(pool: &PgPool, sql: &str, args: PgArguments) -> Result<String, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let explain_sql = format!("EXPLAIN (ANALYZE, BUFFERS) {}", sql);
+    let lines: Vec<String> = sqlx::query_scalar_with(&explain_sql, args)
+        .fetch_all(&mut *tx)
+        .await?;
+    tx.rollback().await?;
+    Ok(lines.join("\n"))
+}
+
+/// Times queries run through `observe`, logs and counts the ones over the threshold,
+/// and keeps the slowest statements for `GET /admin/diagnostics/slow-queries`.
+pub struct QueryMonitor {
+    threshold: Duration,
+    plan_pool: Option<PgPool>,
+    slow_queries_total: AtomicU64,
+    statements: Arc<DashMap<String, SlowQuery>>,
+}
+
+impl Default for QueryMonitor {
+    fn default() -> Self {
+        QueryMonitor::new(DEFAULT_SLOW_QUERY_THRESHOLD)
+    }
+}
+
+impl QueryMonitor {
+    pub fn new(threshold: Duration) -> Self {
+        QueryMonitor {
+            threshold,
+            plan_pool: None,
+            slow_queries_total: AtomicU64::new(0),
+            statements: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Enables `EXPLAIN ANALYZE` capture for a statement whenever it has its slowest run
+    /// so far. Meant for debugging only; see `database.explain_slow_queries`.
+    pub fn with_plan_capture(mut self, pool: PgPool) -> Self {
+        self.plan_pool = Some(pool);
+        self
+    }
+
+    pub async fn observe<T, F, Fut>(
+        &self,
+        sql: &str,
+        args: PgArguments,
+        run: F,
+    ) -> Result<T, AppError>
+    where
+        F: FnOnce(PgArguments) -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let plan_args = self.plan_pool.as_ref().map(|_| args.clone());
+        let started = Instant::now();
+        let result = run(args).await;
+        let elapsed = started.elapsed();
+
+        if elapsed >= self.threshold && self.record_slow(sql, elapsed)
+            && let (Some(pool), Some(args)) = (&self.plan_pool, plan_args) {
+                self.capture_plan(pool.clone(), sql, args);
+            }
+        Ok(result?)
+    }
+
+    /// Returns whether this was the statement's slowest run so far.
+    fn record_slow(&self, sql: &str, elapsed: Duration) -> bool {
+        self.slow_queries_total.fetch_add(1, Ordering::Relaxed);
+        let statement = redact_sql(sql);
+        let elapsed_ms = elapsed.as_millis() as u64;
+        tracing::warn!(
+            elapsed_ms,
+            statement = statement.as_str(),
+            "Slow query took {} ms ({} bound parameters redacted): {}",
+            elapsed_ms,
+            count_placeholders(sql),
+            statement
+        );
+
+        let slowest = {
+            let mut entry = self
+                .statements
+                .entry(statement.clone())
+                .or_insert_with(|| SlowQuery {
+                    sql: statement,
+                    count: 0,
+                    total_ms: 0,
+                    max_ms: 0,
+                    last_seen: Utc::now(),
+                    plan: None,
+                });
+            entry.count += 1;
+            entry.total_ms += elapsed_ms;
+            entry.last_seen = Utc::now();
+            let slowest = entry.max_ms == 0 || elapsed_ms > entry.max_ms;
+            entry.max_ms = entry.max_ms.max(elapsed_ms);
+            slowest
+        };
+        self.evict_fastest();
+        slowest
+    }
+
+    fn evict_fastest(&self) {
+        while self.statements.len() > MAX_TRACKED_STATEMENTS {
+            let fastest = self
+                .statements
+                .iter()
+                .min_by_key(|entry| entry.max_ms)
+                .map(|entry| entry.key().clone());
+            match fastest {
+                Some(key) => self.statements.remove(&key),
+                None => break,
+            };
+        }
+    }
+
+    // Runs in the background so the caller isn't held up by a second execution.
+    fn capture_plan(&self, pool: PgPool, sql: &str, args: PgArguments) {
+        let statements = self.statements.clone();
+        let sql = sql.to_string();
+        rocket::tokio::spawn(async move {
+            match explain(&pool, &sql, args).await {
+                Ok(plan) => {
+                    if let Some(mut entry) = statements.get_mut(&redact_sql(&sql)) {
+                        entry.plan = Some(redact_literals(&plan));
+                    }
+                }
+                Err(e) => tracing::warn!(error = %e, "Could not capture query plan"),
+            }
+        });
+    }
+
+    pub fn diagnostics(&self) -> QueryDiagnostics {
+        let mut slow_queries: Vec<SlowQuery> = self
+            .statements
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect();
+        slow_queries.sort_by_key(|query| std::cmp::Reverse(query.max_ms));
+        QueryDiagnostics {
+            threshold_ms: self.threshold.as_millis() as u64,
+            plan_capture_enabled: self.plan_pool.is_some(),
+            slow_queries_total: self.slow_queries_total.load(Ordering::Relaxed),
+            slow_queries,
+        }
+    }
+
+    /// Forgets the tracked statements; the metrics counter keeps counting.
+    pub fn clear(&self) {
+        self.statements.clear();
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        format!(
+            "# HELP db_slow_queries_total Queries that took longer than the slow-query threshold\n\
+             # TYPE db_slow_queries_total counter\n\
+             db_slow_queries_total {}\n",
+            self.slow_queries_total.load(Ordering::Relaxed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[test]
+    fn test_redacts_literals_and_counts_placeholders() {
+        let sql = "SELECT *\n  FROM users WHERE email = 'a''b@example.com' AND id = $1 OR id = $12";
+
+        assert_eq!(
+            redact_sql(sql),
+            "SELECT * FROM users WHERE email = '?' AND id = $1 OR id = $12"
+        );
+        assert_eq!(count_placeholders(sql), 12);
+    }
+
+    #[tokio::test]
+    async fn test_only_slow_queries_are_counted() {
+        let monitor = QueryMonitor::new(Duration::from_millis(20));
+
+        monitor
+            .observe("SELECT 1", PgArguments::default(), |_| async { Ok(()) })
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            monitor
+                .observe("SELECT  pg_sleep($1)", PgArguments::default(), |_| async {
+                    rocket::tokio::time::sleep(Duration::from_millis(25)).await;
+                    Ok(())
+                })
+                .await
+                .unwrap();
+        }
+
+        let diagnostics = monitor.diagnostics();
+        assert_eq!(diagnostics.slow_queries_total, 2);
+        assert_eq!(diagnostics.slow_queries.len(), 1);
+        assert_eq!(diagnostics.slow_queries[0].sql, "SELECT pg_sleep($1)");
+        assert_eq!(diagnostics.slow_queries[0].count, 2);
+        assert!(
+            monitor
+                .to_prometheus()
+                .contains("db_slow_queries_total 2\n")
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_captures_plan_without_applying_writes() {
+        let db = test_db().await;
+        let monitor =
+            QueryMonitor::new(Duration::from_millis(1)).with_plan_capture(db.pool.clone());
+        let sql = "INSERT INTO transactions (transaction_type, user_id, amount) \
+                   SELECT 'donation', $1, 1000 FROM pg_sleep(0.01)";
+        let mut args = PgArguments::default();
+        sqlx::Arguments::add(&mut args, 7).unwrap();
+
+        monitor
+            .observe(sql, args, |args| {
+                sqlx::query_with(sql, args).execute(&db.pool)
+            })
+            .await
+            .unwrap();
+
+        let mut plan = None;
+        for _ in 0..50 {
+            plan = monitor.diagnostics().slow_queries[0].plan.clone();
+            if plan.is_some() {
+                break;
+            }
+            rocket::tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(plan.unwrap().contains("Insert on transactions"));
+        let (rows,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM transactions")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(rows, 1);
+    }
+}
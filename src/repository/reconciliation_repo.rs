@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::reconciliation::{Discrepancy, NewReconciliationRun, ReconciliationReport, ReconciliationRun};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ReconciliationRepository: Send + Sync {
+    async fn save(&self, run: NewReconciliationRun) -> Result<ReconciliationReport, AppError>;
+    async fn find_by_id(&self, run_id: i32) -> Result<Option<ReconciliationReport>, AppError>;
+    async fn find_recent(&self, limit: i64) -> Result<Vec<ReconciliationRun>, AppError>;
+}
+
+pub struct PgReconciliationRepository {
+    pool: PgPool,
+}
+
+impl PgReconciliationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgReconciliationRepository { pool }
+    }
+}
+
+#[async_trait]
+impl ReconciliationRepository for PgReconciliationRepository {
+    async fn save(&self, run: NewReconciliationRun) -> Result<ReconciliationReport, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let saved = sqlx::query_as::<_, ReconciliationRun>(
+            "INSERT INTO reconciliation_runs \
+             (provider, period_from, period_to, settlement_rows, matched, discrepancy_count, created_by) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+        )
+        .bind(&run.provider)
+        .bind(run.period_from)
+        .bind(run.period_to)
+        .bind(run.settlement_rows)
+        .bind(run.matched)
+        .bind(run.discrepancies.len() as i32)
+        .bind(run.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for discrepancy in &run.discrepancies {
+            sqlx::query(
+                "INSERT INTO reconciliation_discrepancies \
+                 (run_id, kind, order_id, ledger_amount, settlement_amount) VALUES ($1, $2, $3, $4, $5)",
+            )
+            .bind(saved.id)
+            .bind(discrepancy.kind)
+            .bind(discrepancy.order_id)
+            .bind(discrepancy.ledger_amount)
+            .bind(discrepancy.settlement_amount)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(ReconciliationReport {
+            run: saved,
+            discrepancies: run.discrepancies,
+        })
+    }
+
+    async fn find_by_id(&self, run_id: i32) -> Result<Option<ReconciliationReport>, AppError> {
+        let Some(run) = sqlx::query_as::<_, ReconciliationRun>("SELECT * FROM reconciliation_runs WHERE id = $1")
+            .bind(run_id)
+            .fetch_optional(&self.pool)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let discrepancies = sqlx::query_as::<_, Discrepancy>(
+            "SELECT kind, order_id, ledger_amount, settlement_amount FROM reconciliation_discrepancies \
+             WHERE run_id = $1 ORDER BY id ASC",
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(Some(ReconciliationReport { run, discrepancies }))
+    }
+
+    async fn find_recent(&self, limit: i64) -> Result<Vec<ReconciliationRun>, AppError> {
+        let runs = sqlx::query_as::<_, ReconciliationRun>(
+            "SELECT * FROM reconciliation_runs ORDER BY created_at DESC, id DESC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::reconciliation::DiscrepancyKind;
+    use crate::test_support::test_db;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_save_and_load_report() {
+        let db = test_db().await;
+        let repo = PgReconciliationRepository::new(db.pool.clone());
+
+        let now = Utc::now();
+        let saved = repo
+            .save(NewReconciliationRun {
+                provider: "gopay".to_string(),
+                period_from: now - Duration::days(1),
+                period_to: now,
+                settlement_rows: 3,
+                matched: 1,
+                created_by: 9,
+                discrepancies: vec![
+                    Discrepancy {
+                        kind: DiscrepancyKind::AmountMismatch,
+                        order_id: 4,
+                        ledger_amount: Some(10_000.0),
+                        settlement_amount: Some(9_000.0),
+                    },
+                    Discrepancy {
+                        kind: DiscrepancyKind::MissingInLedger,
+                        order_id: 7,
+                        ledger_amount: None,
+                        settlement_amount: Some(5_000.0),
+                    },
+                ],
+            })
+            .await
+            .unwrap();
+        assert_eq!(saved.run.discrepancy_count, 2);
+
+        let loaded = repo.find_by_id(saved.run.id).await.unwrap().unwrap();
+        assert_eq!(loaded, saved);
+        assert_eq!(repo.find_recent(10).await.unwrap(), vec![saved.run.clone()]);
+        assert!(repo.find_by_id(saved.run.id + 1).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::errors::AppError;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SERIALIZATION_FAILURE: &str = "40001";
+const DEADLOCK_DETECTED: &str = "40P01";
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(20),
+            max_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // Exponential backoff with "full jitter": a random delay in [0, base * 2^attempt].
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .base_delay
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_delay);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.subsec_nanos())
+            .unwrap_or(0);
+        ceiling.mul_f64(nanos as f64 / 1_000_000_000.0)
+    }
+}
+
+pub fn is_transient(error: &AppError) -> bool {
+    match error {
+        AppError::DatabaseError(sqlx::Error::Database(db_error)) => matches!(
+            db_error.code().as_deref(),
+            Some(SERIALIZATION_FAILURE) | Some(DEADLOCK_DETECTED)
+        ),
+        _ => false,
+    }
+}
+
+/// Runs `op` again when Postgres aborts it with a serialization failure or deadlock.
+/// `op` must open and commit its own transaction so every attempt starts clean.
+pub async fn with_retry<T, F, Fut>(policy: &RetryPolicy, mut op: F) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e) if attempt < policy.max_attempts && is_transient(&e) => {
+                rocket::tokio::time::sleep(policy.delay_for(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::borrow::Cow;
+    use std::error::Error as StdError;
+    use std::fmt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug)]
+    struct PgCodeError(&'static str);
+
+    impl fmt::Display for PgCodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "postgres error {}", self.0)
+        }
+    }
+
+    impl StdError for PgCodeError {}
+
+    impl DatabaseError for PgCodeError {
+        fn message(&self) -> &str {
+            "postgres error"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+
+        fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    fn pg_error(code: &'static str) -> AppError {
+        AppError::DatabaseError(sqlx::Error::Database(Box::new(PgCodeError(code))))
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&pg_error("40001")));
+        assert!(is_transient(&pg_error("40P01")));
+        assert!(!is_transient(&pg_error("23505")));
+        assert!(!is_transient(&AppError::NotFound("Donation".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_recovers_from_deadlock() {
+        let calls = AtomicU32::new(0);
+        let result = with_retry(&fast_policy(), || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(pg_error("40P01"))
+            } else {
+                Ok(7)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_gives_up_after_max_attempts() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), AppError> = with_retry(&fast_policy(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(pg_error("40001"))
+        })
+        .await;
+
+        assert!(is_transient(&result.unwrap_err()));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_does_not_retry_other_errors() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), AppError> = with_retry(&fast_policy(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(pg_error("23505"))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::model::risk::{
+    BlacklistEntry, FlaggedActivity, NewBlacklistEntryRequest, NewRiskRuleRequest, RiskAction,
+    RiskActivity, RiskRule, UpdateRiskRuleRequest,
+};
+use crate::errors::AppError;
+use crate::model::event::DomainEvent;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RiskRepository: Send + Sync {
+    async fn find_rules(&self) -> Result<Vec<RiskRule>, AppError>;
+    async fn find_enabled_rules(&self) -> Result<Vec<RiskRule>, AppError>;
+    async fn create_rule(&self, new_rule: &NewRiskRuleRequest) -> Result<RiskRule, AppError>;
+    async fn update_rule(&self, rule_id: i32, update: &UpdateRiskRuleRequest) -> Result<Option<RiskRule>, AppError>;
+    async fn delete_rule(&self, rule_id: i32) -> Result<u64, AppError>;
+    async fn is_blacklisted(&self, user_id: i32, ip_address: Option<String>) -> Result<bool, AppError>;
+    async fn find_blacklist(&self) -> Result<Vec<BlacklistEntry>, AppError>;
+    async fn add_blacklist_entry(&self, entry: &NewBlacklistEntryRequest) -> Result<BlacklistEntry, AppError>;
+    async fn remove_blacklist_entry(&self, entry_id: i32) -> Result<u64, AppError>;
+    async fn sum_amount_since(&self, user_id: i32, activity: RiskActivity, since: DateTime<Utc>) -> Result<f64, AppError>;
+    async fn count_distinct_campaigns_since(&self, user_id: i32, campaign_id: i32, since: DateTime<Utc>) -> Result<i64, AppError>;
+    async fn record_flag(&self, user_id: i32, rule_id: Option<i32>, activity: RiskActivity, amount: f64, action: RiskAction, reason: String) -> Result<FlaggedActivity, AppError>;
+    async fn find_flags(&self) -> Result<Vec<FlaggedActivity>, AppError>;
+}
+
+pub struct PgRiskRepository {
+    pool: PgPool,
+}
+
+impl PgRiskRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgRiskRepository { pool }
+    }
+}
+
+#[async_trait]
+impl RiskRepository for PgRiskRepository {
+    async fn find_rules(&self) -> Result<Vec<RiskRule>, AppError> {
+        let rules = sqlx::query_as::<_, RiskRule>("SELECT * FROM risk_rules ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rules)
+    }
+
+    async fn find_enabled_rules(&self) -> Result<Vec<RiskRule>, AppError> {
+        let rules = sqlx::query_as::<_, RiskRule>("SELECT * FROM risk_rules WHERE enabled ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rules)
+    }
+
+    async fn create_rule(&self, new_rule: &NewRiskRuleRequest) -> Result<RiskRule, AppError> {
+        let rule = sqlx::query_as::<_, RiskRule>(
+            "INSERT INTO risk_rules (kind, threshold, action, enabled) VALUES ($1, $2, $3, TRUE) RETURNING *",
+        )
+        .bind(new_rule.kind)
+        .bind(new_rule.threshold)
+        .bind(new_rule.action)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(rule)
+    }
+
+    async fn update_rule(&self, rule_id: i32, update: &UpdateRiskRuleRequest) -> Result<Option<RiskRule>, AppError> {
+        let rule = sqlx::query_as::<_, RiskRule>(
+            "UPDATE risk_rules SET threshold = $2, action = $3, enabled = $4 WHERE id = $1 RETURNING *",
+        )
+        .bind(rule_id)
+        .bind(update.threshold)
+        .bind(update.action)
+        .bind(update.enabled)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(rule)
+    }
+
+    async fn delete_rule(&self, rule_id: i32) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM risk_rules WHERE id = $1")
+            .bind(rule_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn is_blacklisted(&self, user_id: i32, ip_address: Option<String>) -> Result<bool, AppError> {
+        let blacklisted: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM risk_blacklist WHERE user_id = $1 OR ($2::TEXT IS NOT NULL AND ip_address = $2))",
+        )
+        .bind(user_id)
+        .bind(ip_address)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(blacklisted)
+    }
+
+    async fn find_blacklist(&self) -> Result<Vec<BlacklistEntry>, AppError> {
+        let entries = sqlx::query_as::<_, BlacklistEntry>("SELECT * FROM risk_blacklist ORDER BY created_at DESC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(entries)
+    }
+
+    async fn add_blacklist_entry(&self, entry: &NewBlacklistEntryRequest) -> Result<BlacklistEntry, AppError> {
+        let entry = sqlx::query_as::<_, BlacklistEntry>(
+            "INSERT INTO risk_blacklist (user_id, ip_address, reason) VALUES ($1, $2, $3) RETURNING *",
+        )
+        .bind(entry.user_id)
+        .bind(&entry.ip_address)
+        .bind(&entry.reason)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(entry)
+    }
+
+    async fn remove_blacklist_entry(&self, entry_id: i32) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM risk_blacklist WHERE id = $1")
+            .bind(entry_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn sum_amount_since(&self, user_id: i32, activity: RiskActivity, since: DateTime<Utc>) -> Result<f64, AppError> {
+        let query = match activity {
+            // Rejected, expired and refunded donations never moved money. Donations have no
+            // pending status of their own; the pending ones are unconfirmed intents.
+            RiskActivity::Donation => {
+                "SELECT COALESCE(SUM(amount), 0)::FLOAT8 FROM ( \
+                     SELECT amount FROM donations \
+                     WHERE user_id = $1 AND status IN ('pending_review', 'settled') AND created_at >= $2 \
+                     UNION ALL \
+                     SELECT amount FROM donation_intents \
+                     WHERE user_id = $1 AND status = 'pending' AND created_at >= $2 \
+                 ) activity"
+            }
+            RiskActivity::TopUp => {
+                "SELECT COALESCE(SUM(amount), 0)::FLOAT8 FROM transactions \
+                 WHERE user_id = $1 AND transaction_type = 'top_up' AND created_at >= $2"
+            }
+        };
+        let total: f64 = sqlx::query_scalar(query)
+            .bind(user_id)
+            .bind(since)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(total)
+    }
+
+    async fn count_distinct_campaigns_since(&self, user_id: i32, campaign_id: i32, since: DateTime<Utc>) -> Result<i64, AppError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM ( \
+                SELECT campaign_id FROM donations WHERE user_id = $1 AND created_at >= $3 \
+                UNION SELECT $2 \
+             ) campaigns",
+        )
+        .bind(user_id)
+        .bind(campaign_id)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    // A block enqueues `RiskBlocked` in the same transaction as the flag.
+    async fn record_flag(&self, user_id: i32, rule_id: Option<i32>, activity: RiskActivity, amount: f64, action: RiskAction, reason: String) -> Result<FlaggedActivity, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let flag = sqlx::query_as::<_, FlaggedActivity>(
+            "INSERT INTO risk_flags (user_id, rule_id, activity, amount, action, reason) \
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        )
+        .bind(user_id)
+        .bind(rule_id)
+        .bind(activity)
+        .bind(amount)
+        .bind(action)
+        .bind(&reason)
+        .fetch_one(&mut *tx)
+        .await?;
+        if action == RiskAction::Block {
+            enqueue_event(&mut tx, &DomainEvent::RiskBlocked { user_id, activity, amount, reason }).await?;
+        }
+        tx.commit().await?;
+        Ok(flag)
+    }
+
+    async fn find_flags(&self) -> Result<Vec<FlaggedActivity>, AppError> {
+        let flags = sqlx::query_as::<_, FlaggedActivity>("SELECT * FROM risk_flags ORDER BY created_at DESC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_only_blocks_enqueue_risk_blocked() {
+        let db = test_db().await;
+        let repo = PgRiskRepository::new(db.pool.clone());
+
+        repo.record_flag(1, None, RiskActivity::Donation, 500.0, RiskAction::Flag, "Daily amount cap exceeded".to_string()).await.unwrap();
+        repo.record_flag(1, None, RiskActivity::Donation, 500.0, RiskAction::Block, "User or IP address is blacklisted".to_string()).await.unwrap();
+
+        assert_eq!(repo.find_flags().await.unwrap().len(), 2);
+        let events: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(events, vec!["risk_blocked".to_string()]);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_donation_amount_counts_only_live_donations() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO donations (user_id, campaign_id, amount, status) VALUES \
+                 (1, 10, 100, 'settled'), (1, 10, 200, 'pending_review'), (1, 10, 400, 'rejected'), \
+                 (1, 10, 800, 'refunded'), (1, 10, 1600, 'expired'), (2, 10, 3200, 'settled');
+             INSERT INTO donation_intents (user_id, campaign_id, amount, token_hash, status, expires_at) VALUES \
+                 (1, 10, 50, 'a', 'pending', NOW() + INTERVAL '1 hour'), \
+                 (1, 10, 25, 'b', 'expired', NOW());",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgRiskRepository::new(db.pool.clone());
+
+        let since = Utc::now() - chrono::Duration::hours(24);
+        assert_eq!(repo.sum_amount_since(1, RiskActivity::Donation, since).await.unwrap(), 350.0);
+    }
+}
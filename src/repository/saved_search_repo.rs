@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::event::DomainEvent;
+use crate::model::saved_search::{SavedSearch, SavedSearchMatch, SavedSearchRequest};
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SavedSearchRepository: Send + Sync {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<SavedSearch>, AppError>;
+    async fn count_by_user(&self, user_id: i32) -> Result<i64, AppError>;
+    async fn create(&self, user_id: i32, req: &SavedSearchRequest) -> Result<SavedSearch, AppError>;
+    /// None if the search doesn't exist or belongs to someone else.
+    async fn update(&self, search_id: i32, user_id: i32, req: &SavedSearchRequest) -> Result<Option<SavedSearch>, AppError>;
+    async fn delete(&self, search_id: i32, user_id: i32) -> Result<bool, AppError>;
+    /// Records an alert for every saved search the campaign matches and enqueues the
+    /// notifications. Searches already alerted for this campaign are skipped, so
+    /// handling the same approval twice notifies nobody twice.
+    async fn alert_matches(&self, campaign_id: i32) -> Result<Vec<SavedSearchMatch>, AppError>;
+}
+
+pub struct PgSavedSearchRepository {
+    pool: PgPool,
+}
+
+impl PgSavedSearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgSavedSearchRepository { pool }
+    }
+}
+
+#[async_trait]
+impl SavedSearchRepository for PgSavedSearchRepository {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<SavedSearch>, AppError> {
+        let searches = sqlx::query_as::<_, SavedSearch>("SELECT * FROM saved_searches WHERE user_id = $1 ORDER BY id ASC")
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(searches)
+    }
+
+    async fn count_by_user(&self, user_id: i32) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM saved_searches WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count)
+    }
+
+    async fn create(&self, user_id: i32, req: &SavedSearchRequest) -> Result<SavedSearch, AppError> {
+        let search = sqlx::query_as::<_, SavedSearch>(
+            "INSERT INTO saved_searches (user_id, keywords, category, region) VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(user_id)
+        .bind(&req.keywords)
+        .bind(&req.category)
+        .bind(&req.region)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(search)
+    }
+
+    async fn update(&self, search_id: i32, user_id: i32, req: &SavedSearchRequest) -> Result<Option<SavedSearch>, AppError> {
+        let search = sqlx::query_as::<_, SavedSearch>(
+            "UPDATE saved_searches SET keywords = $3, category = $4, region = $5, updated_at = NOW() \
+             WHERE id = $1 AND user_id = $2 RETURNING *",
+        )
+        .bind(search_id)
+        .bind(user_id)
+        .bind(&req.keywords)
+        .bind(&req.category)
+        .bind(&req.region)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(search)
+    }
+
+    async fn delete(&self, search_id: i32, user_id: i32) -> Result<bool, AppError> {
+        let result = sqlx::query("DELETE FROM saved_searches WHERE id = $1 AND user_id = $2")
+            .bind(search_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    // The 'simple' text search configuration doesn't stem, which suits titles in
+    // Indonesian as well as English.
+    async fn alert_matches(&self, campaign_id: i32) -> Result<Vec<SavedSearchMatch>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let matches = sqlx::query_as::<_, SavedSearchMatch>(
+            "WITH matched AS ( \
+                 SELECT s.id FROM saved_searches s JOIN campaigns c ON c.id = $1 \
+                 WHERE (s.category IS NULL OR LOWER(c.category) = LOWER(s.category)) \
+                   AND (s.region IS NULL OR LOWER(c.region) = LOWER(s.region)) \
+                   AND (s.keywords IS NULL OR to_tsvector('simple', c.title) @@ plainto_tsquery('simple', s.keywords)) \
+             ), alerted AS ( \
+                 INSERT INTO saved_search_alerts (saved_search_id, campaign_id) SELECT id, $1 FROM matched \
+                 ON CONFLICT DO NOTHING RETURNING saved_search_id \
+             ) \
+             SELECT a.saved_search_id, s.user_id FROM alerted a JOIN saved_searches s ON s.id = a.saved_search_id \
+             ORDER BY a.saved_search_id ASC",
+        )
+        .bind(campaign_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for matched in &matches {
+            enqueue_event(
+                &mut tx,
+                &DomainEvent::SavedSearchMatched {
+                    saved_search_id: matched.saved_search_id,
+                    user_id: matched.user_id,
+                    campaign_id,
+                },
+            )
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_alert_matches_every_criterion_once() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount, region, category) VALUES \
+                 (10, 'Bantuan Banjir Bandung', 1000, 'Jawa Barat', 'Disaster'); \
+             INSERT INTO saved_searches (id, user_id, keywords, category, region) VALUES \
+                 (1, 7, 'banjir', NULL, NULL), \
+                 (2, 7, NULL, 'disaster', 'jawa barat'), \
+                 (3, 8, 'banjir', NULL, 'Bali'), \
+                 (4, 8, 'banjir gempa', NULL, NULL), \
+                 (5, 9, 'BANDUNG  banjir', 'Disaster', NULL);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgSavedSearchRepository::new(db.pool.clone());
+
+        let matches = repo.alert_matches(10).await.unwrap();
+        let ids: Vec<i32> = matches.iter().map(|matched| matched.saved_search_id).collect();
+        assert_eq!(ids, vec![1, 2, 5]);
+        assert_eq!(matches[2].user_id, 9);
+        assert!(repo.alert_matches(10).await.unwrap().is_empty());
+
+        let queued: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM outbox WHERE event_type = 'saved_search_matched'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(queued, 3);
+    }
+}
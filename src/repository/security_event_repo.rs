@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::security_event::{LoginHistory, SecurityEvent, SecurityEventType};
+use crate::service::commands::security_commands::RecordLoginCommand;
+use crate::errors::AppError;
+use crate::model::event::DomainEvent;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SecurityEventRepository: Send + Sync {
+    async fn login_history(&self, user_id: i32, user_agent: Option<String>, country: Option<String>) -> Result<LoginHistory, AppError>;
+    async fn record_login(&self, cmd: &RecordLoginCommand, suspicious: bool) -> Result<SecurityEvent, AppError>;
+    async fn find_by_user(&self, user_id: i32, limit: i64) -> Result<Vec<SecurityEvent>, AppError>;
+}
+
+pub struct PgSecurityEventRepository {
+    pool: PgPool,
+}
+
+impl PgSecurityEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgSecurityEventRepository { pool }
+    }
+}
+
+#[async_trait]
+impl SecurityEventRepository for PgSecurityEventRepository {
+    // Only successful logins count as known devices and countries.
+    async fn login_history(&self, user_id: i32, user_agent: Option<String>, country: Option<String>) -> Result<LoginHistory, AppError> {
+        let (has_previous_logins, known_device, known_country) = sqlx::query_as::<_, (bool, bool, bool)>(
+            "SELECT COUNT(*) > 0, COALESCE(BOOL_OR(user_agent = $2), FALSE), COALESCE(BOOL_OR(country = $3), FALSE) \
+             FROM security_events WHERE user_id = $1 AND event_type = 'login_succeeded'",
+        )
+        .bind(user_id)
+        .bind(user_agent)
+        .bind(country)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(LoginHistory { has_previous_logins, known_device, known_country })
+    }
+
+    // A suspicious login enqueues `SuspiciousLogin` in the same transaction.
+    async fn record_login(&self, cmd: &RecordLoginCommand, suspicious: bool) -> Result<SecurityEvent, AppError> {
+        let event_type = if cmd.succeeded {
+            SecurityEventType::LoginSucceeded
+        } else {
+            SecurityEventType::LoginFailed
+        };
+        let mut tx = self.pool.begin().await?;
+        let event = sqlx::query_as::<_, SecurityEvent>(
+            "INSERT INTO security_events (user_id, event_type, ip_address, user_agent, country, suspicious) \
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        )
+        .bind(cmd.user_id)
+        .bind(event_type)
+        .bind(&cmd.ip_address)
+        .bind(&cmd.user_agent)
+        .bind(&cmd.country)
+        .bind(suspicious)
+        .fetch_one(&mut *tx)
+        .await?;
+        if suspicious {
+            let suspicious_login = DomainEvent::SuspiciousLogin {
+                user_id: cmd.user_id,
+                ip_address: cmd.ip_address.clone(),
+                user_agent: cmd.user_agent.clone(),
+                country: cmd.country.clone(),
+            };
+            enqueue_event(&mut tx, &suspicious_login).await?;
+        }
+        tx.commit().await?;
+        Ok(event)
+    }
+
+    async fn find_by_user(&self, user_id: i32, limit: i64) -> Result<Vec<SecurityEvent>, AppError> {
+        let events = sqlx::query_as::<_, SecurityEvent>(
+            "SELECT * FROM security_events WHERE user_id = $1 ORDER BY created_at DESC, id DESC LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    fn login(succeeded: bool, user_agent: &str, country: &str) -> RecordLoginCommand {
+        RecordLoginCommand {
+            user_id: 1,
+            succeeded,
+            ip_address: Some("203.0.113.7".to_string()),
+            user_agent: Some(user_agent.to_string()),
+            country: Some(country.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_login_history_ignores_failed_attempts() {
+        let db = test_db().await;
+        let repo = PgSecurityEventRepository::new(db.pool.clone());
+
+        let history = repo.login_history(1, Some("Firefox".to_string()), Some("ID".to_string())).await.unwrap();
+        assert_eq!(history, LoginHistory::default());
+
+        repo.record_login(&login(true, "Firefox", "ID"), false).await.unwrap();
+        repo.record_login(&login(false, "curl", "SG"), false).await.unwrap();
+
+        let history = repo.login_history(1, Some("curl".to_string()), Some("ID".to_string())).await.unwrap();
+        assert_eq!(
+            history,
+            LoginHistory { has_previous_logins: true, known_device: false, known_country: true }
+        );
+        repo.record_login(&login(true, "curl", "SG"), true).await.unwrap();
+        let events = repo.find_by_user(1, 10).await.unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[1].event_type, SecurityEventType::LoginFailed);
+        let queued: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(queued, vec!["suspicious_login".to_string()]);
+    }
+}
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::campaign::Campaign;
+use crate::model::event::DomainEvent;
+use crate::errors::AppError;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Writes for the demo data seeder only; nothing here runs while the app serves requests.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SeedRepository: Send + Sync {
+    /// Leaves existing wallets alone, so re-seeding doesn't reset balances.
+    async fn insert_wallets(&self, user_ids: Vec<i32>, balance: f64) -> Result<u64, AppError>;
+    /// `id` and `collected_amount` are ignored; returns the new ids in input order.
+    async fn insert_campaigns(&self, campaigns: Vec<Campaign>) -> Result<Vec<i32>, AppError>;
+    /// Imported donations don't credit their campaign, so the seeder totals them afterwards.
+    async fn sync_collected_amounts(&self, campaign_ids: Vec<i32>) -> Result<(), AppError>;
+    async fn enqueue_notifications(&self, events: Vec<DomainEvent>) -> Result<u64, AppError>;
+}
+
+pub struct PgSeedRepository {
+    pool: PgPool,
+}
+
+impl PgSeedRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgSeedRepository { pool }
+    }
+}
+
+#[async_trait]
+impl SeedRepository for PgSeedRepository {
+    async fn insert_wallets(&self, user_ids: Vec<i32>, balance: f64) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            "INSERT INTO wallets (user_id, balance) SELECT user_id, $2 FROM UNNEST($1::INT[]) AS user_id \
+             ON CONFLICT (user_id) DO NOTHING",
+        )
+        .bind(&user_ids)
+        .bind(balance)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn insert_campaigns(&self, campaigns: Vec<Campaign>) -> Result<Vec<i32>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let mut ids = Vec::with_capacity(campaigns.len());
+        for campaign in &campaigns {
+            let id: i32 = sqlx::query_scalar(
+                "INSERT INTO campaigns (fundraiser_id, title, description, category, target_amount, status, ends_at) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id",
+            )
+            .bind(campaign.fundraiser_id)
+            .bind(&campaign.title)
+            .bind(&campaign.description)
+            .bind(&campaign.category)
+            .bind(campaign.target_amount)
+            .bind(&campaign.status)
+            .bind(campaign.ends_at)
+            .fetch_one(&mut *tx)
+            .await?;
+            ids.push(id);
+        }
+        tx.commit().await?;
+        Ok(ids)
+    }
+
+    async fn sync_collected_amounts(&self, campaign_ids: Vec<i32>) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE campaigns c SET collected_amount = COALESCE(( \
+                 SELECT SUM(amount) FROM all_donations d \
+                 WHERE d.campaign_id = c.id AND d.status IN ('settled', 'imported')), 0) \
+             WHERE c.id = ANY($1)",
+        )
+        .bind(&campaign_ids)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn enqueue_notifications(&self, events: Vec<DomainEvent>) -> Result<u64, AppError> {
+        let mut tx = self.pool.begin().await?;
+        for event in &events {
+            enqueue_event(&mut tx, event).await?;
+        }
+        tx.commit().await?;
+        Ok(events.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_seeded_campaigns_get_their_imported_totals() {
+        let db = test_db().await;
+        let repo = PgSeedRepository::new(db.pool.clone());
+
+        assert_eq!(repo.insert_wallets(vec![1, 2], 500_000.0).await.unwrap(), 2);
+        assert_eq!(repo.insert_wallets(vec![2, 3], 0.0).await.unwrap(), 1);
+        let ids = repo
+            .insert_campaigns(vec![
+                Campaign { title: "Clean water".to_string(), target_amount: 1000.0, status: "active".to_string(), ..Campaign::default() },
+                Campaign { title: "Draft".to_string(), target_amount: 1000.0, status: "draft".to_string(), ..Campaign::default() },
+            ])
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO donations (user_id, campaign_id, amount, status) VALUES (1, $1, 150, 'imported'), (2, $1, 50, 'imported')")
+            .bind(ids[0])
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        repo.sync_collected_amounts(ids.clone()).await.unwrap();
+
+        let collected: Vec<(String, f64)> = sqlx::query_as("SELECT status, collected_amount FROM campaigns ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(collected, vec![("active".to_string(), 200.0), ("draft".to_string(), 0.0)]);
+
+        let queued = repo
+            .enqueue_notifications(vec![DomainEvent::CampaignApproved { campaign_id: ids[0] }])
+            .await
+            .unwrap();
+        assert_eq!(queued, 1);
+        let events: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(events, vec!["campaign_approved"]);
+    }
+}
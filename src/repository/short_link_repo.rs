@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::short_link::ShortLink;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ShortLinkRepository: Send + Sync {
+    /// Returns `None` if the slug is already taken.
+    async fn create(&self, slug: &str, campaign_id: i32, channel: Option<String>, created_by: i32) -> Result<Option<ShortLink>, AppError>;
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<ShortLink>, AppError>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<ShortLink>, AppError>;
+    async fn record_click(&self, slug: &str) -> Result<Option<ShortLink>, AppError>;
+}
+
+pub struct PgShortLinkRepository {
+    pool: PgPool,
+}
+
+impl PgShortLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgShortLinkRepository { pool }
+    }
+}
+
+#[async_trait]
+impl ShortLinkRepository for PgShortLinkRepository {
+    async fn create(&self, slug: &str, campaign_id: i32, channel: Option<String>, created_by: i32) -> Result<Option<ShortLink>, AppError> {
+        let link = sqlx::query_as::<_, ShortLink>(
+            "INSERT INTO short_links (slug, campaign_id, channel, created_by) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (slug) DO NOTHING RETURNING *",
+        )
+        .bind(slug)
+        .bind(campaign_id)
+        .bind(channel)
+        .bind(created_by)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(link)
+    }
+
+    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<ShortLink>, AppError> {
+        let links = sqlx::query_as::<_, ShortLink>(
+            "SELECT * FROM short_links WHERE campaign_id = $1 ORDER BY created_at, id",
+        )
+        .bind(campaign_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(links)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<ShortLink>, AppError> {
+        let link = sqlx::query_as::<_, ShortLink>("SELECT * FROM short_links WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(link)
+    }
+
+    async fn record_click(&self, slug: &str) -> Result<Option<ShortLink>, AppError> {
+        let link = sqlx::query_as::<_, ShortLink>(
+            "UPDATE short_links SET click_count = click_count + 1 WHERE slug = $1 RETURNING *",
+        )
+        .bind(slug)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_slug_collision_and_click_counting() {
+        let db = test_db().await;
+        let repo = PgShortLinkRepository::new(db.pool.clone());
+
+        let link = repo.create("abc123", 10, Some("poster".to_string()), 1).await.unwrap().unwrap();
+        assert!(repo.create("abc123", 11, None, 2).await.unwrap().is_none());
+
+        repo.record_click("abc123").await.unwrap();
+        let clicked = repo.record_click("abc123").await.unwrap().unwrap();
+        assert_eq!(clicked.click_count, 2);
+        assert!(repo.record_click("missing").await.unwrap().is_none());
+
+        let links = repo.find_by_campaign(10).await.unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].id, link.id);
+        assert_eq!(links[0].channel.as_deref(), Some("poster"));
+    }
+}
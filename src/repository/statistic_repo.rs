@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::statistic::PublicStats;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait StatisticRepository: Send + Sync {
+    async fn public_stats(&self) -> Result<PublicStats, AppError>;
+}
+
+pub struct PgStatisticRepository {
+    pool: PgPool,
+}
+
+impl PgStatisticRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgStatisticRepository { pool }
+    }
+}
+
+#[async_trait]
+impl StatisticRepository for PgStatisticRepository {
+    async fn public_stats(&self) -> Result<PublicStats, AppError> {
+        let stats = sqlx::query_as::<_, PublicStats>(
+            "SELECT d.total_raised, d.donor_count, \
+                 (SELECT COUNT(*) FROM campaigns WHERE target_amount > 0 AND collected_amount >= target_amount) AS campaigns_funded, \
+                 NOW() AS computed_at \
+             FROM ( \
+                 SELECT COALESCE(SUM(amount), 0)::FLOAT8 AS total_raised, COUNT(DISTINCT user_id) AS donor_count \
+                 FROM all_donations WHERE status IN ('settled', 'imported') \
+             ) AS d",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_public_stats_count_settled_donations_and_funded_campaigns() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, target_amount, collected_amount) VALUES (10, 100, 100), (11, 100, 40);
+             INSERT INTO donations (id, user_id, campaign_id, amount, status) VALUES
+                 (1, 1, 10, 60, 'settled'),
+                 (2, 2, 11, 40, 'settled'),
+                 (3, 3, 11, 500, 'pending_review');
+             INSERT INTO donations_archive (id, user_id, campaign_id, amount, status) VALUES
+                 (4, 1, 10, 40, 'settled');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgStatisticRepository::new(db.pool.clone());
+
+        let stats = repo.public_stats().await.unwrap();
+        assert_eq!(stats.total_raised, 140.0);
+        assert_eq!(stats.donor_count, 2);
+        assert_eq!(stats.campaigns_funded, 1);
+    }
+}
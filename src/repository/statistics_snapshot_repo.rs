@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use crate::model::statistics_snapshot::{StatisticMetric, StatisticPoint};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait StatisticsSnapshotRepository: Send + Sync {
+    async fn capture(&self, snapshot_date: NaiveDate) -> Result<u64, AppError>;
+    async fn find_history(&self, metric: StatisticMetric, since: Option<NaiveDate>) -> Result<Vec<StatisticPoint>, AppError>;
+}
+
+pub struct PgStatisticsSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PgStatisticsSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgStatisticsSnapshotRepository { pool }
+    }
+}
+
+#[async_trait]
+impl StatisticsSnapshotRepository for PgStatisticsSnapshotRepository {
+    // Values are platform totals at capture time. Re-running for the same date overwrites
+    // that day's row, so a retried or manual run never leaves duplicates.
+    async fn capture(&self, snapshot_date: NaiveDate) -> Result<u64, AppError> {
+        let captured = sqlx::query(
+            "INSERT INTO statistics_snapshots (snapshot_date, metric, value) \
+             SELECT $1, m.metric::statistic_metric, m.value FROM (VALUES \
+                 ('donation_amount_total', (SELECT COALESCE(SUM(amount), 0)::FLOAT8 FROM all_donations WHERE status IN ('settled', 'imported'))), \
+                 ('donation_count', (SELECT COUNT(*)::FLOAT8 FROM all_donations WHERE status IN ('settled', 'imported'))), \
+                 ('donor_count', (SELECT COUNT(DISTINCT user_id)::FLOAT8 FROM all_donations WHERE status IN ('settled', 'imported'))), \
+                 ('active_campaigns', (SELECT COUNT(*)::FLOAT8 FROM campaigns WHERE status = 'active')), \
+                 ('completed_campaigns', (SELECT COUNT(*)::FLOAT8 FROM campaigns WHERE status = 'completed')), \
+                 ('wallet_balance_total', (SELECT COALESCE(SUM(balance), 0)::FLOAT8 FROM wallets)) \
+             ) AS m(metric, value) \
+             ON CONFLICT (snapshot_date, metric) DO UPDATE SET value = EXCLUDED.value, captured_at = NOW()",
+        )
+        .bind(snapshot_date)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(captured)
+    }
+
+    async fn find_history(&self, metric: StatisticMetric, since: Option<NaiveDate>) -> Result<Vec<StatisticPoint>, AppError> {
+        let points = sqlx::query_as::<_, StatisticPoint>(
+            "SELECT snapshot_date, value FROM statistics_snapshots \
+             WHERE metric = $1 AND ($2::DATE IS NULL OR snapshot_date >= $2) \
+             ORDER BY snapshot_date",
+        )
+        .bind(metric)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_capture_is_idempotent_per_day() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (1, 500), (2, 250);
+             INSERT INTO campaigns (id, target_amount, status) VALUES (10, 1000, 'active'), (11, 100, 'completed');
+             INSERT INTO donations (user_id, campaign_id, amount, status) VALUES
+                 (1, 10, 100, 'settled'), (1, 11, 100, 'settled'), (2, 10, 50, 'rejected');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgStatisticsSnapshotRepository::new(db.pool.clone());
+        let day_one = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let day_two = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+
+        assert_eq!(repo.capture(day_one).await.unwrap(), 6);
+        sqlx::query("INSERT INTO donations (user_id, campaign_id, amount) VALUES (2, 10, 300)")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        repo.capture(day_two).await.unwrap();
+        repo.capture(day_two).await.unwrap();
+
+        let amounts = repo.find_history(StatisticMetric::DonationAmountTotal, None).await.unwrap();
+        let values: Vec<(NaiveDate, f64)> = amounts.iter().map(|p| (p.snapshot_date, p.value)).collect();
+        assert_eq!(values, vec![(day_one, 200.0), (day_two, 500.0)]);
+
+        let donors = repo.find_history(StatisticMetric::DonorCount, Some(day_two)).await.unwrap();
+        assert_eq!(donors.len(), 1);
+        assert_eq!(donors[0].value, 2.0);
+    }
+}
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::tax_summary::{EligibleDonation, TaxDeductibleCampaign};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TaxSummaryRepository: Send + Sync {
+    /// Returns false if the campaign does not exist.
+    async fn mark_deductible(&self, campaign_id: i32, admin_id: i32) -> Result<bool, AppError>;
+    async fn unmark_deductible(&self, campaign_id: i32) -> Result<bool, AppError>;
+    async fn find_deductible(&self) -> Result<Vec<TaxDeductibleCampaign>, AppError>;
+    async fn find_eligible_donations(&self, user_id: i32, year: i32) -> Result<Vec<EligibleDonation>, AppError>;
+}
+
+pub struct PgTaxSummaryRepository {
+    pool: PgPool,
+}
+
+impl PgTaxSummaryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgTaxSummaryRepository { pool }
+    }
+}
+
+#[async_trait]
+impl TaxSummaryRepository for PgTaxSummaryRepository {
+    // Re-marking keeps the original marker and timestamp.
+    async fn mark_deductible(&self, campaign_id: i32, admin_id: i32) -> Result<bool, AppError> {
+        let exists: bool = sqlx::query_scalar(
+            "WITH marked AS ( \
+                 INSERT INTO tax_deductible_campaigns (campaign_id, marked_by) \
+                 SELECT id, $2 FROM campaigns WHERE id = $1 \
+                 ON CONFLICT (campaign_id) DO NOTHING \
+             ) \
+             SELECT EXISTS (SELECT 1 FROM campaigns WHERE id = $1)",
+        )
+        .bind(campaign_id)
+        .bind(admin_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists)
+    }
+
+    async fn unmark_deductible(&self, campaign_id: i32) -> Result<bool, AppError> {
+        let removed = sqlx::query("DELETE FROM tax_deductible_campaigns WHERE campaign_id = $1")
+            .bind(campaign_id)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        Ok(removed > 0)
+    }
+
+    async fn find_deductible(&self) -> Result<Vec<TaxDeductibleCampaign>, AppError> {
+        let campaigns = sqlx::query_as::<_, TaxDeductibleCampaign>(
+            "SELECT t.campaign_id, c.title AS campaign_title, t.marked_by, t.marked_at \
+             FROM tax_deductible_campaigns t JOIN campaigns c ON c.id = t.campaign_id \
+             ORDER BY c.title",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(campaigns)
+    }
+
+    // The tax year is the calendar year (UTC) the receipt was issued in. Refunded
+    // donations keep their receipt number but no longer count.
+    async fn find_eligible_donations(&self, user_id: i32, year: i32) -> Result<Vec<EligibleDonation>, AppError> {
+        let donations = sqlx::query_as::<_, EligibleDonation>(
+            "SELECT d.campaign_id, c.title AS campaign_title, d.id AS donation_id, d.receipt_number, \
+             d.amount, d.receipt_issued_at AS issued_at \
+             FROM all_donations d \
+             JOIN tax_deductible_campaigns t ON t.campaign_id = d.campaign_id \
+             JOIN campaigns c ON c.id = d.campaign_id \
+             WHERE d.user_id = $1 AND d.status = 'settled' AND d.receipt_number IS NOT NULL \
+               AND d.receipt_issued_at >= make_timestamptz($2, 1, 1, 0, 0, 0, 'UTC') \
+               AND d.receipt_issued_at < make_timestamptz($2 + 1, 1, 1, 0, 0, 0, 'UTC') \
+             ORDER BY c.title, d.campaign_id, d.receipt_issued_at",
+        )
+        .bind(user_id)
+        .bind(year)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(donations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_only_settled_receipts_for_deductible_campaigns_in_year_count() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO campaigns (id, title, target_amount) VALUES (10, 'Sumur desa', 1000), (11, 'Konser amal', 1000);
+             INSERT INTO donations (user_id, campaign_id, amount, status, receipt_number, receipt_issued_at) VALUES
+                 (1, 10, 100, 'settled', 'KWT/2024/000001', '2024-03-01T00:00:00Z'),
+                 (1, 10, 200, 'refunded', 'KWT/2024/000002', '2024-04-01T00:00:00Z'),
+                 (1, 10, 300, 'settled', 'KWT/2025/000001', '2025-01-01T00:00:00Z'),
+                 (1, 11, 400, 'settled', 'KWT/2024/000003', '2024-05-01T00:00:00Z'),
+                 (2, 10, 500, 'settled', 'KWT/2024/000004', '2024-06-01T00:00:00Z');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgTaxSummaryRepository::new(db.pool.clone());
+
+        assert!(repo.mark_deductible(10, 99).await.unwrap());
+        assert!(repo.mark_deductible(10, 98).await.unwrap());
+        assert!(!repo.mark_deductible(12, 99).await.unwrap());
+        let deductible = repo.find_deductible().await.unwrap();
+        assert_eq!(deductible.len(), 1);
+        assert_eq!(deductible[0].marked_by, 99);
+
+        let eligible = repo.find_eligible_donations(1, 2024).await.unwrap();
+        let receipts: Vec<&str> = eligible.iter().map(|d| d.receipt_number.as_str()).collect();
+        assert_eq!(receipts, vec!["KWT/2024/000001"]);
+
+        assert!(repo.unmark_deductible(10).await.unwrap());
+        assert!(repo.find_eligible_donations(1, 2024).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use crate::model::transaction::{Transaction, TransactionFilter};
+use crate::repository::query_monitor::{into_parts, QueryMonitor};
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TransactionRepository: Send + Sync {
+    async fn find(&self, filter: &TransactionFilter, limit: i64, offset: i64) -> Result<Vec<Transaction>, AppError>;
+    async fn count(&self, filter: &TransactionFilter) -> Result<i64, AppError>;
+}
+
+pub struct PgTransactionRepository {
+    pool: PgPool,
+    query_monitor: Arc<QueryMonitor>,
+}
+
+impl PgTransactionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgTransactionRepository {
+            pool,
+            query_monitor: Arc::new(QueryMonitor::default()),
+        }
+    }
+
+    // Share the app-wide monitor so these searches show up in diagnostics and metrics.
+    pub fn with_query_monitor(mut self, query_monitor: Arc<QueryMonitor>) -> Self {
+        self.query_monitor = query_monitor;
+        self
+    }
+}
+
+fn push_filter<'a>(query: &mut QueryBuilder<'a, Postgres>, filter: &'a TransactionFilter) {
+    query.push(" WHERE TRUE");
+    if let Some(transaction_type) = filter.transaction_type {
+        query.push(" AND transaction_type = ").push_bind(transaction_type);
+    }
+    if let Some(user_id) = filter.user_id {
+        query.push(" AND user_id = ").push_bind(user_id);
+    }
+    if let Some(campaign_id) = filter.campaign_id {
+        query.push(" AND campaign_id = ").push_bind(campaign_id);
+    }
+    if let Some(min_amount) = filter.min_amount {
+        query.push(" AND amount >= ").push_bind(min_amount);
+    }
+    if let Some(max_amount) = filter.max_amount {
+        query.push(" AND amount <= ").push_bind(max_amount);
+    }
+    if let Some(from) = filter.from {
+        query.push(" AND created_at >= ").push_bind(from);
+    }
+    if let Some(to) = filter.to {
+        query.push(" AND created_at < ").push_bind(to);
+    }
+}
+
+#[async_trait]
+impl TransactionRepository for PgTransactionRepository {
+    async fn find(&self, filter: &TransactionFilter, limit: i64, offset: i64) -> Result<Vec<Transaction>, AppError> {
+        let mut query = QueryBuilder::new("SELECT * FROM transactions");
+        push_filter(&mut query, filter);
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+
+        let (sql, args) = into_parts(&mut query)?;
+        self.query_monitor
+            .observe(&sql, args, |args| sqlx::query_as_with::<_, Transaction, _>(&sql, args).fetch_all(&self.pool))
+            .await
+    }
+
+    async fn count(&self, filter: &TransactionFilter) -> Result<i64, AppError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) FROM transactions");
+        push_filter(&mut query, filter);
+
+        let (sql, args) = into_parts(&mut query)?;
+        self.query_monitor
+            .observe(&sql, args, |args| sqlx::query_scalar_with::<_, i64, _>(&sql, args).fetch_one(&self.pool))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transaction::TransactionType;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_find_applies_filters_and_paging() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO transactions (user_id, campaign_id, transaction_type, amount) VALUES
+                (1, 10, 'donation', 50),
+                (1, 10, 'donation', 150),
+                (1, NULL, 'top_up', 500),
+                (2, 10, 'donation', 300);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgTransactionRepository::new(db.pool.clone());
+
+        let filter = TransactionFilter {
+            transaction_type: Some(TransactionType::Donation),
+            campaign_id: Some(10),
+            min_amount: Some(100.0),
+            ..TransactionFilter::default()
+        };
+
+        assert_eq!(repo.count(&filter).await.unwrap(), 2);
+        let first_page = repo.find(&filter, 1, 0).await.unwrap();
+        assert_eq!(first_page.len(), 1);
+        assert_eq!(repo.find(&filter, 1, 1).await.unwrap().len(), 1);
+        assert_eq!(repo.count(&TransactionFilter::default()).await.unwrap(), 4);
+    }
+}
@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::two_factor::AdminTwoFactor;
+use crate::errors::AppError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TwoFactorRepository: Send + Sync {
+    async fn find(&self, admin_id: i32) -> Result<Option<AdminTwoFactor>, AppError>;
+    async fn save_pending(&self, admin_id: i32, secret: &str) -> Result<AdminTwoFactor, AppError>;
+    async fn enable(&self, admin_id: i32, step: i64, recovery_code_hashes: Vec<String>) -> Result<AdminTwoFactor, AppError>;
+    /// None if `step` is not newer than the last step used, i.e. the code was replayed.
+    async fn record_verification(&self, admin_id: i32, step: Option<i64>) -> Result<Option<AdminTwoFactor>, AppError>;
+    async fn consume_recovery_code(&self, admin_id: i32, code_hash: &str) -> Result<bool, AppError>;
+    /// Rows whose secret predates encryption at rest.
+    async fn find_unencrypted(&self) -> Result<Vec<AdminTwoFactor>, AppError>;
+    /// False if the secret changed since it was read.
+    async fn replace_secret(&self, admin_id: i32, old_secret: &str, new_secret: &str) -> Result<bool, AppError>;
+}
+
+pub struct PgTwoFactorRepository {
+    pool: PgPool,
+}
+
+impl PgTwoFactorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgTwoFactorRepository { pool }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepository for PgTwoFactorRepository {
+    async fn find(&self, admin_id: i32) -> Result<Option<AdminTwoFactor>, AppError> {
+        let two_factor = sqlx::query_as::<_, AdminTwoFactor>("SELECT * FROM admin_two_factor WHERE admin_id = $1")
+            .bind(admin_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(two_factor)
+    }
+
+    // Re-enrolling before verification replaces the pending secret; an enabled
+    // factor is never overwritten here.
+    async fn save_pending(&self, admin_id: i32, secret: &str) -> Result<AdminTwoFactor, AppError> {
+        let two_factor = sqlx::query_as::<_, AdminTwoFactor>(
+            "INSERT INTO admin_two_factor (admin_id, secret) VALUES ($1, $2) \
+             ON CONFLICT (admin_id) DO UPDATE SET secret = EXCLUDED.secret, created_at = NOW() \
+             WHERE admin_two_factor.enabled_at IS NULL \
+             RETURNING *",
+        )
+        .bind(admin_id)
+        .bind(secret)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::ValidationError("Two-factor authentication is already enabled".to_string()))?;
+        Ok(two_factor)
+    }
+
+    async fn enable(&self, admin_id: i32, step: i64, recovery_code_hashes: Vec<String>) -> Result<AdminTwoFactor, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let two_factor = sqlx::query_as::<_, AdminTwoFactor>(
+            "UPDATE admin_two_factor SET enabled_at = NOW(), last_verified_at = NOW(), last_used_step = $2 \
+             WHERE admin_id = $1 RETURNING *",
+        )
+        .bind(admin_id)
+        .bind(step)
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM admin_recovery_codes WHERE admin_id = $1")
+            .bind(admin_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("INSERT INTO admin_recovery_codes (admin_id, code_hash) SELECT $1, UNNEST($2::TEXT[])")
+            .bind(admin_id)
+            .bind(&recovery_code_hashes)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(two_factor)
+    }
+
+    // The step check is part of the update, so two requests racing with the same code
+    // can't both be accepted.
+    async fn record_verification(&self, admin_id: i32, step: Option<i64>) -> Result<Option<AdminTwoFactor>, AppError> {
+        let two_factor = sqlx::query_as::<_, AdminTwoFactor>(
+            "UPDATE admin_two_factor SET last_verified_at = NOW(), last_used_step = COALESCE($2, last_used_step) \
+             WHERE admin_id = $1 AND ($2::BIGINT IS NULL OR last_used_step IS NULL OR last_used_step < $2) \
+             RETURNING *",
+        )
+        .bind(admin_id)
+        .bind(step)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(two_factor)
+    }
+
+    async fn consume_recovery_code(&self, admin_id: i32, code_hash: &str) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            "UPDATE admin_recovery_codes SET used_at = NOW() \
+             WHERE admin_id = $1 AND code_hash = $2 AND used_at IS NULL",
+        )
+        .bind(admin_id)
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_unencrypted(&self) -> Result<Vec<AdminTwoFactor>, AppError> {
+        let rows = sqlx::query_as::<_, AdminTwoFactor>("SELECT * FROM admin_two_factor WHERE secret NOT LIKE 'v1:%'")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows)
+    }
+
+    async fn replace_secret(&self, admin_id: i32, old_secret: &str, new_secret: &str) -> Result<bool, AppError> {
+        let result = sqlx::query("UPDATE admin_two_factor SET secret = $3 WHERE admin_id = $1 AND secret = $2")
+            .bind(admin_id)
+            .bind(old_secret)
+            .bind(new_secret)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_enable_replaces_recovery_codes_and_codes_are_single_use() {
+        let db = test_db().await;
+        let repo = PgTwoFactorRepository::new(db.pool.clone());
+
+        repo.save_pending(1, "SECRETA").await.unwrap();
+        repo.save_pending(1, "SECRETB").await.unwrap();
+        repo.enable(1, 100, vec!["old".to_string()]).await.unwrap();
+        let enabled = repo.enable(1, 101, vec!["h1".to_string(), "h2".to_string()]).await.unwrap();
+        assert_eq!(enabled.secret, "SECRETB");
+        assert_eq!(enabled.last_used_step, Some(101));
+
+        assert!(repo.save_pending(1, "SECRETC").await.is_err());
+        assert!(!repo.consume_recovery_code(1, "old").await.unwrap());
+        assert!(repo.consume_recovery_code(1, "h1").await.unwrap());
+        assert!(!repo.consume_recovery_code(1, "h1").await.unwrap());
+
+        let verified = repo.record_verification(1, None).await.unwrap().unwrap();
+        assert_eq!(verified.last_used_step, Some(101));
+        assert!(repo.record_verification(1, Some(101)).await.unwrap().is_none());
+        assert_eq!(repo.record_verification(1, Some(102)).await.unwrap().unwrap().last_used_step, Some(102));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_replace_secret_only_touches_unencrypted_rows() {
+        let db = test_db().await;
+        let repo = PgTwoFactorRepository::new(db.pool.clone());
+        repo.save_pending(1, "SECRETA").await.unwrap();
+        repo.save_pending(2, "v1:sealed").await.unwrap();
+
+        let plaintext = repo.find_unencrypted().await.unwrap();
+        assert_eq!(plaintext.iter().map(|row| row.admin_id).collect::<Vec<_>>(), vec![1]);
+        assert!(!repo.replace_secret(1, "SECRETB", "v1:other").await.unwrap());
+        assert!(repo.replace_secret(1, "SECRETA", "v1:new").await.unwrap());
+        assert!(repo.find_unencrypted().await.unwrap().is_empty());
+    }
+}
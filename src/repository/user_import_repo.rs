@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::model::event::DomainEvent;
+use crate::model::user_import::InvitedUser;
+use crate::errors::AppError;
+use crate::repository::retry::{with_retry, RetryPolicy};
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UserImportRepository: Send + Sync {
+    /// Gives each new account a profile named after the imported row and enqueues its
+    /// invitation, in one transaction. A profile the user already has is kept.
+    async fn invite(&self, users: Vec<InvitedUser>) -> Result<(), AppError>;
+}
+
+pub struct PgUserImportRepository {
+    pool: PgPool,
+}
+
+impl PgUserImportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgUserImportRepository { pool }
+    }
+}
+
+#[async_trait]
+impl UserImportRepository for PgUserImportRepository {
+    async fn invite(&self, users: Vec<InvitedUser>) -> Result<(), AppError> {
+        let pool = &self.pool;
+        let users = &users;
+        with_retry(&RetryPolicy::default(), || async move {
+            let mut tx = pool.begin().await?;
+            for user in users {
+                // Profile display names are capped at 50 characters.
+                sqlx::query(
+                    "INSERT INTO profiles (user_id, display_name) VALUES ($1, LEFT($2, 50)) \
+                     ON CONFLICT (user_id) DO NOTHING",
+                )
+                .bind(user.user_id)
+                .bind(&user.name)
+                .execute(&mut *tx)
+                .await?;
+                enqueue_event(
+                    &mut tx,
+                    &DomainEvent::UserInvited {
+                        user_id: user.user_id,
+                        email: user.email.clone(),
+                        name: user.name.clone(),
+                    },
+                )
+                .await?;
+            }
+            tx.commit().await?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_invite_creates_missing_profiles_and_enqueues_invitations() {
+        let db = test_db().await;
+        sqlx::query("INSERT INTO profiles (user_id, display_name) VALUES (100, 'Rina Sari')")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgUserImportRepository::new(db.pool.clone());
+
+        repo.invite(vec![
+            InvitedUser { user_id: 100, email: "rina@example.com".to_string(), name: "Rina".to_string() },
+            InvitedUser { user_id: 101, email: "budi@example.com".to_string(), name: "Budi".to_string() },
+        ])
+        .await
+        .unwrap();
+
+        let names: Vec<(i32, String)> = sqlx::query_as("SELECT user_id, display_name FROM profiles ORDER BY user_id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(names, vec![(100, "Rina Sari".to_string()), (101, "Budi".to_string())]);
+        let invited: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM outbox WHERE event_type = 'user_invited'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(invited, 2);
+    }
+}
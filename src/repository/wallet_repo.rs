@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::errors::AppError;
+use crate::model::admin_action::BalanceAdjustmentAudit;
+use crate::model::transaction::Transaction;
+
+#[cfg(test)]
+use mockall::automock;
+
+// Held funds stay in `balance` but are excluded from the available amount,
+// so they can't be spent twice while a withdrawal is under review.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait WalletRepository: Send + Sync {
+    async fn available_balance(&self, user_id: i32) -> Result<f64, AppError>;
+    /// Adds `amount` (negative to deduct) to the balance and records `audit` with it.
+    /// Returns false instead of leaving less than the held funds in the wallet.
+    async fn adjust_balance(&self, user_id: i32, amount: f64, audit: BalanceAdjustmentAudit) -> Result<bool, AppError>;
+    /// Credits a provider-confirmed top-up and records it in `transactions`, creating
+    /// the wallet if needed. A `(provider, reference)` already recorded returns the
+    /// earlier transaction without crediting again.
+    async fn top_up(&self, user_id: i32, amount: f64, provider: &str, reference: &str) -> Result<Transaction, AppError>;
+}
+
+pub struct PgWalletRepository {
+    pool: PgPool,
+}
+
+impl PgWalletRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgWalletRepository { pool }
+    }
+}
+
+#[async_trait]
+impl WalletRepository for PgWalletRepository {
+    async fn available_balance(&self, user_id: i32) -> Result<f64, AppError> {
+        let balance: Option<f64> = sqlx::query_scalar(
+            "SELECT balance - held_amount FROM wallets WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        balance.ok_or_else(|| AppError::NotFound("Wallet not found".to_string()))
+    }
+
+    async fn adjust_balance(&self, user_id: i32, amount: f64, audit: BalanceAdjustmentAudit) -> Result<bool, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query(
+            "UPDATE wallets SET balance = balance + $2 \
+             WHERE user_id = $1 AND balance + $2 >= held_amount",
+        )
+        .bind(user_id)
+        .bind(amount)
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "INSERT INTO balance_adjustments \
+             (user_id, amount, reason, requested_by, approved_by, admin_action_id) \
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(user_id)
+        .bind(amount)
+        .bind(&audit.reason)
+        .bind(audit.requested_by)
+        .bind(audit.approved_by)
+        .bind(audit.admin_action_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn top_up(&self, user_id: i32, amount: f64, provider: &str, reference: &str) -> Result<Transaction, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let recorded: Option<Transaction> = sqlx::query_as(
+            "INSERT INTO transactions (user_id, transaction_type, amount, provider, reference) \
+             VALUES ($1, 'top_up', $2, $3, $4) \
+             ON CONFLICT (provider, reference) WHERE reference IS NOT NULL DO NOTHING \
+             RETURNING id, user_id, campaign_id, transaction_type, amount, created_at",
+        )
+        .bind(user_id)
+        .bind(amount)
+        .bind(provider)
+        .bind(reference)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(recorded) = recorded else {
+            let earlier = sqlx::query_as(
+                "SELECT id, user_id, campaign_id, transaction_type, amount, created_at \
+                 FROM transactions WHERE provider = $1 AND reference = $2",
+            )
+            .bind(provider)
+            .bind(reference)
+            .fetch_one(&mut *tx)
+            .await?;
+            return Ok(earlier);
+        };
+
+        sqlx::query(
+            "INSERT INTO wallets (user_id, balance) VALUES ($1, $2) \
+             ON CONFLICT (user_id) DO UPDATE SET balance = wallets.balance + EXCLUDED.balance",
+        )
+        .bind(user_id)
+        .bind(amount)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(recorded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transaction::TransactionType;
+    use crate::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_adjust_balance_records_who_and_why() {
+        let db = test_db().await;
+        sqlx::query("INSERT INTO wallets (user_id, balance, held_amount) VALUES (7, 1000, 400)")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let repo = PgWalletRepository::new(db.pool.clone());
+        let audit = BalanceAdjustmentAudit {
+            reason: "Chargeback".to_string(),
+            requested_by: 1,
+            approved_by: None,
+            admin_action_id: None,
+        };
+
+        assert!(repo.adjust_balance(7, -500.0, audit.clone()).await.unwrap());
+        assert_eq!(repo.available_balance(7).await.unwrap(), 100.0);
+        // Would leave less than the held 400: nothing changes and nothing is recorded.
+        assert!(!repo.adjust_balance(7, -200.0, audit).await.unwrap());
+        assert_eq!(repo.available_balance(7).await.unwrap(), 100.0);
+
+        let recorded: Vec<(f64, String, i32)> = sqlx::query_as(
+            "SELECT amount, reason, requested_by FROM balance_adjustments WHERE user_id = 7",
+        )
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(recorded, vec![(-500.0, "Chargeback".to_string(), 1)]);
+    }
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_top_up_credits_once_per_provider_reference() {
+        let db = test_db().await;
+        let repo = PgWalletRepository::new(db.pool.clone());
+
+        let first = repo.top_up(7, 250.0, "midtrans", "pay-1").await.unwrap();
+        let replay = repo.top_up(7, 250.0, "midtrans", "pay-1").await.unwrap();
+        let other = repo.top_up(7, 100.0, "xendit", "pay-1").await.unwrap();
+
+        assert_eq!(replay.id, first.id);
+        assert_ne!(other.id, first.id);
+        assert_eq!(first.transaction_type, TransactionType::TopUp);
+        assert_eq!(repo.available_balance(7).await.unwrap(), 350.0);
+    }
+}
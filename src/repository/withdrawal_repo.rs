@@ -0,0 +1,430 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use crate::model::withdrawal::{NewWithdrawalRequest, Withdrawal, WithdrawalAuditEntry, WithdrawalStatus};
+use crate::errors::AppError;
+use crate::model::event::DomainEvent;
+use crate::service::event_bus::enqueue_event;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait WithdrawalRepository: Send + Sync {
+    /// Inserts the pending withdrawal and enqueues `PayoutRequested` with it.
+    /// Holds the amount on the paying wallet and inserts the pending withdrawal in one
+    /// transaction. None if the wallet can't cover the amount.
+    async fn create(&self, user_id: i32, organization_id: Option<i32>, new_withdrawal: &NewWithdrawalRequest, required_approvals: i32) -> Result<Option<Withdrawal>, AppError>;
+    async fn find_by_id(&self, withdrawal_id: i32) -> Result<Option<Withdrawal>, AppError>;
+    /// Payouts from the user's own wallet; organization payouts they requested are left out.
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Withdrawal>, AppError>;
+    async fn find_by_organization(&self, organization_id: i32) -> Result<Vec<Withdrawal>, AppError>;
+    async fn find_by_status(&self, status: WithdrawalStatus) -> Result<Vec<Withdrawal>, AppError>;
+    /// Pending withdrawals that still need an approval `admin_id` hasn't given yet.
+    async fn find_awaiting_approval(&self, admin_id: i32) -> Result<Vec<Withdrawal>, AppError>;
+    async fn find_audit_log(&self, withdrawal_id: i32) -> Result<Vec<WithdrawalAuditEntry>, AppError>;
+    /// Records one approval (`admin_id` is `None` for an automatic one) and approves the
+    /// withdrawal once it has as many as it requires, settling its hold and enqueuing
+    /// `PayoutApproved` in the same transaction. Returns None if it isn't pending.
+    async fn approve(&self, withdrawal_id: i32, admin_id: Option<i32>, note: Option<String>) -> Result<Option<Withdrawal>, AppError>;
+    /// Rejects the withdrawal and releases its hold in the same transaction.
+    async fn reject(&self, withdrawal_id: i32, admin_id: i32, note: Option<String>) -> Result<Option<Withdrawal>, AppError>;
+}
+
+pub struct PgWithdrawalRepository {
+    pool: PgPool,
+}
+
+impl PgWithdrawalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgWithdrawalRepository { pool }
+    }
+}
+
+async fn record_audit(
+    conn: &mut PgConnection,
+    withdrawal_id: i32,
+    actor_id: Option<i32>,
+    action: &str,
+    note: Option<&str>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO withdrawal_audit_log (withdrawal_id, actor_id, action, note) VALUES ($1, $2, $3, $4)",
+    )
+    .bind(withdrawal_id)
+    .bind(actor_id)
+    .bind(action)
+    .bind(note)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HoldOutcome {
+    Settle,
+    Release,
+}
+
+// Organization payouts are held on the organization's wallet rather than on the wallet
+// of the member who requested them. Returns false if the wallet can't cover the amount.
+async fn place_hold(conn: &mut PgConnection, user_id: i32, organization_id: Option<i32>, amount: f64) -> Result<bool, AppError> {
+    let (sql, owner_id) = match organization_id {
+        None => (
+            "UPDATE wallets SET held_amount = held_amount + $2 \
+             WHERE user_id = $1 AND balance - held_amount >= $2",
+            user_id,
+        ),
+        Some(organization_id) => (
+            "UPDATE organization_wallets SET held_amount = held_amount + $2 \
+             WHERE organization_id = $1 AND balance - held_amount >= $2",
+            organization_id,
+        ),
+    };
+    let held = sqlx::query(sql)
+        .bind(owner_id)
+        .bind(amount)
+        .execute(conn)
+        .await?;
+    Ok(held.rows_affected() == 1)
+}
+
+async fn close_hold(conn: &mut PgConnection, withdrawal: &Withdrawal, outcome: HoldOutcome) -> Result<(), AppError> {
+    let (sql, owner_id) = match (withdrawal.organization_id, outcome) {
+        (None, HoldOutcome::Settle) => (
+            "UPDATE wallets SET balance = balance - $2, held_amount = held_amount - $2 WHERE user_id = $1",
+            withdrawal.user_id,
+        ),
+        (None, HoldOutcome::Release) => (
+            "UPDATE wallets SET held_amount = held_amount - $2 WHERE user_id = $1",
+            withdrawal.user_id,
+        ),
+        (Some(organization_id), HoldOutcome::Settle) => (
+            "UPDATE organization_wallets SET balance = balance - $2, held_amount = held_amount - $2 WHERE organization_id = $1",
+            organization_id,
+        ),
+        (Some(organization_id), HoldOutcome::Release) => (
+            "UPDATE organization_wallets SET held_amount = held_amount - $2 WHERE organization_id = $1",
+            organization_id,
+        ),
+    };
+    sqlx::query(sql)
+        .bind(owner_id)
+        .bind(withdrawal.amount)
+        .execute(&mut *conn)
+        .await?;
+
+    // The ledger is per user, so only personal payouts are recorded there; organization
+    // wallets keep their history in the withdrawal audit log.
+    if let (None, HoldOutcome::Settle) = (withdrawal.organization_id, outcome) {
+        sqlx::query(
+            "INSERT INTO transactions (user_id, transaction_type, amount) VALUES ($1, 'withdrawal', $2)",
+        )
+        .bind(withdrawal.user_id)
+        .bind(withdrawal.amount)
+        .execute(conn)
+        .await?;
+    }
+    Ok(())
+}
+
+// Locks the withdrawal so concurrent approvals are counted one at a time.
+async fn lock_pending(conn: &mut PgConnection, withdrawal_id: i32) -> Result<bool, AppError> {
+    let pending: Option<i32> = sqlx::query_scalar(
+        "SELECT id FROM withdrawals WHERE id = $1 AND status = 'pending' FOR UPDATE",
+    )
+    .bind(withdrawal_id)
+    .fetch_optional(conn)
+    .await?;
+    Ok(pending.is_some())
+}
+
+#[async_trait]
+impl WithdrawalRepository for PgWithdrawalRepository {
+    async fn create(&self, user_id: i32, organization_id: Option<i32>, new_withdrawal: &NewWithdrawalRequest, required_approvals: i32) -> Result<Option<Withdrawal>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        if !place_hold(&mut tx, user_id, organization_id, new_withdrawal.amount).await? {
+            return Ok(None);
+        }
+        let withdrawal = sqlx::query_as::<_, Withdrawal>(
+            "INSERT INTO withdrawals (user_id, amount, bank_name, account_number, account_holder, status, required_approvals, organization_id) \
+             VALUES ($1, $2, $3, $4, $5, 'pending', $6, $7) RETURNING *",
+        )
+        .bind(user_id)
+        .bind(new_withdrawal.amount)
+        .bind(&new_withdrawal.bank_name)
+        .bind(&new_withdrawal.account_number)
+        .bind(&new_withdrawal.account_holder)
+        .bind(required_approvals)
+        .bind(organization_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let event = DomainEvent::PayoutRequested {
+            withdrawal_id: withdrawal.id,
+            user_id: withdrawal.user_id,
+            amount: withdrawal.amount,
+        };
+        enqueue_event(&mut tx, &event).await?;
+        tx.commit().await?;
+        Ok(Some(withdrawal))
+    }
+
+    async fn find_by_id(&self, withdrawal_id: i32) -> Result<Option<Withdrawal>, AppError> {
+        let withdrawal = sqlx::query_as::<_, Withdrawal>("SELECT * FROM withdrawals WHERE id = $1")
+            .bind(withdrawal_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(withdrawal)
+    }
+
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Withdrawal>, AppError> {
+        let withdrawals = sqlx::query_as::<_, Withdrawal>(
+            "SELECT * FROM withdrawals WHERE user_id = $1 AND organization_id IS NULL ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(withdrawals)
+    }
+
+    async fn find_by_organization(&self, organization_id: i32) -> Result<Vec<Withdrawal>, AppError> {
+        let withdrawals = sqlx::query_as::<_, Withdrawal>(
+            "SELECT * FROM withdrawals WHERE organization_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(withdrawals)
+    }
+
+    async fn find_by_status(&self, status: WithdrawalStatus) -> Result<Vec<Withdrawal>, AppError> {
+        let withdrawals = sqlx::query_as::<_, Withdrawal>(
+            "SELECT * FROM withdrawals WHERE status = $1 ORDER BY created_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(withdrawals)
+    }
+
+    async fn find_awaiting_approval(&self, admin_id: i32) -> Result<Vec<Withdrawal>, AppError> {
+        let withdrawals = sqlx::query_as::<_, Withdrawal>(
+            "SELECT * FROM withdrawals w WHERE w.status = 'pending' \
+             AND NOT EXISTS ( \
+                 SELECT 1 FROM withdrawal_audit_log a \
+                 WHERE a.withdrawal_id = w.id AND a.actor_id = $1 AND a.action = 'approved' \
+             ) \
+             ORDER BY w.created_at ASC",
+        )
+        .bind(admin_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(withdrawals)
+    }
+
+    async fn find_audit_log(&self, withdrawal_id: i32) -> Result<Vec<WithdrawalAuditEntry>, AppError> {
+        let entries = sqlx::query_as::<_, WithdrawalAuditEntry>(
+            "SELECT * FROM withdrawal_audit_log WHERE withdrawal_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(withdrawal_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(entries)
+    }
+
+    async fn approve(&self, withdrawal_id: i32, admin_id: Option<i32>, note: Option<String>) -> Result<Option<Withdrawal>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        if !lock_pending(&mut tx, withdrawal_id).await? {
+            return Ok(None);
+        }
+
+        let action = match admin_id {
+            Some(admin_id) => {
+                let already_approved: bool = sqlx::query_scalar(
+                    "SELECT EXISTS (SELECT 1 FROM withdrawal_audit_log \
+                     WHERE withdrawal_id = $1 AND actor_id = $2 AND action = 'approved')",
+                )
+                .bind(withdrawal_id)
+                .bind(admin_id)
+                .fetch_one(&mut *tx)
+                .await?;
+                if already_approved {
+                    return Err(AppError::ValidationError("You have already approved this withdrawal".to_string()));
+                }
+                "approved"
+            }
+            None => "auto_approved",
+        };
+        record_audit(&mut tx, withdrawal_id, admin_id, action, note.as_deref()).await?;
+
+        let withdrawal = sqlx::query_as::<_, Withdrawal>(
+            "UPDATE withdrawals SET approval_count = approval_count + 1, \
+                 admin_note = COALESCE($2, admin_note), \
+                 status = CASE WHEN approval_count + 1 >= required_approvals THEN 'approved'::withdrawal_status ELSE status END, \
+                 reviewed_at = CASE WHEN approval_count + 1 >= required_approvals THEN NOW() ELSE reviewed_at END \
+             WHERE id = $1 RETURNING *",
+        )
+        .bind(withdrawal_id)
+        .bind(&note)
+        .fetch_one(&mut *tx)
+        .await?;
+        if withdrawal.status == WithdrawalStatus::Approved {
+            close_hold(&mut tx, &withdrawal, HoldOutcome::Settle).await?;
+            let event = DomainEvent::PayoutApproved {
+                withdrawal_id: withdrawal.id,
+                user_id: withdrawal.user_id,
+                amount: withdrawal.amount,
+            };
+            enqueue_event(&mut tx, &event).await?;
+        }
+
+        tx.commit().await?;
+        Ok(Some(withdrawal))
+    }
+
+    // A single rejection is final, even if other admins already approved.
+    async fn reject(&self, withdrawal_id: i32, admin_id: i32, note: Option<String>) -> Result<Option<Withdrawal>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        if !lock_pending(&mut tx, withdrawal_id).await? {
+            return Ok(None);
+        }
+        record_audit(&mut tx, withdrawal_id, Some(admin_id), "rejected", note.as_deref()).await?;
+
+        let withdrawal = sqlx::query_as::<_, Withdrawal>(
+            "UPDATE withdrawals SET status = 'rejected', admin_note = $2, reviewed_at = NOW() \
+             WHERE id = $1 RETURNING *",
+        )
+        .bind(withdrawal_id)
+        .bind(&note)
+        .fetch_one(&mut *tx)
+        .await?;
+        close_hold(&mut tx, &withdrawal, HoldOutcome::Release).await?;
+
+        tx.commit().await?;
+        Ok(Some(withdrawal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    fn request(amount: f64) -> NewWithdrawalRequest {
+        NewWithdrawalRequest {
+            amount,
+            bank_name: "BCA".to_string(),
+            account_number: "1234567890".to_string(),
+            account_holder: "Budi".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_dual_approval_needs_two_different_admins() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (7, 100000000);
+             INSERT INTO organizations (id, name, registration_number, created_by) VALUES (5, 'Yayasan', 'AHU-1', 7);
+             INSERT INTO organization_wallets (organization_id, balance) VALUES (5, 5000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgWithdrawalRepository::new(db.pool.clone());
+        let withdrawal = repo.create(7, None, &request(75_000_000.0), 2).await.unwrap().unwrap();
+
+        let first = repo.approve(withdrawal.id, Some(1), None).await.unwrap().unwrap();
+        assert_eq!(first.status, WithdrawalStatus::Pending);
+        assert_eq!(first.approval_count, 1);
+        assert!(repo.approve(withdrawal.id, Some(1), None).await.is_err());
+        assert!(repo.find_awaiting_approval(1).await.unwrap().is_empty());
+        assert_eq!(repo.find_awaiting_approval(2).await.unwrap().len(), 1);
+
+        let second = repo.approve(withdrawal.id, Some(2), Some("Checked".to_string())).await.unwrap().unwrap();
+        assert_eq!(second.status, WithdrawalStatus::Approved);
+        assert!(second.reviewed_at.is_some());
+        assert!(repo.reject(withdrawal.id, 3, None).await.unwrap().is_none());
+
+        let actions: Vec<(Option<i32>, String)> = repo
+            .find_audit_log(withdrawal.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.actor_id, entry.action))
+            .collect();
+        assert_eq!(actions, vec![(Some(1), "approved".to_string()), (Some(2), "approved".to_string())]);
+
+        let small = repo.create(7, None, &request(50_000.0), 0).await.unwrap().unwrap();
+        let auto = repo.approve(small.id, None, None).await.unwrap().unwrap();
+        assert_eq!(auto.status, WithdrawalStatus::Approved);
+        assert_eq!(repo.find_audit_log(small.id).await.unwrap()[0].action, "auto_approved");
+
+        let org_payout = repo.create(7, Some(5), &request(1_000.0), 1).await.unwrap().unwrap();
+        assert_eq!(repo.find_by_organization(5).await.unwrap()[0].id, org_payout.id);
+        assert_eq!(repo.find_by_user(7).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_review_settles_or_releases_the_hold() {
+        let db = test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO wallets (user_id, balance) VALUES (7, 1000);
+             INSERT INTO organizations (id, name, registration_number, created_by) VALUES (5, 'Yayasan', 'AHU-1', 7);
+             INSERT INTO organization_wallets (organization_id, balance) VALUES (5, 2000);",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let repo = PgWithdrawalRepository::new(db.pool.clone());
+        let approved = repo.create(7, None, &request(300.0), 1).await.unwrap().unwrap();
+        let rejected = repo.create(7, None, &request(200.0), 1).await.unwrap().unwrap();
+        let org_payout = repo.create(7, Some(5), &request(400.0), 2).await.unwrap().unwrap();
+        // 500 of the wallet's 1000 is now held, so this can't be covered and isn't inserted.
+        assert!(repo.create(7, None, &request(600.0), 1).await.unwrap().is_none());
+        let held: f64 = sqlx::query_scalar("SELECT held_amount FROM wallets WHERE user_id = 7")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(held, 500.0);
+
+        repo.approve(approved.id, Some(1), None).await.unwrap().unwrap();
+        repo.reject(rejected.id, 1, None).await.unwrap().unwrap();
+        // The first of two approvals leaves the organization's hold in place.
+        repo.approve(org_payout.id, Some(1), None).await.unwrap().unwrap();
+        let org_wallet: (f64, f64) =
+            sqlx::query_as("SELECT balance, held_amount FROM organization_wallets WHERE organization_id = 5")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(org_wallet, (2000.0, 400.0));
+        repo.approve(org_payout.id, Some(2), None).await.unwrap().unwrap();
+
+        let wallet: (f64, f64) = sqlx::query_as("SELECT balance, held_amount FROM wallets WHERE user_id = 7")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(wallet, (700.0, 0.0));
+        let org_wallet: (f64, f64) =
+            sqlx::query_as("SELECT balance, held_amount FROM organization_wallets WHERE organization_id = 5")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(org_wallet, (1600.0, 0.0));
+        let events: Vec<(String, i64)> = sqlx::query_as(
+            "SELECT event_type, COUNT(*) FROM outbox GROUP BY event_type ORDER BY event_type",
+        )
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(events, vec![("payout_approved".to_string(), 2), ("payout_requested".to_string(), 3)]);
+        let ledger: Vec<(i32, f64)> = sqlx::query_as(
+            "SELECT user_id, amount FROM transactions WHERE transaction_type = 'withdrawal'",
+        )
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(ledger, vec![(7, 300.0)]);
+    }
+}
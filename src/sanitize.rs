@@ -0,0 +1,97 @@
+use std::collections::{HashMap, HashSet};
+
+use ammonia::Builder;
+use pulldown_cmark::{Options, Parser, html};
+use serde::Serialize;
+
+const ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "h2",
+    "h3",
+    "h4",
+    "hr",
+    "i",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "strong",
+    "u",
+    "ul",
+];
+const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+const LINK_REL: &str = "noopener noreferrer nofollow";
+
+/// A user-written description as submitted, plus the HTML that is safe to render.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RichText {
+    pub raw: String,
+    pub html: String,
+}
+
+impl RichText {
+    pub fn from_raw(raw: &str) -> Self {
+        RichText {
+            raw: raw.to_string(),
+            html: sanitize_description(raw),
+        }
+    }
+}
+
+/// Renders Markdown (inline HTML included) and strips everything outside the allowlist:
+/// no scripts, styles, images, event handlers or non-http(s)/mailto links.
+pub fn sanitize_description(raw: &str) -> String {
+    let mut rendered = String::with_capacity(raw.len() * 3 / 2);
+    let options = Options::ENABLE_STRIKETHROUGH;
+    html::push_html(&mut rendered, Parser::new_ext(raw, options));
+
+    Builder::default()
+        .tags(ALLOWED_TAGS.iter().copied().collect::<HashSet<_>>())
+        .tag_attributes(HashMap::from([("a", HashSet::from(["href"]))]))
+        .generic_attributes(HashSet::new())
+        .url_schemes(ALLOWED_URL_SCHEMES.iter().copied().collect::<HashSet<_>>())
+        .link_rel(Some(LINK_REL))
+        .clean(&rendered)
+        .to_string()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_is_rendered_and_links_are_hardened() {
+        let html = sanitize_description("**Bantu** [kami](https://example.org) ~~sekarang~~");
+        assert_eq!(
+            html,
+            "<p><strong>Bantu</strong> <a href=\"https://example.org\" rel=\"noopener noreferrer nofollow\">kami</a> <del>sekarang</del></p>"
+        );
+    }
+
+    #[test]
+    fn test_disallowed_markup_is_stripped() {
+        let html = sanitize_description(
+            "<p onclick=\"steal()\" style=\"color:red\">Hi</p><script>alert(1)</script>\
+             <img src=x onerror=alert(1)><a href=\"javascript:alert(1)\">x</a>",
+        );
+        assert_eq!(
+            html,
+            "<p>Hi</p><a rel=\"noopener noreferrer nofollow\">x</a>"
+        );
+
+        let rich = RichText::from_raw("<iframe src=\"https://evil.example\"></iframe>plain");
+        assert_eq!(
+            rich.raw,
+            "<iframe src=\"https://evil.example\"></iframe>plain"
+        );
+        assert!(!rich.html.contains("iframe"));
+    }
+}
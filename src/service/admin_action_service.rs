@@ -0,0 +1,568 @@
+use crate::errors::AppError;
+use crate::model::admin_action::{
+    AdminActionKind, AdminActionStatus, BalanceAdjustmentAudit, PendingAdminAction,
+};
+use crate::repository::admin_action_repo::AdminActionRepository;
+use crate::repository::campaign_deletion_repo::CampaignDeletionRepository;
+use crate::repository::wallet_repo::WalletRepository;
+use crate::service::background_job::run_job;
+use crate::service::donation_service::DonationService;
+use crate::service::two_factor_service::TwoFactorService;
+use std::sync::Arc;
+
+pub const DEFAULT_ADJUSTMENT_CAP: f64 = 1_000_000.0;
+
+/// Four-eyes control for destructive admin operations: the requesting admin queues
+/// the action and a different admin has to approve it before it runs.
+pub struct AdminActionService {
+    action_repo: Arc<dyn AdminActionRepository>,
+    deletion_repo: Arc<dyn CampaignDeletionRepository>,
+    wallet_repo: Arc<dyn WalletRepository>,
+    donation_service: Arc<DonationService>,
+    two_factor: Option<Arc<TwoFactorService>>,
+    adjustment_cap: f64,
+}
+
+impl AdminActionService {
+    pub fn new(
+        action_repo: Arc<dyn AdminActionRepository>,
+        deletion_repo: Arc<dyn CampaignDeletionRepository>,
+        wallet_repo: Arc<dyn WalletRepository>,
+        donation_service: Arc<DonationService>,
+    ) -> Self {
+        AdminActionService {
+            action_repo,
+            deletion_repo,
+            wallet_repo,
+            donation_service,
+            two_factor: None,
+            adjustment_cap: DEFAULT_ADJUSTMENT_CAP,
+        }
+    }
+
+    pub fn with_two_factor(mut self, two_factor: Arc<TwoFactorService>) -> Self {
+        self.two_factor = Some(two_factor);
+        self
+    }
+
+    pub fn with_adjustment_cap(mut self, adjustment_cap: f64) -> Self {
+        self.adjustment_cap = adjustment_cap;
+        self
+    }
+
+    /// Deletes a campaign straight away if it has no donations; otherwise queues the
+    /// deletion for a second admin and returns the pending action.
+    pub async fn delete_campaign(
+        &self,
+        campaign_id: i32,
+        admin_id: i32,
+    ) -> Result<Option<PendingAdminAction>, AppError> {
+        let donations = self
+            .deletion_repo
+            .count_donations(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+        if donations == 0 {
+            self.run_delete_campaign(campaign_id).await?;
+            return Ok(None);
+        }
+        self.queue(
+            AdminActionKind::DeleteCampaign,
+            campaign_id,
+            None,
+            None,
+            admin_id,
+        )
+        .await
+        .map(Some)
+    }
+
+    /// Applies adjustments up to the cap straight away and queues larger ones. Either
+    /// way the admin needs a recent second factor, and the reason is recorded.
+    pub async fn adjust_balance(
+        &self,
+        user_id: i32,
+        amount: f64,
+        reason: String,
+        admin_id: i32,
+    ) -> Result<Option<PendingAdminAction>, AppError> {
+        if amount == 0.0 || !amount.is_finite() {
+            return Err(AppError::ValidationError(
+                "Adjustment amount must not be zero".to_string(),
+            ));
+        }
+        self.require_step_up(admin_id).await?;
+        // Fails early with NotFound rather than queueing an action that can't run.
+        self.wallet_repo.available_balance(user_id).await?;
+        if amount.abs() <= self.adjustment_cap {
+            let audit = BalanceAdjustmentAudit {
+                reason,
+                requested_by: admin_id,
+                approved_by: None,
+                admin_action_id: None,
+            };
+            self.run_adjust_balance(user_id, amount, audit).await?;
+            return Ok(None);
+        }
+        self.queue(
+            AdminActionKind::AdjustBalance,
+            user_id,
+            Some(amount),
+            Some(reason),
+            admin_id,
+        )
+        .await
+        .map(Some)
+    }
+
+    pub async fn refund_all_donations(
+        &self,
+        campaign_id: i32,
+        admin_id: i32,
+    ) -> Result<PendingAdminAction, AppError> {
+        self.queue(
+            AdminActionKind::RefundAllDonations,
+            campaign_id,
+            None,
+            None,
+            admin_id,
+        )
+        .await
+    }
+
+    pub async fn get_actions(
+        &self,
+        status: AdminActionStatus,
+    ) -> Result<Vec<PendingAdminAction>, AppError> {
+        self.action_repo.find_by_status(status).await
+    }
+
+    /// Runs the action. If running it fails the action is marked failed, so it has to
+    /// be requested again rather than being retried by another approval. Refunding a
+    /// campaign's donations runs in the background, so that approval returns first.
+    pub async fn approve(
+        &self,
+        action_id: i32,
+        admin_id: i32,
+    ) -> Result<PendingAdminAction, AppError> {
+        let action = self.find(action_id).await?;
+        if action.requested_by == admin_id {
+            return Err(AppError::Forbidden(
+                "A different admin must confirm this action".to_string(),
+            ));
+        }
+        self.require_step_up(admin_id).await?;
+        let approved = self
+            .action_repo
+            .decide(action_id, admin_id, AdminActionStatus::Approved, None)
+            .await?
+            .ok_or_else(already_decided)?;
+
+        if let Err(e) = self.run(&approved).await {
+            self.action_repo
+                .mark_failed(approved.id, e.to_string())
+                .await?;
+            return Err(e);
+        }
+        Ok(approved)
+    }
+
+    pub async fn decline(
+        &self,
+        action_id: i32,
+        admin_id: i32,
+        note: Option<String>,
+    ) -> Result<PendingAdminAction, AppError> {
+        self.find(action_id).await?;
+        self.action_repo
+            .decide(action_id, admin_id, AdminActionStatus::Declined, note)
+            .await?
+            .ok_or_else(already_decided)
+    }
+
+    async fn require_step_up(&self, admin_id: i32) -> Result<(), AppError> {
+        if let Some(two_factor) = &self.two_factor {
+            two_factor.require_recent_second_factor(admin_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn find(&self, action_id: i32) -> Result<PendingAdminAction, AppError> {
+        self.action_repo
+            .find_by_id(action_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Admin action not found".to_string()))
+    }
+
+    async fn queue(
+        &self,
+        kind: AdminActionKind,
+        target_id: i32,
+        amount: Option<f64>,
+        reason: Option<String>,
+        admin_id: i32,
+    ) -> Result<PendingAdminAction, AppError> {
+        self.action_repo
+            .create(kind, target_id, amount, reason, admin_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::ValidationError(
+                    "This action is already waiting for a second admin".to_string(),
+                )
+            })
+    }
+
+    async fn run(&self, action: &PendingAdminAction) -> Result<(), AppError> {
+        match action.kind {
+            AdminActionKind::DeleteCampaign => self.run_delete_campaign(action.target_id).await,
+            AdminActionKind::AdjustBalance => {
+                let amount = action.amount.unwrap_or_default();
+                let audit = BalanceAdjustmentAudit {
+                    reason: action.reason.clone().unwrap_or_default(),
+                    requested_by: action.requested_by,
+                    approved_by: action.decided_by,
+                    admin_action_id: Some(action.id),
+                };
+                self.run_adjust_balance(action.target_id, amount, audit)
+                    .await
+            }
+            AdminActionKind::RefundAllDonations => {
+                let donation_service = self.donation_service.clone();
+                let action_repo = self.action_repo.clone();
+                let (action_id, campaign_id) = (action.id, action.target_id);
+                rocket::tokio::spawn(async move {
+                    run_job("refund_campaign_donations", async move {
+                        let refunded = donation_service
+                            .refund_campaign_donations(campaign_id)
+                            .await;
+                        if let Err(e) = &refunded {
+                            action_repo.mark_failed(action_id, e.to_string()).await?;
+                        }
+                        refunded
+                    })
+                    .await;
+                });
+                Ok(())
+            }
+        }
+    }
+
+    async fn run_delete_campaign(&self, campaign_id: i32) -> Result<(), AppError> {
+        if !self.deletion_repo.delete(campaign_id).await? {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn run_adjust_balance(
+        &self,
+        user_id: i32,
+        amount: f64,
+        audit: BalanceAdjustmentAudit,
+    ) -> Result<(), AppError> {
+        if !self
+            .wallet_repo
+            .adjust_balance(user_id, amount, audit)
+            .await?
+        {
+            return Err(AppError::ValidationError(
+                "Adjustment would leave the wallet below its held funds".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn already_decided() -> AppError {
+    AppError::ValidationError("Admin action has already been decided".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{
+        admin_action_repo::MockAdminActionRepository,
+        campaign_deletion_repo::MockCampaignDeletionRepository,
+        campaign_repo::MockCampaignRepository, donation_repo::MockDonationRepository,
+        two_factor_repo::MockTwoFactorRepository, wallet_repo::MockWalletRepository,
+    };
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn action(kind: AdminActionKind, amount: Option<f64>) -> PendingAdminAction {
+        PendingAdminAction {
+            id: 1,
+            kind,
+            target_id: 7,
+            amount,
+            reason: None,
+            status: AdminActionStatus::Pending,
+            requested_by: 10,
+            decided_by: None,
+            decision_note: None,
+            created_at: Utc::now(),
+            decided_at: None,
+        }
+    }
+
+    fn service(
+        action_repo: MockAdminActionRepository,
+        deletion_repo: MockCampaignDeletionRepository,
+        wallet_repo: MockWalletRepository,
+    ) -> AdminActionService {
+        service_with_donations(
+            action_repo,
+            deletion_repo,
+            wallet_repo,
+            MockDonationRepository::new(),
+        )
+    }
+
+    fn service_with_donations(
+        action_repo: MockAdminActionRepository,
+        deletion_repo: MockCampaignDeletionRepository,
+        wallet_repo: MockWalletRepository,
+        donation_repo: MockDonationRepository,
+    ) -> AdminActionService {
+        let donation_service = DonationService::new(
+            Arc::new(donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        AdminActionService::new(
+            Arc::new(action_repo),
+            Arc::new(deletion_repo),
+            Arc::new(wallet_repo),
+            Arc::new(donation_service),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_campaign_without_donations_is_deleted_directly() {
+        let mut mock_deletion_repo = MockCampaignDeletionRepository::new();
+        mock_deletion_repo
+            .expect_count_donations()
+            .returning(|_| Ok(Some(0)));
+        mock_deletion_repo
+            .expect_delete()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(true));
+        let mut mock_action_repo = MockAdminActionRepository::new();
+        mock_action_repo.expect_create().times(0);
+
+        let service = service(
+            mock_action_repo,
+            mock_deletion_repo,
+            MockWalletRepository::new(),
+        );
+        assert!(service.delete_campaign(7, 10).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_adjustment_above_cap_is_queued() {
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo
+            .expect_available_balance()
+            .returning(|_| Ok(0.0));
+        mock_wallet_repo.expect_adjust_balance().times(0);
+        let mut mock_action_repo = MockAdminActionRepository::new();
+        mock_action_repo
+            .expect_create()
+            .withf(|kind, target_id, amount, _, requested_by| {
+                *kind == AdminActionKind::AdjustBalance
+                    && *target_id == 7
+                    && *amount == Some(-2_000_000.0)
+                    && *requested_by == 10
+            })
+            .times(1)
+            .returning(|kind, _, amount, _, _| Ok(Some(action(kind, amount))));
+
+        let service = service(
+            mock_action_repo,
+            MockCampaignDeletionRepository::new(),
+            mock_wallet_repo,
+        );
+        let queued = service
+            .adjust_balance(7, -2_000_000.0, "Chargeback".to_string(), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(queued.unwrap().status, AdminActionStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_adjustment_below_cap_records_reason_and_admin() {
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo
+            .expect_available_balance()
+            .returning(|_| Ok(0.0));
+        mock_wallet_repo
+            .expect_adjust_balance()
+            .with(
+                eq(7),
+                eq(500.0),
+                eq(BalanceAdjustmentAudit {
+                    reason: "Goodwill credit".to_string(),
+                    requested_by: 10,
+                    approved_by: None,
+                    admin_action_id: None,
+                }),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let service = service(
+            MockAdminActionRepository::new(),
+            MockCampaignDeletionRepository::new(),
+            mock_wallet_repo,
+        );
+        let queued = service
+            .adjust_balance(7, 500.0, "Goodwill credit".to_string(), 10)
+            .await
+            .unwrap();
+
+        assert!(queued.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_adjustment_requires_step_up() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .with(eq(10))
+            .returning(|_| Ok(None));
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo.expect_adjust_balance().times(0);
+
+        let service = service(
+            MockAdminActionRepository::new(),
+            MockCampaignDeletionRepository::new(),
+            mock_wallet_repo,
+        )
+        .with_two_factor(Arc::new(TwoFactorService::new(
+            Arc::new(mock_two_factor_repo),
+            &[0; 32],
+        )));
+        let result = service
+            .adjust_balance(7, 500.0, "Goodwill credit".to_string(), 10)
+            .await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("two-factor")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refund_all_runs_after_approval_returns() {
+        let mut mock_action_repo = MockAdminActionRepository::new();
+        mock_action_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(action(AdminActionKind::RefundAllDonations, None))));
+        mock_action_repo
+            .expect_decide()
+            .returning(|_, _, status, _| {
+                Ok(Some(PendingAdminAction {
+                    status,
+                    ..action(AdminActionKind::RefundAllDonations, None)
+                }))
+            });
+        let (failed_tx, mut failed_rx) = tokio::sync::mpsc::unbounded_channel();
+        mock_action_repo
+            .expect_mark_failed()
+            .withf(|id, error| *id == 1 && error.contains("connection reset"))
+            .times(1)
+            .returning(move |_, _| {
+                failed_tx.send(()).unwrap();
+                Ok(())
+            });
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_refund_batch()
+            .returning(|_, _, _| {
+                Err(AppError::InternalServerError(
+                    "connection reset".to_string(),
+                ))
+            });
+
+        let service = service_with_donations(
+            mock_action_repo,
+            MockCampaignDeletionRepository::new(),
+            MockWalletRepository::new(),
+            mock_donation_repo,
+        );
+        let approved = service.approve(1, 20).await.unwrap();
+
+        assert_eq!(approved.status, AdminActionStatus::Approved);
+        failed_rx.recv().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_requester_cannot_approve_own_action() {
+        let mut mock_action_repo = MockAdminActionRepository::new();
+        mock_action_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(action(AdminActionKind::RefundAllDonations, None))));
+        mock_action_repo.expect_decide().times(0);
+
+        let service = service(
+            mock_action_repo,
+            MockCampaignDeletionRepository::new(),
+            MockWalletRepository::new(),
+        );
+        let result = service.approve(1, 10).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("different admin")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_marks_action_failed() {
+        let mut mock_action_repo = MockAdminActionRepository::new();
+        mock_action_repo.expect_find_by_id().returning(|_| {
+            Ok(Some(action(
+                AdminActionKind::AdjustBalance,
+                Some(-5_000_000.0),
+            )))
+        });
+        mock_action_repo
+            .expect_decide()
+            .with(
+                eq(1),
+                eq(20),
+                eq(AdminActionStatus::Approved),
+                eq(None::<String>),
+            )
+            .returning(|_, _, status, _| {
+                Ok(Some(PendingAdminAction {
+                    status,
+                    decided_by: Some(20),
+                    ..action(AdminActionKind::AdjustBalance, Some(-5_000_000.0))
+                }))
+            });
+        mock_action_repo
+            .expect_mark_failed()
+            .withf(|id, error| *id == 1 && error.contains("held funds"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo
+            .expect_adjust_balance()
+            .withf(|user_id, amount, audit| {
+                *user_id == 7
+                    && *amount == -5_000_000.0
+                    && audit.approved_by == Some(20)
+                    && audit.admin_action_id == Some(1)
+            })
+            .returning(|_, _, _| Ok(false));
+
+        let service = service(
+            mock_action_repo,
+            MockCampaignDeletionRepository::new(),
+            mock_wallet_repo,
+        );
+        assert!(service.approve(1, 20).await.is_err());
+    }
+}
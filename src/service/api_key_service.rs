@@ -0,0 +1,194 @@
+use crate::errors::AppError;
+use crate::model::api_key::{ApiKeyRecord, ApiKeyScope, CreateApiKeyRequest, MintedApiKey};
+use crate::repository::api_key_repo::ApiKeyRepository;
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const API_KEY_PREFIX: &str = "ak_";
+// Keys used by frequent cron jobs would otherwise write on every request.
+const LAST_USED_RESOLUTION: Duration = Duration::minutes(1);
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+pub struct ApiKeyService {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyService {
+    pub fn new(api_key_repo: Arc<dyn ApiKeyRepository>) -> Self {
+        ApiKeyService { api_key_repo }
+    }
+
+    pub async fn mint(
+        &self,
+        admin_id: i32,
+        request: CreateApiKeyRequest,
+    ) -> Result<MintedApiKey, AppError> {
+        // Two v4 UUIDs give 244 random bits from the OS generator.
+        let secret = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let key = format!("{}{}", API_KEY_PREFIX, secret);
+        let record = self
+            .api_key_repo
+            .create(
+                request.name.trim(),
+                &secret[..8],
+                &hash_api_key(&key),
+                &request.scopes,
+                admin_id,
+            )
+            .await?;
+        Ok(MintedApiKey { key, record })
+    }
+
+    pub async fn list(&self) -> Result<Vec<ApiKeyRecord>, AppError> {
+        self.api_key_repo.find_all().await
+    }
+
+    pub async fn revoke(&self, id: i32) -> Result<ApiKeyRecord, AppError> {
+        self.api_key_repo
+            .revoke(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("API key not found".to_string()))
+    }
+
+    pub async fn authenticate(
+        &self,
+        key: &str,
+        scope: ApiKeyScope,
+    ) -> Result<ApiKeyRecord, AppError> {
+        if !key.starts_with(API_KEY_PREFIX) {
+            return Err(AppError::Unauthorized);
+        }
+        let record = self
+            .api_key_repo
+            .find_active_by_hash(&hash_api_key(key))
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+
+        if !record.has_scope(scope) {
+            return Err(AppError::Forbidden(
+                "API key does not have the required scope".to_string(),
+            ));
+        }
+
+        let stale = record
+            .last_used_at
+            .is_none_or(|used| Utc::now() - used > LAST_USED_RESOLUTION);
+        if stale {
+            self.api_key_repo.touch_last_used(record.id).await?;
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::api_key_repo::MockApiKeyRepository;
+    use mockall::predicate::*;
+
+    fn record(scopes: Vec<ApiKeyScope>) -> ApiKeyRecord {
+        ApiKeyRecord {
+            id: 7,
+            name: "dashboard".to_string(),
+            key_prefix: "1a2b3c4d".to_string(),
+            key_hash: String::new(),
+            scopes,
+            created_by: 1,
+            created_at: Utc::now(),
+            last_used_at: None,
+            revoked_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mint_stores_only_the_hash() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+        mock_api_key_repo
+            .expect_create()
+            .withf(|name, prefix, hash, scopes, created_by| {
+                name == "dashboard"
+                    && prefix.len() == 8
+                    && hash.len() == 64
+                    && scopes == [ApiKeyScope::StatsRead]
+                    && *created_by == 1
+            })
+            .returning(|_, prefix, hash, scopes, _| {
+                let mut record = record(scopes.to_vec());
+                record.key_prefix = prefix.to_string();
+                record.key_hash = hash.to_string();
+                Ok(record)
+            });
+        let service = ApiKeyService::new(Arc::new(mock_api_key_repo));
+
+        let minted = service
+            .mint(
+                1,
+                CreateApiKeyRequest {
+                    name: " dashboard ".to_string(),
+                    scopes: vec![ApiKeyScope::StatsRead],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(minted.key.starts_with(API_KEY_PREFIX));
+        assert!(minted.key[API_KEY_PREFIX.len()..].starts_with(&minted.record.key_prefix));
+        assert_eq!(minted.record.key_hash, hash_api_key(&minted.key));
+        assert!(!minted.record.key_hash.contains(&minted.key));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_checks_scope_and_tracks_use() {
+        let key = "ak_0123456789abcdef";
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+        mock_api_key_repo
+            .expect_find_active_by_hash()
+            .with(eq(hash_api_key(key)))
+            .returning(|_| Ok(Some(record(vec![ApiKeyScope::StatsRead]))));
+        mock_api_key_repo
+            .expect_touch_last_used()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(()));
+        let service = ApiKeyService::new(Arc::new(mock_api_key_repo));
+
+        assert_eq!(
+            service
+                .authenticate(key, ApiKeyScope::StatsRead)
+                .await
+                .unwrap()
+                .id,
+            7
+        );
+        match service
+            .authenticate(key, ApiKeyScope::NotificationsWrite)
+            .await
+            .err()
+            .unwrap()
+        {
+            AppError::Forbidden(msg) => assert!(msg.contains("required scope")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_or_revoked_key_is_unauthorized() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+        mock_api_key_repo
+            .expect_find_active_by_hash()
+            .returning(|_| Ok(None));
+        let service = ApiKeyService::new(Arc::new(mock_api_key_repo));
+
+        for key in ["ak_revoked", "Bearer something-else"] {
+            assert!(matches!(
+                service.authenticate(key, ApiKeyScope::StatsRead).await,
+                Err(AppError::Unauthorized)
+            ));
+        }
+    }
+}
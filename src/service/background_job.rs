@@ -0,0 +1,32 @@
+use crate::errors::AppError;
+use std::future::Future;
+use std::time::Instant;
+
+/// Runs one pass of a background job and logs how it went and how long it took, so
+/// jobs show up in the same structured logs as requests. Failures are logged rather
+/// than returned: the job's loop carries on with the next pass either way.
+pub async fn run_job<T>(
+    job: &'static str,
+    run: impl Future<Output = Result<T, AppError>>,
+) -> Option<T> {
+    let started = Instant::now();
+    let result = run.await;
+    let latency_ms = started.elapsed().as_micros() as f64 / 1000.0;
+    match result {
+        Ok(value) => {
+            tracing::info!(job, latency_ms, "Background job {} finished", job);
+            Some(value)
+        }
+        Err(e) => {
+            tracing::error!(
+                job,
+                latency_ms,
+                error = %e,
+                error_code = e.code(),
+                "Background job {} failed",
+                job
+            );
+            None
+        }
+    }
+}
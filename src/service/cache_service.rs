@@ -0,0 +1,25 @@
+use crate::model::cache::{CacheScope, CacheStats};
+use crate::repository::donation_cache::{CacheInvalidator, DonationCache};
+use std::sync::Arc;
+
+pub struct CacheService {
+    donation_cache: Arc<DonationCache>,
+}
+
+impl CacheService {
+    pub fn new(donation_cache: Arc<DonationCache>) -> Self {
+        CacheService { donation_cache }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.donation_cache.stats()
+    }
+
+    pub fn flush(&self, scope: Option<CacheScope>) -> CacheStats {
+        match scope {
+            Some(scope) => self.donation_cache.flush_scope(scope),
+            None => self.donation_cache.flush_all(),
+        }
+        self.donation_cache.stats()
+    }
+}
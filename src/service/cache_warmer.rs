@@ -0,0 +1,207 @@
+use crate::errors::AppError;
+use crate::repository::donation_cache::DonationCache;
+use crate::repository::donation_repo::DonationRepository;
+use crate::service::background_job::run_job;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct CacheWarmupConfig {
+    pub top_campaigns: i64,
+    pub max_attempts: u32,
+    pub retry_delay: Duration,
+}
+
+impl Default for CacheWarmupConfig {
+    fn default() -> Self {
+        CacheWarmupConfig {
+            top_campaigns: 100,
+            max_attempts: 5,
+            retry_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+pub struct CacheWarmer {
+    donation_repo: Arc<dyn DonationRepository>,
+    donation_cache: Arc<DonationCache>,
+    config: CacheWarmupConfig,
+    ready: AtomicBool,
+}
+
+impl CacheWarmer {
+    pub fn new(
+        donation_repo: Arc<dyn DonationRepository>,
+        donation_cache: Arc<DonationCache>,
+        config: CacheWarmupConfig,
+    ) -> Self {
+        CacheWarmer {
+            donation_repo,
+            donation_cache,
+            config,
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            run_job("donation_cache_warm_up", self.warm_with_retry()).await;
+        });
+    }
+
+    pub async fn warm_with_retry(&self) -> Result<(), AppError> {
+        let mut attempt = 1;
+        loop {
+            match self.warm().await {
+                Ok(()) => {
+                    self.ready.store(true, Ordering::Release);
+                    return Ok(());
+                }
+                Err(e) if attempt < self.config.max_attempts => {
+                    tracing::warn!(attempt, error = %e, "Donation cache warm-up attempt failed");
+                    rocket::tokio::time::sleep(self.config.retry_delay * attempt).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn warm(&self) -> Result<(), AppError> {
+        let campaign_totals = self
+            .donation_repo
+            .top_campaign_totals(self.config.top_campaigns)
+            .await?;
+        let campaign_ids: Vec<i32> = campaign_totals.iter().map(|t| t.campaign_id).collect();
+        let user_totals = self
+            .donation_repo
+            .user_totals_for_campaigns(campaign_ids)
+            .await?;
+
+        for total in campaign_totals {
+            self.donation_cache
+                .set_campaign_total(total.campaign_id, total.total_amount);
+        }
+        for total in user_totals {
+            self.donation_cache.set_user_campaign_total(
+                total.user_id,
+                total.campaign_id,
+                total.total_amount,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::{CampaignDonationTotal, UserCampaignTotal};
+    use crate::repository::donation_repo::MockDonationRepository;
+    use mockall::Sequence;
+    use mockall::predicate::*;
+
+    fn config(max_attempts: u32) -> CacheWarmupConfig {
+        CacheWarmupConfig {
+            top_campaigns: 2,
+            max_attempts,
+            retry_delay: Duration::ZERO,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_populates_top_campaigns_and_marks_ready() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_top_campaign_totals()
+            .with(eq(2))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![CampaignDonationTotal {
+                    campaign_id: 10,
+                    total_amount: 150.0,
+                    donation_count: 3,
+                }])
+            });
+        mock_donation_repo
+            .expect_user_totals_for_campaigns()
+            .with(eq(vec![10]))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![UserCampaignTotal {
+                    user_id: 1,
+                    campaign_id: 10,
+                    total_amount: 100.0,
+                }])
+            });
+
+        let cache = Arc::new(DonationCache::new());
+        let warmer = CacheWarmer::new(Arc::new(mock_donation_repo), cache.clone(), config(3));
+        assert!(!warmer.is_ready());
+
+        warmer.warm_with_retry().await.unwrap();
+
+        assert!(warmer.is_ready());
+        assert_eq!(cache.campaign_total(10), Some(150.0));
+        assert_eq!(cache.user_campaign_total(1, 10), Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_warm_retries_after_failure() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut seq = Sequence::new();
+        mock_donation_repo
+            .expect_top_campaign_totals()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| {
+                Err(AppError::InternalServerError(
+                    "connection reset".to_string(),
+                ))
+            });
+        mock_donation_repo
+            .expect_top_campaign_totals()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(vec![]));
+        mock_donation_repo
+            .expect_user_totals_for_campaigns()
+            .returning(|_| Ok(vec![]));
+
+        let warmer = CacheWarmer::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(DonationCache::new()),
+            config(3),
+        );
+
+        assert!(warmer.warm_with_retry().await.is_ok());
+        assert!(warmer.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_warm_gives_up_after_max_attempts() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_top_campaign_totals()
+            .times(2)
+            .returning(|_| {
+                Err(AppError::InternalServerError(
+                    "connection reset".to_string(),
+                ))
+            });
+
+        let warmer = CacheWarmer::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(DonationCache::new()),
+            config(2),
+        );
+
+        assert!(warmer.warm_with_retry().await.is_err());
+        assert!(!warmer.is_ready());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::errors::AppError;
+use crate::model::campaign_budget::{
+    CampaignBudget, DeclareBudgetRequest, NewSpendReportRequest, SpendReport,
+};
+use crate::repository::campaign_budget_repo::CampaignBudgetRepository;
+use std::sync::Arc;
+
+pub struct CampaignBudgetService {
+    budget_repo: Arc<dyn CampaignBudgetRepository>,
+}
+
+impl CampaignBudgetService {
+    pub fn new(budget_repo: Arc<dyn CampaignBudgetRepository>) -> Self {
+        CampaignBudgetService { budget_repo }
+    }
+
+    pub async fn get_budget(&self, campaign_id: i32) -> Result<CampaignBudget, AppError> {
+        self.budget_repo
+            .find_budget(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    pub async fn declare_budget(
+        &self,
+        campaign_id: i32,
+        req: DeclareBudgetRequest,
+    ) -> Result<CampaignBudget, AppError> {
+        self.budget_repo.declare(campaign_id, req.items).await?;
+        self.get_budget(campaign_id).await
+    }
+
+    pub async fn add_spend_report(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        req: NewSpendReportRequest,
+    ) -> Result<SpendReport, AppError> {
+        self.budget_repo
+            .add_report(campaign_id, user_id, &req)
+            .await
+    }
+
+    /// When required, the fundraiser can't request another payout until every approved
+    /// one has a spend report; see `WithdrawalService::with_spend_report_check`.
+    pub async fn set_reports_required(
+        &self,
+        campaign_id: i32,
+        required: bool,
+    ) -> Result<CampaignBudget, AppError> {
+        if !self
+            .budget_repo
+            .set_reports_required(campaign_id, required)
+            .await?
+        {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        }
+        self.get_budget(campaign_id).await
+    }
+}
@@ -0,0 +1,228 @@
+use crate::errors::AppError;
+use crate::model::campaign_feed::{CampaignFeedItem, CampaignFeedPage};
+use crate::repository::campaign_feed_repo::CampaignFeedRepository;
+use dashmap::DashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+pub const FEED_CACHE_TTL: Duration = Duration::from_secs(60);
+pub const FEED_PER_PAGE: i64 = 100;
+pub const ALMOST_FUNDED_RATIO: f64 = 0.8;
+pub const DISCOVERY_LIMIT: i64 = 12;
+// The sitemap protocol caps a single file at 50,000 URLs.
+const SITEMAP_MAX_URLS: i64 = 50_000;
+
+#[derive(Debug, Clone)]
+pub struct CampaignFeedConfig {
+    pub public_base_url: String,
+    pub cache_ttl: Duration,
+}
+
+// Both endpoints are public and unauthenticated, so results are cached briefly
+// instead of hitting the campaigns table on every crawler request.
+pub struct CampaignFeedService {
+    feed_repo: Arc<dyn CampaignFeedRepository>,
+    config: CampaignFeedConfig,
+    pages: DashMap<(i64, Option<String>), (Instant, CampaignFeedPage)>,
+    sitemap: RwLock<Option<(Instant, String)>>,
+    almost_funded: RwLock<Option<(Instant, Vec<CampaignFeedItem>)>>,
+}
+
+impl CampaignFeedService {
+    pub fn new(feed_repo: Arc<dyn CampaignFeedRepository>, config: CampaignFeedConfig) -> Self {
+        CampaignFeedService {
+            feed_repo,
+            config,
+            pages: DashMap::new(),
+            sitemap: RwLock::new(None),
+            almost_funded: RwLock::new(None),
+        }
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        self.config.cache_ttl
+    }
+
+    fn campaign_url(&self, campaign_id: i32) -> String {
+        format!(
+            "{}/campaigns/{}",
+            self.config.public_base_url.trim_end_matches('/'),
+            campaign_id
+        )
+    }
+
+    fn is_fresh(&self, cached_at: Instant) -> bool {
+        cached_at.elapsed() < self.config.cache_ttl
+    }
+
+    /// One page of active campaigns, optionally only those in `region`.
+    pub async fn get_feed_page(
+        &self,
+        page: Option<i64>,
+        region: Option<&str>,
+    ) -> Result<CampaignFeedPage, AppError> {
+        let page = page.unwrap_or(1).max(1);
+        // Case and surrounding spaces don't matter, so they share one cache entry.
+        let region = region
+            .map(|region| region.trim().to_lowercase())
+            .filter(|region| !region.is_empty());
+        let key = (page, region.clone());
+        if let Some(cached) = self.pages.get(&key)
+            && self.is_fresh(cached.0) {
+                return Ok(cached.1.clone());
+            }
+
+        let total = self.feed_repo.count_active(region.clone()).await?;
+        let mut items = self
+            .feed_repo
+            .find_active(region, FEED_PER_PAGE, (page - 1) * FEED_PER_PAGE)
+            .await?;
+        for item in &mut items {
+            item.url = self.campaign_url(item.id);
+        }
+
+        let feed_page = CampaignFeedPage {
+            items,
+            total,
+            page,
+            per_page: FEED_PER_PAGE,
+        };
+        self.pages.insert(key, (Instant::now(), feed_page.clone()));
+        Ok(feed_page)
+    }
+
+    pub async fn get_sitemap(&self) -> Result<String, AppError> {
+        if let Some((cached_at, xml)) = self.sitemap.read().unwrap().as_ref()
+            && self.is_fresh(*cached_at) {
+                return Ok(xml.clone());
+            }
+
+        let ids = self.feed_repo.find_active_ids(SITEMAP_MAX_URLS).await?;
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for id in ids {
+            xml.push_str("  <url><loc>");
+            xml.push_str(&escape_xml(&self.campaign_url(id)));
+            xml.push_str("</loc></url>\n");
+        }
+        xml.push_str("</urlset>\n");
+
+        *self.sitemap.write().unwrap() = Some((Instant::now(), xml.clone()));
+        Ok(xml)
+    }
+
+    /// Homepage list of active campaigns that have raised at least 80% of their target.
+    pub async fn get_almost_funded(&self) -> Result<Vec<CampaignFeedItem>, AppError> {
+        if let Some((cached_at, items)) = self.almost_funded.read().unwrap().as_ref()
+            && self.is_fresh(*cached_at) {
+                return Ok(items.clone());
+            }
+
+        let mut items = self
+            .feed_repo
+            .find_almost_funded(ALMOST_FUNDED_RATIO, DISCOVERY_LIMIT)
+            .await?;
+        for item in &mut items {
+            item.url = self.campaign_url(item.id);
+        }
+        *self.almost_funded.write().unwrap() = Some((Instant::now(), items.clone()));
+        Ok(items)
+    }
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_feed_repo::MockCampaignFeedRepository;
+    use mockall::predicate::*;
+
+    fn config(cache_ttl: Duration) -> CampaignFeedConfig {
+        CampaignFeedConfig {
+            public_base_url: "https://example.org/".to_string(),
+            cache_ttl,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feed_page_is_cached_within_ttl() {
+        let mut mock_feed_repo = MockCampaignFeedRepository::new();
+        mock_feed_repo
+            .expect_count_active()
+            .with(eq(Some("bali".to_string())))
+            .times(1)
+            .returning(|_| Ok(1));
+        mock_feed_repo
+            .expect_find_active()
+            .with(eq(Some("bali".to_string())), eq(FEED_PER_PAGE), eq(0))
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(vec![CampaignFeedItem {
+                    id: 10,
+                    title: "Clean water".to_string(),
+                    target_amount: 1_000_000.0,
+                    collected_amount: 250_000.0,
+                    region: Some("Bali".to_string()),
+                    url: String::new(),
+                }])
+            });
+
+        let service = CampaignFeedService::new(Arc::new(mock_feed_repo), config(FEED_CACHE_TTL));
+        let first = service.get_feed_page(None, Some("Bali")).await.unwrap();
+        let second = service.get_feed_page(Some(1), Some(" bali ")).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.items[0].url, "https://example.org/campaigns/10");
+    }
+
+    #[tokio::test]
+    async fn test_sitemap_lists_active_campaigns_and_refreshes_after_ttl() {
+        let mut mock_feed_repo = MockCampaignFeedRepository::new();
+        mock_feed_repo
+            .expect_find_active_ids()
+            .times(2)
+            .returning(|_| Ok(vec![10, 11]));
+
+        let service = CampaignFeedService::new(Arc::new(mock_feed_repo), config(Duration::ZERO));
+        let sitemap = service.get_sitemap().await.unwrap();
+        service.get_sitemap().await.unwrap();
+
+        assert!(sitemap.contains("<loc>https://example.org/campaigns/10</loc>"));
+        assert!(sitemap.contains("<loc>https://example.org/campaigns/11</loc>"));
+        assert!(sitemap.ends_with("</urlset>\n"));
+    }
+
+    #[tokio::test]
+    async fn test_almost_funded_is_cached_and_linked() {
+        let mut mock_feed_repo = MockCampaignFeedRepository::new();
+        mock_feed_repo
+            .expect_find_almost_funded()
+            .with(eq(ALMOST_FUNDED_RATIO), eq(DISCOVERY_LIMIT))
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![CampaignFeedItem {
+                    id: 11,
+                    title: "School roof".to_string(),
+                    target_amount: 1_000_000.0,
+                    collected_amount: 950_000.0,
+                    region: None,
+                    url: String::new(),
+                }])
+            });
+
+        let service = CampaignFeedService::new(Arc::new(mock_feed_repo), config(FEED_CACHE_TTL));
+        let first = service.get_almost_funded().await.unwrap();
+        assert_eq!(service.get_almost_funded().await.unwrap(), first);
+        assert_eq!(first[0].url, "https://example.org/campaigns/11");
+    }
+}
@@ -0,0 +1,125 @@
+use crate::errors::AppError;
+use crate::model::campaign_image::{
+    AddCampaignImageRequest, CampaignImage, ReorderCampaignImagesRequest,
+};
+use crate::model::campaign_member::CampaignAction;
+use crate::repository::campaign_image_repo::CampaignImageRepository;
+use crate::service::campaign_member_service::CampaignMemberService;
+use std::sync::Arc;
+
+pub struct CampaignImageService {
+    image_repo: Arc<dyn CampaignImageRepository>,
+    member_service: Arc<CampaignMemberService>,
+}
+
+impl CampaignImageService {
+    pub fn new(
+        image_repo: Arc<dyn CampaignImageRepository>,
+        member_service: Arc<CampaignMemberService>,
+    ) -> Self {
+        CampaignImageService {
+            image_repo,
+            member_service,
+        }
+    }
+
+    pub async fn get_images(&self, campaign_id: i32) -> Result<Vec<CampaignImage>, AppError> {
+        self.image_repo.find_by_campaign(campaign_id).await
+    }
+
+    pub async fn add_image(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        image: AddCampaignImageRequest,
+    ) -> Result<CampaignImage, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        self.image_repo.add(campaign_id, &image.url).await
+    }
+
+    pub async fn remove_image(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        image_id: i32,
+    ) -> Result<(), AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        if !self.image_repo.remove(campaign_id, image_id).await? {
+            return Err(AppError::NotFound("Campaign image not found".to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn reorder_images(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        order: ReorderCampaignImagesRequest,
+    ) -> Result<Vec<CampaignImage>, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        self.image_repo.reorder(campaign_id, order.image_ids).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign_member::CampaignRole;
+    use crate::repository::campaign_image_repo::MockCampaignImageRepository;
+    use crate::repository::campaign_member_repo::MockCampaignMemberRepository;
+    use mockall::predicate::*;
+
+    fn member_service(role: Option<CampaignRole>) -> Arc<CampaignMemberService> {
+        let mut mock_member_repo = MockCampaignMemberRepository::new();
+        mock_member_repo
+            .expect_find_role()
+            .with(eq(10), eq(2))
+            .returning(move |_, _| Ok(role));
+        Arc::new(CampaignMemberService::new(Arc::new(mock_member_repo)))
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_add_images() {
+        let mut mock_image_repo = MockCampaignImageRepository::new();
+        mock_image_repo.expect_add().never();
+        let service = CampaignImageService::new(
+            Arc::new(mock_image_repo),
+            member_service(Some(CampaignRole::Viewer)),
+        );
+
+        let result = service
+            .add_image(
+                10,
+                2,
+                AddCampaignImageRequest {
+                    url: "https://cdn.example.org/a.png".to_string(),
+                },
+            )
+            .await;
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_remove_missing_image_is_not_found() {
+        let mut mock_image_repo = MockCampaignImageRepository::new();
+        mock_image_repo
+            .expect_remove()
+            .with(eq(10), eq(99))
+            .returning(|_, _| Ok(false));
+        let service = CampaignImageService::new(
+            Arc::new(mock_image_repo),
+            member_service(Some(CampaignRole::Owner)),
+        );
+
+        match service.remove_image(10, 2, 99).await.err().unwrap() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Campaign image not found"),
+            _ => panic!("Expected NotFound error"),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::errors::AppError;
+use crate::model::campaign_location::{
+    CampaignLocation, NearbyCampaign, UpdateCampaignLocationRequest,
+};
+use crate::repository::campaign_location_repo::CampaignLocationRepository;
+use std::sync::Arc;
+
+pub const DEFAULT_NEARBY_RADIUS_KM: f64 = 25.0;
+pub const MAX_NEARBY_RADIUS_KM: f64 = 500.0;
+pub const NEARBY_LIMIT: i64 = 50;
+
+pub struct CampaignLocationService {
+    location_repo: Arc<dyn CampaignLocationRepository>,
+}
+
+impl CampaignLocationService {
+    pub fn new(location_repo: Arc<dyn CampaignLocationRepository>) -> Self {
+        CampaignLocationService { location_repo }
+    }
+
+    pub async fn get_location(&self, campaign_id: i32) -> Result<CampaignLocation, AppError> {
+        self.location_repo
+            .find(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    pub async fn update_location(
+        &self,
+        campaign_id: i32,
+        req: UpdateCampaignLocationRequest,
+    ) -> Result<CampaignLocation, AppError> {
+        if req.latitude.is_some() != req.longitude.is_some() {
+            return Err(AppError::ValidationError(
+                "Latitude and longitude must be set together".to_string(),
+            ));
+        }
+        let region = req
+            .region
+            .map(|region| region.trim().to_string())
+            .filter(|region| !region.is_empty());
+        self.location_repo
+            .set(campaign_id, req.latitude, req.longitude, region)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    pub async fn find_nearby(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        radius_km: Option<f64>,
+    ) -> Result<Vec<NearbyCampaign>, AppError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(AppError::ValidationError(
+                "lat must be between -90 and 90 and lng between -180 and 180".to_string(),
+            ));
+        }
+        let radius_km = radius_km.unwrap_or(DEFAULT_NEARBY_RADIUS_KM);
+        if !(radius_km > 0.0 && radius_km <= MAX_NEARBY_RADIUS_KM) {
+            return Err(AppError::ValidationError(
+                "radius_km must be greater than 0 and at most 500".to_string(),
+            ));
+        }
+        self.location_repo
+            .find_nearby(latitude, longitude, radius_km, NEARBY_LIMIT)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_location_repo::MockCampaignLocationRepository;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_nearby_uses_default_radius_and_rejects_large_ones() {
+        let mut mock_location_repo = MockCampaignLocationRepository::new();
+        mock_location_repo
+            .expect_find_nearby()
+            .with(
+                eq(-6.2),
+                eq(106.8),
+                eq(DEFAULT_NEARBY_RADIUS_KM),
+                eq(NEARBY_LIMIT),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(vec![]));
+
+        let service = CampaignLocationService::new(Arc::new(mock_location_repo));
+        assert!(
+            service
+                .find_nearby(-6.2, 106.8, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        match service.find_nearby(-6.2, 106.8, Some(1000.0)).await {
+            Err(AppError::ValidationError(msg)) => assert!(msg.contains("radius_km")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_location_requires_both_coordinates() {
+        let mut mock_location_repo = MockCampaignLocationRepository::new();
+        mock_location_repo.expect_set().times(0);
+
+        let service = CampaignLocationService::new(Arc::new(mock_location_repo));
+        let req = UpdateCampaignLocationRequest {
+            latitude: Some(-6.2),
+            longitude: None,
+            region: Some("DKI Jakarta".to_string()),
+        };
+        let result = service.update_location(10, req).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("set together")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+}
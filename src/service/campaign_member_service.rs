@@ -0,0 +1,189 @@
+use crate::errors::AppError;
+use crate::model::campaign_member::{
+    AddCampaignMemberRequest, CampaignAction, CampaignMember, CampaignRole,
+};
+use crate::repository::campaign_member_repo::CampaignMemberRepository;
+use std::sync::Arc;
+
+pub struct CampaignMemberService {
+    member_repo: Arc<dyn CampaignMemberRepository>,
+}
+
+impl CampaignMemberService {
+    pub fn new(member_repo: Arc<dyn CampaignMemberRepository>) -> Self {
+        CampaignMemberService { member_repo }
+    }
+
+    /// Membership check for campaign writes; campaign updates, evidence uploads and
+    /// update posts should go through this instead of comparing the campaign's owner id.
+    pub async fn authorize(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        action: CampaignAction,
+    ) -> Result<CampaignRole, AppError> {
+        let role = self
+            .member_repo
+            .find_role(campaign_id, user_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Forbidden("You are not a member of this campaign".to_string())
+            })?;
+
+        if !role.allows(action) {
+            return Err(AppError::Forbidden(
+                "Your campaign role does not allow this action".to_string(),
+            ));
+        }
+        Ok(role)
+    }
+
+    pub async fn get_members(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+    ) -> Result<Vec<CampaignMember>, AppError> {
+        self.authorize(campaign_id, user_id, CampaignAction::View)
+            .await?;
+        self.member_repo.find_by_campaign(campaign_id).await
+    }
+
+    pub async fn add_member(
+        &self,
+        campaign_id: i32,
+        actor_id: i32,
+        member: AddCampaignMemberRequest,
+    ) -> Result<CampaignMember, AppError> {
+        self.authorize(campaign_id, actor_id, CampaignAction::ManageMembers)
+            .await?;
+        self.member_repo
+            .upsert(campaign_id, member.user_id, member.role, actor_id)
+            .await
+    }
+
+    pub async fn remove_member(
+        &self,
+        campaign_id: i32,
+        actor_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        self.authorize(campaign_id, actor_id, CampaignAction::ManageMembers)
+            .await?;
+        if self.member_repo.remove(campaign_id, user_id).await? == 0 {
+            return Err(AppError::NotFound("Campaign member not found".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_member_repo::MockCampaignMemberRepository;
+    use mockall::predicate::*;
+
+    fn repo_with_role(role: Option<CampaignRole>) -> MockCampaignMemberRepository {
+        let mut mock_member_repo = MockCampaignMemberRepository::new();
+        mock_member_repo
+            .expect_find_role()
+            .with(eq(10), eq(2))
+            .returning(move |_, _| Ok(role));
+        mock_member_repo
+    }
+
+    #[tokio::test]
+    async fn test_editor_can_post_updates_but_not_manage_members() {
+        let service =
+            CampaignMemberService::new(Arc::new(repo_with_role(Some(CampaignRole::Editor))));
+
+        assert!(
+            service
+                .authorize(10, 2, CampaignAction::PostUpdate)
+                .await
+                .is_ok()
+        );
+        match service
+            .authorize(10, 2, CampaignAction::ManageMembers)
+            .await
+            .err()
+            .unwrap()
+        {
+            AppError::Forbidden(msg) => assert!(msg.contains("does not allow")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_owner_reads_private_notes() {
+        let owner =
+            CampaignMemberService::new(Arc::new(repo_with_role(Some(CampaignRole::Owner))));
+        assert!(
+            owner
+                .authorize(10, 2, CampaignAction::ReadPrivateNotes)
+                .await
+                .is_ok()
+        );
+
+        let editor =
+            CampaignMemberService::new(Arc::new(repo_with_role(Some(CampaignRole::Editor))));
+        let result = editor
+            .authorize(10, 2, CampaignAction::ReadPrivateNotes)
+            .await;
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_upload_evidence() {
+        let service =
+            CampaignMemberService::new(Arc::new(repo_with_role(Some(CampaignRole::Viewer))));
+        let result = service
+            .authorize(10, 2, CampaignAction::UploadEvidence)
+            .await;
+
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_non_member_forbidden() {
+        let service = CampaignMemberService::new(Arc::new(repo_with_role(None)));
+        let result = service.get_members(10, 2).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("not a member")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_owner_adds_member() {
+        let mut mock_member_repo = repo_with_role(Some(CampaignRole::Owner));
+        mock_member_repo
+            .expect_upsert()
+            .with(eq(10), eq(3), eq(CampaignRole::Editor), eq(2))
+            .times(1)
+            .returning(|campaign_id, user_id, role, added_by| {
+                Ok(CampaignMember {
+                    campaign_id,
+                    user_id,
+                    role,
+                    added_by,
+                    created_at: chrono::Utc::now(),
+                })
+            });
+
+        let service = CampaignMemberService::new(Arc::new(mock_member_repo));
+        let member = service
+            .add_member(
+                10,
+                2,
+                AddCampaignMemberRequest {
+                    user_id: 3,
+                    role: CampaignRole::Editor,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(member.role, CampaignRole::Editor);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::errors::AppError;
+use crate::model::campaign_overflow::{CampaignOverflowSettings, UpdateOverflowPolicyRequest};
+use crate::repository::campaign_overflow_repo::CampaignOverflowRepository;
+use std::sync::Arc;
+
+pub struct CampaignOverflowService {
+    overflow_repo: Arc<dyn CampaignOverflowRepository>,
+}
+
+impl CampaignOverflowService {
+    pub fn new(overflow_repo: Arc<dyn CampaignOverflowRepository>) -> Self {
+        CampaignOverflowService { overflow_repo }
+    }
+
+    pub async fn get_settings(
+        &self,
+        campaign_id: i32,
+    ) -> Result<CampaignOverflowSettings, AppError> {
+        self.overflow_repo
+            .find(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    pub async fn update_policy(
+        &self,
+        campaign_id: i32,
+        req: UpdateOverflowPolicyRequest,
+    ) -> Result<CampaignOverflowSettings, AppError> {
+        self.overflow_repo
+            .set_policy(campaign_id, req.overflow_policy)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+}
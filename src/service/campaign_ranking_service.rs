@@ -0,0 +1,190 @@
+use crate::errors::AppError;
+use crate::model::campaign_ranking::{RecommendedCampaign, TrendingCampaign};
+use crate::repository::campaign_ranking_repo::CampaignRankingRepository;
+use crate::service::background_job::run_job;
+use dashmap::DashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+pub const TRENDING_LIMIT: i64 = 20;
+pub const RECOMMENDED_LIMIT: i64 = 20;
+
+#[derive(Debug, Clone)]
+pub struct CampaignRankingConfig {
+    pub half_life_hours: f64,
+    pub window_days: i32,
+    /// Donation amount one share is worth in the score.
+    pub share_weight: f64,
+    pub refresh_interval: Duration,
+    pub cache_ttl: Duration,
+}
+
+impl Default for CampaignRankingConfig {
+    fn default() -> Self {
+        CampaignRankingConfig {
+            half_life_hours: 48.0,
+            window_days: 14,
+            share_weight: 10_000.0,
+            refresh_interval: Duration::from_secs(15 * 60),
+            cache_ttl: Duration::from_secs(30),
+        }
+    }
+}
+
+// Rankings are rebuilt on a schedule; reads only hit the small rankings table and
+// are cached briefly on top of that.
+pub struct CampaignRankingService {
+    ranking_repo: Arc<dyn CampaignRankingRepository>,
+    config: CampaignRankingConfig,
+    trending: RwLock<Option<(Instant, Vec<TrendingCampaign>)>>,
+    recommended: DashMap<i32, (Instant, Vec<RecommendedCampaign>)>,
+}
+
+impl CampaignRankingService {
+    pub fn new(
+        ranking_repo: Arc<dyn CampaignRankingRepository>,
+        config: CampaignRankingConfig,
+    ) -> Self {
+        CampaignRankingService {
+            ranking_repo,
+            config,
+            trending: RwLock::new(None),
+            recommended: DashMap::new(),
+        }
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        self.config.cache_ttl
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("campaign_ranking_refresh", self.refresh()).await;
+                rocket::tokio::time::sleep(self.config.refresh_interval).await;
+            }
+        });
+    }
+
+    pub async fn refresh(&self) -> Result<u64, AppError> {
+        let ranked = self
+            .ranking_repo
+            .recompute(
+                self.config.half_life_hours,
+                self.config.window_days,
+                self.config.share_weight,
+            )
+            .await?;
+        *self.trending.write().unwrap() = None;
+        self.recommended.clear();
+        Ok(ranked)
+    }
+
+    pub async fn get_trending(&self) -> Result<Vec<TrendingCampaign>, AppError> {
+        if let Some((cached_at, campaigns)) = self.trending.read().unwrap().as_ref()
+            && cached_at.elapsed() < self.config.cache_ttl {
+                return Ok(campaigns.clone());
+            }
+
+        let campaigns = self.ranking_repo.find_trending(TRENDING_LIMIT).await?;
+        *self.trending.write().unwrap() = Some((Instant::now(), campaigns.clone()));
+        Ok(campaigns)
+    }
+
+    /// Empty for users with no donations to go by.
+    pub async fn get_recommended(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<RecommendedCampaign>, AppError> {
+        if let Some(entry) = self.recommended.get(&user_id)
+            && entry.0.elapsed() < self.config.cache_ttl {
+                return Ok(entry.1.clone());
+            }
+
+        let campaigns = self
+            .ranking_repo
+            .find_recommended(user_id, RECOMMENDED_LIMIT)
+            .await?;
+        // Drop expired entries so users who stop asking don't stay cached.
+        let ttl = self.config.cache_ttl;
+        self.recommended
+            .retain(|_, (cached_at, _)| cached_at.elapsed() < ttl);
+        self.recommended
+            .insert(user_id, (Instant::now(), campaigns.clone()));
+        Ok(campaigns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_ranking_repo::MockCampaignRankingRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn trending(campaign_id: i32, score: f64) -> TrendingCampaign {
+        TrendingCampaign {
+            campaign_id,
+            title: format!("Campaign {}", campaign_id),
+            target_amount: 1_000_000.0,
+            collected_amount: 100_000.0,
+            score,
+            computed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trending_is_cached_until_next_refresh() {
+        let mut mock_ranking_repo = MockCampaignRankingRepository::new();
+        mock_ranking_repo
+            .expect_find_trending()
+            .with(eq(TRENDING_LIMIT))
+            .times(2)
+            .returning(|_| Ok(vec![trending(11, 98.6), trending(10, 50.0)]));
+        mock_ranking_repo
+            .expect_recompute()
+            .with(eq(48.0), eq(14), eq(10_000.0))
+            .times(1)
+            .returning(|_, _, _| Ok(2));
+        let service = CampaignRankingService::new(
+            Arc::new(mock_ranking_repo),
+            CampaignRankingConfig::default(),
+        );
+
+        let first = service.get_trending().await.unwrap();
+        assert_eq!(service.get_trending().await.unwrap(), first);
+        assert_eq!(service.refresh().await.unwrap(), 2);
+        assert_eq!(service.get_trending().await.unwrap()[0].campaign_id, 11);
+    }
+
+    #[tokio::test]
+    async fn test_recommendations_are_cached_per_user() {
+        let mut mock_ranking_repo = MockCampaignRankingRepository::new();
+        mock_ranking_repo
+            .expect_find_recommended()
+            .with(eq(1), eq(RECOMMENDED_LIMIT))
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![RecommendedCampaign {
+                    campaign_id: 11,
+                    title: "Campaign 11".to_string(),
+                    category: "health".to_string(),
+                    target_amount: 1_000_000.0,
+                    collected_amount: 100_000.0,
+                }])
+            });
+        mock_ranking_repo
+            .expect_find_recommended()
+            .with(eq(2), eq(RECOMMENDED_LIMIT))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        let service = CampaignRankingService::new(
+            Arc::new(mock_ranking_repo),
+            CampaignRankingConfig::default(),
+        );
+
+        assert_eq!(service.get_recommended(1).await.unwrap().len(), 1);
+        assert_eq!(service.get_recommended(1).await.unwrap().len(), 1);
+        assert!(service.get_recommended(2).await.unwrap().is_empty());
+    }
+}
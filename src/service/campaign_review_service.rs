@@ -0,0 +1,198 @@
+use crate::errors::AppError;
+use crate::model::campaign_review::{
+    BulkReviewAction, BulkReviewRequest, BulkReviewResult, CampaignReview, CampaignReviewItem,
+    SimilarCampaign, UpdateChecklistItemRequest,
+};
+use crate::repository::campaign_review_repo::CampaignReviewRepository;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// pg_trgm's own default threshold for "similar".
+pub const SIMILARITY_THRESHOLD: f64 = 0.3;
+pub const SIMILAR_CAMPAIGNS_LIMIT: i64 = 20;
+
+pub struct CampaignReviewService {
+    review_repo: Arc<dyn CampaignReviewRepository>,
+}
+
+impl CampaignReviewService {
+    pub fn new(review_repo: Arc<dyn CampaignReviewRepository>) -> Self {
+        CampaignReviewService { review_repo }
+    }
+
+    pub async fn get_review(&self, campaign_id: i32) -> Result<CampaignReview, AppError> {
+        let recorded = self
+            .review_repo
+            .find_items(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+        Ok(CampaignReview::from_recorded(campaign_id, recorded))
+    }
+
+    pub async fn record_item(
+        &self,
+        campaign_id: i32,
+        reviewer_id: i32,
+        req: UpdateChecklistItemRequest,
+    ) -> Result<CampaignReviewItem, AppError> {
+        self.review_repo
+            .record_item(campaign_id, reviewer_id, req.item, req.result, req.comment)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    /// Fails unless every checklist item has passed; see `CampaignReview::can_approve`.
+    pub async fn approve(&self, campaign_id: i32) -> Result<CampaignReview, AppError> {
+        self.review_repo.approve(campaign_id).await?;
+        self.get_review(campaign_id).await
+    }
+
+    /// The failed items and their comments are what the fundraiser is told to fix.
+    pub async fn reject(&self, campaign_id: i32) -> Result<CampaignReview, AppError> {
+        self.review_repo.reject(campaign_id).await?;
+        self.get_review(campaign_id).await
+    }
+
+    /// Approves or rejects each listed campaign and reports how each one went.
+    pub async fn bulk_review(
+        &self,
+        req: BulkReviewRequest,
+    ) -> Result<Vec<BulkReviewResult>, AppError> {
+        if req.action == BulkReviewAction::Reject && req.reason.is_none() {
+            return Err(AppError::ValidationError(
+                "A reason is required to reject campaigns".to_string(),
+            ));
+        }
+        let mut campaign_ids = req.campaign_ids;
+        let mut seen = HashSet::new();
+        campaign_ids.retain(|campaign_id| seen.insert(*campaign_id));
+        self.review_repo
+            .bulk_review(campaign_ids, req.action, req.reason)
+            .await
+    }
+
+    /// Near-duplicates of the campaign, for spotting copies of existing campaigns.
+    pub async fn get_similar_campaigns(
+        &self,
+        campaign_id: i32,
+    ) -> Result<Vec<SimilarCampaign>, AppError> {
+        self.review_repo
+            .find_similar(campaign_id, SIMILARITY_THRESHOLD, SIMILAR_CAMPAIGNS_LIMIT)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign_review::{CheckResult, ChecklistItem};
+    use crate::repository::campaign_review_repo::MockCampaignReviewRepository;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_review_of_unknown_campaign_is_not_found() {
+        let mut mock_review_repo = MockCampaignReviewRepository::new();
+        mock_review_repo
+            .expect_find_items()
+            .with(eq(99))
+            .returning(|_| Ok(None));
+        let service = CampaignReviewService::new(Arc::new(mock_review_repo));
+
+        assert!(matches!(
+            service.get_review(99).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejection_returns_failed_items() {
+        let mut mock_review_repo = MockCampaignReviewRepository::new();
+        mock_review_repo
+            .expect_reject()
+            .with(eq(10))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_review_repo.expect_find_items().returning(|_| {
+            Ok(Some(vec![CampaignReviewItem {
+                item: ChecklistItem::DocumentsValid,
+                result: CheckResult::Failed,
+                comment: Some("KTP scan is blurry".to_string()),
+                reviewer_id: Some(1),
+                updated_at: None,
+            }]))
+        });
+        let service = CampaignReviewService::new(Arc::new(mock_review_repo));
+
+        let review = service.reject(10).await.unwrap();
+        assert!(!review.can_approve);
+        assert_eq!(review.items[1].result, CheckResult::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_reject_needs_a_reason() {
+        let mut mock_review_repo = MockCampaignReviewRepository::new();
+        mock_review_repo.expect_bulk_review().times(0);
+        let service = CampaignReviewService::new(Arc::new(mock_review_repo));
+
+        let req = BulkReviewRequest {
+            campaign_ids: vec![10, 11],
+            action: BulkReviewAction::Reject,
+            reason: None,
+        };
+        assert!(matches!(
+            service.bulk_review(req).await,
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_review_skips_repeated_ids() {
+        let mut mock_review_repo = MockCampaignReviewRepository::new();
+        mock_review_repo
+            .expect_bulk_review()
+            .with(eq(vec![10, 11]), eq(BulkReviewAction::Approve), eq(None))
+            .times(1)
+            .returning(|campaign_ids, _, _| {
+                Ok(campaign_ids
+                    .into_iter()
+                    .map(|campaign_id| BulkReviewResult {
+                        campaign_id,
+                        error: None,
+                    })
+                    .collect())
+            });
+        let service = CampaignReviewService::new(Arc::new(mock_review_repo));
+
+        let req = BulkReviewRequest {
+            campaign_ids: vec![10, 11, 10],
+            action: BulkReviewAction::Approve,
+            reason: None,
+        };
+        assert_eq!(service.bulk_review(req).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_similar_campaigns_use_default_threshold() {
+        let mut mock_review_repo = MockCampaignReviewRepository::new();
+        mock_review_repo
+            .expect_find_similar()
+            .with(
+                eq(10),
+                eq(SIMILARITY_THRESHOLD),
+                eq(SIMILAR_CAMPAIGNS_LIMIT),
+            )
+            .returning(|_, _, _| Ok(Some(vec![])));
+        mock_review_repo
+            .expect_find_similar()
+            .with(eq(99), always(), always())
+            .returning(|_, _, _| Ok(None));
+        let service = CampaignReviewService::new(Arc::new(mock_review_repo));
+
+        assert!(service.get_similar_campaigns(10).await.unwrap().is_empty());
+        assert!(matches!(
+            service.get_similar_campaigns(99).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::config::SharingConfig;
+use crate::errors::AppError;
+use crate::locale::Locale;
+use crate::model::campaign_share::{
+    CampaignOpenGraph, CampaignPreview, MetaTag, ShareCount, SharePlatform,
+};
+use crate::money::format_amount;
+use crate::repository::campaign_share_repo::CampaignShareRepository;
+use crate::service::short_link_service::campaign_share_url;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Crawlers re-fetch previews rarely; a few minutes keeps the progress figure close enough.
+pub const OPEN_GRAPH_MAX_AGE: Duration = Duration::from_secs(300);
+
+fn progress_percent(collected: f64, target: f64) -> f64 {
+    if target <= 0.0 {
+        return 0.0;
+    }
+    (collected / target * 100.0).floor()
+}
+
+fn describe_progress(preview: &CampaignPreview, percent: f64, locale: Locale) -> String {
+    let collected = format_amount(preview.collected_amount, locale);
+    let target = format_amount(preview.target_amount, locale);
+    match locale {
+        Locale::Id => format!(
+            "Terkumpul {} dari target {} ({}%).",
+            collected, target, percent
+        ),
+        Locale::En => format!("{} raised of {} ({}%).", collected, target, percent),
+    }
+}
+
+fn meta_tags(open_graph: &CampaignOpenGraph) -> Vec<MetaTag> {
+    let property = |key, content: &str| MetaTag {
+        attribute: "property",
+        key,
+        content: content.to_string(),
+    };
+    let name = |key, content: &str| MetaTag {
+        attribute: "name",
+        key,
+        content: content.to_string(),
+    };
+
+    let mut tags = vec![
+        property("og:type", "website"),
+        property("og:title", &open_graph.title),
+        property("og:description", &open_graph.description),
+        property("og:url", &open_graph.url),
+    ];
+    let card = match &open_graph.image_url {
+        Some(image_url) => {
+            tags.push(property("og:image", image_url));
+            "summary_large_image"
+        }
+        None => "summary",
+    };
+    tags.push(name("twitter:card", card));
+    tags.push(name("twitter:title", &open_graph.title));
+    tags.push(name("twitter:description", &open_graph.description));
+    if let Some(image_url) = &open_graph.image_url {
+        tags.push(name("twitter:image", image_url));
+    }
+    tags
+}
+
+pub struct CampaignShareService {
+    share_repo: Arc<dyn CampaignShareRepository>,
+    sharing: SharingConfig,
+}
+
+impl CampaignShareService {
+    pub fn new(share_repo: Arc<dyn CampaignShareRepository>, sharing: SharingConfig) -> Self {
+        CampaignShareService {
+            share_repo,
+            sharing,
+        }
+    }
+
+    /// Link-preview metadata for chat apps and social networks. Public, so it only
+    /// exposes what the campaign page already shows.
+    pub async fn get_open_graph(
+        &self,
+        campaign_id: i32,
+        locale: Option<Locale>,
+    ) -> Result<CampaignOpenGraph, AppError> {
+        let preview = self
+            .share_repo
+            .find_preview(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+        let percent = progress_percent(preview.collected_amount, preview.target_amount);
+
+        let mut open_graph = CampaignOpenGraph {
+            campaign_id,
+            title: preview.title.clone(),
+            description: describe_progress(&preview, percent, locale.unwrap_or(Locale::Id)),
+            url: campaign_share_url(&self.sharing, campaign_id),
+            image_url: preview.image_url,
+            collected_amount: preview.collected_amount,
+            target_amount: preview.target_amount,
+            progress_percent: percent,
+            tags: Vec::new(),
+        };
+        open_graph.tags = meta_tags(&open_graph);
+        Ok(open_graph)
+    }
+
+    pub async fn record_share(
+        &self,
+        campaign_id: i32,
+        platform: SharePlatform,
+    ) -> Result<Vec<ShareCount>, AppError> {
+        if !self.share_repo.record_share(campaign_id, platform).await? {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        }
+        self.share_repo.share_counts(campaign_id).await
+    }
+
+    pub async fn get_share_counts(&self, campaign_id: i32) -> Result<Vec<ShareCount>, AppError> {
+        self.share_repo.share_counts(campaign_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_share_repo::MockCampaignShareRepository;
+    use mockall::predicate::eq;
+
+    fn service(mock_share_repo: MockCampaignShareRepository) -> CampaignShareService {
+        CampaignShareService::new(
+            Arc::new(mock_share_repo),
+            SharingConfig {
+                public_base_url: "https://donasi.example.com".to_string(),
+                short_link_base_url: "https://api.example.com".to_string(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_open_graph_describes_progress_in_locale() {
+        let mut mock_share_repo = MockCampaignShareRepository::new();
+        mock_share_repo
+            .expect_find_preview()
+            .with(eq(10))
+            .returning(|_| {
+                Ok(Some(CampaignPreview {
+                    campaign_id: 10,
+                    title: "Sumur desa".to_string(),
+                    target_amount: 10_000_000.0,
+                    collected_amount: 2_575_000.0,
+                    image_url: Some("https://cdn.example.org/a.png".to_string()),
+                }))
+            });
+        let service = service(mock_share_repo);
+
+        let open_graph = service.get_open_graph(10, None).await.unwrap();
+        assert_eq!(open_graph.url, "https://donasi.example.com/campaigns/10");
+        assert_eq!(open_graph.progress_percent, 25.0);
+        assert_eq!(
+            open_graph.description,
+            "Terkumpul Rp 2.575.000 dari target Rp 10.000.000 (25%)."
+        );
+        let tag = |key: &str| {
+            open_graph
+                .tags
+                .iter()
+                .find(|tag| tag.key == key)
+                .map(|tag| tag.content.as_str())
+        };
+        assert_eq!(tag("og:image"), Some("https://cdn.example.org/a.png"));
+        assert_eq!(tag("twitter:card"), Some("summary_large_image"));
+
+        let english = service.get_open_graph(10, Some(Locale::En)).await.unwrap();
+        assert_eq!(
+            english.description,
+            "IDR 2,575,000 raised of IDR 10,000,000 (25%)."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_share_for_missing_campaign() {
+        let mut mock_share_repo = MockCampaignShareRepository::new();
+        mock_share_repo
+            .expect_record_share()
+            .with(eq(99), eq(SharePlatform::Whatsapp))
+            .returning(|_, _| Ok(false));
+        mock_share_repo.expect_share_counts().never();
+        let service = service(mock_share_repo);
+
+        let result = service.record_share(99, SharePlatform::Whatsapp).await;
+        match result.err().unwrap() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Campaign not found"),
+            _ => panic!("Expected NotFound"),
+        }
+    }
+}
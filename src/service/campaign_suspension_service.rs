@@ -0,0 +1,106 @@
+use crate::errors::AppError;
+use crate::model::campaign_suspension::{
+    CampaignAuditEntry, ResolveSuspensionRequest, SuspendCampaignRequest,
+};
+use crate::repository::campaign_suspension_repo::CampaignSuspensionRepository;
+use std::sync::Arc;
+
+/// Takes an active campaign offline while it is investigated, then reinstates or
+/// rejects it. Every step is audited and the fundraiser is notified.
+pub struct CampaignSuspensionService {
+    suspension_repo: Arc<dyn CampaignSuspensionRepository>,
+}
+
+impl CampaignSuspensionService {
+    pub fn new(suspension_repo: Arc<dyn CampaignSuspensionRepository>) -> Self {
+        CampaignSuspensionService { suspension_repo }
+    }
+
+    pub async fn suspend(
+        &self,
+        campaign_id: i32,
+        admin_id: i32,
+        req: SuspendCampaignRequest,
+    ) -> Result<CampaignAuditEntry, AppError> {
+        self.suspension_repo
+            .suspend(campaign_id, admin_id, req.reason)
+            .await
+    }
+
+    pub async fn resolve(
+        &self,
+        campaign_id: i32,
+        admin_id: i32,
+        req: ResolveSuspensionRequest,
+    ) -> Result<CampaignAuditEntry, AppError> {
+        self.suspension_repo
+            .resolve(campaign_id, admin_id, req.decision, req.note)
+            .await
+    }
+
+    pub async fn get_audit_log(
+        &self,
+        campaign_id: i32,
+    ) -> Result<Vec<CampaignAuditEntry>, AppError> {
+        self.suspension_repo
+            .find_audit_log(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign_suspension::SuspensionDecision;
+    use crate::repository::campaign_suspension_repo::MockCampaignSuspensionRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_resolve_passes_decision_and_note() {
+        let mut mock_suspension_repo = MockCampaignSuspensionRepository::new();
+        mock_suspension_repo
+            .expect_resolve()
+            .with(
+                eq(10),
+                eq(2),
+                eq(SuspensionDecision::Reject),
+                eq(Some("Confirmed fraud".to_string())),
+            )
+            .times(1)
+            .returning(|campaign_id, actor_id, _, note| {
+                Ok(CampaignAuditEntry {
+                    id: 1,
+                    campaign_id,
+                    actor_id,
+                    action: "rejected".to_string(),
+                    note,
+                    created_at: Utc::now(),
+                })
+            });
+        let service = CampaignSuspensionService::new(Arc::new(mock_suspension_repo));
+
+        let req = ResolveSuspensionRequest {
+            decision: SuspensionDecision::Reject,
+            note: Some("Confirmed fraud".to_string()),
+        };
+        let entry = service.resolve(10, 2, req).await.unwrap();
+        assert_eq!(entry.action, "rejected");
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_of_unknown_campaign_is_not_found() {
+        let mut mock_suspension_repo = MockCampaignSuspensionRepository::new();
+        mock_suspension_repo
+            .expect_find_audit_log()
+            .with(eq(99))
+            .returning(|_| Ok(None));
+        let service = CampaignSuspensionService::new(Arc::new(mock_suspension_repo));
+
+        assert!(matches!(
+            service.get_audit_log(99).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
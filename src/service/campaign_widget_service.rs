@@ -0,0 +1,250 @@
+use crate::config::WidgetConfig;
+use crate::errors::AppError;
+use crate::model::campaign_widget::CampaignWidget;
+use crate::rate_limit::RateLimiter;
+use crate::repository::campaign_feed_repo::CampaignFeedRepository;
+use crate::service::campaign_feed_service::escape_xml;
+use dashmap::DashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// Progress data for widgets partners embed on their own sites. Requests come from
+/// any visitor of any partner page, so each campaign is read from the database at
+/// most once per TTL, unknown ids included, and each client address is rate limited.
+pub struct CampaignWidgetService {
+    feed_repo: Arc<dyn CampaignFeedRepository>,
+    public_base_url: String,
+    cache_ttl: Duration,
+    widgets: DashMap<i32, (Instant, Option<CampaignWidget>)>,
+    limiter: RateLimiter<IpAddr>,
+    rate_limited_total: AtomicU64,
+}
+
+impl CampaignWidgetService {
+    pub fn new(
+        feed_repo: Arc<dyn CampaignFeedRepository>,
+        public_base_url: String,
+        config: &WidgetConfig,
+    ) -> Self {
+        CampaignWidgetService {
+            feed_repo,
+            public_base_url,
+            cache_ttl: Duration::from_secs(config.cache_ttl_secs),
+            widgets: DashMap::new(),
+            limiter: RateLimiter::new(config.requests_per_minute, MINUTE),
+            rate_limited_total: AtomicU64::new(0),
+        }
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        self.cache_ttl
+    }
+
+    /// Requests without a known client address (local tooling) are not limited.
+    pub async fn get_widget(
+        &self,
+        campaign_id: i32,
+        client_ip: Option<IpAddr>,
+    ) -> Result<CampaignWidget, AppError> {
+        if let Some(client_ip) = client_ip
+            && let Err(retry_after) = self.limiter.try_acquire(client_ip) {
+                self.rate_limited_total.fetch_add(1, Ordering::Relaxed);
+                return Err(AppError::TooManyRequests {
+                    message: "Too many widget requests".to_string(),
+                    retry_after_secs: retry_after.as_secs().max(1),
+                });
+            }
+
+        let cached = self
+            .widgets
+            .get(&campaign_id)
+            .filter(|cached| cached.0.elapsed() < self.cache_ttl)
+            .map(|cached| cached.1.clone());
+        let widget = match cached {
+            Some(widget) => widget,
+            None => {
+                let widget = self
+                    .feed_repo
+                    .find_widget(campaign_id)
+                    .await?
+                    .map(|widget| self.complete(widget));
+                self.widgets
+                    .insert(campaign_id, (Instant::now(), widget.clone()));
+                widget
+            }
+        };
+        widget.ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))
+    }
+
+    fn complete(&self, mut widget: CampaignWidget) -> CampaignWidget {
+        widget.percent_funded = if widget.target_amount > 0.0 {
+            (widget.collected_amount / widget.target_amount * 100.0).floor() as u32
+        } else {
+            0
+        };
+        widget.url = format!(
+            "{}/campaigns/{}",
+            self.public_base_url.trim_end_matches('/'),
+            widget.id
+        );
+        widget
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                rocket::tokio::time::sleep(MINUTE).await;
+                self.prune();
+            }
+        });
+    }
+
+    /// Drops idle client addresses and expired widgets.
+    pub fn prune(&self) {
+        self.limiter.prune();
+        self.widgets
+            .retain(|_, (cached_at, _)| cached_at.elapsed() < self.cache_ttl);
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        format!(
+            "# HELP widget_rate_limited_total Widget requests refused by the per-address limit\n\
+             # TYPE widget_rate_limited_total counter\n\
+             widget_rate_limited_total {}\n\
+             # HELP widget_cached_campaigns Campaigns currently held in the widget cache\n\
+             # TYPE widget_cached_campaigns gauge\n\
+             widget_cached_campaigns {}\n",
+            self.rate_limited_total.load(Ordering::Relaxed),
+            self.widgets.len()
+        )
+    }
+}
+
+/// A self-contained progress bar. All styling is inline so the snippet works in an
+/// iframe without loading anything else.
+pub fn render_html(widget: &CampaignWidget) -> String {
+    let title = escape_xml(&widget.title);
+    format!(
+        "<!DOCTYPE html>\n\
+         <html><head><meta charset=\"utf-8\"><title>{title}</title></head>\n\
+         <body style=\"margin:0;font-family:sans-serif;font-size:14px\">\n\
+         <a href=\"{url}\" target=\"_blank\" rel=\"noopener\" style=\"color:inherit;text-decoration:none\">\n\
+         <div style=\"font-weight:bold\">{title}</div>\n\
+         <div style=\"background:#e5e7eb;border-radius:4px;height:8px;margin:6px 0\">\
+         <div style=\"background:#16a34a;border-radius:4px;height:8px;width:{width}%\"></div></div>\n\
+         <div>{collected:.0} / {target:.0} ({percent}%)</div>\n\
+         </a>\n\
+         </body></html>\n",
+        title = title,
+        url = escape_xml(&widget.url),
+        width = widget.percent_funded.min(100),
+        collected = widget.collected_amount,
+        target = widget.target_amount,
+        percent = widget.percent_funded,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::campaign_feed_repo::MockCampaignFeedRepository;
+    use mockall::predicate::*;
+
+    fn widget(id: i32) -> CampaignWidget {
+        CampaignWidget {
+            id,
+            title: "Clean <water>".to_string(),
+            target_amount: 1_000_000.0,
+            collected_amount: 333_000.0,
+            completed: false,
+            percent_funded: 0,
+            url: String::new(),
+        }
+    }
+
+    fn service(
+        mock_feed_repo: MockCampaignFeedRepository,
+        requests_per_minute: usize,
+    ) -> CampaignWidgetService {
+        CampaignWidgetService::new(
+            Arc::new(mock_feed_repo),
+            "https://example.org/".to_string(),
+            &WidgetConfig {
+                requests_per_minute,
+                cache_ttl_secs: 300,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_widget_and_misses_are_cached() {
+        let mut mock_feed_repo = MockCampaignFeedRepository::new();
+        mock_feed_repo
+            .expect_find_widget()
+            .with(eq(10))
+            .times(1)
+            .returning(|id| Ok(Some(widget(id))));
+        mock_feed_repo
+            .expect_find_widget()
+            .with(eq(99))
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let service = service(mock_feed_repo, 100);
+        let first = service.get_widget(10, None).await.unwrap();
+        assert_eq!(service.get_widget(10, None).await.unwrap(), first);
+        assert_eq!(first.percent_funded, 33);
+        assert_eq!(first.url, "https://example.org/campaigns/10");
+        for _ in 0..2 {
+            assert!(matches!(
+                service.get_widget(99, None).await.err().unwrap(),
+                AppError::NotFound(_)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_per_client_address() {
+        let mut mock_feed_repo = MockCampaignFeedRepository::new();
+        mock_feed_repo
+            .expect_find_widget()
+            .returning(|id| Ok(Some(widget(id))));
+
+        let service = service(mock_feed_repo, 2);
+        let client: IpAddr = "203.0.113.7".parse().unwrap();
+        let other: IpAddr = "203.0.113.8".parse().unwrap();
+        service.get_widget(10, Some(client)).await.unwrap();
+        service.get_widget(11, Some(client)).await.unwrap();
+
+        match service.get_widget(10, Some(client)).await.err().unwrap() {
+            AppError::TooManyRequests {
+                retry_after_secs, ..
+            } => assert!(retry_after_secs >= 1),
+            _ => panic!("Expected TooManyRequests"),
+        }
+        assert!(service.get_widget(10, Some(other)).await.is_ok());
+        assert!(
+            service
+                .to_prometheus()
+                .contains("widget_rate_limited_total 1")
+        );
+    }
+
+    #[test]
+    fn test_html_escapes_title_and_caps_bar() {
+        let mut overfunded = widget(10);
+        overfunded.percent_funded = 150;
+        overfunded.url = "https://example.org/campaigns/10".to_string();
+
+        let html = render_html(&overfunded);
+
+        assert!(html.contains("Clean &lt;water&gt;"));
+        assert!(!html.contains("<water>"));
+        assert!(html.contains("width:100%"));
+        assert!(html.contains("(150%)"));
+    }
+}
@@ -0,0 +1,16 @@
+use crate::model::dispute::DisputeResolution;
+
+#[derive(Debug)]
+pub struct OpenDisputeCommand {
+    pub donation_id: i32,
+    pub user_id: i32,
+    pub reason: String,
+}
+
+#[derive(Debug)]
+pub struct ResolveDisputeCommand {
+    pub dispute_id: i32,
+    pub admin_id: i32,
+    pub resolution: DisputeResolution,
+    pub note: Option<String>,
+}
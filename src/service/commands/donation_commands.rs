@@ -1,9 +1,26 @@
+use crate::model::donation_basket::BasketItem;
+
 #[derive(Debug)]
 pub struct MakeDonationCommand {
     pub donor_id: i32,
     pub campaign_id: i32,
     pub amount: f64,
     pub message: Option<String>,
+    pub private_note: Option<String>,
+    pub tier_id: Option<i32>,
+    pub referral_code: Option<String>,
+    pub honoree_name: Option<String>,
+    pub honoree_email: Option<String>,
+    pub anonymous: bool,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MakeBasketDonationCommand {
+    pub donor_id: i32,
+    pub items: Vec<BasketItem>,
+    pub message: Option<String>,
+    pub ip_address: Option<String>,
 }
 
 #[derive(Debug)]
@@ -12,4 +29,27 @@ pub struct DeleteDonationMessageCommand {
     pub user_id: i32,
 }
 
-pub mod donation_commands;
\ No newline at end of file
+#[derive(Debug)]
+pub struct ReviewDonationCommand {
+    pub donation_id: i32,
+    pub admin_id: i32,
+}
+
+#[derive(Debug)]
+pub struct CreateDonationIntentCommand {
+    pub donor_id: i32,
+    pub campaign_id: i32,
+    pub amount: f64,
+    pub message: Option<String>,
+    pub private_note: Option<String>,
+    pub tier_id: Option<i32>,
+    pub referral_code: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ConfirmDonationIntentCommand {
+    pub intent_id: i32,
+    pub user_id: i32,
+    pub confirmation_token: String,
+}
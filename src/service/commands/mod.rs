@@ -0,0 +1,5 @@
+pub mod dispute_commands;
+pub mod donation_commands;
+pub mod risk_commands;
+pub mod security_commands;
+pub mod withdrawal_commands;
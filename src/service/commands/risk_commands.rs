@@ -0,0 +1,10 @@
+use crate::model::risk::RiskActivity;
+
+#[derive(Debug)]
+pub struct EvaluateRiskCommand {
+    pub user_id: i32,
+    pub ip_address: Option<String>,
+    pub activity: RiskActivity,
+    pub campaign_id: Option<i32>,
+    pub amount: f64,
+}
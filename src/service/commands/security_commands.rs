@@ -0,0 +1,9 @@
+#[derive(Debug, Clone)]
+pub struct RecordLoginCommand {
+    pub user_id: i32,
+    pub succeeded: bool,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    /// ISO country code, e.g. from the edge proxy's geo header.
+    pub country: Option<String>,
+}
@@ -0,0 +1,17 @@
+#[derive(Debug)]
+pub struct RequestWithdrawalCommand {
+    pub user_id: i32,
+    /// Pays out of this organization's wallet instead of the user's.
+    pub organization_id: Option<i32>,
+    pub amount: f64,
+    pub bank_name: String,
+    pub account_number: String,
+    pub account_holder: String,
+}
+
+#[derive(Debug)]
+pub struct ReviewWithdrawalCommand {
+    pub withdrawal_id: i32,
+    pub admin_id: i32,
+    pub note: Option<String>,
+}
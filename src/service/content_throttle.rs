@@ -0,0 +1,154 @@
+use crate::config::ContentThrottleConfig;
+use crate::errors::AppError;
+use crate::rate_limit::RateLimiter;
+use crate::validation::FieldError;
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+// Case and spacing changes don't make a repeated message new.
+fn fingerprint(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for word in text.split_whitespace() {
+        word.to_lowercase().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Anti-spam limits on public text users post: a per-user hourly cap (429) and
+/// refusal of the same text posted again within the duplicate window (422).
+pub struct ContentThrottle {
+    limiter: RateLimiter<i32>,
+    duplicate_window: Duration,
+    recent: DashMap<i32, VecDeque<(Instant, u64)>>,
+    rate_limited_total: AtomicU64,
+    duplicates_total: AtomicU64,
+}
+
+impl ContentThrottle {
+    pub fn new(config: &ContentThrottleConfig) -> Self {
+        ContentThrottle {
+            limiter: RateLimiter::new(config.max_messages_per_hour, HOUR),
+            duplicate_window: Duration::from_secs(config.duplicate_window_minutes * 60),
+            recent: DashMap::new(),
+            rate_limited_total: AtomicU64::new(0),
+            duplicates_total: AtomicU64::new(0),
+        }
+    }
+
+    /// Counts `text` against the user's limits. `field` names the request field in
+    /// the 422 body. Blank text is not a public message and always passes.
+    pub fn check(&self, user_id: i32, field: &str, text: &str) -> Result<(), AppError> {
+        if text.trim().is_empty() {
+            return Ok(());
+        }
+        let now = Instant::now();
+        let fingerprint = fingerprint(text);
+
+        let mut recent = self.recent.entry(user_id).or_default();
+        recent.retain(|&(at, _)| now.duration_since(at) < self.duplicate_window);
+        if recent.iter().any(|&(_, seen)| seen == fingerprint) {
+            self.duplicates_total.fetch_add(1, Ordering::Relaxed);
+            return Err(AppError::UnprocessableEntity(vec![FieldError {
+                field: field.to_string(),
+                code: "duplicate".to_string(),
+                message: "You already posted this message recently".to_string(),
+            }]));
+        }
+
+        if let Err(retry_after) = self.limiter.try_acquire(user_id) {
+            self.rate_limited_total.fetch_add(1, Ordering::Relaxed);
+            return Err(AppError::TooManyRequests {
+                message: "You are posting messages too quickly".to_string(),
+                retry_after_secs: retry_after.as_secs().max(1),
+            });
+        }
+        recent.push_back((now, fingerprint));
+        Ok(())
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                rocket::tokio::time::sleep(HOUR).await;
+                self.prune();
+            }
+        });
+    }
+
+    /// Drops users with nothing left in either window.
+    pub fn prune(&self) {
+        self.limiter.prune();
+        let now = Instant::now();
+        self.recent.retain(|_, recent| {
+            recent
+                .back()
+                .is_some_and(|&(at, _)| now.duration_since(at) < self.duplicate_window)
+        });
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        format!(
+            "# HELP content_throttled_total Public messages refused by the anti-spam throttle\n\
+             # TYPE content_throttled_total counter\n\
+             content_throttled_total{{reason=\"rate_limit\"}} {}\n\
+             content_throttled_total{{reason=\"duplicate\"}} {}\n",
+            self.rate_limited_total.load(Ordering::Relaxed),
+            self.duplicates_total.load(Ordering::Relaxed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn throttle(max_messages_per_hour: usize) -> ContentThrottle {
+        ContentThrottle::new(&ContentThrottleConfig {
+            max_messages_per_hour,
+            duplicate_window_minutes: 60,
+        })
+    }
+
+    #[test]
+    fn test_refuses_repeated_text_with_422() {
+        let throttle = throttle(10);
+
+        throttle.check(1, "message", "Semangat!").unwrap();
+        match throttle.check(1, "message", "  semangat! ").unwrap_err() {
+            AppError::UnprocessableEntity(fields) => {
+                assert_eq!(fields[0].field, "message");
+                assert_eq!(fields[0].code, "duplicate");
+            }
+            _ => panic!("Expected UnprocessableEntity"),
+        }
+        throttle.check(2, "message", "Semangat!").unwrap();
+    }
+
+    #[test]
+    fn test_refuses_over_hourly_cap_with_429() {
+        let throttle = throttle(2);
+
+        throttle.check(1, "message", "first").unwrap();
+        throttle.check(1, "message", "second").unwrap();
+        throttle.check(1, "message", "").unwrap();
+        match throttle.check(1, "message", "third").unwrap_err() {
+            AppError::TooManyRequests {
+                retry_after_secs, ..
+            } => assert!(retry_after_secs > 0 && retry_after_secs <= 3600),
+            _ => panic!("Expected TooManyRequests"),
+        }
+
+        assert!(
+            throttle
+                .to_prometheus()
+                .contains("content_throttled_total{reason=\"rate_limit\"} 1\n")
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::errors::AppError;
+use crate::model::data_export::{DataExport, UserDataExport};
+use crate::repository::data_export_repo::DataExportRepository;
+use crate::repository::donation_repo::DonationRepository;
+use crate::repository::withdrawal_repo::WithdrawalRepository;
+use crate::service::background_job::run_job;
+use chrono::Utc;
+use rocket::serde::json;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DataExportService {
+    donation_repo: Arc<dyn DonationRepository>,
+    withdrawal_repo: Arc<dyn WithdrawalRepository>,
+    export_repo: Arc<dyn DataExportRepository>,
+}
+
+impl DataExportService {
+    pub fn new(
+        donation_repo: Arc<dyn DonationRepository>,
+        withdrawal_repo: Arc<dyn WithdrawalRepository>,
+        export_repo: Arc<dyn DataExportRepository>,
+    ) -> Self {
+        DataExportService {
+            donation_repo,
+            withdrawal_repo,
+            export_repo,
+        }
+    }
+
+    pub async fn assemble_export(&self, user_id: i32) -> Result<UserDataExport, AppError> {
+        let donations = self.donation_repo.find_by_user(user_id).await?;
+        let withdrawals = self.withdrawal_repo.find_by_user(user_id).await?;
+
+        Ok(UserDataExport {
+            user_id,
+            generated_at: Utc::now(),
+            donations,
+            withdrawals,
+        })
+    }
+
+    // Large accounts are exported in the background; the client polls `get_export`.
+    pub async fn request_export(&self, user_id: i32) -> Result<DataExport, AppError> {
+        let export = self.export_repo.create(user_id).await?;
+
+        let service = self.clone();
+        let export_id = export.id;
+        rocket::tokio::spawn(async move {
+            run_job("data_export", service.generate_export(export_id, user_id)).await;
+        });
+
+        Ok(export)
+    }
+
+    pub async fn generate_export(&self, export_id: i32, user_id: i32) -> Result<(), AppError> {
+        let payload = self.assemble_export(user_id).await.and_then(|export| {
+            json::to_value(export).map_err(|e| AppError::InternalServerError(e.to_string()))
+        });
+
+        match payload {
+            Ok(payload) => self.export_repo.mark_ready(export_id, payload).await,
+            Err(e) => self.export_repo.mark_failed(export_id, e.to_string()).await,
+        }
+    }
+
+    pub async fn get_export(&self, export_id: i32, user_id: i32) -> Result<DataExport, AppError> {
+        let export = self
+            .export_repo
+            .find_by_id(export_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Data export not found".to_string()))?;
+
+        if export.user_id != user_id {
+            return Err(AppError::Forbidden(
+                "You cannot access this data export".to_string(),
+            ));
+        }
+        Ok(export)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::data_export::DataExportStatus;
+    use crate::model::donation::{Donation, DonationStatus};
+    use crate::repository::{
+        data_export_repo::{MockDataExportRepository, PgDataExportRepository},
+        donation_repo::{MockDonationRepository, PgDonationRepository},
+        withdrawal_repo::{MockWithdrawalRepository, PgWithdrawalRepository},
+    };
+    use crate::test_support::test_db;
+    use mockall::predicate::*;
+
+    fn sample_export(id: i32, user_id: i32) -> DataExport {
+        DataExport {
+            id,
+            user_id,
+            status: DataExportStatus::Pending,
+            payload: None,
+            error: None,
+            created_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assemble_export_collects_user_data() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+
+        mock_donation_repo
+            .expect_find_by_user()
+            .with(eq(1))
+            .times(1)
+            .returning(|user_id| {
+                Ok(vec![Donation {
+                    id: 1,
+                    user_id,
+                    campaign_id: 10,
+                    amount: 50.0,
+                    message: None,
+                    private_note: None,
+                    status: DonationStatus::Settled,
+                    receipt_number: None,
+                    refunded_excess: None,
+                    created_at: Utc::now(),
+                }])
+            });
+        mock_withdrawal_repo
+            .expect_find_by_user()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let service = DataExportService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_withdrawal_repo),
+            Arc::new(MockDataExportRepository::new()),
+        );
+        let export = service.assemble_export(1).await.unwrap();
+
+        assert_eq!(export.user_id, 1);
+        assert_eq!(export.donations.len(), 1);
+        assert!(export.withdrawals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_generate_export_marks_ready() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let mut mock_export_repo = MockDataExportRepository::new();
+
+        mock_donation_repo
+            .expect_find_by_user()
+            .returning(|_| Ok(vec![]));
+        mock_withdrawal_repo
+            .expect_find_by_user()
+            .returning(|_| Ok(vec![]));
+        mock_export_repo
+            .expect_mark_ready()
+            .withf(|id, payload| *id == 5 && payload["user_id"] == 1)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_export_repo.expect_mark_failed().times(0);
+
+        let service = DataExportService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_withdrawal_repo),
+            Arc::new(mock_export_repo),
+        );
+
+        assert!(service.generate_export(5, 1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_generate_export_marks_failed() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_export_repo = MockDataExportRepository::new();
+
+        mock_donation_repo
+            .expect_find_by_user()
+            .returning(|_| Err(AppError::NotFound("User not found".to_string())));
+        mock_export_repo
+            .expect_mark_failed()
+            .withf(|id, error| *id == 5 && error.contains("User not found"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = DataExportService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockWithdrawalRepository::new()),
+            Arc::new(mock_export_repo),
+        );
+
+        assert!(service.generate_export(5, 1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_export_of_other_user_forbidden() {
+        let mut mock_export_repo = MockDataExportRepository::new();
+        mock_export_repo
+            .expect_find_by_id()
+            .with(eq(5))
+            .returning(|id| Ok(Some(sample_export(id, 2))));
+
+        let service = DataExportService::new(
+            Arc::new(MockDonationRepository::new()),
+            Arc::new(MockWithdrawalRepository::new()),
+            Arc::new(mock_export_repo),
+        );
+        let result = service.get_export(5, 1).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("cannot access")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_generate_export_end_to_end() {
+        let db =
+            test_db().await;
+        sqlx::raw_sql(
+            "INSERT INTO donations (user_id, campaign_id, amount) VALUES (1, 10, 50), (2, 10, 70);
+             INSERT INTO withdrawals (user_id, amount, bank_name, account_number, account_holder) \
+                 VALUES (1, 20, 'BCA', '123', 'Budi');",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let export_repo = Arc::new(PgDataExportRepository::new(db.pool.clone()));
+        let service = DataExportService::new(
+            Arc::new(PgDonationRepository::new(db.pool.clone())),
+            Arc::new(PgWithdrawalRepository::new(db.pool.clone())),
+            export_repo.clone(),
+        );
+
+        let export = export_repo.create(1).await.unwrap();
+        service.generate_export(export.id, 1).await.unwrap();
+
+        let export = service.get_export(export.id, 1).await.unwrap();
+        assert_eq!(export.status, DataExportStatus::Ready);
+        let payload = export.payload.unwrap();
+        assert_eq!(payload["donations"].as_array().unwrap().len(), 1);
+        assert_eq!(payload["donations"][0]["amount"], 50.0);
+        assert_eq!(payload["withdrawals"][0]["bank_name"], "BCA");
+    }
+}
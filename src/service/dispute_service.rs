@@ -0,0 +1,317 @@
+use crate::errors::AppError;
+use crate::model::dispute::{Dispute, DisputeAuditEntry, DisputeResolution, DisputeStatus};
+use crate::model::donation::DonationStatus;
+use crate::repository::dispute_repo::DisputeRepository;
+use crate::repository::donation_cache::CacheInvalidator;
+use crate::repository::donation_repo::DonationRepository;
+use crate::service::commands::dispute_commands::{OpenDisputeCommand, ResolveDisputeCommand};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub const DEFAULT_DISPUTE_WINDOW_DAYS: i64 = 30;
+
+// Notifications hang off the DisputeOpened/DisputeResolved events the repository
+// writes to the outbox alongside each state change.
+pub struct DisputeService {
+    dispute_repo: Arc<dyn DisputeRepository>,
+    donation_repo: Arc<dyn DonationRepository>,
+    cache_invalidator: Option<Arc<dyn CacheInvalidator>>,
+    dispute_window: Duration,
+}
+
+impl DisputeService {
+    pub fn new(
+        dispute_repo: Arc<dyn DisputeRepository>,
+        donation_repo: Arc<dyn DonationRepository>,
+    ) -> Self {
+        DisputeService {
+            dispute_repo,
+            donation_repo,
+            cache_invalidator: None,
+            dispute_window: Duration::days(DEFAULT_DISPUTE_WINDOW_DAYS),
+        }
+    }
+
+    pub fn with_cache_invalidator(mut self, cache_invalidator: Arc<dyn CacheInvalidator>) -> Self {
+        self.cache_invalidator = Some(cache_invalidator);
+        self
+    }
+
+    pub fn with_dispute_window_days(mut self, days: i64) -> Self {
+        self.dispute_window = Duration::days(days);
+        self
+    }
+
+    pub async fn open_dispute(&self, cmd: OpenDisputeCommand) -> Result<Dispute, AppError> {
+        let donation = self
+            .donation_repo
+            .find_by_id(cmd.donation_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Donation not found".to_string()))?;
+
+        if donation.user_id != cmd.user_id {
+            return Err(AppError::Forbidden(
+                "You cannot dispute this donation".to_string(),
+            ));
+        }
+        if donation.status != DonationStatus::Settled {
+            return Err(AppError::ValidationError(
+                "Only settled donations can be disputed".to_string(),
+            ));
+        }
+        if Utc::now() - donation.created_at > self.dispute_window {
+            return Err(AppError::ValidationError(
+                "The dispute window for this donation has closed".to_string(),
+            ));
+        }
+
+        self.dispute_repo.open(&donation, cmd.reason).await
+    }
+
+    pub async fn resolve_dispute(&self, cmd: ResolveDisputeCommand) -> Result<Dispute, AppError> {
+        let dispute = match self
+            .dispute_repo
+            .resolve(cmd.dispute_id, cmd.admin_id, cmd.resolution, cmd.note)
+            .await?
+        {
+            Some(dispute) => dispute,
+            None => {
+                let exists = self
+                    .dispute_repo
+                    .find_by_id(cmd.dispute_id)
+                    .await?
+                    .is_some();
+                if !exists {
+                    return Err(AppError::NotFound("Dispute not found".to_string()));
+                }
+                return Err(AppError::ValidationError(
+                    "Dispute has already been resolved".to_string(),
+                ));
+            }
+        };
+
+        if cmd.resolution == DisputeResolution::Refund
+            && let Some(cache_invalidator) = &self.cache_invalidator {
+                cache_invalidator.invalidate_campaign(dispute.campaign_id);
+                cache_invalidator.invalidate_user_campaign(dispute.user_id, dispute.campaign_id);
+            }
+        Ok(dispute)
+    }
+
+    pub async fn get_disputes_by_user(&self, user_id: i32) -> Result<Vec<Dispute>, AppError> {
+        self.dispute_repo.find_by_user(user_id).await
+    }
+
+    pub async fn get_open_disputes(&self) -> Result<Vec<Dispute>, AppError> {
+        self.dispute_repo.find_by_status(DisputeStatus::Open).await
+    }
+
+    pub async fn get_audit_log(&self, dispute_id: i32) -> Result<Vec<DisputeAuditEntry>, AppError> {
+        if self.dispute_repo.find_by_id(dispute_id).await?.is_none() {
+            return Err(AppError::NotFound("Dispute not found".to_string()));
+        }
+        self.dispute_repo.find_audit_log(dispute_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::Donation;
+    use crate::repository::{
+        dispute_repo::{MockDisputeRepository, PgDisputeRepository},
+        donation_cache::DonationCache,
+        donation_repo::{MockDonationRepository, PgDonationRepository},
+    };
+    use crate::test_support::test_db;
+    use mockall::predicate::*;
+
+    fn donation(id: i32, user_id: i32, status: DonationStatus, age_days: i64) -> Donation {
+        Donation {
+            id,
+            user_id,
+            campaign_id: 10,
+            amount: 50_000.0,
+            message: None,
+            private_note: None,
+            status,
+            receipt_number: None,
+            refunded_excess: None,
+            created_at: Utc::now() - Duration::days(age_days),
+        }
+    }
+
+    fn dispute(id: i32, status: DisputeStatus) -> Dispute {
+        Dispute {
+            id,
+            donation_id: 1,
+            user_id: 1,
+            campaign_id: 10,
+            amount: 50_000.0,
+            reason: "I did not make this donation".to_string(),
+            status,
+            resolved_by: None,
+            resolution_note: None,
+            created_at: Utc::now(),
+            resolved_at: None,
+        }
+    }
+
+    fn open_cmd(user_id: i32) -> OpenDisputeCommand {
+        OpenDisputeCommand {
+            donation_id: 1,
+            user_id,
+            reason: "I did not make this donation".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_dispute_success() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_dispute_repo = MockDisputeRepository::new();
+        mock_donation_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Ok(Some(donation(id, 1, DonationStatus::Settled, 3))));
+        mock_dispute_repo
+            .expect_open()
+            .withf(|donation, reason| donation.id == 1 && reason.contains("did not"))
+            .times(1)
+            .returning(|_, _| Ok(dispute(7, DisputeStatus::Open)));
+
+        let service =
+            DisputeService::new(Arc::new(mock_dispute_repo), Arc::new(mock_donation_repo));
+        let result = service.open_dispute(open_cmd(1)).await;
+
+        assert_eq!(result.unwrap().id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_open_dispute_after_window_closed() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_dispute_repo = MockDisputeRepository::new();
+        mock_donation_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(donation(id, 1, DonationStatus::Settled, 45))));
+        mock_dispute_repo.expect_open().times(0);
+
+        let service =
+            DisputeService::new(Arc::new(mock_dispute_repo), Arc::new(mock_donation_repo));
+        let result = service.open_dispute(open_cmd(1)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("window")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_dispute_on_other_users_donation() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(donation(id, 2, DonationStatus::Settled, 1))));
+
+        let service = DisputeService::new(
+            Arc::new(MockDisputeRepository::new()),
+            Arc::new(mock_donation_repo),
+        );
+        let result = service.open_dispute(open_cmd(1)).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("cannot dispute")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_dispute_refund_invalidates_cache() {
+        let mut mock_dispute_repo = MockDisputeRepository::new();
+        mock_dispute_repo
+            .expect_resolve()
+            .with(eq(7), eq(99), eq(DisputeResolution::Refund), always())
+            .times(1)
+            .returning(|id, _, _, _| Ok(Some(dispute(id, DisputeStatus::Refunded))));
+        let cache = Arc::new(DonationCache::new());
+        cache.set_campaign_total(10, 50_000.0);
+
+        let service = DisputeService::new(
+            Arc::new(mock_dispute_repo),
+            Arc::new(MockDonationRepository::new()),
+        )
+        .with_cache_invalidator(cache.clone());
+        let cmd = ResolveDisputeCommand {
+            dispute_id: 7,
+            admin_id: 99,
+            resolution: DisputeResolution::Refund,
+            note: None,
+        };
+        let result = service.resolve_dispute(cmd).await;
+
+        assert_eq!(result.unwrap().status, DisputeStatus::Refunded);
+        assert_eq!(cache.campaign_total(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_already_resolved_dispute() {
+        let mut mock_dispute_repo = MockDisputeRepository::new();
+        mock_dispute_repo
+            .expect_resolve()
+            .returning(|_, _, _, _| Ok(None));
+        mock_dispute_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(dispute(id, DisputeStatus::Dismissed))));
+
+        let service = DisputeService::new(
+            Arc::new(mock_dispute_repo),
+            Arc::new(MockDonationRepository::new()),
+        );
+        let cmd = ResolveDisputeCommand {
+            dispute_id: 7,
+            admin_id: 99,
+            resolution: DisputeResolution::Dismiss,
+            note: None,
+        };
+        let result = service.resolve_dispute(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("already been resolved")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker or TEST_DATABASE_URL"]
+    async fn test_open_dispute_end_to_end() {
+        let db = test_db().await;
+        let donation_id: i32 = sqlx::query_scalar(
+            "WITH campaign AS (INSERT INTO campaigns (id, target_amount, collected_amount) VALUES (10, 1000, 300)) \
+             INSERT INTO donations (user_id, campaign_id, amount) VALUES (1, 10, 300) RETURNING id",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        let service = DisputeService::new(
+            Arc::new(PgDisputeRepository::new(db.pool.clone())),
+            Arc::new(PgDonationRepository::new(db.pool.clone())),
+        );
+        let cmd = |donation_id, user_id| OpenDisputeCommand {
+            donation_id,
+            user_id,
+            reason: "I did not make this donation".to_string(),
+        };
+
+        assert!(matches!(
+            service.open_dispute(cmd(donation_id + 1, 1)).await,
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            service.open_dispute(cmd(donation_id, 2)).await,
+            Err(AppError::Forbidden(_))
+        ));
+        let dispute = service.open_dispute(cmd(donation_id, 1)).await.unwrap();
+        assert_eq!(dispute.donation_id, donation_id);
+        assert_eq!(dispute.status, DisputeStatus::Open);
+        assert_eq!(dispute.amount, 300.0);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::errors::AppError;
+use crate::model::donation_archive::{
+    ArchivedDonation, DonationArchiveReport, DonationRestoreReport,
+};
+use crate::repository::donation_archive_repo::DonationArchiveRepository;
+use crate::service::background_job::run_job;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct DonationArchiveConfig {
+    /// Donations older than this many years are moved to the archive.
+    pub min_age_years: i64,
+    pub batch_size: i64,
+    pub interval: Duration,
+}
+
+impl Default for DonationArchiveConfig {
+    fn default() -> Self {
+        DonationArchiveConfig {
+            min_age_years: 5,
+            batch_size: 1000,
+            interval: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+pub struct DonationArchiveService {
+    archive_repo: Arc<dyn DonationArchiveRepository>,
+    config: DonationArchiveConfig,
+}
+
+impl DonationArchiveService {
+    pub fn new(
+        archive_repo: Arc<dyn DonationArchiveRepository>,
+        config: DonationArchiveConfig,
+    ) -> Self {
+        DonationArchiveService {
+            archive_repo,
+            config,
+        }
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("donation_archival", self.archive_old_donations()).await;
+                rocket::tokio::time::sleep(self.config.interval).await;
+            }
+        });
+    }
+
+    // Small batches keep each delete short so live donation writes are not blocked.
+    pub async fn archive_old_donations(&self) -> Result<DonationArchiveReport, AppError> {
+        let cutoff = Utc::now() - chrono::Duration::days(self.config.min_age_years * 365);
+        let mut report = DonationArchiveReport {
+            cutoff,
+            archived_count: 0,
+        };
+        loop {
+            let archived = self
+                .archive_repo
+                .archive_batch(cutoff, self.config.batch_size)
+                .await?;
+            report.archived_count += archived;
+            if (archived as i64) < self.config.batch_size {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Moves a campaign's archived donations back into the live table, e.g. for an audit.
+    /// The next archival run moves them out again.
+    pub async fn restore_campaign(
+        &self,
+        campaign_id: i32,
+    ) -> Result<DonationRestoreReport, AppError> {
+        let restored_count = self.archive_repo.restore_campaign(campaign_id).await?;
+        Ok(DonationRestoreReport {
+            campaign_id,
+            restored_count,
+        })
+    }
+
+    pub async fn get_archived_donations(
+        &self,
+        campaign_id: i32,
+    ) -> Result<Vec<ArchivedDonation>, AppError> {
+        self.archive_repo.find_by_campaign(campaign_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::donation_archive_repo::MockDonationArchiveRepository;
+    use mockall::Sequence;
+
+    #[tokio::test]
+    async fn test_archive_drains_full_batches() {
+        let mut mock_archive_repo = MockDonationArchiveRepository::new();
+        let mut seq = Sequence::new();
+        for archived in [2, 2, 1] {
+            mock_archive_repo
+                .expect_archive_batch()
+                .withf(|cutoff, limit| {
+                    *cutoff < Utc::now() - chrono::Duration::days(364) && *limit == 2
+                })
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(move |_, _| Ok(archived));
+        }
+        let service = DonationArchiveService::new(
+            Arc::new(mock_archive_repo),
+            DonationArchiveConfig {
+                min_age_years: 1,
+                batch_size: 2,
+                ..Default::default()
+            },
+        );
+
+        let report = service.archive_old_donations().await.unwrap();
+        assert_eq!(report.archived_count, 5);
+    }
+}
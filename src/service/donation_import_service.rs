@@ -0,0 +1,287 @@
+use crate::errors::AppError;
+use crate::model::donation_import::{
+    DonationImportFormat, DonationImportReport, ImportRowError, ImportedDonationRow,
+};
+use crate::repository::donation_cache::CacheInvalidator;
+use crate::repository::donation_repo::DonationRepository;
+use crate::validation::Validate;
+use chrono::Utc;
+use rocket::serde::DeserializeOwned;
+use rocket::serde::json;
+use std::sync::Arc;
+
+pub const IMPORT_CHUNK_SIZE: usize = 500;
+
+pub struct DonationImportService {
+    donation_repo: Arc<dyn DonationRepository>,
+    cache_invalidator: Option<Arc<dyn CacheInvalidator>>,
+    chunk_size: usize,
+}
+
+impl DonationImportService {
+    pub fn new(donation_repo: Arc<dyn DonationRepository>) -> Self {
+        DonationImportService {
+            donation_repo,
+            cache_invalidator: None,
+            chunk_size: IMPORT_CHUNK_SIZE,
+        }
+    }
+
+    pub fn with_cache_invalidator(mut self, cache_invalidator: Arc<dyn CacheInvalidator>) -> Self {
+        self.cache_invalidator = Some(cache_invalidator);
+        self
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    pub async fn import(
+        &self,
+        format: DonationImportFormat,
+        body: &str,
+    ) -> Result<DonationImportReport, AppError> {
+        let (rows, mut errors) = match format {
+            DonationImportFormat::Ndjson => parse_ndjson(body),
+            DonationImportFormat::Csv => parse_csv(body)?,
+        };
+        let total_rows = rows.len() + errors.len();
+
+        let mut valid_rows = Vec::with_capacity(rows.len());
+        for (line, row) in rows {
+            match check_row(&row) {
+                Ok(()) => valid_rows.push((line, row)),
+                Err(message) => errors.push(ImportRowError { line, message }),
+            }
+        }
+
+        let mut imported = 0;
+        for chunk in valid_rows.chunks(self.chunk_size) {
+            let batch: Vec<ImportedDonationRow> =
+                chunk.iter().map(|(_, row)| row.clone()).collect();
+            match self.donation_repo.import_batch(batch).await {
+                Ok(inserted) => imported += inserted as usize,
+                Err(e) => errors.extend(chunk.iter().map(|(line, _)| ImportRowError {
+                    line: *line,
+                    message: format!("Batch insert failed: {}", e),
+                })),
+            }
+        }
+
+        // Cached totals predate the imported rows, so drop them and let reads rebuild them.
+        if imported > 0
+            && let Some(invalidator) = &self.cache_invalidator {
+                invalidator.flush_all();
+            }
+
+        errors.sort_by_key(|error| error.line);
+        Ok(DonationImportReport {
+            total_rows,
+            imported,
+            failed: errors.len(),
+            errors,
+        })
+    }
+}
+
+pub(crate) type ParsedRows<T> = (Vec<(usize, T)>, Vec<ImportRowError>);
+
+fn parse_ndjson(body: &str) -> ParsedRows<ImportedDonationRow> {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (index, raw) in body.lines().enumerate() {
+        if raw.trim().is_empty() {
+            continue;
+        }
+        let line = index + 1;
+        match json::from_str::<ImportedDonationRow>(raw) {
+            Ok(row) => rows.push((line, row)),
+            Err(e) => errors.push(ImportRowError {
+                line,
+                message: format!("Malformed row: {}", e),
+            }),
+        }
+    }
+    (rows, errors)
+}
+
+/// Parses a CSV body with a header row. Rows are keyed by their line in the file,
+/// so reports point at the line an admin would fix.
+pub(crate) fn parse_csv<T: DeserializeOwned>(body: &str) -> Result<ParsedRows<T>, AppError> {
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| AppError::ValidationError(format!("Invalid CSV header: {}", e)))?
+        .clone();
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|pos| pos.line() as usize).unwrap_or(0);
+                errors.push(ImportRowError {
+                    line,
+                    message: format!("Malformed row: {}", e),
+                });
+                continue;
+            }
+        };
+        let line = record
+            .position()
+            .map(|pos| pos.line() as usize)
+            .unwrap_or(0);
+        match record.deserialize::<T>(Some(&headers)) {
+            Ok(row) => rows.push((line, row)),
+            Err(e) => errors.push(ImportRowError {
+                line,
+                message: format!("Malformed row: {}", e),
+            }),
+        }
+    }
+    Ok((rows, errors))
+}
+
+/// Every field error on the row, joined into one message.
+pub(crate) fn row_validation_message<T: Validate>(row: &T) -> Result<(), String> {
+    if let Err(e) = row.validate() {
+        let AppError::UnprocessableEntity(fields) = AppError::from(e) else {
+            unreachable!("validation errors always map to UnprocessableEntity");
+        };
+        let messages: Vec<String> = fields.into_iter().map(|field| field.message).collect();
+        return Err(messages.join("; "));
+    }
+    Ok(())
+}
+
+fn check_row(row: &ImportedDonationRow) -> Result<(), String> {
+    row_validation_message(row)?;
+    if row.created_at > Utc::now() {
+        return Err("created_at cannot be in the future".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::donation_cache::MockCacheInvalidator;
+    use crate::repository::donation_repo::MockDonationRepository;
+
+    const NDJSON: &str = r#"{"user_id":1,"campaign_id":10,"amount":50.0,"message":"Semangat","created_at":"2024-03-01T10:00:00Z"}
+{"user_id":2,"campaign_id":10,"amount":-5.0,"created_at":"2024-03-02T10:00:00Z"}
+
+not json
+{"user_id":3,"campaign_id":11,"amount":75.0,"created_at":"2024-03-03T10:00:00Z"}"#;
+
+    #[tokio::test]
+    async fn test_import_ndjson_reports_invalid_rows() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_import_batch()
+            .withf(|rows| rows.len() == 2 && rows[1].user_id == 3)
+            .times(1)
+            .returning(|rows| Ok(rows.len() as u64));
+        let mut mock_invalidator = MockCacheInvalidator::new();
+        mock_invalidator
+            .expect_flush_all()
+            .times(1)
+            .return_const(());
+
+        let service = DonationImportService::new(Arc::new(mock_donation_repo))
+            .with_cache_invalidator(Arc::new(mock_invalidator));
+        let report = service
+            .import(DonationImportFormat::Ndjson, NDJSON)
+            .await
+            .unwrap();
+
+        assert_eq!(report.total_rows, 4);
+        assert_eq!(report.imported, 2);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.errors[0].line, 2);
+        assert!(report.errors[0].message.contains("amount must be positive"));
+        assert_eq!(report.errors[1].line, 4);
+        assert!(report.errors[1].message.contains("Malformed row"));
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_uses_file_line_numbers() {
+        let body = "user_id,campaign_id,amount,message,created_at\n\
+                    1,10,50.0,,2024-03-01T10:00:00Z\n\
+                    2,abc,20.0,Hi,2024-03-01T10:00:00Z\n";
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_import_batch()
+            .withf(|rows| rows.len() == 1 && rows[0].message.is_none())
+            .times(1)
+            .returning(|rows| Ok(rows.len() as u64));
+
+        let service = DonationImportService::new(Arc::new(mock_donation_repo));
+        let report = service
+            .import(DonationImportFormat::Csv, body)
+            .await
+            .unwrap();
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].line, 3);
+    }
+
+    #[tokio::test]
+    async fn test_import_failed_chunk_marks_its_rows() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut calls = 0;
+        mock_donation_repo
+            .expect_import_batch()
+            .times(2)
+            .returning(move |rows| {
+                calls += 1;
+                if calls == 1 {
+                    Ok(rows.len() as u64)
+                } else {
+                    Err(AppError::InternalServerError(
+                        "deadlock detected".to_string(),
+                    ))
+                }
+            });
+
+        let service = DonationImportService::new(Arc::new(mock_donation_repo)).with_chunk_size(1);
+        let body = NDJSON
+            .lines()
+            .take(1)
+            .chain(NDJSON.lines().skip(4))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let report = service
+            .import(DonationImportFormat::Ndjson, &body)
+            .await
+            .unwrap();
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.errors[0].line, 2);
+        assert!(report.errors[0].message.contains("deadlock detected"));
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_future_dates() {
+        let body =
+            r#"{"user_id":1,"campaign_id":10,"amount":50.0,"created_at":"2999-01-01T00:00:00Z"}"#;
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo.expect_import_batch().times(0);
+        let mut mock_invalidator = MockCacheInvalidator::new();
+        mock_invalidator.expect_flush_all().times(0);
+
+        let service = DonationImportService::new(Arc::new(mock_donation_repo))
+            .with_cache_invalidator(Arc::new(mock_invalidator));
+        let report = service
+            .import(DonationImportFormat::Ndjson, body)
+            .await
+            .unwrap();
+
+        assert_eq!(report.imported, 0);
+        assert!(report.errors[0].message.contains("in the future"));
+    }
+}
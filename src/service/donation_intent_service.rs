@@ -0,0 +1,438 @@
+use crate::errors::AppError;
+use crate::model::donation::{Donation, NewDonationRequest};
+use crate::model::donation_intent::{CreatedDonationIntent, DonationIntentStatus};
+use crate::model::risk::{RiskActivity, RiskDecision};
+use crate::repository::campaign_repo::CampaignRepository;
+use crate::repository::donation_intent_repo::DonationIntentRepository;
+use crate::repository::donation_repo::DonationRepository;
+use crate::service::background_job::run_job;
+use crate::service::commands::donation_commands::{
+    ConfirmDonationIntentCommand, CreateDonationIntentCommand,
+};
+use crate::service::commands::risk_commands::EvaluateRiskCommand;
+use crate::service::content_throttle::ContentThrottle;
+use crate::service::donation_service::{DEFAULT_REVIEW_THRESHOLD, normalize_referral_code};
+use crate::service::keyed_lock::KeyedLock;
+use crate::service::risk_service::RiskService;
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const DEFAULT_INTENT_TTL: Duration = Duration::minutes(15);
+pub const DEFAULT_EXPIRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+const EXPIRY_BATCH_SIZE: i64 = 200;
+
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+pub struct DonationIntentService {
+    intent_repo: Arc<dyn DonationIntentRepository>,
+    donation_repo: Arc<dyn DonationRepository>,
+    campaign_repo: Arc<dyn CampaignRepository>,
+    risk_service: Option<Arc<RiskService>>,
+    content_throttle: Option<Arc<ContentThrottle>>,
+    ttl: Duration,
+    review_threshold: f64,
+    // Intents and donations in progress, keyed by donor (and so by wallet).
+    in_flight: KeyedLock<i32>,
+}
+
+impl DonationIntentService {
+    pub fn new(
+        intent_repo: Arc<dyn DonationIntentRepository>,
+        donation_repo: Arc<dyn DonationRepository>,
+        campaign_repo: Arc<dyn CampaignRepository>,
+    ) -> Self {
+        DonationIntentService {
+            intent_repo,
+            donation_repo,
+            campaign_repo,
+            risk_service: None,
+            content_throttle: None,
+            ttl: DEFAULT_INTENT_TTL,
+            review_threshold: DEFAULT_REVIEW_THRESHOLD,
+            in_flight: KeyedLock::new(),
+        }
+    }
+
+    pub fn with_risk_service(mut self, risk_service: Arc<RiskService>) -> Self {
+        self.risk_service = Some(risk_service);
+        self
+    }
+
+    pub fn with_content_throttle(mut self, content_throttle: Arc<ContentThrottle>) -> Self {
+        self.content_throttle = Some(content_throttle);
+        self
+    }
+
+    // Pass `DonationService::donor_lock` so intents and direct donations by the same
+    // donor run one at a time.
+    pub fn with_donor_lock(mut self, in_flight: KeyedLock<i32>) -> Self {
+        self.in_flight = in_flight;
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_review_threshold(mut self, review_threshold: f64) -> Self {
+        self.review_threshold = review_threshold;
+        self
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: std::time::Duration) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("donation_intent_expiry", self.release_expired()).await;
+                rocket::tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    /// Reserves the amount on the donor's wallet and returns a one-time confirmation token.
+    pub async fn create_intent(
+        &self,
+        cmd: CreateDonationIntentCommand,
+    ) -> Result<CreatedDonationIntent, AppError> {
+        if cmd.amount <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Donation amount must be positive".to_string(),
+            ));
+        }
+        // Large donations go through admin review, which already holds the funds.
+        if cmd.amount > self.review_threshold {
+            return Err(AppError::ValidationError(
+                "Donations above the review threshold cannot use intents".to_string(),
+            ));
+        }
+        if let (Some(content_throttle), Some(message)) = (&self.content_throttle, &cmd.message) {
+            content_throttle.check(cmd.donor_id, "message", message)?;
+        }
+
+        let _in_flight = self.in_flight.lock(cmd.donor_id).await;
+
+        self.campaign_repo
+            .find_by_id(cmd.campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+
+        if let Some(risk_service) = &self.risk_service {
+            let decision = risk_service
+                .evaluate(EvaluateRiskCommand {
+                    user_id: cmd.donor_id,
+                    ip_address: cmd.ip_address.clone(),
+                    activity: RiskActivity::Donation,
+                    campaign_id: Some(cmd.campaign_id),
+                    amount: cmd.amount,
+                })
+                .await?;
+            match decision {
+                RiskDecision::Allow => {}
+                RiskDecision::Flag(_) => {
+                    return Err(AppError::ValidationError(
+                        "Donations that need review cannot use intents".to_string(),
+                    ));
+                }
+                RiskDecision::Block(reason) => return Err(AppError::Forbidden(reason)),
+            }
+        }
+
+        let req = NewDonationRequest {
+            campaign_id: cmd.campaign_id,
+            amount: cmd.amount,
+            message: cmd.message,
+            private_note: cmd.private_note,
+            tier_id: cmd.tier_id,
+            referral_code: normalize_referral_code(cmd.referral_code),
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+        let confirmation_token = Uuid::new_v4().simple().to_string();
+        let expires_at = Utc::now() + self.ttl;
+        let intent = self
+            .intent_repo
+            .create(
+                cmd.donor_id,
+                &req,
+                &hash_token(&confirmation_token),
+                expires_at,
+            )
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Insufficient wallet balance".to_string()))?;
+        Ok(CreatedDonationIntent {
+            confirmation_token,
+            intent,
+        })
+    }
+
+    /// Turns the reserved funds into a settled donation. A failed settlement releases the
+    /// hold and marks the intent failed.
+    pub async fn confirm_intent(
+        &self,
+        cmd: ConfirmDonationIntentCommand,
+    ) -> Result<Donation, AppError> {
+        let _in_flight = self.in_flight.lock(cmd.user_id).await;
+
+        let token_hash = hash_token(&cmd.confirmation_token);
+        match self
+            .donation_repo
+            .settle_intent(cmd.intent_id, cmd.user_id, &token_hash)
+            .await
+        {
+            Ok(Some(donation)) => Ok(donation),
+            Ok(None) => Err(self.explain_unclaimable(&cmd).await),
+            Err(e) => {
+                self.intent_repo
+                    .fail(cmd.intent_id, cmd.user_id, &token_hash)
+                    .await?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Expires pending intents past their deadline and gives the funds back.
+    pub async fn release_expired(&self) -> Result<usize, AppError> {
+        let mut released = 0;
+        loop {
+            let expired = self.intent_repo.expire_due(EXPIRY_BATCH_SIZE).await?;
+            released += expired.len();
+            if (expired.len() as i64) < EXPIRY_BATCH_SIZE {
+                return Ok(released);
+            }
+        }
+    }
+
+    async fn explain_unclaimable(&self, cmd: &ConfirmDonationIntentCommand) -> AppError {
+        let intent = match self.intent_repo.find_by_id(cmd.intent_id).await {
+            Ok(Some(intent)) => intent,
+            Ok(None) => return AppError::NotFound("Donation intent not found".to_string()),
+            Err(e) => return e,
+        };
+        if intent.user_id != cmd.user_id {
+            AppError::Forbidden("You can only confirm your own donation intents".to_string())
+        } else if intent.status != DonationIntentStatus::Pending {
+            AppError::ValidationError("Donation intent is no longer pending".to_string())
+        } else if intent.expires_at <= Utc::now() {
+            AppError::ValidationError("Donation intent has expired".to_string())
+        } else {
+            AppError::Forbidden("Invalid confirmation token".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign::Campaign;
+    use crate::model::donation::DonationStatus;
+    use crate::model::donation_intent::DonationIntent;
+    use crate::repository::campaign_repo::MockCampaignRepository;
+    use crate::repository::donation_intent_repo::MockDonationIntentRepository;
+    use crate::repository::donation_repo::MockDonationRepository;
+    use mockall::predicate::*;
+
+    fn intent(id: i32, status: DonationIntentStatus, expires_in: Duration) -> DonationIntent {
+        DonationIntent {
+            id,
+            user_id: 1,
+            campaign_id: 10,
+            amount: 5000.0,
+            message: Some("Semangat".to_string()),
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            token_hash: hash_token("token"),
+            status,
+            donation_id: None,
+            expires_at: Utc::now() + expires_in,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn confirm(intent_id: i32, user_id: i32) -> ConfirmDonationIntentCommand {
+        ConfirmDonationIntentCommand {
+            intent_id,
+            user_id,
+            confirmation_token: "token".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_refuses_blacklisted_donor() {
+        use crate::model::risk::FlaggedActivity;
+        use crate::repository::risk_repo::MockRiskRepository;
+
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+        mock_campaign_repo.expect_find_by_id().returning(|id| {
+            Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            }))
+        });
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .returning(|_, _| Ok(true));
+        mock_risk_repo.expect_record_flag().returning(
+            |user_id, rule_id, activity, amount, action, reason| {
+                Ok(FlaggedActivity {
+                    id: 1,
+                    user_id,
+                    rule_id,
+                    activity,
+                    amount,
+                    action,
+                    reason,
+                    created_at: Utc::now(),
+                })
+            },
+        );
+        let mut mock_intent_repo = MockDonationIntentRepository::new();
+        mock_intent_repo.expect_create().times(0);
+        let service = DonationIntentService::new(
+            Arc::new(mock_intent_repo),
+            Arc::new(MockDonationRepository::new()),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_risk_service(Arc::new(RiskService::new(Arc::new(mock_risk_repo))));
+        let cmd = CreateDonationIntentCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 5000.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            ip_address: Some("203.0.113.7".to_string()),
+        };
+
+        match service.create_intent(cmd).await.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("blacklisted")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm_intent_settles_reserved_funds() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_settle_intent()
+            .with(eq(7), eq(1), eq(hash_token("token")))
+            .times(1)
+            .returning(|_, user_id, _| {
+                Ok(Some(Donation {
+                    id: 42,
+                    user_id,
+                    campaign_id: 10,
+                    amount: 5000.0,
+                    message: Some("Semangat".to_string()),
+                    private_note: None,
+                    status: DonationStatus::Settled,
+                    receipt_number: None,
+                    refunded_excess: None,
+                    created_at: Utc::now(),
+                }))
+            });
+        let service = DonationIntentService::new(
+            Arc::new(MockDonationIntentRepository::new()),
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        let donation = service.confirm_intent(confirm(7, 1)).await.unwrap();
+        assert_eq!(donation.id, 42);
+        assert_eq!(donation.message.as_deref(), Some("Semangat"));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_intent_failure_releases_hold() {
+        let mut mock_intent_repo = MockDonationIntentRepository::new();
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_settle_intent()
+            .returning(|_, _, _| Err(AppError::NotFound("Campaign not found".to_string())));
+        mock_intent_repo
+            .expect_fail()
+            .with(eq(7), eq(1), eq(hash_token("token")))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let service = DonationIntentService::new(
+            Arc::new(mock_intent_repo),
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        match service.confirm_intent(confirm(7, 1)).await.err().unwrap() {
+            AppError::NotFound(msg) => assert!(msg.contains("Campaign")),
+            _ => panic!("Expected NotFound error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm_intent_explains_why_it_was_not_claimed() {
+        let mut mock_intent_repo = MockDonationIntentRepository::new();
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_settle_intent()
+            .returning(|_, _, _| Ok(None));
+        mock_intent_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|_| Ok(None));
+        mock_intent_repo
+            .expect_find_by_id()
+            .with(eq(2))
+            .returning(|_| {
+                Ok(Some(intent(
+                    2,
+                    DonationIntentStatus::Pending,
+                    -Duration::minutes(1),
+                )))
+            });
+        let service = DonationIntentService::new(
+            Arc::new(mock_intent_repo),
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        match service.confirm_intent(confirm(1, 1)).await.err().unwrap() {
+            AppError::NotFound(_) => {}
+            _ => panic!("Expected NotFound error"),
+        }
+        match service.confirm_intent(confirm(2, 3)).await.err().unwrap() {
+            AppError::Forbidden(_) => {}
+            _ => panic!("Expected Forbidden error"),
+        }
+        match service.confirm_intent(confirm(2, 1)).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("expired")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_release_expired_counts_expired_intents() {
+        let mut mock_intent_repo = MockDonationIntentRepository::new();
+        mock_intent_repo
+            .expect_expire_due()
+            .with(eq(EXPIRY_BATCH_SIZE))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    intent(1, DonationIntentStatus::Expired, -Duration::minutes(1)),
+                    intent(2, DonationIntentStatus::Expired, -Duration::minutes(2)),
+                ])
+            });
+        let service = DonationIntentService::new(
+            Arc::new(mock_intent_repo),
+            Arc::new(MockDonationRepository::new()),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        assert_eq!(service.release_expired().await.unwrap(), 2);
+    }
+}
@@ -1,15 +1,55 @@
 use crate::errors::AppError;
-use crate::model::donation::Donation;
+use crate::model::campaign::CampaignDetail;
+use crate::model::donation::{
+    CampaignDonationStats, CampaignDonorStatistics, CampaignRefundReport, Donation,
+    DonationPrivateNote, DonationReceipt, DonationSizeBucket, DonationStatus, DonationSummary,
+    MonthlyDonationTotal, PublicDonation, ReferralTotal,
+};
+use crate::model::donation_basket::DonationBasketReceipt;
+use crate::model::risk::{RiskActivity, RiskDecision};
 use crate::repository::campaign_repo::CampaignRepository;
+use crate::repository::donation_cache::CacheInvalidator;
 use crate::repository::donation_repo::DonationRepository;
+use crate::repository::profile_repo::ProfileRepository;
 use crate::service::commands::donation_commands::{
-    DeleteDonationMessageCommand, MakeDonationCommand,
+    DeleteDonationMessageCommand, MakeBasketDonationCommand, MakeDonationCommand,
+    ReviewDonationCommand,
 };
+use crate::service::commands::risk_commands::EvaluateRiskCommand;
+use crate::service::background_job::run_job;
+use crate::service::content_throttle::ContentThrottle;
+use crate::service::keyed_lock::KeyedLock;
+use crate::service::risk_service::RiskService;
+use chrono::{Datelike, Duration, TimeZone, Utc};
 use std::sync::Arc;
 
+pub const DEFAULT_REVIEW_THRESHOLD: f64 = 10_000_000.0;
+pub const RECENT_DONATIONS_LIMIT: i64 = 5;
+pub const REFUND_BATCH_SIZE: i64 = 200;
+/// Donations left in review this long are expired and their holds released.
+pub const REVIEW_MAX_AGE_HOURS: i64 = 48;
+pub const DEFAULT_REVIEW_EXPIRY_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(15 * 60);
+// Histogram bucket edges in rupiah; the last bucket is open-ended.
+pub const DONATION_SIZE_BOUNDS: [f64; 5] =
+    [50_000.0, 100_000.0, 500_000.0, 1_000_000.0, 5_000_000.0];
+
+/// Trims the `ref` code a client passed along; blank codes mean no referral.
+pub(crate) fn normalize_referral_code(code: Option<String>) -> Option<String> {
+    code.map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty())
+}
+
 pub struct DonationService {
     donation_repo: Arc<dyn DonationRepository>,
     campaign_repo: Arc<dyn CampaignRepository>,
+    risk_service: Option<Arc<RiskService>>,
+    cache_invalidator: Option<Arc<dyn CacheInvalidator>>,
+    review_threshold: f64,
+    content_throttle: Option<Arc<ContentThrottle>>,
+    profile_repo: Option<Arc<dyn ProfileRepository>>,
+    // Donations in progress, keyed by donor (and so by wallet).
+    in_flight: KeyedLock<i32>,
 }
 
 impl DonationService {
@@ -20,6 +60,53 @@ impl DonationService {
         DonationService {
             donation_repo,
             campaign_repo,
+            risk_service: None,
+            cache_invalidator: None,
+            review_threshold: DEFAULT_REVIEW_THRESHOLD,
+            content_throttle: None,
+            profile_repo: None,
+            in_flight: KeyedLock::new(),
+        }
+    }
+
+    pub fn with_risk_service(mut self, risk_service: Arc<RiskService>) -> Self {
+        self.risk_service = Some(risk_service);
+        self
+    }
+
+    pub fn with_cache_invalidator(mut self, cache_invalidator: Arc<dyn CacheInvalidator>) -> Self {
+        self.cache_invalidator = Some(cache_invalidator);
+        self
+    }
+
+    pub fn with_review_threshold(mut self, review_threshold: f64) -> Self {
+        self.review_threshold = review_threshold;
+        self
+    }
+
+    // Donation messages are shown publicly on the campaign page.
+    pub fn with_content_throttle(mut self, content_throttle: Arc<ContentThrottle>) -> Self {
+        self.content_throttle = Some(content_throttle);
+        self
+    }
+
+    // Fundraiser cards on the campaign page.
+    pub fn with_profile_repo(mut self, profile_repo: Arc<dyn ProfileRepository>) -> Self {
+        self.profile_repo = Some(profile_repo);
+        self
+    }
+
+    /// Shared with the donation intent service so both paths queue on the same wallet.
+    pub fn donor_lock(&self) -> KeyedLock<i32> {
+        self.in_flight.clone()
+    }
+
+    fn check_message(&self, donor_id: i32, message: Option<&str>) -> Result<(), AppError> {
+        match (&self.content_throttle, message) {
+            (Some(content_throttle), Some(message)) => {
+                content_throttle.check(donor_id, "message", message)
+            }
+            _ => Ok(()),
         }
     }
 
@@ -29,24 +116,240 @@ impl DonationService {
                 "Donation amount must be positive".to_string(),
             ));
         }
+        self.check_message(cmd.donor_id, cmd.message.as_deref())?;
 
-        let campaign = self
-            .campaign_repo
+        // A double-submitted donation waits here for the first one to finish instead of
+        // racing it for the wallet row lock, so it is checked against the updated balance.
+        let _in_flight = self.in_flight.lock(cmd.donor_id).await;
+
+        self.campaign_repo
             .find_by_id(cmd.campaign_id)
             .await?
             .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
 
+        let mut needs_review = cmd.amount > self.review_threshold;
+        if let Some(risk_service) = &self.risk_service {
+            let decision = risk_service
+                .evaluate(EvaluateRiskCommand {
+                    user_id: cmd.donor_id,
+                    ip_address: cmd.ip_address.clone(),
+                    activity: RiskActivity::Donation,
+                    campaign_id: Some(cmd.campaign_id),
+                    amount: cmd.amount,
+                })
+                .await?;
+            match decision {
+                RiskDecision::Allow => {}
+                RiskDecision::Flag(_) => needs_review = true,
+                RiskDecision::Block(reason) => return Err(AppError::Forbidden(reason)),
+            }
+        }
+
         let req = crate::model::donation::NewDonationRequest {
             campaign_id: cmd.campaign_id,
             amount: cmd.amount,
             message: cmd.message,
+            private_note: cmd.private_note,
+            tier_id: cmd.tier_id,
+            referral_code: normalize_referral_code(cmd.referral_code),
+            honoree_name: cmd.honoree_name,
+            honoree_email: cmd.honoree_email,
+            anonymous: cmd.anonymous,
         };
 
-        let donation = self.donation_repo.create(cmd.donor_id, &req).await?;
+        if needs_review {
+            // Tier stock is only claimed when a donation settles.
+            if req.tier_id.is_some() {
+                return Err(AppError::ValidationError(
+                    "Reward tiers are not available for donations that need review".to_string(),
+                ));
+            }
+            return self.make_pending_review_donation(cmd.donor_id, &req).await;
+        }
+
+        self.donation_repo.create(cmd.donor_id, &req).await
+    }
+
+    /// Splits one wallet debit across several campaigns. Either every donation settles or
+    /// none does, so a basket can't be held for review part-way; those go one at a time.
+    pub async fn make_basket_donation(
+        &self,
+        cmd: MakeBasketDonationCommand,
+    ) -> Result<DonationBasketReceipt, AppError> {
+        if cmd.items.iter().any(|item| item.amount <= 0.0) {
+            return Err(AppError::ValidationError(
+                "Donation amount must be positive".to_string(),
+            ));
+        }
+        let total_amount: f64 = cmd.items.iter().map(|item| item.amount).sum();
+        self.check_message(cmd.donor_id, cmd.message.as_deref())?;
+
+        let _in_flight = self.in_flight.lock(cmd.donor_id).await;
 
+        let mut needs_review = total_amount > self.review_threshold;
+        if let Some(risk_service) = &self.risk_service {
+            let decision = risk_service
+                .evaluate(EvaluateRiskCommand {
+                    user_id: cmd.donor_id,
+                    ip_address: cmd.ip_address.clone(),
+                    activity: RiskActivity::Donation,
+                    campaign_id: None,
+                    amount: total_amount,
+                })
+                .await?;
+            match decision {
+                RiskDecision::Allow => {}
+                RiskDecision::Flag(_) => needs_review = true,
+                RiskDecision::Block(reason) => return Err(AppError::Forbidden(reason)),
+            }
+        }
+        if needs_review {
+            return Err(AppError::ValidationError(
+                "Donations that need review cannot be made as a basket".to_string(),
+            ));
+        }
+
+        let (_, receipt) = self
+            .donation_repo
+            .create_basket(cmd.donor_id, cmd.items, cmd.message)
+            .await?;
+        Ok(receipt)
+    }
+
+    // Large or flagged donations only reserve the donor's funds; campaign totals are
+    // untouched until an admin approves the donation.
+    async fn make_pending_review_donation(
+        &self,
+        donor_id: i32,
+        req: &crate::model::donation::NewDonationRequest,
+    ) -> Result<Donation, AppError> {
+        self.donation_repo
+            .create_pending_review(donor_id, req)
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Insufficient wallet balance".to_string()))
+    }
+
+    pub async fn approve_donation(&self, cmd: ReviewDonationCommand) -> Result<Donation, AppError> {
+        let approved = self.donation_repo.approve_pending(cmd.donation_id).await?;
+        let donation = self.require_reviewed(cmd.donation_id, approved).await?;
+        self.invalidate_totals(&donation);
         Ok(donation)
     }
 
+    pub async fn reject_donation(&self, cmd: ReviewDonationCommand) -> Result<Donation, AppError> {
+        let rejected = self
+            .donation_repo
+            .close_pending(cmd.donation_id, DonationStatus::Rejected)
+            .await?;
+        self.require_reviewed(cmd.donation_id, rejected).await
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: std::time::Duration) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job(
+                    "donation_review_expiry",
+                    self.expire_stale_reviews(Duration::hours(REVIEW_MAX_AGE_HOURS)),
+                )
+                .await;
+                rocket::tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    pub async fn expire_stale_reviews(&self, max_age: Duration) -> Result<usize, AppError> {
+        let cutoff = Utc::now() - max_age;
+        let stale = self.donation_repo.find_pending_review_before(cutoff).await?;
+
+        let mut expired = 0;
+        for donation in stale {
+            let closed = self
+                .donation_repo
+                .close_pending(donation.id, DonationStatus::Expired)
+                .await?;
+            if closed.is_some() {
+                expired += 1;
+            }
+        }
+        Ok(expired)
+    }
+
+    // Used when a campaign is taken down after it has already received donations.
+    pub async fn refund_campaign_donations(
+        &self,
+        campaign_id: i32,
+    ) -> Result<CampaignRefundReport, AppError> {
+        let mut report = CampaignRefundReport {
+            campaign_id,
+            refunded_count: 0,
+            refunded_amount: 0.0,
+        };
+
+        // Skipping locked rows lets two refund runs share the work, but an empty
+        // skipping batch only means every remaining row is held elsewhere. One more
+        // batch that waits for those locks decides whether anything is left.
+        let mut skip_locked = true;
+        loop {
+            let batch = self
+                .donation_repo
+                .refund_batch(campaign_id, REFUND_BATCH_SIZE, skip_locked)
+                .await?;
+            if batch.is_empty() {
+                if !skip_locked {
+                    break;
+                }
+                skip_locked = false;
+                continue;
+            }
+            skip_locked = true;
+
+            for donation in &batch {
+                report.refunded_count += 1;
+                report.refunded_amount += donation.amount;
+                self.invalidate_totals(donation);
+            }
+        }
+        Ok(report)
+    }
+
+    pub async fn get_pending_reviews(&self) -> Result<Vec<Donation>, AppError> {
+        self.donation_repo
+            .find_by_status(DonationStatus::PendingReview)
+            .await
+    }
+
+    fn invalidate_totals(&self, donation: &Donation) {
+        if let Some(invalidator) = &self.cache_invalidator {
+            invalidator.invalidate_campaign(donation.campaign_id);
+            invalidator.invalidate_user_campaign(donation.user_id, donation.campaign_id);
+        }
+    }
+
+    // Explains why a review found nothing to approve or reject.
+    async fn require_reviewed(
+        &self,
+        donation_id: i32,
+        reviewed: Option<Donation>,
+    ) -> Result<Donation, AppError> {
+        match reviewed {
+            Some(donation) => Ok(donation),
+            None => {
+                let donation_exists = self
+                    .donation_repo
+                    .find_by_id(donation_id)
+                    .await?
+                    .is_some();
+                if !donation_exists {
+                    Err(AppError::NotFound("Donation not found".to_string()))
+                } else {
+                    Err(AppError::ValidationError(
+                        "Donation is not awaiting review".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+
     pub async fn delete_donation_message(
         &self,
         cmd: DeleteDonationMessageCommand,
@@ -80,49 +383,199 @@ impl DonationService {
         self.donation_repo.find_by_campaign(campaign_id).await
     }
 
+    pub async fn get_public_donations_by_campaign(
+        &self,
+        campaign_id: i32,
+    ) -> Result<Vec<PublicDonation>, AppError> {
+        self.donation_repo.find_public_by_campaign(campaign_id).await
+    }
+
+    /// Callers must first check the requester may `ReadPrivateNotes` on the campaign.
+    pub async fn get_private_notes(
+        &self,
+        campaign_id: i32,
+    ) -> Result<Vec<DonationPrivateNote>, AppError> {
+        self.donation_repo.find_private_notes(campaign_id).await
+    }
+
+    pub async fn get_campaign_donation_stats(
+        &self,
+        campaign_id: i32,
+    ) -> Result<CampaignDonationStats, AppError> {
+        let total_amount = self.donation_repo.campaign_total(campaign_id).await?;
+        let (donor_count, recent_donations) = self
+            .donation_repo
+            .recent_with_donor_count(campaign_id, RECENT_DONATIONS_LIMIT)
+            .await?;
+
+        Ok(CampaignDonationStats {
+            campaign_id,
+            total_amount,
+            donor_count,
+            recent_donations,
+        })
+    }
+
+    /// Everything the campaign page needs in one call: the campaign, its donation
+    /// stats, progress towards the target and days left.
+    pub async fn get_campaign_detail(&self, campaign_id: i32) -> Result<CampaignDetail, AppError> {
+        let campaign = self
+            .campaign_repo
+            .find_by_id(campaign_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+        let stats = self.get_campaign_donation_stats(campaign_id).await?;
+        let owner = match (&self.profile_repo, campaign.fundraiser_id) {
+            (Some(profile_repo), Some(fundraiser_id)) => profile_repo.find(fundraiser_id).await?,
+            _ => None,
+        };
+
+        Ok(CampaignDetail {
+            owner,
+            progress_percent: campaign.progress_percent(),
+            days_remaining: campaign.days_remaining(Utc::now()),
+            campaign,
+            stats,
+        })
+    }
+
+    pub async fn get_campaign_donor_statistics(
+        &self,
+        campaign_id: i32,
+    ) -> Result<CampaignDonorStatistics, AppError> {
+        let counts = self.donation_repo.donor_counts(campaign_id).await?;
+        let counted = self
+            .donation_repo
+            .size_histogram(campaign_id, DONATION_SIZE_BOUNDS.to_vec())
+            .await?;
+
+        let mut size_histogram: Vec<DonationSizeBucket> = (0..=DONATION_SIZE_BOUNDS.len())
+            .map(|bucket| DonationSizeBucket {
+                min_amount: if bucket == 0 {
+                    0.0
+                } else {
+                    DONATION_SIZE_BOUNDS[bucket - 1]
+                },
+                max_amount: DONATION_SIZE_BOUNDS.get(bucket).copied(),
+                donation_count: 0,
+                total_amount: 0.0,
+            })
+            .collect();
+        for row in counted {
+            if let Some(bucket) = size_histogram.get_mut(row.bucket as usize) {
+                bucket.donation_count = row.donation_count;
+                bucket.total_amount = row.total_amount;
+            }
+        }
+
+        Ok(CampaignDonorStatistics {
+            campaign_id,
+            unique_donors: counts.unique_donors,
+            new_donors: counts.unique_donors - counts.repeat_donors,
+            repeat_donors: counts.repeat_donors,
+            median_donation: counts.median_donation,
+            size_histogram,
+        })
+    }
+
+    /// Pass `None` for platform-wide totals.
+    pub async fn get_referral_totals(
+        &self,
+        campaign_id: Option<i32>,
+    ) -> Result<Vec<ReferralTotal>, AppError> {
+        self.donation_repo.referral_totals(campaign_id).await
+    }
+
     pub async fn get_donations_by_user(&self, user_id: i32) -> Result<Vec<Donation>, AppError> {
         self.donation_repo.find_by_user(user_id).await
     }
+
+    // Other donors' donations are reported as missing rather than forbidden.
+    pub async fn get_donation(&self, donation_id: i32, user_id: i32) -> Result<Donation, AppError> {
+        self.donation_repo
+            .find_by_id(donation_id)
+            .await?
+            .filter(|donation| donation.user_id == user_id)
+            .ok_or_else(|| AppError::NotFound("Donation not found".to_string()))
+    }
+
+    /// Only settled donations have a receipt; it stays valid if the donation is later refunded.
+    pub async fn get_receipt(
+        &self,
+        donation_id: i32,
+        user_id: i32,
+    ) -> Result<DonationReceipt, AppError> {
+        self.donation_repo
+            .find_receipt(donation_id)
+            .await?
+            .filter(|receipt| receipt.user_id == user_id)
+            .ok_or_else(|| AppError::NotFound("Receipt not found".to_string()))
+    }
+
+    pub async fn get_donation_summary(&self, user_id: i32) -> Result<DonationSummary, AppError> {
+        let per_campaign = self.donation_repo.sum_by_campaign_for_user(user_id).await?;
+
+        let months = last_twelve_months();
+        let since = Utc
+            .with_ymd_and_hms(months[0].0, months[0].1, 1, 0, 0, 0)
+            .unwrap();
+        let monthly = self
+            .donation_repo
+            .sum_by_month_for_user(user_id, since)
+            .await?;
+
+        // Months without donations are reported as zero so clients can chart all 12.
+        let per_month = months
+            .into_iter()
+            .map(|(year, month)| {
+                let key = format!("{:04}-{:02}", year, month);
+                monthly
+                    .iter()
+                    .find(|total| total.month == key)
+                    .cloned()
+                    .unwrap_or(MonthlyDonationTotal {
+                        month: key,
+                        total_amount: 0.0,
+                        donation_count: 0,
+                    })
+            })
+            .collect();
+
+        Ok(DonationSummary {
+            lifetime_total: per_campaign.iter().map(|total| total.total_amount).sum(),
+            campaigns_supported: per_campaign.len(),
+            per_campaign,
+            per_month,
+        })
+    }
+}
+
+fn last_twelve_months() -> Vec<(i32, u32)> {
+    let now = Utc::now();
+    let current = now.year() * 12 + now.month0() as i32;
+    (current - 11..=current)
+        .map(|index| (index.div_euclid(12), index.rem_euclid(12) as u32 + 1))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::errors::AppError;
-    use crate::model::{campaign::Campaign, donation::Donation};
+    use crate::model::{
+        campaign::Campaign,
+        donation::{CampaignDonorCounts, Donation, DonationSizeCount},
+    };
     use crate::repository::{
-        campaign_repo::{CampaignRepository, MockCampaignRepository},
-        donation_repo::{DonationRepository, MockDonationRepository},
+        campaign_repo::MockCampaignRepository,
+        donation_cache::MockCacheInvalidator,
+        donation_repo::MockDonationRepository,
     };
+    use crate::model::donation_basket::BasketItem;
     use chrono::Utc;
     use mockall::predicate::*;
     use std::sync::Arc;
 
-    fn create_mock_service() -> (
-        DonationService,
-        MockDonationRepository,
-        MockCampaignRepository,
-    ) {
-        let mock_donation_repo = MockDonationRepository::new();
-        let mock_campaign_repo = MockCampaignRepository::new();
-
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
-
-        let mut mock_donation_repo = MockDonationRepository::new();
-        let mut mock_campaign_repo = MockCampaignRepository::new();
-
-        let donation_repo_arc = Arc::new(MockDonationRepository::new());
-        let campaign_repo_arc = Arc::new(MockCampaignRepository::new());
-        let service = DonationService::new(donation_repo_arc.clone(), campaign_repo_arc.clone());
-
-        (
-            service,
-            Arc::try_unwrap(donation_repo_arc).ok().unwrap(),
-            Arc::try_unwrap(campaign_repo_arc).ok().unwrap(),
-        )
-    }
-
     #[tokio::test]
     async fn test_make_donation_success() {
         let mut mock_donation_repo = MockDonationRepository::new();
@@ -132,7 +585,9 @@ mod tests {
         let amount = 50.0;
 
         let expected_campaign = Campaign {
-            /* ... fill campaign details ... */ id: campaign_id, /* status: Active */
+            id: campaign_id,
+            status: "active".to_string(),
+            ..Default::default()
         };
         let expected_donation = Donation {
             id: 1,
@@ -140,6 +595,10 @@ mod tests {
             campaign_id,
             amount,
             message: None,
+            private_note: None,
+            status: DonationStatus::Settled,
+            receipt_number: None,
+            refunded_excess: None,
             created_at: Utc::now(),
         };
         let expected_donation_clone = expected_donation.clone();
@@ -158,14 +617,23 @@ mod tests {
             .times(1)
             .returning(move |_, _| Ok(expected_donation_clone.clone()));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
 
         let cmd = MakeDonationCommand {
             donor_id,
             campaign_id,
             amount,
             message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
         };
         let result = service.make_donation(cmd).await;
 
@@ -176,18 +644,100 @@ mod tests {
         assert_eq!(donation.amount, amount);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_make_donation_runs_serially_per_donor() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration as StdDuration;
+
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+        // Counts donations between the campaign lookup and the insert. mockall serializes
+        // calls to one mock, so the overlap is measured across the two repositories.
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let (entered, max_entered) = (in_flight.clone(), max_in_flight.clone());
+        mock_campaign_repo.expect_find_by_id().returning(move |id| {
+            let now = entered.fetch_add(1, Ordering::SeqCst) + 1;
+            max_entered.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(StdDuration::from_millis(20));
+            Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            }))
+        });
+        let left = in_flight.clone();
+        mock_donation_repo.expect_create().times(2).returning(move |uid, req| {
+            std::thread::sleep(StdDuration::from_millis(50));
+            left.fetch_sub(1, Ordering::SeqCst);
+            Ok(Donation {
+                id: 1,
+                user_id: uid,
+                campaign_id: req.campaign_id,
+                amount: req.amount,
+                message: None,
+                private_note: None,
+                status: DonationStatus::Settled,
+                receipt_number: None,
+                refunded_excess: None,
+                created_at: Utc::now(),
+            })
+        });
+        let service = Arc::new(DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        ));
+
+        let clicks: Vec<_> = (0..2)
+            .map(|_| {
+                let service = service.clone();
+                tokio::spawn(async move {
+                    service
+                        .make_donation(MakeDonationCommand {
+                            donor_id: 1,
+                            campaign_id: 10,
+                            amount: 50.0,
+                            message: None,
+                            private_note: None,
+                            tier_id: None,
+                            referral_code: None,
+                            honoree_name: None,
+                            honoree_email: None,
+                            anonymous: false,
+                            ip_address: None,
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for click in clicks {
+            assert!(click.await.unwrap().is_ok());
+        }
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_make_donation_invalid_amount() {
         let mock_donation_repo = MockDonationRepository::new();
         let mock_campaign_repo = MockCampaignRepository::new();
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
 
         let cmd = MakeDonationCommand {
             donor_id: 1,
             campaign_id: 10,
             amount: 0.0,
             message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
         };
         let result = service.make_donation(cmd).await;
 
@@ -198,9 +748,44 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_make_donation_refuses_repeated_message() {
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+        mock_campaign_repo.expect_find_by_id().times(0);
+        let content_throttle = Arc::new(ContentThrottle::new(
+            &crate::config::ContentThrottleConfig::default(),
+        ));
+        content_throttle.check(1, "message", "Buy followers here").unwrap();
+
+        let service = DonationService::new(
+            Arc::new(MockDonationRepository::new()),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_content_throttle(content_throttle);
+        let cmd = MakeDonationCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 50.0,
+            message: Some("Buy followers here".to_string()),
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
+        };
+        let result = service.make_donation(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::UnprocessableEntity(fields) => assert_eq!(fields[0].code, "duplicate"),
+            _ => panic!("Expected UnprocessableEntity"),
+        }
+    }
+
     #[tokio::test]
     async fn test_make_donation_campaign_not_found() {
-        let mut mock_donation_repo = MockDonationRepository::new();
+        let mock_donation_repo = MockDonationRepository::new();
         let mut mock_campaign_repo = MockCampaignRepository::new();
         let campaign_id = 99;
 
@@ -210,13 +795,22 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(None));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
         let cmd = MakeDonationCommand {
             donor_id: 1,
             campaign_id,
             amount: 50.0,
             message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
         };
         let result = service.make_donation(cmd).await;
 
@@ -240,8 +834,10 @@ mod tests {
             .times(1)
             .returning(|_, _, _| Ok(1));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
         let cmd = DeleteDonationMessageCommand {
             donation_id,
             user_id,
@@ -271,6 +867,10 @@ mod tests {
             campaign_id: 10,
             amount: 50.0,
             message: Some("Test".to_string()),
+            private_note: None,
+            status: DonationStatus::Settled,
+            receipt_number: None,
+            refunded_excess: None,
             created_at: Utc::now(),
         };
         mock_donation_repo
@@ -279,8 +879,10 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(existing_donation.clone())));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
         let cmd = DeleteDonationMessageCommand {
             donation_id,
             user_id: attacker_user_id,
@@ -315,8 +917,10 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(None));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
         let cmd = DeleteDonationMessageCommand {
             donation_id,
             user_id,
@@ -342,6 +946,10 @@ mod tests {
                 campaign_id,
                 amount: 50.0,
                 message: None,
+                private_note: None,
+                status: DonationStatus::Settled,
+                receipt_number: None,
+                refunded_excess: None,
                 created_at: Utc::now(),
             },
             Donation {
@@ -350,6 +958,10 @@ mod tests {
                 campaign_id,
                 amount: 100.0,
                 message: Some("Good luck!".to_string()),
+                private_note: None,
+                status: DonationStatus::Settled,
+                receipt_number: None,
+                refunded_excess: None,
                 created_at: Utc::now(),
             },
         ];
@@ -361,35 +973,721 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(expected_donations_clone.clone()));
 
-        let service =
-            DonationService::new(Arc::new(mock_donation_repo), Arc::new(mock_campaign_repo));
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        );
         let result = service.get_donations_by_campaign(campaign_id).await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), expected_donations);
     }
     // Add test for get_donations_by_user...
-}
 
-// IMPORTANT: You need to derive the Mock structs for your repository traits.
-// Add this near your trait definitions or in a separate test utility module.
-#[cfg(test)]
-use mockall::automock;
-
-#[cfg_attr(test, automock)]
-#[async_trait]
-pub trait DonationRepository: Send + Sync {
-    async fn create(&self, user_id: i32, new_donation: &NewDonationRequest) -> Result<Donation, AppError>;
-    async fn find_by_id(&self, donation_id: i32) -> Result<Option<Donation>, AppError>;
-    async fn find_by_campaign(&self, campaign_id: i32) -> Result<Vec<Donation>, AppError>;
-    async fn find_by_user(&self, user_id: i32) -> Result<Vec<Donation>, AppError>;
-    async fn update_message(&self, donation_id: i32, user_id: i32, message: Option<String>) -> Result<u64, AppError>;
-}
+    fn pending_donation(id: i32, user_id: i32, amount: f64, status: DonationStatus) -> Donation {
+        Donation {
+            id,
+            user_id,
+            campaign_id: 10,
+            amount,
+            message: None,
+            private_note: None,
+            status,
+            receipt_number: None,
+            refunded_excess: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_make_donation_above_threshold_enters_review() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+        let pending = pending_donation(3, 1, 5000.0, DonationStatus::PendingReview);
+
+        mock_campaign_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            })));
+        mock_donation_repo.expect_create().times(0);
+        mock_donation_repo
+            .expect_create_pending_review()
+            .withf(|user_id, req| *user_id == 1 && req.amount == 5000.0)
+            .times(1)
+            .returning(move |_, _| Ok(Some(pending.clone())));
 
-#[cfg_attr(test, automock)]
-#[async_trait]
-pub trait CampaignRepository: Send + Sync {
-    // Assuming you have this trait
-    async fn find_by_id(&self, campaign_id: i32) -> Result<Option<Campaign>, AppError>;
-    // ... other campaign methods ...
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_review_threshold(1000.0);
+        let cmd = MakeDonationCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 5000.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
+        };
+        let result = service.make_donation(cmd).await;
+
+        assert_eq!(result.unwrap().status, DonationStatus::PendingReview);
+    }
+
+    #[tokio::test]
+    async fn test_make_donation_above_threshold_insufficient_balance() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+
+        mock_campaign_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            })));
+        mock_donation_repo.expect_create_pending_review().returning(|_, _| Ok(None));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_review_threshold(1000.0);
+        let cmd = MakeDonationCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 5000.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
+        };
+        let result = service.make_donation(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("Insufficient")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_basket_donation_returns_one_receipt() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+
+        mock_donation_repo
+            .expect_create_basket()
+            .withf(|user_id, items, _| *user_id == 1 && items.len() == 2)
+            .times(1)
+            .returning(|user_id, items, _| {
+                let donations: Vec<Donation> = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| Donation {
+                        id: i as i32 + 1,
+                        user_id,
+                        campaign_id: item.campaign_id,
+                        amount: item.amount,
+                        message: None,
+                        private_note: None,
+                        status: DonationStatus::Settled,
+                        receipt_number: None,
+                        refunded_excess: None,
+                        created_at: Utc::now(),
+                    })
+                    .collect();
+                let receipt = DonationBasketReceipt {
+                    user_id,
+                    total_amount: 300.0,
+                    refunded_excess: None,
+                    issued_at: Utc::now(),
+                    lines: vec![],
+                };
+                Ok((donations, receipt))
+            });
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let cmd = MakeBasketDonationCommand {
+            donor_id: 1,
+            items: vec![
+                BasketItem { campaign_id: 10, amount: 100.0 },
+                BasketItem { campaign_id: 11, amount: 200.0 },
+            ],
+            message: None,
+            ip_address: None,
+        };
+
+        let receipt = service.make_basket_donation(cmd).await.unwrap();
+        assert_eq!(receipt.total_amount, 300.0);
+    }
+
+    #[tokio::test]
+    async fn test_basket_donation_above_threshold_is_rejected() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo.expect_create_basket().times(0);
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        )
+        .with_review_threshold(1000.0);
+        let cmd = MakeBasketDonationCommand {
+            donor_id: 1,
+            items: vec![
+                BasketItem { campaign_id: 10, amount: 600.0 },
+                BasketItem { campaign_id: 11, amount: 600.0 },
+            ],
+            message: None,
+            ip_address: None,
+        };
+
+        match service.make_basket_donation(cmd).await.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("review")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approve_donation_settles_the_donation() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let settled = pending_donation(3, 1, 5000.0, DonationStatus::Settled);
+
+        mock_donation_repo
+            .expect_approve_pending()
+            .with(eq(3))
+            .times(1)
+            .returning(move |_| Ok(Some(settled.clone())));
+
+        let mut mock_invalidator = MockCacheInvalidator::new();
+        mock_invalidator
+            .expect_invalidate_campaign()
+            .with(eq(10))
+            .times(1)
+            .return_const(());
+        mock_invalidator
+            .expect_invalidate_user_campaign()
+            .with(eq(1), eq(10))
+            .times(1)
+            .return_const(());
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        )
+        .with_cache_invalidator(Arc::new(mock_invalidator));
+        let cmd = ReviewDonationCommand {
+            donation_id: 3,
+            admin_id: 99,
+        };
+        let result = service.approve_donation(cmd).await;
+
+        assert_eq!(result.unwrap().status, DonationStatus::Settled);
+    }
+
+    #[tokio::test]
+    async fn test_reject_donation_releases_hold() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let rejected = pending_donation(3, 1, 5000.0, DonationStatus::Rejected);
+
+        mock_donation_repo
+            .expect_close_pending()
+            .with(eq(3), eq(DonationStatus::Rejected))
+            .times(1)
+            .returning(move |_, _| Ok(Some(rejected.clone())));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let cmd = ReviewDonationCommand {
+            donation_id: 3,
+            admin_id: 99,
+        };
+        let result = service.reject_donation(cmd).await;
+
+        assert_eq!(result.unwrap().status, DonationStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_approve_donation_not_pending_review() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let settled = pending_donation(3, 1, 50.0, DonationStatus::Settled);
+
+        mock_donation_repo
+            .expect_approve_pending()
+            .returning(|_| Ok(None));
+        mock_donation_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .returning(move |_| Ok(Some(settled.clone())));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let cmd = ReviewDonationCommand {
+            donation_id: 3,
+            admin_id: 99,
+        };
+        let result = service.approve_donation(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("not awaiting review")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expire_stale_reviews_releases_holds() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let stale = vec![
+            pending_donation(3, 1, 5000.0, DonationStatus::PendingReview),
+            pending_donation(4, 2, 7000.0, DonationStatus::PendingReview),
+        ];
+
+        mock_donation_repo
+            .expect_find_pending_review_before()
+            .times(1)
+            .returning(move |_| Ok(stale.clone()));
+        mock_donation_repo
+            .expect_close_pending()
+            .with(eq(3), eq(DonationStatus::Expired))
+            .returning(|_, _| Ok(Some(pending_donation(3, 1, 5000.0, DonationStatus::Expired))));
+        // Donation 4 was approved concurrently, so nothing is expired for it.
+        mock_donation_repo
+            .expect_close_pending()
+            .with(eq(4), eq(DonationStatus::Expired))
+            .returning(|_, _| Ok(None));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let result = service.expire_stale_reviews(Duration::hours(48)).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    fn risk_service_with_decision(action: Option<crate::model::risk::RiskAction>) -> Arc<RiskService> {
+        use crate::model::risk::{FlaggedActivity, RiskRule, RiskRuleKind};
+        use crate::repository::risk_repo::MockRiskRepository;
+
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .returning(|_, _| Ok(false));
+        mock_risk_repo.expect_find_enabled_rules().returning(move || {
+            Ok(action
+                .into_iter()
+                .map(|action| RiskRule {
+                    id: 1,
+                    kind: RiskRuleKind::DailyAmountCap,
+                    threshold: 10.0,
+                    action,
+                    enabled: true,
+                    created_at: Utc::now(),
+                })
+                .collect())
+        });
+        mock_risk_repo
+            .expect_sum_amount_since()
+            .returning(|_, _, _| Ok(0.0));
+        mock_risk_repo
+            .expect_record_flag()
+            .returning(|user_id, rule_id, activity, amount, action, reason| {
+                Ok(FlaggedActivity {
+                    id: 1,
+                    user_id,
+                    rule_id,
+                    activity,
+                    amount,
+                    action,
+                    reason,
+                    created_at: Utc::now(),
+                })
+            });
+        Arc::new(RiskService::new(Arc::new(mock_risk_repo)))
+    }
+
+    #[tokio::test]
+    async fn test_make_donation_blocked_by_risk_rule() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+
+        mock_campaign_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            })));
+        mock_donation_repo.expect_create().times(0);
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_risk_service(risk_service_with_decision(Some(
+            crate::model::risk::RiskAction::Block,
+        )));
+        let cmd = MakeDonationCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 50.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
+        };
+        let result = service.make_donation(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("Daily amount cap")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_make_donation_flagged_by_risk_rule_enters_review() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+        let pending = pending_donation(3, 1, 50.0, DonationStatus::PendingReview);
+
+        mock_campaign_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(Campaign {
+                id,
+                ..Default::default()
+            })));
+        mock_donation_repo.expect_create().times(0);
+        mock_donation_repo
+            .expect_create_pending_review()
+            .times(1)
+            .returning(move |_, _| Ok(Some(pending.clone())));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_risk_service(risk_service_with_decision(Some(
+            crate::model::risk::RiskAction::Flag,
+        )));
+        let cmd = MakeDonationCommand {
+            donor_id: 1,
+            campaign_id: 10,
+            amount: 50.0,
+            message: None,
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+            ip_address: None,
+        };
+        let result = service.make_donation(cmd).await;
+
+        assert_eq!(result.unwrap().status, DonationStatus::PendingReview);
+    }
+
+    #[tokio::test]
+    async fn test_get_campaign_detail() {
+        use crate::model::profile::Profile;
+        use crate::repository::profile_repo::MockProfileRepository;
+
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut mock_campaign_repo = MockCampaignRepository::new();
+
+        mock_campaign_repo
+            .expect_find_by_id()
+            .with(eq(10))
+            .returning(|id| Ok(Some(Campaign {
+                id,
+                fundraiser_id: Some(3),
+                target_amount: 1000.0,
+                collected_amount: 500.0,
+                status: "active".to_string(),
+                ..Default::default()
+            })));
+        mock_campaign_repo
+            .expect_find_by_id()
+            .with(eq(99))
+            .returning(|_| Ok(None));
+        mock_donation_repo
+            .expect_campaign_total()
+            .returning(|_| Ok(500.0));
+        mock_donation_repo
+            .expect_recent_with_donor_count()
+            .returning(|_, _| Ok((2, vec![])));
+        let mut mock_profile_repo = MockProfileRepository::new();
+        mock_profile_repo
+            .expect_find()
+            .with(eq(3))
+            .returning(|user_id| Ok(Some(Profile {
+                user_id,
+                display_name: "Yayasan Peduli".to_string(),
+                avatar_url: None,
+                bio: None,
+                updated_at: Utc::now(),
+            })));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_campaign_repo),
+        )
+        .with_profile_repo(Arc::new(mock_profile_repo));
+        let detail = service.get_campaign_detail(10).await.unwrap();
+
+        assert_eq!(detail.campaign.id, 10);
+        assert_eq!(detail.owner.unwrap().display_name, "Yayasan Peduli");
+        assert_eq!(detail.stats.donor_count, 2);
+        assert_eq!(detail.progress_percent, 50.0);
+        assert_eq!(detail.days_remaining, None);
+        assert!(matches!(service.get_campaign_detail(99).await, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_campaign_donation_stats() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let recent = pending_donation(3, 1, 50.0, DonationStatus::Settled);
+
+        mock_donation_repo
+            .expect_campaign_total()
+            .with(eq(10))
+            .times(1)
+            .returning(|_| Ok(250.0));
+        mock_donation_repo
+            .expect_recent_with_donor_count()
+            .with(eq(10), eq(RECENT_DONATIONS_LIMIT))
+            .times(1)
+            .returning(move |_, _| Ok((4, vec![recent.clone()])));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let stats = service.get_campaign_donation_stats(10).await.unwrap();
+
+        assert_eq!(stats.total_amount, 250.0);
+        assert_eq!(stats.donor_count, 4);
+        assert_eq!(stats.recent_donations.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_campaign_donor_statistics_fills_empty_buckets() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_donor_counts()
+            .with(eq(10))
+            .returning(|_| {
+                Ok(CampaignDonorCounts {
+                    unique_donors: 5,
+                    repeat_donors: 2,
+                    median_donation: Some(75_000.0),
+                })
+            });
+        mock_donation_repo
+            .expect_size_histogram()
+            .with(eq(10), eq(DONATION_SIZE_BOUNDS.to_vec()))
+            .returning(|_, _| {
+                Ok(vec![
+                    DonationSizeCount {
+                        bucket: 1,
+                        donation_count: 4,
+                        total_amount: 300_000.0,
+                    },
+                    DonationSizeCount {
+                        bucket: 5,
+                        donation_count: 1,
+                        total_amount: 10_000_000.0,
+                    },
+                ])
+            });
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        let stats = service.get_campaign_donor_statistics(10).await.unwrap();
+        assert_eq!(stats.new_donors, 3);
+        assert_eq!(stats.size_histogram.len(), 6);
+        assert_eq!(stats.size_histogram[0].donation_count, 0);
+        assert_eq!(stats.size_histogram[1].min_amount, 50_000.0);
+        assert_eq!(stats.size_histogram[1].max_amount, Some(100_000.0));
+        assert_eq!(stats.size_histogram[1].donation_count, 4);
+        assert_eq!(stats.size_histogram[5].max_amount, None);
+        assert_eq!(stats.size_histogram[5].donation_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_refund_campaign_donations_drains_batches() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let mut seq = mockall::Sequence::new();
+
+        mock_donation_repo
+            .expect_refund_batch()
+            .with(eq(10), eq(REFUND_BATCH_SIZE), eq(true))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| {
+                Ok(vec![
+                    pending_donation(1, 1, 100.0, DonationStatus::Refunded),
+                    pending_donation(2, 2, 50.0, DonationStatus::Refunded),
+                ])
+            });
+        // Everything left is locked by another run, so the next batch waits for it.
+        mock_donation_repo
+            .expect_refund_batch()
+            .with(eq(10), eq(REFUND_BATCH_SIZE), eq(true))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(vec![]));
+        mock_donation_repo
+            .expect_refund_batch()
+            .with(eq(10), eq(REFUND_BATCH_SIZE), eq(false))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(vec![pending_donation(3, 3, 25.0, DonationStatus::Refunded)]));
+        mock_donation_repo
+            .expect_refund_batch()
+            .with(eq(10), eq(REFUND_BATCH_SIZE), eq(true))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(vec![]));
+        mock_donation_repo
+            .expect_refund_batch()
+            .with(eq(10), eq(REFUND_BATCH_SIZE), eq(false))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(vec![]));
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let report = service.refund_campaign_donations(10).await.unwrap();
+
+        assert_eq!(report.refunded_count, 3);
+        assert_eq!(report.refunded_amount, 175.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_donation_summary() {
+        use crate::model::donation::CampaignDonationTotal;
+
+        let mut mock_donation_repo = MockDonationRepository::new();
+        let current_month = super::last_twelve_months()[11];
+        let current_key = format!("{:04}-{:02}", current_month.0, current_month.1);
+        let current_key_clone = current_key.clone();
+
+        mock_donation_repo
+            .expect_sum_by_campaign_for_user()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    CampaignDonationTotal {
+                        campaign_id: 10,
+                        total_amount: 150.0,
+                        donation_count: 2,
+                    },
+                    CampaignDonationTotal {
+                        campaign_id: 11,
+                        total_amount: 50.0,
+                        donation_count: 1,
+                    },
+                ])
+            });
+        mock_donation_repo
+            .expect_sum_by_month_for_user()
+            .withf(|uid, _| *uid == 1)
+            .times(1)
+            .returning(move |_, _| {
+                Ok(vec![MonthlyDonationTotal {
+                    month: current_key_clone.clone(),
+                    total_amount: 200.0,
+                    donation_count: 3,
+                }])
+            });
+
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+        let summary = service.get_donation_summary(1).await.unwrap();
+
+        assert_eq!(summary.lifetime_total, 200.0);
+        assert_eq!(summary.campaigns_supported, 2);
+        assert_eq!(summary.per_month.len(), 12);
+        assert_eq!(summary.per_month[11].month, current_key);
+        assert_eq!(summary.per_month[11].total_amount, 200.0);
+        assert!(summary.per_month[..11].iter().all(|m| m.donation_count == 0));
+    }
+
+    #[tokio::test]
+    async fn test_get_receipt_hides_other_donors_receipts() {
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_find_receipt()
+            .with(eq(3))
+            .returning(|_| {
+                Ok(Some(DonationReceipt {
+                    receipt_number: "KWT/2026/000042".to_string(),
+                    donation_id: 3,
+                    user_id: 1,
+                    campaign_id: 10,
+                    campaign_title: "Sumur desa".to_string(),
+                    amount: 50_000.0,
+                    status: DonationStatus::Settled,
+                    issued_at: Utc::now(),
+                }))
+            });
+        let service = DonationService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(MockCampaignRepository::new()),
+        );
+
+        let receipt = service.get_receipt(3, 1).await.unwrap();
+        assert_eq!(receipt.receipt_number, "KWT/2026/000042");
+        match service.get_receipt(3, 2).await.err().unwrap() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Receipt not found"),
+            _ => panic!("Expected NotFound"),
+        }
+    }
+
+    #[test]
+    fn test_normalize_referral_code() {
+        assert_eq!(
+            normalize_referral_code(Some("  ig-bio ".to_string())),
+            Some("ig-bio".to_string())
+        );
+        assert_eq!(normalize_referral_code(Some("   ".to_string())), None);
+        assert_eq!(normalize_referral_code(None), None);
+    }
+
+    #[test]
+    fn test_last_twelve_months_is_contiguous() {
+        let months = super::last_twelve_months();
+        assert_eq!(months.len(), 12);
+        for pair in months.windows(2) {
+            let (y0, m0) = pair[0];
+            let (y1, m1) = pair[1];
+            assert_eq!(y1 * 12 + m1 as i32, y0 * 12 + m0 as i32 + 1);
+        }
+    }
 }
@@ -0,0 +1,135 @@
+use crate::errors::AppError;
+use crate::model::campaign_member::CampaignAction;
+use crate::model::donation_tier::{DonationTier, NewDonationTierRequest, RewardClaim};
+use crate::repository::donation_tier_repo::DonationTierRepository;
+use crate::service::campaign_member_service::CampaignMemberService;
+use std::sync::Arc;
+
+pub struct DonationTierService {
+    tier_repo: Arc<dyn DonationTierRepository>,
+    member_service: Arc<CampaignMemberService>,
+}
+
+impl DonationTierService {
+    pub fn new(
+        tier_repo: Arc<dyn DonationTierRepository>,
+        member_service: Arc<CampaignMemberService>,
+    ) -> Self {
+        DonationTierService {
+            tier_repo,
+            member_service,
+        }
+    }
+
+    pub async fn get_tiers(&self, campaign_id: i32) -> Result<Vec<DonationTier>, AppError> {
+        self.tier_repo.find_by_campaign(campaign_id).await
+    }
+
+    pub async fn create_tier(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        mut tier: NewDonationTierRequest,
+    ) -> Result<DonationTier, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        tier.title = tier.title.trim().to_string();
+        if tier.title.is_empty() {
+            return Err(AppError::ValidationError(
+                "title must be between 1 and 100 characters".to_string(),
+            ));
+        }
+        tier.description = tier
+            .description
+            .map(|description| description.trim().to_string())
+            .filter(|description| !description.is_empty());
+        self.tier_repo.create(campaign_id, &tier).await
+    }
+
+    /// Settled donations that claimed a reward, oldest first, for fulfillment.
+    pub async fn get_claims(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+    ) -> Result<Vec<RewardClaim>, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        self.tier_repo.find_claims(campaign_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign_member::CampaignRole;
+    use crate::repository::campaign_member_repo::MockCampaignMemberRepository;
+    use crate::repository::donation_tier_repo::MockDonationTierRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn member_service(role: Option<CampaignRole>) -> Arc<CampaignMemberService> {
+        let mut mock_member_repo = MockCampaignMemberRepository::new();
+        mock_member_repo
+            .expect_find_role()
+            .with(eq(10), eq(2))
+            .returning(move |_, _| Ok(role));
+        Arc::new(CampaignMemberService::new(Arc::new(mock_member_repo)))
+    }
+
+    #[tokio::test]
+    async fn test_create_tier_trims_input() {
+        let mut mock_tier_repo = MockDonationTierRepository::new();
+        mock_tier_repo
+            .expect_create()
+            .withf(|campaign_id, tier| {
+                *campaign_id == 10 && tier.title == "Kaos" && tier.description.is_none()
+            })
+            .times(1)
+            .returning(|campaign_id, tier| {
+                Ok(DonationTier {
+                    id: 1,
+                    campaign_id,
+                    title: tier.title.clone(),
+                    description: tier.description.clone(),
+                    min_amount: tier.min_amount,
+                    quantity: tier.quantity,
+                    remaining: tier.quantity,
+                    created_at: Utc::now(),
+                })
+            });
+        let service = DonationTierService::new(
+            Arc::new(mock_tier_repo),
+            member_service(Some(CampaignRole::Owner)),
+        );
+
+        let tier = service
+            .create_tier(
+                10,
+                2,
+                NewDonationTierRequest {
+                    title: "  Kaos ".to_string(),
+                    description: Some("   ".to_string()),
+                    min_amount: 100_000.0,
+                    quantity: Some(50),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(tier.remaining, Some(50));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_read_claims() {
+        let mut mock_tier_repo = MockDonationTierRepository::new();
+        mock_tier_repo.expect_find_claims().never();
+        let service = DonationTierService::new(
+            Arc::new(mock_tier_repo),
+            member_service(Some(CampaignRole::Viewer)),
+        );
+
+        let result = service.get_claims(10, 2).await;
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+}
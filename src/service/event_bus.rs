@@ -0,0 +1,458 @@
+use crate::errors::AppError;
+use crate::model::event::{
+    DomainEvent, DomainEventKind, PublishReport, SubscriberOutcome, SubscriberResult,
+};
+use crate::model::outbox::OutboxEvent;
+use crate::repository::outbox_repo;
+use crate::service::notification_delivery_service::NotificationDeliveryService;
+use crate::service::outbox_dispatcher::OutboxHandler;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use rocket::futures::future::join_all;
+use rocket::serde::json::{self, Value};
+use sqlx::PgConnection;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+use mockall::automock;
+
+pub const SUBSCRIBER_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    /// Stable identifier; failed deliveries are tracked and retried under it.
+    fn name(&self) -> &'static str;
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Priority {
+    /// Persists state; runs first, in order, without a timeout.
+    Critical,
+    /// Runs concurrently once critical subscribers are done, each under the timeout.
+    Background,
+}
+
+/// Delivery counts per subscriber and outcome, for the metrics endpoint.
+#[derive(Default)]
+pub struct SubscriberStats {
+    deliveries: DashMap<(&'static str, &'static str), u64>,
+}
+
+impl SubscriberStats {
+    fn record(&self, result: &SubscriberResult) {
+        *self
+            .deliveries
+            .entry((result.subscriber, result.outcome.as_str()))
+            .or_default() += 1;
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        let mut counts: Vec<_> = self
+            .deliveries
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect();
+        counts.sort();
+        let mut out = String::from(
+            "# HELP event_subscriber_deliveries_total Event deliveries by subscriber and outcome\n\
+             # TYPE event_subscriber_deliveries_total counter\n",
+        );
+        for ((subscriber, outcome), count) in counts {
+            out.push_str(&format!(
+                "event_subscriber_deliveries_total{{subscriber=\"{}\",outcome=\"{}\"}} {}\n",
+                subscriber, outcome, count
+            ));
+        }
+        out
+    }
+}
+
+type Subscribers = Vec<(Priority, Arc<dyn EventSubscriber>)>;
+
+pub struct EventBus {
+    subscribers: HashMap<DomainEventKind, Subscribers>,
+    subscriber_timeout: Duration,
+    stats: Arc<SubscriberStats>,
+    deliveries: Option<Arc<NotificationDeliveryService>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        EventBus {
+            subscribers: HashMap::new(),
+            subscriber_timeout: SUBSCRIBER_TIMEOUT,
+            stats: Arc::new(SubscriberStats::default()),
+            deliveries: None,
+        }
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Notification side effects (alerts, metrics, pushes). A slow one is cut off
+    /// after the subscriber timeout and never holds up the others.
+    pub fn subscribe(
+        mut self,
+        kind: DomainEventKind,
+        subscriber: Arc<dyn EventSubscriber>,
+    ) -> Self {
+        self.subscribers
+            .entry(kind)
+            .or_default()
+            .push((Priority::Background, subscriber));
+        self
+    }
+
+    /// Subscribers that persist state other deliveries rely on. They run before any
+    /// background subscriber and are never cut off.
+    pub fn subscribe_critical(
+        mut self,
+        kind: DomainEventKind,
+        subscriber: Arc<dyn EventSubscriber>,
+    ) -> Self {
+        self.subscribers
+            .entry(kind)
+            .or_default()
+            .push((Priority::Critical, subscriber));
+        self
+    }
+
+    pub fn with_subscriber_timeout(mut self, subscriber_timeout: Duration) -> Self {
+        self.subscriber_timeout = subscriber_timeout;
+        self
+    }
+
+    /// Outbox events then count as handled once every subscriber has run; a failed
+    /// subscriber is retried on its own instead of the whole event being redelivered.
+    pub fn with_deliveries(mut self, deliveries: Arc<NotificationDeliveryService>) -> Self {
+        self.deliveries = Some(deliveries);
+        self
+    }
+
+    pub fn stats(&self) -> Arc<SubscriberStats> {
+        self.stats.clone()
+    }
+
+    async fn run(
+        &self,
+        priority: Priority,
+        subscriber: &dyn EventSubscriber,
+        event: &DomainEvent,
+    ) -> SubscriberResult {
+        let started = Instant::now();
+        let result = match priority {
+            Priority::Critical => Ok(subscriber.on_event(event).await),
+            Priority::Background => {
+                rocket::tokio::time::timeout(self.subscriber_timeout, subscriber.on_event(event))
+                    .await
+            }
+        };
+        let outcome = match result {
+            Ok(Ok(())) => SubscriberOutcome::Delivered,
+            Ok(Err(e)) => SubscriberOutcome::Failed {
+                error: e.to_string(),
+            },
+            Err(_) => SubscriberOutcome::TimedOut,
+        };
+        let result = SubscriberResult {
+            subscriber: subscriber.name(),
+            outcome,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        };
+        if result.outcome != SubscriberOutcome::Delivered {
+            tracing::warn!(
+                event_type = event.kind().as_str(),
+                subscriber = result.subscriber,
+                outcome = result.outcome.as_str(),
+                elapsed_ms = result.elapsed_ms,
+                "Subscriber for {}: {}",
+                event.kind().as_str(),
+                result
+            );
+        }
+        self.stats.record(&result);
+        result
+    }
+
+    /// Runs every subscriber of the event, even after a failure: critical ones in
+    /// order, then the rest concurrently.
+    pub async fn deliver(&self, event: &DomainEvent) -> PublishReport {
+        let Some(subscribers) = self.subscribers.get(&event.kind()) else {
+            return PublishReport::default();
+        };
+
+        let mut results = Vec::with_capacity(subscribers.len());
+        for (priority, subscriber) in subscribers {
+            if *priority == Priority::Critical {
+                results.push(self.run(*priority, subscriber.as_ref(), event).await);
+            }
+        }
+        let background = subscribers
+            .iter()
+            .filter(|(priority, _)| *priority == Priority::Background)
+            .map(|(priority, subscriber)| self.run(*priority, subscriber.as_ref(), event));
+        results.extend(join_all(background).await);
+        PublishReport { results }
+    }
+
+    /// Delivers to one named subscriber only; used to retry a failed delivery.
+    /// None if no such subscriber handles this kind of event.
+    pub async fn deliver_to(&self, event: &DomainEvent, name: &str) -> Option<SubscriberResult> {
+        let (priority, subscriber) = self
+            .subscribers
+            .get(&event.kind())?
+            .iter()
+            .find(|(_, subscriber)| subscriber.name() == name)?;
+        Some(self.run(*priority, subscriber.as_ref(), event).await)
+    }
+
+    // The first failure is returned so outbox delivery retries the event.
+    pub async fn publish(&self, event: &DomainEvent) -> Result<(), AppError> {
+        let report = self.deliver(event).await;
+        match report.failures().next() {
+            Some(failure) => Err(AppError::InternalServerError(failure.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    // Only an error recording a failure is returned, so the outbox retries the event.
+    async fn publish_tracked(
+        &self,
+        outbox_id: i64,
+        event: &DomainEvent,
+        deliveries: &NotificationDeliveryService,
+    ) -> Result<(), AppError> {
+        let report = self.deliver(event).await;
+        for failure in report.failures() {
+            deliveries.record_failure(outbox_id, event, failure).await?;
+        }
+        Ok(())
+    }
+}
+
+pub fn decode_event(outbox_id: i64, payload: &Value) -> Result<DomainEvent, AppError> {
+    json::from_value(payload.clone()).map_err(|e| {
+        AppError::InternalServerError(format!("Unreadable outbox event {}: {}", outbox_id, e))
+    })
+}
+
+/// Durable publish: stores the event in the outbox on the caller's transaction.
+/// The outbox dispatcher later hands it to the `EventBus`.
+pub async fn enqueue_event(conn: &mut PgConnection, event: &DomainEvent) -> Result<(), AppError> {
+    let payload =
+        json::to_value(event).map_err(|e| AppError::InternalServerError(e.to_string()))?;
+    outbox_repo::enqueue(conn, event.kind().as_str(), &payload).await
+}
+
+#[async_trait]
+impl OutboxHandler for EventBus {
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), AppError> {
+        let domain_event = decode_event(event.id, &event.payload)?;
+        match &self.deliveries {
+            Some(deliveries) => {
+                self.publish_tracked(event.id, &domain_event, deliveries)
+                    .await
+            }
+            None => self.publish(&domain_event).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::outbox::OutboxStatus;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct SlowSubscriber {
+        name: &'static str,
+        delay: Duration,
+        finished: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl EventSubscriber for SlowSubscriber {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn on_event(&self, _event: &DomainEvent) -> Result<(), AppError> {
+            rocket::tokio::time::sleep(self.delay).await;
+            self.finished.lock().unwrap().push(self.name);
+            Ok(())
+        }
+    }
+
+    fn donation_created() -> DomainEvent {
+        DomainEvent::DonationCreated {
+            donation_id: 1,
+            user_id: 2,
+            campaign_id: 10,
+            amount: 50.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_only_reaches_subscribers_of_kind() {
+        let mut donation_subscriber = MockEventSubscriber::new();
+        donation_subscriber.expect_name().return_const("donations");
+        donation_subscriber
+            .expect_on_event()
+            .withf(|event| event.kind() == DomainEventKind::DonationCreated)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut payout_subscriber = MockEventSubscriber::new();
+        payout_subscriber.expect_name().return_const("payouts");
+        payout_subscriber.expect_on_event().times(0);
+
+        let bus = EventBus::new()
+            .subscribe(
+                DomainEventKind::DonationCreated,
+                Arc::new(donation_subscriber),
+            )
+            .subscribe(DomainEventKind::PayoutApproved, Arc::new(payout_subscriber));
+
+        assert!(bus.publish(&donation_created()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_publish_runs_all_subscribers_and_reports_failure() {
+        let mut failing = MockEventSubscriber::new();
+        failing.expect_name().return_const("failing");
+        failing
+            .expect_on_event()
+            .times(1)
+            .returning(|_| Err(AppError::InternalServerError("SSE hub closed".to_string())));
+        let mut succeeding = MockEventSubscriber::new();
+        succeeding.expect_name().return_const("succeeding");
+        succeeding.expect_on_event().times(1).returning(|_| Ok(()));
+
+        let bus = EventBus::new()
+            .subscribe(DomainEventKind::DonationCreated, Arc::new(failing))
+            .subscribe(DomainEventKind::DonationCreated, Arc::new(succeeding));
+
+        assert!(bus.publish(&donation_created()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_outbox_event_is_decoded_and_published() {
+        let mut subscriber = MockEventSubscriber::new();
+        subscriber.expect_name().return_const("subscriber");
+        subscriber
+            .expect_on_event()
+            .withf(|event| *event == DomainEvent::CampaignCompleted { campaign_id: 10 })
+            .times(1)
+            .returning(|_| Ok(()));
+        let bus =
+            EventBus::new().subscribe(DomainEventKind::CampaignCompleted, Arc::new(subscriber));
+
+        let outbox_event = OutboxEvent {
+            id: 1,
+            event_type: "campaign_completed".to_string(),
+            payload: json::to_value(DomainEvent::CampaignCompleted { campaign_id: 10 }).unwrap(),
+            attempts: 0,
+            last_error: None,
+            status: OutboxStatus::Pending,
+            next_attempt_at: Utc::now(),
+            created_at: Utc::now(),
+            dispatched_at: None,
+        };
+
+        assert!(bus.handle(&outbox_event).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_tracked_delivery_records_failed_subscriber_only() {
+        use crate::config::NotificationRetryConfig;
+        use crate::repository::notification_delivery_repo::MockNotificationDeliveryRepository;
+
+        let mut failing = MockEventSubscriber::new();
+        failing.expect_name().return_const("ops_alerter");
+        failing
+            .expect_on_event()
+            .returning(|_| Err(AppError::InternalServerError("webhook down".to_string())));
+        let mut succeeding = MockEventSubscriber::new();
+        succeeding.expect_name().return_const("metrics");
+        succeeding.expect_on_event().times(1).returning(|_| Ok(()));
+        let mut mock_delivery_repo = MockNotificationDeliveryRepository::new();
+        mock_delivery_repo
+            .expect_record_failure()
+            .withf(|outbox_id, event_type, subscriber, error, _| {
+                *outbox_id == 7
+                    && event_type == "donation_created"
+                    && subscriber == "ops_alerter"
+                    && error.contains("webhook down")
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let deliveries = NotificationDeliveryService::new(
+            Arc::new(mock_delivery_repo),
+            &NotificationRetryConfig::default(),
+        );
+
+        let bus = EventBus::new()
+            .subscribe(DomainEventKind::DonationCreated, Arc::new(failing))
+            .subscribe(DomainEventKind::DonationCreated, Arc::new(succeeding))
+            .with_deliveries(Arc::new(deliveries));
+        let outbox_event = OutboxEvent {
+            id: 7,
+            event_type: "donation_created".to_string(),
+            payload: json::to_value(donation_created()).unwrap(),
+            attempts: 0,
+            last_error: None,
+            status: OutboxStatus::Pending,
+            next_attempt_at: Utc::now(),
+            created_at: Utc::now(),
+            dispatched_at: None,
+        };
+
+        assert!(bus.handle(&outbox_event).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_critical_subscribers_run_first_and_slow_ones_are_cut_off() {
+        let finished = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = |name, delay_ms| {
+            Arc::new(SlowSubscriber {
+                name,
+                delay: Duration::from_millis(delay_ms),
+                finished: finished.clone(),
+            })
+        };
+        let bus = EventBus::new()
+            .with_subscriber_timeout(Duration::from_millis(100))
+            .subscribe(DomainEventKind::DonationCreated, subscriber("push", 5_000))
+            .subscribe(DomainEventKind::DonationCreated, subscriber("sse", 10))
+            .subscribe_critical(DomainEventKind::DonationCreated, subscriber("in_app", 150));
+
+        let started = Instant::now();
+        let report = bus.deliver(&donation_created()).await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(*finished.lock().unwrap(), vec!["in_app", "sse"]);
+        let outcomes: Vec<_> = report
+            .results
+            .iter()
+            .map(|result| (result.subscriber, result.outcome.clone()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("in_app", SubscriberOutcome::Delivered),
+                ("push", SubscriberOutcome::TimedOut),
+                ("sse", SubscriberOutcome::Delivered),
+            ]
+        );
+        assert!(bus.stats().to_prometheus().contains(
+            "event_subscriber_deliveries_total{subscriber=\"push\",outcome=\"timed_out\"} 1"
+        ));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::errors::AppError;
+use crate::model::evidence::{EvidenceFile, EvidenceMetadata, EvidenceProcessingReport};
+use crate::repository::evidence_repo::EvidenceRepository;
+use crate::service::background_job::run_job;
+use crate::service::donation_export_service::ObjectStore;
+use std::sync::Arc;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const JPEG_SOI: &[u8] = b"\xFF\xD8";
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+
+fn unsupported() -> AppError {
+    AppError::ValidationError("Unsupported evidence file type".to_string())
+}
+
+fn malformed(kind: &str) -> AppError {
+    AppError::ValidationError(format!("Evidence file is not a valid {}", kind))
+}
+
+/// Counts `/Type /Page` objects, skipping the `/Type /Pages` tree nodes. Good enough for
+/// the uncompressed object streams that scanners and office exports produce.
+fn count_pdf_pages(body: &[u8]) -> i32 {
+    let mut pages = 0;
+    let mut rest = body;
+    while let Some(index) = rest.windows(5).position(|window| window == b"/Type") {
+        rest = &rest[index + 5..];
+        let after = rest
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let name = &rest[after..];
+        if name.starts_with(b"/Page") && !name[5..].starts_with(b"s") {
+            pages += 1;
+        }
+    }
+    pages
+}
+
+// Drops APP1 Exif segments. Everything from the start-of-scan marker on is image data
+// and is copied as is.
+fn strip_jpeg_exif(body: &[u8]) -> Result<(Vec<u8>, bool), AppError> {
+    let mut out = Vec::with_capacity(body.len());
+    out.extend_from_slice(JPEG_SOI);
+    let mut stripped = false;
+    let mut pos = JPEG_SOI.len();
+    loop {
+        if pos + 4 > body.len() || body[pos] != 0xFF {
+            return Err(malformed("JPEG"));
+        }
+        let marker = body[pos + 1];
+        if marker == 0xDA {
+            out.extend_from_slice(&body[pos..]);
+            return Ok((out, stripped));
+        }
+        let length = u16::from_be_bytes([body[pos + 2], body[pos + 3]]) as usize;
+        let end = pos + 2 + length;
+        if length < 2 || end > body.len() {
+            return Err(malformed("JPEG"));
+        }
+        if marker == 0xE1 && body[pos + 4..end].starts_with(EXIF_HEADER) {
+            stripped = true;
+        } else {
+            out.extend_from_slice(&body[pos..end]);
+        }
+        pos = end;
+    }
+}
+
+fn strip_png_exif(body: &[u8]) -> Result<(Vec<u8>, bool), AppError> {
+    let mut out = Vec::with_capacity(body.len());
+    out.extend_from_slice(PNG_SIGNATURE);
+    let mut stripped = false;
+    let mut pos = PNG_SIGNATURE.len();
+    while pos < body.len() {
+        if pos + 8 > body.len() {
+            return Err(malformed("PNG"));
+        }
+        let length =
+            u32::from_be_bytes([body[pos], body[pos + 1], body[pos + 2], body[pos + 3]]) as usize;
+        // Length, type, data and CRC.
+        let end = pos + 12 + length;
+        if end > body.len() {
+            return Err(malformed("PNG"));
+        }
+        if &body[pos + 4..pos + 8] == b"eXIf" {
+            stripped = true;
+        } else {
+            out.extend_from_slice(&body[pos..end]);
+        }
+        pos = end;
+    }
+    Ok((out, stripped))
+}
+
+/// Identifies the file from its content, not its name, and returns the copy to store.
+pub fn extract_metadata(body: &[u8]) -> Result<(EvidenceMetadata, Vec<u8>), AppError> {
+    if body.starts_with(b"%PDF-") {
+        let metadata = EvidenceMetadata {
+            content_type: "application/pdf",
+            page_count: Some(count_pdf_pages(body)),
+            exif_stripped: false,
+        };
+        return Ok((metadata, body.to_vec()));
+    }
+    let (content_type, (cleaned, exif_stripped)) = if body.starts_with(PNG_SIGNATURE) {
+        ("image/png", strip_png_exif(body)?)
+    } else if body.starts_with(JPEG_SOI) {
+        ("image/jpeg", strip_jpeg_exif(body)?)
+    } else {
+        return Err(unsupported());
+    };
+    let metadata = EvidenceMetadata {
+        content_type,
+        page_count: None,
+        exif_stripped,
+    };
+    Ok((metadata, cleaned))
+}
+
+#[derive(Clone)]
+pub struct EvidenceService {
+    evidence_repo: Arc<dyn EvidenceRepository>,
+    object_store: Arc<dyn ObjectStore>,
+}
+
+impl EvidenceService {
+    pub fn new(
+        evidence_repo: Arc<dyn EvidenceRepository>,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Self {
+        EvidenceService {
+            evidence_repo,
+            object_store,
+        }
+    }
+
+    // Processing runs in the background; admins poll `get_processing_report` before
+    // reviewing. The upload itself is never stored, only the processed copy.
+    pub async fn upload(
+        &self,
+        campaign_id: i32,
+        uploaded_by: i32,
+        file_name: &str,
+        body: Vec<u8>,
+    ) -> Result<EvidenceFile, AppError> {
+        let file = self
+            .evidence_repo
+            .create(campaign_id, uploaded_by, file_name, body.len() as i64)
+            .await?;
+
+        let service = self.clone();
+        let evidence_id = file.id;
+        rocket::tokio::spawn(async move {
+            run_job(
+                "evidence_processing",
+                service.process(evidence_id, campaign_id, body),
+            )
+            .await;
+        });
+
+        Ok(file)
+    }
+
+    pub async fn process(
+        &self,
+        evidence_id: i32,
+        campaign_id: i32,
+        body: Vec<u8>,
+    ) -> Result<(), AppError> {
+        let object_key = format!("evidence/{}/{}", campaign_id, evidence_id);
+        let stored = match extract_metadata(&body) {
+            Ok((metadata, cleaned)) => self
+                .object_store
+                .put(&object_key, cleaned, metadata.content_type)
+                .await
+                .map(|_| metadata),
+            Err(e) => Err(e),
+        };
+
+        match stored {
+            Ok(metadata) => {
+                self.evidence_repo
+                    .mark_completed(evidence_id, metadata, &object_key)
+                    .await
+            }
+            Err(e) => {
+                self.evidence_repo
+                    .mark_failed(evidence_id, e.to_string())
+                    .await
+            }
+        }
+    }
+
+    pub async fn get_processing_report(
+        &self,
+        campaign_id: i32,
+    ) -> Result<EvidenceProcessingReport, AppError> {
+        let files = self.evidence_repo.find_by_campaign(campaign_id).await?;
+        Ok(EvidenceProcessingReport::new(campaign_id, files))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::evidence_repo::MockEvidenceRepository;
+    use crate::service::donation_export_service::MockObjectStore;
+    use mockall::predicate::*;
+
+    fn jpeg_with_exif() -> Vec<u8> {
+        let mut body = JPEG_SOI.to_vec();
+        // APP1 Exif segment: length covers itself, the header and two bytes of data.
+        body.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x0A]);
+        body.extend_from_slice(EXIF_HEADER);
+        body.extend_from_slice(&[0x4D, 0x4D]);
+        // APP0 JFIF segment, kept.
+        body.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x07]);
+        body.extend_from_slice(b"JFIF\0");
+        // Start of scan, then image data and end of image.
+        body.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+        body
+    }
+
+    #[test]
+    fn test_jpeg_exif_is_stripped() {
+        let (metadata, cleaned) = extract_metadata(&jpeg_with_exif()).unwrap();
+
+        assert_eq!(metadata.content_type, "image/jpeg");
+        assert!(metadata.exif_stripped);
+        assert!(!cleaned.windows(4).any(|window| window == b"Exif"));
+        assert!(cleaned.windows(4).any(|window| window == b"JFIF"));
+        assert!(cleaned.ends_with(&[0xFF, 0xD9]));
+    }
+
+    #[test]
+    fn test_pdf_pages_are_counted() {
+        let pdf = b"%PDF-1.4\n1 0 obj << /Type /Pages /Count 2 >>\n\
+                    2 0 obj << /Type /Page >>\n3 0 obj << /Type/Page >>\n%%EOF";
+
+        let (metadata, _) = extract_metadata(pdf).unwrap();
+        assert_eq!(metadata.content_type, "application/pdf");
+        assert_eq!(metadata.page_count, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_file_is_marked_failed() {
+        let mut mock_evidence_repo = MockEvidenceRepository::new();
+        mock_evidence_repo
+            .expect_mark_failed()
+            .with(
+                eq(3),
+                eq("Validation error: Unsupported evidence file type".to_string()),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_store = MockObjectStore::new();
+        mock_store.expect_put().times(0);
+        let service = EvidenceService::new(Arc::new(mock_evidence_repo), Arc::new(mock_store));
+
+        service
+            .process(3, 10, b"BM not really a bitmap".to_vec())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+use crate::errors::AppError;
+use crate::model::fundraiser::FundraiserSummary;
+use crate::model::withdrawal::WithdrawalStatus;
+use crate::repository::fundraiser_repo::FundraiserRepository;
+use crate::repository::withdrawal_repo::WithdrawalRepository;
+use std::sync::Arc;
+
+pub const RECENT_FUNDRAISER_DONATIONS_LIMIT: i64 = 10;
+
+pub struct FundraiserService {
+    fundraiser_repo: Arc<dyn FundraiserRepository>,
+    withdrawal_repo: Arc<dyn WithdrawalRepository>,
+}
+
+impl FundraiserService {
+    pub fn new(
+        fundraiser_repo: Arc<dyn FundraiserRepository>,
+        withdrawal_repo: Arc<dyn WithdrawalRepository>,
+    ) -> Self {
+        FundraiserService {
+            fundraiser_repo,
+            withdrawal_repo,
+        }
+    }
+
+    pub async fn get_summary(&self, fundraiser_id: i32) -> Result<FundraiserSummary, AppError> {
+        let overview = self
+            .fundraiser_repo
+            .campaign_overview(fundraiser_id)
+            .await?;
+        let recent_donations = self
+            .fundraiser_repo
+            .recent_donations(fundraiser_id, RECENT_FUNDRAISER_DONATIONS_LIMIT)
+            .await?;
+        let pending_payouts: Vec<_> = self
+            .withdrawal_repo
+            .find_by_user(fundraiser_id)
+            .await?
+            .into_iter()
+            .filter(|withdrawal| withdrawal.status == WithdrawalStatus::Pending)
+            .collect();
+
+        Ok(FundraiserSummary {
+            total_raised: overview.total_raised,
+            active_campaigns: overview.active_campaigns,
+            pending_payout_count: pending_payouts.len(),
+            pending_payout_amount: pending_payouts.iter().map(|w| w.amount).sum(),
+            recent_donations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fundraiser::FundraiserCampaignOverview;
+    use crate::model::withdrawal::Withdrawal;
+    use crate::repository::{
+        fundraiser_repo::MockFundraiserRepository, withdrawal_repo::MockWithdrawalRepository,
+    };
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn withdrawal(id: i32, amount: f64, status: WithdrawalStatus) -> Withdrawal {
+        Withdrawal {
+            id,
+            user_id: 7,
+            amount,
+            bank_name: "BCA".to_string(),
+            account_number: "1234567890".to_string(),
+            account_holder: "Budi".to_string(),
+            status,
+            admin_note: None,
+            required_approvals: 1,
+            approval_count: 0,
+            organization_id: None,
+            created_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_summary_aggregates_campaigns_and_pending_payouts() {
+        let mut mock_fundraiser_repo = MockFundraiserRepository::new();
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+
+        mock_fundraiser_repo
+            .expect_campaign_overview()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| {
+                Ok(FundraiserCampaignOverview {
+                    total_raised: 1_500_000.0,
+                    active_campaigns: 2,
+                })
+            });
+        mock_fundraiser_repo
+            .expect_recent_donations()
+            .with(eq(7), eq(RECENT_FUNDRAISER_DONATIONS_LIMIT))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        mock_withdrawal_repo
+            .expect_find_by_user()
+            .with(eq(7))
+            .returning(|_| {
+                Ok(vec![
+                    withdrawal(1, 100_000.0, WithdrawalStatus::Pending),
+                    withdrawal(2, 250_000.0, WithdrawalStatus::Approved),
+                    withdrawal(3, 50_000.0, WithdrawalStatus::Pending),
+                ])
+            });
+
+        let service = FundraiserService::new(
+            Arc::new(mock_fundraiser_repo),
+            Arc::new(mock_withdrawal_repo),
+        );
+        let summary = service.get_summary(7).await.unwrap();
+
+        assert_eq!(summary.total_raised, 1_500_000.0);
+        assert_eq!(summary.active_campaigns, 2);
+        assert_eq!(summary.pending_payout_count, 2);
+        assert_eq!(summary.pending_payout_amount, 150_000.0);
+    }
+}
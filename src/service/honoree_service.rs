@@ -0,0 +1,92 @@
+use crate::errors::AppError;
+use crate::model::event::{DomainEvent, DomainEventKind};
+use crate::repository::honoree_repo::HonoreeRepository;
+use crate::service::event_bus::{EventBus, EventSubscriber};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Greets the honoree of a gift donation once it settles. Donations held for review
+/// only publish `DonationCreated` when approved, so their honoree waits until then.
+pub struct HonoreeService {
+    honoree_repo: Arc<dyn HonoreeRepository>,
+}
+
+impl HonoreeService {
+    pub fn new(honoree_repo: Arc<dyn HonoreeRepository>) -> Self {
+        HonoreeService { honoree_repo }
+    }
+
+    pub fn subscribe_to(self: &Arc<Self>, event_bus: EventBus) -> EventBus {
+        event_bus.subscribe_critical(DomainEventKind::DonationCreated, self.clone())
+    }
+
+    async fn greet_honoree(&self, donation_id: i32) -> Result<(), AppError> {
+        if let Some(gift) = self.honoree_repo.find_gift(donation_id).await? {
+            self.honoree_repo.enqueue_greeting(&gift).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for HonoreeService {
+    fn name(&self) -> &'static str {
+        "honoree_greetings"
+    }
+
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError> {
+        if let DomainEvent::DonationCreated { donation_id, .. } = event {
+            self.greet_honoree(*donation_id).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::DonationGift;
+    use crate::repository::honoree_repo::MockHonoreeRepository;
+    use mockall::predicate::*;
+
+    fn donation_created(donation_id: i32) -> DomainEvent {
+        DomainEvent::DonationCreated {
+            donation_id,
+            user_id: 7,
+            campaign_id: 10,
+            amount: 50_000.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_greets_only_gift_donations() {
+        let mut mock_honoree_repo = MockHonoreeRepository::new();
+        mock_honoree_repo
+            .expect_find_gift()
+            .with(eq(1))
+            .returning(|donation_id| {
+                Ok(Some(DonationGift {
+                    donation_id,
+                    user_id: 7,
+                    campaign_id: 10,
+                    message: Some("Happy birthday!".to_string()),
+                    honoree_name: Some("Sari".to_string()),
+                    honoree_email: "sari@example.com".to_string(),
+                }))
+            });
+        mock_honoree_repo
+            .expect_find_gift()
+            .with(eq(2))
+            .returning(|_| Ok(None));
+        mock_honoree_repo
+            .expect_enqueue_greeting()
+            .withf(|gift| gift.donation_id == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+        let event_bus = Arc::new(HonoreeService::new(Arc::new(mock_honoree_repo)))
+            .subscribe_to(EventBus::new());
+
+        event_bus.publish(&donation_created(1)).await.unwrap();
+        event_bus.publish(&donation_created(2)).await.unwrap();
+    }
+}
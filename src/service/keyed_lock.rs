@@ -0,0 +1,103 @@
+use dashmap::DashMap;
+use rocket::tokio::sync::{Mutex, OwnedMutexGuard};
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// One async mutex per key, created on first use and dropped again once nobody holds
+/// or waits for it, so the map only grows with the number of keys in flight. Clones
+/// share the same locks.
+#[derive(Clone)]
+pub struct KeyedLock<K: Eq + Hash + Clone> {
+    locks: Arc<DashMap<K, Arc<Mutex<()>>>>,
+}
+
+impl<K: Eq + Hash + Clone> Default for KeyedLock<K> {
+    fn default() -> Self {
+        KeyedLock {
+            locks: Arc::new(DashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> KeyedLock<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until no other holder of `key` is left; callers are served in FIFO order.
+    pub async fn lock(&self, key: K) -> KeyedGuard<K> {
+        let mutex = self.locks.entry(key.clone()).or_default().clone();
+        let guard = mutex.lock_owned().await;
+        KeyedGuard {
+            key,
+            locks: self.locks.clone(),
+            guard: Some(guard),
+        }
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.locks.len()
+    }
+}
+
+pub struct KeyedGuard<K: Eq + Hash + Clone> {
+    key: K,
+    locks: Arc<DashMap<K, Arc<Mutex<()>>>>,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl<K: Eq + Hash + Clone> Drop for KeyedGuard<K> {
+    fn drop(&mut self) {
+        self.guard.take();
+        // Waiters hold their own clone of the Arc, so the entry is only removed once
+        // the map's reference is the last one. `remove_if` and `entry` take the same
+        // shard lock, so a new caller can't pick up an entry that is being removed.
+        self.locks
+            .remove_if(&self.key, |_, mutex| Arc::strong_count(mutex) == 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_same_key_runs_serially_and_entry_is_released() {
+        let lock = Arc::new(KeyedLock::new());
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..5)
+            .map(|_| {
+                let (lock, running, max_running) =
+                    (lock.clone(), running.clone(), max_running.clone());
+                rocket::tokio::spawn(async move {
+                    let _guard = lock.lock(1).await;
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now, Ordering::SeqCst);
+                    rocket::tokio::time::sleep(Duration::from_millis(5)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(max_running.load(Ordering::SeqCst), 1);
+        assert_eq!(lock.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_different_keys_do_not_block_each_other() {
+        let lock = KeyedLock::new();
+
+        let _first = lock.lock(1).await;
+        let second = rocket::tokio::time::timeout(Duration::from_millis(50), lock.lock(2)).await;
+
+        assert!(second.is_ok());
+        assert_eq!(lock.in_flight(), 2);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::errors::AppError;
+use crate::model::kyc::{
+    KycDocument, KycDocumentType, KycStatus, KycSubject, KycSubmission, KycVerification,
+};
+use crate::repository::kyc_repo::KycRepository;
+use crate::service::donation_export_service::ObjectStore;
+use crate::service::evidence_service::extract_metadata;
+use std::sync::Arc;
+
+pub struct KycService {
+    kyc_repo: Arc<dyn KycRepository>,
+    object_store: Arc<dyn ObjectStore>,
+}
+
+impl KycService {
+    pub fn new(kyc_repo: Arc<dyn KycRepository>, object_store: Arc<dyn ObjectStore>) -> Self {
+        KycService {
+            kyc_repo,
+            object_store,
+        }
+    }
+
+    pub async fn get_verification(&self, subject: KycSubject) -> Result<KycVerification, AppError> {
+        let submission = self.kyc_repo.find_latest(subject).await?;
+        self.verification(subject, submission).await
+    }
+
+    /// Adds the document to the subject's draft submission, starting one if needed.
+    /// Only PDFs, PNGs and JPEGs are accepted, and EXIF data is removed before storing.
+    pub async fn upload_document(
+        &self,
+        subject: KycSubject,
+        uploaded_by: i32,
+        document_type: KycDocumentType,
+        file_name: &str,
+        body: Vec<u8>,
+    ) -> Result<KycDocument, AppError> {
+        let draft = self
+            .kyc_repo
+            .open_draft(subject, uploaded_by)
+            .await?
+            .ok_or_else(|| {
+                AppError::ValidationError(
+                    "Verification documents can't be changed while under review".to_string(),
+                )
+            })?;
+        let (metadata, cleaned) = extract_metadata(&body).map_err(|_| {
+            AppError::ValidationError("Documents must be PDF, PNG or JPEG files".to_string())
+        })?;
+
+        let object_key = format!("kyc/{}/{}", draft.id, document_type.as_str());
+        let size_bytes = cleaned.len() as i64;
+        self.object_store
+            .put(&object_key, cleaned, metadata.content_type)
+            .await?;
+        self.kyc_repo
+            .upsert_document(
+                draft.id,
+                document_type,
+                file_name,
+                metadata.content_type,
+                size_bytes,
+                &object_key,
+            )
+            .await
+    }
+
+    pub async fn submit(&self, subject: KycSubject) -> Result<KycSubmission, AppError> {
+        let draft = match self.kyc_repo.find_latest(subject).await? {
+            Some(submission) if submission.status == KycStatus::Draft => submission,
+            Some(submission) if submission.status == KycStatus::Pending => {
+                return Err(AppError::ValidationError(
+                    "Verification is already under review".to_string(),
+                ));
+            }
+            _ => {
+                return Err(AppError::ValidationError(
+                    "Upload your verification documents first".to_string(),
+                ));
+            }
+        };
+
+        let uploaded: Vec<KycDocumentType> = self
+            .kyc_repo
+            .find_documents(draft.id)
+            .await?
+            .into_iter()
+            .map(|document| document.document_type)
+            .collect();
+        if let Some(missing) = subject
+            .required_documents()
+            .iter()
+            .find(|document_type| !uploaded.contains(document_type))
+        {
+            return Err(AppError::ValidationError(format!(
+                "Missing required document: {}",
+                missing.as_str()
+            )));
+        }
+
+        self.kyc_repo.submit(draft.id).await?.ok_or_else(|| {
+            AppError::ValidationError("Verification is already under review".to_string())
+        })
+    }
+
+    pub async fn get_queue(&self, status: KycStatus) -> Result<Vec<KycSubmission>, AppError> {
+        self.kyc_repo.find_by_status(status).await
+    }
+
+    pub async fn get_submission(&self, submission_id: i32) -> Result<KycVerification, AppError> {
+        let submission = self.find_submission(submission_id).await?;
+        self.verification(submission.subject(), Some(submission))
+            .await
+    }
+
+    pub async fn approve(
+        &self,
+        submission_id: i32,
+        admin_id: i32,
+        note: Option<String>,
+    ) -> Result<KycSubmission, AppError> {
+        let approved = self
+            .kyc_repo
+            .review(submission_id, admin_id, KycStatus::Approved, note)
+            .await?;
+        self.reviewed(submission_id, approved).await
+    }
+
+    /// The note is sent to the applicant, so it is required.
+    pub async fn reject(
+        &self,
+        submission_id: i32,
+        admin_id: i32,
+        note: Option<String>,
+    ) -> Result<KycSubmission, AppError> {
+        let note = note.filter(|note| !note.trim().is_empty()).ok_or_else(|| {
+            AppError::ValidationError("A reason is required to reject a verification".to_string())
+        })?;
+        let rejected = self
+            .kyc_repo
+            .review(submission_id, admin_id, KycStatus::Rejected, Some(note))
+            .await?;
+        self.reviewed(submission_id, rejected).await
+    }
+
+    async fn find_submission(&self, submission_id: i32) -> Result<KycSubmission, AppError> {
+        self.kyc_repo
+            .find_by_id(submission_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Verification submission not found".to_string()))
+    }
+
+    async fn verification(
+        &self,
+        subject: KycSubject,
+        submission: Option<KycSubmission>,
+    ) -> Result<KycVerification, AppError> {
+        let documents = match &submission {
+            Some(submission) => self.kyc_repo.find_documents(submission.id).await?,
+            None => Vec::new(),
+        };
+        Ok(KycVerification {
+            verified: self.kyc_repo.is_approved(subject).await?,
+            submission,
+            documents,
+        })
+    }
+
+    // The repository only refuses a pending submission when the admin submitted it.
+    async fn reviewed(
+        &self,
+        submission_id: i32,
+        submission: Option<KycSubmission>,
+    ) -> Result<KycSubmission, AppError> {
+        if let Some(submission) = submission {
+            return Ok(submission);
+        }
+        let submission = self.find_submission(submission_id).await?;
+        if submission.status != KycStatus::Pending {
+            return Err(AppError::ValidationError(
+                "Verification submission is not awaiting review".to_string(),
+            ));
+        }
+        Err(AppError::Forbidden(
+            "You can't review your own verification".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::kyc_repo::MockKycRepository;
+    use crate::service::donation_export_service::MockObjectStore;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn submission(status: KycStatus) -> KycSubmission {
+        KycSubmission {
+            id: 3,
+            user_id: Some(7),
+            organization_id: None,
+            submitted_by: 7,
+            status,
+            review_note: None,
+            reviewed_by: None,
+            created_at: Utc::now(),
+            submitted_at: None,
+            reviewed_at: None,
+        }
+    }
+
+    fn document(document_type: KycDocumentType) -> KycDocument {
+        KycDocument {
+            id: 1,
+            submission_id: 3,
+            document_type,
+            file_name: "ktp.jpg".to_string(),
+            content_type: "image/jpeg".to_string(),
+            size_bytes: 100,
+            object_key: format!("kyc/3/{}", document_type.as_str()),
+            uploaded_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_requires_every_document() {
+        let mut mock_kyc_repo = MockKycRepository::new();
+        mock_kyc_repo
+            .expect_find_latest()
+            .returning(|_| Ok(Some(submission(KycStatus::Draft))));
+        mock_kyc_repo
+            .expect_find_documents()
+            .with(eq(3))
+            .returning(|_| Ok(vec![document(KycDocumentType::IdCard)]));
+        mock_kyc_repo.expect_submit().times(0);
+
+        let service = KycService::new(Arc::new(mock_kyc_repo), Arc::new(MockObjectStore::new()));
+        let result = service.submit(KycSubject::User(7)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("selfie")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_stores_document_on_draft() {
+        let mut mock_kyc_repo = MockKycRepository::new();
+        mock_kyc_repo
+            .expect_open_draft()
+            .with(eq(KycSubject::User(7)), eq(7))
+            .returning(|_, _| Ok(Some(submission(KycStatus::Draft))));
+        mock_kyc_repo
+            .expect_upsert_document()
+            .withf(
+                |submission_id, document_type, _, content_type, _, object_key| {
+                    *submission_id == 3
+                        && *document_type == KycDocumentType::IdCard
+                        && content_type == "application/pdf"
+                        && object_key == "kyc/3/id_card"
+                },
+            )
+            .times(1)
+            .returning(|_, document_type, _, _, _, _| Ok(document(document_type)));
+        let mut mock_store = MockObjectStore::new();
+        mock_store
+            .expect_put()
+            .withf(|key, _, _| key == "kyc/3/id_card")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let service = KycService::new(Arc::new(mock_kyc_repo), Arc::new(mock_store));
+        let result = service
+            .upload_document(
+                KycSubject::User(7),
+                7,
+                KycDocumentType::IdCard,
+                "ktp.pdf",
+                b"%PDF-1.4\n/Type /Page\n".to_vec(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reject_requires_reason() {
+        let mut mock_kyc_repo = MockKycRepository::new();
+        mock_kyc_repo.expect_review().times(0);
+
+        let service = KycService::new(Arc::new(mock_kyc_repo), Arc::new(MockObjectStore::new()));
+        let result = service.reject(3, 99, Some("  ".to_string())).await;
+
+        assert!(matches!(
+            result.err().unwrap(),
+            AppError::ValidationError(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_review_own_submission() {
+        let mut mock_kyc_repo = MockKycRepository::new();
+        mock_kyc_repo
+            .expect_review()
+            .returning(|_, _, _, _| Ok(None));
+        mock_kyc_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(submission(KycStatus::Pending))));
+
+        let service = KycService::new(Arc::new(mock_kyc_repo), Arc::new(MockObjectStore::new()));
+        let result = service.approve(3, 7, None).await;
+
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+}
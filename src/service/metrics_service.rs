@@ -0,0 +1,166 @@
+use crate::errors::AppError;
+use crate::fairing::request_metrics::RequestMetrics;
+use crate::model::event::{DomainEvent, DomainEventKind};
+use crate::model::metrics::BusinessMetrics;
+use crate::repository::donation_cache::DonationCache;
+use crate::repository::metrics_repo::MetricsRepository;
+use crate::repository::query_monitor::QueryMonitor;
+use crate::service::campaign_widget_service::CampaignWidgetService;
+use crate::service::content_throttle::ContentThrottle;
+use crate::service::event_bus::{EventBus, EventSubscriber, SubscriberStats};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Counters are fed from the event bus; gauges are read from the database at scrape time.
+pub struct MetricsService {
+    metrics_repo: Arc<dyn MetricsRepository>,
+    donation_cache: Arc<DonationCache>,
+    query_monitor: Option<Arc<QueryMonitor>>,
+    content_throttle: Option<Arc<ContentThrottle>>,
+    campaign_widgets: Option<Arc<CampaignWidgetService>>,
+    subscriber_stats: Option<Arc<SubscriberStats>>,
+    request_metrics: Option<Arc<RequestMetrics>>,
+    donations_created: AtomicU64,
+}
+
+impl MetricsService {
+    pub fn new(
+        metrics_repo: Arc<dyn MetricsRepository>,
+        donation_cache: Arc<DonationCache>,
+    ) -> Self {
+        MetricsService {
+            metrics_repo,
+            donation_cache,
+            query_monitor: None,
+            content_throttle: None,
+            campaign_widgets: None,
+            subscriber_stats: None,
+            request_metrics: None,
+            donations_created: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_query_monitor(mut self, query_monitor: Arc<QueryMonitor>) -> Self {
+        self.query_monitor = Some(query_monitor);
+        self
+    }
+
+    pub fn with_content_throttle(mut self, content_throttle: Arc<ContentThrottle>) -> Self {
+        self.content_throttle = Some(content_throttle);
+        self
+    }
+
+    pub fn with_campaign_widgets(mut self, campaign_widgets: Arc<CampaignWidgetService>) -> Self {
+        self.campaign_widgets = Some(campaign_widgets);
+        self
+    }
+
+    pub fn with_subscriber_stats(mut self, subscriber_stats: Arc<SubscriberStats>) -> Self {
+        self.subscriber_stats = Some(subscriber_stats);
+        self
+    }
+
+    pub fn with_request_metrics(mut self, request_metrics: Arc<RequestMetrics>) -> Self {
+        self.request_metrics = Some(request_metrics);
+        self
+    }
+
+    pub fn subscribe_to(self: &Arc<Self>, event_bus: EventBus) -> EventBus {
+        event_bus.subscribe(DomainEventKind::DonationCreated, self.clone())
+    }
+
+    pub async fn business_metrics(&self) -> Result<BusinessMetrics, AppError> {
+        Ok(BusinessMetrics {
+            donations_created_total: self.donations_created.load(Ordering::Relaxed),
+            donation_amount_by_campaign_status: self
+                .metrics_repo
+                .donation_amount_by_campaign_status()
+                .await?,
+            wallet_balance_total: self.metrics_repo.wallet_balance_total().await?,
+        })
+    }
+
+    pub async fn render_prometheus(&self) -> Result<String, AppError> {
+        let mut out = self.donation_cache.stats().to_prometheus();
+        if let Some(query_monitor) = &self.query_monitor {
+            out.push_str(&query_monitor.to_prometheus());
+        }
+        if let Some(content_throttle) = &self.content_throttle {
+            out.push_str(&content_throttle.to_prometheus());
+        }
+        if let Some(campaign_widgets) = &self.campaign_widgets {
+            out.push_str(&campaign_widgets.to_prometheus());
+        }
+        if let Some(subscriber_stats) = &self.subscriber_stats {
+            out.push_str(&subscriber_stats.to_prometheus());
+        }
+        if let Some(request_metrics) = &self.request_metrics {
+            out.push_str(&request_metrics.to_prometheus());
+        }
+        out.push_str(&self.business_metrics().await?.to_prometheus());
+        Ok(out)
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for MetricsService {
+    fn name(&self) -> &'static str {
+        "metrics"
+    }
+
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError> {
+        if let DomainEvent::DonationCreated { .. } = event {
+            self.donations_created.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::metrics::CampaignStatusAmount;
+    use crate::repository::metrics_repo::MockMetricsRepository;
+
+    #[tokio::test]
+    async fn test_render_includes_business_and_cache_metrics() {
+        let mut mock_metrics_repo = MockMetricsRepository::new();
+        mock_metrics_repo
+            .expect_donation_amount_by_campaign_status()
+            .returning(|| {
+                Ok(vec![CampaignStatusAmount {
+                    campaign_status: "active".to_string(),
+                    total_amount: 150_000.0,
+                }])
+            });
+        mock_metrics_repo
+            .expect_wallet_balance_total()
+            .returning(|| Ok(2_500_000.0));
+
+        let service = Arc::new(
+            MetricsService::new(Arc::new(mock_metrics_repo), Arc::new(DonationCache::new()))
+                .with_query_monitor(Arc::new(QueryMonitor::default()))
+                .with_request_metrics(Arc::new(RequestMetrics::new())),
+        );
+        let event_bus = service.subscribe_to(EventBus::new());
+        for donation_id in 1..=2 {
+            let event = DomainEvent::DonationCreated {
+                donation_id,
+                user_id: 1,
+                campaign_id: 10,
+                amount: 75_000.0,
+            };
+            event_bus.publish(&event).await.unwrap();
+        }
+
+        let output = service.render_prometheus().await.unwrap();
+
+        assert!(output.contains("donations_created_total 2\n"));
+        assert!(output.contains("donation_amount_sum{campaign_status=\"active\"} 150000\n"));
+        assert!(output.contains("wallet_balance_total 2500000\n"));
+        assert!(output.contains("donation_cache_hits_total{scope=\"campaign_totals\"}"));
+        assert!(output.contains("db_slow_queries_total 0\n"));
+        assert!(output.contains("# TYPE http_request_duration_seconds histogram\n"));
+    }
+}
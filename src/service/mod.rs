@@ -1,2 +1,53 @@
+pub mod admin_action_service;
+pub mod api_key_service;
+pub mod background_job;
+pub mod cache_service;
+pub mod cache_warmer;
+pub mod campaign_budget_service;
+pub mod campaign_feed_service;
+pub mod campaign_image_service;
+pub mod campaign_location_service;
+pub mod campaign_member_service;
+pub mod campaign_overflow_service;
+pub mod campaign_ranking_service;
+pub mod campaign_review_service;
+pub mod campaign_share_service;
+pub mod campaign_suspension_service;
+pub mod campaign_widget_service;
+pub mod content_throttle;
+pub mod data_export_service;
+pub mod dispute_service;
+pub mod donation_archive_service;
+pub mod donation_export_service;
+pub mod donation_import_service;
+pub mod donation_intent_service;
 pub mod donation_service;
+pub mod donation_tier_service;
+pub mod event_bus;
+pub mod evidence_service;
+pub mod fundraiser_service;
+pub mod honoree_service;
+pub mod keyed_lock;
+pub mod kyc_service;
+pub mod metrics_service;
+pub mod notification_delivery_service;
+pub mod notification_preference_service;
+pub mod ops_alerter;
+pub mod organization_service;
+pub mod outbox_dispatcher;
+pub mod profile_service;
+pub mod reconciliation_service;
+pub mod risk_service;
+pub mod saved_search_service;
+pub mod security_event_service;
+pub mod seed_service;
+pub mod short_link_service;
+pub mod statistic_service;
+pub mod statistics_snapshot_service;
+pub mod tax_summary_service;
+pub mod top_up_service;
+pub mod transaction_service;
+pub mod two_factor_service;
+pub mod user_import_service;
+pub mod withdrawal_service;
 pub mod commands;
\ No newline at end of file
@@ -0,0 +1,274 @@
+use crate::config::NotificationRetryConfig;
+use crate::errors::AppError;
+use crate::model::event::{DomainEvent, SubscriberOutcome, SubscriberResult};
+use crate::model::notification_delivery::{NotificationDelivery, NotificationDeliveryStatus};
+use crate::repository::notification_delivery_repo::NotificationDeliveryRepository;
+use crate::service::background_job::run_job;
+use crate::service::event_bus::{EventBus, decode_event};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const DELIVERY_BATCH_SIZE: i64 = 100;
+pub const DELIVERY_LIST_LIMIT: i64 = 200;
+pub const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Retries subscriber deliveries that failed when the outbox handed their event to
+/// the event bus. Each retry doubles the wait, up to the configured cap; once out of
+/// attempts the delivery is dead-lettered for an admin to inspect and resend.
+pub struct NotificationDeliveryService {
+    delivery_repo: Arc<dyn NotificationDeliveryRepository>,
+    max_attempts: i32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl NotificationDeliveryService {
+    pub fn new(
+        delivery_repo: Arc<dyn NotificationDeliveryRepository>,
+        config: &NotificationRetryConfig,
+    ) -> Self {
+        NotificationDeliveryService {
+            delivery_repo,
+            max_attempts: config.max_attempts,
+            base_delay: Duration::from_secs(config.base_delay_secs),
+            max_delay: Duration::from_secs(config.max_delay_secs),
+        }
+    }
+
+    /// Wait before the next attempt, after `failures` failed attempts.
+    pub fn retry_delay(&self, failures: i32) -> Duration {
+        let factor = 1u32
+            .checked_shl(failures.max(1) as u32 - 1)
+            .unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    fn retry_at(&self, failures: i32) -> chrono::DateTime<Utc> {
+        Utc::now() + chrono::Duration::seconds(self.retry_delay(failures).as_secs() as i64)
+    }
+
+    pub async fn record_failure(
+        &self,
+        outbox_id: i64,
+        event: &DomainEvent,
+        failure: &SubscriberResult,
+    ) -> Result<(), AppError> {
+        self.delivery_repo
+            .record_failure(
+                outbox_id,
+                event.kind().as_str(),
+                failure.subscriber,
+                &failure.to_string(),
+                self.retry_at(1),
+            )
+            .await
+    }
+
+    pub fn spawn(self: Arc<Self>, event_bus: Arc<EventBus>, interval: Duration) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("notification_retries", self.retry_due(&event_bus)).await;
+                rocket::tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    /// Runs every due delivery once more. Returns how many went through.
+    pub async fn retry_due(&self, event_bus: &EventBus) -> Result<usize, AppError> {
+        let due = self.delivery_repo.find_due(DELIVERY_BATCH_SIZE).await?;
+        let mut delivered = 0;
+        for delivery in due {
+            let event = match decode_event(delivery.outbox_id, &delivery.payload) {
+                Ok(event) => event,
+                Err(e) => {
+                    self.delivery_repo
+                        .mark_dead(delivery.id, &e.to_string())
+                        .await?;
+                    continue;
+                }
+            };
+            let Some(result) = event_bus.deliver_to(&event, &delivery.subscriber).await else {
+                self.delivery_repo
+                    .mark_dead(delivery.id, "Subscriber is no longer registered")
+                    .await?;
+                continue;
+            };
+
+            if result.outcome == SubscriberOutcome::Delivered {
+                self.delivery_repo.mark_delivered(delivery.id).await?;
+                delivered += 1;
+                continue;
+            }
+            let failures = delivery.attempts + 1;
+            if failures >= self.max_attempts {
+                tracing::error!(
+                    delivery_id = delivery.id,
+                    event_type = delivery.event_type.as_str(),
+                    subscriber = delivery.subscriber.as_str(),
+                    "Dead-lettered {} delivery {}: {}",
+                    delivery.event_type,
+                    delivery.id,
+                    result
+                );
+                self.delivery_repo
+                    .mark_dead(delivery.id, &result.to_string())
+                    .await?;
+            } else {
+                self.delivery_repo
+                    .reschedule(delivery.id, &result.to_string(), self.retry_at(failures))
+                    .await?;
+            }
+        }
+        Ok(delivered)
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        status: NotificationDeliveryStatus,
+    ) -> Result<Vec<NotificationDelivery>, AppError> {
+        self.delivery_repo
+            .find_by_status(status, DELIVERY_LIST_LIMIT)
+            .await
+    }
+
+    /// Queues a dead-lettered delivery for the next retry run.
+    pub async fn retry_dead_letter(
+        &self,
+        delivery_id: i64,
+    ) -> Result<NotificationDelivery, AppError> {
+        self.delivery_repo
+            .requeue(delivery_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Dead-lettered delivery not found".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event::DomainEventKind;
+    use crate::repository::notification_delivery_repo::MockNotificationDeliveryRepository;
+    use crate::service::event_bus::MockEventSubscriber;
+    use mockall::predicate::*;
+    use rocket::serde::json;
+
+    fn config() -> NotificationRetryConfig {
+        NotificationRetryConfig {
+            max_attempts: 3,
+            base_delay_secs: 30,
+            max_delay_secs: 100,
+        }
+    }
+
+    fn delivery(attempts: i32) -> NotificationDelivery {
+        NotificationDelivery {
+            id: 5,
+            outbox_id: 1,
+            event_type: "campaign_completed".to_string(),
+            subscriber: "ops_alerter".to_string(),
+            status: NotificationDeliveryStatus::Pending,
+            attempts,
+            last_error: Some("timeout".to_string()),
+            next_attempt_at: Utc::now(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            payload: json::to_value(DomainEvent::CampaignCompleted { campaign_id: 10 }).unwrap(),
+        }
+    }
+
+    fn failing_subscriber() -> MockEventSubscriber {
+        let mut subscriber = MockEventSubscriber::new();
+        subscriber.expect_name().return_const("ops_alerter");
+        subscriber
+            .expect_on_event()
+            .returning(|_| Err(AppError::InternalServerError("timeout".to_string())));
+        subscriber
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_cap() {
+        let service = NotificationDeliveryService::new(
+            Arc::new(MockNotificationDeliveryRepository::new()),
+            &config(),
+        );
+
+        assert_eq!(service.retry_delay(1), Duration::from_secs(30));
+        assert_eq!(service.retry_delay(2), Duration::from_secs(60));
+        assert_eq!(service.retry_delay(3), Duration::from_secs(100));
+        assert_eq!(service.retry_delay(40), Duration::from_secs(100));
+    }
+
+    #[tokio::test]
+    async fn test_failed_retry_is_rescheduled_with_backoff() {
+        let mut mock_delivery_repo = MockNotificationDeliveryRepository::new();
+        mock_delivery_repo
+            .expect_find_due()
+            .returning(|_| Ok(vec![delivery(1)]));
+        mock_delivery_repo
+            .expect_reschedule()
+            .withf(|id, error, retry_at| {
+                let wait = (*retry_at - Utc::now()).num_seconds();
+                *id == 5 && error.contains("timeout") && (55..=60).contains(&wait)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_delivery_repo.expect_mark_dead().times(0);
+
+        let service = NotificationDeliveryService::new(Arc::new(mock_delivery_repo), &config());
+        let bus = EventBus::new().subscribe(
+            DomainEventKind::CampaignCompleted,
+            Arc::new(failing_subscriber()),
+        );
+
+        assert_eq!(service.retry_due(&bus).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_out_of_attempts_is_dead_lettered() {
+        let mut mock_delivery_repo = MockNotificationDeliveryRepository::new();
+        mock_delivery_repo
+            .expect_find_due()
+            .returning(|_| Ok(vec![delivery(2)]));
+        mock_delivery_repo
+            .expect_mark_dead()
+            .with(eq(5), always())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_delivery_repo.expect_reschedule().times(0);
+
+        let service = NotificationDeliveryService::new(Arc::new(mock_delivery_repo), &config());
+        let bus = EventBus::new().subscribe(
+            DomainEventKind::CampaignCompleted,
+            Arc::new(failing_subscriber()),
+        );
+
+        service.retry_due(&bus).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_successful_retry_is_marked_delivered() {
+        let mut mock_delivery_repo = MockNotificationDeliveryRepository::new();
+        mock_delivery_repo
+            .expect_find_due()
+            .returning(|_| Ok(vec![delivery(1)]));
+        mock_delivery_repo
+            .expect_mark_delivered()
+            .with(eq(5))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut subscriber = MockEventSubscriber::new();
+        subscriber.expect_name().return_const("ops_alerter");
+        subscriber
+            .expect_on_event()
+            .withf(|event| *event == DomainEvent::CampaignCompleted { campaign_id: 10 })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = NotificationDeliveryService::new(Arc::new(mock_delivery_repo), &config());
+        let bus =
+            EventBus::new().subscribe(DomainEventKind::CampaignCompleted, Arc::new(subscriber));
+
+        assert_eq!(service.retry_due(&bus).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::errors::AppError;
+use crate::model::event::{DomainEvent, DomainEventKind};
+use crate::model::notification_preference::{
+    NotificationPreferences, UpdateNotificationPreferencesRequest,
+};
+use crate::repository::notification_preference_repo::NotificationPreferenceRepository;
+use crate::repository::wallet_repo::WalletRepository;
+use crate::service::background_job::run_job;
+use crate::service::event_bus::{EventBus, EventSubscriber};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const DIGEST_PERIOD_HOURS: i64 = 24;
+/// How often the digest job looks for users whose day is up.
+pub const DEFAULT_DIGEST_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// Notifications addressed to the user themselves; admin alerts are never digested.
+const DIGEST_EVENT_KINDS: &[DomainEventKind] = &[
+    DomainEventKind::DonationRefunded,
+    DomainEventKind::PayoutApproved,
+    DomainEventKind::DisputeResolved,
+    DomainEventKind::WalletBalanceLow,
+    DomainEventKind::KycApproved,
+    DomainEventKind::KycRejected,
+    DomainEventKind::SavedSearchMatched,
+];
+
+pub struct NotificationPreferenceService {
+    preference_repo: Arc<dyn NotificationPreferenceRepository>,
+    wallet_repo: Arc<dyn WalletRepository>,
+}
+
+impl NotificationPreferenceService {
+    pub fn new(
+        preference_repo: Arc<dyn NotificationPreferenceRepository>,
+        wallet_repo: Arc<dyn WalletRepository>,
+    ) -> Self {
+        NotificationPreferenceService {
+            preference_repo,
+            wallet_repo,
+        }
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: Duration) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("notification_digest", self.send_daily_digests()).await;
+                rocket::tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    pub fn subscribe_to(self: &Arc<Self>, event_bus: EventBus) -> EventBus {
+        event_bus.subscribe_critical(DomainEventKind::DonationCreated, self.clone())
+    }
+
+    pub async fn get_preferences(&self, user_id: i32) -> Result<NotificationPreferences, AppError> {
+        Ok(self
+            .preference_repo
+            .find(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::defaults(user_id)))
+    }
+
+    pub async fn update_preferences(
+        &self,
+        user_id: i32,
+        req: UpdateNotificationPreferencesRequest,
+    ) -> Result<NotificationPreferences, AppError> {
+        self.preference_repo
+            .upsert(user_id, req.low_balance_threshold, req.daily_digest)
+            .await
+    }
+
+    /// Sends a summary to each digest user whose last one is a day old; returns how
+    /// many were sent. A day without notifications sends nothing but still counts.
+    pub async fn send_daily_digests(&self) -> Result<usize, AppError> {
+        let since = Utc::now() - chrono::Duration::hours(DIGEST_PERIOD_HOURS);
+        let event_types: Vec<String> = DIGEST_EVENT_KINDS
+            .iter()
+            .map(|kind| kind.as_str().to_string())
+            .collect();
+        let mut sent = 0;
+        for user_id in self.preference_repo.find_due_digests(since).await? {
+            let counts = self
+                .preference_repo
+                .send_digest(user_id, event_types.clone(), since)
+                .await?;
+            if counts.is_some_and(|counts| !counts.is_empty()) {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    // Only alerts when this donation is what took the balance to or below the threshold,
+    // so further donations from an already-low wallet don't repeat the notification.
+    async fn check_low_balance(&self, user_id: i32, amount: f64) -> Result<(), AppError> {
+        let Some(threshold) = self.get_preferences(user_id).await?.low_balance_threshold else {
+            return Ok(());
+        };
+        let available_balance = self.wallet_repo.available_balance(user_id).await?;
+        if available_balance <= threshold && available_balance + amount > threshold {
+            self.preference_repo
+                .enqueue_low_balance_alert(user_id, available_balance, threshold)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for NotificationPreferenceService {
+    fn name(&self) -> &'static str {
+        "notification_preferences"
+    }
+
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError> {
+        if let DomainEvent::DonationCreated {
+            user_id, amount, ..
+        } = event
+        {
+            self.check_low_balance(*user_id, *amount).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::notification_preference::NotificationCount;
+    use crate::repository::notification_preference_repo::MockNotificationPreferenceRepository;
+    use crate::repository::wallet_repo::MockWalletRepository;
+    use mockall::predicate::*;
+
+    fn donation_created(amount: f64) -> DomainEvent {
+        DomainEvent::DonationCreated {
+            donation_id: 1,
+            user_id: 7,
+            campaign_id: 10,
+            amount,
+        }
+    }
+
+    fn service(
+        threshold: Option<f64>,
+        available_balance: f64,
+        alerts: usize,
+    ) -> Arc<NotificationPreferenceService> {
+        let mut mock_preference_repo = MockNotificationPreferenceRepository::new();
+        mock_preference_repo
+            .expect_find()
+            .with(eq(7))
+            .returning(move |user_id| {
+                Ok(Some(NotificationPreferences {
+                    user_id,
+                    low_balance_threshold: threshold,
+                    daily_digest: false,
+                    updated_at: None,
+                }))
+            });
+        mock_preference_repo
+            .expect_enqueue_low_balance_alert()
+            .with(
+                eq(7),
+                eq(available_balance),
+                eq(threshold.unwrap_or_default()),
+            )
+            .times(alerts)
+            .returning(|_, _, _| Ok(()));
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo
+            .expect_available_balance()
+            .returning(move |_| Ok(available_balance));
+        Arc::new(NotificationPreferenceService::new(
+            Arc::new(mock_preference_repo),
+            Arc::new(mock_wallet_repo),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_alerts_when_donation_crosses_threshold() {
+        let event_bus = service(Some(100_000.0), 80_000.0, 1).subscribe_to(EventBus::new());
+
+        event_bus
+            .publish(&donation_created(50_000.0))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_no_repeat_alert_when_already_below_threshold() {
+        let event_bus = service(Some(100_000.0), 30_000.0, 0).subscribe_to(EventBus::new());
+
+        event_bus
+            .publish(&donation_created(50_000.0))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_zero_threshold_alerts_on_empty_wallet() {
+        let event_bus = service(Some(0.0), 0.0, 1).subscribe_to(EventBus::new());
+
+        event_bus
+            .publish(&donation_created(25_000.0))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_daily_digests_counts_only_non_empty_ones() {
+        let mut mock_preference_repo = MockNotificationPreferenceRepository::new();
+        mock_preference_repo
+            .expect_find_due_digests()
+            .withf(|since| {
+                (Utc::now() - *since - chrono::Duration::hours(DIGEST_PERIOD_HOURS)).num_seconds()
+                    < 5
+            })
+            .returning(|_| Ok(vec![7, 8, 9]));
+        mock_preference_repo
+            .expect_send_digest()
+            .withf(|_, event_types, _| {
+                event_types.contains(&"wallet_balance_low".to_string())
+                    && !event_types.contains(&"payout_requested".to_string())
+            })
+            .returning(|user_id, _, _| {
+                Ok(match user_id {
+                    7 => Some(vec![NotificationCount {
+                        event_type: "wallet_balance_low".to_string(),
+                        count: 2,
+                    }]),
+                    8 => Some(vec![]),
+                    _ => None,
+                })
+            });
+        let service = NotificationPreferenceService::new(
+            Arc::new(mock_preference_repo),
+            Arc::new(MockWalletRepository::new()),
+        );
+
+        assert_eq!(service.send_daily_digests().await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::errors::AppError;
+use crate::model::event::{DomainEvent, DomainEventKind};
+use crate::service::background_job::run_job;
+use crate::service::event_bus::{EventBus, EventSubscriber};
+use async_trait::async_trait;
+use rocket::serde::Deserialize;
+use rocket::serde::json::{Value, json};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(crate = "rocket::serde", rename_all = "lowercase")]
+pub enum AlertWebhookFormat {
+    Slack,
+    Discord,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpsAlertConfig {
+    pub webhook_url: String,
+    pub format: AlertWebhookFormat,
+    pub payout_threshold: f64,
+    pub batch_interval: Duration,
+    pub max_alerts_per_batch: usize,
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    async fn send(&self, text: String) -> Result<(), AppError>;
+}
+
+pub struct WebhookAlertSink {
+    client: reqwest::Client,
+    webhook_url: String,
+    format: AlertWebhookFormat,
+}
+
+impl WebhookAlertSink {
+    pub fn new(webhook_url: String, format: AlertWebhookFormat) -> Self {
+        WebhookAlertSink {
+            client: reqwest::Client::new(),
+            webhook_url,
+            format,
+        }
+    }
+
+    fn body(&self, text: String) -> Value {
+        match self.format {
+            AlertWebhookFormat::Slack => json!({ "text": text }),
+            AlertWebhookFormat::Discord => json!({ "content": text }),
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookAlertSink {
+    async fn send(&self, text: String) -> Result<(), AppError> {
+        self.client
+            .post(&self.webhook_url)
+            .json(&self.body(text))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AppError::InternalServerError(format!("Alert webhook failed: {}", e)))?;
+        Ok(())
+    }
+}
+
+// Alerts are queued by the subscriber and posted as one message per batch interval,
+// so a burst of events cannot flood the channel or trip the webhook's rate limit.
+pub struct OpsAlerter {
+    sink: Arc<dyn AlertSink>,
+    config: OpsAlertConfig,
+    pending: Mutex<Vec<String>>,
+}
+
+impl OpsAlerter {
+    pub fn new(sink: Arc<dyn AlertSink>, config: OpsAlertConfig) -> Self {
+        OpsAlerter {
+            sink,
+            config,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn subscribe_to(self: &Arc<Self>, event_bus: EventBus) -> EventBus {
+        event_bus
+            .subscribe(DomainEventKind::CampaignFlagged, self.clone())
+            .subscribe(DomainEventKind::PayoutRequested, self.clone())
+            .subscribe(DomainEventKind::RiskBlocked, self.clone())
+            .subscribe(DomainEventKind::KycSubmitted, self.clone())
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                rocket::tokio::time::sleep(self.config.batch_interval).await;
+                run_job("ops_alert_delivery", self.flush()).await;
+            }
+        });
+    }
+
+    fn alert_text(&self, event: &DomainEvent) -> Option<String> {
+        match event {
+            DomainEvent::CampaignFlagged {
+                campaign_id,
+                reason,
+            } => Some(format!("Campaign {} flagged: {}", campaign_id, reason)),
+            DomainEvent::PayoutRequested {
+                withdrawal_id,
+                user_id,
+                amount,
+            } if *amount >= self.config.payout_threshold => Some(format!(
+                "Large payout requested: withdrawal {} by user {} for {:.2}",
+                withdrawal_id, user_id, amount
+            )),
+            DomainEvent::RiskBlocked {
+                user_id,
+                activity,
+                amount,
+                reason,
+            } => Some(format!(
+                "Fraud engine blocked {:?} of {:.2} by user {}: {}",
+                activity, amount, user_id, reason
+            )),
+            DomainEvent::KycSubmitted {
+                submission_id,
+                user_id,
+                organization_id,
+            } => Some(match organization_id {
+                Some(organization_id) => format!(
+                    "Verification {} submitted for organization {} by user {}",
+                    submission_id, organization_id, user_id
+                ),
+                None => format!(
+                    "Verification {} submitted by user {}",
+                    submission_id, user_id
+                ),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Posts everything queued since the last flush as a single message.
+    /// Returns how many alerts were included.
+    pub async fn flush(&self) -> Result<usize, AppError> {
+        let alerts = std::mem::take(&mut *self.pending.lock().unwrap());
+        if alerts.is_empty() {
+            return Ok(0);
+        }
+
+        let shown = alerts.len().min(self.config.max_alerts_per_batch);
+        let mut text = alerts[..shown].join("\n");
+        if alerts.len() > shown {
+            text.push_str(&format!("\n...and {} more alerts", alerts.len() - shown));
+        }
+        self.sink.send(text).await?;
+        Ok(alerts.len())
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for OpsAlerter {
+    fn name(&self) -> &'static str {
+        "ops_alerter"
+    }
+
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError> {
+        if let Some(text) = self.alert_text(event) {
+            self.pending.lock().unwrap().push(text);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::risk::RiskActivity;
+
+    fn config(max_alerts_per_batch: usize) -> OpsAlertConfig {
+        OpsAlertConfig {
+            webhook_url: "https://hooks.example.org/alerts".to_string(),
+            format: AlertWebhookFormat::Slack,
+            payout_threshold: 10_000_000.0,
+            batch_interval: Duration::from_secs(30),
+            max_alerts_per_batch,
+        }
+    }
+
+    fn payout(amount: f64) -> DomainEvent {
+        DomainEvent::PayoutRequested {
+            withdrawal_id: 1,
+            user_id: 2,
+            amount,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_small_payouts_do_not_alert() {
+        let mut mock_sink = MockAlertSink::new();
+        mock_sink.expect_send().times(0);
+
+        let alerter = OpsAlerter::new(Arc::new(mock_sink), config(10));
+        alerter.on_event(&payout(50_000.0)).await.unwrap();
+
+        assert_eq!(alerter.flush().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_flush_batches_alerts_into_one_message() {
+        let mut mock_sink = MockAlertSink::new();
+        mock_sink
+            .expect_send()
+            .withf(|text| {
+                text.starts_with("Large payout requested")
+                    && text.contains("Fraud engine blocked")
+                    && text.ends_with("...and 1 more alerts")
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let alerter = OpsAlerter::new(Arc::new(mock_sink), config(2));
+        alerter.on_event(&payout(25_000_000.0)).await.unwrap();
+        alerter
+            .on_event(&DomainEvent::RiskBlocked {
+                user_id: 3,
+                activity: RiskActivity::Donation,
+                amount: 100.0,
+                reason: "User or IP address is blacklisted".to_string(),
+            })
+            .await
+            .unwrap();
+        alerter
+            .on_event(&DomainEvent::CampaignFlagged {
+                campaign_id: 10,
+                reason: "Reported as fraudulent".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(alerter.flush().await.unwrap(), 3);
+        assert_eq!(alerter.flush().await.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_webhook_body_matches_format() {
+        let slack = WebhookAlertSink::new(String::new(), AlertWebhookFormat::Slack);
+        let discord = WebhookAlertSink::new(String::new(), AlertWebhookFormat::Discord);
+
+        assert_eq!(slack.body("hi".to_string()), json!({ "text": "hi" }));
+        assert_eq!(discord.body("hi".to_string()), json!({ "content": "hi" }));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::errors::AppError;
+use crate::model::organization::{
+    AddOrganizationMemberRequest, NewOrganizationRequest, Organization, OrganizationAction,
+    OrganizationMember, OrganizationRole, OrganizationWallet,
+};
+use crate::repository::organization_repo::OrganizationRepository;
+use std::sync::Arc;
+
+pub struct OrganizationService {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl OrganizationService {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        OrganizationService { organization_repo }
+    }
+
+    /// Membership check for everything done on the organization's behalf, including
+    /// payouts from its wallet.
+    pub async fn authorize(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+        action: OrganizationAction,
+    ) -> Result<OrganizationRole, AppError> {
+        let role = self
+            .organization_repo
+            .find_role(organization_id, user_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Forbidden("You are not a member of this organization".to_string())
+            })?;
+
+        if !role.allows(action) {
+            return Err(AppError::Forbidden(
+                "Your organization role does not allow this action".to_string(),
+            ));
+        }
+        Ok(role)
+    }
+
+    pub async fn create_organization(
+        &self,
+        user_id: i32,
+        organization: NewOrganizationRequest,
+    ) -> Result<Organization, AppError> {
+        self.organization_repo
+            .create(user_id, &organization)
+            .await?
+            .ok_or_else(|| {
+                AppError::ValidationError(
+                    "An organization with this registration number already exists".to_string(),
+                )
+            })
+    }
+
+    pub async fn get_organization(&self, organization_id: i32) -> Result<Organization, AppError> {
+        self.organization_repo
+            .find_by_id(organization_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Organization not found".to_string()))
+    }
+
+    pub async fn verify_organization(
+        &self,
+        organization_id: i32,
+        admin_id: i32,
+    ) -> Result<Organization, AppError> {
+        match self
+            .organization_repo
+            .verify(organization_id, admin_id)
+            .await?
+        {
+            Some(organization) => Ok(organization),
+            None => {
+                self.get_organization(organization_id).await?;
+                Err(AppError::ValidationError(
+                    "Organization is already verified".to_string(),
+                ))
+            }
+        }
+    }
+
+    pub async fn get_members(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<Vec<OrganizationMember>, AppError> {
+        self.authorize(organization_id, user_id, OrganizationAction::View)
+            .await?;
+        self.organization_repo.find_members(organization_id).await
+    }
+
+    pub async fn add_member(
+        &self,
+        organization_id: i32,
+        actor_id: i32,
+        member: AddOrganizationMemberRequest,
+    ) -> Result<OrganizationMember, AppError> {
+        self.authorize(organization_id, actor_id, OrganizationAction::ManageMembers)
+            .await?;
+        self.organization_repo
+            .upsert_member(organization_id, member.user_id, member.role, actor_id)
+            .await?
+            .ok_or_else(last_admin_error)
+    }
+
+    pub async fn remove_member(
+        &self,
+        organization_id: i32,
+        actor_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        self.authorize(organization_id, actor_id, OrganizationAction::ManageMembers)
+            .await?;
+        if self
+            .organization_repo
+            .remove_member(organization_id, user_id)
+            .await?
+            == 0
+        {
+            let is_member = self
+                .organization_repo
+                .find_role(organization_id, user_id)
+                .await?
+                .is_some();
+            return Err(if is_member {
+                last_admin_error()
+            } else {
+                AppError::NotFound("Organization member not found".to_string())
+            });
+        }
+        Ok(())
+    }
+
+    pub async fn get_wallet(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<OrganizationWallet, AppError> {
+        self.authorize(organization_id, user_id, OrganizationAction::ViewWallet)
+            .await?;
+        self.organization_repo
+            .find_wallet(organization_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Wallet not found".to_string()))
+    }
+
+    /// Hands the campaign to the organization, whose members then manage it according
+    /// to their organization role. The caller's campaign role is checked by the route.
+    pub async fn assign_campaign(
+        &self,
+        campaign_id: i32,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        self.authorize(
+            organization_id,
+            user_id,
+            OrganizationAction::ManageCampaigns,
+        )
+        .await?;
+        let organization = self.get_organization(organization_id).await?;
+        if organization.verified_at.is_none() {
+            return Err(AppError::ValidationError(
+                "Only verified organizations can own campaigns".to_string(),
+            ));
+        }
+        if !self
+            .organization_repo
+            .assign_campaign(campaign_id, organization_id)
+            .await?
+        {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn last_admin_error() -> AppError {
+    AppError::ValidationError("An organization needs at least one admin".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::organization_repo::MockOrganizationRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn repo_with_role(role: Option<OrganizationRole>) -> MockOrganizationRepository {
+        let mut mock_organization_repo = MockOrganizationRepository::new();
+        mock_organization_repo
+            .expect_find_role()
+            .with(eq(5), eq(2))
+            .returning(move |_, _| Ok(role));
+        mock_organization_repo
+    }
+
+    fn organization(verified: bool) -> Organization {
+        Organization {
+            id: 5,
+            name: "Yayasan Peduli".to_string(),
+            registration_number: "AHU-0001".to_string(),
+            description: None,
+            website: None,
+            created_by: 2,
+            verified_at: verified.then(Utc::now),
+            verified_by: verified.then_some(99),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_treasurer_can_request_payouts_but_not_manage_members() {
+        let service =
+            OrganizationService::new(Arc::new(repo_with_role(Some(OrganizationRole::Treasurer))));
+
+        assert!(
+            service
+                .authorize(5, 2, OrganizationAction::RequestPayout)
+                .await
+                .is_ok()
+        );
+        match service
+            .authorize(5, 2, OrganizationAction::ManageMembers)
+            .await
+            .err()
+            .unwrap()
+        {
+            AppError::Forbidden(msg) => assert!(msg.contains("does not allow")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_member_cannot_view_wallet() {
+        let service =
+            OrganizationService::new(Arc::new(repo_with_role(Some(OrganizationRole::Member))));
+        let result = service.get_wallet(5, 2).await;
+
+        assert!(matches!(result.err().unwrap(), AppError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unverified_organization_cannot_own_campaigns() {
+        let mut mock_organization_repo = repo_with_role(Some(OrganizationRole::Admin));
+        mock_organization_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(organization(false))));
+        mock_organization_repo.expect_assign_campaign().times(0);
+
+        let service = OrganizationService::new(Arc::new(mock_organization_repo));
+        let result = service.assign_campaign(10, 5, 2).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("verified")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_removing_last_admin_is_rejected() {
+        let mut mock_organization_repo = MockOrganizationRepository::new();
+        mock_organization_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(OrganizationRole::Admin)));
+        mock_organization_repo
+            .expect_remove_member()
+            .with(eq(5), eq(2))
+            .returning(|_, _| Ok(0));
+
+        let service = OrganizationService::new(Arc::new(mock_organization_repo));
+        let result = service.remove_member(5, 2, 2).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("at least one admin")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::errors::AppError;
+use crate::model::outbox::OutboxEvent;
+use crate::repository::outbox_repo::OutboxRepository;
+use crate::service::background_job::run_job;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(test)]
+use mockall::automock;
+
+pub const OUTBOX_BATCH_SIZE: i64 = 100;
+pub const DEFAULT_DISPATCH_INTERVAL: Duration = Duration::from_secs(5);
+/// Attempts before an event is dead-lettered.
+pub const OUTBOX_MAX_ATTEMPTS: i32 = 10;
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(30);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60 * 60);
+// How long a claimed batch is hidden from other dispatchers; longer than a batch takes.
+const CLAIM_LEASE: Duration = Duration::from_secs(10 * 60);
+
+// Implemented by whatever delivers the side effect (e.g. the notification service).
+// Events can be delivered more than once, so handlers must be idempotent.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait OutboxHandler: Send + Sync {
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), AppError>;
+}
+
+pub struct OutboxDispatcher {
+    outbox_repo: Arc<dyn OutboxRepository>,
+    handler: Arc<dyn OutboxHandler>,
+}
+
+impl OutboxDispatcher {
+    pub fn new(outbox_repo: Arc<dyn OutboxRepository>, handler: Arc<dyn OutboxHandler>) -> Self {
+        OutboxDispatcher {
+            outbox_repo,
+            handler,
+        }
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: Duration) {
+        rocket::tokio::spawn(async move {
+            loop {
+                run_job("outbox_dispatch", self.dispatch_pending()).await;
+                rocket::tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    /// Delay before the next attempt of an event that has failed `failures` times.
+    pub fn retry_delay(failures: i32) -> Duration {
+        let factor = 1u32
+            .checked_shl(failures.max(1) as u32 - 1)
+            .unwrap_or(u32::MAX);
+        RETRY_BASE_DELAY.saturating_mul(factor).min(RETRY_MAX_DELAY)
+    }
+
+    // An event is only marked dispatched after its handler succeeds; failures back off
+    // and are dead-lettered after OUTBOX_MAX_ATTEMPTS.
+    pub async fn dispatch_pending(&self) -> Result<usize, AppError> {
+        let lease = chrono::Duration::seconds(CLAIM_LEASE.as_secs() as i64);
+        let events = self.outbox_repo.claim_due(OUTBOX_BATCH_SIZE, lease).await?;
+        let mut dispatched = 0;
+        for event in events {
+            match self.handler.handle(&event).await {
+                Ok(()) => {
+                    self.outbox_repo.mark_dispatched(event.id).await?;
+                    dispatched += 1;
+                }
+                Err(e) => {
+                    let failures = event.attempts + 1;
+                    if failures >= OUTBOX_MAX_ATTEMPTS {
+                        tracing::error!(
+                            event_id = event.id,
+                            event_type = %event.event_type,
+                            error = %e,
+                            "outbox event dead-lettered"
+                        );
+                    }
+                    let delay = Self::retry_delay(failures);
+                    let retry_at = Utc::now() + chrono::Duration::seconds(delay.as_secs() as i64);
+                    self.outbox_repo
+                        .mark_failed(event.id, e.to_string(), retry_at, OUTBOX_MAX_ATTEMPTS)
+                        .await?;
+                }
+            }
+        }
+        Ok(dispatched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::outbox::OutboxStatus;
+    use crate::repository::outbox_repo::MockOutboxRepository;
+    use mockall::predicate::*;
+    use rocket::serde::json::json;
+
+    fn event(id: i64, event_type: &str) -> OutboxEvent {
+        OutboxEvent {
+            id,
+            event_type: event_type.to_string(),
+            payload: json!({ "campaign_id": 10 }),
+            attempts: 0,
+            last_error: None,
+            status: OutboxStatus::Pending,
+            next_attempt_at: Utc::now(),
+            created_at: Utc::now(),
+            dispatched_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_marks_delivered_events() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_handler = MockOutboxHandler::new();
+
+        mock_outbox_repo
+            .expect_claim_due()
+            .withf(|limit, _| *limit == OUTBOX_BATCH_SIZE)
+            .returning(|_, _| {
+                Ok(vec![
+                    event(1, "campaign_approved"),
+                    event(2, "campaign_approved"),
+                ])
+            });
+        mock_handler.expect_handle().times(2).returning(|_| Ok(()));
+        mock_outbox_repo
+            .expect_mark_dispatched()
+            .times(2)
+            .returning(|_| Ok(()));
+        mock_outbox_repo.expect_mark_failed().times(0);
+
+        let dispatcher = OutboxDispatcher::new(Arc::new(mock_outbox_repo), Arc::new(mock_handler));
+
+        assert_eq!(dispatcher.dispatch_pending().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_keeps_failed_events_pending() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_handler = MockOutboxHandler::new();
+
+        mock_outbox_repo.expect_claim_due().returning(|_, _| {
+            Ok(vec![
+                event(1, "campaign_approved"),
+                event(2, "campaign_approved"),
+            ])
+        });
+        mock_handler.expect_handle().returning(|event| {
+            if event.id == 1 {
+                Err(AppError::InternalServerError(
+                    "SMTP unavailable".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        });
+        mock_outbox_repo
+            .expect_mark_failed()
+            .withf(|id, error, retry_at, max_attempts| {
+                *id == 1
+                    && error.contains("SMTP unavailable")
+                    && *retry_at > Utc::now()
+                    && *max_attempts == OUTBOX_MAX_ATTEMPTS
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        mock_outbox_repo
+            .expect_mark_dispatched()
+            .with(eq(2))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let dispatcher = OutboxDispatcher::new(Arc::new(mock_outbox_repo), Arc::new(mock_handler));
+
+        assert_eq!(dispatcher.dispatch_pending().await.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_cap() {
+        assert_eq!(OutboxDispatcher::retry_delay(1), Duration::from_secs(30));
+        assert_eq!(OutboxDispatcher::retry_delay(2), Duration::from_secs(60));
+        assert_eq!(OutboxDispatcher::retry_delay(9), RETRY_MAX_DELAY);
+        assert_eq!(OutboxDispatcher::retry_delay(40), RETRY_MAX_DELAY);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::errors::AppError;
+use crate::model::profile::{Profile, UpdateProfileRequest};
+use crate::repository::profile_repo::ProfileRepository;
+use std::sync::Arc;
+
+pub struct ProfileService {
+    profile_repo: Arc<dyn ProfileRepository>,
+}
+
+impl ProfileService {
+    pub fn new(profile_repo: Arc<dyn ProfileRepository>) -> Self {
+        ProfileService { profile_repo }
+    }
+
+    pub async fn get_profile(&self, user_id: i32) -> Result<Profile, AppError> {
+        self.profile_repo
+            .find(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Profile not found".to_string()))
+    }
+
+    pub async fn update_profile(
+        &self,
+        user_id: i32,
+        mut profile: UpdateProfileRequest,
+    ) -> Result<Profile, AppError> {
+        profile.display_name = profile.display_name.trim().to_string();
+        if profile.display_name.is_empty() {
+            return Err(AppError::ValidationError(
+                "display_name must not be blank".to_string(),
+            ));
+        }
+        // Clearing the bio from a form sends an empty string; store it as absent.
+        profile.bio = profile.bio.filter(|bio| !bio.trim().is_empty());
+        self.profile_repo.upsert(user_id, &profile).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::profile_repo::MockProfileRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_update_profile_trims_name_and_drops_empty_bio() {
+        let mut mock_profile_repo = MockProfileRepository::new();
+        mock_profile_repo
+            .expect_upsert()
+            .withf(|user_id, profile| {
+                *user_id == 1 && profile.display_name == "Budi" && profile.bio.is_none()
+            })
+            .times(1)
+            .returning(|user_id, profile| {
+                Ok(Profile {
+                    user_id,
+                    display_name: profile.display_name.clone(),
+                    avatar_url: profile.avatar_url.clone(),
+                    bio: profile.bio.clone(),
+                    updated_at: Utc::now(),
+                })
+            });
+        let service = ProfileService::new(Arc::new(mock_profile_repo));
+
+        let profile = service
+            .update_profile(
+                1,
+                UpdateProfileRequest {
+                    display_name: "  Budi ".to_string(),
+                    avatar_url: None,
+                    bio: Some("  ".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(profile.display_name, "Budi");
+    }
+
+    #[tokio::test]
+    async fn test_get_profile_not_found() {
+        let mut mock_profile_repo = MockProfileRepository::new();
+        mock_profile_repo
+            .expect_find()
+            .with(eq(2))
+            .returning(|_| Ok(None));
+        let service = ProfileService::new(Arc::new(mock_profile_repo));
+
+        match service.get_profile(2).await.err().unwrap() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Profile not found"),
+            _ => panic!("Expected NotFound error"),
+        }
+    }
+}
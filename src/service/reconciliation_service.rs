@@ -0,0 +1,527 @@
+use crate::config::PaymentProviderConfig;
+use crate::errors::AppError;
+use crate::model::reconciliation::{
+    Discrepancy, DiscrepancyKind, NewReconciliationRun, ReconciliationReport, ReconciliationRun,
+    SettlementRow,
+};
+use crate::model::transaction::{Transaction, TransactionFilter, TransactionType};
+use crate::repository::reconciliation_repo::ReconciliationRepository;
+use crate::repository::transaction_repo::TransactionRepository;
+use crate::service::background_job::run_job;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+#[cfg(test)]
+use mockall::automock;
+
+const LEDGER_BATCH_SIZE: i64 = 1000;
+// Generous for a day's CSV, but a provider that stops answering must not hang the job.
+const SETTLEMENT_FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+const RECENT_RUNS_LIMIT: i64 = 50;
+// Amounts are FLOAT8 on both sides; anything below a cent is rounding noise.
+const AMOUNT_TOLERANCE: f64 = 0.005;
+// Providers publish the previous day's settlement report shortly after midnight UTC.
+const NIGHTLY_RUN_AT: NaiveTime = match NaiveTime::from_hms_opt(2, 0, 0) {
+    Some(time) => time,
+    None => panic!("invalid nightly run time"),
+};
+
+/// `created_by` of runs started by the nightly schedule rather than an admin.
+pub const SCHEDULED_RUN_CREATOR: i32 = 0;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SettlementReportSource: Send + Sync {
+    /// The provider's settlement CSV for top-ups in `[from, to)`.
+    async fn fetch(
+        &self,
+        provider: &PaymentProviderConfig,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<String, AppError>;
+}
+
+/// Fetches `GET <api_base_url><settlement_report_path>?from=YYYY-MM-DD&to=YYYY-MM-DD`,
+/// `to` exclusive, authenticated with the provider's secret key as a bearer token.
+pub struct HttpSettlementReportSource {
+    client: reqwest::Client,
+}
+
+impl HttpSettlementReportSource {
+    pub fn new() -> Self {
+        HttpSettlementReportSource {
+            client: reqwest::Client::builder()
+                .timeout(SETTLEMENT_FETCH_TIMEOUT)
+                .build()
+                .expect("settlement report HTTP client"),
+        }
+    }
+}
+
+impl Default for HttpSettlementReportSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SettlementReportSource for HttpSettlementReportSource {
+    async fn fetch(
+        &self,
+        provider: &PaymentProviderConfig,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<String, AppError> {
+        let mut request = self
+            .client
+            .get(format!(
+                "{}{}",
+                provider.api_base_url, provider.settlement_report_path
+            ))
+            .query(&[
+                ("from", from.format("%Y-%m-%d").to_string()),
+                ("to", to.format("%Y-%m-%d").to_string()),
+            ]);
+        if let Some(secret_key) = &provider.secret_key {
+            request = request.bearer_auth(secret_key);
+        }
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                AppError::InternalServerError(format!(
+                    "Could not fetch the {} settlement report: {}",
+                    provider.name, e
+                ))
+            })?;
+        response.text().await.map_err(|e| {
+            AppError::InternalServerError(format!(
+                "Could not read the {} settlement report: {}",
+                provider.name, e
+            ))
+        })
+    }
+}
+
+/// How long from `now` until the next nightly run.
+fn until_next_run(now: DateTime<Utc>) -> std::time::Duration {
+    let today = now.date_naive().and_time(NIGHTLY_RUN_AT).and_utc();
+    let next = if today > now {
+        today
+    } else {
+        today + Duration::days(1)
+    };
+    (next - now).to_std().unwrap_or_default()
+}
+
+pub struct ReconciliationService {
+    transaction_repo: Arc<dyn TransactionRepository>,
+    reconciliation_repo: Arc<dyn ReconciliationRepository>,
+    settlement_reports: Option<Arc<dyn SettlementReportSource>>,
+    providers: Vec<PaymentProviderConfig>,
+}
+
+impl ReconciliationService {
+    pub fn new(
+        transaction_repo: Arc<dyn TransactionRepository>,
+        reconciliation_repo: Arc<dyn ReconciliationRepository>,
+    ) -> Self {
+        ReconciliationService {
+            transaction_repo,
+            reconciliation_repo,
+            settlement_reports: None,
+            providers: Vec::new(),
+        }
+    }
+
+    /// Enables the nightly run for the enabled providers in `providers`.
+    pub fn with_settlement_reports(
+        mut self,
+        settlement_reports: Arc<dyn SettlementReportSource>,
+        providers: Vec<PaymentProviderConfig>,
+    ) -> Self {
+        self.settlement_reports = Some(settlement_reports);
+        self.providers = providers;
+        self
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                rocket::tokio::time::sleep(until_next_run(Utc::now())).await;
+                run_job(
+                    "settlement_reconciliation",
+                    self.reconcile_previous_day(Utc::now()),
+                )
+                .await;
+            }
+        });
+    }
+
+    /// Reconciles every enabled provider's report for the UTC day before `now`. A
+    /// provider that fails doesn't stop the others; the error names each one that did.
+    pub async fn reconcile_previous_day(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ReconciliationReport>, AppError> {
+        let Some(settlement_reports) = &self.settlement_reports else {
+            return Err(AppError::InternalServerError(
+                "No settlement report source is configured for reconciliation".to_string(),
+            ));
+        };
+
+        let to = now.date_naive().and_time(NaiveTime::MIN).and_utc();
+        let from = to - Duration::days(1);
+        let mut reports = Vec::new();
+        let mut failed = Vec::new();
+        for provider in self.providers.iter().filter(|provider| provider.enabled) {
+            let report = match settlement_reports.fetch(provider, from, to).await {
+                Ok(csv) => {
+                    self.reconcile(SCHEDULED_RUN_CREATOR, &provider.name, from, to, &csv)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            match report {
+                Ok(report) => reports.push(report),
+                Err(e) => {
+                    tracing::error!(provider = provider.name.as_str(), error = %e, "Nightly reconciliation failed");
+                    failed.push(provider.name.clone());
+                }
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(AppError::InternalServerError(format!(
+                "Nightly reconciliation failed for {}",
+                failed.join(", ")
+            )));
+        }
+        Ok(reports)
+    }
+
+    /// Matches a provider settlement CSV (`order_id,amount` header) against the
+    /// top-ups recorded in `[from, to)` and stores the discrepancy report.
+    pub async fn reconcile(
+        &self,
+        admin_id: i32,
+        provider: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        settlement_csv: &str,
+    ) -> Result<ReconciliationReport, AppError> {
+        let provider = provider.trim();
+        if provider.is_empty() {
+            return Err(AppError::ValidationError(
+                "provider is required".to_string(),
+            ));
+        }
+        if from >= to {
+            return Err(AppError::ValidationError(
+                "from must be before to".to_string(),
+            ));
+        }
+
+        let settlement = parse_settlement_csv(settlement_csv)?;
+        let ledger = self.load_top_ups(from, to).await?;
+        let (matched, discrepancies) = compare(&ledger, &settlement);
+
+        self.reconciliation_repo
+            .save(NewReconciliationRun {
+                provider: provider.to_string(),
+                period_from: from,
+                period_to: to,
+                settlement_rows: settlement.len() as i32,
+                matched: matched as i32,
+                created_by: admin_id,
+                discrepancies,
+            })
+            .await
+    }
+
+    pub async fn get_report(&self, run_id: i32) -> Result<ReconciliationReport, AppError> {
+        self.reconciliation_repo
+            .find_by_id(run_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Reconciliation report not found".to_string()))
+    }
+
+    pub async fn list_runs(&self) -> Result<Vec<ReconciliationRun>, AppError> {
+        self.reconciliation_repo
+            .find_recent(RECENT_RUNS_LIMIT)
+            .await
+    }
+
+    async fn load_top_ups(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Transaction>, AppError> {
+        let filter = TransactionFilter {
+            transaction_type: Some(TransactionType::TopUp),
+            from: Some(from),
+            to: Some(to),
+            ..Default::default()
+        };
+        let mut top_ups = Vec::new();
+        let mut offset = 0;
+        loop {
+            let batch = self
+                .transaction_repo
+                .find(&filter, LEDGER_BATCH_SIZE, offset)
+                .await?;
+            let done = (batch.len() as i64) < LEDGER_BATCH_SIZE;
+            top_ups.extend(batch);
+            if done {
+                break;
+            }
+            offset += LEDGER_BATCH_SIZE;
+        }
+        Ok(top_ups)
+    }
+}
+
+fn parse_settlement_csv(body: &str) -> Result<Vec<SettlementRow>, AppError> {
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| AppError::ValidationError(format!("Invalid CSV header: {}", e)))?
+        .clone();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.and_then(|record| record.deserialize::<SettlementRow>(Some(&headers)));
+        match record {
+            Ok(row) => rows.push(row),
+            Err(e) => {
+                let line = e.position().map(|pos| pos.line()).unwrap_or(0);
+                return Err(AppError::ValidationError(format!(
+                    "Malformed settlement row on line {}: {}",
+                    line, e
+                )));
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// Returns the number of cleanly matched orders and every discrepancy, ordered by order id.
+fn compare(ledger: &[Transaction], settlement: &[SettlementRow]) -> (usize, Vec<Discrepancy>) {
+    let mut settled: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+    for row in settlement {
+        settled.entry(row.order_id).or_default().push(row.amount);
+    }
+    let ledger_amounts: HashMap<i32, f64> = ledger
+        .iter()
+        .map(|transaction| (transaction.id, transaction.amount))
+        .collect();
+
+    let mut matched = 0;
+    let mut discrepancies = Vec::new();
+    for (&order_id, amounts) in &settled {
+        let ledger_amount = ledger_amounts.get(&order_id).copied();
+        if amounts.len() > 1 {
+            discrepancies.extend(amounts.iter().map(|&amount| Discrepancy {
+                kind: DiscrepancyKind::Duplicated,
+                order_id,
+                ledger_amount,
+                settlement_amount: Some(amount),
+            }));
+            continue;
+        }
+        let settlement_amount = amounts[0];
+        match ledger_amount {
+            None => discrepancies.push(Discrepancy {
+                kind: DiscrepancyKind::MissingInLedger,
+                order_id,
+                ledger_amount: None,
+                settlement_amount: Some(settlement_amount),
+            }),
+            Some(amount) if (amount - settlement_amount).abs() > AMOUNT_TOLERANCE => discrepancies
+                .push(Discrepancy {
+                    kind: DiscrepancyKind::AmountMismatch,
+                    order_id,
+                    ledger_amount: Some(amount),
+                    settlement_amount: Some(settlement_amount),
+                }),
+            Some(_) => matched += 1,
+        }
+    }
+
+    let mut unsettled: Vec<&Transaction> = ledger
+        .iter()
+        .filter(|transaction| !settled.contains_key(&transaction.id))
+        .collect();
+    unsettled.sort_by_key(|transaction| transaction.id);
+    discrepancies.extend(unsettled.into_iter().map(|transaction| Discrepancy {
+        kind: DiscrepancyKind::MissingInSettlement,
+        order_id: transaction.id,
+        ledger_amount: Some(transaction.amount),
+        settlement_amount: None,
+    }));
+
+    (matched, discrepancies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::reconciliation_repo::MockReconciliationRepository;
+    use crate::repository::transaction_repo::MockTransactionRepository;
+
+    fn top_up(id: i32, amount: f64) -> Transaction {
+        Transaction {
+            id,
+            user_id: 1,
+            campaign_id: None,
+            transaction_type: TransactionType::TopUp,
+            amount,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_each_kind_of_discrepancy() {
+        let ledger = vec![
+            top_up(1, 10_000.0),
+            top_up(2, 20_000.0),
+            top_up(3, 30_000.0),
+            top_up(4, 40_000.0),
+        ];
+        let settlement =
+            parse_settlement_csv("order_id,amount\n1,10000\n2,19000\n3,30000\n3,30000\n9,5000\n")
+                .unwrap();
+
+        let (matched, discrepancies) = compare(&ledger, &settlement);
+        assert_eq!(matched, 1);
+        let kinds: Vec<(DiscrepancyKind, i32)> = discrepancies
+            .iter()
+            .map(|discrepancy| (discrepancy.kind, discrepancy.order_id))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (DiscrepancyKind::AmountMismatch, 2),
+                (DiscrepancyKind::Duplicated, 3),
+                (DiscrepancyKind::Duplicated, 3),
+                (DiscrepancyKind::MissingInLedger, 9),
+                (DiscrepancyKind::MissingInSettlement, 4),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_rejects_malformed_settlement_rows() {
+        let service = ReconciliationService::new(
+            Arc::new(MockTransactionRepository::new()),
+            Arc::new(MockReconciliationRepository::new()),
+        );
+        let to = Utc::now();
+        let from = to - chrono::Duration::days(1);
+
+        let result = service
+            .reconcile(1, "gopay", from, to, "order_id,amount\n1,10000\nabc,5\n")
+            .await;
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("line 3")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[test]
+    fn test_until_next_run_waits_for_the_nightly_slot() {
+        let at = |time: &str| {
+            DateTime::parse_from_rfc3339(time)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        assert_eq!(
+            until_next_run(at("2026-10-16T01:30:00Z")),
+            std::time::Duration::from_secs(30 * 60)
+        );
+        assert_eq!(
+            until_next_run(at("2026-10-16T02:00:00Z")),
+            std::time::Duration::from_secs(24 * 60 * 60)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nightly_run_reconciles_previous_day_per_enabled_provider() {
+        let provider = |name: &str, enabled: bool| PaymentProviderConfig {
+            name: name.to_string(),
+            api_base_url: format!("https://{}.example", name),
+            secret_key: None,
+            enabled,
+            ..PaymentProviderConfig::default()
+        };
+        let now = DateTime::parse_from_rfc3339("2026-10-16T02:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let day_start = DateTime::parse_from_rfc3339("2026-10-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut mock_source = MockSettlementReportSource::new();
+        mock_source
+            .expect_fetch()
+            .withf(move |provider, from, to| {
+                *from == day_start && *to == day_start + Duration::days(1) && provider.enabled
+            })
+            .returning(|provider, _, _| match provider.name.as_str() {
+                "gopay" => Ok("order_id,amount\n1,10000\n".to_string()),
+                _ => Err(AppError::InternalServerError("unreachable".to_string())),
+            });
+        let mut mock_transaction_repo = MockTransactionRepository::new();
+        mock_transaction_repo
+            .expect_find()
+            .returning(|_, _, _| Ok(vec![top_up(1, 10_000.0)]));
+        let mut mock_reconciliation_repo = MockReconciliationRepository::new();
+        mock_reconciliation_repo
+            .expect_save()
+            .withf(|run| run.created_by == SCHEDULED_RUN_CREATOR && run.matched == 1)
+            .times(1)
+            .returning(|run| {
+                Ok(ReconciliationReport {
+                    run: ReconciliationRun {
+                        id: 1,
+                        provider: run.provider,
+                        period_from: run.period_from,
+                        period_to: run.period_to,
+                        settlement_rows: run.settlement_rows,
+                        matched: run.matched,
+                        discrepancy_count: run.discrepancies.len() as i32,
+                        created_by: run.created_by,
+                        created_at: Utc::now(),
+                    },
+                    discrepancies: run.discrepancies,
+                })
+            });
+
+        let service = ReconciliationService::new(
+            Arc::new(mock_transaction_repo),
+            Arc::new(mock_reconciliation_repo),
+        )
+        .with_settlement_reports(
+            Arc::new(mock_source),
+            vec![
+                provider("gopay", true),
+                provider("ovo", false),
+                provider("dana", true),
+            ],
+        );
+
+        match service.reconcile_previous_day(now).await {
+            Err(AppError::InternalServerError(msg)) => {
+                assert_eq!(msg, "Nightly reconciliation failed for dana")
+            }
+            other => panic!(
+                "Expected the failed provider to be reported, got {:?}",
+                other
+            ),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::errors::AppError;
+use crate::model::risk::{
+    BlacklistEntry, FlaggedActivity, NewBlacklistEntryRequest, NewRiskRuleRequest, RiskAction,
+    RiskActivity, RiskDecision, RiskRule, RiskRuleKind, UpdateRiskRuleRequest,
+};
+use crate::repository::risk_repo::RiskRepository;
+use crate::service::commands::risk_commands::EvaluateRiskCommand;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub struct RiskService {
+    risk_repo: Arc<dyn RiskRepository>,
+}
+
+impl RiskService {
+    pub fn new(risk_repo: Arc<dyn RiskRepository>) -> Self {
+        RiskService { risk_repo }
+    }
+
+    /// A blocking flag enqueues `RiskBlocked` along with the flag itself.
+    pub async fn evaluate(&self, cmd: EvaluateRiskCommand) -> Result<RiskDecision, AppError> {
+        if self
+            .risk_repo
+            .is_blacklisted(cmd.user_id, cmd.ip_address.clone())
+            .await?
+        {
+            let reason = "User or IP address is blacklisted".to_string();
+            self.risk_repo
+                .record_flag(
+                    cmd.user_id,
+                    None,
+                    cmd.activity,
+                    cmd.amount,
+                    RiskAction::Block,
+                    reason.clone(),
+                )
+                .await?;
+            return Ok(RiskDecision::Block(reason));
+        }
+
+        let mut decision = RiskDecision::Allow;
+        for rule in self.risk_repo.find_enabled_rules().await? {
+            let Some(reason) = self.check_rule(&rule, &cmd).await? else {
+                continue;
+            };
+
+            self.risk_repo
+                .record_flag(
+                    cmd.user_id,
+                    Some(rule.id),
+                    cmd.activity,
+                    cmd.amount,
+                    rule.action,
+                    reason.clone(),
+                )
+                .await?;
+
+            match rule.action {
+                RiskAction::Block => return Ok(RiskDecision::Block(reason)),
+                RiskAction::Flag => {
+                    if decision == RiskDecision::Allow {
+                        decision = RiskDecision::Flag(reason);
+                    }
+                }
+            }
+        }
+        Ok(decision)
+    }
+
+    async fn check_rule(
+        &self,
+        rule: &RiskRule,
+        cmd: &EvaluateRiskCommand,
+    ) -> Result<Option<String>, AppError> {
+        match rule.kind {
+            RiskRuleKind::DailyAmountCap => {
+                let since = Utc::now() - Duration::hours(24);
+                let total = self
+                    .risk_repo
+                    .sum_amount_since(cmd.user_id, cmd.activity, since)
+                    .await?;
+                if total + cmd.amount > rule.threshold {
+                    return Ok(Some(format!(
+                        "Daily amount cap exceeded: {}",
+                        rule.threshold
+                    )));
+                }
+            }
+            RiskRuleKind::DistinctCampaignsPerHour => {
+                let Some(campaign_id) = cmd.campaign_id else {
+                    return Ok(None);
+                };
+                if cmd.activity != RiskActivity::Donation {
+                    return Ok(None);
+                }
+                let since = Utc::now() - Duration::hours(1);
+                let count = self
+                    .risk_repo
+                    .count_distinct_campaigns_since(cmd.user_id, campaign_id, since)
+                    .await?;
+                if count as f64 > rule.threshold {
+                    return Ok(Some(format!(
+                        "Distinct campaign limit exceeded: more than {} in the last hour",
+                        rule.threshold
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn get_rules(&self) -> Result<Vec<RiskRule>, AppError> {
+        self.risk_repo.find_rules().await
+    }
+
+    pub async fn create_rule(&self, new_rule: NewRiskRuleRequest) -> Result<RiskRule, AppError> {
+        if new_rule.threshold <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Rule threshold must be positive".to_string(),
+            ));
+        }
+        self.risk_repo.create_rule(&new_rule).await
+    }
+
+    pub async fn update_rule(
+        &self,
+        rule_id: i32,
+        update: UpdateRiskRuleRequest,
+    ) -> Result<RiskRule, AppError> {
+        if update.threshold <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Rule threshold must be positive".to_string(),
+            ));
+        }
+        self.risk_repo
+            .update_rule(rule_id, &update)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Risk rule not found".to_string()))
+    }
+
+    pub async fn delete_rule(&self, rule_id: i32) -> Result<(), AppError> {
+        if self.risk_repo.delete_rule(rule_id).await? == 0 {
+            return Err(AppError::NotFound("Risk rule not found".to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn get_blacklist(&self) -> Result<Vec<BlacklistEntry>, AppError> {
+        self.risk_repo.find_blacklist().await
+    }
+
+    pub async fn add_blacklist_entry(
+        &self,
+        entry: NewBlacklistEntryRequest,
+    ) -> Result<BlacklistEntry, AppError> {
+        if entry.user_id.is_none() && entry.ip_address.is_none() {
+            return Err(AppError::ValidationError(
+                "Blacklist entry needs a user id or an IP address".to_string(),
+            ));
+        }
+        self.risk_repo.add_blacklist_entry(&entry).await
+    }
+
+    pub async fn remove_blacklist_entry(&self, entry_id: i32) -> Result<(), AppError> {
+        if self.risk_repo.remove_blacklist_entry(entry_id).await? == 0 {
+            return Err(AppError::NotFound("Blacklist entry not found".to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn get_flagged_activity(&self) -> Result<Vec<FlaggedActivity>, AppError> {
+        self.risk_repo.find_flags().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::risk_repo::MockRiskRepository;
+    use mockall::predicate::*;
+
+    fn rule(id: i32, kind: RiskRuleKind, threshold: f64, action: RiskAction) -> RiskRule {
+        RiskRule {
+            id,
+            kind,
+            threshold,
+            action,
+            enabled: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn flag(action: RiskAction) -> FlaggedActivity {
+        FlaggedActivity {
+            id: 1,
+            user_id: 1,
+            rule_id: None,
+            activity: RiskActivity::Donation,
+            amount: 100.0,
+            action,
+            reason: String::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn donation_cmd(amount: f64) -> EvaluateRiskCommand {
+        EvaluateRiskCommand {
+            user_id: 1,
+            ip_address: Some("10.0.0.1".to_string()),
+            activity: RiskActivity::Donation,
+            campaign_id: Some(10),
+            amount,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_allows_when_no_rule_matches() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .returning(|_, _| Ok(false));
+        mock_risk_repo.expect_find_enabled_rules().returning(|| {
+            Ok(vec![rule(
+                1,
+                RiskRuleKind::DailyAmountCap,
+                1000.0,
+                RiskAction::Block,
+            )])
+        });
+        mock_risk_repo
+            .expect_sum_amount_since()
+            .returning(|_, _, _| Ok(200.0));
+        mock_risk_repo.expect_record_flag().times(0);
+
+        let service = RiskService::new(Arc::new(mock_risk_repo));
+        let result = service.evaluate(donation_cmd(100.0)).await;
+
+        assert_eq!(result.unwrap(), RiskDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_blocks_blacklisted_user() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .with(eq(1), eq(Some("10.0.0.1".to_string())))
+            .returning(|_, _| Ok(true));
+        mock_risk_repo
+            .expect_record_flag()
+            .withf(|_, rule_id, _, _, action, _| rule_id.is_none() && *action == RiskAction::Block)
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok(flag(RiskAction::Block)));
+
+        let service = RiskService::new(Arc::new(mock_risk_repo));
+        let result = service.evaluate(donation_cmd(100.0)).await;
+
+        assert!(matches!(result.unwrap(), RiskDecision::Block(_)));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_daily_cap_flags() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .returning(|_, _| Ok(false));
+        mock_risk_repo.expect_find_enabled_rules().returning(|| {
+            Ok(vec![rule(
+                1,
+                RiskRuleKind::DailyAmountCap,
+                1000.0,
+                RiskAction::Flag,
+            )])
+        });
+        mock_risk_repo
+            .expect_sum_amount_since()
+            .returning(|_, _, _| Ok(950.0));
+        mock_risk_repo
+            .expect_record_flag()
+            .withf(|_, rule_id, _, _, action, _| *rule_id == Some(1) && *action == RiskAction::Flag)
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok(flag(RiskAction::Flag)));
+
+        let service = RiskService::new(Arc::new(mock_risk_repo));
+        let result = service.evaluate(donation_cmd(100.0)).await;
+
+        match result.unwrap() {
+            RiskDecision::Flag(reason) => assert!(reason.contains("Daily amount cap")),
+            other => panic!("Expected Flag, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_block_takes_precedence_over_flag() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .returning(|_, _| Ok(false));
+        mock_risk_repo.expect_find_enabled_rules().returning(|| {
+            Ok(vec![
+                rule(1, RiskRuleKind::DailyAmountCap, 1000.0, RiskAction::Flag),
+                rule(2, RiskRuleKind::DistinctCampaignsPerHour, 3.0, RiskAction::Block),
+            ])
+        });
+        mock_risk_repo
+            .expect_sum_amount_since()
+            .returning(|_, _, _| Ok(5000.0));
+        mock_risk_repo
+            .expect_count_distinct_campaigns_since()
+            .with(eq(1), eq(10), always())
+            .returning(|_, _, _| Ok(4));
+        mock_risk_repo
+            .expect_record_flag()
+            .times(2)
+            .returning(|_, _, _, _, _, _| Ok(flag(RiskAction::Flag)));
+
+        let service = RiskService::new(Arc::new(mock_risk_repo));
+        let result = service.evaluate(donation_cmd(100.0)).await;
+
+        match result.unwrap() {
+            RiskDecision::Block(reason) => assert!(reason.starts_with("Distinct campaign limit exceeded")),
+            other => panic!("Expected Block, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_blacklist_entry_requires_target() {
+        let service = RiskService::new(Arc::new(MockRiskRepository::new()));
+        let entry = NewBlacklistEntryRequest {
+            user_id: None,
+            ip_address: None,
+            reason: "Chargeback abuse".to_string(),
+        };
+        let result = service.add_blacklist_entry(entry).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("user id or an IP")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_rule_not_found() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo.expect_delete_rule().returning(|_| Ok(0));
+
+        let service = RiskService::new(Arc::new(mock_risk_repo));
+        let result = service.delete_rule(5).await;
+
+        match result.err().unwrap() {
+            AppError::NotFound(msg) => assert!(msg.contains("Risk rule not found")),
+            _ => panic!("Expected NotFound error"),
+        }
+    }
+}
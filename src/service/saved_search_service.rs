@@ -0,0 +1,189 @@
+use crate::errors::AppError;
+use crate::model::event::{DomainEvent, DomainEventKind};
+use crate::model::saved_search::{SavedSearch, SavedSearchRequest};
+use crate::repository::saved_search_repo::SavedSearchRepository;
+use crate::service::event_bus::{EventBus, EventSubscriber};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub const MAX_SAVED_SEARCHES: i64 = 20;
+
+fn normalize(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|value| !value.is_empty())
+}
+
+/// Saved searches, and the alerts sent when a newly approved campaign matches one.
+pub struct SavedSearchService {
+    saved_search_repo: Arc<dyn SavedSearchRepository>,
+}
+
+impl SavedSearchService {
+    pub fn new(saved_search_repo: Arc<dyn SavedSearchRepository>) -> Self {
+        SavedSearchService { saved_search_repo }
+    }
+
+    // Alerts are written to the outbox, so matching runs with the persisting subscribers.
+    pub fn subscribe_to(self: &Arc<Self>, event_bus: EventBus) -> EventBus {
+        event_bus.subscribe_critical(DomainEventKind::CampaignApproved, self.clone())
+    }
+
+    fn criteria(req: SavedSearchRequest) -> Result<SavedSearchRequest, AppError> {
+        let req = SavedSearchRequest {
+            keywords: normalize(req.keywords),
+            category: normalize(req.category),
+            region: normalize(req.region),
+        };
+        if req.keywords.is_none() && req.category.is_none() && req.region.is_none() {
+            return Err(AppError::ValidationError(
+                "Set at least one of keywords, category or region".to_string(),
+            ));
+        }
+        Ok(req)
+    }
+
+    pub async fn get_saved_searches(&self, user_id: i32) -> Result<Vec<SavedSearch>, AppError> {
+        self.saved_search_repo.find_by_user(user_id).await
+    }
+
+    pub async fn create_saved_search(
+        &self,
+        user_id: i32,
+        req: SavedSearchRequest,
+    ) -> Result<SavedSearch, AppError> {
+        let req = Self::criteria(req)?;
+        if self.saved_search_repo.count_by_user(user_id).await? >= MAX_SAVED_SEARCHES {
+            return Err(AppError::ValidationError(format!(
+                "You can save at most {} searches",
+                MAX_SAVED_SEARCHES
+            )));
+        }
+        self.saved_search_repo.create(user_id, &req).await
+    }
+
+    pub async fn update_saved_search(
+        &self,
+        search_id: i32,
+        user_id: i32,
+        req: SavedSearchRequest,
+    ) -> Result<SavedSearch, AppError> {
+        let req = Self::criteria(req)?;
+        self.saved_search_repo
+            .update(search_id, user_id, &req)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Saved search not found".to_string()))
+    }
+
+    pub async fn delete_saved_search(&self, search_id: i32, user_id: i32) -> Result<(), AppError> {
+        if !self.saved_search_repo.delete(search_id, user_id).await? {
+            return Err(AppError::NotFound("Saved search not found".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for SavedSearchService {
+    fn name(&self) -> &'static str {
+        "saved_searches"
+    }
+
+    async fn on_event(&self, event: &DomainEvent) -> Result<(), AppError> {
+        if let DomainEvent::CampaignApproved { campaign_id } = event {
+            self.saved_search_repo.alert_matches(*campaign_id).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::saved_search_repo::MockSavedSearchRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn request(keywords: Option<&str>, region: Option<&str>) -> SavedSearchRequest {
+        SavedSearchRequest {
+            keywords: keywords.map(String::from),
+            category: None,
+            region: region.map(String::from),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_trims_criteria() {
+        let mut mock_repo = MockSavedSearchRepository::new();
+        mock_repo.expect_count_by_user().returning(|_| Ok(0));
+        mock_repo
+            .expect_create()
+            .withf(|user_id, req| {
+                *user_id == 7
+                    && req.keywords.as_deref() == Some("banjir bandung")
+                    && req.region.is_none()
+            })
+            .times(1)
+            .returning(|user_id, req| {
+                Ok(SavedSearch {
+                    id: 1,
+                    user_id,
+                    keywords: req.keywords.clone(),
+                    category: None,
+                    region: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                })
+            });
+
+        let service = SavedSearchService::new(Arc::new(mock_repo));
+        let result = service
+            .create_saved_search(7, request(Some("  banjir   bandung "), Some("  ")))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_requires_a_criterion_and_respects_cap() {
+        let mut mock_repo = MockSavedSearchRepository::new();
+        mock_repo
+            .expect_count_by_user()
+            .returning(|_| Ok(MAX_SAVED_SEARCHES));
+        mock_repo.expect_create().times(0);
+
+        let service = SavedSearchService::new(Arc::new(mock_repo));
+
+        assert!(matches!(
+            service
+                .create_saved_search(7, request(None, Some(" ")))
+                .await,
+            Err(AppError::ValidationError(_))
+        ));
+        match service
+            .create_saved_search(7, request(Some("banjir"), None))
+            .await
+        {
+            Err(AppError::ValidationError(msg)) => assert!(msg.contains("at most 20")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_campaign_approval_alerts_matching_searches() {
+        let mut mock_repo = MockSavedSearchRepository::new();
+        mock_repo
+            .expect_alert_matches()
+            .with(eq(10))
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let service = Arc::new(SavedSearchService::new(Arc::new(mock_repo)));
+        let event_bus = service.subscribe_to(EventBus::new());
+
+        event_bus
+            .publish(&DomainEvent::CampaignApproved { campaign_id: 10 })
+            .await
+            .unwrap();
+    }
+}
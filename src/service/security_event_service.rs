@@ -0,0 +1,135 @@
+use crate::errors::AppError;
+use crate::model::security_event::SecurityEvent;
+use crate::repository::security_event_repo::SecurityEventRepository;
+use crate::service::commands::security_commands::RecordLoginCommand;
+use std::sync::Arc;
+
+pub const SECURITY_EVENTS_LIMIT: i64 = 100;
+
+pub struct SecurityEventService {
+    security_event_repo: Arc<dyn SecurityEventRepository>,
+}
+
+impl SecurityEventService {
+    pub fn new(security_event_repo: Arc<dyn SecurityEventRepository>) -> Self {
+        SecurityEventService {
+            security_event_repo,
+        }
+    }
+
+    /// Called by the login handler for every attempt. A successful login from a device
+    /// or country the user has not logged in from before enqueues `SuspiciousLogin`,
+    /// which the notification subscriber turns into a message to that user.
+    pub async fn record_login(&self, cmd: RecordLoginCommand) -> Result<SecurityEvent, AppError> {
+        let suspicious = if cmd.succeeded {
+            let history = self
+                .security_event_repo
+                .login_history(cmd.user_id, cmd.user_agent.clone(), cmd.country.clone())
+                .await?;
+            // The first login has nothing to compare against, and unknown values are
+            // not treated as new.
+            history.has_previous_logins
+                && ((cmd.user_agent.is_some() && !history.known_device)
+                    || (cmd.country.is_some() && !history.known_country))
+        } else {
+            false
+        };
+
+        self.security_event_repo
+            .record_login(&cmd, suspicious)
+            .await
+    }
+
+    pub async fn get_events(&self, user_id: i32) -> Result<Vec<SecurityEvent>, AppError> {
+        self.security_event_repo
+            .find_by_user(user_id, SECURITY_EVENTS_LIMIT)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::security_event::{LoginHistory, SecurityEventType};
+    use crate::repository::security_event_repo::MockSecurityEventRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn login(succeeded: bool) -> RecordLoginCommand {
+        RecordLoginCommand {
+            user_id: 1,
+            succeeded,
+            ip_address: Some("203.0.113.7".to_string()),
+            user_agent: Some("Firefox".to_string()),
+            country: Some("SG".to_string()),
+        }
+    }
+
+    fn recorded(cmd: &RecordLoginCommand, suspicious: bool) -> SecurityEvent {
+        SecurityEvent {
+            id: 1,
+            user_id: cmd.user_id,
+            event_type: if cmd.succeeded {
+                SecurityEventType::LoginSucceeded
+            } else {
+                SecurityEventType::LoginFailed
+            },
+            ip_address: cmd.ip_address.clone(),
+            user_agent: cmd.user_agent.clone(),
+            country: cmd.country.clone(),
+            suspicious,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service_with_history(
+        history: LoginHistory,
+        expect_suspicious: bool,
+    ) -> SecurityEventService {
+        let mut mock_security_event_repo = MockSecurityEventRepository::new();
+        mock_security_event_repo
+            .expect_login_history()
+            .returning(move |_, _, _| Ok(history));
+        mock_security_event_repo
+            .expect_record_login()
+            .withf(move |_, suspicious| *suspicious == expect_suspicious)
+            .times(1)
+            .returning(|cmd, suspicious| Ok(recorded(cmd, suspicious)));
+        SecurityEventService::new(Arc::new(mock_security_event_repo))
+    }
+
+    #[tokio::test]
+    async fn test_login_from_new_country_is_suspicious() {
+        let history = LoginHistory {
+            has_previous_logins: true,
+            known_device: true,
+            known_country: false,
+        };
+        let service = service_with_history(history, true);
+
+        let event = service.record_login(login(true)).await.unwrap();
+        assert!(event.suspicious);
+    }
+
+    #[tokio::test]
+    async fn test_first_login_and_failures_are_not_suspicious() {
+        let service = service_with_history(LoginHistory::default(), false);
+        assert!(!service.record_login(login(true)).await.unwrap().suspicious);
+
+        let service = service_with_history(LoginHistory::default(), false);
+        let event = service.record_login(login(false)).await.unwrap();
+        assert_eq!(event.event_type, SecurityEventType::LoginFailed);
+    }
+
+    #[tokio::test]
+    async fn test_get_events_is_limited() {
+        let mut mock_security_event_repo = MockSecurityEventRepository::new();
+        mock_security_event_repo
+            .expect_find_by_user()
+            .with(eq(1), eq(SECURITY_EVENTS_LIMIT))
+            .returning(|_, _| Ok(vec![]));
+        let service = SecurityEventService::new(Arc::new(mock_security_event_repo));
+
+        assert!(service.get_events(1).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,392 @@
+use crate::errors::AppError;
+use crate::model::campaign::Campaign;
+use crate::model::donation_import::ImportedDonationRow;
+use crate::model::event::DomainEvent;
+use crate::model::profile::UpdateProfileRequest;
+use crate::repository::donation_repo::DonationRepository;
+use crate::repository::profile_repo::ProfileRepository;
+use crate::repository::seed_repo::SeedRepository;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+const SEED_CHUNK_SIZE: usize = 500;
+const DEMO_MESSAGES: [&str; 4] = [
+    "Semangat!",
+    "Semoga cepat terkumpul",
+    "Get well soon",
+    "Untuk adik-adik di sana",
+];
+const DEMO_NAMES: [&str; 8] = [
+    "Budi Santoso",
+    "Siti Rahma",
+    "Andi Wijaya",
+    "Dewi Lestari",
+    "Rizky Pratama",
+    "Putri Ayu",
+    "Agus Setiawan",
+    "Nur Aini",
+];
+const DEMO_CAMPAIGNS: [(&str, &str); 6] = [
+    ("Bantu operasi jantung adik Raka", "health"),
+    ("Renovasi sekolah dasar di Lombok", "education"),
+    ("Air bersih untuk desa Sumba", "environment"),
+    ("Dapur umum korban banjir Demak", "disaster"),
+    ("Beasiswa anak nelayan", "education"),
+    ("Rumah singgah pasien kanker", "health"),
+];
+/// Every status a campaign can be in, so each screen has something to show.
+const DEMO_STATUSES: [&str; 6] = [
+    "draft",
+    "pending_review",
+    "active",
+    "suspended",
+    "rejected",
+    "completed",
+];
+const DEMO_WALLET_BALANCE: f64 = 5_000_000.0;
+
+#[derive(Debug, Clone)]
+pub struct SeedConfig {
+    /// Accounts live in the auth service; the seeder gives these ids a profile and a wallet.
+    pub user_ids: Vec<i32>,
+    pub campaigns_per_status: usize,
+    pub donations: usize,
+    pub days: i64,
+    pub seed: u64,
+}
+
+impl Default for SeedConfig {
+    fn default() -> Self {
+        SeedConfig {
+            user_ids: (1..=20).collect(),
+            campaigns_per_status: 2,
+            donations: 300,
+            days: 30,
+            seed: 42,
+        }
+    }
+}
+
+/// What one seeding run inserted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeedReport {
+    pub profiles: u64,
+    pub wallets: u64,
+    pub campaigns: u64,
+    pub donations: u64,
+    pub notifications: u64,
+}
+
+// Deterministic so every developer gets the same demo dataset for the same config.
+struct DemoRng(u64);
+
+impl DemoRng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+pub fn generate_demo_profiles(config: &SeedConfig) -> Vec<(i32, UpdateProfileRequest)> {
+    config
+        .user_ids
+        .iter()
+        .enumerate()
+        .map(|(i, &user_id)| {
+            let name = DEMO_NAMES[i % DEMO_NAMES.len()];
+            (
+                user_id,
+                UpdateProfileRequest {
+                    display_name: format!("{} {}", name, user_id),
+                    avatar_url: None,
+                    bio: Some(format!("Akun demo {}", name)),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Campaigns cycle through `DEMO_STATUSES`; their fundraisers are drawn from `user_ids`.
+pub fn generate_demo_campaigns(config: &SeedConfig, now: DateTime<Utc>) -> Vec<Campaign> {
+    let mut rng = DemoRng(config.seed.max(1));
+    (0..config.campaigns_per_status * DEMO_STATUSES.len())
+        .map(|i| {
+            let (title, category) = DEMO_CAMPAIGNS[i % DEMO_CAMPAIGNS.len()];
+            let status = DEMO_STATUSES[i % DEMO_STATUSES.len()];
+            let fundraiser_id = match config.user_ids.len() as u64 {
+                0 => None,
+                users => Some(config.user_ids[rng.below(users) as usize]),
+            };
+            // Targets between 5M and 100M rupiah; completed campaigns have already ended.
+            let target_amount = ((rng.below(20) + 1) * 5_000_000) as f64;
+            let ends_at = match status {
+                "completed" => now - Duration::days(rng.below(10) as i64 + 1),
+                _ => now + Duration::days(rng.below(60) as i64 + 7),
+            };
+            Campaign {
+                fundraiser_id,
+                title: title.to_string(),
+                description: Some(format!("Kampanye demo: {}", title)),
+                category: Some(category.to_string()),
+                target_amount,
+                status: status.to_string(),
+                ends_at: Some(ends_at),
+                ..Campaign::default()
+            }
+        })
+        .collect()
+}
+
+pub fn generate_demo_donations(
+    config: &SeedConfig,
+    campaign_ids: &[i32],
+    now: DateTime<Utc>,
+) -> Vec<ImportedDonationRow> {
+    if config.user_ids.is_empty() || campaign_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let mut rng = DemoRng(config.seed.max(1));
+    let window_minutes = (config.days.max(1) * 24 * 60) as u64;
+    (0..config.donations)
+        .map(|_| {
+            let user_id = config.user_ids[rng.below(config.user_ids.len() as u64) as usize];
+            let campaign_id = campaign_ids[rng.below(campaign_ids.len() as u64) as usize];
+            // Rupiah amounts between 10k and 1M, rounded to the nearest thousand.
+            let amount = ((rng.below(991) + 10) * 1000) as f64;
+            let message = match rng.below(8) as usize {
+                i if i < DEMO_MESSAGES.len() => Some(DEMO_MESSAGES[i].to_string()),
+                _ => None,
+            };
+            let created_at = now - Duration::minutes(rng.below(window_minutes) as i64);
+            ImportedDonationRow {
+                user_id,
+                campaign_id,
+                amount,
+                message,
+                created_at,
+            }
+        })
+        .collect()
+}
+
+/// The notifications a campaign in each status would have sent its fundraiser.
+pub fn demo_notifications(campaigns: &[Campaign]) -> Vec<DomainEvent> {
+    campaigns
+        .iter()
+        .filter_map(|campaign| {
+            let campaign_id = campaign.id;
+            match campaign.status.as_str() {
+                "active" => Some(DomainEvent::CampaignApproved { campaign_id }),
+                "completed" => Some(DomainEvent::CampaignCompleted { campaign_id }),
+                "rejected" => Some(DomainEvent::CampaignRejected {
+                    campaign_id,
+                    reason: Some("Dokumen pendukung belum lengkap".to_string()),
+                }),
+                "suspended" => Some(DomainEvent::CampaignSuspended {
+                    campaign_id,
+                    fundraiser_id: campaign.fundraiser_id,
+                    reason: "Menunggu verifikasi ulang".to_string(),
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+pub struct SeedService {
+    donation_repo: Arc<dyn DonationRepository>,
+    profile_repo: Arc<dyn ProfileRepository>,
+    seed_repo: Arc<dyn SeedRepository>,
+}
+
+impl SeedService {
+    pub fn new(
+        donation_repo: Arc<dyn DonationRepository>,
+        profile_repo: Arc<dyn ProfileRepository>,
+        seed_repo: Arc<dyn SeedRepository>,
+    ) -> Self {
+        SeedService {
+            donation_repo,
+            profile_repo,
+            seed_repo,
+        }
+    }
+
+    /// Profiles and wallets for the demo users, campaigns in every status, donations to
+    /// the ones taking them over the last `config.days`, and the matching notifications.
+    pub async fn seed(&self, config: &SeedConfig) -> Result<SeedReport, AppError> {
+        let now = Utc::now();
+        let mut report = SeedReport::default();
+
+        for (user_id, profile) in generate_demo_profiles(config) {
+            self.profile_repo.upsert(user_id, &profile).await?;
+            report.profiles += 1;
+        }
+        report.wallets = self
+            .seed_repo
+            .insert_wallets(config.user_ids.clone(), DEMO_WALLET_BALANCE)
+            .await?;
+
+        let mut campaigns = generate_demo_campaigns(config, now);
+        let ids = self.seed_repo.insert_campaigns(campaigns.clone()).await?;
+        for (campaign, id) in campaigns.iter_mut().zip(&ids) {
+            campaign.id = *id;
+        }
+        report.campaigns = ids.len() as u64;
+
+        let funded: Vec<i32> = campaigns
+            .iter()
+            .filter(|campaign| matches!(campaign.status.as_str(), "active" | "completed"))
+            .map(|campaign| campaign.id)
+            .collect();
+        report.donations = self.seed_donations(config, &funded, now).await?;
+        self.seed_repo.sync_collected_amounts(funded).await?;
+
+        report.notifications = self
+            .seed_repo
+            .enqueue_notifications(demo_notifications(&campaigns))
+            .await?;
+        Ok(report)
+    }
+
+    async fn seed_donations(
+        &self,
+        config: &SeedConfig,
+        campaign_ids: &[i32],
+        now: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let rows = generate_demo_donations(config, campaign_ids, now);
+        let mut inserted = 0;
+        for chunk in rows.chunks(SEED_CHUNK_SIZE) {
+            inserted += self.donation_repo.import_batch(chunk.to_vec()).await?;
+        }
+        Ok(inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::profile::Profile;
+    use crate::repository::donation_repo::MockDonationRepository;
+    use crate::repository::profile_repo::MockProfileRepository;
+    use crate::repository::seed_repo::MockSeedRepository;
+
+    const CAMPAIGN_IDS: [i32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    #[test]
+    fn test_generate_demo_donations_within_window() {
+        let now = Utc::now();
+        let config = SeedConfig::default();
+        let rows = generate_demo_donations(&config, &CAMPAIGN_IDS, now);
+
+        assert_eq!(rows.len(), 300);
+        for row in &rows {
+            assert!(config.user_ids.contains(&row.user_id));
+            assert!(CAMPAIGN_IDS.contains(&row.campaign_id));
+            assert!(row.amount >= 10_000.0 && row.amount <= 1_000_000.0);
+            assert!(row.created_at <= now && row.created_at > now - Duration::days(30));
+        }
+    }
+
+    #[test]
+    fn test_generate_demo_donations_is_deterministic() {
+        let now = Utc::now();
+        let config = SeedConfig::default();
+
+        assert_eq!(
+            generate_demo_donations(&config, &CAMPAIGN_IDS, now),
+            generate_demo_donations(&config, &CAMPAIGN_IDS, now)
+        );
+    }
+
+    #[test]
+    fn test_generate_demo_campaigns_covers_every_status() {
+        let config = SeedConfig::default();
+        let campaigns = generate_demo_campaigns(&config, Utc::now());
+
+        assert_eq!(campaigns.len(), 12);
+        for status in DEMO_STATUSES {
+            let count = campaigns
+                .iter()
+                .filter(|campaign| campaign.status == status)
+                .count();
+            assert_eq!(count, 2, "{}", status);
+        }
+        assert!(campaigns.iter().all(|campaign| {
+            campaign
+                .fundraiser_id
+                .is_some_and(|id| config.user_ids.contains(&id))
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_seed_funds_only_campaigns_taking_donations() {
+        let mut mock_profile_repo = MockProfileRepository::new();
+        mock_profile_repo
+            .expect_upsert()
+            .times(20)
+            .returning(|user_id, profile| {
+                Ok(Profile {
+                    user_id,
+                    display_name: profile.display_name.clone(),
+                    avatar_url: None,
+                    bio: profile.bio.clone(),
+                    updated_at: Utc::now(),
+                })
+            });
+        let mut mock_seed_repo = MockSeedRepository::new();
+        mock_seed_repo
+            .expect_insert_wallets()
+            .returning(|user_ids, _| Ok(user_ids.len() as u64));
+        mock_seed_repo
+            .expect_insert_campaigns()
+            .returning(|campaigns| Ok((1..=campaigns.len() as i32).collect()));
+        // Statuses cycle draft, pending_review, active, suspended, rejected, completed.
+        mock_seed_repo
+            .expect_sync_collected_amounts()
+            .withf(|ids| ids == &vec![3, 6, 9, 12])
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_seed_repo
+            .expect_enqueue_notifications()
+            .returning(|events| Ok(events.len() as u64));
+        let mut mock_donation_repo = MockDonationRepository::new();
+        mock_donation_repo
+            .expect_import_batch()
+            .times(3)
+            .withf(|rows| {
+                rows.iter()
+                    .all(|row| [3, 6, 9, 12].contains(&row.campaign_id))
+            })
+            .returning(|rows| Ok(rows.len() as u64));
+
+        let service = SeedService::new(
+            Arc::new(mock_donation_repo),
+            Arc::new(mock_profile_repo),
+            Arc::new(mock_seed_repo),
+        );
+        let config = SeedConfig {
+            donations: 1200,
+            ..SeedConfig::default()
+        };
+
+        assert_eq!(
+            service.seed(&config).await.unwrap(),
+            SeedReport {
+                profiles: 20,
+                wallets: 20,
+                campaigns: 12,
+                donations: 1200,
+                notifications: 8,
+            }
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::config::SharingConfig;
+use crate::errors::AppError;
+use crate::model::campaign_member::CampaignAction;
+use crate::model::short_link::{NewShortLinkRequest, ShortLink, ShortLinkResponse};
+use crate::repository::campaign_repo::CampaignRepository;
+use crate::repository::short_link_repo::ShortLinkRepository;
+use crate::service::campaign_member_service::CampaignMemberService;
+use image::{ImageFormat, Luma};
+use qrcode::QrCode;
+use rand::Rng;
+use rocket::http::RawStr;
+use std::io::Cursor;
+use std::sync::Arc;
+
+const SLUG_ALPHABET: &[u8] = b"abcdefghijkmnpqrstuvwxyz23456789";
+const SLUG_LENGTH: usize = 7;
+const SLUG_ATTEMPTS: usize = 5;
+// Large enough to print on an A4 poster without visible pixelation.
+const QR_MIN_SIZE: u32 = 512;
+
+// Lowercase without 0/o/1/l so slugs survive being typed off a printed poster.
+fn generate_slug() -> String {
+    let mut rng = rand::thread_rng();
+    (0..SLUG_LENGTH)
+        .map(|_| SLUG_ALPHABET[rng.gen_range(0..SLUG_ALPHABET.len())] as char)
+        .collect()
+}
+
+/// The frontend page a campaign is shared as.
+pub fn campaign_share_url(sharing: &SharingConfig, campaign_id: i32) -> String {
+    format!("{}/campaigns/{}", sharing.public_base_url, campaign_id)
+}
+
+fn render_qr_png(content: &str) -> Result<Vec<u8>, AppError> {
+    let code = QrCode::new(content.as_bytes())
+        .map_err(|e| AppError::InternalServerError(format!("QR encoding failed: {}", e)))?;
+    let image = code
+        .render::<Luma<u8>>()
+        .min_dimensions(QR_MIN_SIZE, QR_MIN_SIZE)
+        .build();
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| AppError::InternalServerError(format!("QR rendering failed: {}", e)))?;
+    Ok(png)
+}
+
+pub struct ShortLinkService {
+    short_link_repo: Arc<dyn ShortLinkRepository>,
+    campaign_repo: Arc<dyn CampaignRepository>,
+    member_service: Arc<CampaignMemberService>,
+    sharing: SharingConfig,
+}
+
+impl ShortLinkService {
+    pub fn new(
+        short_link_repo: Arc<dyn ShortLinkRepository>,
+        campaign_repo: Arc<dyn CampaignRepository>,
+        member_service: Arc<CampaignMemberService>,
+        sharing: SharingConfig,
+    ) -> Self {
+        ShortLinkService {
+            short_link_repo,
+            campaign_repo,
+            member_service,
+            sharing,
+        }
+    }
+
+    pub fn campaign_url(&self, campaign_id: i32) -> String {
+        campaign_share_url(&self.sharing, campaign_id)
+    }
+
+    fn short_url(&self, slug: &str) -> String {
+        format!("{}/c/{}", self.sharing.short_link_base_url, slug)
+    }
+
+    fn respond(&self, link: ShortLink) -> ShortLinkResponse {
+        ShortLinkResponse {
+            url: self.short_url(&link.slug),
+            link,
+        }
+    }
+
+    pub async fn create_link(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+        req: NewShortLinkRequest,
+    ) -> Result<ShortLinkResponse, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::UpdateCampaign)
+            .await?;
+        let channel = req
+            .channel
+            .map(|channel| channel.trim().to_lowercase())
+            .filter(|channel| !channel.is_empty());
+
+        for _ in 0..SLUG_ATTEMPTS {
+            let created = self
+                .short_link_repo
+                .create(&generate_slug(), campaign_id, channel.clone(), user_id)
+                .await?;
+            if let Some(link) = created {
+                return Ok(self.respond(link));
+            }
+        }
+        Err(AppError::InternalServerError(
+            "Could not allocate a unique short link".to_string(),
+        ))
+    }
+
+    pub async fn list_links(
+        &self,
+        campaign_id: i32,
+        user_id: i32,
+    ) -> Result<Vec<ShortLinkResponse>, AppError> {
+        self.member_service
+            .authorize(campaign_id, user_id, CampaignAction::View)
+            .await?;
+        let links = self.short_link_repo.find_by_campaign(campaign_id).await?;
+        Ok(links.into_iter().map(|link| self.respond(link)).collect())
+    }
+
+    /// Counts the click and returns where to send the visitor.
+    pub async fn follow(&self, slug: &str) -> Result<String, AppError> {
+        let link = self
+            .short_link_repo
+            .record_click(slug)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Short link not found".to_string()))?;
+        let mut target = self.campaign_url(link.campaign_id);
+        if let Some(channel) = &link.channel {
+            target.push_str(&format!(
+                "?utm_source={}",
+                RawStr::new(channel).percent_encode()
+            ));
+        }
+        Ok(target)
+    }
+
+    /// PNG QR code for the campaign page, or for one of its short links so scans are counted.
+    pub async fn campaign_qr(
+        &self,
+        campaign_id: i32,
+        slug: Option<&str>,
+    ) -> Result<Vec<u8>, AppError> {
+        let url = match slug {
+            Some(slug) => {
+                let link = self
+                    .short_link_repo
+                    .find_by_slug(slug)
+                    .await?
+                    .filter(|link| link.campaign_id == campaign_id)
+                    .ok_or_else(|| AppError::NotFound("Short link not found".to_string()))?;
+                self.short_url(&link.slug)
+            }
+            None => {
+                self.campaign_repo
+                    .find_by_id(campaign_id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Campaign not found".to_string()))?;
+                self.campaign_url(campaign_id)
+            }
+        };
+        render_qr_png(&url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::campaign_member::CampaignRole;
+    use crate::repository::campaign_member_repo::MockCampaignMemberRepository;
+    use crate::repository::campaign_repo::MockCampaignRepository;
+    use crate::repository::short_link_repo::MockShortLinkRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn link(slug: &str, channel: Option<&str>) -> ShortLink {
+        ShortLink {
+            id: 1,
+            slug: slug.to_string(),
+            campaign_id: 10,
+            channel: channel.map(str::to_string),
+            click_count: 1,
+            created_by: 2,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(mock_short_link_repo: MockShortLinkRepository) -> ShortLinkService {
+        let mut mock_member_repo = MockCampaignMemberRepository::new();
+        mock_member_repo
+            .expect_find_role()
+            .with(eq(10), eq(2))
+            .returning(|_, _| Ok(Some(CampaignRole::Owner)));
+        ShortLinkService::new(
+            Arc::new(mock_short_link_repo),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(CampaignMemberService::new(Arc::new(mock_member_repo))),
+            SharingConfig {
+                public_base_url: "https://donasi.example.com".to_string(),
+                short_link_base_url: "https://api.example.com".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_generated_slugs_use_unambiguous_alphabet() {
+        let slug = generate_slug();
+        assert_eq!(slug.len(), SLUG_LENGTH);
+        assert!(slug.bytes().all(|b| SLUG_ALPHABET.contains(&b)));
+    }
+
+    #[test]
+    fn test_qr_is_png() {
+        let png = render_qr_png("https://donasi.example.com/campaigns/10").unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[tokio::test]
+    async fn test_create_link_retries_slug_collisions() {
+        let mut mock_short_link_repo = MockShortLinkRepository::new();
+        let mut attempts = 0;
+        mock_short_link_repo
+            .expect_create()
+            .withf(|_, campaign_id, channel, created_by| {
+                *campaign_id == 10 && channel.as_deref() == Some("instagram") && *created_by == 2
+            })
+            .times(2)
+            .returning(move |slug, _, _, _| {
+                attempts += 1;
+                Ok((attempts > 1).then(|| link(slug, Some("instagram"))))
+            });
+        let service = service(mock_short_link_repo);
+
+        let created = service
+            .create_link(
+                10,
+                2,
+                NewShortLinkRequest {
+                    channel: Some(" Instagram ".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            created.url,
+            format!("https://api.example.com/c/{}", created.link.slug)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_follow_tags_channel() {
+        let mut mock_short_link_repo = MockShortLinkRepository::new();
+        mock_short_link_repo
+            .expect_record_click()
+            .with(eq("abc"))
+            .returning(|slug| Ok(Some(link(slug, Some("poster a4")))));
+        mock_short_link_repo
+            .expect_record_click()
+            .with(eq("nope"))
+            .returning(|_| Ok(None));
+        let service = service(mock_short_link_repo);
+
+        assert_eq!(
+            service.follow("abc").await.unwrap(),
+            "https://donasi.example.com/campaigns/10?utm_source=poster%20a4"
+        );
+        assert!(matches!(
+            service.follow("nope").await.err().unwrap(),
+            AppError::NotFound(_)
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::errors::AppError;
+use crate::model::statistic::PublicStats;
+use crate::repository::statistic_repo::StatisticRepository;
+use rocket::tokio::sync::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub const PUBLIC_STATS_TTL: Duration = Duration::from_secs(60);
+
+pub struct StatisticService {
+    statistic_repo: Arc<dyn StatisticRepository>,
+    cache_ttl: Duration,
+    // An async mutex rather than a RwLock: when the entry expires, the first caller
+    // refreshes it while the others wait for that result instead of each querying.
+    public_stats: Mutex<Option<(Instant, PublicStats)>>,
+}
+
+impl StatisticService {
+    pub fn new(statistic_repo: Arc<dyn StatisticRepository>) -> Self {
+        StatisticService {
+            statistic_repo,
+            cache_ttl: PUBLIC_STATS_TTL,
+            public_stats: Mutex::new(None),
+        }
+    }
+
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        self.cache_ttl
+    }
+
+    pub async fn get_public_stats(&self) -> Result<PublicStats, AppError> {
+        let mut cached = self.public_stats.lock().await;
+        if let Some((cached_at, stats)) = cached.as_ref()
+            && cached_at.elapsed() < self.cache_ttl {
+                return Ok(stats.clone());
+            }
+
+        let stats = self.statistic_repo.public_stats().await?;
+        *cached = Some((Instant::now(), stats.clone()));
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::statistic_repo::MockStatisticRepository;
+    use chrono::Utc;
+
+    fn stats() -> PublicStats {
+        PublicStats {
+            total_raised: 1_500_000.0,
+            campaigns_funded: 3,
+            donor_count: 42,
+            computed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_public_stats_are_cached_within_ttl() {
+        let mut mock_statistic_repo = MockStatisticRepository::new();
+        mock_statistic_repo
+            .expect_public_stats()
+            .times(1)
+            .returning(|| Ok(stats()));
+        let service = Arc::new(StatisticService::new(Arc::new(mock_statistic_repo)));
+
+        let readers: Vec<_> = (0..8)
+            .map(|_| {
+                let service = service.clone();
+                rocket::tokio::spawn(async move { service.get_public_stats().await })
+            })
+            .collect();
+        for reader in readers {
+            assert_eq!(reader.await.unwrap().unwrap().donor_count, 42);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_public_stats_refresh_after_ttl() {
+        let mut mock_statistic_repo = MockStatisticRepository::new();
+        mock_statistic_repo
+            .expect_public_stats()
+            .times(2)
+            .returning(|| Ok(stats()));
+        let service =
+            StatisticService::new(Arc::new(mock_statistic_repo)).with_cache_ttl(Duration::ZERO);
+
+        service.get_public_stats().await.unwrap();
+        service.get_public_stats().await.unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use crate::errors::AppError;
+use crate::model::statistics_snapshot::{StatisticHistory, StatisticMetric, StatisticRange};
+use crate::repository::statistics_snapshot_repo::StatisticsSnapshotRepository;
+use crate::service::background_job::run_job;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use std::sync::Arc;
+
+/// 17:00 UTC is midnight WIB, so each snapshot closes out an Indonesian calendar day.
+pub const DEFAULT_SNAPSHOT_TIME: NaiveTime = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+
+/// Time left until the next `run_at` (UTC), never zero so the loop cannot spin.
+fn until_next_run(now: DateTime<Utc>, run_at: NaiveTime) -> std::time::Duration {
+    let today = now.date_naive().and_time(run_at).and_utc();
+    let next = if today > now {
+        today
+    } else {
+        today + Duration::days(1)
+    };
+    (next - now)
+        .to_std()
+        .unwrap_or(std::time::Duration::from_secs(1))
+}
+
+pub struct StatisticsSnapshotService {
+    snapshot_repo: Arc<dyn StatisticsSnapshotRepository>,
+    run_at: NaiveTime,
+}
+
+impl StatisticsSnapshotService {
+    pub fn new(snapshot_repo: Arc<dyn StatisticsSnapshotRepository>) -> Self {
+        StatisticsSnapshotService {
+            snapshot_repo,
+            run_at: DEFAULT_SNAPSHOT_TIME,
+        }
+    }
+
+    pub fn with_run_at(mut self, run_at: NaiveTime) -> Self {
+        self.run_at = run_at;
+        self
+    }
+
+    pub fn spawn(self: Arc<Self>) {
+        rocket::tokio::spawn(async move {
+            loop {
+                rocket::tokio::time::sleep(until_next_run(Utc::now(), self.run_at)).await;
+                run_job("statistics_snapshot", self.capture()).await;
+            }
+        });
+    }
+
+    pub async fn capture(&self) -> Result<u64, AppError> {
+        self.snapshot_repo.capture(Utc::now().date_naive()).await
+    }
+
+    pub async fn get_history(
+        &self,
+        metric: StatisticMetric,
+        range: StatisticRange,
+    ) -> Result<StatisticHistory, AppError> {
+        let points = self
+            .snapshot_repo
+            .find_history(metric, range.since(Utc::now()))
+            .await?;
+        Ok(StatisticHistory {
+            metric,
+            range,
+            points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    #[test]
+    fn test_until_next_run_rolls_over_to_tomorrow() {
+        let run_at = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        let before = Utc.with_ymd_and_hms(2024, 5, 1, 16, 30, 0).unwrap();
+        assert_eq!(
+            until_next_run(before, run_at),
+            std::time::Duration::from_secs(30 * 60)
+        );
+        let at = Utc.with_ymd_and_hms(2024, 5, 1, 17, 0, 0).unwrap();
+        assert_eq!(
+            until_next_run(at, run_at),
+            std::time::Duration::from_secs(24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn test_range_since() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 31, 12, 0, 0).unwrap();
+        assert_eq!(
+            StatisticRange::Days30.since(now),
+            NaiveDate::from_ymd_opt(2024, 5, 1)
+        );
+        assert_eq!(
+            StatisticRange::Year.since(now),
+            NaiveDate::from_ymd_opt(2023, 6, 1)
+        );
+        assert_eq!(StatisticRange::All.since(now), None);
+    }
+}
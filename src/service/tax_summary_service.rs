@@ -0,0 +1,204 @@
+use crate::errors::AppError;
+use crate::locale::Locale;
+use crate::model::tax_summary::{
+    EligibleDonation, TaxDeductibleCampaign, TaxReceiptLine, TaxSummary, TaxSummaryCampaign,
+};
+use crate::money::format_amount;
+use crate::pdf::render_text_pdf;
+use crate::repository::tax_summary_repo::TaxSummaryRepository;
+use chrono::{Datelike, Utc};
+use std::sync::Arc;
+
+// Only guards against typos; the platform holds no donations from before then.
+const FIRST_TAX_YEAR: i32 = 2000;
+
+fn group_by_campaign(donations: Vec<EligibleDonation>) -> Vec<TaxSummaryCampaign> {
+    let mut campaigns: Vec<TaxSummaryCampaign> = Vec::new();
+    for donation in donations {
+        let line = TaxReceiptLine {
+            donation_id: donation.donation_id,
+            receipt_number: donation.receipt_number,
+            amount: donation.amount,
+            issued_at: donation.issued_at,
+        };
+        // Rows arrive ordered by campaign, so a new campaign always starts a new group.
+        match campaigns.last_mut() {
+            Some(campaign) if campaign.campaign_id == donation.campaign_id => {
+                campaign.total_amount += line.amount;
+                campaign.receipts.push(line);
+            }
+            _ => campaigns.push(TaxSummaryCampaign {
+                campaign_id: donation.campaign_id,
+                campaign_title: donation.campaign_title,
+                total_amount: line.amount,
+                receipts: vec![line],
+            }),
+        }
+    }
+    campaigns
+}
+
+fn summary_lines(summary: &TaxSummary, locale: Locale) -> (String, Vec<String>) {
+    let (title, empty) = match locale {
+        Locale::Id => (
+            format!("Ringkasan Donasi Pengurang Pajak {}", summary.year),
+            "Tidak ada donasi yang dapat dikurangkan pada tahun ini.",
+        ),
+        Locale::En => (
+            format!("Tax-Deductible Donation Summary {}", summary.year),
+            "No tax-deductible donations this year.",
+        ),
+    };
+
+    let mut lines = Vec::new();
+    if summary.campaigns.is_empty() {
+        lines.push(empty.to_string());
+    }
+    for campaign in &summary.campaigns {
+        lines.push(format!(
+            "{}: {}",
+            campaign.campaign_title,
+            format_amount(campaign.total_amount, locale)
+        ));
+        for receipt in &campaign.receipts {
+            lines.push(format!(
+                "    {}    {}    {}",
+                receipt.receipt_number,
+                receipt.issued_at.format("%Y-%m-%d"),
+                format_amount(receipt.amount, locale)
+            ));
+        }
+        lines.push(String::new());
+    }
+    lines.push(format!(
+        "Total: {}",
+        format_amount(summary.total_amount, locale)
+    ));
+    (title, lines)
+}
+
+pub struct TaxSummaryService {
+    tax_repo: Arc<dyn TaxSummaryRepository>,
+}
+
+impl TaxSummaryService {
+    pub fn new(tax_repo: Arc<dyn TaxSummaryRepository>) -> Self {
+        TaxSummaryService { tax_repo }
+    }
+
+    pub async fn get_summary(&self, user_id: i32, year: i32) -> Result<TaxSummary, AppError> {
+        if !(FIRST_TAX_YEAR..=Utc::now().year()).contains(&year) {
+            return Err(AppError::ValidationError(
+                "year must be between 2000 and the current year".to_string(),
+            ));
+        }
+
+        let donations = self.tax_repo.find_eligible_donations(user_id, year).await?;
+        let campaigns = group_by_campaign(donations);
+        Ok(TaxSummary {
+            user_id,
+            year,
+            total_amount: campaigns.iter().map(|campaign| campaign.total_amount).sum(),
+            campaigns,
+        })
+    }
+
+    /// The same summary as a printable PDF, in the donor's language (Indonesian by default).
+    pub async fn get_summary_pdf(
+        &self,
+        user_id: i32,
+        year: i32,
+        locale: Option<Locale>,
+    ) -> Result<Vec<u8>, AppError> {
+        let summary = self.get_summary(user_id, year).await?;
+        let (title, lines) = summary_lines(&summary, locale.unwrap_or(Locale::Id));
+        Ok(render_text_pdf(&title, &lines))
+    }
+
+    pub async fn list_deductible_campaigns(&self) -> Result<Vec<TaxDeductibleCampaign>, AppError> {
+        self.tax_repo.find_deductible().await
+    }
+
+    pub async fn mark_deductible(&self, campaign_id: i32, admin_id: i32) -> Result<(), AppError> {
+        if !self.tax_repo.mark_deductible(campaign_id, admin_id).await? {
+            return Err(AppError::NotFound("Campaign not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Summaries are computed on request, so past donations stop counting as well.
+    pub async fn unmark_deductible(&self, campaign_id: i32) -> Result<(), AppError> {
+        if !self.tax_repo.unmark_deductible(campaign_id).await? {
+            return Err(AppError::NotFound(
+                "Campaign is not marked tax-deductible".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::tax_summary_repo::MockTaxSummaryRepository;
+    use chrono::TimeZone;
+    use mockall::predicate::eq;
+
+    fn eligible(campaign_id: i32, title: &str, number: i32, amount: f64) -> EligibleDonation {
+        EligibleDonation {
+            campaign_id,
+            campaign_title: title.to_string(),
+            donation_id: number,
+            receipt_number: format!("KWT/2024/{:06}", number),
+            amount,
+            issued_at: Utc
+                .with_ymd_and_hms(2024, 3, number as u32, 0, 0, 0)
+                .unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summary_groups_receipts_by_campaign() {
+        let mut mock_tax_repo = MockTaxSummaryRepository::new();
+        mock_tax_repo
+            .expect_find_eligible_donations()
+            .with(eq(1), eq(2024))
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![
+                    eligible(11, "Beasiswa", 3, 75_000.0),
+                    eligible(10, "Sumur desa", 1, 50_000.0),
+                    eligible(10, "Sumur desa", 2, 25_000.0),
+                ])
+            });
+        let service = TaxSummaryService::new(Arc::new(mock_tax_repo));
+
+        let summary = service.get_summary(1, 2024).await.unwrap();
+        assert_eq!(summary.total_amount, 150_000.0);
+        let totals: Vec<(i32, f64, usize)> = summary
+            .campaigns
+            .iter()
+            .map(|c| (c.campaign_id, c.total_amount, c.receipts.len()))
+            .collect();
+        assert_eq!(totals, vec![(11, 75_000.0, 1), (10, 75_000.0, 2)]);
+
+        let (title, lines) = summary_lines(&summary, Locale::Id);
+        assert_eq!(title, "Ringkasan Donasi Pengurang Pajak 2024");
+        assert_eq!(lines[0], "Beasiswa: Rp 75.000");
+        assert_eq!(lines[1], "    KWT/2024/000003    2024-03-03    Rp 75.000");
+        assert_eq!(lines.last().unwrap(), "Total: Rp 150.000");
+    }
+
+    #[tokio::test]
+    async fn test_summary_rejects_future_year() {
+        let service = TaxSummaryService::new(Arc::new(MockTaxSummaryRepository::new()));
+
+        let result = service.get_summary(1, Utc::now().year() + 1).await;
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => {
+                assert_eq!(msg, "year must be between 2000 and the current year")
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+}
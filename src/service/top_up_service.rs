@@ -0,0 +1,246 @@
+use crate::config::PaymentProviderConfig;
+use crate::errors::AppError;
+use crate::model::risk::{RiskActivity, RiskDecision};
+use crate::model::transaction::{TopUpNotification, Transaction};
+use crate::repository::wallet_repo::WalletRepository;
+use crate::service::commands::risk_commands::EvaluateRiskCommand;
+use crate::service::risk_service::RiskService;
+use crate::validation::validate;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+
+/// Hex HMAC-SHA256 of the raw notification body, keyed with the provider's secret.
+pub const TOP_UP_SIGNATURE_HEADER: &str = "X-Signature";
+
+// Payment providers call back here once a wallet top-up is paid. Each one is
+// written to the `transactions` ledger, which the nightly reconciliation matches
+// against the provider's settlement report.
+pub struct TopUpService {
+    wallet_repo: Arc<dyn WalletRepository>,
+    providers: Vec<PaymentProviderConfig>,
+    risk_service: Option<Arc<RiskService>>,
+}
+
+impl TopUpService {
+    pub fn new(
+        wallet_repo: Arc<dyn WalletRepository>,
+        providers: Vec<PaymentProviderConfig>,
+    ) -> Self {
+        TopUpService {
+            wallet_repo,
+            providers,
+            risk_service: None,
+        }
+    }
+
+    pub fn with_risk_service(mut self, risk_service: Arc<RiskService>) -> Self {
+        self.risk_service = Some(risk_service);
+        self
+    }
+
+    pub async fn record_top_up(
+        &self,
+        provider: &str,
+        signature: Option<&str>,
+        body: &str,
+    ) -> Result<Transaction, AppError> {
+        let provider = self
+            .providers
+            .iter()
+            .find(|candidate| candidate.enabled && candidate.name == provider)
+            .ok_or_else(|| AppError::NotFound("Payment provider not found".to_string()))?;
+        let secret_key = provider.secret_key.as_deref().unwrap_or_default();
+        verify_signature(secret_key, signature, body)?;
+
+        let notification: TopUpNotification =
+            rocket::serde::json::from_str(body).map_err(|_| {
+                AppError::ValidationError("Top-up notification is not valid JSON".to_string())
+            })?;
+        validate(&notification)?;
+
+        // The notification comes from the provider, so there is no donor IP to check.
+        // A flag is recorded by the evaluation itself; only a block stops the credit.
+        if let Some(risk_service) = &self.risk_service
+            && let RiskDecision::Block(reason) = risk_service
+                .evaluate(EvaluateRiskCommand {
+                    user_id: notification.user_id,
+                    ip_address: None,
+                    activity: RiskActivity::TopUp,
+                    campaign_id: None,
+                    amount: notification.amount,
+                })
+                .await?
+        {
+            return Err(AppError::Forbidden(reason));
+        }
+
+        self.wallet_repo
+            .top_up(
+                notification.user_id,
+                notification.amount,
+                &provider.name,
+                &notification.reference,
+            )
+            .await
+    }
+}
+
+fn verify_signature(secret_key: &str, signature: Option<&str>, body: &str) -> Result<(), AppError> {
+    let signature = signature
+        .and_then(|signature| hex::decode(signature).ok())
+        .ok_or(AppError::Unauthorized)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(body.as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| AppError::Unauthorized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::risk::{
+        FlaggedActivity, RiskAction, RiskRule, RiskRuleKind,
+    };
+    use crate::model::transaction::TransactionType;
+    use crate::repository::risk_repo::MockRiskRepository;
+    use crate::repository::wallet_repo::MockWalletRepository;
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    const BODY: &str = r#"{"user_id":7,"amount":250000.0,"reference":"pay-1"}"#;
+
+    fn providers() -> Vec<PaymentProviderConfig> {
+        vec![
+            PaymentProviderConfig {
+                name: "midtrans".to_string(),
+                secret_key: Some("midtrans-secret".to_string()),
+                ..PaymentProviderConfig::default()
+            },
+            PaymentProviderConfig {
+                name: "xendit".to_string(),
+                secret_key: Some("xendit-secret".to_string()),
+                enabled: false,
+                ..PaymentProviderConfig::default()
+            },
+        ]
+    }
+
+    fn sign(secret_key: &str, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes()).unwrap();
+        mac.update(body.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[tokio::test]
+    async fn test_record_top_up_credits_signed_notification() {
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo
+            .expect_top_up()
+            .with(eq(7), eq(250_000.0), eq("midtrans"), eq("pay-1"))
+            .times(1)
+            .returning(|user_id, amount, _, _| {
+                Ok(Transaction {
+                    id: 41,
+                    user_id,
+                    campaign_id: None,
+                    transaction_type: TransactionType::TopUp,
+                    amount,
+                    created_at: Utc::now(),
+                })
+            });
+        let service = TopUpService::new(Arc::new(mock_wallet_repo), providers());
+
+        let signature = sign("midtrans-secret", BODY);
+        let recorded = service
+            .record_top_up("midtrans", Some(&signature), BODY)
+            .await
+            .unwrap();
+
+        assert_eq!(recorded.id, 41);
+    }
+
+    #[tokio::test]
+    async fn test_record_top_up_rejects_bad_signature_and_disabled_provider() {
+        let service = TopUpService::new(Arc::new(MockWalletRepository::new()), providers());
+
+        let forged = sign("wrong-secret", BODY);
+        assert!(matches!(
+            service.record_top_up("midtrans", Some(&forged), BODY).await,
+            Err(AppError::Unauthorized)
+        ));
+        assert!(matches!(
+            service.record_top_up("midtrans", None, BODY).await,
+            Err(AppError::Unauthorized)
+        ));
+        let signature = sign("xendit-secret", BODY);
+        assert!(matches!(
+            service
+                .record_top_up("xendit", Some(&signature), BODY)
+                .await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_record_top_up_validates_the_notification() {
+        let service = TopUpService::new(Arc::new(MockWalletRepository::new()), providers());
+        let body = r#"{"user_id":7,"amount":-5.0,"reference":"pay-2"}"#;
+
+        let signature = sign("midtrans-secret", body);
+        let result = service
+            .record_top_up("midtrans", Some(&signature), body)
+            .await;
+
+        assert!(matches!(result, Err(AppError::UnprocessableEntity(_))));
+    }
+
+    #[tokio::test]
+    async fn test_record_top_up_stops_when_risk_rules_block_it() {
+        let mut mock_risk_repo = MockRiskRepository::new();
+        mock_risk_repo
+            .expect_is_blacklisted()
+            .with(eq(7), eq(None))
+            .returning(|_, _| Ok(false));
+        mock_risk_repo.expect_find_enabled_rules().returning(|| {
+            Ok(vec![RiskRule {
+                id: 3,
+                kind: RiskRuleKind::DailyAmountCap,
+                threshold: 100_000.0,
+                action: RiskAction::Block,
+                enabled: true,
+                created_at: Utc::now(),
+            }])
+        });
+        mock_risk_repo
+            .expect_sum_amount_since()
+            .withf(|user_id, activity, _| *user_id == 7 && *activity == RiskActivity::TopUp)
+            .returning(|_, _, _| Ok(0.0));
+        mock_risk_repo.expect_record_flag().times(1).returning(
+            |user_id, rule_id, activity, amount, action, reason| {
+                Ok(FlaggedActivity {
+                    id: 1,
+                    user_id,
+                    rule_id,
+                    activity,
+                    amount,
+                    action,
+                    reason,
+                    created_at: Utc::now(),
+                })
+            },
+        );
+        let mut mock_wallet_repo = MockWalletRepository::new();
+        mock_wallet_repo.expect_top_up().times(0);
+        let service = TopUpService::new(Arc::new(mock_wallet_repo), providers())
+            .with_risk_service(Arc::new(RiskService::new(Arc::new(mock_risk_repo))));
+
+        let signature = sign("midtrans-secret", BODY);
+        let result = service
+            .record_top_up("midtrans", Some(&signature), BODY)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+}
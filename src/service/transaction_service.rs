@@ -0,0 +1,185 @@
+use crate::errors::AppError;
+use crate::model::transaction::{TransactionFilter, TransactionPage};
+use crate::repository::transaction_repo::TransactionRepository;
+use std::sync::Arc;
+
+pub const DEFAULT_PER_PAGE: i64 = 50;
+pub const MAX_PER_PAGE: i64 = 200;
+const EXPORT_BATCH_SIZE: i64 = 1000;
+
+pub struct TransactionService {
+    transaction_repo: Arc<dyn TransactionRepository>,
+}
+
+impl TransactionService {
+    pub fn new(transaction_repo: Arc<dyn TransactionRepository>) -> Self {
+        TransactionService { transaction_repo }
+    }
+
+    pub async fn list(
+        &self,
+        filter: TransactionFilter,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<TransactionPage, AppError> {
+        validate_filter(&filter)?;
+        let page = page.unwrap_or(1).max(1);
+        let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
+
+        let total = self.transaction_repo.count(&filter).await?;
+        let items = self
+            .transaction_repo
+            .find(&filter, per_page, (page - 1) * per_page)
+            .await?;
+
+        Ok(TransactionPage {
+            items,
+            total,
+            page,
+            per_page,
+        })
+    }
+
+    pub async fn export_csv(&self, filter: TransactionFilter) -> Result<String, AppError> {
+        validate_filter(&filter)?;
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record([
+                "id",
+                "user_id",
+                "campaign_id",
+                "transaction_type",
+                "amount",
+                "created_at",
+            ])
+            .map_err(|e| AppError::InternalServerError(e.to_string()))?;
+
+        let mut offset = 0;
+        loop {
+            let batch = self
+                .transaction_repo
+                .find(&filter, EXPORT_BATCH_SIZE, offset)
+                .await?;
+            for transaction in &batch {
+                writer
+                    .serialize((
+                        transaction.id,
+                        transaction.user_id,
+                        transaction.campaign_id,
+                        transaction.transaction_type,
+                        transaction.amount,
+                        transaction.created_at.to_rfc3339(),
+                    ))
+                    .map_err(|e| AppError::InternalServerError(e.to_string()))?;
+            }
+            if (batch.len() as i64) < EXPORT_BATCH_SIZE {
+                break;
+            }
+            offset += EXPORT_BATCH_SIZE;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| AppError::InternalServerError(e.to_string()))?;
+        String::from_utf8(bytes).map_err(|e| AppError::InternalServerError(e.to_string()))
+    }
+}
+
+fn validate_filter(filter: &TransactionFilter) -> Result<(), AppError> {
+    if let (Some(min), Some(max)) = (filter.min_amount, filter.max_amount)
+        && min > max {
+            return Err(AppError::ValidationError(
+                "min_amount cannot exceed max_amount".to_string(),
+            ));
+        }
+    if let (Some(from), Some(to)) = (filter.from, filter.to)
+        && from > to {
+            return Err(AppError::ValidationError(
+                "from date cannot be after to date".to_string(),
+            ));
+        }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transaction::{Transaction, TransactionType};
+    use crate::repository::transaction_repo::MockTransactionRepository;
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::*;
+
+    fn transaction(id: i32) -> Transaction {
+        Transaction {
+            id,
+            user_id: 1,
+            campaign_id: Some(10),
+            transaction_type: TransactionType::Donation,
+            amount: 50_000.0,
+            created_at: Utc.with_ymd_and_hms(2024, 3, 1, 10, 0, 0).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_clamps_pagination() {
+        let mut mock_transaction_repo = MockTransactionRepository::new();
+        mock_transaction_repo.expect_count().returning(|_| Ok(450));
+        mock_transaction_repo
+            .expect_find()
+            .with(always(), eq(MAX_PER_PAGE), eq(0))
+            .times(1)
+            .returning(|_, _, _| Ok(vec![transaction(1)]));
+
+        let service = TransactionService::new(Arc::new(mock_transaction_repo));
+        let page = service
+            .list(TransactionFilter::default(), Some(0), Some(10_000))
+            .await
+            .unwrap();
+
+        assert_eq!(page.page, 1);
+        assert_eq!(page.per_page, MAX_PER_PAGE);
+        assert_eq!(page.total, 450);
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_inverted_amount_range() {
+        let service = TransactionService::new(Arc::new(MockTransactionRepository::new()));
+        let filter = TransactionFilter {
+            min_amount: Some(500.0),
+            max_amount: Some(100.0),
+            ..TransactionFilter::default()
+        };
+        let result = service.list(filter, None, None).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("min_amount")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_writes_header_and_rows() {
+        let mut mock_transaction_repo = MockTransactionRepository::new();
+        mock_transaction_repo
+            .expect_find()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![transaction(1), transaction(2)]));
+
+        let service = TransactionService::new(Arc::new(mock_transaction_repo));
+        let csv = service
+            .export_csv(TransactionFilter::default())
+            .await
+            .unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "id,user_id,campaign_id,transaction_type,amount,created_at"
+        );
+        assert_eq!(
+            lines[1],
+            "1,1,10,donation,50000.0,2024-03-01T10:00:00+00:00"
+        );
+    }
+}
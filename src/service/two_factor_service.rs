@@ -0,0 +1,503 @@
+use crate::errors::AppError;
+use crate::model::two_factor::{AdminTwoFactor, RecoveryCodes, StepUpStatus, TwoFactorEnrollment};
+use crate::rate_limit::RateLimiter;
+use crate::repository::two_factor_repo::TwoFactorRepository;
+use aes_gcm::aead::{Aead, AeadCore};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+pub const DEFAULT_STEP_UP_WINDOW: Duration = Duration::minutes(10);
+pub const TOTP_ISSUER: &str = "A12 Backend";
+const TOTP_STEP_SECONDS: i64 = 30;
+const TOTP_DIGITS: u32 = 6;
+// Accept the previous and next code to allow for clock drift.
+const TOTP_DRIFT_STEPS: i64 = 1;
+const RECOVERY_CODE_COUNT: usize = 10;
+// Step-up attempts per admin, so a six-digit code can't be guessed by brute force.
+const STEP_UP_ATTEMPTS: usize = 5;
+const STEP_UP_ATTEMPT_WINDOW: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+// Marks a stored secret as AES-256-GCM ciphertext: "v1:" + base64(nonce || ciphertext).
+const ENCRYPTED_SECRET_PREFIX: &str = "v1:";
+const NONCE_LEN: usize = 12;
+
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in bytes.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..chars {
+            let index = (bits >> (35 - i * 5)) & 0x1f;
+            out.push(BASE32_ALPHABET[index as usize] as char);
+        }
+    }
+    out
+}
+
+fn base32_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut bits = 0u64;
+    let mut bit_count = 0;
+    for c in text.trim_end_matches('=').bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c.to_ascii_uppercase())?;
+        bits = (bits << 5) | value as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push((bits >> bit_count) as u8);
+        }
+    }
+    Some(out)
+}
+
+// RFC 4226 HOTP with HMAC-SHA1, as used by authenticator apps for TOTP.
+fn hotp(secret: &[u8], counter: i64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = (digest[19] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    code % 10u32.pow(TOTP_DIGITS)
+}
+
+/// Returns the time step the code belongs to, if it is valid and newer than `last_used_step`.
+fn verify_totp(
+    secret: &str,
+    code: &str,
+    now: DateTime<Utc>,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let secret = base32_decode(secret)?;
+    let code: u32 = code.parse().ok()?;
+    let current = now.timestamp() / TOTP_STEP_SECONDS;
+    (current - TOTP_DRIFT_STEPS..=current + TOTP_DRIFT_STEPS)
+        .filter(|step| last_used_step.is_none_or(|used| *step > used))
+        .find(|step| hotp(&secret, *step) == code)
+}
+
+fn is_totp_code(code: &str) -> bool {
+    code.len() == TOTP_DIGITS as usize && code.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn hash_recovery_code(code: &str) -> String {
+    hex::encode(Sha256::digest(code.trim().to_ascii_lowercase().as_bytes()))
+}
+
+// Named in full: `KeyInit::new_from_slice` would clash with `Mac`'s for the HMACs above.
+fn secret_cipher(secret_key: &[u8; 32]) -> Aes256Gcm {
+    <Aes256Gcm as aes_gcm::KeyInit>::new(secret_key.into())
+}
+
+fn encrypt_secret(cipher: &Aes256Gcm, secret: &str) -> String {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, secret.as_bytes())
+        .expect("AES-GCM encryption does not fail for short inputs");
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    format!("{}{}", ENCRYPTED_SECRET_PREFIX, STANDARD.encode(sealed))
+}
+
+fn decrypt_secret(cipher: &Aes256Gcm, stored: &str) -> Result<String, AppError> {
+    let undecryptable =
+        || AppError::InternalServerError("Could not decrypt a two-factor secret".to_string());
+    let sealed = stored
+        .strip_prefix(ENCRYPTED_SECRET_PREFIX)
+        .and_then(|encoded| STANDARD.decode(encoded).ok())
+        .filter(|sealed| sealed.len() > NONCE_LEN)
+        .ok_or_else(undecryptable)?;
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let secret = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| undecryptable())?;
+    String::from_utf8(secret).map_err(|_| undecryptable())
+}
+
+fn generate_recovery_code() -> String {
+    let mut bytes = [0u8; 5];
+    OsRng.fill_bytes(&mut bytes);
+    let code = hex::encode(bytes);
+    format!("{}-{}", &code[..5], &code[5..])
+}
+
+// TOTP secrets are stored encrypted with `secret_key`, so a database dump alone
+// can't be used to generate codes.
+pub struct TwoFactorService {
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    cipher: Aes256Gcm,
+    step_up_window: Duration,
+    step_up_attempts: RateLimiter<i32>,
+}
+
+impl TwoFactorService {
+    pub fn new(two_factor_repo: Arc<dyn TwoFactorRepository>, secret_key: &[u8; 32]) -> Self {
+        TwoFactorService {
+            two_factor_repo,
+            cipher: secret_cipher(secret_key),
+            step_up_window: DEFAULT_STEP_UP_WINDOW,
+            step_up_attempts: RateLimiter::new(STEP_UP_ATTEMPTS, STEP_UP_ATTEMPT_WINDOW),
+        }
+    }
+
+    pub fn with_step_up_window(mut self, step_up_window: Duration) -> Self {
+        self.step_up_window = step_up_window;
+        self
+    }
+
+    pub async fn enroll(&self, admin_id: i32) -> Result<TwoFactorEnrollment, AppError> {
+        let mut secret = [0u8; 20];
+        OsRng.fill_bytes(&mut secret);
+        let secret = base32_encode(&secret);
+        self.two_factor_repo
+            .save_pending(admin_id, &encrypt_secret(&self.cipher, &secret))
+            .await?;
+
+        let otpauth_uri = format!(
+            "otpauth://totp/{issuer}:admin-{admin_id}?secret={secret}&issuer={issuer}&digits={TOTP_DIGITS}&period={TOTP_STEP_SECONDS}",
+            issuer = TOTP_ISSUER.replace(' ', "%20"),
+        );
+        Ok(TwoFactorEnrollment {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    /// Confirms enrollment with a first code and issues fresh recovery codes.
+    pub async fn verify_enrollment(
+        &self,
+        admin_id: i32,
+        code: &str,
+    ) -> Result<RecoveryCodes, AppError> {
+        let two_factor = self.find(admin_id).await?;
+        if two_factor.enabled_at.is_some() {
+            return Err(AppError::ValidationError(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+        let secret = decrypt_secret(&self.cipher, &two_factor.secret)?;
+        let step = verify_totp(&secret, code.trim(), Utc::now(), None)
+            .ok_or_else(|| AppError::ValidationError("Invalid two-factor code".to_string()))?;
+
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+        let hashes = recovery_codes
+            .iter()
+            .map(|code| hash_recovery_code(code))
+            .collect();
+        self.two_factor_repo.enable(admin_id, step, hashes).await?;
+        Ok(RecoveryCodes { recovery_codes })
+    }
+
+    /// Re-verifies the second factor before sensitive actions; accepts a TOTP code or
+    /// a recovery code, which is then spent. Only failed attempts count toward the limit.
+    pub async fn step_up(&self, admin_id: i32, code: &str) -> Result<StepUpStatus, AppError> {
+        if let Err(retry_after) = self.step_up_attempts.try_acquire(admin_id) {
+            return Err(AppError::TooManyRequests {
+                message: "Too many two-factor attempts".to_string(),
+                retry_after_secs: retry_after.as_secs().max(1),
+            });
+        }
+        let two_factor = self.find(admin_id).await?;
+        if two_factor.enabled_at.is_none() {
+            return Err(AppError::Forbidden(
+                "Two-factor authentication is not enabled".to_string(),
+            ));
+        }
+
+        let code = code.trim();
+        let step = if is_totp_code(code) {
+            let secret = decrypt_secret(&self.cipher, &two_factor.secret)?;
+            let step = verify_totp(&secret, code, Utc::now(), two_factor.last_used_step)
+                .ok_or_else(|| AppError::ValidationError("Invalid two-factor code".to_string()))?;
+            Some(step)
+        } else {
+            let consumed = self
+                .two_factor_repo
+                .consume_recovery_code(admin_id, &hash_recovery_code(code))
+                .await?;
+            if !consumed {
+                return Err(AppError::ValidationError(
+                    "Invalid two-factor code".to_string(),
+                ));
+            }
+            None
+        };
+
+        let two_factor = self
+            .two_factor_repo
+            .record_verification(admin_id, step)
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Invalid two-factor code".to_string()))?;
+        self.step_up_attempts.release(&admin_id);
+        let verified_at = two_factor.last_verified_at.unwrap_or_else(Utc::now);
+        Ok(StepUpStatus {
+            verified_until: verified_at + self.step_up_window,
+        })
+    }
+
+    /// Gate for payout approval and other admin actions that move money or reach all users.
+    pub async fn require_recent_second_factor(&self, admin_id: i32) -> Result<(), AppError> {
+        let recent = self
+            .two_factor_repo
+            .find(admin_id)
+            .await?
+            .filter(|two_factor| two_factor.enabled_at.is_some())
+            .and_then(|two_factor| two_factor.last_verified_at)
+            .is_some_and(|verified_at| Utc::now() - verified_at <= self.step_up_window);
+        if !recent {
+            return Err(AppError::Forbidden(
+                "Recent two-factor verification required".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Encrypts secrets stored before encryption at rest; run once at startup.
+    pub async fn encrypt_stored_secrets(&self) -> Result<usize, AppError> {
+        let mut encrypted = 0;
+        for two_factor in self.two_factor_repo.find_unencrypted().await? {
+            let sealed = encrypt_secret(&self.cipher, &two_factor.secret);
+            if self
+                .two_factor_repo
+                .replace_secret(two_factor.admin_id, &two_factor.secret, &sealed)
+                .await?
+            {
+                encrypted += 1;
+            }
+        }
+        Ok(encrypted)
+    }
+
+    async fn find(&self, admin_id: i32) -> Result<AdminTwoFactor, AppError> {
+        self.two_factor_repo
+            .find(admin_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Two-factor enrollment not found".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::two_factor_repo::MockTwoFactorRepository;
+    use chrono::TimeZone;
+    use mockall::predicate::*;
+
+    // RFC 6238 appendix B secret ("12345678901234567890").
+    const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+    const SECRET_KEY: &[u8; 32] = b"0123456789abcdef0123456789abcdef";
+
+    fn two_factor(enabled: bool, last_verified_at: Option<DateTime<Utc>>) -> AdminTwoFactor {
+        AdminTwoFactor {
+            admin_id: 1,
+            secret: encrypt_secret(&secret_cipher(SECRET_KEY), RFC_SECRET),
+            enabled_at: enabled.then(Utc::now),
+            last_verified_at,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_totp_matches_rfc_6238_vectors() {
+        assert_eq!(base32_encode(b"12345678901234567890"), RFC_SECRET);
+        assert_eq!(base32_decode(RFC_SECRET).unwrap(), b"12345678901234567890");
+
+        let at = Utc.timestamp_opt(1_111_111_109, 0).unwrap();
+        assert_eq!(
+            verify_totp(RFC_SECRET, "081804", at, None),
+            Some(37_037_036)
+        );
+        let at = Utc.timestamp_opt(59, 0).unwrap();
+        assert_eq!(verify_totp(RFC_SECRET, "287082", at, None), Some(1));
+        // Replaying a code from an already used step is rejected.
+        assert_eq!(verify_totp(RFC_SECRET, "287082", at, Some(1)), None);
+        assert_eq!(verify_totp(RFC_SECRET, "000000", at, None), None);
+    }
+
+    #[test]
+    fn test_secrets_round_trip_through_the_cipher() {
+        let cipher = secret_cipher(SECRET_KEY);
+        let sealed = encrypt_secret(&cipher, RFC_SECRET);
+
+        assert!(sealed.starts_with(ENCRYPTED_SECRET_PREFIX));
+        assert!(!sealed.contains(RFC_SECRET));
+        assert_ne!(sealed, encrypt_secret(&cipher, RFC_SECRET));
+        assert_eq!(decrypt_secret(&cipher, &sealed).unwrap(), RFC_SECRET);
+
+        let other = secret_cipher(b"fedcba9876543210fedcba9876543210");
+        assert!(decrypt_secret(&other, &sealed).is_err());
+        assert!(decrypt_secret(&cipher, RFC_SECRET).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enroll_stores_the_secret_encrypted() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_save_pending()
+            .withf(|admin_id, secret| *admin_id == 1 && secret.starts_with(ENCRYPTED_SECRET_PREFIX))
+            .times(1)
+            .returning(|_, secret| {
+                Ok(AdminTwoFactor {
+                    secret: secret.to_string(),
+                    ..two_factor(false, None)
+                })
+            });
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        let enrollment = service.enroll(1).await.unwrap();
+        assert!(enrollment.otpauth_uri.contains(&enrollment.secret));
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_stored_secrets_seals_plaintext_rows() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find_unencrypted()
+            .returning(|| {
+                Ok(vec![AdminTwoFactor {
+                    secret: RFC_SECRET.to_string(),
+                    ..two_factor(true, None)
+                }])
+            });
+        mock_two_factor_repo
+            .expect_replace_secret()
+            .withf(|admin_id, old, new| {
+                *admin_id == 1
+                    && old == RFC_SECRET
+                    && decrypt_secret(&secret_cipher(SECRET_KEY), new).unwrap() == RFC_SECRET
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        assert_eq!(service.encrypt_stored_secrets().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_step_up_with_recovery_code_spends_it() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .returning(|_| Ok(Some(two_factor(true, None))));
+        mock_two_factor_repo
+            .expect_consume_recovery_code()
+            .with(eq(1), eq(hash_recovery_code("abcde-12345")))
+            .times(1)
+            .returning(|_, _| Ok(true));
+        mock_two_factor_repo
+            .expect_record_verification()
+            .with(eq(1), eq(None))
+            .returning(|_, _| Ok(Some(two_factor(true, Some(Utc::now())))));
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        let status = service.step_up(1, " ABCDE-12345 ").await.unwrap();
+        assert!(status.verified_until > Utc::now() + Duration::minutes(9));
+    }
+
+    #[tokio::test]
+    async fn test_step_up_attempts_are_limited() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .times(STEP_UP_ATTEMPTS)
+            .returning(|_| {
+                // Every step counts as used, so no code is accepted.
+                Ok(Some(AdminTwoFactor {
+                    last_used_step: Some(i64::MAX),
+                    ..two_factor(true, None)
+                }))
+            });
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        for _ in 0..STEP_UP_ATTEMPTS {
+            match service.step_up(1, "000000").await.err().unwrap() {
+                AppError::ValidationError(_) => {}
+                _ => panic!("Expected ValidationError"),
+            }
+        }
+        match service.step_up(1, "000000").await.err().unwrap() {
+            AppError::TooManyRequests { .. } => {}
+            _ => panic!("Expected TooManyRequests error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_successful_step_ups_do_not_count_toward_the_limit() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .returning(|_| Ok(Some(two_factor(true, None))));
+        mock_two_factor_repo
+            .expect_consume_recovery_code()
+            .times(STEP_UP_ATTEMPTS + 1)
+            .returning(|_, _| Ok(true));
+        mock_two_factor_repo
+            .expect_record_verification()
+            .returning(|_, _| Ok(Some(two_factor(true, Some(Utc::now())))));
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        for _ in 0..=STEP_UP_ATTEMPTS {
+            assert!(service.step_up(1, "abcde-12345").await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sensitive_actions_require_recent_verification() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .with(eq(1))
+            .returning(|_| {
+                Ok(Some(two_factor(
+                    true,
+                    Some(Utc::now() - Duration::minutes(2)),
+                )))
+            });
+        mock_two_factor_repo
+            .expect_find()
+            .with(eq(2))
+            .returning(|_| {
+                Ok(Some(two_factor(
+                    true,
+                    Some(Utc::now() - Duration::minutes(30)),
+                )))
+            });
+        mock_two_factor_repo
+            .expect_find()
+            .with(eq(3))
+            .returning(|_| Ok(None));
+        let service = TwoFactorService::new(Arc::new(mock_two_factor_repo), SECRET_KEY);
+
+        assert!(service.require_recent_second_factor(1).await.is_ok());
+        for admin_id in [2, 3] {
+            match service
+                .require_recent_second_factor(admin_id)
+                .await
+                .err()
+                .unwrap()
+            {
+                AppError::Forbidden(msg) => assert!(msg.contains("Recent two-factor")),
+                _ => panic!("Expected Forbidden error"),
+            }
+        }
+    }
+}
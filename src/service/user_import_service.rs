@@ -0,0 +1,379 @@
+use crate::errors::AppError;
+use crate::model::user_import::{
+    ImportedAccount, ImportedUserRow, InvitedUser, UserImportReport, UserImportRowResult,
+    UserImportStatus,
+};
+use crate::repository::user_import_repo::UserImportRepository;
+use crate::service::donation_import_service::{parse_csv, row_validation_message};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(test)]
+use mockall::automock;
+
+pub const USER_IMPORT_CHUNK_SIZE: usize = 200;
+const ACCOUNTS_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where accounts are created. Users and credentials belong to the auth service, so
+/// this service never writes them itself.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait AccountDirectory: Send + Sync {
+    /// One answer per row. Rows whose email the directory already has come back with
+    /// `created: false` and the existing user id.
+    async fn create_accounts(
+        &self,
+        rows: Vec<ImportedUserRow>,
+    ) -> Result<Vec<ImportedAccount>, AppError>;
+}
+
+/// Posts each batch as a JSON array of `{email, name, role}` to the auth service's
+/// `auth.accounts_url`, authenticated with `auth.service_token` as a bearer token. The
+/// response is a JSON array of `{user_id, email, created}`.
+pub struct HttpAccountDirectory {
+    client: reqwest::Client,
+    accounts_url: String,
+    service_token: String,
+}
+
+impl HttpAccountDirectory {
+    pub fn new(accounts_url: String, service_token: String) -> Self {
+        HttpAccountDirectory {
+            client: reqwest::Client::builder()
+                .timeout(ACCOUNTS_REQUEST_TIMEOUT)
+                .build()
+                .expect("auth service HTTP client"),
+            accounts_url,
+            service_token,
+        }
+    }
+}
+
+#[async_trait]
+impl AccountDirectory for HttpAccountDirectory {
+    async fn create_accounts(
+        &self,
+        rows: Vec<ImportedUserRow>,
+    ) -> Result<Vec<ImportedAccount>, AppError> {
+        let response = self
+            .client
+            .post(&self.accounts_url)
+            .bearer_auth(&self.service_token)
+            .json(&rows)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                AppError::InternalServerError(format!("Auth service rejected the accounts: {}", e))
+            })?;
+        response.json().await.map_err(|e| {
+            AppError::InternalServerError(format!("Could not read the auth service reply: {}", e))
+        })
+    }
+}
+
+/// Creates accounts from an admin's CSV of `email,name,role` rows through the auth
+/// service and invites each new user. Emails that already have an account are skipped
+/// rather than failed, so an import that stopped halfway can simply be uploaded again.
+pub struct UserImportService {
+    account_directory: Arc<dyn AccountDirectory>,
+    user_import_repo: Arc<dyn UserImportRepository>,
+    chunk_size: usize,
+}
+
+impl UserImportService {
+    pub fn new(
+        account_directory: Arc<dyn AccountDirectory>,
+        user_import_repo: Arc<dyn UserImportRepository>,
+    ) -> Self {
+        UserImportService {
+            account_directory,
+            user_import_repo,
+            chunk_size: USER_IMPORT_CHUNK_SIZE,
+        }
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    pub async fn import(&self, body: &str) -> Result<UserImportReport, AppError> {
+        let (rows, parse_errors) = parse_csv::<ImportedUserRow>(body)?;
+        let mut results: Vec<UserImportRowResult> = parse_errors
+            .into_iter()
+            .map(|error| failed(error.line, None, error.message))
+            .collect();
+
+        let mut first_line_by_email: HashMap<String, usize> = HashMap::new();
+        let mut valid_rows = Vec::with_capacity(rows.len());
+        for (line, row) in rows {
+            let row = ImportedUserRow {
+                email: row.email.trim().to_string(),
+                name: row.name.trim().to_string(),
+                role: row.role,
+            };
+            if let Err(message) = row_validation_message(&row) {
+                results.push(failed(line, Some(row.email), message));
+                continue;
+            }
+            if let Some(first_line) = first_line_by_email.get(&row.email.to_lowercase()) {
+                results.push(UserImportRowResult {
+                    line,
+                    email: Some(row.email),
+                    status: UserImportStatus::Skipped,
+                    user_id: None,
+                    message: Some(format!("Duplicate of line {}", first_line)),
+                });
+                continue;
+            }
+            first_line_by_email.insert(row.email.to_lowercase(), line);
+            valid_rows.push((line, row));
+        }
+
+        for chunk in valid_rows.chunks(self.chunk_size) {
+            let batch: Vec<ImportedUserRow> = chunk.iter().map(|(_, row)| row.clone()).collect();
+            let accounts = match self.account_directory.create_accounts(batch).await {
+                Ok(accounts) => accounts,
+                Err(e) => {
+                    results.extend(chunk.iter().map(|(line, row)| {
+                        failed(
+                            *line,
+                            Some(row.email.clone()),
+                            format!("Account creation failed: {}", e),
+                        )
+                    }));
+                    continue;
+                }
+            };
+            let accounts: HashMap<String, ImportedAccount> = accounts
+                .into_iter()
+                .map(|account| (account.email.to_lowercase(), account))
+                .collect();
+
+            let invited: Vec<InvitedUser> = chunk
+                .iter()
+                .filter_map(|(_, row)| {
+                    let account = accounts.get(&row.email.to_lowercase())?;
+                    account.created.then(|| InvitedUser {
+                        user_id: account.user_id,
+                        email: row.email.clone(),
+                        name: row.name.clone(),
+                    })
+                })
+                .collect();
+            let invitation_error = if invited.is_empty() {
+                None
+            } else {
+                self.user_import_repo.invite(invited).await.err()
+            };
+
+            results.extend(chunk.iter().map(|(line, row)| {
+                let Some(account) = accounts.get(&row.email.to_lowercase()) else {
+                    return failed(
+                        *line,
+                        Some(row.email.clone()),
+                        "The auth service did not return this account".to_string(),
+                    );
+                };
+                let (status, message) = match (&invitation_error, account.created) {
+                    (_, false) => (
+                        UserImportStatus::Skipped,
+                        Some("An account with this email already exists".to_string()),
+                    ),
+                    (None, true) => (UserImportStatus::Created, None),
+                    (Some(e), true) => (
+                        UserImportStatus::Failed,
+                        Some(format!("Account created but the invitation failed: {}", e)),
+                    ),
+                };
+                UserImportRowResult {
+                    line: *line,
+                    email: Some(row.email.clone()),
+                    status,
+                    user_id: Some(account.user_id),
+                    message,
+                }
+            }));
+        }
+
+        results.sort_by_key(|result| result.line);
+        let count = |status| {
+            results
+                .iter()
+                .filter(|result| result.status == status)
+                .count()
+        };
+        Ok(UserImportReport {
+            total_rows: results.len(),
+            created: count(UserImportStatus::Created),
+            skipped: count(UserImportStatus::Skipped),
+            failed: count(UserImportStatus::Failed),
+            rows: results,
+        })
+    }
+}
+
+fn failed(line: usize, email: Option<String>, message: String) -> UserImportRowResult {
+    UserImportRowResult {
+        line,
+        email,
+        status: UserImportStatus::Failed,
+        user_id: None,
+        message: Some(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::user_import::UserRole;
+    use crate::repository::user_import_repo::MockUserImportRepository;
+
+    const CSV: &str = "email,name,role\n\
+                       rina@example.com,Rina,fundraiser\n\
+                       budi@example.com,Budi,donor\n\
+                       not-an-email,Sari,donor\n\
+                       RINA@example.com,Rina Again,donor\n\
+                       andi@example.com,Andi,superuser\n";
+
+    // The directory's answer when it already has the `existing` emails.
+    fn accounts(rows: &[ImportedUserRow], existing: &[&str]) -> Vec<ImportedAccount> {
+        rows.iter()
+            .enumerate()
+            .map(|(index, row)| ImportedAccount {
+                user_id: index as i32 + 1,
+                email: row.email.clone(),
+                created: !existing.contains(&row.email.as_str()),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_every_row() {
+        let mut mock_directory = MockAccountDirectory::new();
+        mock_directory
+            .expect_create_accounts()
+            .withf(|rows| {
+                rows.len() == 2
+                    && rows[0].role == UserRole::Fundraiser
+                    && rows[1].email == "budi@example.com"
+            })
+            .times(1)
+            .returning(|rows| Ok(accounts(&rows, &["budi@example.com"])));
+        let mut mock_repo = MockUserImportRepository::new();
+        mock_repo
+            .expect_invite()
+            .withf(|users| {
+                *users
+                    == vec![InvitedUser {
+                        user_id: 1,
+                        email: "rina@example.com".to_string(),
+                        name: "Rina".to_string(),
+                    }]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = UserImportService::new(Arc::new(mock_directory), Arc::new(mock_repo));
+        let report = service.import(CSV).await.unwrap();
+
+        assert_eq!(report.total_rows, 5);
+        assert_eq!((report.created, report.skipped, report.failed), (1, 2, 2));
+        let statuses: Vec<UserImportStatus> = report.rows.iter().map(|row| row.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                UserImportStatus::Created,
+                UserImportStatus::Skipped,
+                UserImportStatus::Failed,
+                UserImportStatus::Skipped,
+                UserImportStatus::Failed,
+            ]
+        );
+        assert_eq!(report.rows[0].user_id, Some(1));
+        assert_eq!(report.rows[1].user_id, Some(2));
+        assert_eq!(report.rows[3].line, 5);
+        assert_eq!(
+            report.rows[3].message.as_deref(),
+            Some("Duplicate of line 2")
+        );
+        assert!(
+            report.rows[4]
+                .message
+                .as_ref()
+                .unwrap()
+                .contains("Malformed row")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_failed_batch_marks_its_rows() {
+        let mut mock_directory = MockAccountDirectory::new();
+        let mut calls = 0;
+        mock_directory
+            .expect_create_accounts()
+            .times(2)
+            .returning(move |rows| {
+                calls += 1;
+                if calls == 1 {
+                    Ok(accounts(&rows, &[]))
+                } else {
+                    Err(AppError::InternalServerError(
+                        "auth service unavailable".to_string(),
+                    ))
+                }
+            });
+        let mut mock_repo = MockUserImportRepository::new();
+        mock_repo.expect_invite().times(1).returning(|_| Ok(()));
+
+        let service = UserImportService::new(Arc::new(mock_directory), Arc::new(mock_repo))
+            .with_chunk_size(1);
+        let report = service
+            .import("email,name,role\nrina@example.com,Rina,donor\nbudi@example.com,Budi,donor\n")
+            .await
+            .unwrap();
+
+        assert_eq!((report.created, report.failed), (1, 1));
+        assert_eq!(report.rows[1].email.as_deref(), Some("budi@example.com"));
+        assert!(
+            report.rows[1]
+                .message
+                .as_ref()
+                .unwrap()
+                .contains("auth service unavailable")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_accounts_whose_invitation_failed() {
+        let mut mock_directory = MockAccountDirectory::new();
+        mock_directory
+            .expect_create_accounts()
+            .returning(|rows| Ok(accounts(&rows, &[])));
+        let mut mock_repo = MockUserImportRepository::new();
+        mock_repo.expect_invite().returning(|_| {
+            Err(AppError::InternalServerError(
+                "deadlock detected".to_string(),
+            ))
+        });
+
+        let service = UserImportService::new(Arc::new(mock_directory), Arc::new(mock_repo));
+        let report = service
+            .import("email,name,role\nrina@example.com,Rina,donor\n")
+            .await
+            .unwrap();
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.rows[0].user_id, Some(1));
+        assert!(
+            report.rows[0]
+                .message
+                .as_ref()
+                .unwrap()
+                .contains("invitation failed")
+        );
+    }
+}
@@ -0,0 +1,647 @@
+use crate::config::PayoutPolicyConfig;
+use crate::errors::AppError;
+use crate::model::kyc::KycSubject;
+use crate::model::withdrawal::{
+    NewWithdrawalRequest, Withdrawal, WithdrawalAuditEntry, WithdrawalStatus,
+};
+use crate::repository::campaign_budget_repo::CampaignBudgetRepository;
+use crate::repository::kyc_repo::KycRepository;
+use crate::repository::organization_repo::OrganizationRepository;
+use crate::repository::withdrawal_repo::WithdrawalRepository;
+use crate::service::commands::withdrawal_commands::{
+    RequestWithdrawalCommand, ReviewWithdrawalCommand,
+};
+use crate::service::two_factor_service::TwoFactorService;
+use std::sync::Arc;
+
+pub struct WithdrawalService {
+    withdrawal_repo: Arc<dyn WithdrawalRepository>,
+    two_factor: Option<Arc<TwoFactorService>>,
+    budget_repo: Option<Arc<dyn CampaignBudgetRepository>>,
+    payout_policy: Option<PayoutPolicyConfig>,
+    organization_repo: Option<Arc<dyn OrganizationRepository>>,
+    kyc_repo: Option<Arc<dyn KycRepository>>,
+}
+
+impl WithdrawalService {
+    pub fn new(withdrawal_repo: Arc<dyn WithdrawalRepository>) -> Self {
+        WithdrawalService {
+            withdrawal_repo,
+            two_factor: None,
+            budget_repo: None,
+            payout_policy: None,
+            organization_repo: None,
+            kyc_repo: None,
+        }
+    }
+
+    // Approving a payout then requires a recent two-factor step-up by the admin.
+    pub fn with_two_factor(mut self, two_factor: Arc<TwoFactorService>) -> Self {
+        self.two_factor = Some(two_factor);
+        self
+    }
+
+    // Fundraisers of campaigns that require spend reports must report on every approved
+    // payout before requesting the next one.
+    pub fn with_spend_report_check(
+        mut self,
+        budget_repo: Arc<dyn CampaignBudgetRepository>,
+    ) -> Self {
+        self.budget_repo = Some(budget_repo);
+        self
+    }
+
+    // Without a policy every payout needs exactly one admin approval.
+    pub fn with_payout_policy(mut self, payout_policy: PayoutPolicyConfig) -> Self {
+        self.payout_policy = Some(payout_policy);
+        self
+    }
+
+    // Needed for payouts out of an organization's shared wallet. Whether the member may
+    // request one is checked by the route.
+    pub fn with_organizations(
+        mut self,
+        organization_repo: Arc<dyn OrganizationRepository>,
+    ) -> Self {
+        self.organization_repo = Some(organization_repo);
+        self
+    }
+
+    // Personal payouts then need an approved identity verification. Organization payouts
+    // are covered by the organization having to be verified.
+    pub fn with_kyc_check(mut self, kyc_repo: Arc<dyn KycRepository>) -> Self {
+        self.kyc_repo = Some(kyc_repo);
+        self
+    }
+
+    fn required_approvals(&self, amount: f64) -> i32 {
+        match &self.payout_policy {
+            Some(policy) if amount < policy.auto_approve_below => 0,
+            Some(policy) if amount >= policy.dual_approval_from => 2,
+            _ => 1,
+        }
+    }
+
+    pub async fn request_withdrawal(
+        &self,
+        cmd: RequestWithdrawalCommand,
+    ) -> Result<Withdrawal, AppError> {
+        if cmd.amount <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Withdrawal amount must be positive".to_string(),
+            ));
+        }
+        if cmd.bank_name.trim().is_empty()
+            || cmd.account_number.trim().is_empty()
+            || cmd.account_holder.trim().is_empty()
+        {
+            return Err(AppError::ValidationError(
+                "Bank account details are required".to_string(),
+            ));
+        }
+        if let (Some(kyc_repo), None) = (&self.kyc_repo, cmd.organization_id)
+            && !kyc_repo.is_approved(KycSubject::User(cmd.user_id)).await? {
+                return Err(AppError::ValidationError(
+                    "Verify your identity before requesting a payout".to_string(),
+                ));
+            }
+        if let Some(budget_repo) = &self.budget_repo
+            && budget_repo.has_unreported_payout(cmd.user_id).await? {
+                return Err(AppError::ValidationError(
+                    "Submit a spend report for your previous payout before requesting another"
+                        .to_string(),
+                ));
+            }
+
+        if let Some(organization_id) = cmd.organization_id {
+            let organization = self
+                .organization_repo()?
+                .find_by_id(organization_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Organization not found".to_string()))?;
+            if organization.verified_at.is_none() {
+                return Err(AppError::ValidationError(
+                    "Only verified organizations can request payouts".to_string(),
+                ));
+            }
+        }
+
+        let req = NewWithdrawalRequest {
+            amount: cmd.amount,
+            bank_name: cmd.bank_name,
+            account_number: cmd.account_number,
+            account_holder: cmd.account_holder,
+        };
+
+        let required_approvals = self.required_approvals(cmd.amount);
+        let withdrawal = self
+            .withdrawal_repo
+            .create(cmd.user_id, cmd.organization_id, &req, required_approvals)
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Insufficient wallet balance".to_string()))?;
+
+        if required_approvals > 0 {
+            return Ok(withdrawal);
+        }
+        let note = Some("Approved automatically by the payout policy".to_string());
+        self.approve(withdrawal.id, None, note).await
+    }
+
+    /// Adds the admin's approval. The withdrawal stays pending until it has as many
+    /// approvals as the payout policy required when it was requested.
+    pub async fn approve_withdrawal(
+        &self,
+        cmd: ReviewWithdrawalCommand,
+    ) -> Result<Withdrawal, AppError> {
+        if let Some(two_factor) = &self.two_factor {
+            two_factor
+                .require_recent_second_factor(cmd.admin_id)
+                .await?;
+        }
+        self.approve(cmd.withdrawal_id, Some(cmd.admin_id), cmd.note)
+            .await
+    }
+
+    pub async fn reject_withdrawal(
+        &self,
+        cmd: ReviewWithdrawalCommand,
+    ) -> Result<Withdrawal, AppError> {
+        let rejected = self
+            .withdrawal_repo
+            .reject(cmd.withdrawal_id, cmd.admin_id, cmd.note)
+            .await?;
+        self.reviewed(cmd.withdrawal_id, rejected).await
+    }
+
+    pub async fn get_withdrawals_by_user(&self, user_id: i32) -> Result<Vec<Withdrawal>, AppError> {
+        self.withdrawal_repo.find_by_user(user_id).await
+    }
+
+    pub async fn get_withdrawals_by_organization(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<Withdrawal>, AppError> {
+        self.withdrawal_repo
+            .find_by_organization(organization_id)
+            .await
+    }
+
+    pub async fn get_withdrawals_by_status(
+        &self,
+        status: WithdrawalStatus,
+    ) -> Result<Vec<Withdrawal>, AppError> {
+        self.withdrawal_repo.find_by_status(status).await
+    }
+
+    pub async fn get_pending_approvals(&self, admin_id: i32) -> Result<Vec<Withdrawal>, AppError> {
+        self.withdrawal_repo.find_awaiting_approval(admin_id).await
+    }
+
+    pub async fn get_audit_log(
+        &self,
+        withdrawal_id: i32,
+    ) -> Result<Vec<WithdrawalAuditEntry>, AppError> {
+        if self.withdrawal_repo.find_by_id(withdrawal_id).await?.is_none() {
+            return Err(AppError::NotFound("Withdrawal not found".to_string()));
+        }
+        self.withdrawal_repo.find_audit_log(withdrawal_id).await
+    }
+
+    async fn approve(
+        &self,
+        withdrawal_id: i32,
+        admin_id: Option<i32>,
+        note: Option<String>,
+    ) -> Result<Withdrawal, AppError> {
+        let approved = self
+            .withdrawal_repo
+            .approve(withdrawal_id, admin_id, note)
+            .await?;
+        self.reviewed(withdrawal_id, approved).await
+    }
+
+    fn organization_repo(&self) -> Result<&Arc<dyn OrganizationRepository>, AppError> {
+        self.organization_repo.as_ref().ok_or_else(|| {
+            AppError::InternalServerError("Organization payouts are not configured".to_string())
+        })
+    }
+
+    async fn reviewed(
+        &self,
+        withdrawal_id: i32,
+        withdrawal: Option<Withdrawal>,
+    ) -> Result<Withdrawal, AppError> {
+        match withdrawal {
+            Some(withdrawal) => Ok(withdrawal),
+            None => {
+                let exists = self
+                    .withdrawal_repo
+                    .find_by_id(withdrawal_id)
+                    .await?
+                    .is_some();
+                if !exists {
+                    Err(AppError::NotFound("Withdrawal not found".to_string()))
+                } else {
+                    Err(AppError::ValidationError(
+                        "Withdrawal has already been reviewed".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::organization::Organization;
+    use crate::repository::{
+        campaign_budget_repo::MockCampaignBudgetRepository, kyc_repo::MockKycRepository,
+        organization_repo::MockOrganizationRepository, two_factor_repo::MockTwoFactorRepository,
+        withdrawal_repo::MockWithdrawalRepository,
+    };
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn sample_withdrawal(id: i32, user_id: i32, amount: f64, status: WithdrawalStatus) -> Withdrawal {
+        Withdrawal {
+            id,
+            user_id,
+            amount,
+            bank_name: "BCA".to_string(),
+            account_number: "1234567890".to_string(),
+            account_holder: "Budi".to_string(),
+            status,
+            admin_note: None,
+            required_approvals: 1,
+            approval_count: 0,
+            organization_id: None,
+            created_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    fn request_cmd(user_id: i32, amount: f64) -> RequestWithdrawalCommand {
+        RequestWithdrawalCommand {
+            user_id,
+            organization_id: None,
+            amount,
+            bank_name: "BCA".to_string(),
+            account_number: "1234567890".to_string(),
+            account_holder: "Budi".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_success() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let expected = sample_withdrawal(1, 1, 100.0, WithdrawalStatus::Pending);
+        let expected_clone = expected.clone();
+
+        mock_withdrawal_repo
+            .expect_create()
+            .withf(|uid, organization_id, req, required_approvals| {
+                *uid == 1
+                    && organization_id.is_none()
+                    && req.amount == 100.0
+                    && *required_approvals == 1
+            })
+            .times(1)
+            .returning(move |_, _, _, _| Ok(Some(expected_clone.clone())));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let result = service.request_withdrawal(request_cmd(1, 100.0)).await;
+
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_insufficient_balance() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+
+        mock_withdrawal_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _, _, _| Ok(None));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let result = service.request_withdrawal(request_cmd(1, 100.0)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("Insufficient")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_invalid_amount() {
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()));
+        let result = service.request_withdrawal(request_cmd(1, 0.0)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("must be positive")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_blocked_by_missing_spend_report() {
+        let mut mock_budget_repo = MockCampaignBudgetRepository::new();
+        mock_budget_repo
+            .expect_has_unreported_payout()
+            .with(eq(1))
+            .returning(|_| Ok(true));
+
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()))
+        .with_spend_report_check(Arc::new(mock_budget_repo));
+        let result = service.request_withdrawal(request_cmd(1, 100.0)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("spend report")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_blocked_until_kyc_approved() {
+        let mut mock_kyc_repo = MockKycRepository::new();
+        mock_kyc_repo
+            .expect_is_approved()
+            .with(eq(KycSubject::User(1)))
+            .returning(|_| Ok(false));
+
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()))
+        .with_kyc_check(Arc::new(mock_kyc_repo));
+        let result = service.request_withdrawal(request_cmd(1, 100.0)).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("Verify your identity")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approve_withdrawal_returns_approved() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let approved = sample_withdrawal(7, 3, 250.0, WithdrawalStatus::Approved);
+
+        mock_withdrawal_repo
+            .expect_approve()
+            .with(eq(7), eq(Some(99)), eq(None::<String>))
+            .times(1)
+            .returning(move |_, _, _| Ok(Some(approved.clone())));
+
+        let service = WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 7,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.approve_withdrawal(cmd).await;
+
+        assert_eq!(result.unwrap().status, WithdrawalStatus::Approved);
+    }
+
+    fn policy() -> PayoutPolicyConfig {
+        PayoutPolicyConfig {
+            auto_approve_below: 500.0,
+            dual_approval_from: 10_000.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_withdrawal_below_threshold_is_auto_approved() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let pending = sample_withdrawal(5, 1, 100.0, WithdrawalStatus::Pending);
+        let approved = sample_withdrawal(5, 1, 100.0, WithdrawalStatus::Approved);
+
+        mock_withdrawal_repo
+            .expect_create()
+            .withf(|_, _, _, required_approvals| *required_approvals == 0)
+            .returning(move |_, _, _, _| Ok(Some(pending.clone())));
+        mock_withdrawal_repo
+            .expect_approve()
+            .withf(|id, admin_id, _| *id == 5 && admin_id.is_none())
+            .times(1)
+            .returning(move |_, _, _| Ok(Some(approved.clone())));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo))
+                .with_payout_policy(policy());
+        let result = service.request_withdrawal(request_cmd(1, 100.0)).await;
+
+        assert_eq!(result.unwrap().status, WithdrawalStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn test_first_of_two_approvals_stays_pending() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let partly_approved = Withdrawal {
+            required_approvals: 2,
+            approval_count: 1,
+            organization_id: None,
+            ..sample_withdrawal(7, 3, 20_000.0, WithdrawalStatus::Pending)
+        };
+
+        mock_withdrawal_repo
+            .expect_approve()
+            .returning(move |_, _, _| Ok(Some(partly_approved.clone())));
+
+        let service = WithdrawalService::new(Arc::new(mock_withdrawal_repo))
+        .with_payout_policy(policy());
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 7,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.approve_withdrawal(cmd).await.unwrap();
+
+        assert_eq!(result.status, WithdrawalStatus::Pending);
+        assert_eq!(result.approval_count, 1);
+    }
+
+    #[test]
+    fn test_required_approvals_follow_policy_tiers() {
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()));
+        assert_eq!(service.required_approvals(1.0), 1);
+
+        let service = service.with_payout_policy(policy());
+        assert_eq!(service.required_approvals(499.0), 0);
+        assert_eq!(service.required_approvals(500.0), 1);
+        assert_eq!(service.required_approvals(10_000.0), 2);
+    }
+
+    #[tokio::test]
+    async fn test_approve_withdrawal_requires_step_up() {
+        let mut mock_two_factor_repo = MockTwoFactorRepository::new();
+        mock_two_factor_repo
+            .expect_find()
+            .with(eq(99))
+            .returning(|_| Ok(None));
+        let two_factor = Arc::new(TwoFactorService::new(Arc::new(mock_two_factor_repo), &[0; 32]));
+
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()))
+        .with_two_factor(two_factor);
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 7,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.approve_withdrawal(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::Forbidden(msg) => assert!(msg.contains("two-factor")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reject_withdrawal_returns_rejected() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let rejected = sample_withdrawal(7, 3, 250.0, WithdrawalStatus::Rejected);
+
+        mock_withdrawal_repo
+            .expect_reject()
+            .with(eq(7), eq(99), always())
+            .times(1)
+            .returning(move |_, _, _| Ok(Some(rejected.clone())));
+
+        let service = WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 7,
+            admin_id: 99,
+            note: Some("Account name mismatch".to_string()),
+        };
+        let result = service.reject_withdrawal(cmd).await;
+
+        assert_eq!(result.unwrap().status, WithdrawalStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_review_already_reviewed_withdrawal() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let existing = sample_withdrawal(7, 3, 250.0, WithdrawalStatus::Approved);
+
+        mock_withdrawal_repo
+            .expect_approve()
+            .returning(|_, _, _| Ok(None));
+        mock_withdrawal_repo
+            .expect_find_by_id()
+            .with(eq(7))
+            .returning(move |_| Ok(Some(existing.clone())));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 7,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.approve_withdrawal(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("already been reviewed")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_review_withdrawal_not_found() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+
+        mock_withdrawal_repo
+            .expect_reject()
+            .returning(|_, _, _| Ok(None));
+        mock_withdrawal_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(None));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo));
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 42,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.reject_withdrawal(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::NotFound(msg) => assert!(msg.contains("Withdrawal not found")),
+            _ => panic!("Expected NotFound error"),
+        }
+    }
+
+    fn organization(verified: bool) -> Organization {
+        Organization {
+            id: 5,
+            name: "Yayasan Peduli".to_string(),
+            registration_number: "AHU-0001".to_string(),
+            description: None,
+            website: None,
+            created_by: 2,
+            verified_at: verified.then(Utc::now),
+            verified_by: verified.then_some(99),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_organization_payout_is_held_on_organization_wallet() {
+        let mut mock_withdrawal_repo = MockWithdrawalRepository::new();
+        let mut mock_organization_repo = MockOrganizationRepository::new();
+        let mut pending = sample_withdrawal(8, 2, 300.0, WithdrawalStatus::Pending);
+        pending.organization_id = Some(5);
+        let mut approved = pending.clone();
+        approved.status = WithdrawalStatus::Approved;
+
+        mock_organization_repo
+            .expect_find_by_id()
+            .with(eq(5))
+            .returning(|_| Ok(Some(organization(true))));
+        mock_withdrawal_repo
+            .expect_create()
+            .withf(|uid, organization_id, _, _| *uid == 2 && *organization_id == Some(5))
+            .times(1)
+            .returning(move |_, _, _, _| Ok(Some(pending.clone())));
+        mock_withdrawal_repo
+            .expect_approve()
+            .returning(move |_, _, _| Ok(Some(approved.clone())));
+
+        let service =
+            WithdrawalService::new(Arc::new(mock_withdrawal_repo))
+                .with_organizations(Arc::new(mock_organization_repo));
+        let mut cmd = request_cmd(2, 300.0);
+        cmd.organization_id = Some(5);
+        service.request_withdrawal(cmd).await.unwrap();
+
+        let cmd = ReviewWithdrawalCommand {
+            withdrawal_id: 8,
+            admin_id: 99,
+            note: None,
+        };
+        let result = service.approve_withdrawal(cmd).await;
+
+        assert_eq!(result.unwrap().organization_id, Some(5));
+    }
+
+    #[tokio::test]
+    async fn test_unverified_organization_cannot_request_payout() {
+        let mut mock_organization_repo = MockOrganizationRepository::new();
+        mock_organization_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(organization(false))));
+
+        let service = WithdrawalService::new(Arc::new(MockWithdrawalRepository::new()))
+        .with_organizations(Arc::new(mock_organization_repo));
+        let mut cmd = request_cmd(2, 300.0);
+        cmd.organization_id = Some(5);
+        let result = service.request_withdrawal(cmd).await;
+
+        match result.err().unwrap() {
+            AppError::ValidationError(msg) => assert!(msg.contains("verified")),
+            _ => panic!("Expected ValidationError"),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Isolated Postgres databases for repository tests.
+//!
+//! Every `test_db` call gets its own schema, so tests can run in parallel. The
+//! server comes from `TEST_DATABASE_URL` when set (e.g. a CI service container);
+//! otherwise one ephemeral Postgres container is started per test binary. The
+//! schema is dropped again when the `TestDb` goes out of scope.
+#![cfg(test)]
+
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use sqlx::{Connection, Executor, PgConnection};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::testcontainers::ContainerAsync;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use tokio::sync::OnceCell;
+
+// Kept alive for the whole test binary; Ryuk removes the container once the process exits.
+static CONTAINER: OnceLock<ContainerAsync<Postgres>> = OnceLock::new();
+static DATABASE_URL: OnceCell<String> = OnceCell::const_new();
+static NEXT_SCHEMA: AtomicUsize = AtomicUsize::new(0);
+
+pub struct TestDb {
+    pub pool: PgPool,
+    url: &'static str,
+    schema: String,
+}
+
+// `Drop` cannot await, so the schema is dropped from a helper thread with its own
+// runtime. The test's runtime is blocked meanwhile and cannot finish rolling back
+// connections it still holds, so those are terminated first (the pool tags them with
+// the schema name). A failure only leaks the schema, so it is reported rather than
+// panicking.
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        let url = self.url;
+        let schema = std::mem::take(&mut self.schema);
+        let dropped = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start teardown runtime");
+            runtime.block_on(async {
+                let mut admin = PgConnection::connect(url).await?;
+                sqlx::query(
+                    "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE application_name = $1",
+                )
+                .bind(&schema)
+                .execute(&mut admin)
+                .await?;
+                admin.execute("SET lock_timeout = '10s'").await?;
+                admin
+                    .execute(format!("DROP SCHEMA IF EXISTS {} CASCADE", schema).as_str())
+                    .await?;
+                admin.close().await
+            })
+        })
+        .join();
+        if let Ok(Err(e)) = dropped {
+            eprintln!("failed to drop test schema: {}", e);
+        }
+    }
+}
+
+async fn database_url() -> &'static str {
+    DATABASE_URL
+        .get_or_init(|| async {
+            if let Ok(url) = std::env::var("TEST_DATABASE_URL") {
+                return url;
+            }
+            let container = Postgres::default()
+                .start()
+                .await
+                .expect("failed to start Postgres test container");
+            let host = container.get_host().await.expect("container host");
+            let port = container
+                .get_host_port_ipv4(5432)
+                .await
+                .expect("container port");
+            let _ = CONTAINER.set(container);
+            format!("postgres://postgres:postgres@{}:{}/postgres", host, port)
+        })
+        .await
+}
+
+/// Creates a fresh schema, runs the migrations inside it and returns a pool scoped to it.
+pub async fn test_db() -> TestDb {
+    let url = database_url().await;
+    let schema = format!(
+        "test_{}_{}",
+        std::process::id(),
+        NEXT_SCHEMA.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let admin = PgPool::connect(url)
+        .await
+        .expect("failed to connect to test database");
+    admin
+        .execute(format!("CREATE SCHEMA {}", schema).as_str())
+        .await
+        .expect("failed to create test schema");
+    admin.close().await;
+
+    let options = PgConnectOptions::from_str(url)
+        .expect("invalid test database URL")
+        .application_name(&schema);
+    let search_path = format!("SET search_path TO {}, public", schema);
+    let pool = PgPoolOptions::new()
+        .max_connections(4)
+        .after_connect(move |conn, _meta| {
+            let search_path = search_path.clone();
+            Box::pin(async move {
+                conn.execute(search_path.as_str()).await?;
+                Ok(())
+            })
+        })
+        .connect_with(options)
+        .await
+        .expect("failed to connect to test schema");
+    // Each test migrates its own schema, so the database-wide lock would only serialize them.
+    let mut migrator = sqlx::migrate!();
+    migrator.set_locking(false);
+    migrator
+        .run(&pool)
+        .await
+        .expect("failed to migrate test schema");
+
+    TestDb { pool, url, schema }
+}
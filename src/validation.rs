@@ -0,0 +1,122 @@
+use serde::Serialize;
+use validator::{ValidationErrors, ValidationErrorsKind};
+use crate::errors::AppError;
+
+pub use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+pub fn validate<T: Validate>(value: &T) -> Result<(), AppError> {
+    value.validate().map_err(AppError::from)
+}
+
+fn collect_field_errors(prefix: &str, errors: &ValidationErrors, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                for error in field_errors {
+                    out.push(FieldError {
+                        field: path.clone(),
+                        code: error.code.to_string(),
+                        message: error
+                            .message
+                            .as_ref()
+                            .map(|message| message.to_string())
+                            .unwrap_or_else(|| format!("{} is invalid", path)),
+                    });
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(&path, nested, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(&format!("{}[{}]", path, index), nested, out);
+                }
+            }
+        }
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut field_errors = Vec::new();
+        collect_field_errors("", &errors, &mut field_errors);
+        field_errors.sort_by(|a, b| a.field.cmp(&b.field));
+        AppError::UnprocessableEntity(field_errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::donation::NewDonationRequest;
+    use crate::model::withdrawal::NewWithdrawalRequest;
+
+    #[test]
+    fn test_valid_donation_request_passes() {
+        let req = NewDonationRequest {
+            campaign_id: 10,
+            amount: 50.0,
+            message: Some("Semangat!".to_string()),
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+        assert!(validate(&req).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_donation_request_reports_each_field() {
+        let req = NewDonationRequest {
+            campaign_id: 0,
+            amount: -5.0,
+            message: Some("x".repeat(501)),
+            private_note: None,
+            tier_id: None,
+            referral_code: None,
+            honoree_name: None,
+            honoree_email: None,
+            anonymous: false,
+        };
+
+        match validate(&req).err().unwrap() {
+            AppError::UnprocessableEntity(errors) => {
+                let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields, vec!["amount", "campaign_id", "message"]);
+                assert_eq!(errors[0].code, "range");
+                assert!(errors[0].message.contains("must be positive"));
+            }
+            _ => panic!("Expected UnprocessableEntity error"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_withdrawal_request_reports_blank_bank_fields() {
+        let req = NewWithdrawalRequest {
+            amount: 100.0,
+            bank_name: String::new(),
+            account_number: "1234567890".to_string(),
+            account_holder: String::new(),
+        };
+
+        match validate(&req).err().unwrap() {
+            AppError::UnprocessableEntity(errors) => {
+                let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields, vec!["account_holder", "bank_name"]);
+            }
+            _ => panic!("Expected UnprocessableEntity error"),
+        }
+    }
+}